@@ -18,6 +18,8 @@
 //! # Example
 //!
 //! ```rust,no_run
+//! # #[cfg(feature = "api-projects")]
+//! # fn main() {
 //! use serde::Deserialize;
 //! use gitlab::Gitlab;
 //! use gitlab::api::{self, projects, Query};
@@ -55,34 +57,106 @@
 //! // usually meant for endpoints which represent file contents, pipeline artifacts, etc., but may
 //! // be used with any endpoint.
 //! let raw_data: Vec<u8> = api::raw(endpoint).query(&client).unwrap();
+//! # }
+//! # #[cfg(not(feature = "api-projects"))]
+//! # fn main() {}
 //! ```
 
+mod batch;
+mod body;
 mod client;
 mod endpoint;
 mod error;
 mod ignore;
+mod link;
 mod paged;
 mod params;
 pub(crate) mod query;
 mod raw;
+mod response_meta;
+mod strict;
 mod sudo;
+mod trace;
 
 pub mod endpoint_prelude;
+pub mod prelude;
 
+#[cfg(feature = "api-admin")]
+pub mod admin;
+#[cfg(feature = "api-application")]
+pub mod application;
+#[cfg(feature = "api-avatar")]
+pub mod avatar;
+#[cfg(feature = "api-broadcast-messages")]
+pub mod broadcast_messages;
+pub mod cache;
+#[cfg(feature = "api-ci-lint")]
+pub mod ci_lint;
 pub mod common;
+#[cfg(feature = "api-deploy-keys")]
 pub mod deploy_keys;
+#[cfg(feature = "api-events")]
+pub mod events;
+pub mod export;
+#[cfg(feature = "api-features")]
+pub mod features;
+#[cfg(feature = "flows")]
+pub mod flows;
+#[cfg(feature = "api-groups")]
 pub mod groups;
+#[cfg(feature = "api-projects")]
+pub mod handles;
+pub mod instance;
+#[cfg(feature = "api-issues")]
 pub mod issues;
 pub mod job;
+#[cfg(feature = "api-member-roles")]
+pub mod member_roles;
+#[cfg(feature = "api-namespaces")]
+pub mod namespaces;
+#[cfg(feature = "api-notification-settings")]
+pub mod notification_settings;
+#[cfg(feature = "api-packages")]
 pub mod packages;
+#[cfg(feature = "api-personal-access-tokens")]
 pub mod personal_access_tokens;
+#[cfg(feature = "api-projects")]
+pub mod pool;
+#[cfg(feature = "api-projects")]
 pub mod projects;
+#[cfg(feature = "api-groups")]
+pub mod resolver;
 pub mod retry;
+#[cfg(feature = "api-runners")]
 pub mod runners;
+#[cfg(feature = "api-search")]
+pub mod search;
+pub mod secrets;
+#[cfg(feature = "api-sidekiq")]
+pub mod sidekiq;
+#[cfg(feature = "api-snippets")]
+pub mod snippets;
+#[cfg(feature = "api-todos")]
+pub mod todos;
+#[cfg(feature = "api-users")]
 pub mod users;
 
+#[cfg(any(
+    feature = "api-groups",
+    feature = "api-issues",
+    feature = "api-projects"
+))]
 pub(crate) mod helpers;
 
+pub use self::batch::batch;
+pub use self::batch::Batch;
+pub use self::batch::BatchError;
+pub use self::batch::DEFAULT_BATCH_CONCURRENCY;
+
+pub use self::body::SpooledBody;
+pub use self::body::SpooledBodyReader;
+pub use self::body::DEFAULT_SPOOL_THRESHOLD;
+
 pub use self::client::AsyncClient;
 pub use self::client::Client;
 pub use self::client::RestClient;
@@ -96,6 +170,9 @@ pub use self::error::BodyError;
 pub use self::ignore::ignore;
 pub use self::ignore::Ignore;
 
+pub use self::link::ApiLink;
+pub use self::link::ApiLinks;
+
 pub use self::paged::paged;
 pub use self::paged::LazilyPagedIter;
 pub use self::paged::LinkHeaderParseError;
@@ -106,6 +183,8 @@ pub use self::paged::PaginationError;
 
 pub use self::params::FormParams;
 pub use self::params::JsonParams;
+pub use self::params::MultipartParams;
+pub use self::params::ParamName;
 pub use self::params::ParamValue;
 pub use self::params::QueryParams;
 
@@ -115,6 +194,13 @@ pub use self::query::Query;
 pub use self::raw::raw;
 pub use self::raw::Raw;
 
+pub use self::response_meta::with_response_meta;
+pub use self::response_meta::ResponseMeta;
+pub use self::response_meta::WithResponseMeta;
+
+pub use self::strict::strict;
+pub use self::strict::Strict;
+
 pub use self::sudo::sudo;
 pub use self::sudo::Sudo;
 pub use self::sudo::SudoContext;
@@ -0,0 +1,12 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Administrative API endpoints.
+//!
+//! These endpoints are used for managing the GitLab instance itself and generally require an
+//! administrator token.
+
+pub mod ci_variables;
@@ -0,0 +1,37 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Instance-level CI variable API endpoints.
+//!
+//! These endpoints are used for querying the CI variables available to all projects on an
+//! instance.
+
+mod create;
+mod delete;
+mod update;
+mod variable;
+mod variables;
+
+pub use self::create::CiVariableType;
+pub use self::create::CreateCiVariable;
+pub use self::create::CreateCiVariableBuilder;
+pub use self::create::CreateCiVariableBuilderError;
+
+pub use self::delete::DeleteCiVariable;
+pub use self::delete::DeleteCiVariableBuilder;
+pub use self::delete::DeleteCiVariableBuilderError;
+
+pub use self::update::UpdateCiVariable;
+pub use self::update::UpdateCiVariableBuilder;
+pub use self::update::UpdateCiVariableBuilderError;
+
+pub use self::variable::CiVariable;
+pub use self::variable::CiVariableBuilder;
+pub use self::variable::CiVariableBuilderError;
+
+pub use self::variables::CiVariables;
+pub use self::variables::CiVariablesBuilder;
+pub use self::variables::CiVariablesBuilderError;
@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Delete an instance-level CI variable.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteCiVariable<'a> {
+    /// The name of the variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> DeleteCiVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteCiVariableBuilder<'a> {
+        DeleteCiVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteCiVariable<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/ci/variables/{}", common::path_escaped(&self.key)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::admin::ci_variables::{DeleteCiVariable, DeleteCiVariableBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_necessary() {
+        let err = DeleteCiVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteCiVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_sufficient() {
+        DeleteCiVariable::builder().key("testkey").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/ci/variables/testkey%2F")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteCiVariable::builder().key("testkey/").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,232 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::admin::ci_variables::CiVariableType;
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Edit an instance-level CI variable.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct UpdateCiVariable<'a> {
+    /// The name of the variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value of the variable.
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+    /// The type of the variable.
+    #[builder(default)]
+    variable_type: Option<CiVariableType>,
+    /// Whether the variable is protected.
+    #[builder(default)]
+    protected: Option<bool>,
+    /// Whether the variable is masked.
+    #[builder(default)]
+    masked: Option<bool>,
+    /// Whether the variable is treated as a raw string.
+    #[builder(default)]
+    raw: Option<bool>,
+    /// The description of the variable.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+}
+
+impl<'a> UpdateCiVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateCiVariableBuilder<'a> {
+        UpdateCiVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for UpdateCiVariable<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/ci/variables/{}", common::path_escaped(&self.key)).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("value", &self.value)
+            .push_opt("variable_type", self.variable_type)
+            .push_opt("protected", self.protected)
+            .push_opt("masked", self.masked)
+            .push_opt("raw", self.raw)
+            .push_opt("description", self.description.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::admin::ci_variables::update::{
+        CiVariableType, UpdateCiVariable, UpdateCiVariableBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = UpdateCiVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateCiVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = UpdateCiVariable::builder()
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateCiVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn value_level_is_necessary() {
+        let err = UpdateCiVariable::builder()
+            .key("testkey")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateCiVariableBuilderError, "value");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/ci/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=testvalue")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_variable_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/ci/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&variable_type=file"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .variable_type(CiVariableType::File)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_protected() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/ci/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&protected=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .protected(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_masked() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/ci/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&masked=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .masked(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_raw() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/ci/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&raw=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .raw(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/ci/variables/testkey")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=testvalue", "&description=desc"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateCiVariable::builder()
+            .key("testkey")
+            .value("testvalue")
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
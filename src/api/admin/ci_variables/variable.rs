@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Get an instance-level CI variable.
+#[derive(Debug, Builder, Clone)]
+pub struct CiVariable<'a> {
+    /// The name of the variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> CiVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CiVariableBuilder<'a> {
+        CiVariableBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CiVariable<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/ci/variables/{}", common::path_escaped(&self.key)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::admin::ci_variables::{CiVariable, CiVariableBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_necessary() {
+        let err = CiVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CiVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_sufficient() {
+        CiVariable::builder().key("testkey").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("admin/ci/variables/testkey%2F")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CiVariable::builder().key("testkey/").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the instance-level CI variables.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CiVariables {}
+
+impl CiVariables {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CiVariablesBuilder {
+        CiVariablesBuilder::default()
+    }
+}
+
+impl Endpoint for CiVariables {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "admin/ci/variables".into()
+    }
+}
+
+impl Pageable for CiVariables {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::admin::ci_variables::CiVariables;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        CiVariables::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("admin/ci/variables")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CiVariables::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
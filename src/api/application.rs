@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Application API endpoints.
+//!
+//! These endpoints are used for querying and managing the configuration of the GitLab instance.
+//! Most of them require an administrator token.
+
+mod edit_settings;
+mod plan_limits;
+mod settings;
+mod statistics;
+
+pub use self::edit_settings::EditSettings;
+pub use self::edit_settings::EditSettingsBuilder;
+pub use self::edit_settings::EditSettingsBuilderError;
+
+pub use self::plan_limits::PlanLimits;
+pub use self::plan_limits::PlanLimitsBuilder;
+pub use self::plan_limits::PlanLimitsBuilderError;
+
+pub use self::settings::Settings;
+pub use self::settings::SettingsBuilder;
+pub use self::settings::SettingsBuilderError;
+
+pub use self::statistics::Statistics;
+pub use self::statistics::StatisticsBuilder;
+pub use self::statistics::StatisticsBuilderError;
@@ -0,0 +1,641 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::VisibilityLevel;
+use crate::api::endpoint_prelude::*;
+
+/// Edit the settings of the instance.
+///
+/// Only settings which are set are changed.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditSettings<'a> {
+    /// Whether anyone may sign up for an account.
+    #[builder(default)]
+    signup_enabled: Option<bool>,
+    /// Whether new accounts must be approved by an administrator.
+    #[builder(default)]
+    require_admin_approval_after_user_signup: Option<bool>,
+    /// Domains which are allowed to sign up.
+    #[builder(setter(name = "_domain_allowlist"), default, private)]
+    domain_allowlist: BTreeSet<Cow<'a, str>>,
+    /// Whether the domain denylist is used or not.
+    #[builder(default)]
+    domain_denylist_enabled: Option<bool>,
+    /// Domains which are not allowed to sign up.
+    #[builder(setter(name = "_domain_denylist"), default, private)]
+    domain_denylist: BTreeSet<Cow<'a, str>>,
+    /// Whether email addresses used for sign up are restricted or not.
+    #[builder(default)]
+    email_restrictions_enabled: Option<bool>,
+    /// A regular expression which email addresses used for sign up must not match.
+    #[builder(setter(into), default)]
+    email_restrictions: Option<Cow<'a, str>>,
+    /// Whether new users are external by default or not.
+    #[builder(default)]
+    user_default_external: Option<bool>,
+    /// The number of projects a new user may create.
+    #[builder(default)]
+    default_projects_limit: Option<u64>,
+
+    /// The default visibility of new projects.
+    #[builder(default)]
+    default_project_visibility: Option<VisibilityLevel>,
+    /// The default visibility of new groups.
+    #[builder(default)]
+    default_group_visibility: Option<VisibilityLevel>,
+    /// The default visibility of new snippets.
+    #[builder(default)]
+    default_snippet_visibility: Option<VisibilityLevel>,
+    /// The name of the initial branch of new repositories.
+    #[builder(setter(into), default)]
+    default_branch_name: Option<Cow<'a, str>>,
+
+    /// The maximum size of attachments (in MiB).
+    #[builder(default)]
+    max_attachment_size: Option<u64>,
+    /// The maximum size of imports (in MiB).
+    ///
+    /// Use `0` for no limit.
+    #[builder(default)]
+    max_import_size: Option<u64>,
+
+    /// Whether API requests from authenticated users are rate limited or not.
+    #[builder(default)]
+    throttle_authenticated_api_enabled: Option<bool>,
+    /// The number of API requests authenticated users may make per period.
+    #[builder(default)]
+    throttle_authenticated_api_requests_per_period: Option<u64>,
+    /// The length of the rate limit period for authenticated API requests (in seconds).
+    #[builder(default)]
+    throttle_authenticated_api_period_in_seconds: Option<u64>,
+    /// Whether web requests from authenticated users are rate limited or not.
+    #[builder(default)]
+    throttle_authenticated_web_enabled: Option<bool>,
+    /// The number of web requests authenticated users may make per period.
+    #[builder(default)]
+    throttle_authenticated_web_requests_per_period: Option<u64>,
+    /// The length of the rate limit period for authenticated web requests (in seconds).
+    #[builder(default)]
+    throttle_authenticated_web_period_in_seconds: Option<u64>,
+    /// Whether API requests from unauthenticated clients are rate limited or not.
+    #[builder(default)]
+    throttle_unauthenticated_api_enabled: Option<bool>,
+    /// The number of API requests an unauthenticated IP address may make per period.
+    #[builder(default)]
+    throttle_unauthenticated_api_requests_per_period: Option<u64>,
+    /// The length of the rate limit period for unauthenticated API requests (in seconds).
+    #[builder(default)]
+    throttle_unauthenticated_api_period_in_seconds: Option<u64>,
+    /// Whether web requests from unauthenticated clients are rate limited or not.
+    #[builder(default)]
+    throttle_unauthenticated_web_enabled: Option<bool>,
+    /// The number of web requests an unauthenticated IP address may make per period.
+    #[builder(default)]
+    throttle_unauthenticated_web_requests_per_period: Option<u64>,
+    /// The length of the rate limit period for unauthenticated web requests (in seconds).
+    #[builder(default)]
+    throttle_unauthenticated_web_period_in_seconds: Option<u64>,
+}
+
+impl<'a> EditSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditSettingsBuilder<'a> {
+        EditSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditSettingsBuilder<'a> {
+    /// Allow a domain to sign up.
+    pub fn domain_allowlist_entry<D>(&mut self, domain: D) -> &mut Self
+    where
+        D: Into<Cow<'a, str>>,
+    {
+        self.domain_allowlist
+            .get_or_insert_with(BTreeSet::new)
+            .insert(domain.into());
+        self
+    }
+
+    /// Allow multiple domains to sign up.
+    pub fn domain_allowlist<I, D>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = D>,
+        D: Into<Cow<'a, str>>,
+    {
+        self.domain_allowlist
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Deny a domain from signing up.
+    pub fn domain_denylist_entry<D>(&mut self, domain: D) -> &mut Self
+    where
+        D: Into<Cow<'a, str>>,
+    {
+        self.domain_denylist
+            .get_or_insert_with(BTreeSet::new)
+            .insert(domain.into());
+        self
+    }
+
+    /// Deny multiple domains from signing up.
+    pub fn domain_denylist<I, D>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = D>,
+        D: Into<Cow<'a, str>>,
+    {
+        self.domain_denylist
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for EditSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/settings".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("signup_enabled", self.signup_enabled)
+            .push_opt(
+                "require_admin_approval_after_user_signup",
+                self.require_admin_approval_after_user_signup,
+            )
+            .extend(
+                self.domain_allowlist
+                    .iter()
+                    .map(|value| (ParamName::new("domain_allowlist").array(), value)),
+            )
+            .push_opt("domain_denylist_enabled", self.domain_denylist_enabled)
+            .extend(
+                self.domain_denylist
+                    .iter()
+                    .map(|value| (ParamName::new("domain_denylist").array(), value)),
+            )
+            .push_opt(
+                "email_restrictions_enabled",
+                self.email_restrictions_enabled,
+            )
+            .push_opt("email_restrictions", self.email_restrictions.as_ref())
+            .push_opt("user_default_external", self.user_default_external)
+            .push_opt("default_projects_limit", self.default_projects_limit)
+            .push_opt(
+                "default_project_visibility",
+                self.default_project_visibility,
+            )
+            .push_opt("default_group_visibility", self.default_group_visibility)
+            .push_opt(
+                "default_snippet_visibility",
+                self.default_snippet_visibility,
+            )
+            .push_opt("default_branch_name", self.default_branch_name.as_ref())
+            .push_opt("max_attachment_size", self.max_attachment_size)
+            .push_opt("max_import_size", self.max_import_size)
+            .push_opt(
+                "throttle_authenticated_api_enabled",
+                self.throttle_authenticated_api_enabled,
+            )
+            .push_opt(
+                "throttle_authenticated_api_requests_per_period",
+                self.throttle_authenticated_api_requests_per_period,
+            )
+            .push_opt(
+                "throttle_authenticated_api_period_in_seconds",
+                self.throttle_authenticated_api_period_in_seconds,
+            )
+            .push_opt(
+                "throttle_authenticated_web_enabled",
+                self.throttle_authenticated_web_enabled,
+            )
+            .push_opt(
+                "throttle_authenticated_web_requests_per_period",
+                self.throttle_authenticated_web_requests_per_period,
+            )
+            .push_opt(
+                "throttle_authenticated_web_period_in_seconds",
+                self.throttle_authenticated_web_period_in_seconds,
+            )
+            .push_opt(
+                "throttle_unauthenticated_api_enabled",
+                self.throttle_unauthenticated_api_enabled,
+            )
+            .push_opt(
+                "throttle_unauthenticated_api_requests_per_period",
+                self.throttle_unauthenticated_api_requests_per_period,
+            )
+            .push_opt(
+                "throttle_unauthenticated_api_period_in_seconds",
+                self.throttle_unauthenticated_api_period_in_seconds,
+            )
+            .push_opt(
+                "throttle_unauthenticated_enabled",
+                self.throttle_unauthenticated_web_enabled,
+            )
+            .push_opt(
+                "throttle_unauthenticated_requests_per_period",
+                self.throttle_unauthenticated_web_requests_per_period,
+            )
+            .push_opt(
+                "throttle_unauthenticated_period_in_seconds",
+                self.throttle_unauthenticated_web_period_in_seconds,
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::application::EditSettings;
+    use crate::api::common::VisibilityLevel;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        EditSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_signup_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("signup_enabled=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .signup_enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_require_admin_approval_after_user_signup() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("require_admin_approval_after_user_signup=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .require_admin_approval_after_user_signup(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_domain_allowlist() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("domain_allowlist%5B%5D=example.com&domain_allowlist%5B%5D=example.org")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .domain_allowlist_entry("example.com")
+            .domain_allowlist(["example.org"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_domain_denylist_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("domain_denylist_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .domain_denylist_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_domain_denylist() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("domain_denylist%5B%5D=example.com&domain_denylist%5B%5D=example.org")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .domain_denylist_entry("example.com")
+            .domain_denylist(["example.org"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_email_restrictions_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("email_restrictions_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .email_restrictions_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_email_restrictions() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("email_restrictions=%40example%5C.com%24")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .email_restrictions("@example\\.com$")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_user_default_external() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("user_default_external=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .user_default_external(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_projects_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_projects_limit=10")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .default_projects_limit(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_project_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_project_visibility=private")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .default_project_visibility(VisibilityLevel::Private)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_group_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_group_visibility=internal")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .default_group_visibility(VisibilityLevel::Internal)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_snippet_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_snippet_visibility=public")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .default_snippet_visibility(VisibilityLevel::Public)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_branch_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_branch_name=main")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .default_branch_name("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_max_attachment_size() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("max_attachment_size=100")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .max_attachment_size(100)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_max_import_size() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("max_import_size=0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder().max_import_size(0).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_api() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_api_enabled=true&throttle_authenticated_api_requests_per_period=100&throttle_authenticated_api_period_in_seconds=60")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .throttle_authenticated_api_enabled(true)
+            .throttle_authenticated_api_requests_per_period(100)
+            .throttle_authenticated_api_period_in_seconds(60)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_web() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_web_enabled=true&throttle_authenticated_web_requests_per_period=100&throttle_authenticated_web_period_in_seconds=60")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .throttle_authenticated_web_enabled(true)
+            .throttle_authenticated_web_requests_per_period(100)
+            .throttle_authenticated_web_period_in_seconds(60)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_unauthenticated_api() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_unauthenticated_api_enabled=true&throttle_unauthenticated_api_requests_per_period=100&throttle_unauthenticated_api_period_in_seconds=60")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .throttle_unauthenticated_api_enabled(true)
+            .throttle_unauthenticated_api_requests_per_period(100)
+            .throttle_unauthenticated_api_period_in_seconds(60)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_unauthenticated_web() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_unauthenticated_enabled=true&throttle_unauthenticated_requests_per_period=100&throttle_unauthenticated_period_in_seconds=60")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditSettings::builder()
+            .throttle_unauthenticated_web_enabled(true)
+            .throttle_unauthenticated_web_requests_per_period(100)
+            .throttle_unauthenticated_web_period_in_seconds(60)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the limits of a plan on the instance.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct PlanLimits<'a> {
+    /// The name of the plan to query (e.g., `default` or `ultimate`).
+    ///
+    /// Defaults to the `default` plan.
+    #[builder(setter(into), default)]
+    plan_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> PlanLimits<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PlanLimitsBuilder<'a> {
+        PlanLimitsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PlanLimits<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/plan_limits".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("plan_name", self.plan_name.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::application::PlanLimits;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        PlanLimits::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("application/plan_limits")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PlanLimits::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_plan_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("application/plan_limits")
+            .add_query_params(&[("plan_name", "ultimate")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PlanLimits::builder().plan_name("ultimate").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for statistics about the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Statistics {}
+
+impl Statistics {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StatisticsBuilder {
+        StatisticsBuilder::default()
+    }
+}
+
+impl Endpoint for Statistics {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/statistics".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::application::Statistics;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Statistics::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("application/statistics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Statistics::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
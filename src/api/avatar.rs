@@ -0,0 +1,17 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Avatar API endpoints.
+//!
+//! These endpoints are used for looking up avatars by email address.
+
+mod avatar;
+
+pub use self::avatar::Avatar;
+pub use self::avatar::AvatarBuilder;
+pub use self::avatar::AvatarBuilderError;
@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query the URL of the avatar for an email address.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Avatar<'a> {
+    /// The public email address of the user.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+
+    /// The size of the avatar (in pixels).
+    ///
+    /// Only used for Gravatar and services compatible with its `libravatar` protocol.
+    #[builder(default)]
+    size: Option<u64>,
+}
+
+impl<'a> Avatar<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AvatarBuilder<'a> {
+        AvatarBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Avatar<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "avatar".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("email", &self.email)
+            .push_opt("size", self.size);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::avatar::{Avatar, AvatarBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn email_is_needed() {
+        let err = Avatar::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AvatarBuilderError, "email");
+    }
+
+    #[test]
+    fn email_is_sufficient() {
+        Avatar::builder().email("user@example.com").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("avatar")
+            .add_query_params(&[("email", "user@example.com")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Avatar::builder().email("user@example.com").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_size() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("avatar")
+            .add_query_params(&[("email", "user@example.com"), ("size", "64")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Avatar::builder()
+            .email("user@example.com")
+            .size(64)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
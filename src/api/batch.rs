@@ -0,0 +1,263 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+use std::fmt;
+
+use futures_util::stream::{self, StreamExt};
+
+use crate::api::{ApiError, AsyncClient, AsyncQuery};
+
+/// The default number of queries a batch runs at the same time.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
+/// A set of queries to execute concurrently against an asynchronous client.
+#[derive(Debug, Clone)]
+pub struct Batch<Q> {
+    queries: Vec<Q>,
+    concurrency: usize,
+}
+
+/// Execute a set of queries concurrently.
+///
+/// Any query may be used, so endpoints may be wrapped in `api::ignore`, `api::paged`, etc. as
+/// needed. Each query goes through the client as usual, so any throttling or retry behavior of
+/// the client applies to every request; the concurrency limit bounds the number of requests in
+/// flight at any time.
+pub fn batch<I>(queries: I) -> Batch<I::Item>
+where
+    I: IntoIterator,
+{
+    Batch {
+        queries: queries.into_iter().collect(),
+        concurrency: DEFAULT_BATCH_CONCURRENCY,
+    }
+}
+
+impl<Q> Batch<Q> {
+    /// Set the maximum number of queries to run at the same time.
+    ///
+    /// A limit of `0` is treated as `1`. Defaults to `DEFAULT_BATCH_CONCURRENCY`.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Execute the queries.
+    ///
+    /// Results are returned in the same order as the queries. All queries are executed even if
+    /// some of them fail; the failures are collected into the returned error.
+    pub async fn query_async<T, C>(&self, client: &C) -> Result<Vec<T>, BatchError<C::Error>>
+    where
+        Q: AsyncQuery<T, C>,
+        C: AsyncClient + Sync,
+    {
+        let results: Vec<_> =
+            stream::iter(self.queries.iter().map(|query| query.query_async(client)))
+                .buffered(self.concurrency)
+                .collect()
+                .await;
+
+        let total = results.len();
+        let mut data = Vec::with_capacity(total);
+        let mut errors = Vec::new();
+        for (index, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(value) => data.push(value),
+                Err(err) => errors.push((index, err)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(data)
+        } else {
+            Err(BatchError {
+                errors,
+                total,
+            })
+        }
+    }
+}
+
+/// Errors from the failed queries of a batch.
+#[derive(Debug)]
+pub struct BatchError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    errors: Vec<(usize, ApiError<E>)>,
+    total: usize,
+}
+
+impl<E> BatchError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The errors of the failed queries along with the index of the query within the batch.
+    pub fn errors(&self) -> &[(usize, ApiError<E>)] {
+        &self.errors
+    }
+
+    /// Extract the errors of the failed queries.
+    pub fn into_errors(self) -> Vec<(usize, ApiError<E>)> {
+        self.errors
+    }
+
+    /// The number of queries in the batch.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+impl<E> fmt::Display for BatchError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} of {} queries failed", self.errors.len(), self.total,)?;
+        if let Some((index, err)) = self.errors.first() {
+            write!(f, "; query {}: {}", index, err)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> Error for BatchError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors
+            .first()
+            .map(|(_, err)| err as &(dyn Error + 'static))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    struct Dummy {
+        id: u64,
+    }
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            format!("dummy/{}", self.id).into()
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        value: u64,
+    }
+
+    fn mock_client(fail: &[u64]) -> MockClient {
+        let client = MockClient::unordered();
+        for id in 0..5 {
+            let request = ExpectedRequest::builder()
+                .endpoint(format!("dummy/{}", id))
+                .build()
+                .unwrap();
+            let response = if fail.contains(&id) {
+                MockResponse::json(&json!({"message": format!("failure {}", id)}))
+                    .status(StatusCode::NOT_FOUND)
+            } else {
+                MockResponse::json(&json!({"value": id * 10}))
+            };
+            client.expect(request, response);
+        }
+        client
+    }
+
+    fn queries() -> Vec<Dummy> {
+        (0..5)
+            .map(|id| {
+                Dummy {
+                    id,
+                }
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_batch_in_order() {
+        let client = mock_client(&[]);
+
+        let results: Vec<DummyResult> = api::batch(queries())
+            .concurrency(2)
+            .query_async(&client)
+            .await
+            .unwrap();
+        let values: Vec<_> = results.into_iter().map(|result| result.value).collect();
+        assert_eq!(values, [0, 10, 20, 30, 40]);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn test_batch_zero_concurrency() {
+        let client = mock_client(&[]);
+
+        let results: Vec<DummyResult> = api::batch(queries())
+            .concurrency(0)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 5);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn test_batch_empty() {
+        let client = MockClient::new();
+
+        let results: Vec<DummyResult> = api::batch(Vec::<Dummy>::new())
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_errors() {
+        let client = mock_client(&[1, 3]);
+
+        let res: Result<Vec<DummyResult>, _> = api::batch(queries()).query_async(&client).await;
+        // All queries are still made.
+        client.assert_all_consumed();
+
+        let err = res.unwrap_err();
+        assert_eq!(err.total(), 5);
+        assert_eq!(
+            err.to_string(),
+            "2 of 5 queries failed; query 1: gitlab server error: failure 1",
+        );
+
+        let errors = err.into_errors();
+        assert_eq!(errors.len(), 2);
+        for ((index, err), expected) in errors.into_iter().zip([1, 3]) {
+            assert_eq!(index, expected);
+            if let ApiError::Gitlab {
+                msg,
+            } = err
+            {
+                assert_eq!(msg, format!("failure {}", expected));
+            } else {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+}
@@ -0,0 +1,386 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::hash_map::RandomState;
+use std::env;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
+
+#[cfg(feature = "api-projects")]
+mod form;
+
+#[cfg(feature = "api-projects")]
+pub(crate) use self::form::FormWriter;
+
+/// The default size at which a `SpooledBody` moves its contents to disk.
+pub const DEFAULT_SPOOL_THRESHOLD: usize = 8 * 1024 * 1024;
+
+/// The number of names to try before giving up on creating a spool file.
+const SPOOL_FILE_ATTEMPTS: usize = 16;
+
+static SPOOL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Generate an unpredictable name for a spool file.
+fn spool_file_name() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(process::id());
+    hasher.write_usize(SPOOL_COUNTER.fetch_add(1, Ordering::Relaxed));
+    if let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(now.as_nanos());
+    }
+    format!("gitlab-body-{:016x}", hasher.finish())
+}
+
+/// A temporary file which is removed when dropped.
+struct SpoolFile {
+    file: Option<File>,
+    path: PathBuf,
+}
+
+impl SpoolFile {
+    fn new() -> io::Result<Self> {
+        let dir = env::temp_dir();
+        let mut options = OpenOptions::new();
+        options.read(true).write(true).create_new(true);
+        // Bodies may contain repository contents, so keep them private to the current user.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        for _ in 0..SPOOL_FILE_ATTEMPTS {
+            let path = dir.join(spool_file_name());
+            match options.open(&path) {
+                Ok(file) => {
+                    return Ok(Self {
+                        file: Some(file),
+                        path,
+                    });
+                },
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "failed to find an unused name for a spool file",
+        ))
+    }
+
+    fn file(&mut self) -> &mut File {
+        self.file
+            .as_mut()
+            .expect("spool files are only closed when dropped")
+    }
+}
+
+impl Drop for SpoolFile {
+    fn drop(&mut self) {
+        // Close the file before removing it for platforms which do not allow removing open files.
+        self.file.take();
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+impl Write for SpoolFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file().flush()
+    }
+}
+
+enum Storage {
+    Memory(Vec<u8>),
+    File(BufWriter<SpoolFile>),
+}
+
+/// A request body which is moved to disk once it becomes large.
+///
+/// Endpoints which may have large bodies (e.g., file contents) can write their body into this
+/// rather than assembling it in memory. Data is kept in memory until it reaches a threshold at
+/// which point it is moved into a temporary file. The temporary file is removed once the body
+/// (or its reader) is dropped.
+pub struct SpooledBody {
+    threshold: usize,
+    len: u64,
+    storage: Storage,
+}
+
+impl SpooledBody {
+    /// Create a new body using the default threshold.
+    pub fn new() -> Self {
+        Self::with_threshold(DEFAULT_SPOOL_THRESHOLD)
+    }
+
+    /// Create a new body which moves to disk once it is larger than `threshold` bytes.
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self {
+            threshold,
+            len: 0,
+            storage: Storage::Memory(Vec::new()),
+        }
+    }
+
+    /// The length of the body.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the body is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the body has been moved to disk.
+    pub fn is_spooled(&self) -> bool {
+        matches!(self.storage, Storage::File(_))
+    }
+
+    /// Create a copy of the body.
+    ///
+    /// Bodies which have been moved to disk are copied into a new temporary file rather than into
+    /// memory.
+    pub fn try_clone(&mut self) -> io::Result<Self> {
+        let storage = match &mut self.storage {
+            Storage::Memory(data) => Storage::Memory(data.clone()),
+            Storage::File(writer) => {
+                writer.flush()?;
+                let mut source = File::open(&writer.get_ref().path)?;
+                let mut copy = BufWriter::new(SpoolFile::new()?);
+                io::copy(&mut source, &mut copy)?;
+                Storage::File(copy)
+            },
+        };
+
+        Ok(Self {
+            threshold: self.threshold,
+            len: self.len,
+            storage,
+        })
+    }
+
+    /// Read the entire body into memory.
+    pub fn into_vec(self) -> io::Result<Vec<u8>> {
+        match self.storage {
+            Storage::Memory(data) => Ok(data),
+            Storage::File(_) => {
+                let mut data = Vec::with_capacity(self.len as usize);
+                self.into_reader()?.read_to_end(&mut data)?;
+                Ok(data)
+            },
+        }
+    }
+
+    /// Get a reader for the contents of the body.
+    pub fn into_reader(self) -> io::Result<SpooledBodyReader> {
+        let inner = match self.storage {
+            Storage::Memory(data) => ReaderInner::Memory(io::Cursor::new(data)),
+            Storage::File(writer) => {
+                let mut spool = writer
+                    .into_inner()
+                    .map_err(io::IntoInnerError::into_error)?;
+                spool.file().seek(SeekFrom::Start(0))?;
+                ReaderInner::File(spool)
+            },
+        };
+
+        Ok(SpooledBodyReader {
+            len: self.len,
+            inner,
+        })
+    }
+
+    fn spool(&mut self) -> io::Result<()> {
+        let mut writer = BufWriter::new(SpoolFile::new()?);
+        if let Storage::Memory(data) = &self.storage {
+            writer.write_all(data)?;
+        }
+        self.storage = Storage::File(writer);
+        Ok(())
+    }
+}
+
+impl Default for SpooledBody {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for SpooledBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpooledBody")
+            .field("threshold", &self.threshold)
+            .field("len", &self.len)
+            .field("spooled", &self.is_spooled())
+            .finish()
+    }
+}
+
+impl Write for SpooledBody {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Storage::Memory(data) = &self.storage {
+            if data.len() + buf.len() > self.threshold {
+                self.spool()?;
+            }
+        }
+
+        let written = match &mut self.storage {
+            Storage::Memory(data) => data.write(buf)?,
+            Storage::File(writer) => writer.write(buf)?,
+        };
+        self.len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.storage {
+            Storage::Memory(_) => Ok(()),
+            Storage::File(writer) => writer.flush(),
+        }
+    }
+}
+
+enum ReaderInner {
+    Memory(io::Cursor<Vec<u8>>),
+    File(SpoolFile),
+}
+
+/// A reader for the contents of a `SpooledBody`.
+pub struct SpooledBodyReader {
+    len: u64,
+    inner: ReaderInner,
+}
+
+impl SpooledBodyReader {
+    /// The length of the body.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the body is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Debug for SpooledBodyReader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpooledBodyReader")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl Read for SpooledBodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.inner {
+            ReaderInner::Memory(cursor) => cursor.read(buf),
+            ReaderInner::File(spool) => spool.file().read(buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use crate::api::body::{SpoolFile, SpooledBody};
+
+    #[test]
+    fn spooled_body_small() {
+        let mut body = SpooledBody::with_threshold(16);
+        body.write_all(b"small").unwrap();
+        assert_eq!(body.len(), 5);
+        assert!(!body.is_spooled());
+        assert_eq!(body.into_vec().unwrap(), b"small");
+    }
+
+    #[test]
+    fn spooled_body_empty() {
+        let body = SpooledBody::new();
+        assert!(body.is_empty());
+        assert!(body.into_vec().unwrap().is_empty());
+    }
+
+    #[test]
+    fn spooled_body_large() {
+        let mut body = SpooledBody::with_threshold(16);
+        body.write_all(b"0123456789").unwrap();
+        assert!(!body.is_spooled());
+        body.write_all(b"0123456789").unwrap();
+        assert!(body.is_spooled());
+        body.write_all(b"abcdef").unwrap();
+        assert_eq!(body.len(), 26);
+        assert_eq!(body.into_vec().unwrap(), b"01234567890123456789abcdef");
+    }
+
+    #[test]
+    fn spooled_body_reader() {
+        let mut body = SpooledBody::with_threshold(4);
+        body.write_all(b"spooled content").unwrap();
+        assert!(body.is_spooled());
+
+        let mut reader = body.into_reader().unwrap();
+        assert_eq!(reader.len(), 15);
+        let mut data = String::new();
+        reader.read_to_string(&mut data).unwrap();
+        assert_eq!(data, "spooled content");
+    }
+
+    #[test]
+    fn spooled_body_try_clone() {
+        let mut body = SpooledBody::with_threshold(4);
+        body.write_all(b"spooled").unwrap();
+        assert!(body.is_spooled());
+
+        let copy = body.try_clone().unwrap();
+        assert!(copy.is_spooled());
+        assert_eq!(copy.len(), 7);
+        assert_eq!(copy.into_vec().unwrap(), b"spooled");
+
+        body.write_all(b" content").unwrap();
+        assert_eq!(body.into_vec().unwrap(), b"spooled content");
+    }
+
+    #[test]
+    fn spooled_body_try_clone_memory() {
+        let mut body = SpooledBody::with_threshold(16);
+        body.write_all(b"small").unwrap();
+
+        let copy = body.try_clone().unwrap();
+        assert!(!copy.is_spooled());
+        assert_eq!(copy.into_vec().unwrap(), b"small");
+        assert_eq!(body.into_vec().unwrap(), b"small");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn spool_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let spool = SpoolFile::new().unwrap();
+        let mode = spool.path.metadata().unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn spool_file_names_are_unique() {
+        let first = SpoolFile::new().unwrap();
+        let second = SpoolFile::new().unwrap();
+        assert_ne!(first.path, second.path);
+    }
+}
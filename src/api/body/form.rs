@@ -0,0 +1,139 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use url::form_urlencoded;
+
+use crate::api::ParamValue;
+
+/// A writer which URL encodes data written to it.
+pub(crate) struct UrlEncoder<W> {
+    writer: W,
+}
+
+impl<W> Write for UrlEncoder<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for chunk in form_urlencoded::byte_serialize(buf) {
+            self.writer.write_all(chunk.as_bytes())?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A writer for `application/x-www-form-urlencoded` data.
+///
+/// Unlike `FormParams`, values are encoded directly into the output rather than collected first.
+pub(crate) struct FormWriter<W> {
+    writer: W,
+    empty: bool,
+}
+
+impl<W> FormWriter<W>
+where
+    W: Write,
+{
+    /// Create a new form writer.
+    pub(crate) fn new(writer: W) -> Self {
+        Self {
+            writer,
+            empty: true,
+        }
+    }
+
+    /// Push a single parameter.
+    pub(crate) fn push<'b, 'k, K, V>(&mut self, key: K, value: V) -> io::Result<&mut Self>
+    where
+        K: Into<Cow<'k, str>>,
+        V: ParamValue<'b>,
+    {
+        self.push_with(key, |writer| writer.write_all(value.as_value().as_bytes()))
+    }
+
+    /// Push a single parameter.
+    pub(crate) fn push_opt<'b, 'k, K, V>(
+        &mut self,
+        key: K,
+        value: Option<V>,
+    ) -> io::Result<&mut Self>
+    where
+        K: Into<Cow<'k, str>>,
+        V: ParamValue<'b>,
+    {
+        if let Some(value) = value {
+            self.push(key, value)
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Push a parameter whose value is written by a function.
+    ///
+    /// Data written by the function is URL encoded.
+    pub(crate) fn push_with<'k, K, F>(&mut self, key: K, f: F) -> io::Result<&mut Self>
+    where
+        K: Into<Cow<'k, str>>,
+        F: FnOnce(&mut UrlEncoder<&mut W>) -> io::Result<()>,
+    {
+        if !self.empty {
+            self.writer.write_all(b"&")?;
+        }
+        self.empty = false;
+
+        let mut encoder = UrlEncoder {
+            writer: &mut self.writer,
+        };
+        encoder.write_all(key.into().as_bytes())?;
+        encoder.writer.write_all(b"=")?;
+        f(&mut encoder)?;
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::api::body::FormWriter;
+    use crate::api::FormParams;
+
+    #[test]
+    fn form_writer_matches_form_params() {
+        let mut params = FormParams::default();
+        params
+            .push("key", "value")
+            .push("actions[][content]", "a b&c=d/+")
+            .push_opt("missing", None::<&str>)
+            .push_opt("flag", Some(true));
+        let (_, expected) = params.into_body().unwrap().unwrap();
+
+        let mut data = Vec::new();
+        let mut writer = FormWriter::new(&mut data);
+        writer
+            .push("key", "value")
+            .unwrap()
+            .push_with("actions[][content]", |writer| {
+                writer.write_all(b"a b&c")?;
+                writer.write_all(b"=d/+")
+            })
+            .unwrap()
+            .push_opt("missing", None::<&str>)
+            .unwrap()
+            .push_opt("flag", Some(true))
+            .unwrap();
+
+        assert_eq!(data, expected);
+    }
+}
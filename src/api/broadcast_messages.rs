@@ -0,0 +1,39 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Broadcast message API endpoints.
+//!
+//! These endpoints are used for managing messages which are shown to users of
+//! the instance. Modifying broadcast messages requires an administrator token.
+
+mod broadcast_message;
+mod broadcast_messages;
+mod create;
+mod delete;
+mod edit;
+
+pub use self::broadcast_message::BroadcastMessage;
+pub use self::broadcast_message::BroadcastMessageBuilder;
+pub use self::broadcast_message::BroadcastMessageBuilderError;
+
+pub use self::broadcast_messages::BroadcastMessages;
+pub use self::broadcast_messages::BroadcastMessagesBuilder;
+pub use self::broadcast_messages::BroadcastMessagesBuilderError;
+
+pub use self::create::BroadcastMessageType;
+pub use self::create::CreateBroadcastMessage;
+pub use self::create::CreateBroadcastMessageBuilder;
+pub use self::create::CreateBroadcastMessageBuilderError;
+
+pub use self::delete::DeleteBroadcastMessage;
+pub use self::delete::DeleteBroadcastMessageBuilder;
+pub use self::delete::DeleteBroadcastMessageBuilderError;
+
+pub use self::edit::EditBroadcastMessage;
+pub use self::edit::EditBroadcastMessageBuilder;
+pub use self::edit::EditBroadcastMessageBuilderError;
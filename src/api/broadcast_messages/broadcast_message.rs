@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for a broadcast message.
+#[derive(Debug, Builder, Clone, Copy)]
+pub struct BroadcastMessage {
+    /// The ID of the broadcast message.
+    id: u64,
+}
+
+impl BroadcastMessage {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BroadcastMessageBuilder {
+        BroadcastMessageBuilder::default()
+    }
+}
+
+impl Endpoint for BroadcastMessage {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("broadcast_messages/{}", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::broadcast_messages::{BroadcastMessage, BroadcastMessageBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn id_is_necessary() {
+        let err = BroadcastMessage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BroadcastMessageBuilderError, "id");
+    }
+
+    #[test]
+    fn id_is_sufficient() {
+        BroadcastMessage::builder().id(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("broadcast_messages/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BroadcastMessage::builder().id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for broadcast messages.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct BroadcastMessages {}
+
+impl BroadcastMessages {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BroadcastMessagesBuilder {
+        BroadcastMessagesBuilder::default()
+    }
+}
+
+impl Endpoint for BroadcastMessages {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "broadcast_messages".into()
+    }
+}
+
+impl Pageable for BroadcastMessages {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::broadcast_messages::BroadcastMessages;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        BroadcastMessages::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("broadcast_messages")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BroadcastMessages::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,358 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::AccessLevel;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// How a broadcast message is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BroadcastMessageType {
+    /// A banner shown at the top of pages.
+    Banner,
+    /// A notification shown at the bottom of pages.
+    Notification,
+}
+
+impl BroadcastMessageType {
+    /// The broadcast type as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            BroadcastMessageType::Banner => "banner",
+            BroadcastMessageType::Notification => "notification",
+        }
+    }
+}
+
+impl ParamValue<'static> for BroadcastMessageType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Create a new broadcast message.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateBroadcastMessage<'a> {
+    /// The message to display.
+    #[builder(setter(into))]
+    message: Cow<'a, str>,
+
+    /// When to start displaying the message.
+    #[builder(default)]
+    starts_at: Option<DateTime<Utc>>,
+    /// When to stop displaying the message.
+    #[builder(default)]
+    ends_at: Option<DateTime<Utc>>,
+    /// The background color of the message (as a hex code).
+    #[builder(setter(into), default)]
+    color: Option<Cow<'a, str>>,
+    /// The foreground color of the message (as a hex code).
+    #[builder(setter(into), default)]
+    font: Option<Cow<'a, str>>,
+    /// Only show the message to users with these access levels.
+    #[builder(setter(name = "_target_access_levels"), default, private)]
+    target_access_levels: BTreeSet<AccessLevel>,
+    /// Only show the message on pages matching this path (wildcards are supported).
+    #[builder(setter(into), default)]
+    target_path: Option<Cow<'a, str>>,
+    /// How the message is displayed.
+    #[builder(default)]
+    broadcast_type: Option<BroadcastMessageType>,
+    /// Whether users may dismiss the message.
+    #[builder(default)]
+    dismissable: Option<bool>,
+}
+
+impl<'a> CreateBroadcastMessage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateBroadcastMessageBuilder<'a> {
+        CreateBroadcastMessageBuilder::default()
+    }
+}
+
+impl<'a> CreateBroadcastMessageBuilder<'a> {
+    /// Show the message to users with an access level.
+    pub fn target_access_level(&mut self, access_level: AccessLevel) -> &mut Self {
+        self.target_access_levels
+            .get_or_insert_with(BTreeSet::new)
+            .insert(access_level);
+        self
+    }
+
+    /// Show the message to users with any of a set of access levels.
+    pub fn target_access_levels<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = AccessLevel>,
+    {
+        self.target_access_levels
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateBroadcastMessage<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "broadcast_messages".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("message", &self.message)
+            .push_opt("starts_at", self.starts_at)
+            .push_opt("ends_at", self.ends_at)
+            .push_opt("color", self.color.as_ref())
+            .push_opt("font", self.font.as_ref())
+            .extend(self.target_access_levels.iter().map(|level| {
+                (
+                    ParamName::new("target_access_levels").array(),
+                    level.as_u64(),
+                )
+            }))
+            .push_opt("target_path", self.target_path.as_ref())
+            .push_opt("broadcast_type", self.broadcast_type)
+            .push_opt("dismissable", self.dismissable);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::broadcast_messages::{
+        BroadcastMessageType, CreateBroadcastMessage, CreateBroadcastMessageBuilderError,
+    };
+    use crate::api::common::AccessLevel;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn broadcast_message_type_as_str() {
+        let items = &[
+            (BroadcastMessageType::Banner, "banner"),
+            (BroadcastMessageType::Notification, "notification"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn message_is_necessary() {
+        let err = CreateBroadcastMessage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBroadcastMessageBuilderError, "message");
+    }
+
+    #[test]
+    fn message_is_sufficient() {
+        CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("message=maintenance")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_starts_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "message=maintenance",
+                "&starts_at=2024-01-01T00%3A00%3A00Z",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .starts_at(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ends_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "message=maintenance",
+                "&ends_at=2024-01-01T00%3A00%3A00Z",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .ends_at(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_color() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("message=maintenance", "&color=%23E75E40"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .color("#E75E40")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_font() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("message=maintenance", "&font=%23FFFFFF"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .font("#FFFFFF")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_target_access_levels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "message=maintenance",
+                "&target_access_levels%5B%5D=30",
+                "&target_access_levels%5B%5D=40",
+                "&target_access_levels%5B%5D=50",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .target_access_level(AccessLevel::Maintainer)
+            .target_access_levels([AccessLevel::Owner, AccessLevel::Developer].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_target_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("message=maintenance", "&target_path=%2Fadmin%2F*"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .target_path("/admin/*")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_broadcast_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "message=maintenance",
+                "&broadcast_type=notification"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .broadcast_type(BroadcastMessageType::Notification)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dismissable() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("broadcast_messages")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("message=maintenance", "&dismissable=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBroadcastMessage::builder()
+            .message("maintenance")
+            .dismissable(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete a broadcast message.
+#[derive(Debug, Builder, Clone, Copy)]
+pub struct DeleteBroadcastMessage {
+    /// The ID of the broadcast message to delete.
+    id: u64,
+}
+
+impl DeleteBroadcastMessage {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteBroadcastMessageBuilder {
+        DeleteBroadcastMessageBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteBroadcastMessage {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("broadcast_messages/{}", self.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::broadcast_messages::{
+        DeleteBroadcastMessage, DeleteBroadcastMessageBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn id_is_necessary() {
+        let err = DeleteBroadcastMessage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBroadcastMessageBuilderError, "id");
+    }
+
+    #[test]
+    fn id_is_sufficient() {
+        DeleteBroadcastMessage::builder().id(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("broadcast_messages/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteBroadcastMessage::builder().id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,507 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conditional request client wrapper
+//!
+//! This module provides a `Client` implementation which can wrap other `ApiClient` instances in
+//! order to cache responses using their `ETag` headers. Subsequent `GET` requests for the same
+//! URL send an `If-None-Match` header and, when GitLab answers with `304 Not Modified`, the
+//! cached response is used instead. Responses which are not modified do not count against the
+//! API rate limits of the instance, so this is useful for tools which poll endpoints.
+
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::header::{self, HeaderMap, HeaderValue};
+use http::{Method, Response, StatusCode, Version};
+use thiserror::Error;
+use url::Url;
+
+use crate::api;
+use crate::api::instance::Capabilities;
+
+/// How to handle responses which have not been modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Mode {
+    /// Return the cached response as if it were returned by GitLab.
+    Transparent,
+    /// Return an [`Error::NotModified`] error.
+    ///
+    /// This is useful when the caller only needs to act when data changes.
+    NotModified,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Mode {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        Mode::Transparent
+    }
+}
+
+/// An error from a caching client.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error<E>
+where
+    E: StdError + Send + Sync + 'static,
+{
+    /// The resource has not been modified since it was last fetched.
+    #[error("not modified")]
+    NotModified {},
+    /// An error occurred within the client.
+    #[error("{}", source)]
+    Inner {
+        /// The source of the error.
+        #[from]
+        source: E,
+    },
+}
+
+impl<E> Error<E>
+where
+    E: StdError + Send + Sync + 'static,
+{
+    fn not_modified() -> Self {
+        Self::NotModified {}
+    }
+
+    fn inner(source: E) -> Self {
+        Self::Inner {
+            source,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CachedResponse {
+    etag: HeaderValue,
+    status: StatusCode,
+    version: Version,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl CachedResponse {
+    fn new(rsp: &Response<Bytes>) -> Option<Self> {
+        if !rsp.status().is_success() {
+            return None;
+        }
+
+        rsp.headers().get(header::ETAG).map(|etag| {
+            Self {
+                etag: etag.clone(),
+                status: rsp.status(),
+                version: rsp.version(),
+                headers: rsp.headers().clone(),
+                body: rsp.body().clone(),
+            }
+        })
+    }
+
+    fn response(&self) -> Response<Bytes> {
+        let mut rsp = Response::new(self.body.clone());
+        *rsp.status_mut() = self.status;
+        *rsp.version_mut() = self.version;
+        *rsp.headers_mut() = self.headers.clone();
+        rsp
+    }
+}
+
+/// A wrapper around a client to cache responses using conditional requests.
+///
+/// Only `GET` requests are cached and they are keyed by their full URL (including query
+/// parameters). Responses without an `ETag` header or with a non-success status are not cached.
+/// The cache is not bounded; use [`Client::clear`] to drop entries for long-running processes.
+pub struct Client<C> {
+    client: C,
+    mode: Mode,
+    cache: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl<C> Client<C> {
+    /// Create a client which caches responses and transparently returns them when unmodified.
+    pub fn new(client: C) -> Self {
+        Self::with_mode(client, Mode::default())
+    }
+
+    /// Create a client which caches responses with the given mode for unmodified responses.
+    pub fn with_mode(client: C, mode: Mode) -> Self {
+        Self {
+            client,
+            mode,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The number of cached responses.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drop all cached responses.
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+
+    fn lock(&self) -> MutexGuard<HashMap<String, CachedResponse>> {
+        // A panic while holding the lock cannot leave the map in an inconsistent state.
+        self.cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn cache_key(request: &http::request::Builder) -> Option<String> {
+        if request.method_ref().unwrap_or(&Method::GET) != Method::GET {
+            return None;
+        }
+
+        request.uri_ref().map(ToString::to_string)
+    }
+
+    fn prepare(&self, request: http::request::Builder, key: &str) -> http::request::Builder {
+        if let Some(cached) = self.lock().get(key) {
+            request.header(header::IF_NONE_MATCH, cached.etag.clone())
+        } else {
+            request
+        }
+    }
+
+    fn handle<E>(
+        &self,
+        key: &str,
+        rsp: Result<Response<Bytes>, api::ApiError<E>>,
+    ) -> Result<Response<Bytes>, api::ApiError<Error<E>>>
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        let rsp = rsp.map_err(|e| e.map_client(Error::inner))?;
+
+        let mut cache = self.lock();
+        if rsp.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cache.get(key) {
+                return match self.mode {
+                    Mode::Transparent => Ok(cached.response()),
+                    Mode::NotModified => Err(api::ApiError::client(Error::not_modified())),
+                };
+            }
+        } else if let Some(cached) = CachedResponse::new(&rsp) {
+            cache.insert(key.into(), cached);
+        } else {
+            cache.remove(key);
+        }
+
+        Ok(rsp)
+    }
+}
+
+impl<C> api::RestClient for Client<C>
+where
+    C: api::RestClient,
+{
+    type Error = Error<C::Error>;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        self.client
+            .rest_endpoint(endpoint)
+            .map_err(|e| e.map_client(Error::inner))
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        self.client
+            .instance_endpoint(endpoint)
+            .map_err(|e| e.map_client(Error::inner))
+    }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        self.client.capabilities()
+    }
+}
+
+impl<C> api::Client for Client<C>
+where
+    C: api::Client,
+{
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        if let Some(key) = Self::cache_key(&request) {
+            let request = self.prepare(request, &key);
+            let rsp = self.client.rest(request, body);
+            self.handle(&key, rsp)
+        } else {
+            self.client
+                .rest(request, body)
+                .map_err(|e| e.map_client(Error::inner))
+        }
+    }
+
+    fn rest_spooled(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        self.client
+            .rest_spooled(request, body)
+            .map_err(|e| e.map_client(Error::inner))
+    }
+}
+
+#[async_trait]
+impl<C> api::AsyncClient for Client<C>
+where
+    C: api::AsyncClient + Sync + Send,
+{
+    async fn rest_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        if let Some(key) = Self::cache_key(&request) {
+            let request = self.prepare(request, &key);
+            let rsp = self.client.rest_async(request, body).await;
+            self.handle(&key, rsp)
+        } else {
+            self.client
+                .rest_async(request, body)
+                .await
+                .map_err(|e| e.map_client(Error::inner))
+        }
+    }
+
+    async fn rest_spooled_async(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        self.client
+            .rest_spooled_async(request, body)
+            .await
+            .map_err(|e| e.map_client(Error::inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{header, HeaderValue, StatusCode};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, cache, ApiError, AsyncQuery, Query};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    struct Dummy {
+        method: Method,
+    }
+
+    impl Dummy {
+        fn get() -> Self {
+            Self {
+                method: Method::GET,
+            }
+        }
+    }
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            self.method.clone()
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    fn expected(method: Method) -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .method(method)
+            .endpoint("dummy")
+            .build()
+            .unwrap()
+    }
+
+    fn fresh(value: u8, etag: &'static str) -> MockResponse {
+        MockResponse::json(&json!({"value": value}))
+            .header(header::ETAG, HeaderValue::from_static(etag))
+    }
+
+    fn not_modified() -> MockResponse {
+        MockResponse::empty().status(StatusCode::NOT_MODIFIED)
+    }
+
+    fn if_none_match(client: &MockClient) -> Vec<Option<String>> {
+        client
+            .requests()
+            .into_iter()
+            .map(|req| {
+                req.headers
+                    .get(header::IF_NONE_MATCH)
+                    .map(|value| value.to_str().unwrap().into())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cache_client_transparent() {
+        let mock = MockClient::new();
+        mock.expect(expected(Method::GET), fresh(1, "\"a\""))
+            .expect(expected(Method::GET), not_modified())
+            .expect(expected(Method::GET), fresh(2, "\"b\""));
+        let client = cache::Client::new(mock);
+
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        assert_eq!(client.len(), 1);
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 2);
+
+        client.client.assert_all_consumed();
+        assert_eq!(
+            if_none_match(&client.client),
+            [None, Some("\"a\"".into()), Some("\"a\"".into())],
+        );
+    }
+
+    #[test]
+    fn cache_client_not_modified() {
+        let mock = MockClient::new();
+        mock.expect(expected(Method::GET), fresh(1, "\"a\""))
+            .expect(expected(Method::GET), not_modified());
+        let client = cache::Client::with_mode(mock, cache::Mode::NotModified);
+
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        let err = api::ignore(Dummy::get()).query(&client).unwrap_err();
+        if let ApiError::Client {
+            source: cache::Error::NotModified {},
+        } = err
+        {
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        client.client.assert_all_consumed();
+    }
+
+    #[test]
+    fn cache_client_no_etag() {
+        let mock = MockClient::new();
+        mock.expect(
+            expected(Method::GET),
+            MockResponse::json(&json!({"value": 1})),
+        )
+        .expect(
+            expected(Method::GET),
+            MockResponse::json(&json!({"value": 2})),
+        );
+        let client = cache::Client::new(mock);
+
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        assert!(client.is_empty());
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 2);
+
+        client.client.assert_all_consumed();
+        assert_eq!(if_none_match(&client.client), [None, None]);
+    }
+
+    #[test]
+    fn cache_client_ignores_non_get() {
+        let mock = MockClient::new();
+        mock.expect(expected(Method::POST), fresh(1, "\"a\""))
+            .expect(expected(Method::POST), fresh(2, "\"a\""));
+        let client = cache::Client::new(mock);
+
+        let endpoint = Dummy {
+            method: Method::POST,
+        };
+        let res: DummyResult = endpoint.query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        let res: DummyResult = endpoint.query(&client).unwrap();
+        assert_eq!(res.value, 2);
+        assert!(client.is_empty());
+
+        client.client.assert_all_consumed();
+        assert_eq!(if_none_match(&client.client), [None, None]);
+    }
+
+    #[test]
+    fn cache_client_error_evicts() {
+        let mock = MockClient::new();
+        mock.expect(expected(Method::GET), fresh(1, "\"a\""))
+            .expect(
+                expected(Method::GET),
+                MockResponse::json(&json!({"message": "gone"})).status(StatusCode::NOT_FOUND),
+            )
+            .expect(expected(Method::GET), fresh(2, "\"b\""));
+        let client = cache::Client::new(mock);
+
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        let res: Result<DummyResult, _> = Dummy::get().query(&client);
+        res.unwrap_err();
+        assert!(client.is_empty());
+        let res: DummyResult = Dummy::get().query(&client).unwrap();
+        assert_eq!(res.value, 2);
+
+        client.client.assert_all_consumed();
+        assert_eq!(
+            if_none_match(&client.client),
+            [None, Some("\"a\"".into()), None],
+        );
+    }
+
+    #[test]
+    fn cache_client_clear() {
+        let mock = MockClient::new();
+        mock.expect(expected(Method::GET), fresh(1, "\"a\""))
+            .expect(expected(Method::GET), fresh(1, "\"a\""));
+        let client = cache::Client::new(mock);
+
+        let _: DummyResult = Dummy::get().query(&client).unwrap();
+        client.clear();
+        let _: DummyResult = Dummy::get().query(&client).unwrap();
+
+        client.client.assert_all_consumed();
+        assert_eq!(if_none_match(&client.client), [None, None]);
+    }
+
+    #[tokio::test]
+    async fn cache_client_transparent_async() {
+        let mock = MockClient::new();
+        mock.expect(expected(Method::GET), fresh(1, "\"a\""))
+            .expect(expected(Method::GET), not_modified());
+        let client = cache::Client::new(mock);
+
+        let res: DummyResult = Dummy::get().query_async(&client).await.unwrap();
+        assert_eq!(res.value, 1);
+        let res: DummyResult = Dummy::get().query_async(&client).await.unwrap();
+        assert_eq!(res.value, 1);
+
+        client.client.assert_all_consumed();
+        assert_eq!(if_none_match(&client.client), [None, Some("\"a\"".into())],);
+    }
+}
@@ -0,0 +1,17 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Instance CI lint API endpoints.
+//!
+//! These endpoints are used for validating CI/CD configuration outside of any project. Note that
+//! GitLab 16.0 removed this endpoint in favor of the project-level endpoints in
+//! `projects::ci_lint`.
+
+mod lint;
+
+pub use self::lint::Lint;
+pub use self::lint::LintBuilder;
+pub use self::lint::LintBuilderError;
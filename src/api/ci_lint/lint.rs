@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Validate CI/CD configuration content.
+///
+/// Removed in GitLab 16.0; use `projects::ci_lint::Lint` instead.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Lint<'a> {
+    /// The CI/CD configuration content.
+    #[builder(setter(into))]
+    content: Cow<'a, str>,
+
+    /// Include the configuration after expanding `include` directives in the result.
+    #[builder(default)]
+    include_merged_yaml: Option<bool>,
+    /// Include the list of jobs which would exist in the result.
+    #[builder(default)]
+    include_jobs: Option<bool>,
+}
+
+impl<'a> Lint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LintBuilder<'a> {
+        LintBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Lint<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "ci/lint".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("content", &self.content)
+            .push_opt("include_merged_yaml", self.include_merged_yaml)
+            .push_opt("include_jobs", self.include_jobs);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::ci_lint::{Lint, LintBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn content_is_needed() {
+        let err = Lint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, LintBuilderError, "content");
+    }
+
+    #[test]
+    fn content_is_sufficient() {
+        Lint::builder().content("job: {}").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("content=job%3A+%7B%7D")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder().content("job: {}").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_merged_yaml() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "content=job%3A+%7B%7D",
+                "&include_merged_yaml=true"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder()
+            .content("job: {}")
+            .include_merged_yaml(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_jobs() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&include_jobs=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder()
+            .content("job: {}")
+            .include_jobs(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -12,7 +12,8 @@ use http::request::Builder as RequestBuilder;
 use http::Response;
 use url::Url;
 
-use crate::api::{ApiError, UrlBase};
+use crate::api::instance::Capabilities;
+use crate::api::{ApiError, BodyError, SpooledBody, UrlBase};
 
 /// A trait representing a client which can communicate with a GitLab instance via REST.
 pub trait RestClient {
@@ -29,6 +30,15 @@ pub trait RestClient {
         let _ = endpoint;
         Err(ApiError::unsupported_url_base(UrlBase::Instance))
     }
+
+    /// The capabilities of the instance the client communicates with.
+    ///
+    /// Endpoints which behave differently across GitLab versions use this to decide how to make
+    /// requests. Clients which do not track capabilities return `None` and are assumed to support
+    /// every feature.
+    fn capabilities(&self) -> Option<&Capabilities> {
+        None
+    }
 }
 
 /// A trait representing a client which can communicate with a GitLab instance.
@@ -39,6 +49,19 @@ pub trait Client: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query with a spooled body.
+    ///
+    /// Clients which can stream request bodies should implement this to avoid holding the body in
+    /// memory. The default implementation reads the body into memory and uses `rest`.
+    fn rest_spooled(
+        &self,
+        request: RequestBuilder,
+        body: SpooledBody,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let body = body.into_vec().map_err(BodyError::from)?;
+        self.rest(request, body)
+    }
 }
 
 /// A trait representing an asynchronous client which can communicate with a GitLab instance.
@@ -50,4 +73,17 @@ pub trait AsyncClient: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query with a spooled body asynchronously.
+    ///
+    /// Clients which can stream request bodies should implement this to avoid holding the body in
+    /// memory. The default implementation reads the body into memory and uses `rest_async`.
+    async fn rest_spooled_async(
+        &self,
+        request: RequestBuilder,
+        body: SpooledBody,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let body = body.into_vec().map_err(BodyError::from)?;
+        self.rest_async(request, body).await
+    }
 }
@@ -13,6 +13,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::iter;
 use std::ops;
+use std::time::Duration;
 
 use itertools::Itertools;
 use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
@@ -220,6 +221,98 @@ impl<'a> ParamValue<'a> for &'a NameOrId<'a> {
     }
 }
 
+/// A value which may be used as a single segment of an endpoint's path.
+///
+/// Segments are percent-encoded so that values such as project paths cannot add segments to the
+/// path. Endpoints check their path parameters using this trait when they are built.
+pub trait PathParam {
+    /// The percent-encoded path segment for the value.
+    fn path_segment(&self) -> Cow<str>;
+
+    /// Check that the value is valid as a path segment.
+    ///
+    /// Returns a description of the problem if the value is invalid.
+    fn validate_path_segment(&self) -> Result<(), &'static str>;
+}
+
+impl PathParam for u64 {
+    fn path_segment(&self) -> Cow<str> {
+        self.to_string().into()
+    }
+
+    fn validate_path_segment(&self) -> Result<(), &'static str> {
+        if *self == 0 {
+            Err("IDs must not be zero")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl PathParam for str {
+    fn path_segment(&self) -> Cow<str> {
+        utf8_percent_encode(self, PATH_SEGMENT_ENCODE_SET).into()
+    }
+
+    fn validate_path_segment(&self) -> Result<(), &'static str> {
+        if self.is_empty() {
+            Err("names must not be empty")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> PathParam for Cow<'a, str> {
+    fn path_segment(&self) -> Cow<str> {
+        self.as_ref().path_segment()
+    }
+
+    fn validate_path_segment(&self) -> Result<(), &'static str> {
+        self.as_ref().validate_path_segment()
+    }
+}
+
+impl<'a> PathParam for NameOrId<'a> {
+    fn path_segment(&self) -> Cow<str> {
+        match self {
+            NameOrId::Name(name) => name.path_segment(),
+            NameOrId::Id(id) => id.path_segment(),
+        }
+    }
+
+    fn validate_path_segment(&self) -> Result<(), &'static str> {
+        match self {
+            NameOrId::Name(name) => name.validate_path_segment(),
+            NameOrId::Id(id) => id.validate_path_segment(),
+        }
+    }
+}
+
+/// Validate a path parameter of an endpoint from its builder.
+///
+/// Unset parameters are ignored so that the builder can report them as missing instead.
+#[cfg(any(
+    feature = "api-groups",
+    feature = "api-issues",
+    feature = "api-notification-settings",
+    feature = "api-projects",
+    feature = "api-search",
+    feature = "api-users",
+))]
+pub(crate) fn validate_path_param<P>(name: &str, param: Option<&P>) -> Result<(), String>
+where
+    P: PathParam + ?Sized,
+{
+    if let Some(param) = param {
+        param
+            .validate_path_segment()
+            .map_err(|reason| format!("invalid `{}`: {}", name, reason))?;
+    }
+
+    Ok(())
+}
+
 /// Visibility levels of projects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -286,6 +379,56 @@ impl ParamValue<'static> for YesNo {
     }
 }
 
+/// A boolean setting which may also be reset.
+///
+/// Endpoints which edit settings generally use `Option<bool>` where `None` leaves the setting
+/// unchanged. Some settings are nullable on the GitLab side (e.g., to fall back to an inherited
+/// or instance-wide default). This type allows such a setting to be explicitly cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriState {
+    /// Set the value to `true`.
+    True,
+    /// Set the value to `false`.
+    False,
+    /// Clear the value.
+    Unset,
+}
+
+impl TriState {
+    /// The string representation of the state.
+    ///
+    /// An unset value is sent as an empty string which GitLab interprets as `null`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TriState::True => "true",
+            TriState::False => "false",
+            TriState::Unset => "",
+        }
+    }
+}
+
+impl From<bool> for TriState {
+    fn from(b: bool) -> Self {
+        if b {
+            TriState::True
+        } else {
+            TriState::False
+        }
+    }
+}
+
+impl From<Option<bool>> for TriState {
+    fn from(b: Option<bool>) -> Self {
+        b.map_or(TriState::Unset, Into::into)
+    }
+}
+
+impl ParamValue<'static> for TriState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
 /// Access levels for protected branches and tags.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[non_exhaustive]
@@ -317,6 +460,16 @@ impl ProtectedAccessLevel {
             ProtectedAccessLevel::NoAccess => "0",
         }
     }
+
+    /// The integer representation of the access level.
+    pub fn as_u64(self) -> u64 {
+        match self {
+            ProtectedAccessLevel::Developer => 30,
+            ProtectedAccessLevel::Maintainer => 40,
+            ProtectedAccessLevel::Admin => 60,
+            ProtectedAccessLevel::NoAccess => 0,
+        }
+    }
 }
 
 impl ParamValue<'static> for ProtectedAccessLevel {
@@ -446,15 +599,98 @@ where
     }
 }
 
+/// A duration in GitLab's human-readable format (e.g., `3h30m`).
+///
+/// Durations are expressed using hours, minutes, and seconds because GitLab converts larger units
+/// (such as days) using the working hours configured for the instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanDuration {
+    seconds: u64,
+    negative: bool,
+}
+
+impl HumanDuration {
+    /// A duration of a number of seconds.
+    pub fn from_secs(seconds: u64) -> Self {
+        Self {
+            seconds,
+            negative: false,
+        }
+    }
+
+    /// A duration of a number of minutes.
+    pub fn from_mins(minutes: u64) -> Self {
+        Self::from_secs(minutes * 60)
+    }
+
+    /// A duration of a number of hours.
+    pub fn from_hours(hours: u64) -> Self {
+        Self::from_secs(hours * 60 * 60)
+    }
+
+    /// Negate the duration.
+    ///
+    /// Negative durations may be used to subtract from the time spent on an item.
+    pub fn negate(self) -> Self {
+        Self {
+            seconds: self.seconds,
+            negative: !self.negative && self.seconds != 0,
+        }
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    /// Convert from a duration, truncating to whole seconds.
+    fn from(duration: Duration) -> Self {
+        Self::from_secs(duration.as_secs())
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.seconds == 0 {
+            return write!(f, "0s");
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        let hours = self.seconds / 3600;
+        let minutes = (self.seconds % 3600) / 60;
+        let seconds = self.seconds % 60;
+
+        if hours != 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}m", minutes)?;
+        }
+        if seconds != 0 {
+            write!(f, "{}s", seconds)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ParamValue<'static> for HumanDuration {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.to_string().into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
     use std::cmp;
     use std::iter;
+    use std::time::Duration;
 
     use crate::api::common::{
-        AccessLevel, CommaSeparatedList, EnableState, NameOrId, ProtectedAccessLevel,
-        ProtectedAccessLevelWithAccess, SortOrder, VisibilityLevel, YesNo,
+        AccessLevel, CommaSeparatedList, EnableState, HumanDuration, NameOrId, PathParam,
+        ProtectedAccessLevel, ProtectedAccessLevelWithAccess, SortOrder, TriState, VisibilityLevel,
+        YesNo,
     };
     use crate::api::params::ParamValue;
 
@@ -570,6 +806,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn name_or_id_path_segment() {
+        let items: &[(NameOrId, _)] = &[
+            ("user".into(), "user"),
+            ("special/name".into(), "special%2Fname"),
+            ("special/name?string".into(), "special%2Fname%3Fstring"),
+            (1.into(), "1"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.path_segment(), *s);
+            assert_eq!(i.validate_path_segment(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn path_param_validate() {
+        let name: NameOrId = "".into();
+        assert_eq!(name.validate_path_segment(), Err("names must not be empty"),);
+        let id: NameOrId = 0.into();
+        assert_eq!(id.validate_path_segment(), Err("IDs must not be zero"));
+        assert_eq!(0.validate_path_segment(), Err("IDs must not be zero"));
+        assert_eq!("".validate_path_segment(), Err("names must not be empty"));
+        assert_eq!(
+            Cow::Borrowed("").validate_path_segment(),
+            Err("names must not be empty"),
+        );
+    }
+
+    #[cfg(any(
+        feature = "api-groups",
+        feature = "api-issues",
+        feature = "api-notification-settings",
+        feature = "api-projects",
+        feature = "api-search",
+        feature = "api-users",
+    ))]
+    #[test]
+    fn validate_path_param() {
+        use crate::api::common::validate_path_param;
+
+        assert_eq!(validate_path_param::<u64>("id", None), Ok(()));
+        assert_eq!(validate_path_param("id", Some(&1)), Ok(()));
+        assert_eq!(
+            validate_path_param("id", Some(&0)),
+            Err("invalid `id`: IDs must not be zero".into()),
+        );
+    }
+
     #[test]
     fn visibility_level_as_str() {
         let items = &[
@@ -601,6 +886,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tri_state_as_str() {
+        let items = &[
+            (TriState::True, "true"),
+            (TriState::False, "false"),
+            (TriState::Unset, ""),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn tri_state_from_bool() {
+        let items = &[(TriState::True, true), (TriState::False, false)];
+
+        for (i, s) in items {
+            assert_eq!(*i, (*s).into());
+        }
+    }
+
+    #[test]
+    fn tri_state_from_option_bool() {
+        let items = &[
+            (TriState::True, Some(true)),
+            (TriState::False, Some(false)),
+            (TriState::Unset, None),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(*i, (*s).into());
+        }
+    }
+
     #[test]
     fn protected_access_level_default() {
         assert_eq!(
@@ -666,6 +986,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn protected_access_level_as_u64() {
+        let items = &[
+            (ProtectedAccessLevel::Developer, 30),
+            (ProtectedAccessLevel::Maintainer, 40),
+            (ProtectedAccessLevel::Admin, 60),
+            (ProtectedAccessLevel::NoAccess, 0),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_u64(), *s);
+        }
+    }
+
     #[test]
     fn protected_access_level_with_access_default() {
         assert_eq!(
@@ -766,4 +1100,26 @@ mod tests {
             ["one".into(), "two".into()].iter().cloned().collect();
         assert_eq!(csl_str_two.as_value(), "one,two");
     }
+
+    #[test]
+    fn human_duration_as_str() {
+        let items = &[
+            (HumanDuration::from_secs(0), "0s"),
+            (HumanDuration::from_secs(0).negate(), "0s"),
+            (HumanDuration::from_secs(45), "45s"),
+            (HumanDuration::from_mins(30), "30m"),
+            (HumanDuration::from_hours(3), "3h"),
+            (HumanDuration::from_mins(210), "3h30m"),
+            (HumanDuration::from_secs(3 * 3600 + 5), "3h5s"),
+            (HumanDuration::from_hours(27), "27h"),
+            (HumanDuration::from_mins(90).negate(), "-1h30m"),
+            (HumanDuration::from_mins(90).negate().negate(), "1h30m"),
+            (Duration::from_millis(61_500).into(), "1m1s"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.to_string(), *s);
+            assert_eq!(i.as_value(), *s);
+        }
+    }
 }
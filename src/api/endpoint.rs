@@ -7,12 +7,13 @@
 use std::borrow::Cow;
 
 use async_trait::async_trait;
-use http::{self, header, Method, Request};
+use http::{self, Method, Request};
 use reqwest::Url;
 use serde::de::DeserializeOwned;
 
 use crate::api::{
     query, ApiError, AsyncClient, AsyncQuery, BodyError, Client, Query, QueryParams, RestClient,
+    SpooledBody,
 };
 
 /// URL bases for endpoints.
@@ -61,6 +62,16 @@ pub trait Endpoint {
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         Ok(None)
     }
+
+    /// The body for the endpoint, written into a `SpooledBody`.
+    ///
+    /// Endpoints which may have large bodies may implement this so that the body does not need to
+    /// be held in memory in its entirety. When this returns a body, it is used instead of `body`.
+    ///
+    /// Returns the `Content-Encoding` header for the data as well as the data itself.
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        Ok(None)
+    }
 }
 
 impl<E> Endpoint for &E
@@ -86,6 +97,10 @@ where
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         (*self).body()
     }
+
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        (*self).spooled_body()
+    }
 }
 
 impl<E, T, C> Query<T, C> for E
@@ -101,13 +116,7 @@ where
         let req = Request::builder()
             .method(self.method())
             .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest(req, data)?;
+        let rsp = query::rest(client, req, self)?;
         let status = rsp.status();
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
@@ -140,13 +149,7 @@ where
         let req = Request::builder()
             .method(self.method())
             .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest_async(req, data).await?;
+        let rsp = query::rest_async(client, req, self).await?;
         let status = rsp.status();
         let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
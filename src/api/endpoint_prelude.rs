@@ -18,6 +18,9 @@ pub use crate::api::Client;
 pub use crate::api::Endpoint;
 pub use crate::api::FormParams;
 pub use crate::api::JsonParams;
+pub use crate::api::MultipartParams;
 pub use crate::api::Pageable;
+pub use crate::api::ParamName;
 pub use crate::api::QueryParams;
+pub use crate::api::SpooledBody;
 pub use crate::api::UrlBase;
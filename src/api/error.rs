@@ -8,6 +8,7 @@ use std::any;
 use std::error::Error;
 
 use thiserror::Error;
+use url::Url;
 
 use crate::api::{PaginationError, UrlBase};
 
@@ -29,6 +30,16 @@ pub enum BodyError {
         #[from]
         source: serde_json::Error,
     },
+    /// Body data could not be written.
+    #[error("failed to write body data: {}", source)]
+    Io {
+        /// The source of the error.
+        #[from]
+        source: std::io::Error,
+    },
+    /// Multipart form data contains the boundary used to separate its parts.
+    #[error("multipart form data contains the form boundary")]
+    MultipartBoundary {},
 }
 
 /// Errors which may occur when using API endpoints.
@@ -112,6 +123,32 @@ where
         /// The name of the type that could not be deserialized.
         typename: &'static str,
     },
+    /// Failed to parse an expected data type from JSON in strict mode.
+    #[error(
+        "could not parse {} data from JSON at `{}`: {}{}",
+        typename,
+        path,
+        source,
+        snippet_suffix(snippet.as_deref())
+    )]
+    StrictDataType {
+        /// The source of the error.
+        source: serde_json::Error,
+        /// The path to the value which could not be deserialized.
+        path: String,
+        /// An excerpt of the JSON at the path, if available.
+        snippet: Option<String>,
+        /// The name of the type that could not be deserialized.
+        typename: &'static str,
+    },
+    /// JSON contained fields which are not part of the expected data type in strict mode.
+    #[error("unknown fields in {} data from JSON: {}", typename, fields.join(", "))]
+    UnknownFields {
+        /// The paths to the fields which are not known.
+        fields: Vec<String>,
+        /// The name of the type that was deserialized.
+        typename: &'static str,
+    },
     /// An error with pagination occurred.
     #[error("failed to handle for pagination: {}", source)]
     Pagination {
@@ -125,6 +162,24 @@ where
         /// The URL base that is not supported.
         url_base: UrlBase,
     },
+    /// A URL does not point to the API of the client's instance.
+    #[error("url is not part of the instance's API: {}", url)]
+    ForeignUrl {
+        /// The URL.
+        url: String,
+    },
+    /// A helper could not build an endpoint from the given parameters.
+    #[error("invalid endpoint parameters: {}", msg)]
+    InvalidEndpoint {
+        /// The reason the endpoint could not be built.
+        msg: String,
+    },
+}
+
+fn snippet_suffix(snippet: Option<&str>) -> String {
+    snippet
+        .map(|snippet| format!(" (found `{}`)", snippet))
+        .unwrap_or_default()
 }
 
 impl<E> ApiError<E>
@@ -222,6 +277,28 @@ where
                     typename,
                 }
             },
+            Self::StrictDataType {
+                source,
+                path,
+                snippet,
+                typename,
+            } => {
+                ApiError::StrictDataType {
+                    source,
+                    path,
+                    snippet,
+                    typename,
+                }
+            },
+            Self::UnknownFields {
+                fields,
+                typename,
+            } => {
+                ApiError::UnknownFields {
+                    fields,
+                    typename,
+                }
+            },
             Self::Pagination {
                 source,
             } => {
@@ -236,6 +313,20 @@ where
                     url_base,
                 }
             },
+            Self::ForeignUrl {
+                url,
+            } => {
+                ApiError::ForeignUrl {
+                    url,
+                }
+            },
+            Self::InvalidEndpoint {
+                msg,
+            } => {
+                ApiError::InvalidEndpoint {
+                    msg,
+                }
+            },
         }
     }
 
@@ -282,11 +373,52 @@ where
         }
     }
 
+    pub(crate) fn strict_data_type<T>(
+        source: serde_json::Error,
+        path: String,
+        snippet: Option<String>,
+    ) -> Self {
+        ApiError::StrictDataType {
+            source,
+            path,
+            snippet,
+            typename: any::type_name::<T>(),
+        }
+    }
+
+    pub(crate) fn unknown_fields<T>(fields: Vec<String>) -> Self {
+        ApiError::UnknownFields {
+            fields,
+            typename: any::type_name::<T>(),
+        }
+    }
+
     pub(crate) fn unsupported_url_base(url_base: UrlBase) -> Self {
         Self::UnsupportedUrlBase {
             url_base,
         }
     }
+
+    pub(crate) fn foreign_url(url: &Url) -> Self {
+        Self::ForeignUrl {
+            url: url.as_str().into(),
+        }
+    }
+
+    #[cfg(any(
+        feature = "flows",
+        feature = "api-groups",
+        feature = "api-projects",
+        feature = "api-runners",
+    ))]
+    pub(crate) fn invalid_endpoint<B>(err: B) -> Self
+    where
+        B: std::fmt::Display,
+    {
+        Self::InvalidEndpoint {
+            msg: err.to_string(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,358 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::SortOrder;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Actions which events may represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventAction {
+    /// A merge request was approved.
+    Approved,
+    /// A resource was closed.
+    Closed,
+    /// A comment was made.
+    Commented,
+    /// A resource was created.
+    Created,
+    /// A resource was destroyed.
+    Destroyed,
+    /// A resource expired.
+    Expired,
+    /// A user joined a project.
+    Joined,
+    /// A user left a project.
+    Left,
+    /// A merge request was merged.
+    Merged,
+    /// Commits were pushed.
+    Pushed,
+    /// A resource was reopened.
+    Reopened,
+    /// A resource was updated.
+    Updated,
+}
+
+impl EventAction {
+    /// The action as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            EventAction::Approved => "approved",
+            EventAction::Closed => "closed",
+            EventAction::Commented => "commented",
+            EventAction::Created => "created",
+            EventAction::Destroyed => "destroyed",
+            EventAction::Expired => "expired",
+            EventAction::Joined => "joined",
+            EventAction::Left => "left",
+            EventAction::Merged => "merged",
+            EventAction::Pushed => "pushed",
+            EventAction::Reopened => "reopened",
+            EventAction::Updated => "updated",
+        }
+    }
+}
+
+impl ParamValue<'static> for EventAction {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Types of resources events may target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventTargetType {
+    /// Epics.
+    Epic,
+    /// Issues.
+    Issue,
+    /// Milestones.
+    Milestone,
+    /// Merge requests.
+    MergeRequest,
+    /// Notes (comments).
+    Note,
+    /// Projects.
+    Project,
+    /// Snippets.
+    Snippet,
+    /// Users.
+    User,
+}
+
+impl EventTargetType {
+    /// The target type as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            EventTargetType::Epic => "epic",
+            EventTargetType::Issue => "issue",
+            EventTargetType::Milestone => "milestone",
+            EventTargetType::MergeRequest => "merge_request",
+            EventTargetType::Note => "note",
+            EventTargetType::Project => "project",
+            EventTargetType::Snippet => "snippet",
+            EventTargetType::User => "user",
+        }
+    }
+}
+
+impl ParamValue<'static> for EventTargetType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Scopes for event queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventScope {
+    /// Include events from all of the user's projects.
+    All,
+}
+
+impl EventScope {
+    /// The scope as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            EventScope::All => "all",
+        }
+    }
+}
+
+impl ParamValue<'static> for EventScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for events of the current user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Events {
+    /// Filter events by action.
+    #[builder(default)]
+    action: Option<EventAction>,
+    /// Filter events by target type.
+    #[builder(default)]
+    target_type: Option<EventTargetType>,
+    /// Only return events created before the given date.
+    #[builder(default)]
+    before: Option<NaiveDate>,
+    /// Only return events created after the given date.
+    #[builder(default)]
+    after: Option<NaiveDate>,
+    /// The scope of events to return.
+    #[builder(default)]
+    scope: Option<EventScope>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl Events {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EventsBuilder {
+        EventsBuilder::default()
+    }
+}
+
+impl Endpoint for Events {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "events".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("action", self.action)
+            .push_opt("target_type", self.target_type)
+            .push_opt("before", self.before)
+            .push_opt("after", self.after)
+            .push_opt("scope", self.scope)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for Events {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::api::common::SortOrder;
+    use crate::api::events::{EventAction, EventScope, EventTargetType, Events};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn event_action_as_str() {
+        let items = &[
+            (EventAction::Approved, "approved"),
+            (EventAction::Closed, "closed"),
+            (EventAction::Commented, "commented"),
+            (EventAction::Created, "created"),
+            (EventAction::Destroyed, "destroyed"),
+            (EventAction::Expired, "expired"),
+            (EventAction::Joined, "joined"),
+            (EventAction::Left, "left"),
+            (EventAction::Merged, "merged"),
+            (EventAction::Pushed, "pushed"),
+            (EventAction::Reopened, "reopened"),
+            (EventAction::Updated, "updated"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn event_target_type_as_str() {
+        let items = &[
+            (EventTargetType::Epic, "epic"),
+            (EventTargetType::Issue, "issue"),
+            (EventTargetType::Milestone, "milestone"),
+            (EventTargetType::MergeRequest, "merge_request"),
+            (EventTargetType::Note, "note"),
+            (EventTargetType::Project, "project"),
+            (EventTargetType::Snippet, "snippet"),
+            (EventTargetType::User, "user"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn event_scope_as_str() {
+        let items = &[(EventScope::All, "all")];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Events::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("events").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_action() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("events")
+            .add_query_params(&[("action", "pushed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder()
+            .action(EventAction::Pushed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_target_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("events")
+            .add_query_params(&[("target_type", "merge_request")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder()
+            .target_type(EventTargetType::MergeRequest)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("events")
+            .add_query_params(&[("before", "2024-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder()
+            .before(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("events")
+            .add_query_params(&[("after", "2024-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder()
+            .after(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("events")
+            .add_query_params(&[("scope", "all")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder().scope(EventScope::All).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("events")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Events::builder()
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
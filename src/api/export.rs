@@ -0,0 +1,574 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Export helpers.
+//!
+//! These helpers stream the results of paginated endpoints into writers without collecting the
+//! full result set in memory first. They are intended for endpoints such as issue or merge request
+//! listings which are used for reporting.
+//!
+//! Results may be written in one of the built-in `ExportFormat` formats or with a custom
+//! `ExportSerializer`. When writing to an asynchronous writer, the next result is not requested
+//! until the previous one has been accepted by the writer.
+
+use std::error::Error;
+use std::io::{self, Write};
+
+use futures_util::io::{AsyncWrite, AsyncWriteExt};
+use futures_util::StreamExt;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::api::{self, ApiError, AsyncClient, Client, Endpoint, Pageable, Paged, Pagination};
+
+/// Errors which may occur when exporting results.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ExportError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The API returned an error.
+    #[error("api error: {}", source)]
+    Api {
+        /// The source of the error.
+        #[from]
+        source: ApiError<E>,
+    },
+    /// The results could not be written.
+    #[error("failed to write results: {}", source)]
+    Io {
+        /// The source of the error.
+        #[from]
+        source: io::Error,
+    },
+}
+
+/// Formats results may be exported as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExportFormat {
+    /// Comma-separated values with a header line.
+    ///
+    /// Strings are written as-is and arrays or objects are written as JSON. Missing or `null`
+    /// values are written as empty cells.
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+}
+
+/// A serializer for exported results.
+///
+/// This may be used to write results in formats other than those provided by `ExportFormat`.
+/// Closures which take the output buffer and a result implement this trait.
+pub trait ExportSerializer {
+    /// Append a result to the output.
+    fn serialize(&mut self, output: &mut Vec<u8>, item: &Value) -> io::Result<()>;
+}
+
+impl<F> ExportSerializer for F
+where
+    F: FnMut(&mut Vec<u8>, &Value) -> io::Result<()>,
+{
+    fn serialize(&mut self, output: &mut Vec<u8>, item: &Value) -> io::Result<()> {
+        self(output, item)
+    }
+}
+
+/// A helper which writes the results of a paginated endpoint to a writer.
+#[derive(Debug, Clone)]
+pub struct Export<E> {
+    paged: Paged<E>,
+    format: ExportFormat,
+    fields: Vec<String>,
+}
+
+/// Export the results of a paginated endpoint.
+pub fn export<E>(endpoint: E, pagination: Pagination, format: ExportFormat) -> Export<E> {
+    Export {
+        paged: api::paged(endpoint, pagination),
+        format,
+        fields: Vec::new(),
+    }
+}
+
+impl<E> Export<E> {
+    /// Add a field to export.
+    ///
+    /// Nested fields may be selected using `.` to separate keys (e.g., `author.username`). If no
+    /// fields are selected, all top-level fields are exported. For CSV, the fields of the first
+    /// result are used as the columns.
+    pub fn field<F>(mut self, field: F) -> Self
+    where
+        F: Into<String>,
+    {
+        self.fields.push(field.into());
+        self
+    }
+
+    /// Add multiple fields to export.
+    pub fn fields<I, F>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<String>,
+    {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl<E> Export<E>
+where
+    E: Endpoint + Pageable,
+{
+    /// Write the results to a writer.
+    ///
+    /// Returns the number of exported results.
+    pub fn write<C, W>(&self, client: &C, writer: W) -> Result<u64, ExportError<C::Error>>
+    where
+        C: Client,
+        W: Write,
+    {
+        self.write_with(client, writer, ExportState::new(self.format, &self.fields))
+    }
+
+    /// Write the results to a writer using a custom serializer.
+    ///
+    /// The selected fields and format are ignored; the serializer receives each result as
+    /// returned by GitLab.
+    ///
+    /// Returns the number of exported results.
+    pub fn write_with<C, W, S>(
+        &self,
+        client: &C,
+        mut writer: W,
+        mut serializer: S,
+    ) -> Result<u64, ExportError<C::Error>>
+    where
+        C: Client,
+        W: Write,
+        S: ExportSerializer,
+    {
+        let mut output = Vec::new();
+        let mut count = 0;
+        for item in self.paged.iter(client) {
+            serializer.serialize(&mut output, &item?)?;
+            writer.write_all(&output)?;
+            output.clear();
+            count += 1;
+        }
+        writer.flush()?;
+
+        Ok(count)
+    }
+}
+
+impl<E> Export<E>
+where
+    E: Endpoint + Pageable + Sync,
+{
+    /// Write the results to a writer asynchronously.
+    ///
+    /// Returns the number of exported results.
+    pub async fn write_async<C, W>(
+        &self,
+        client: &C,
+        mut writer: W,
+    ) -> Result<u64, ExportError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        W: Write,
+    {
+        let mut state = ExportState::new(self.format, &self.fields);
+        let mut items = Box::pin(self.paged.iter_async(client));
+        while let Some(item) = items.next().await {
+            state.write(&mut writer, &item?)?;
+        }
+        writer.flush()?;
+
+        Ok(state.count)
+    }
+
+    /// Write the results to an asynchronous writer.
+    ///
+    /// Each result is written before the next one is requested, so a slow writer also slows down
+    /// fetching of further pages.
+    ///
+    /// Returns the number of exported results.
+    pub async fn write_async_writer<C, W>(
+        &self,
+        client: &C,
+        writer: W,
+    ) -> Result<u64, ExportError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        W: AsyncWrite + Unpin,
+    {
+        self.write_with_async_writer(client, writer, ExportState::new(self.format, &self.fields))
+            .await
+    }
+
+    /// Write the results to an asynchronous writer using a custom serializer.
+    ///
+    /// See [`Export::write_with`] and [`Export::write_async_writer`].
+    ///
+    /// Returns the number of exported results.
+    pub async fn write_with_async_writer<C, W, S>(
+        &self,
+        client: &C,
+        mut writer: W,
+        mut serializer: S,
+    ) -> Result<u64, ExportError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        W: AsyncWrite + Unpin,
+        S: ExportSerializer,
+    {
+        let mut output = Vec::new();
+        let mut count = 0;
+        let mut items = Box::pin(self.paged.iter_async(client));
+        while let Some(item) = items.next().await {
+            serializer.serialize(&mut output, &item?)?;
+            writer.write_all(&output).await?;
+            output.clear();
+            count += 1;
+        }
+        writer.flush().await?;
+
+        Ok(count)
+    }
+}
+
+struct ExportState {
+    format: ExportFormat,
+    fields: Vec<String>,
+    count: u64,
+}
+
+impl ExportState {
+    fn new(format: ExportFormat, fields: &[String]) -> Self {
+        Self {
+            format,
+            fields: fields.into(),
+            count: 0,
+        }
+    }
+
+    fn write<W>(&mut self, writer: &mut W, item: &Value) -> io::Result<()>
+    where
+        W: Write,
+    {
+        match self.format {
+            ExportFormat::Csv => {
+                if self.count == 0 {
+                    if self.fields.is_empty() {
+                        if let Value::Object(object) = item {
+                            self.fields = object.keys().cloned().collect();
+                        }
+                    }
+                    let header = self.fields.iter().map(|field| csv_cell(field)).collect();
+                    write_csv_record(writer, header)?;
+                }
+                let record = self
+                    .fields
+                    .iter()
+                    .map(|field| csv_value(lookup(item, field)))
+                    .collect();
+                write_csv_record(writer, record)?;
+            },
+            ExportFormat::JsonLines => {
+                if self.fields.is_empty() {
+                    serde_json::to_writer(&mut *writer, item)?;
+                } else {
+                    let object: Map<_, _> = self
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let value = lookup(item, field).cloned().unwrap_or(Value::Null);
+                            (field.clone(), value)
+                        })
+                        .collect();
+                    serde_json::to_writer(&mut *writer, &object)?;
+                }
+                writer.write_all(b"\n")?;
+            },
+        }
+        self.count += 1;
+
+        Ok(())
+    }
+}
+
+impl ExportSerializer for ExportState {
+    fn serialize(&mut self, output: &mut Vec<u8>, item: &Value) -> io::Result<()> {
+        self.write(output, item)
+    }
+}
+
+fn lookup<'a>(item: &'a Value, field: &str) -> Option<&'a Value> {
+    field.split('.').try_fold(item, |value, key| value.get(key))
+}
+
+fn csv_value(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => csv_cell(s),
+        Some(value) => csv_cell(&value.to_string()),
+    }
+}
+
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.into()
+    }
+}
+
+fn write_csv_record<W>(writer: &mut W, cells: Vec<String>) -> io::Result<()>
+where
+    W: Write,
+{
+    writeln!(writer, "{}", cells.join(","))
+}
+
+#[cfg(all(test, feature = "api-issues"))]
+mod tests {
+    use std::io::{self, Write};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use futures_util::io::AsyncWrite;
+    use serde_json::{json, Value};
+
+    use crate::api::export::{self, csv_cell, ExportFormat};
+    use crate::api::issues::ProjectIssues;
+    use crate::api::Pagination;
+    use crate::testing::{ExpectedRequest, MockClient};
+
+    #[test]
+    fn csv_cell_quoting() {
+        let items = &[
+            ("plain", "plain"),
+            ("with space", "with space"),
+            ("a,b", "\"a,b\""),
+            ("say \"hi\"", "\"say \"\"hi\"\"\""),
+            ("multi\nline", "\"multi\nline\""),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(csv_cell(i), *s);
+        }
+    }
+
+    fn mock_client() -> MockClient {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/simple%2Fproject/issues")
+                .build()
+                .unwrap(),
+            [
+                json!({
+                    "iid": 1,
+                    "title": "First, issue",
+                    "author": {"username": "alice"},
+                    "labels": ["bug"],
+                    "closed_at": null,
+                }),
+                json!({
+                    "iid": 2,
+                    "title": "Second",
+                    "author": {"username": "bob"},
+                    "labels": [],
+                }),
+            ],
+        );
+        client
+    }
+
+    fn endpoint() -> ProjectIssues<'static> {
+        ProjectIssues::builder()
+            .project("simple/project")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn export_csv() {
+        let client = mock_client();
+
+        let mut output = Vec::new();
+        let count = export::export(endpoint(), Pagination::All, ExportFormat::Csv)
+            .fields(["iid", "title", "author.username", "labels", "closed_at"])
+            .write(&client, &mut output)
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                "iid,title,author.username,labels,closed_at\n",
+                "1,\"First, issue\",alice,\"[\"\"bug\"\"]\",\n",
+                "2,Second,bob,[],\n",
+            ),
+        );
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn export_csv_all_fields() {
+        let client = mock_client();
+
+        let mut output = Vec::new();
+        export::export(endpoint(), Pagination::All, ExportFormat::Csv)
+            .write(&client, &mut output)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                "author,closed_at,iid,labels,title\n",
+                "\"{\"\"username\"\":\"\"alice\"\"}\",,1,\"[\"\"bug\"\"]\",\"First, issue\"\n",
+                "\"{\"\"username\"\":\"\"bob\"\"}\",,2,[],Second\n",
+            ),
+        );
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn export_json_lines() {
+        let client = mock_client();
+
+        let mut output = Vec::new();
+        let count = export::export(endpoint(), Pagination::All, ExportFormat::JsonLines)
+            .field("iid")
+            .field("author.username")
+            .write(&client, &mut output)
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                "{\"author.username\":\"alice\",\"iid\":1}\n",
+                "{\"author.username\":\"bob\",\"iid\":2}\n",
+            ),
+        );
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn export_json_lines_async() {
+        let client = mock_client();
+
+        let mut output = Vec::new();
+        let count = export::export(endpoint(), Pagination::All, ExportFormat::JsonLines)
+            .field("iid")
+            .write_async(&client, &mut output)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"iid\":1}\n{\"iid\":2}\n",
+        );
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn export_with_serializer() {
+        let client = mock_client();
+
+        let mut output = Vec::new();
+        let count = export::export(endpoint(), Pagination::All, ExportFormat::Csv)
+            .field("iid")
+            .write_with(
+                &client,
+                &mut output,
+                |output: &mut Vec<u8>, item: &Value| {
+                    writeln!(output, "{}\t{}", item["iid"], item["author"]["username"])
+                },
+            )
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "1\t\"alice\"\n2\t\"bob\"\n",
+        );
+        client.assert_all_consumed();
+    }
+
+    /// A writer which accepts a single byte at a time.
+    #[derive(Default)]
+    struct SlowWriter {
+        data: Vec<u8>,
+        flushed: bool,
+    }
+
+    impl AsyncWrite for SlowWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            if let Some(&byte) = buf.first() {
+                self.data.push(byte);
+                // Make the writer wake up the task again for the rest of the buffer.
+                cx.waker().wake_by_ref();
+                Poll::Ready(Ok(1))
+            } else {
+                Poll::Ready(Ok(0))
+            }
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.flushed = true;
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn export_json_lines_async_writer() {
+        let client = mock_client();
+
+        let mut writer = SlowWriter::default();
+        let count = export::export(endpoint(), Pagination::All, ExportFormat::JsonLines)
+            .field("iid")
+            .write_async_writer(&client, &mut writer)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        assert!(writer.flushed);
+        assert_eq!(
+            String::from_utf8(writer.data).unwrap(),
+            "{\"iid\":1}\n{\"iid\":2}\n",
+        );
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn export_with_serializer_async_writer() {
+        let client = mock_client();
+
+        let mut output = Vec::new();
+        let count = export::export(endpoint(), Pagination::All, ExportFormat::JsonLines)
+            .write_with_async_writer(
+                &client,
+                &mut output,
+                |output: &mut Vec<u8>, item: &Value| writeln!(output, "{}", item["title"]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\"First, issue\"\n\"Second\"\n",
+        );
+        client.assert_all_consumed();
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the definitions of all feature flags known to the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct FeatureDefinitions {}
+
+impl FeatureDefinitions {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FeatureDefinitionsBuilder {
+        FeatureDefinitionsBuilder::default()
+    }
+}
+
+impl Endpoint for FeatureDefinitions {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "features/definitions".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::features::FeatureDefinitions;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        FeatureDefinitions::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("features/definitions")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureDefinitions::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a feature flag.
+///
+/// This removes all of the gates of the feature flag so that its default state applies again.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteFeature<'a> {
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> DeleteFeature<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteFeatureBuilder<'a> {
+        DeleteFeatureBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteFeature<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("features/{}", common::path_escaped(&self.name)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::features::{DeleteFeature, DeleteFeatureBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn name_is_needed() {
+        let err = DeleteFeature::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureBuilderError, "name");
+    }
+
+    #[test]
+    fn name_is_sufficient() {
+        DeleteFeature::builder().name("feature").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("features/my_feature")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteFeature::builder().name("my_feature").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the persisted feature flags of the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Features {}
+
+impl Features {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FeaturesBuilder {
+        FeaturesBuilder::default()
+    }
+}
+
+impl Endpoint for Features {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "features".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::features::Features;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Features::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("features").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Features::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,486 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList};
+use crate::api::endpoint_prelude::*;
+
+/// The value of a feature flag gate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum FeatureValue {
+    /// Enable or disable the feature.
+    ///
+    /// If actors are given, only their gates are changed.
+    Boolean(bool),
+    /// Enable the feature for a percentage of the time.
+    PercentageOfTime(u64),
+    /// Enable the feature for a percentage of actors.
+    PercentageOfActors(f64),
+}
+
+impl FeatureValue {
+    fn add_params<'a>(self, params: &mut FormParams<'a>) {
+        match self {
+            FeatureValue::Boolean(value) => {
+                params.push("value", value);
+            },
+            FeatureValue::PercentageOfTime(value) => {
+                params
+                    .push("value", value)
+                    .push("key", "percentage_of_time");
+            },
+            FeatureValue::PercentageOfActors(value) => {
+                params
+                    .push("value", value)
+                    .push("key", "percentage_of_actors");
+            },
+        }
+    }
+}
+
+/// Set a gate value for a feature flag.
+///
+/// The feature flag is created if it does not already exist.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct SetFeature<'a> {
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The value to set.
+    value: FeatureValue,
+
+    /// A feature group to set the gate for.
+    #[builder(setter(into), default)]
+    feature_group: Option<Cow<'a, str>>,
+    /// Usernames to set the gate for.
+    #[builder(setter(name = "_users"), default, private)]
+    users: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Group paths to set the gate for.
+    #[builder(setter(name = "_groups"), default, private)]
+    groups: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Namespace paths to set the gate for.
+    #[builder(setter(name = "_namespaces"), default, private)]
+    namespaces: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Project paths to set the gate for.
+    #[builder(setter(name = "_projects"), default, private)]
+    projects: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Repository paths to set the gate for.
+    #[builder(setter(name = "_repositories"), default, private)]
+    repositories: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Skip the checks of the feature flag's definition.
+    #[builder(default)]
+    force: Option<bool>,
+}
+
+impl<'a> SetFeature<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetFeatureBuilder<'a> {
+        SetFeatureBuilder::default()
+    }
+}
+
+impl<'a> SetFeatureBuilder<'a> {
+    /// Set the gate for a user (by username).
+    pub fn user<A>(&mut self, user: A) -> &mut Self
+    where
+        A: Into<Cow<'a, str>>,
+    {
+        self.users
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(user.into());
+        self
+    }
+
+    /// Set the gate for a set of users (by username).
+    pub fn users<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = A>,
+        A: Into<Cow<'a, str>>,
+    {
+        self.users
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Set the gate for a group (by path).
+    pub fn group<A>(&mut self, group: A) -> &mut Self
+    where
+        A: Into<Cow<'a, str>>,
+    {
+        self.groups
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(group.into());
+        self
+    }
+
+    /// Set the gate for a set of groups (by path).
+    pub fn groups<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = A>,
+        A: Into<Cow<'a, str>>,
+    {
+        self.groups
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Set the gate for a namespace (by path).
+    pub fn namespace<A>(&mut self, namespace: A) -> &mut Self
+    where
+        A: Into<Cow<'a, str>>,
+    {
+        self.namespaces
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(namespace.into());
+        self
+    }
+
+    /// Set the gate for a set of namespaces (by path).
+    pub fn namespaces<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = A>,
+        A: Into<Cow<'a, str>>,
+    {
+        self.namespaces
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Set the gate for a project (by path).
+    pub fn project<A>(&mut self, project: A) -> &mut Self
+    where
+        A: Into<Cow<'a, str>>,
+    {
+        self.projects
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(project.into());
+        self
+    }
+
+    /// Set the gate for a set of projects (by path).
+    pub fn projects<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = A>,
+        A: Into<Cow<'a, str>>,
+    {
+        self.projects
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Set the gate for a repository (by path).
+    pub fn repository<A>(&mut self, repository: A) -> &mut Self
+    where
+        A: Into<Cow<'a, str>>,
+    {
+        self.repositories
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(repository.into());
+        self
+    }
+
+    /// Set the gate for a set of repositories (by path).
+    pub fn repositories<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = A>,
+        A: Into<Cow<'a, str>>,
+    {
+        self.repositories
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for SetFeature<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("features/{}", common::path_escaped(&self.name)).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        self.value.add_params(&mut params);
+        params
+            .push_opt("feature_group", self.feature_group.as_ref())
+            .push_opt("user", self.users.as_ref())
+            .push_opt("group", self.groups.as_ref())
+            .push_opt("namespace", self.namespaces.as_ref())
+            .push_opt("project", self.projects.as_ref())
+            .push_opt("repository", self.repositories.as_ref())
+            .push_opt("force", self.force);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::features::{FeatureValue, SetFeature, SetFeatureBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn name_and_value_are_needed() {
+        let err = SetFeature::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetFeatureBuilderError, "name");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = SetFeature::builder()
+            .value(FeatureValue::Boolean(true))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetFeatureBuilderError, "name");
+    }
+
+    #[test]
+    fn value_is_needed() {
+        let err = SetFeature::builder().name("feature").build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetFeatureBuilderError, "value");
+    }
+
+    #[test]
+    fn name_and_value_are_sufficient() {
+        SetFeature::builder()
+            .name("feature")
+            .value(FeatureValue::Boolean(true))
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_percentage_of_time() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=25", "&key=percentage_of_time"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::PercentageOfTime(25))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_percentage_of_actors() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=0.5", "&key=percentage_of_actors"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::PercentageOfActors(0.5))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_feature_group() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&feature_group=beta"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .feature_group("beta")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_users() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&user=alice%2Cbob%2Ccarol"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .user("alice")
+            .users(["bob", "carol"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=false", "&group=group%2Cother%2Fgroup"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(false))
+            .group("group")
+            .groups(["other/group"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespaces() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&namespace=user%2Cgroup"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .namespace("user")
+            .namespaces(["group"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_projects() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "value=true",
+                "&project=simple%2Fproject%2Cother%2Fproject",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .project("simple/project")
+            .projects(["other/project"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_repositories() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "value=true",
+                "&repository=simple%2Fproject%2Csimple%2Fproject.wiki",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .repository("simple/project")
+            .repositories(["simple/project.wiki"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_force() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/my_feature")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&force=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("my_feature")
+            .value(FeatureValue::Boolean(true))
+            .force(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,445 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! High-level helpers for common workflows.
+//!
+//! These functions combine multiple endpoint calls into operations which are commonly needed by
+//! tools built on top of the API. They only use the fields of the responses which they need, so
+//! the results of the underlying endpoints should be queried separately if more information is
+//! needed.
+
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::merge_requests::{
+    CreateMergeRequest, EditMergeRequest, MergeRequestState, MergeRequests,
+};
+use crate::api::projects::pipelines::{Pipeline, PipelineStatus};
+use crate::api::projects::repository::branches::{Branches, CreateBranch};
+use crate::api::{self, ApiError, Client, Pagination, Query};
+
+/// The result of ensuring that a branch exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BranchOutcome {
+    /// The branch already existed.
+    Existed,
+    /// The branch was created.
+    Created,
+}
+
+/// The fields of a branch needed to find it.
+#[derive(Debug, Deserialize)]
+struct BranchInfo {
+    name: String,
+}
+
+/// Ensure that a branch exists in a project.
+///
+/// If the branch does not exist, it is created from `ref_`. An existing branch is left as-is even
+/// if it does not point to `ref_`.
+pub fn ensure_branch<'a, C, P>(
+    client: &C,
+    project: P,
+    branch: &str,
+    ref_: &str,
+) -> Result<BranchOutcome, ApiError<C::Error>>
+where
+    C: Client,
+    P: Into<NameOrId<'a>>,
+{
+    let project = project.into();
+
+    let endpoint = Branches::builder()
+        .project(project.clone())
+        .search(format!("^{}$", branch))
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+    let branches: Vec<BranchInfo> = api::paged(endpoint, Pagination::All).query(client)?;
+    if branches.iter().any(|info| info.name == branch) {
+        return Ok(BranchOutcome::Existed);
+    }
+
+    let endpoint = CreateBranch::builder()
+        .project(project)
+        .branch(branch)
+        .ref_(ref_)
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+    api::ignore(endpoint).query(client)?;
+
+    Ok(BranchOutcome::Created)
+}
+
+/// The result of opening or updating a merge request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MergeRequestOutcome {
+    /// A new merge request was opened with the given IID.
+    Created(u64),
+    /// An existing merge request with the given IID was updated.
+    Updated(u64),
+}
+
+impl MergeRequestOutcome {
+    /// The IID of the merge request.
+    pub fn iid(self) -> u64 {
+        match self {
+            MergeRequestOutcome::Created(iid) | MergeRequestOutcome::Updated(iid) => iid,
+        }
+    }
+}
+
+/// The fields of a merge request needed to update it.
+#[derive(Debug, Deserialize)]
+struct MergeRequestInfo {
+    iid: u64,
+}
+
+/// Open a merge request for a branch or update the open merge request for it.
+///
+/// An open merge request from `source_branch` into `target_branch` within the project has its
+/// title and description updated. Otherwise, a new merge request is opened.
+pub fn open_or_update_merge_request<'a, C, P>(
+    client: &C,
+    project: P,
+    source_branch: &str,
+    target_branch: &str,
+    title: &str,
+    description: Option<&str>,
+) -> Result<MergeRequestOutcome, ApiError<C::Error>>
+where
+    C: Client,
+    P: Into<NameOrId<'a>>,
+{
+    let project = project.into();
+
+    let endpoint = MergeRequests::builder()
+        .project(project.clone())
+        .state(MergeRequestState::Opened)
+        .source_branch(source_branch)
+        .target_branch(target_branch)
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+    let existing: Vec<MergeRequestInfo> = api::paged(endpoint, Pagination::All).query(client)?;
+
+    if let Some(mr) = existing.first() {
+        let mut builder = EditMergeRequest::builder();
+        builder.project(project).merge_request(mr.iid).title(title);
+        if let Some(description) = description {
+            builder.description(description);
+        }
+        api::ignore(builder.build().map_err(ApiError::invalid_endpoint)?).query(client)?;
+
+        Ok(MergeRequestOutcome::Updated(mr.iid))
+    } else {
+        let mut builder = CreateMergeRequest::builder();
+        builder
+            .project(project)
+            .source_branch(source_branch)
+            .target_branch(target_branch)
+            .title(title);
+        if let Some(description) = description {
+            builder.description(description);
+        }
+        let created: MergeRequestInfo = builder
+            .build()
+            .map_err(ApiError::invalid_endpoint)?
+            .query(client)?;
+
+        Ok(MergeRequestOutcome::Created(created.iid))
+    }
+}
+
+/// The fields of a pipeline needed to check its status.
+#[derive(Debug, Deserialize)]
+struct PipelineInfo {
+    status: String,
+}
+
+fn pipeline_status(status: &str) -> Option<PipelineStatus> {
+    Some(match status {
+        "running" => PipelineStatus::Running,
+        "pending" => PipelineStatus::Pending,
+        "success" => PipelineStatus::Success,
+        "failed" => PipelineStatus::Failed,
+        "canceled" => PipelineStatus::Canceled,
+        "skipped" => PipelineStatus::Skipped,
+        "created" => PipelineStatus::Created,
+        "manual" => PipelineStatus::Manual,
+        "scheduled" => PipelineStatus::Scheduled,
+        "preparing" => PipelineStatus::Preparing,
+        "waiting_for_resource" => PipelineStatus::WaitingForResource,
+        _ => return None,
+    })
+}
+
+/// Whether a pipeline status indicates that the pipeline will not make further progress on its
+/// own.
+///
+/// This is suitable for use as the predicate for [`wait_for_pipeline`].
+pub fn is_pipeline_complete(status: PipelineStatus) -> bool {
+    matches!(
+        status,
+        PipelineStatus::Success
+            | PipelineStatus::Failed
+            | PipelineStatus::Canceled
+            | PipelineStatus::Skipped
+            | PipelineStatus::Manual,
+    )
+}
+
+/// Wait for a pipeline to reach a status.
+///
+/// The pipeline is queried every `poll_interval` until its status satisfies `predicate`. Statuses
+/// which are not known to the crate never satisfy the predicate. The current thread is blocked
+/// while waiting.
+///
+/// Returns the status which satisfied the predicate.
+pub fn wait_for_pipeline<'a, C, P, F>(
+    client: &C,
+    project: P,
+    pipeline: u64,
+    mut predicate: F,
+    poll_interval: Duration,
+) -> Result<PipelineStatus, ApiError<C::Error>>
+where
+    C: Client,
+    P: Into<NameOrId<'a>>,
+    F: FnMut(PipelineStatus) -> bool,
+{
+    let endpoint = Pipeline::builder()
+        .project(project)
+        .pipeline(pipeline)
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+
+    loop {
+        let info: PipelineInfo = endpoint.query(client)?;
+        if let Some(status) = pipeline_status(&info.status) {
+            if predicate(status) {
+                return Ok(status);
+            }
+        }
+
+        thread::sleep(poll_interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::Method;
+    use serde_json::json;
+
+    use crate::api::flows::{self, BranchOutcome, MergeRequestOutcome};
+    use crate::api::projects::pipelines::PipelineStatus;
+    use crate::api::ApiError;
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    fn branches_request() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("projects/simple%2Fproject/repository/branches")
+            .query_param("search", "^topic$")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn ensure_branch_existed() {
+        let client = MockClient::new();
+        client.expect_paged(branches_request(), [json!({"name": "topic"})]);
+
+        let outcome = flows::ensure_branch(&client, "simple/project", "topic", "main").unwrap();
+        assert_eq!(outcome, BranchOutcome::Existed);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn ensure_branch_created() {
+        let client = MockClient::new();
+        client
+            .expect_paged(branches_request(), [json!({"name": "topic-other"})])
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::POST)
+                    .endpoint("projects/simple%2Fproject/repository/branches")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str("branch=topic&ref=main")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"name": "topic"})),
+            );
+
+        let outcome = flows::ensure_branch(&client, "simple/project", "topic", "main").unwrap();
+        assert_eq!(outcome, BranchOutcome::Created);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn ensure_branch_invalid_project() {
+        let client = MockClient::new();
+
+        let err = flows::ensure_branch(&client, 0, "topic", "main").unwrap_err();
+        if let ApiError::InvalidEndpoint {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "invalid `project`: IDs must not be zero");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    fn merge_requests_request() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .query_param("state", "opened")
+            .query_param("source_branch", "topic")
+            .query_param("target_branch", "main")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn open_merge_request() {
+        let client = MockClient::new();
+        client
+            .expect_paged(merge_requests_request(), [] as [serde_json::Value; 0])
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::POST)
+                    .endpoint("projects/simple%2Fproject/merge_requests")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str(concat!(
+                        "source_branch=topic",
+                        "&target_branch=main",
+                        "&title=Add+a+feature",
+                        "&description=Details",
+                    ))
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"iid": 7})),
+            );
+
+        let outcome = flows::open_or_update_merge_request(
+            &client,
+            "simple/project",
+            "topic",
+            "main",
+            "Add a feature",
+            Some("Details"),
+        )
+        .unwrap();
+        assert_eq!(outcome, MergeRequestOutcome::Created(7));
+        assert_eq!(outcome.iid(), 7);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn update_merge_request() {
+        let client = MockClient::new();
+        client
+            .expect_paged(merge_requests_request(), [json!({"iid": 3})])
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::PUT)
+                    .endpoint("projects/simple%2Fproject/merge_requests/3")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str("title=Add+a+feature")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"iid": 3})),
+            );
+
+        let outcome = flows::open_or_update_merge_request(
+            &client,
+            "simple/project",
+            "topic",
+            "main",
+            "Add a feature",
+            None,
+        )
+        .unwrap();
+        assert_eq!(outcome, MergeRequestOutcome::Updated(3));
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn is_pipeline_complete() {
+        let items = &[
+            (PipelineStatus::Running, false),
+            (PipelineStatus::Pending, false),
+            (PipelineStatus::Success, true),
+            (PipelineStatus::Failed, true),
+            (PipelineStatus::Canceled, true),
+            (PipelineStatus::Skipped, true),
+            (PipelineStatus::Created, false),
+            (PipelineStatus::Manual, true),
+            (PipelineStatus::Scheduled, false),
+            (PipelineStatus::Preparing, false),
+            (PipelineStatus::WaitingForResource, false),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(flows::is_pipeline_complete(*i), *s);
+        }
+    }
+
+    #[test]
+    fn wait_for_pipeline() {
+        let request = || {
+            ExpectedRequest::builder()
+                .endpoint("projects/simple%2Fproject/pipelines/1")
+                .build()
+                .unwrap()
+        };
+        let client = MockClient::new();
+        client
+            .expect(request(), MockResponse::json(&json!({"status": "pending"})))
+            .expect(request(), MockResponse::json(&json!({"status": "unknown"})))
+            .expect(request(), MockResponse::json(&json!({"status": "running"})))
+            .expect(request(), MockResponse::json(&json!({"status": "failed"})));
+
+        let status = flows::wait_for_pipeline(
+            &client,
+            "simple/project",
+            1,
+            flows::is_pipeline_complete,
+            Duration::from_secs(0),
+        )
+        .unwrap();
+        assert_eq!(status, PipelineStatus::Failed);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn wait_for_pipeline_predicate() {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("projects/simple%2Fproject/pipelines/1")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({"status": "running"})),
+        );
+
+        let status = flows::wait_for_pipeline(
+            &client,
+            "simple/project",
+            1,
+            |status| status != PipelineStatus::Pending,
+            Duration::from_secs(0),
+        )
+        .unwrap();
+        assert_eq!(status, PipelineStatus::Running);
+        client.assert_all_consumed();
+    }
+}
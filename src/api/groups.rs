@@ -11,12 +11,22 @@
 //! These endpoints are used for querying and modifying groups and their resources.
 
 pub mod access_requests;
+pub mod access_tokens;
+pub mod analytics;
+pub mod badges;
+pub mod boards;
+mod ci_baseline;
 mod create;
 mod edit;
+pub mod epics;
 mod group;
 mod groups;
 pub mod hooks;
+pub mod invitations;
 pub mod issues;
+pub mod iterations;
+pub mod labels;
+pub mod member_roles;
 pub mod members;
 pub mod milestones;
 pub mod packages;
@@ -26,6 +36,14 @@ pub mod runners;
 mod share;
 pub mod subgroups;
 mod unshare;
+pub mod variables;
+pub mod wikis;
+
+pub use ci_baseline::ApplyCiBaseline;
+pub use ci_baseline::ApplyCiBaselineBuilder;
+pub use ci_baseline::ApplyCiBaselineBuilderError;
+pub use ci_baseline::CiBaselineReport;
+pub use ci_baseline::CiBaselineTarget;
 
 pub use create::BranchProtection;
 pub use create::BranchProtectionAccessLevel;
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for access requests to a group.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GroupAccessRequests<'a> {
     /// The group to query for pipelines.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> GroupAccessRequests<'a> {
     }
 }
 
+impl<'a> GroupAccessRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupAccessRequestsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GroupAccessRequests<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/access_requests", self.group).into()
+        format!("groups/{}/access_requests", self.group.path_segment()).into()
     }
 }
 
@@ -55,6 +64,12 @@ mod tests {
         GroupAccessRequests::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupAccessRequests::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupAccessRequestsBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Access levels for groups.
@@ -59,7 +59,7 @@ impl GroupAccessLevel {
 
 /// Submit approval for a user access request to a group
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupAccessRequestsApprove<'a> {
     /// The group to query for membership.
     #[builder(setter(into))]
@@ -80,6 +80,15 @@ impl<'a> GroupAccessRequestsApprove<'a> {
     }
 }
 
+impl<'a> GroupAccessRequestsApproveBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupAccessRequestsApproveBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user_id", self.user_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GroupAccessRequestsApprove<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -88,7 +97,8 @@ impl<'a> Endpoint for GroupAccessRequestsApprove<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "groups/{}/access_requests/{}/approve",
-            self.group, self.user_id,
+            self.group.path_segment(),
+            self.user_id.path_segment(),
         )
         .into()
     }
@@ -201,6 +211,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupAccessRequestsApprove::builder()
+            .group(0)
+            .user_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            GroupAccessRequestsApproveBuilderError,
+            "group"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
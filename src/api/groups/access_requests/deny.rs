@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Deny a user access request to a group
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GroupAccessRequestsDeny<'a> {
     /// The group to query for membership.
     #[builder(setter(into))]
@@ -27,13 +28,27 @@ impl<'a> GroupAccessRequestsDeny<'a> {
     }
 }
 
+impl<'a> GroupAccessRequestsDenyBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupAccessRequestsDenyBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user_id", self.user_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GroupAccessRequestsDeny<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/access_requests/{}", self.group, self.user_id).into()
+        format!(
+            "groups/{}/access_requests/{}",
+            self.group.path_segment(),
+            self.user_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -74,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupAccessRequestsDeny::builder()
+            .group(0)
+            .user_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupAccessRequestsDenyBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
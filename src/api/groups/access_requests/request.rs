@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Submit an access request to a group for the clients user token.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GroupAccessRequest<'a> {
     /// The group to query for pipelines.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> GroupAccessRequest<'a> {
     }
 }
 
+impl<'a> GroupAccessRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupAccessRequestBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GroupAccessRequest<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/access_requests", self.group).into()
+        format!("groups/{}/access_requests", self.group.path_segment()).into()
     }
 }
 
@@ -53,6 +62,12 @@ mod tests {
         GroupAccessRequest::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupAccessRequest::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupAccessRequestBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
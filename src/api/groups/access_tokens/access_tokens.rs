@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Get access tokens of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupAccessTokens<'a> {
+    /// The group for which to list tokens.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupAccessTokens<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupAccessTokensBuilder<'a> {
+        GroupAccessTokensBuilder::default()
+    }
+}
+
+impl<'a> GroupAccessTokensBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupAccessTokensBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupAccessTokens<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/access_tokens", self.group.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for GroupAccessTokens<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::access_tokens::{GroupAccessTokens, GroupAccessTokensBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_required() {
+        let err = GroupAccessTokens::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAccessTokensBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupAccessTokens::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupAccessTokens::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupAccessTokensBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("groups/1/access_tokens")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupAccessTokens::builder().group(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
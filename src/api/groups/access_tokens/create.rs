@@ -0,0 +1,353 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Scopes for personal access tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum GroupAccessTokenScope {
+    /// Access the API and perform git reads and writes.
+    Api,
+    /// Access read-only API endpoints.
+    ReadApi,
+    /// Read access to repositories.
+    ReadRepository,
+    /// Write access to repositories.
+    WriteRepository,
+    /// Read access to Docker registries.
+    ReadRegistry,
+    /// Write access to Docker registries.
+    WriteRegistry,
+    /// Permission to create instance runners.
+    CreateRunner,
+    /// Access to AI features (GitLab Duo for JetBrains).
+    AiFeatures,
+    /// Access to perform Kubernetes API calls.
+    K8sProxy,
+}
+
+impl GroupAccessTokenScope {
+    /// The scope as a query parameter.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Api => "api",
+            Self::ReadApi => "read_api",
+            Self::ReadRepository => "read_repository",
+            Self::WriteRepository => "write_repository",
+            Self::ReadRegistry => "read_registry",
+            Self::WriteRegistry => "write_registry",
+            Self::CreateRunner => "create_runner",
+            Self::AiFeatures => "ai_features",
+            Self::K8sProxy => "k8s_proxy",
+        }
+    }
+}
+
+impl ParamValue<'static> for GroupAccessTokenScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Access levels for group access tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum GroupAccessTokenAccessLevel {
+    /// Guest access (can see the group).
+    Guest,
+    /// Reporter access (can open issues).
+    Reporter,
+    /// Developer access (can push branches, handle issues and merge requests).
+    Developer,
+    /// Maintainer access (can push to protected branches).
+    Maintainer,
+    /// Owner access (full rights).
+    Owner,
+}
+
+impl From<GroupAccessTokenAccessLevel> for AccessLevel {
+    fn from(p: GroupAccessTokenAccessLevel) -> Self {
+        match p {
+            GroupAccessTokenAccessLevel::Guest => Self::Guest,
+            GroupAccessTokenAccessLevel::Reporter => Self::Reporter,
+            GroupAccessTokenAccessLevel::Developer => Self::Developer,
+            GroupAccessTokenAccessLevel::Maintainer => Self::Maintainer,
+            GroupAccessTokenAccessLevel::Owner => Self::Owner,
+        }
+    }
+}
+
+/// Create a new personal access token for the authenticated user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateGroupAccessToken<'a> {
+    /// The group to create the access token for.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the personal access token.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The scopes to allow the token to access.
+    #[builder(setter(name = "_scopes"), private)]
+    scopes: BTreeSet<GroupAccessTokenScope>,
+
+    /// When the token expires.
+    #[builder(default)]
+    access_level: Option<GroupAccessTokenAccessLevel>,
+    /// When the token expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> CreateGroupAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupAccessTokenBuilder<'a> {
+        CreateGroupAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupAccessTokenBuilder<'a> {
+    /// Add a scope for the token.
+    pub fn scope(&mut self, scope: GroupAccessTokenScope) -> &mut Self {
+        self.scopes.get_or_insert_with(BTreeSet::new).insert(scope);
+        self
+    }
+
+    /// Add scopes for the token.
+    pub fn scopes<I>(&mut self, scopes: I) -> &mut Self
+    where
+        I: Iterator<Item = GroupAccessTokenScope>,
+    {
+        self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateGroupAccessTokenBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateGroupAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/access_tokens", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push_opt(
+                "access_level",
+                self.access_level.map(|a| AccessLevel::from(a).as_u64()),
+            )
+            .push_opt("expires_at", self.expires_at);
+
+        params.extend(
+            self.scopes
+                .iter()
+                .map(|&value| (ParamName::new("scopes").array(), value)),
+        );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::groups::access_tokens::{
+        CreateGroupAccessToken, CreateGroupAccessTokenBuilderError, GroupAccessTokenAccessLevel,
+        GroupAccessTokenScope,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn personal_access_token_create_scope_as_str() {
+        let items = &[
+            (GroupAccessTokenScope::Api, "api"),
+            (GroupAccessTokenScope::ReadApi, "read_api"),
+            (GroupAccessTokenScope::ReadRepository, "read_repository"),
+            (GroupAccessTokenScope::WriteRepository, "write_repository"),
+            (GroupAccessTokenScope::ReadRegistry, "read_registry"),
+            (GroupAccessTokenScope::WriteRegistry, "write_registry"),
+            (GroupAccessTokenScope::CreateRunner, "create_runner"),
+            (GroupAccessTokenScope::AiFeatures, "ai_features"),
+            (GroupAccessTokenScope::K8sProxy, "k8s_proxy"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn group_token_access_level_from() {
+        use crate::api::common::AccessLevel;
+
+        let items = &[
+            (GroupAccessTokenAccessLevel::Guest, AccessLevel::Guest),
+            (GroupAccessTokenAccessLevel::Reporter, AccessLevel::Reporter),
+            (
+                GroupAccessTokenAccessLevel::Developer,
+                AccessLevel::Developer,
+            ),
+            (
+                GroupAccessTokenAccessLevel::Maintainer,
+                AccessLevel::Maintainer,
+            ),
+            (GroupAccessTokenAccessLevel::Owner, AccessLevel::Owner),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(AccessLevel::from(*i), *s);
+        }
+    }
+
+    #[test]
+    fn group_name_and_scopes_are_necessary() {
+        let err = CreateGroupAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = CreateGroupAccessToken::builder()
+            .name("name")
+            .scope(GroupAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = CreateGroupAccessToken::builder()
+            .group(1)
+            .scope(GroupAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "name");
+    }
+
+    #[test]
+    fn scopes_is_necessary() {
+        let err = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupAccessTokenBuilderError, "scopes");
+    }
+
+    #[test]
+    fn group_name_and_scopes_are_sufficient() {
+        CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scope(GroupAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateGroupAccessToken::builder()
+            .group(0)
+            .name("name")
+            .scope(GroupAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/1/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&scopes%5B%5D=k8s_proxy"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scopes([GroupAccessTokenScope::K8sProxy].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/1/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&access_level=30",
+                "&scopes%5B%5D=k8s_proxy",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scope(GroupAccessTokenScope::K8sProxy)
+            .access_level(GroupAccessTokenAccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/1/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&expires_at=2022-01-01",
+                "&scopes%5B%5D=k8s_proxy",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupAccessToken::builder()
+            .group(1)
+            .name("name")
+            .scope(GroupAccessTokenScope::K8sProxy)
+            .expires_at(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Revoke a group access token.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RevokeGroupAccessToken<'a> {
+    /// The ID of the group.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the token to delete.
+    id: u64,
+}
+
+impl<'a> RevokeGroupAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RevokeGroupAccessTokenBuilder<'a> {
+        RevokeGroupAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> RevokeGroupAccessTokenBuilder<'a> {
+    fn validate(&self) -> Result<(), RevokeGroupAccessTokenBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for RevokeGroupAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/access_tokens/{}",
+            self.group.path_segment(),
+            self.id.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::access_tokens::{
+        RevokeGroupAccessToken, RevokeGroupAccessTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_id_is_necessary() {
+        let err = RevokeGroupAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RevokeGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = RevokeGroupAccessToken::builder().id(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, RevokeGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn id_is_necessary() {
+        let err = RevokeGroupAccessToken::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RevokeGroupAccessTokenBuilderError, "id");
+    }
+
+    #[test]
+    fn group_and_id_are_sufficient() {
+        RevokeGroupAccessToken::builder()
+            .group(1)
+            .id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = RevokeGroupAccessToken::builder()
+            .group(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RevokeGroupAccessTokenBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/1/access_tokens/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RevokeGroupAccessToken::builder()
+            .group(1)
+            .id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
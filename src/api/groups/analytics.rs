@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group analytics API endpoints.
+//!
+//! These endpoints are used for querying analytics of a group.
+
+mod dora;
+mod issues_count;
+mod merge_requests_count;
+mod new_members_count;
+
+pub use self::dora::GroupDoraMetrics;
+pub use self::dora::GroupDoraMetricsBuilder;
+pub use self::dora::GroupDoraMetricsBuilderError;
+
+pub use self::issues_count::GroupActivityIssuesCount;
+pub use self::issues_count::GroupActivityIssuesCountBuilder;
+pub use self::issues_count::GroupActivityIssuesCountBuilderError;
+
+pub use self::merge_requests_count::GroupActivityMergeRequestsCount;
+pub use self::merge_requests_count::GroupActivityMergeRequestsCountBuilder;
+pub use self::merge_requests_count::GroupActivityMergeRequestsCountBuilderError;
+
+pub use self::new_members_count::GroupActivityNewMembersCount;
+pub use self::new_members_count::GroupActivityNewMembersCountBuilder;
+pub use self::new_members_count::GroupActivityNewMembersCountBuilderError;
@@ -0,0 +1,259 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::analytics::{DoraMetric, DoraMetricInterval};
+use crate::api::projects::environments::EnvironmentTier;
+
+/// Query for a DORA metric of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupDoraMetrics<'a> {
+    /// The group to query for metrics.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The metric to query.
+    metric: DoraMetric,
+
+    /// The first date of the range to query.
+    ///
+    /// Defaults to 3 months ago.
+    #[builder(default)]
+    start_date: Option<NaiveDate>,
+    /// The last date of the range to query.
+    ///
+    /// Defaults to the current date.
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    /// The interval to aggregate data over.
+    ///
+    /// Defaults to `daily`.
+    #[builder(default)]
+    interval: Option<DoraMetricInterval>,
+    /// The tiers of environments to consider.
+    ///
+    /// Defaults to `production`.
+    #[builder(setter(name = "_environment_tiers"), default, private)]
+    environment_tiers: BTreeSet<EnvironmentTier>,
+}
+
+impl<'a> GroupDoraMetrics<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupDoraMetricsBuilder<'a> {
+        GroupDoraMetricsBuilder::default()
+    }
+}
+
+impl<'a> GroupDoraMetricsBuilder<'a> {
+    /// Consider environments of a tier.
+    pub fn environment_tier(&mut self, tier: EnvironmentTier) -> &mut Self {
+        self.environment_tiers
+            .get_or_insert_with(BTreeSet::new)
+            .insert(tier);
+        self
+    }
+
+    /// Consider environments of a set of tiers.
+    pub fn environment_tiers<I>(&mut self, tiers: I) -> &mut Self
+    where
+        I: Iterator<Item = EnvironmentTier>,
+    {
+        self.environment_tiers
+            .get_or_insert_with(BTreeSet::new)
+            .extend(tiers);
+        self
+    }
+
+    fn validate(&self) -> Result<(), GroupDoraMetricsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupDoraMetrics<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/dora/metrics", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("metric", self.metric)
+            .push_opt("start_date", self.start_date)
+            .push_opt("end_date", self.end_date)
+            .push_opt("interval", self.interval)
+            .extend(
+                self.environment_tiers
+                    .iter()
+                    .map(|&value| (ParamName::new("environment_tiers").array(), value)),
+            );
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::api::groups::analytics::{GroupDoraMetrics, GroupDoraMetricsBuilderError};
+    use crate::api::projects::analytics::{DoraMetric, DoraMetricInterval};
+    use crate::api::projects::environments::EnvironmentTier;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_metric_are_needed() {
+        let err = GroupDoraMetrics::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupDoraMetricsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupDoraMetrics::builder()
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupDoraMetricsBuilderError, "group");
+    }
+
+    #[test]
+    fn metric_is_needed() {
+        let err = GroupDoraMetrics::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupDoraMetricsBuilderError, "metric");
+    }
+
+    #[test]
+    fn group_and_metric_are_sufficient() {
+        GroupDoraMetrics::builder()
+            .group(1)
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupDoraMetrics::builder()
+            .group(0)
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupDoraMetricsBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/dora/metrics")
+            .add_query_params(&[("metric", "deployment_frequency")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupDoraMetrics::builder()
+            .group("simple/group")
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_start_date() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/dora/metrics")
+            .add_query_params(&[
+                ("metric", "lead_time_for_changes"),
+                ("start_date", "2024-01-01"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupDoraMetrics::builder()
+            .group("simple/group")
+            .metric(DoraMetric::LeadTimeForChanges)
+            .start_date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_end_date() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/dora/metrics")
+            .add_query_params(&[
+                ("metric", "lead_time_for_changes"),
+                ("end_date", "2024-03-31"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupDoraMetrics::builder()
+            .group("simple/group")
+            .metric(DoraMetric::LeadTimeForChanges)
+            .end_date(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_interval() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/dora/metrics")
+            .add_query_params(&[("metric", "change_failure_rate"), ("interval", "monthly")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupDoraMetrics::builder()
+            .group("simple/group")
+            .metric(DoraMetric::ChangeFailureRate)
+            .interval(DoraMetricInterval::Monthly)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_environment_tiers() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/dora/metrics")
+            .add_query_params(&[
+                ("metric", "time_to_restore_service"),
+                ("environment_tiers[]", "production"),
+                ("environment_tiers[]", "staging"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupDoraMetrics::builder()
+            .group("simple/group")
+            .metric(DoraMetric::TimeToRestoreService)
+            .environment_tier(EnvironmentTier::Staging)
+            .environment_tiers([EnvironmentTier::Production, EnvironmentTier::Staging].into_iter())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the number of merge requests created in a group within the last 90 days.
+#[derive(Debug, Builder, Clone)]
+pub struct GroupActivityMergeRequestsCount<'a> {
+    /// The full path of the group to query.
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+}
+
+impl<'a> GroupActivityMergeRequestsCount<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupActivityMergeRequestsCountBuilder<'a> {
+        GroupActivityMergeRequestsCountBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for GroupActivityMergeRequestsCount<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "analytics/group_activity/merge_requests_count".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push("group_path", &self.group);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::analytics::{
+        GroupActivityMergeRequestsCount, GroupActivityMergeRequestsCountBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupActivityMergeRequestsCount::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            GroupActivityMergeRequestsCountBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupActivityMergeRequestsCount::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("analytics/group_activity/merge_requests_count")
+            .add_query_params(&[("group_path", "simple/group")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupActivityMergeRequestsCount::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
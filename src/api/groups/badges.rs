@@ -0,0 +1,44 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group badge API endpoints.
+//!
+//! These endpoints are used for querying and managing badges for a group.
+
+mod badge;
+mod badges;
+mod create;
+mod delete;
+mod edit;
+mod preview;
+
+pub use self::badge::Badge;
+pub use self::badge::BadgeBuilder;
+pub use self::badge::BadgeBuilderError;
+
+pub use self::badges::Badges;
+pub use self::badges::BadgesBuilder;
+pub use self::badges::BadgesBuilderError;
+
+pub use self::create::CreateBadge;
+pub use self::create::CreateBadgeBuilder;
+pub use self::create::CreateBadgeBuilderError;
+
+pub use self::delete::DeleteBadge;
+pub use self::delete::DeleteBadgeBuilder;
+pub use self::delete::DeleteBadgeBuilderError;
+
+pub use self::edit::EditBadge;
+pub use self::edit::EditBadgeBuilder;
+pub use self::edit::EditBadgeBuilderError;
+
+pub use self::preview::PreviewBadge;
+pub use self::preview::PreviewBadgeBuilder;
+pub use self::preview::PreviewBadgeBuilderError;
+
+pub use crate::api::helpers::BadgePlaceholders;
+pub use crate::api::helpers::BadgePlaceholdersBuilder;
+pub use crate::api::helpers::BadgePlaceholdersBuilderError;
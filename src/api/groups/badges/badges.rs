@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for badges within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Badges<'a> {
+    /// The group to query for badges.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Filter badges by name.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> Badges<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BadgesBuilder<'a> {
+        BadgesBuilder::default()
+    }
+}
+
+impl<'a> BadgesBuilder<'a> {
+    fn validate(&self) -> Result<(), BadgesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Badges<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("name", self.name.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for Badges<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::badges::{Badges, BadgesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = Badges::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BadgesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        Badges::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = Badges::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BadgesBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/badges")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Badges::builder().group("group/subgroup").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/badges")
+            .add_query_params(&[("name", "coverage")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Badges::builder()
+            .group("group/subgroup")
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::helpers;
+
+/// Create a new badge on a group.
+///
+/// Placeholders in the URLs are checked against those supported by GitLab.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateBadge<'a> {
+    /// The group to create the badge within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The URL the badge links to.
+    #[builder(setter(into))]
+    link_url: Cow<'a, str>,
+    /// The URL of the badge image.
+    #[builder(setter(into))]
+    image_url: Cow<'a, str>,
+
+    /// The name of the badge.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateBadgeBuilder<'a> {
+        CreateBadgeBuilder::default()
+    }
+}
+
+impl<'a> CreateBadgeBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateBadgeBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        helpers::validate_badge_url("link_url", self.link_url.as_ref())?;
+        helpers::validate_badge_url("image_url", self.image_url.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateBadge<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/badges", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("link_url", &self.link_url)
+            .push("image_url", &self.image_url)
+            .push_opt("name", self.name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::badges::{CreateBadge, CreateBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_link_url_and_image_url_are_needed() {
+        let err = CreateBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateBadge::builder()
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn link_url_is_needed() {
+        let err = CreateBadge::builder()
+            .group(1)
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_is_needed() {
+        let err = CreateBadge::builder()
+            .group(1)
+            .link_url("https://example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn group_link_url_and_image_url_are_sufficient() {
+        CreateBadge::builder()
+            .group(1)
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateBadge::builder()
+            .group(0)
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn link_url_placeholders_are_validated() {
+        let err = CreateBadge::builder()
+            .group(1)
+            .link_url("https://example.com/%{unknown}")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_placeholders_are_validated() {
+        let err = CreateBadge::builder()
+            .group(1)
+            .link_url("https://example.com")
+            .image_url("https://example.com/%{unknown}/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/group%2Fsubgroup/badges")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "link_url=https%3A%2F%2Fexample.com%2F%25%7Bproject_path%7D",
+                "&image_url=https%3A%2F%2Fexample.com%2Fbadge.svg",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBadge::builder()
+            .group("group/subgroup")
+            .link_url("https://example.com/%{project_path}")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/group%2Fsubgroup/badges")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "link_url=https%3A%2F%2Fexample.com",
+                "&image_url=https%3A%2F%2Fexample.com%2Fbadge.svg",
+                "&name=coverage",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBadge::builder()
+            .group("group/subgroup")
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
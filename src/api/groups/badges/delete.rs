@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a badge from a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteBadge<'a> {
+    /// The group to delete the badge from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+}
+
+impl<'a> DeleteBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteBadgeBuilder<'a> {
+        DeleteBadgeBuilder::default()
+    }
+}
+
+impl<'a> DeleteBadgeBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteBadgeBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("badge", self.badge.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteBadge<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/badges/{}",
+            self.group.path_segment(),
+            self.badge.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::badges::{DeleteBadge, DeleteBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_badge_are_needed() {
+        let err = DeleteBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = DeleteBadge::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn group_and_badge_are_sufficient() {
+        DeleteBadge::builder().group(1).badge(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteBadge::builder()
+            .group(0)
+            .badge(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteBadgeBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/group%2Fsubgroup/badges/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteBadge::builder()
+            .group("group/subgroup")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
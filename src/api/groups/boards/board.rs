@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for an issue board within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Board<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+}
+
+impl<'a> Board<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BoardBuilder<'a> {
+        BoardBuilder::default()
+    }
+}
+
+impl<'a> BoardBuilder<'a> {
+    fn validate(&self) -> Result<(), BoardBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Board<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/boards/{}",
+            self.group.path_segment(),
+            self.board.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::boards::{Board, BoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = Board::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = Board::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = Board::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        Board::builder().group(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = Board::builder().group(0).board(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BoardBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Board::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for issue boards within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Boards<'a> {
+    /// The group to query for boards.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> Boards<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BoardsBuilder<'a> {
+        BoardsBuilder::default()
+    }
+}
+
+impl<'a> BoardsBuilder<'a> {
+    fn validate(&self) -> Result<(), BoardsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Boards<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards", self.group.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for Boards<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::boards::{Boards, BoardsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = Boards::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        Boards::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = Boards::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BoardsBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/boards")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Boards::builder().group("simple/group").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::boards::BoardListType;
+
+/// Create a list on an issue board within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateBoardList<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+    /// The issues to collect in the list.
+    list_type: BoardListType,
+}
+
+impl<'a> CreateBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateBoardListBuilder<'a> {
+        CreateBoardListBuilder::default()
+    }
+}
+
+impl<'a> CreateBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateBoardListBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/boards/{}/lists",
+            self.group.path_segment(),
+            self.board.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        self.list_type.add_params(&mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::{BoardListType, CreateBoardList, CreateBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateBoardList::builder()
+            .board(1)
+            .list_type(BoardListType::Label(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = CreateBoardList::builder()
+            .group(1)
+            .list_type(BoardListType::Label(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_type_is_needed() {
+        let err = CreateBoardList::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardListBuilderError, "list_type");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        CreateBoardList::builder()
+            .group(1)
+            .board(1)
+            .list_type(BoardListType::Label(1))
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateBoardList::builder()
+            .group(0)
+            .board(1)
+            .list_type(BoardListType::Label(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let items = &[
+            (BoardListType::Label(2), "label_id=2"),
+            (BoardListType::Assignee(3), "assignee_id=3"),
+            (BoardListType::Milestone(4), "milestone_id=4"),
+            (BoardListType::Iteration(5), "iteration_id=5"),
+        ];
+
+        for (list_type, body) in items {
+            let endpoint = ExpectedUrl::builder()
+                .method(Method::POST)
+                .endpoint("groups/simple%2Fgroup/boards/1/lists")
+                .content_type("application/x-www-form-urlencoded")
+                .body_str(body)
+                .build()
+                .unwrap();
+            let client = SingleTestClient::new_raw(endpoint, "");
+
+            let endpoint = CreateBoardList::builder()
+                .group("simple/group")
+                .board(1)
+                .list_type(*list_type)
+                .build()
+                .unwrap();
+            api::ignore(endpoint).query(&client).unwrap();
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a list from an issue board within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteBoardList<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+}
+
+impl<'a> DeleteBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteBoardListBuilder<'a> {
+        DeleteBoardListBuilder::default()
+    }
+}
+
+impl<'a> DeleteBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteBoardListBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+        common::validate_path_param("list", self.list.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/boards/{}/lists/{}",
+            self.group.path_segment(),
+            self.board.path_segment(),
+            self.list.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::{DeleteBoardList, DeleteBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = DeleteBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteBoardList::builder()
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = DeleteBoardList::builder()
+            .group(1)
+            .list(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_needed() {
+        let err = DeleteBoardList::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        DeleteBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteBoardList::builder()
+            .group(0)
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,169 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Move a list on an issue board within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct EditBoardList<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+    /// The new position of the list.
+    position: u64,
+}
+
+impl<'a> EditBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditBoardListBuilder<'a> {
+        EditBoardListBuilder::default()
+    }
+}
+
+impl<'a> EditBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), EditBoardListBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+        common::validate_path_param("list", self.list.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/boards/{}/lists/{}",
+            self.group.path_segment(),
+            self.board.path_segment(),
+            self.list.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("position", self.position);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::{EditBoardList, EditBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = EditBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditBoardList::builder()
+            .board(1)
+            .list(2)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = EditBoardList::builder()
+            .group(1)
+            .list(2)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_needed() {
+        let err = EditBoardList::builder()
+            .group(1)
+            .board(1)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn position_is_needed() {
+        let err = EditBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardListBuilderError, "position");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        EditBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(2)
+            .position(0)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditBoardList::builder()
+            .group(0)
+            .board(1)
+            .list(2)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("position=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .list(2)
+            .position(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
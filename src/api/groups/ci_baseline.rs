@@ -0,0 +1,626 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::groups::projects::GroupProjects;
+use crate::api::groups::subgroups::GroupSubgroups;
+use crate::api::groups::{BranchProtectionDefaults, EditGroup, Group, SharedRunnersSetting};
+use crate::api::projects::{EditProject, SquashOption};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// A group or project which the CI baseline is applied to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CiBaselineTarget {
+    /// A group.
+    Group {
+        /// The ID of the group.
+        id: u64,
+        /// The full path of the group.
+        full_path: String,
+    },
+    /// A project.
+    Project {
+        /// The ID of the project.
+        id: u64,
+        /// The full path of the project.
+        path_with_namespace: String,
+    },
+}
+
+/// The result of applying a CI baseline to the targets within a group.
+///
+/// Each target has its own result so that a failure for one target (e.g., due to insufficient
+/// permissions) does not prevent the baseline from being applied elsewhere.
+#[derive(Debug)]
+pub struct CiBaselineReport<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    entries: Vec<(CiBaselineTarget, Result<(), ApiError<E>>)>,
+}
+
+impl<E> CiBaselineReport<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The result for each target, in the order they were processed.
+    pub fn entries(&self) -> &[(CiBaselineTarget, Result<(), ApiError<E>>)] {
+        &self.entries
+    }
+
+    /// Extract the result for each target.
+    pub fn into_entries(self) -> Vec<(CiBaselineTarget, Result<(), ApiError<E>>)> {
+        self.entries
+    }
+
+    /// The targets which were updated successfully.
+    pub fn succeeded(&self) -> impl Iterator<Item = &CiBaselineTarget> {
+        self.entries
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .map(|(target, _)| target)
+    }
+
+    /// The targets which could not be updated along with the reason.
+    pub fn failed(&self) -> impl Iterator<Item = (&CiBaselineTarget, &ApiError<E>)> {
+        self.entries
+            .iter()
+            .filter_map(|(target, result)| result.as_ref().err().map(|err| (target, err)))
+    }
+
+    /// Whether all targets were updated successfully.
+    pub fn is_success(&self) -> bool {
+        self.entries.iter().all(|(_, result)| result.is_ok())
+    }
+}
+
+/// The fields of a group needed to apply the baseline.
+#[derive(Debug, Deserialize)]
+struct GroupInfo {
+    id: u64,
+    full_path: String,
+}
+
+/// The fields of a project needed to apply the baseline.
+#[derive(Debug, Deserialize)]
+struct ProjectInfo {
+    id: u64,
+    path_with_namespace: String,
+}
+
+/// Apply a standard CI baseline to a group, its subgroups, and their projects.
+///
+/// Group settings are applied to the group (unless `include_group` is disabled) and all of its
+/// descendant groups. Project settings are applied to all unarchived projects within the group
+/// hierarchy. Settings which are not given are left as-is and targets are only edited if at least
+/// one setting applies to them.
+///
+/// Listing the targets must succeed for the helper to proceed. Afterwards, each target is edited
+/// independently and the result for each is collected into the report. In dry-run mode, the
+/// targets are listed and reported as successful without being modified.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ApplyCiBaseline<'a> {
+    /// The group to apply the baseline to.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// The shared runner setting for groups.
+    #[builder(default)]
+    shared_runners_setting: Option<SharedRunnersSetting>,
+    /// The default branch protection rules for new projects in groups.
+    #[builder(default)]
+    default_branch_protection_defaults: Option<BranchProtectionDefaults>,
+
+    /// Whether shared runners are enabled for projects.
+    #[builder(default)]
+    shared_runners_enabled: Option<bool>,
+    /// Whether merge requests require a successful pipeline to merge.
+    #[builder(default)]
+    only_allow_merge_if_pipeline_succeeds: Option<bool>,
+    /// Whether merge requests require all discussions to be resolved to merge.
+    #[builder(default)]
+    only_allow_merge_if_all_discussions_are_resolved: Option<bool>,
+    /// The squash option for projects.
+    #[builder(default)]
+    squash_option: Option<SquashOption>,
+
+    /// Apply the group settings to the group itself as well as its subgroups.
+    #[builder(default = "true")]
+    include_group: bool,
+    /// Only report the targets which would be edited.
+    #[builder(default)]
+    dry_run: bool,
+}
+
+impl<'a> ApplyCiBaseline<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> ApplyCiBaselineBuilder<'a> {
+        ApplyCiBaselineBuilder::default()
+    }
+
+    fn has_group_settings(&self) -> bool {
+        self.shared_runners_setting.is_some() || self.default_branch_protection_defaults.is_some()
+    }
+
+    fn has_project_settings(&self) -> bool {
+        self.shared_runners_enabled.is_some()
+            || self.only_allow_merge_if_pipeline_succeeds.is_some()
+            || self
+                .only_allow_merge_if_all_discussions_are_resolved
+                .is_some()
+            || self.squash_option.is_some()
+    }
+
+    fn group_query<E>(&self) -> Result<Group<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Group::builder()
+            .group(self.group.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn subgroups_query<E>(group: NameOrId<'a>) -> Result<GroupSubgroups<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        GroupSubgroups::builder()
+            .group(group)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn projects_query<E>(&self) -> Result<GroupProjects<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        GroupProjects::builder()
+            .group(self.group.clone())
+            .include_subgroups(true)
+            .archived(false)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn edit_group<E>(&self, group: u64) -> Result<EditGroup<'static>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let mut builder = EditGroup::builder();
+        builder.group(group);
+        if let Some(setting) = self.shared_runners_setting {
+            builder.shared_runners_setting(setting);
+        }
+        if let Some(defaults) = self.default_branch_protection_defaults.as_ref() {
+            builder.default_branch_protection_defaults(defaults.clone());
+        }
+        builder.build().map_err(ApiError::invalid_endpoint)
+    }
+
+    fn edit_project<E>(&self, project: u64) -> Result<EditProject<'static>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let mut builder = EditProject::builder();
+        builder.project(project);
+        if let Some(enabled) = self.shared_runners_enabled {
+            builder.shared_runners_enabled(enabled);
+        }
+        if let Some(required) = self.only_allow_merge_if_pipeline_succeeds {
+            builder.only_allow_merge_if_pipeline_succeeds(required);
+        }
+        if let Some(required) = self.only_allow_merge_if_all_discussions_are_resolved {
+            builder.only_allow_merge_if_all_discussions_are_resolved(required);
+        }
+        if let Some(option) = self.squash_option {
+            builder.squash_option(option);
+        }
+        builder.build().map_err(ApiError::invalid_endpoint)
+    }
+}
+
+impl<'a, C> Query<CiBaselineReport<C::Error>, C> for ApplyCiBaseline<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<CiBaselineReport<C::Error>, ApiError<C::Error>> {
+        let mut groups = Vec::new();
+        if self.has_group_settings() {
+            if self.include_group {
+                groups.push(self.group_query()?.query(client)?);
+            }
+            let mut pending = vec![self.group.clone()];
+            while let Some(parent) = pending.pop() {
+                let subgroups: Vec<GroupInfo> =
+                    api::paged(Self::subgroups_query(parent)?, Pagination::All).query(client)?;
+                pending.extend(subgroups.iter().map(|group| group.id.into()));
+                groups.extend(subgroups);
+            }
+        }
+
+        let projects: Vec<ProjectInfo> = if self.has_project_settings() {
+            api::paged(self.projects_query()?, Pagination::All).query(client)?
+        } else {
+            Vec::new()
+        };
+
+        let mut entries = Vec::new();
+        for group in groups {
+            let result = if self.dry_run {
+                Ok(())
+            } else {
+                self.edit_group(group.id)
+                    .and_then(|endpoint| api::ignore(endpoint).query(client))
+            };
+            entries.push((
+                CiBaselineTarget::Group {
+                    id: group.id,
+                    full_path: group.full_path,
+                },
+                result,
+            ));
+        }
+        for project in projects {
+            let result = if self.dry_run {
+                Ok(())
+            } else {
+                self.edit_project(project.id)
+                    .and_then(|endpoint| api::ignore(endpoint).query(client))
+            };
+            entries.push((
+                CiBaselineTarget::Project {
+                    id: project.id,
+                    path_with_namespace: project.path_with_namespace,
+                },
+                result,
+            ));
+        }
+
+        Ok(CiBaselineReport {
+            entries,
+        })
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<CiBaselineReport<C::Error>, C> for ApplyCiBaseline<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(
+        &self,
+        client: &C,
+    ) -> Result<CiBaselineReport<C::Error>, ApiError<C::Error>> {
+        let mut groups = Vec::new();
+        if self.has_group_settings() {
+            if self.include_group {
+                groups.push(self.group_query()?.query_async(client).await?);
+            }
+            let mut pending = vec![self.group.clone()];
+            while let Some(parent) = pending.pop() {
+                let subgroups: Vec<GroupInfo> =
+                    api::paged(Self::subgroups_query(parent)?, Pagination::All)
+                        .query_async(client)
+                        .await?;
+                pending.extend(subgroups.iter().map(|group| group.id.into()));
+                groups.extend(subgroups);
+            }
+        }
+
+        let projects: Vec<ProjectInfo> = if self.has_project_settings() {
+            api::paged(self.projects_query()?, Pagination::All)
+                .query_async(client)
+                .await?
+        } else {
+            Vec::new()
+        };
+
+        let mut entries = Vec::new();
+        for group in groups {
+            let result = if self.dry_run {
+                Ok(())
+            } else {
+                match self.edit_group(group.id) {
+                    Ok(endpoint) => api::ignore(endpoint).query_async(client).await,
+                    Err(err) => Err(err),
+                }
+            };
+            entries.push((
+                CiBaselineTarget::Group {
+                    id: group.id,
+                    full_path: group.full_path,
+                },
+                result,
+            ));
+        }
+        for project in projects {
+            let result = if self.dry_run {
+                Ok(())
+            } else {
+                match self.edit_project(project.id) {
+                    Ok(endpoint) => api::ignore(endpoint).query_async(client).await,
+                    Err(err) => Err(err),
+                }
+            };
+            entries.push((
+                CiBaselineTarget::Project {
+                    id: project.id,
+                    path_with_namespace: project.path_with_namespace,
+                },
+                result,
+            ));
+        }
+
+        Ok(CiBaselineReport {
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::api::groups::{
+        ApplyCiBaseline, ApplyCiBaselineBuilderError, CiBaselineReport, CiBaselineTarget,
+        SharedRunnersSetting,
+    };
+    use crate::api::projects::SquashOption;
+    use crate::api::{ApiError, AsyncQuery, Query};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    #[test]
+    fn group_is_needed() {
+        let err = ApplyCiBaseline::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ApplyCiBaselineBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        ApplyCiBaseline::builder().group(1).build().unwrap();
+    }
+
+    fn listing(client: &MockClient) {
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("groups/simple%2Fgroup")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"id": 1, "full_path": "simple/group"})),
+            )
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("groups/simple%2Fgroup/subgroups")
+                    .build()
+                    .unwrap(),
+                [json!({"id": 2, "full_path": "simple/group/sub"})],
+            )
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("groups/2/subgroups")
+                    .build()
+                    .unwrap(),
+                Vec::<serde_json::Value>::new(),
+            )
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("groups/simple%2Fgroup/projects")
+                    .query_param("archived", "false")
+                    .query_param("include_subgroups", "true")
+                    .build()
+                    .unwrap(),
+                [
+                    json!({"id": 10, "path_with_namespace": "simple/group/project"}),
+                    json!({"id": 11, "path_with_namespace": "simple/group/sub/project"}),
+                ],
+            );
+    }
+
+    fn mock_client() -> MockClient {
+        let client = MockClient::new();
+        listing(&client);
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::PUT)
+                    .endpoint("groups/1")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str("shared_runners_setting=enabled")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"id": 1})),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::PUT)
+                    .endpoint("groups/2")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str("shared_runners_setting=enabled")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"id": 2})),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::PUT)
+                    .endpoint("projects/10")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str(concat!(
+                        "only_allow_merge_if_pipeline_succeeds=true",
+                        "&squash_option=default_on",
+                    ))
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"id": 10})),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::PUT)
+                    .endpoint("projects/11")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str(concat!(
+                        "only_allow_merge_if_pipeline_succeeds=true",
+                        "&squash_option=default_on",
+                    ))
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"message": "403 Forbidden"}))
+                    .status(StatusCode::FORBIDDEN),
+            );
+        client
+    }
+
+    fn baseline(dry_run: bool) -> ApplyCiBaseline<'static> {
+        ApplyCiBaseline::builder()
+            .group("simple/group")
+            .shared_runners_setting(SharedRunnersSetting::Enabled)
+            .only_allow_merge_if_pipeline_succeeds(true)
+            .squash_option(SquashOption::DefaultOn)
+            .dry_run(dry_run)
+            .build()
+            .unwrap()
+    }
+
+    fn targets() -> Vec<CiBaselineTarget> {
+        vec![
+            CiBaselineTarget::Group {
+                id: 1,
+                full_path: "simple/group".into(),
+            },
+            CiBaselineTarget::Group {
+                id: 2,
+                full_path: "simple/group/sub".into(),
+            },
+            CiBaselineTarget::Project {
+                id: 10,
+                path_with_namespace: "simple/group/project".into(),
+            },
+            CiBaselineTarget::Project {
+                id: 11,
+                path_with_namespace: "simple/group/sub/project".into(),
+            },
+        ]
+    }
+
+    fn check<E>(report: CiBaselineReport<E>)
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let all = targets();
+        assert!(!report.is_success());
+        assert_eq!(report.succeeded().cloned().collect::<Vec<_>>(), &all[..3],);
+        let failed = report.failed().collect::<Vec<_>>();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, &all[3]);
+        if let ApiError::Gitlab {
+            msg,
+        } = failed[0].1
+        {
+            assert_eq!(msg, "403 Forbidden");
+        } else {
+            panic!("unexpected error: {}", failed[0].1);
+        }
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = mock_client();
+        let report = baseline(false).query(&client).unwrap();
+        check(report);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn endpoint_async() {
+        let client = mock_client();
+        let report = baseline(false).query_async(&client).await.unwrap();
+        check(report);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let client = MockClient::new();
+        listing(&client);
+        let report = baseline(true).query(&client).unwrap();
+        assert!(report.is_success());
+        let entries = report
+            .into_entries()
+            .into_iter()
+            .map(|(target, _)| target)
+            .collect::<Vec<_>>();
+        assert_eq!(entries, targets());
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_projects_only() {
+        let client = MockClient::new();
+        client
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("groups/simple%2Fgroup/projects")
+                    .query_param("archived", "false")
+                    .query_param("include_subgroups", "true")
+                    .build()
+                    .unwrap(),
+                [json!({"id": 10, "path_with_namespace": "simple/group/project"})],
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::PUT)
+                    .endpoint("projects/10")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str("shared_runners_enabled=false")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"id": 10})),
+            );
+
+        let report = ApplyCiBaseline::builder()
+            .group("simple/group")
+            .shared_runners_enabled(false)
+            .build()
+            .unwrap()
+            .query(&client)
+            .unwrap();
+        assert!(report.is_success());
+        assert_eq!(report.entries().len(), 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_invalid_group() {
+        let client = MockClient::new();
+
+        let err = ApplyCiBaseline::builder()
+            .group("")
+            .shared_runners_setting(SharedRunnersSetting::Enabled)
+            .build()
+            .unwrap()
+            .query(&client)
+            .unwrap_err();
+        if let ApiError::InvalidEndpoint {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "invalid `group`: names must not be empty");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -130,6 +130,10 @@ impl ParamValue<'static> for BranchProtectionAccessLevel {
     }
 }
 
+fn defaults_param(field: &str) -> ParamName {
+    ParamName::new("default_branch_protection_defaults").key(field)
+}
+
 /// Branch protection rule defaults for groups.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(strip_option))]
@@ -156,24 +160,19 @@ impl BranchProtectionDefaults {
 
     pub(crate) fn add_query<'b>(&'b self, params: &mut FormParams<'b>) {
         params
-            .extend(self.allowed_to_push.iter().map(|&value| {
-                (
-                    "default_branch_protection_defaults[allowed_to_push][]",
-                    value,
-                )
-            }))
-            .push_opt(
-                "default_branch_protection_defaults[allow_force_push]",
-                self.allow_force_push,
+            .extend(
+                self.allowed_to_push
+                    .iter()
+                    .map(|&value| (defaults_param("allowed_to_push").array(), value)),
+            )
+            .push_opt(defaults_param("allow_force_push"), self.allow_force_push)
+            .extend(
+                self.allowed_to_merge
+                    .iter()
+                    .map(|&value| (defaults_param("allowed_to_merge").array(), value)),
             )
-            .extend(self.allowed_to_merge.iter().map(|&value| {
-                (
-                    "default_branch_protection_defaults[allowed_to_merge][]",
-                    value,
-                )
-            }))
             .push_opt(
-                "default_branch_protection_defaults[developer_can_initial_push]",
+                defaults_param("developer_can_initial_push"),
                 self.developer_can_initial_push,
             );
     }
@@ -499,8 +498,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path"))
+            .form_params(&[("name", "name"), ("path", "path")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -518,12 +516,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&description=description",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("description", "description"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -542,8 +539,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path", "&membership_lock=true"))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("membership_lock", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -562,8 +562,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path", "&visibility=internal"))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("visibility", "internal"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -582,12 +585,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&share_with_group_lock=true",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("share_with_group_lock", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -606,12 +608,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&require_two_factor_authentication=true",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("require_two_factor_authentication", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -630,12 +631,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&two_factor_grace_period=1",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("two_factor_grace_period", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -654,12 +654,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&project_creation_level=maintainer",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("project_creation_level", "maintainer"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -678,12 +677,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&auto_devops_enabled=false",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("auto_devops_enabled", "false"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -702,12 +700,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&subgroup_creation_level=owner",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("subgroup_creation_level", "owner"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -727,8 +724,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path", "&emails_disabled=false"))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("emails_disabled", "false"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -747,8 +747,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path", "&emails_enabled=false"))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("emails_enabled", "false"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -767,12 +770,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&mentions_disabled=true",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("mentions_disabled", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -791,8 +793,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path", "&lfs_enabled=true"))
+            .form_params(&[("name", "name"), ("path", "path"), ("lfs_enabled", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -811,12 +812,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&request_access_enabled=true",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("request_access_enabled", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -835,8 +835,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("name=name", "&path=path", "&parent_id=1"))
+            .form_params(&[("name", "name"), ("path", "path"), ("parent_id", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -855,12 +854,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&default_branch_protection=2",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("default_branch_protection", "2"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -879,12 +877,14 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&default_branch_protection_defaults%5Ballowed_to_push%5D%5B%5D=30",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                (
+                    "default_branch_protection_defaults[allowed_to_push][]",
+                    "30",
+                ),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -910,12 +910,14 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&default_branch_protection_defaults%5Ballow_force_push%5D=true",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                (
+                    "default_branch_protection_defaults[allow_force_push]",
+                    "true",
+                ),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -939,12 +941,14 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&default_branch_protection_defaults%5Ballowed_to_merge%5D%5B%5D=30",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                (
+                    "default_branch_protection_defaults[allowed_to_merge][]",
+                    "30",
+                ),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -970,12 +974,14 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&default_branch_protection_defaults%5Bdeveloper_can_initial_push%5D=true",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                (
+                    "default_branch_protection_defaults[developer_can_initial_push]",
+                    "true",
+                ),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -999,12 +1005,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&shared_runners_minutes_limit=0",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("shared_runners_minutes_limit", "0"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1023,12 +1028,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&shared_runners_minutes_limit=1",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("shared_runners_minutes_limit", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1047,12 +1051,11 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("groups")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "name=name",
-                "&path=path",
-                "&extra_shared_runners_minutes_limit=1",
-            ))
+            .form_params(&[
+                ("name", "name"),
+                ("path", "path"),
+                ("extra_shared_runners_minutes_limit", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
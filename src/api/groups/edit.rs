@@ -9,7 +9,7 @@ use std::time::Duration;
 
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId, VisibilityLevel};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::groups::{
     BranchProtection, BranchProtectionDefaults, GroupProjectCreationAccessLevel,
@@ -48,7 +48,7 @@ impl ParamValue<'static> for SharedRunnersSetting {
 
 /// Edit an existing group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditGroup<'a> {
     /// The group to edit.
     #[builder(setter(into))]
@@ -184,6 +184,14 @@ impl<'a> EditGroup<'a> {
     }
 }
 
+impl<'a> EditGroupBuilder<'a> {
+    fn validate(&self) -> Result<(), EditGroupBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> EditGroupBuilder<'a> {
     /// An IP address or IP range that is allowed to access the group.
     pub fn ip_restriction_range<R>(&mut self, range: R) -> &mut Self
@@ -259,7 +267,7 @@ impl<'a> Endpoint for EditGroup<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}", self.group).into()
+        format!("groups/{}", self.group.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -318,12 +326,22 @@ impl<'a> Endpoint for EditGroup<'a> {
             .extend(
                 self.unique_project_download_limit_allowlist
                     .iter()
-                    .map(|value| ("unique_project_download_limit_allowlist[]", value)),
+                    .map(|value| {
+                        (
+                            ParamName::new("unique_project_download_limit_allowlist").array(),
+                            value,
+                        )
+                    }),
             )
             .extend(
                 self.unique_project_download_limit_alertlist
                     .iter()
-                    .map(|&value| ("unique_project_download_limit_alertlist[]", value)),
+                    .map(|&value| {
+                        (
+                            ParamName::new("unique_project_download_limit_alertlist").array(),
+                            value,
+                        )
+                    }),
             )
             .push_opt(
                 "auto_ban_user_on_excessive_projects_download",
@@ -389,6 +407,12 @@ mod tests {
         EditGroup::builder().group("group").build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditGroup::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditGroupBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -408,8 +432,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("name=name")
+            .form_params(&[("name", "name")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -427,8 +450,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("path=path")
+            .form_params(&[("path", "path")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -446,8 +468,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("description=description")
+            .form_params(&[("description", "description")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -465,8 +486,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("membership_lock=true")
+            .form_params(&[("membership_lock", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -484,8 +504,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("visibility=internal")
+            .form_params(&[("visibility", "internal")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -503,8 +522,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("share_with_group_lock=true")
+            .form_params(&[("share_with_group_lock", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -522,8 +540,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("require_two_factor_authentication=true")
+            .form_params(&[("require_two_factor_authentication", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -541,8 +558,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("two_factor_grace_period=1")
+            .form_params(&[("two_factor_grace_period", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -560,8 +576,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("project_creation_level=maintainer")
+            .form_params(&[("project_creation_level", "maintainer")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -579,8 +594,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("auto_devops_enabled=false")
+            .form_params(&[("auto_devops_enabled", "false")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -598,8 +612,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("subgroup_creation_level=owner")
+            .form_params(&[("subgroup_creation_level", "owner")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -618,8 +631,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("emails_disabled=false")
+            .form_params(&[("emails_disabled", "false")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -637,8 +649,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("emails_enabled=false")
+            .form_params(&[("emails_enabled", "false")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -656,8 +667,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("mentions_disabled=true")
+            .form_params(&[("mentions_disabled", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -675,8 +685,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("prevent_sharing_groups_outside_hierarchy=true")
+            .form_params(&[("prevent_sharing_groups_outside_hierarchy", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -694,8 +703,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("lfs_enabled=true")
+            .form_params(&[("lfs_enabled", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -713,8 +721,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("request_access_enabled=true")
+            .form_params(&[("request_access_enabled", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -732,8 +739,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("parent_id=1")
+            .form_params(&[("parent_id", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -751,8 +757,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("default_branch_protection=2")
+            .form_params(&[("default_branch_protection", "2")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -770,8 +775,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("default_branch_protection_defaults%5Ballowed_to_push%5D%5B%5D=30")
+            .form_params(&[(
+                "default_branch_protection_defaults[allowed_to_push][]",
+                "30",
+            )])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -796,8 +803,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("default_branch_protection_defaults%5Ballow_force_push%5D=true")
+            .form_params(&[(
+                "default_branch_protection_defaults[allow_force_push]",
+                "true",
+            )])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -820,8 +829,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("default_branch_protection_defaults%5Ballowed_to_merge%5D%5B%5D=30")
+            .form_params(&[(
+                "default_branch_protection_defaults[allowed_to_merge][]",
+                "30",
+            )])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -846,8 +857,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("default_branch_protection_defaults%5Bdeveloper_can_initial_push%5D=true")
+            .form_params(&[(
+                "default_branch_protection_defaults[developer_can_initial_push]",
+                "true",
+            )])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -870,8 +883,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("shared_runners_setting=disabled_with_override")
+            .form_params(&[("shared_runners_setting", "disabled_with_override")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -889,8 +901,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("shared_runners_minutes_limit=0")
+            .form_params(&[("shared_runners_minutes_limit", "0")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -908,8 +919,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("shared_runners_minutes_limit=1")
+            .form_params(&[("shared_runners_minutes_limit", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -927,8 +937,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("extra_shared_runners_minutes_limit=1")
+            .form_params(&[("extra_shared_runners_minutes_limit", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -946,8 +955,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("file_template_project_id=1")
+            .form_params(&[("file_template_project_id", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -965,8 +973,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("prevent_forking_outside_group=true")
+            .form_params(&[("prevent_forking_outside_group", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -984,8 +991,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("ip_restriction_ranges=10.0.0.0%2F8%2C192.168.1.1%2C192.168.1.128%2F7")
+            .form_params(&[(
+                "ip_restriction_ranges",
+                "10.0.0.0/8,192.168.1.1,192.168.1.128/7",
+            )])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1004,8 +1013,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("wiki_access_level=disabled")
+            .form_params(&[("wiki_access_level", "disabled")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1023,8 +1031,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("unique_project_download_limit=100")
+            .form_params(&[("unique_project_download_limit", "100")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1042,8 +1049,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("unique_project_download_limit_interval_in_seconds=3600")
+            .form_params(&[("unique_project_download_limit_interval_in_seconds", "3600")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1061,11 +1067,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "unique_project_download_limit_allowlist%5B%5D=auditor",
-                "&unique_project_download_limit_allowlist%5B%5D=robot",
-            ))
+            .form_params(&[
+                ("unique_project_download_limit_allowlist[]", "auditor"),
+                ("unique_project_download_limit_allowlist[]", "robot"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1084,11 +1089,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "unique_project_download_limit_alertlist%5B%5D=1",
-                "&unique_project_download_limit_alertlist%5B%5D=2",
-            ))
+            .form_params(&[
+                ("unique_project_download_limit_alertlist[]", "1"),
+                ("unique_project_download_limit_alertlist[]", "2"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1107,8 +1111,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("groups/simple%2Fgroup")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("auto_ban_user_on_excessive_projects_download=true")
+            .form_params(&[("auto_ban_user_on_excessive_projects_download", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
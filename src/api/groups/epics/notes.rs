@@ -0,0 +1,39 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group epic note API endpoints.
+//!
+//! These endpoints are used for querying and managing group epic notes.
+
+mod create;
+mod delete;
+mod edit;
+mod note;
+mod notes;
+
+pub use self::create::CreateEpicNote;
+pub use self::create::CreateEpicNoteBuilder;
+pub use self::create::CreateEpicNoteBuilderError;
+
+pub use self::delete::DeleteEpicNote;
+pub use self::delete::DeleteEpicNoteBuilder;
+pub use self::delete::DeleteEpicNoteBuilderError;
+
+pub use self::edit::EditEpicNote;
+pub use self::edit::EditEpicNoteBuilder;
+pub use self::edit::EditEpicNoteBuilderError;
+
+pub use self::note::EpicNote;
+pub use self::note::EpicNoteBuilder;
+pub use self::note::EpicNoteBuilderError;
+
+pub use self::notes::EpicNotes;
+pub use self::notes::EpicNotesBuilder;
+pub use self::notes::EpicNotesBuilderError;
+pub use crate::api::helpers::filter_notes;
+pub use crate::api::helpers::FilteredNotes;
+pub use crate::api::helpers::NoteActivityFilter;
+pub use crate::api::helpers::NoteOrderBy;
@@ -0,0 +1,174 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create a new note on an epic.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateEpicNote<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the epic.
+    epic: u64,
+    /// The content of the note.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+
+    /// Whether to create an internal note or not.
+    #[builder(default)]
+    internal: Option<bool>,
+}
+
+impl<'a> CreateEpicNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateEpicNoteBuilder<'a> {
+        CreateEpicNoteBuilder::default()
+    }
+}
+
+impl<'a> CreateEpicNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateEpicNoteBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("epic", self.epic.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateEpicNote<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/notes",
+            self.group.path_segment(),
+            self.epic.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("body", self.body.as_ref())
+            .push_opt("internal", self.internal);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::notes::{CreateEpicNote, CreateEpicNoteBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_body_are_necessary() {
+        let err = CreateEpicNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = CreateEpicNote::builder()
+            .epic(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_necessary() {
+        let err = CreateEpicNote::builder()
+            .group(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicNoteBuilderError, "epic");
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = CreateEpicNote::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEpicNoteBuilderError, "body");
+    }
+
+    #[test]
+    fn group_epic_and_body_are_sufficient() {
+        CreateEpicNote::builder()
+            .group(1)
+            .epic(1)
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateEpicNote::builder()
+            .group(0)
+            .epic(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateEpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics/1/notes")
+            .form_params(&[("body", "body")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpicNote::builder()
+            .group("simple/group")
+            .epic(1)
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_internal() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics/1/notes")
+            .form_params(&[("body", "body"), ("internal", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEpicNote::builder()
+            .group("simple/group")
+            .epic(1)
+            .body("body")
+            .internal(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit a note on an epic.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct EditEpicNote<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the epic.
+    epic: u64,
+    /// The ID of the note.
+    note: u64,
+
+    /// The content of the note.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+}
+
+impl<'a> EditEpicNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditEpicNoteBuilder<'a> {
+        EditEpicNoteBuilder::default()
+    }
+}
+
+impl<'a> EditEpicNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EditEpicNoteBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("epic", self.epic.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditEpicNote<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/notes/{}",
+            self.group.path_segment(),
+            self.epic.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("body", self.body.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::notes::{EditEpicNote, EditEpicNoteBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_note_and_body_are_necessary() {
+        let err = EditEpicNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = EditEpicNote::builder()
+            .epic(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_necessary() {
+        let err = EditEpicNote::builder()
+            .group(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicNoteBuilderError, "epic");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = EditEpicNote::builder()
+            .group(1)
+            .epic(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = EditEpicNote::builder()
+            .group(1)
+            .epic(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditEpicNoteBuilderError, "body");
+    }
+
+    #[test]
+    fn group_epic_note_and_body_are_sufficient() {
+        EditEpicNote::builder()
+            .group(1)
+            .epic(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditEpicNote::builder()
+            .group(0)
+            .epic(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditEpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/notes/2")
+            .form_params(&[("body", "body")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEpicNote::builder()
+            .group("simple/group")
+            .epic(1)
+            .note(2)
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query a single note on an epic.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct EpicNote<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the epic.
+    epic: u64,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> EpicNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EpicNoteBuilder<'a> {
+        EpicNoteBuilder::default()
+    }
+}
+
+impl<'a> EpicNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EpicNoteBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("epic", self.epic.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EpicNote<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/notes/{}",
+            self.group.path_segment(),
+            self.epic.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::epics::notes::{EpicNote, EpicNoteBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_note_are_necessary() {
+        let err = EpicNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = EpicNote::builder().epic(1).note(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_necessary() {
+        let err = EpicNote::builder().group(1).note(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNoteBuilderError, "epic");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = EpicNote::builder().group(1).epic(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn group_epic_and_note_are_sufficient() {
+        EpicNote::builder()
+            .group(1)
+            .epic(1)
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = EpicNote::builder()
+            .group(0)
+            .epic(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EpicNoteBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics/1/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EpicNote::builder()
+            .group("simple/group")
+            .epic(1)
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::helpers::NoteOrderBy;
+
+/// Query for notes on an epic.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EpicNotes<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the epic.
+    epic: u64,
+
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<NoteOrderBy>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> EpicNotes<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EpicNotesBuilder<'a> {
+        EpicNotesBuilder::default()
+    }
+}
+
+impl<'a> EpicNotesBuilder<'a> {
+    fn validate(&self) -> Result<(), EpicNotesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("epic", self.epic.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EpicNotes<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/notes",
+            self.group.path_segment(),
+            self.epic.path_segment()
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for EpicNotes<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::groups::epics::notes::{EpicNotes, EpicNotesBuilderError, NoteOrderBy};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_epic_are_necessary() {
+        let err = EpicNotes::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNotesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = EpicNotes::builder().epic(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNotesBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_necessary() {
+        let err = EpicNotes::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EpicNotesBuilderError, "epic");
+    }
+
+    #[test]
+    fn group_and_epic_are_sufficient() {
+        EpicNotes::builder().group(1).epic(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = EpicNotes::builder().group(0).epic(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EpicNotesBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics/1/notes")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EpicNotes::builder()
+            .group("simple/group")
+            .epic(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics/1/notes")
+            .add_query_params(&[("order_by", "updated_at")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EpicNotes::builder()
+            .group("simple/group")
+            .epic(1)
+            .order_by(NoteOrderBy::UpdatedAt)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics/1/notes")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EpicNotes::builder()
+            .group("simple/group")
+            .epic(1)
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
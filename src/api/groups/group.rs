@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific group on an instance.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Group<'a> {
     /// The group to get.
     #[builder(setter(into))]
@@ -33,13 +33,21 @@ impl<'a> Group<'a> {
     }
 }
 
+impl<'a> GroupBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Group<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}", self.group).into()
+        format!("groups/{}", self.group.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -73,6 +81,12 @@ mod tests {
         Group::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = Group::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -10,6 +10,7 @@ use derive_builder::Builder;
 
 use crate::api::common::{AccessLevel, SortOrder};
 use crate::api::endpoint_prelude::*;
+use crate::api::instance::Feature;
 use crate::api::ParamValue;
 
 /// Keys group results may be ordered by.
@@ -176,7 +177,7 @@ impl<'a> Endpoint for Groups<'a> {
             .extend(
                 self.skip_groups
                     .iter()
-                    .map(|&value| ("skip_groups[]", value)),
+                    .map(|&value| (ParamName::new("skip_groups").array(), value)),
             )
             .push_opt("all_available", self.all_available)
             .push_opt("owned", self.owned)
@@ -189,9 +190,9 @@ impl<'a> Endpoint for Groups<'a> {
             .push_opt("statistics", self.statistics)
             .push_opt("with_custom_attributes", self.with_custom_attributes)
             .extend(
-                self.custom_attributes
-                    .iter()
-                    .map(|(key, value)| (format!("custom_attributes[{}]", key), value)),
+                self.custom_attributes.iter().map(|(key, value)| {
+                    (ParamName::new("custom_attributes").key(key.as_ref()), value)
+                }),
             )
             .push_opt("order_by", self.order_by)
             .push_opt("sort", self.sort);
@@ -206,14 +207,21 @@ impl<'a> Pageable for Groups<'a> {
             .map_or(false, |order_by| order_by.use_keyset_pagination())
             && self.sort.map_or(true, |sort| sort == SortOrder::Ascending)
     }
+
+    fn keyset_pagination_feature(&self) -> Option<Feature> {
+        Some(Feature::KeysetPaginationGroups)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::api::common::{AccessLevel, SortOrder};
     use crate::api::groups::{GroupOrderBy, Groups};
-    use crate::api::{self, Query};
+    use crate::api::{self, AsyncQuery, Pagination, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
 
     #[test]
     fn order_by_default() {
@@ -430,4 +438,103 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    fn versioned_client(version: &str) -> MockClient {
+        let client = MockClient::new().with_capabilities();
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("version")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({
+                    "version": version,
+                })),
+            )
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("groups")
+                    .query_param("order_by", "name")
+                    .build()
+                    .unwrap(),
+                (0..3).map(|id| json!({"id": id})),
+            );
+        client
+    }
+
+    fn name_ordered() -> Groups<'static> {
+        Groups::builder()
+            .order_by(GroupOrderBy::Name)
+            .build()
+            .unwrap()
+    }
+
+    fn uses_keyset(client: &MockClient) -> bool {
+        client
+            .requests()
+            .iter()
+            .filter(|request| request.url.path().ends_with("/groups"))
+            .all(|request| {
+                request
+                    .url
+                    .query_pairs()
+                    .any(|(key, value)| key == "pagination" && value == "keyset")
+            })
+    }
+
+    #[test]
+    fn keyset_pagination_supported() {
+        let client = versioned_client("14.3.0");
+
+        let groups: Vec<serde_json::Value> = api::paged(name_ordered(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(groups.len(), 3);
+        assert!(uses_keyset(&client));
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn keyset_pagination_unsupported() {
+        let client = versioned_client("14.2.5");
+
+        let groups: Vec<serde_json::Value> = api::paged(name_ordered(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(groups.len(), 3);
+        assert!(!uses_keyset(&client));
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn keyset_pagination_unsupported_async() {
+        let client = versioned_client("13.12.0");
+
+        let groups: Vec<serde_json::Value> = api::paged(name_ordered(), Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(groups.len(), 3);
+        assert!(!uses_keyset(&client));
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn keyset_pagination_without_capabilities() {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("groups")
+                .query_param("order_by", "name")
+                .build()
+                .unwrap(),
+            (0..3).map(|id| json!({"id": id})),
+        );
+
+        let groups: Vec<serde_json::Value> = api::paged(name_ordered(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(groups.len(), 3);
+        assert!(uses_keyset(&client));
+    }
 }
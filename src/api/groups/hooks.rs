@@ -10,9 +10,14 @@
 
 mod create;
 mod delete;
+mod delete_custom_header;
+mod delete_url_variable;
 mod edit;
 mod hook;
 mod hooks;
+mod set_custom_header;
+mod set_url_variable;
+mod test;
 
 pub use self::create::CreateHook;
 pub use self::create::CreateHookBuilder;
@@ -33,3 +38,25 @@ pub use self::hook::HookBuilderError;
 pub use self::hooks::Hooks;
 pub use self::hooks::HooksBuilder;
 pub use self::hooks::HooksBuilderError;
+
+pub use crate::api::projects::hooks::HookTestTrigger;
+
+pub use self::delete_custom_header::DeleteHookCustomHeader;
+pub use self::delete_custom_header::DeleteHookCustomHeaderBuilder;
+pub use self::delete_custom_header::DeleteHookCustomHeaderBuilderError;
+
+pub use self::delete_url_variable::DeleteHookUrlVariable;
+pub use self::delete_url_variable::DeleteHookUrlVariableBuilder;
+pub use self::delete_url_variable::DeleteHookUrlVariableBuilderError;
+
+pub use self::set_custom_header::SetHookCustomHeader;
+pub use self::set_custom_header::SetHookCustomHeaderBuilder;
+pub use self::set_custom_header::SetHookCustomHeaderBuilderError;
+
+pub use self::set_url_variable::SetHookUrlVariable;
+pub use self::set_url_variable::SetHookUrlVariableBuilder;
+pub use self::set_url_variable::SetHookUrlVariableBuilderError;
+
+pub use self::test::TestHook;
+pub use self::test::TestHookBuilder;
+pub use self::test::TestHookBuilderError;
@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
+use crate::api::secrets::{SecretError, SecretProvider};
 
 /// Create a new webhook for a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateHook<'a> {
     /// The group to create a webhook within.
     #[builder(setter(into))]
@@ -81,13 +82,30 @@ impl<'a> CreateHook<'a> {
     }
 }
 
+impl<'a> CreateHookBuilder<'a> {
+    /// Set the secret token from a secret provider.
+    pub fn token_from<P>(&mut self, provider: &P, name: &str) -> Result<&mut Self, SecretError>
+    where
+        P: SecretProvider + ?Sized,
+    {
+        let token = provider.secret(name)?;
+        Ok(self.token(token))
+    }
+
+    fn validate(&self) -> Result<(), CreateHookBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateHook<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/hooks", self.group).into()
+        format!("groups/{}/hooks", self.group.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -157,6 +175,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateHook::builder()
+            .group("")
+            .url("url")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateHookBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
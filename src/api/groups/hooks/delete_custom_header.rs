@@ -0,0 +1,141 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a custom header from a webhook of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteHookCustomHeader<'a> {
+    /// The group of the webhook.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the hook.
+    hook_id: u64,
+    /// The name of the header.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> DeleteHookCustomHeader<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteHookCustomHeaderBuilder<'a> {
+        DeleteHookCustomHeaderBuilder::default()
+    }
+}
+
+impl<'a> DeleteHookCustomHeaderBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteHookCustomHeaderBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteHookCustomHeader<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/hooks/{}/custom_headers/{}",
+            self.group.path_segment(),
+            self.hook_id.path_segment(),
+            self.key.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::hooks::{DeleteHookCustomHeader, DeleteHookCustomHeaderBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_necessary() {
+        let err = DeleteHookCustomHeader::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = DeleteHookCustomHeader::builder()
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "group");
+    }
+
+    #[test]
+    fn hook_id_is_necessary() {
+        let err = DeleteHookCustomHeader::builder()
+            .group("group")
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "hook_id");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = DeleteHookCustomHeader::builder()
+            .group("group")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "key");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        DeleteHookCustomHeader::builder()
+            .group("group")
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteHookCustomHeader::builder()
+            .group("")
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteHookCustomHeaderBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/hooks/1/custom_headers/X-Key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteHookCustomHeader::builder()
+            .group("simple/group")
+            .hook_id(1)
+            .key("X-Key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
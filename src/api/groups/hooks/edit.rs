@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
+use crate::api::secrets::{SecretError, SecretProvider};
 
 /// Edit an existing webhook for a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditHook<'a> {
     /// The group to edit a webhook within.
     #[builder(setter(into))]
@@ -86,13 +87,36 @@ impl<'a> EditHook<'a> {
     }
 }
 
+impl<'a> EditHookBuilder<'a> {
+    /// Set the secret token from a secret provider.
+    pub fn token_from<P>(&mut self, provider: &P, name: &str) -> Result<&mut Self, SecretError>
+    where
+        P: SecretProvider + ?Sized,
+    {
+        let token = provider.secret(name)?;
+        Ok(self.token(token))
+    }
+
+    fn validate(&self) -> Result<(), EditHookBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditHook<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/hooks/{}", self.group, self.hook_id).into()
+        format!(
+            "groups/{}/hooks/{}",
+            self.group.path_segment(),
+            self.hook_id.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -136,6 +160,7 @@ mod tests {
     use http::Method;
 
     use crate::api::groups::hooks::{EditHook, EditHookBuilderError};
+    use crate::api::secrets::SecretError;
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
@@ -166,6 +191,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditHook::builder()
+            .group("")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditHookBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -523,4 +558,26 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_token_from() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/hooks/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("token=secret")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let provider = |_: &str| -> Result<String, SecretError> { Ok("secret".into()) };
+        let endpoint = EditHook::builder()
+            .group("simple/group")
+            .hook_id(1)
+            .token_from(&provider, "hook")
+            .unwrap()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a webhook within a group.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Hook<'a> {
     /// The group to query for webhooks.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> Hook<'a> {
     }
 }
 
+impl<'a> HookBuilder<'a> {
+    fn validate(&self) -> Result<(), HookBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("hook", self.hook.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Hook<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/hooks/{}", self.group, self.hook).into()
+        format!(
+            "groups/{}/hooks/{}",
+            self.group.path_segment(),
+            self.hook.path_segment()
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,12 @@ mod tests {
         Hook::builder().group(1).hook(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = Hook::builder().group(0).hook(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, HookBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
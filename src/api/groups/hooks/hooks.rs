@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for webhooks within a group.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Hooks<'a> {
     /// The group to query for webhooks.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> Hooks<'a> {
     }
 }
 
+impl<'a> HooksBuilder<'a> {
+    fn validate(&self) -> Result<(), HooksBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Hooks<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/hooks", self.group).into()
+        format!("groups/{}/hooks", self.group.path_segment()).into()
     }
 }
 
@@ -53,6 +62,12 @@ mod tests {
         Hooks::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = Hooks::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, HooksBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,171 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Set a custom header for a webhook of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SetHookCustomHeader<'a> {
+    /// The group of the webhook.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the hook.
+    hook_id: u64,
+    /// The name of the header.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value to set.
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+}
+
+impl<'a> SetHookCustomHeader<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetHookCustomHeaderBuilder<'a> {
+        SetHookCustomHeaderBuilder::default()
+    }
+}
+
+impl<'a> SetHookCustomHeaderBuilder<'a> {
+    fn validate(&self) -> Result<(), SetHookCustomHeaderBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for SetHookCustomHeader<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/hooks/{}/custom_headers/{}",
+            self.group.path_segment(),
+            self.hook_id.path_segment(),
+            self.key.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("value", &self.value);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::hooks::{SetHookCustomHeader, SetHookCustomHeaderBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_necessary() {
+        let err = SetHookCustomHeader::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookCustomHeaderBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = SetHookCustomHeader::builder()
+            .hook_id(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookCustomHeaderBuilderError, "group");
+    }
+
+    #[test]
+    fn hook_id_is_necessary() {
+        let err = SetHookCustomHeader::builder()
+            .group("group")
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookCustomHeaderBuilderError, "hook_id");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = SetHookCustomHeader::builder()
+            .group("group")
+            .hook_id(1)
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookCustomHeaderBuilderError, "key");
+    }
+
+    #[test]
+    fn value_is_necessary() {
+        let err = SetHookCustomHeader::builder()
+            .group("group")
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookCustomHeaderBuilderError, "value");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        SetHookCustomHeader::builder()
+            .group("group")
+            .hook_id(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = SetHookCustomHeader::builder()
+            .group("")
+            .hook_id(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SetHookCustomHeaderBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/hooks/1/custom_headers/X-Key")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=some+value")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetHookCustomHeader::builder()
+            .group("simple/group")
+            .hook_id(1)
+            .key("X-Key")
+            .value("some value")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::hooks::HookTestTrigger;
+
+/// Trigger a test event for a webhook of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct TestHook<'a> {
+    /// The group of the webhook.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the hook to test.
+    hook_id: u64,
+    /// The event to trigger.
+    trigger: HookTestTrigger,
+}
+
+impl<'a> TestHook<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TestHookBuilder<'a> {
+        TestHookBuilder::default()
+    }
+}
+
+impl<'a> TestHookBuilder<'a> {
+    fn validate(&self) -> Result<(), TestHookBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for TestHook<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/hooks/{}/test/{}",
+            self.group.path_segment(),
+            self.hook_id.path_segment(),
+            self.trigger.as_str(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::hooks::{HookTestTrigger, TestHook, TestHookBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_hook_id_and_trigger_are_necessary() {
+        let err = TestHook::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = TestHook::builder()
+            .hook_id(1)
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "group");
+    }
+
+    #[test]
+    fn hook_id_is_necessary() {
+        let err = TestHook::builder()
+            .group("group")
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "hook_id");
+    }
+
+    #[test]
+    fn trigger_is_necessary() {
+        let err = TestHook::builder()
+            .group("group")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "trigger");
+    }
+
+    #[test]
+    fn group_hook_id_and_trigger_are_sufficient() {
+        TestHook::builder()
+            .group("group")
+            .hook_id(1)
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = TestHook::builder()
+            .group("")
+            .hook_id(1)
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TestHookBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/hooks/1/test/merge_requests_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TestHook::builder()
+            .group("simple/group")
+            .hook_id(1)
+            .trigger(HookTestTrigger::MergeRequestsEvents)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Group invitation API endpoints.
+//!
+//! These endpoints are used for inviting users to groups, including by email address before
+//! they have an account, and managing pending invitations.
+
+mod create;
+mod delete;
+mod edit;
+mod invitations;
+
+pub use self::create::CreateGroupInvitation;
+pub use self::create::CreateGroupInvitationBuilder;
+pub use self::create::CreateGroupInvitationBuilderError;
+
+pub use self::delete::DeleteGroupInvitation;
+pub use self::delete::DeleteGroupInvitationBuilder;
+pub use self::delete::DeleteGroupInvitationBuilderError;
+
+pub use self::edit::EditGroupInvitation;
+pub use self::edit::EditGroupInvitationBuilder;
+pub use self::edit::EditGroupInvitationBuilderError;
+
+pub use self::invitations::GroupInvitations;
+pub use self::invitations::GroupInvitationsBuilder;
+pub use self::invitations::GroupInvitationsBuilderError;
@@ -0,0 +1,353 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, AccessLevel, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+const EMAIL_OR_USER_REQUIRED: &str = "at least one email address or user ID is required";
+
+/// Invite users to a group.
+///
+/// Users may be invited by email address, even if they do not have an account yet, or by ID.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateGroupInvitation<'a> {
+    /// The group to invite users to.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The email addresses to invite.
+    #[builder(setter(name = "_emails"), default, private)]
+    emails: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// The users to invite (by ID).
+    #[builder(setter(name = "_user_ids"), default, private)]
+    user_ids: Option<CommaSeparatedList<u64>>,
+    /// The access level for the invited users in the group.
+    access_level: AccessLevel,
+
+    /// When the invited users' access expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+    /// The source of the invitation.
+    #[builder(setter(into), default)]
+    invite_source: Option<Cow<'a, str>>,
+    /// The ID of a member role to assign to the invited users.
+    #[builder(default)]
+    member_role_id: Option<u64>,
+}
+
+impl<'a> CreateGroupInvitation<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupInvitationBuilder<'a> {
+        CreateGroupInvitationBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupInvitationBuilder<'a> {
+    /// Invite an email address.
+    pub fn email<E>(&mut self, email: E) -> &mut Self
+    where
+        E: Into<Cow<'a, str>>,
+    {
+        self.emails
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(email.into());
+        self
+    }
+
+    /// Invite a set of email addresses.
+    pub fn emails<I, E>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = E>,
+        E: Into<Cow<'a, str>>,
+    {
+        self.emails
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Invite a user (by ID).
+    pub fn user(&mut self, user: u64) -> &mut Self {
+        self.user_ids
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(user);
+        self
+    }
+
+    /// Invite a set of users (by ID).
+    pub fn users<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.user_ids
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateGroupInvitationBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        let has_emails = matches!(self.emails, Some(Some(_)));
+        let has_users = matches!(self.user_ids, Some(Some(_)));
+
+        if !has_emails && !has_users {
+            return Err(CreateGroupInvitationBuilderError::ValidationError(
+                EMAIL_OR_USER_REQUIRED.into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateGroupInvitation<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/invitations", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("email", self.emails.as_ref())
+            .push_opt("user_id", self.user_ids.as_ref())
+            .push("access_level", self.access_level.as_u64())
+            .push_opt("expires_at", self.expires_at)
+            .push_opt("invite_source", self.invite_source.as_ref())
+            .push_opt("member_role_id", self.member_role_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::groups::invitations::{
+        CreateGroupInvitation, CreateGroupInvitationBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    use super::EMAIL_OR_USER_REQUIRED;
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateGroupInvitation::builder()
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupInvitationBuilderError, "group");
+    }
+
+    #[test]
+    fn access_level_is_needed() {
+        let err = CreateGroupInvitation::builder()
+            .group(1)
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupInvitationBuilderError, "access_level",);
+    }
+
+    #[test]
+    fn email_or_user_is_needed() {
+        let err = CreateGroupInvitation::builder()
+            .group(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        if let CreateGroupInvitationBuilderError::ValidationError(msg) = err {
+            assert_eq!(msg, EMAIL_OR_USER_REQUIRED);
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn group_email_and_access_level_are_sufficient() {
+        CreateGroupInvitation::builder()
+            .group(1)
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateGroupInvitation::builder()
+            .group(0)
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateGroupInvitationBuilderError, "group");
+    }
+
+    #[test]
+    fn group_user_and_access_level_are_sufficient() {
+        CreateGroupInvitation::builder()
+            .group(1)
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .form_params(&[("email", "user@example.com"), ("access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupInvitation::builder()
+            .group("simple/group")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_emails() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .form_params(&[
+                (
+                    "email",
+                    "user@example.com,other@example.com,third@example.com",
+                ),
+                ("access_level", "30"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupInvitation::builder()
+            .group("simple/group")
+            .email("user@example.com")
+            .emails(["other@example.com", "third@example.com"].iter().copied())
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_users() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .form_params(&[("user_id", "1,2,3"), ("access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupInvitation::builder()
+            .group("simple/group")
+            .user(1)
+            .users([2, 3].iter().copied())
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("access_level", "30"),
+                ("expires_at", "2020-01-01"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupInvitation::builder()
+            .group("simple/group")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .expires_at(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_invite_source() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("access_level", "30"),
+                ("invite_source", "onboarding"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupInvitation::builder()
+            .group("simple/group")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .invite_source("onboarding")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("access_level", "30"),
+                ("member_role_id", "1"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupInvitation::builder()
+            .group("simple/group")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .member_role_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a pending invitation to a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupInvitation<'a> {
+    /// The group the invitation is for.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The email address the invitation was sent to.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+}
+
+impl<'a> DeleteGroupInvitation<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupInvitationBuilder<'a> {
+        DeleteGroupInvitationBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupInvitationBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteGroupInvitationBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("email", self.email.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupInvitation<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/invitations/{}",
+            self.group.path_segment(),
+            self.email.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::invitations::{
+        DeleteGroupInvitation, DeleteGroupInvitationBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_email_are_needed() {
+        let err = DeleteGroupInvitation::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupInvitationBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupInvitation::builder()
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupInvitationBuilderError, "group");
+    }
+
+    #[test]
+    fn email_is_needed() {
+        let err = DeleteGroupInvitation::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupInvitationBuilderError, "email");
+    }
+
+    #[test]
+    fn group_and_email_are_sufficient() {
+        DeleteGroupInvitation::builder()
+            .group(1)
+            .email("user@example.com")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteGroupInvitation::builder()
+            .group(0)
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteGroupInvitationBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/invitations/user@example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupInvitation::builder()
+            .group("simple/group")
+            .email("user@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
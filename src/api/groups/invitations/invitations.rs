@@ -0,0 +1,114 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for pending invitations to a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupInvitations<'a> {
+    /// The group to query for invitations.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Search for invitations by the invited email address.
+    #[builder(setter(into), default)]
+    query: Option<Cow<'a, str>>,
+}
+
+impl<'a> GroupInvitations<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupInvitationsBuilder<'a> {
+        GroupInvitationsBuilder::default()
+    }
+}
+
+impl<'a> GroupInvitationsBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupInvitationsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupInvitations<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/invitations", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("query", self.query.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupInvitations<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::invitations::{GroupInvitations, GroupInvitationsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupInvitations::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupInvitationsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupInvitations::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupInvitations::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupInvitationsBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupInvitations::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/invitations")
+            .add_query_params(&[("query", "user@example.com")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupInvitations::builder()
+            .group("simple/group")
+            .query("user@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,260 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group iteration API endpoint.
+//!
+//! These endpoints are used for querying iterations available to a group.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::iterations::{IterationSearchScope, IterationState};
+
+/// Query for iterations within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupIterations<'a> {
+    /// The group to query for iterations.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Filter iterations by state.
+    #[builder(default)]
+    state: Option<IterationState>,
+    /// Search for iterations matching the given string.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// The scopes to look for search query within.
+    #[builder(setter(name = "_search_in"), default, private)]
+    search_in: Option<CommaSeparatedList<IterationSearchScope>>,
+    /// Include iterations from ancestor groups.
+    #[builder(default)]
+    include_ancestors: Option<bool>,
+    /// Include iterations from descendant groups and projects.
+    #[builder(default)]
+    include_descendants: Option<bool>,
+    /// Return only iterations updated before the given time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+    /// Return only iterations updated after the given time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+}
+
+impl<'a> GroupIterations<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupIterationsBuilder<'a> {
+        GroupIterationsBuilder::default()
+    }
+}
+
+impl<'a> GroupIterationsBuilder<'a> {
+    /// The scopes to look for search query within.
+    pub fn search_in(&mut self, scope: IterationSearchScope) -> &mut Self {
+        self.search_in
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(scope);
+        self
+    }
+
+    fn validate(&self) -> Result<(), GroupIterationsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupIterations<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/iterations", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("state", self.state)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("in", self.search_in.as_ref())
+            .push_opt("include_ancestors", self.include_ancestors)
+            .push_opt("include_descendants", self.include_descendants)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("updated_after", self.updated_after);
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupIterations<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::groups::iterations::{GroupIterations, GroupIterationsBuilderError};
+    use crate::api::projects::iterations::{IterationSearchScope, IterationState};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupIterations::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupIterationsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupIterations::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupIterations::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupIterationsBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("state", "current")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .state(IterationState::Current)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("search", "sprint")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .search("sprint")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search_in() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("in", "title,cadence_title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .search_in(IterationSearchScope::Title)
+            .search_in(IterationSearchScope::CadenceTitle)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestors() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("include_ancestors", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .include_ancestors(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_descendants() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("include_descendants", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .include_descendants(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/iterations")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupIterations::builder()
+            .group("simple/group")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group label API endpoints.
+//!
+//! These endpoints are used for querying and managing group labels.
+
+mod create;
+mod delete;
+mod edit;
+mod label;
+mod labels;
+mod subscribe;
+mod unsubscribe;
+
+pub use self::create::CreateGroupLabel;
+pub use self::create::CreateGroupLabelBuilder;
+pub use self::create::CreateGroupLabelBuilderError;
+
+pub use self::delete::DeleteGroupLabel;
+pub use self::delete::DeleteGroupLabelBuilder;
+pub use self::delete::DeleteGroupLabelBuilderError;
+
+pub use self::edit::EditGroupLabel;
+pub use self::edit::EditGroupLabelBuilder;
+pub use self::edit::EditGroupLabelBuilderError;
+
+pub use self::label::GroupLabel;
+pub use self::label::GroupLabelBuilder;
+pub use self::label::GroupLabelBuilderError;
+
+pub use self::labels::GroupLabels;
+pub use self::labels::GroupLabelsBuilder;
+pub use self::labels::GroupLabelsBuilderError;
+
+pub use self::subscribe::SubscribeToGroupLabel;
+pub use self::subscribe::SubscribeToGroupLabelBuilder;
+pub use self::subscribe::SubscribeToGroupLabelBuilderError;
+
+pub use self::unsubscribe::UnsubscribeFromGroupLabel;
+pub use self::unsubscribe::UnsubscribeFromGroupLabelBuilder;
+pub use self::unsubscribe::UnsubscribeFromGroupLabelBuilderError;
@@ -0,0 +1,180 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create a label within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateGroupLabel<'a> {
+    /// The group to create a label within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the label.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The color of the label.
+    ///
+    /// CSS and RGB colors in `#RRGGBB` format are supported.
+    ///
+    /// TODO: Use a specific structure for this.
+    #[builder(setter(into))]
+    color: Cow<'a, str>,
+
+    /// The description of the label.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateGroupLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupLabelBuilder<'a> {
+        CreateGroupLabelBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateGroupLabelBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateGroupLabel<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/labels", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push("color", &self.color)
+            .push_opt("description", self.description.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::labels::{CreateGroupLabel, CreateGroupLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_name_and_color_are_necessary() {
+        let err = CreateGroupLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = CreateGroupLabel::builder()
+            .name("label")
+            .color("#f100fe")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = CreateGroupLabel::builder()
+            .group(1)
+            .color("#f100fe")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupLabelBuilderError, "name");
+    }
+
+    #[test]
+    fn color_is_necessary() {
+        let err = CreateGroupLabel::builder()
+            .group(1)
+            .name("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupLabelBuilderError, "color");
+    }
+
+    #[test]
+    fn group_name_and_color_are_sufficient() {
+        CreateGroupLabel::builder()
+            .group(1)
+            .name("label")
+            .color("#f100fe")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateGroupLabel::builder()
+            .group(0)
+            .name("label")
+            .color("#f100fe")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/labels")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=label", "&color=%23ffffff"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupLabel::builder()
+            .group("simple/group")
+            .name("label")
+            .color("#ffffff")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/labels")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=label",
+                "&color=%23ffffff",
+                "&description=description",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupLabel::builder()
+            .group("simple/group")
+            .name("label")
+            .color("#ffffff")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a label within a group.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupLabel<'a> {
+    /// The group to delete a label within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+}
+
+impl<'a> DeleteGroupLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupLabelBuilder<'a> {
+        DeleteGroupLabelBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteGroupLabelBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupLabel<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/labels/{}",
+            self.group.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::labels::{DeleteGroupLabel, DeleteGroupLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_label_are_necessary() {
+        let err = DeleteGroupLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = DeleteGroupLabel::builder()
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn label_is_necessary() {
+        let err = DeleteGroupLabel::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn group_and_label_are_sufficient() {
+        DeleteGroupLabel::builder()
+            .group(1)
+            .label("label")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteGroupLabel::builder()
+            .group(0)
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/labels/simple%2Flabel")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupLabel::builder()
+            .group("simple/group")
+            .label("simple/label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
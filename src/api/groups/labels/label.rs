@@ -0,0 +1,190 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a label within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupLabel<'a> {
+    /// The group to query for the label.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+
+    /// Include ancestor groups.
+    ///
+    /// Defaults to `true`.
+    #[builder(default)]
+    include_ancestor_groups: Option<bool>,
+    /// Include descendant groups.
+    #[builder(default)]
+    include_descendant_groups: Option<bool>,
+}
+
+impl<'a> GroupLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupLabelBuilder<'a> {
+        GroupLabelBuilder::default()
+    }
+}
+
+impl<'a> GroupLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupLabelBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupLabel<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/labels/{}",
+            self.group.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("include_ancestor_groups", self.include_ancestor_groups)
+            .push_opt("include_descendant_groups", self.include_descendant_groups);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::labels::{GroupLabel, GroupLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_label_are_needed() {
+        let err = GroupLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupLabel::builder().label(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn label_is_needed() {
+        let err = GroupLabel::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn group_and_label_are_sufficient() {
+        GroupLabel::builder().group(1).label(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupLabel::builder().group(0).label(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn label_by_id() {
+        GroupLabel::builder().group(1).label(1).build().unwrap();
+    }
+
+    #[test]
+    fn label_by_name() {
+        GroupLabel::builder()
+            .group(1)
+            .label("label_name")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabel::builder()
+            .group("simple/group")
+            .label(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestor_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels/1")
+            .add_query_params(&[("include_ancestor_groups", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabel::builder()
+            .group("simple/group")
+            .label(1)
+            .include_ancestor_groups(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_descendant_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels/1")
+            .add_query_params(&[("include_descendant_groups", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabel::builder()
+            .group("simple/group")
+            .label(1)
+            .include_descendant_groups(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_escapes_label_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels/simple%2Flabel")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabel::builder()
+            .group("simple/group")
+            .label("simple/label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for labels within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupLabels<'a> {
+    /// The group to query for labels.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Include issue and merge request counts..
+    #[builder(default)]
+    with_counts: Option<bool>,
+    /// Include ancestor groups.
+    ///
+    /// Defaults to `true`.
+    #[builder(default)]
+    include_ancestor_groups: Option<bool>,
+    /// Include descendant groups.
+    #[builder(default)]
+    include_descendant_groups: Option<bool>,
+    /// Only return labels defined on the group itself.
+    ///
+    /// Defaults to `true`.
+    #[builder(default)]
+    only_group_labels: Option<bool>,
+    /// Search for a term.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+}
+
+impl<'a> GroupLabels<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupLabelsBuilder<'a> {
+        GroupLabelsBuilder::default()
+    }
+}
+
+impl<'a> GroupLabelsBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupLabelsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupLabels<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/labels", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("with_counts", self.with_counts)
+            .push_opt("include_ancestor_groups", self.include_ancestor_groups)
+            .push_opt("include_descendant_groups", self.include_descendant_groups)
+            .push_opt("only_group_labels", self.only_group_labels)
+            .push_opt("search", self.search.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupLabels<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::labels::{GroupLabels, GroupLabelsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupLabels::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupLabelsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupLabels::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupLabels::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupLabelsBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabels::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_counts() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels")
+            .add_query_params(&[("with_counts", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabels::builder()
+            .group("simple/group")
+            .with_counts(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestor_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels")
+            .add_query_params(&[("include_ancestor_groups", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabels::builder()
+            .group("simple/group")
+            .include_ancestor_groups(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_descendant_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels")
+            .add_query_params(&[("include_descendant_groups", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabels::builder()
+            .group("simple/group")
+            .include_descendant_groups(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_only_group_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels")
+            .add_query_params(&[("only_group_labels", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabels::builder()
+            .group("simple/group")
+            .only_group_labels(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/labels")
+            .add_query_params(&[("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupLabels::builder()
+            .group("simple/group")
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
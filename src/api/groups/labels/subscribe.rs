@@ -0,0 +1,122 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Subscribe to a label within a group.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SubscribeToGroupLabel<'a> {
+    /// The group containing the label.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+}
+
+impl<'a> SubscribeToGroupLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SubscribeToGroupLabelBuilder<'a> {
+        SubscribeToGroupLabelBuilder::default()
+    }
+}
+
+impl<'a> SubscribeToGroupLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), SubscribeToGroupLabelBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for SubscribeToGroupLabel<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/labels/{}/subscribe",
+            self.group.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::labels::{SubscribeToGroupLabel, SubscribeToGroupLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_label_are_necessary() {
+        let err = SubscribeToGroupLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeToGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = SubscribeToGroupLabel::builder()
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeToGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn label_is_necessary() {
+        let err = SubscribeToGroupLabel::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeToGroupLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn group_and_label_are_sufficient() {
+        SubscribeToGroupLabel::builder()
+            .group(1)
+            .label("label")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = SubscribeToGroupLabel::builder()
+            .group(0)
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SubscribeToGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/labels/simple%2Flabel/subscribe")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SubscribeToGroupLabel::builder()
+            .group("simple/group")
+            .label("simple/label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
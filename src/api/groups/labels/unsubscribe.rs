@@ -0,0 +1,128 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Unsubscribe from a label within a group.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UnsubscribeFromGroupLabel<'a> {
+    /// The group containing the label.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+}
+
+impl<'a> UnsubscribeFromGroupLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnsubscribeFromGroupLabelBuilder<'a> {
+        UnsubscribeFromGroupLabelBuilder::default()
+    }
+}
+
+impl<'a> UnsubscribeFromGroupLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), UnsubscribeFromGroupLabelBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UnsubscribeFromGroupLabel<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/labels/{}/unsubscribe",
+            self.group.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::labels::{
+        UnsubscribeFromGroupLabel, UnsubscribeFromGroupLabelBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_label_are_necessary() {
+        let err = UnsubscribeFromGroupLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeFromGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = UnsubscribeFromGroupLabel::builder()
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeFromGroupLabelBuilderError, "group");
+    }
+
+    #[test]
+    fn label_is_necessary() {
+        let err = UnsubscribeFromGroupLabel::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeFromGroupLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn group_and_label_are_sufficient() {
+        UnsubscribeFromGroupLabel::builder()
+            .group(1)
+            .label("label")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = UnsubscribeFromGroupLabel::builder()
+            .group(0)
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            UnsubscribeFromGroupLabelBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/labels/simple%2Flabel/unsubscribe")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnsubscribeFromGroupLabel::builder()
+            .group("simple/group")
+            .label("simple/label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,27 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Group member role API endpoints.
+//!
+//! These endpoints are used for managing custom member roles of top-level groups.
+
+mod create;
+mod delete;
+mod member_roles;
+
+pub use self::create::CreateGroupMemberRole;
+pub use self::create::CreateGroupMemberRoleBuilder;
+pub use self::create::CreateGroupMemberRoleBuilderError;
+
+pub use self::delete::DeleteGroupMemberRole;
+pub use self::delete::DeleteGroupMemberRoleBuilder;
+pub use self::delete::DeleteGroupMemberRoleBuilderError;
+
+pub use self::member_roles::GroupMemberRoles;
+pub use self::member_roles::GroupMemberRolesBuilder;
+pub use self::member_roles::GroupMemberRolesBuilderError;
@@ -0,0 +1,248 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::member_roles::MemberRoleAbility;
+
+/// Create a member role for a group.
+///
+/// The group must be a top-level group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateGroupMemberRole<'a> {
+    /// The group to create the member role in.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the member role.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The access level the member role is based upon.
+    base_access_level: AccessLevel,
+
+    /// The description of the member role.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The abilities granted by the member role.
+    #[builder(setter(name = "_abilities"), default, private)]
+    abilities: BTreeSet<MemberRoleAbility>,
+}
+
+impl<'a> CreateGroupMemberRole<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupMemberRoleBuilder<'a> {
+        CreateGroupMemberRoleBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupMemberRoleBuilder<'a> {
+    /// Grant an ability.
+    pub fn ability(&mut self, ability: MemberRoleAbility) -> &mut Self {
+        self.abilities
+            .get_or_insert_with(BTreeSet::new)
+            .insert(ability);
+        self
+    }
+
+    /// Grant a set of abilities.
+    pub fn abilities<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = MemberRoleAbility>,
+    {
+        self.abilities
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateGroupMemberRoleBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateGroupMemberRole<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/member_roles", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push("base_access_level", self.base_access_level.as_u64())
+            .push_opt("description", self.description.as_ref())
+            .extend(
+                self.abilities
+                    .iter()
+                    .map(|ability| (ability.as_str(), true)),
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::groups::member_roles::{
+        CreateGroupMemberRole, CreateGroupMemberRoleBuilderError,
+    };
+    use crate::api::member_roles::MemberRoleAbility;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_name_and_base_access_level_are_necessary() {
+        let err = CreateGroupMemberRole::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupMemberRoleBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = CreateGroupMemberRole::builder()
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupMemberRoleBuilderError, "group");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = CreateGroupMemberRole::builder()
+            .group(1)
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupMemberRoleBuilderError, "name");
+    }
+
+    #[test]
+    fn base_access_level_is_necessary() {
+        let err = CreateGroupMemberRole::builder()
+            .group(1)
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateGroupMemberRoleBuilderError,
+            "base_access_level"
+        );
+    }
+
+    #[test]
+    fn group_name_and_base_access_level_are_sufficient() {
+        CreateGroupMemberRole::builder()
+            .group(1)
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateGroupMemberRole::builder()
+            .group(0)
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateGroupMemberRoleBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/member_roles")
+            .form_params(&[("name", "name"), ("base_access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupMemberRole::builder()
+            .group("simple/group")
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/member_roles")
+            .form_params(&[
+                ("name", "name"),
+                ("base_access_level", "30"),
+                ("description", "description"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupMemberRole::builder()
+            .group("simple/group")
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_abilities() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/member_roles")
+            .form_params(&[
+                ("name", "name"),
+                ("base_access_level", "30"),
+                ("admin_merge_request", "true"),
+                ("read_code", "true"),
+                ("read_vulnerability", "true"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupMemberRole::builder()
+            .group("simple/group")
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .ability(MemberRoleAbility::ReadCode)
+            .abilities(
+                [
+                    MemberRoleAbility::AdminMergeRequest,
+                    MemberRoleAbility::ReadCode,
+                    MemberRoleAbility::ReadVulnerability,
+                ]
+                .iter()
+                .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
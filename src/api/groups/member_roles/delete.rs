@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a member role of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupMemberRole<'a> {
+    /// The group to delete the member role from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the member role.
+    member_role: u64,
+}
+
+impl<'a> DeleteGroupMemberRole<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupMemberRoleBuilder<'a> {
+        DeleteGroupMemberRoleBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupMemberRoleBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteGroupMemberRoleBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("member_role", self.member_role.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupMemberRole<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/member_roles/{}",
+            self.group.path_segment(),
+            self.member_role.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::member_roles::{
+        DeleteGroupMemberRole, DeleteGroupMemberRoleBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_member_role_are_necessary() {
+        let err = DeleteGroupMemberRole::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupMemberRoleBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = DeleteGroupMemberRole::builder()
+            .member_role(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupMemberRoleBuilderError, "group");
+    }
+
+    #[test]
+    fn member_role_is_necessary() {
+        let err = DeleteGroupMemberRole::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupMemberRoleBuilderError, "member_role");
+    }
+
+    #[test]
+    fn group_and_member_role_are_sufficient() {
+        DeleteGroupMemberRole::builder()
+            .group(1)
+            .member_role(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteGroupMemberRole::builder()
+            .group(0)
+            .member_role(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteGroupMemberRoleBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/member_roles/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupMemberRole::builder()
+            .group("simple/group")
+            .member_role(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
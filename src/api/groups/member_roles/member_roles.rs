@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the member roles of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupMemberRoles<'a> {
+    /// The group to query for member roles.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupMemberRoles<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMemberRolesBuilder<'a> {
+        GroupMemberRolesBuilder::default()
+    }
+}
+
+impl<'a> GroupMemberRolesBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupMemberRolesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupMemberRoles<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/member_roles", self.group.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::member_roles::{GroupMemberRoles, GroupMemberRolesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_necessary() {
+        let err = GroupMemberRoles::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMemberRolesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupMemberRoles::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMemberRoles::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupMemberRolesBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/member_roles")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMemberRoles::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
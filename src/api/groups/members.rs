@@ -9,16 +9,46 @@
 //! These endpoints are used for querying group members.
 
 mod add;
+mod all_member;
+mod all_members;
+mod ban;
+mod banned;
+mod billable;
 mod edit;
 mod member;
 mod members;
+mod pending;
+mod reconcile;
 mod remove;
+mod unban;
 
 pub use self::add::AddGroupMember;
 pub use self::add::AddGroupMemberBuilder;
 pub use self::add::AddGroupMemberBuilderError;
 pub use self::add::GroupInviteTasksToBeDone;
 
+pub use self::all_member::AllGroupMember;
+pub use self::all_member::AllGroupMemberBuilder;
+pub use self::all_member::AllGroupMemberBuilderError;
+
+pub use self::all_members::AllGroupMembers;
+pub use self::all_members::AllGroupMembersBuilder;
+pub use self::all_members::AllGroupMembersBuilderError;
+pub use self::all_members::GroupMemberState;
+
+pub use self::ban::BanGroupMember;
+pub use self::ban::BanGroupMemberBuilder;
+pub use self::ban::BanGroupMemberBuilderError;
+
+pub use self::banned::BannedGroupMembers;
+pub use self::banned::BannedGroupMembersBuilder;
+pub use self::banned::BannedGroupMembersBuilderError;
+
+pub use self::billable::BillableGroupMembers;
+pub use self::billable::BillableGroupMembersBuilder;
+pub use self::billable::BillableGroupMembersBuilderError;
+pub use self::billable::BillableGroupMembersSort;
+
 pub use self::edit::EditGroupMember;
 pub use self::edit::EditGroupMemberBuilder;
 pub use self::edit::EditGroupMemberBuilderError;
@@ -31,6 +61,19 @@ pub use self::members::GroupMembers;
 pub use self::members::GroupMembersBuilder;
 pub use self::members::GroupMembersBuilderError;
 
+pub use self::pending::PendingGroupMembers;
+pub use self::pending::PendingGroupMembersBuilder;
+pub use self::pending::PendingGroupMembersBuilderError;
+
+pub use self::reconcile::MembershipChange;
+pub use self::reconcile::ReconcileGroupMembers;
+pub use self::reconcile::ReconcileGroupMembersBuilder;
+pub use self::reconcile::ReconcileGroupMembersBuilderError;
+
 pub use self::remove::RemoveGroupMember;
 pub use self::remove::RemoveGroupMemberBuilder;
 pub use self::remove::RemoveGroupMemberBuilderError;
+
+pub use self::unban::UnbanGroupMember;
+pub use self::unban::UnbanGroupMemberBuilder;
+pub use self::unban::UnbanGroupMemberBuilderError;
@@ -8,7 +8,7 @@ use chrono::NaiveDate;
 use derive_builder::Builder;
 use itertools::Itertools;
 
-use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -43,7 +43,7 @@ impl ParamValue<'static> for GroupInviteTasksToBeDone {
 
 /// Add a user as a member of a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct AddGroupMember<'a> {
     /// The group to add the user to.
     #[builder(setter(into))]
@@ -59,6 +59,9 @@ pub struct AddGroupMember<'a> {
     /// The source of the invitation.
     #[builder(setter(into), default)]
     invite_source: Option<Cow<'a, str>>,
+    /// The ID of a member role to assign to the user.
+    #[builder(default)]
+    member_role_id: Option<u64>,
     /// Tasks the inviter wants the member to focus on.
     ///
     /// Requires `tasks_project_id`.
@@ -101,6 +104,12 @@ impl<'a> AddGroupMemberBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), AddGroupMemberBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for AddGroupMember<'a> {
@@ -109,7 +118,7 @@ impl<'a> Endpoint for AddGroupMember<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members", self.group).into()
+        format!("groups/{}/members", self.group.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -119,7 +128,8 @@ impl<'a> Endpoint for AddGroupMember<'a> {
             .push("user_id", self.user)
             .push("access_level", self.access_level.as_u64())
             .push_opt("expires_at", self.expires_at)
-            .push_opt("invite_source", self.invite_source.as_ref());
+            .push_opt("invite_source", self.invite_source.as_ref())
+            .push_opt("member_role_id", self.member_role_id);
 
         #[allow(deprecated)]
         {
@@ -129,7 +139,7 @@ impl<'a> Endpoint for AddGroupMember<'a> {
                         .iter()
                         .map(|task| task.as_str())
                         .unique()
-                        .map(|value| ("tasks_to_be_done[]", value)),
+                        .map(|value| (ParamName::new("tasks_to_be_done").array(), value)),
                 )
                 .push_opt("tasks_project_id", self.tasks_project_id);
         }
@@ -209,6 +219,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = AddGroupMember::builder()
+            .group("")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, AddGroupMemberBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -279,6 +300,31 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/group%2Fsubgroup/members")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "user_id=1",
+                "&access_level=30",
+                "&member_role_id=1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupMember::builder()
+            .group("group/subgroup")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .member_role_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     #[allow(deprecated)]
     fn endpoint_tasks_to_be_done() {
@@ -0,0 +1,108 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query a single member of a group with ancestor collapsing.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct AllGroupMember<'a> {
+    /// The group to query for membership.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the user.
+    user: u64,
+}
+
+impl<'a> AllGroupMember<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AllGroupMemberBuilder<'a> {
+        AllGroupMemberBuilder::default()
+    }
+}
+
+impl<'a> AllGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), AllGroupMemberBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for AllGroupMember<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/members/all/{}",
+            self.group.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::members::{AllGroupMember, AllGroupMemberBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_user_are_needed() {
+        let err = AllGroupMember::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AllGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = AllGroupMember::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, AllGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = AllGroupMember::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, AllGroupMemberBuilderError, "user");
+    }
+
+    #[test]
+    fn group_and_user_are_sufficient() {
+        AllGroupMember::builder().group(1).user(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = AllGroupMember::builder()
+            .group(0)
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, AllGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/members/all/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllGroupMember::builder()
+            .group("group/subgroup")
+            .user(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
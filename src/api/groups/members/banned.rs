@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for banned members of a top-level group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct BannedGroupMembers<'a> {
+    /// The group to query for banned members.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> BannedGroupMembers<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BannedGroupMembersBuilder<'a> {
+        BannedGroupMembersBuilder::default()
+    }
+}
+
+impl<'a> BannedGroupMembersBuilder<'a> {
+    fn validate(&self) -> Result<(), BannedGroupMembersBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for BannedGroupMembers<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/banned_members", self.group.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for BannedGroupMembers<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::members::{BannedGroupMembers, BannedGroupMembersBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = BannedGroupMembers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BannedGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        BannedGroupMembers::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = BannedGroupMembers::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BannedGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/banned_members")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BannedGroupMembers::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Sort orders for billable members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BillableGroupMembersSort {
+    /// Sort by access level (ascending).
+    AccessLevelAsc,
+    /// Sort by access level (descending).
+    AccessLevelDesc,
+    /// Sort by the most recent join time.
+    LastJoined,
+    /// Sort by name (ascending).
+    NameAsc,
+    /// Sort by name (descending).
+    NameDesc,
+    /// Sort by the oldest join time.
+    OldestJoined,
+    /// Sort by the oldest sign in time.
+    OldestSignIn,
+    /// Sort by the most recent sign in time.
+    RecentSignIn,
+    /// Sort by the last activity date (ascending).
+    LastActivityOnAsc,
+    /// Sort by the last activity date (descending).
+    LastActivityOnDesc,
+}
+
+impl BillableGroupMembersSort {
+    /// The sort order as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            BillableGroupMembersSort::AccessLevelAsc => "access_level_asc",
+            BillableGroupMembersSort::AccessLevelDesc => "access_level_desc",
+            BillableGroupMembersSort::LastJoined => "last_joined",
+            BillableGroupMembersSort::NameAsc => "name_asc",
+            BillableGroupMembersSort::NameDesc => "name_desc",
+            BillableGroupMembersSort::OldestJoined => "oldest_joined",
+            BillableGroupMembersSort::OldestSignIn => "oldest_sign_in",
+            BillableGroupMembersSort::RecentSignIn => "recent_sign_in",
+            BillableGroupMembersSort::LastActivityOnAsc => "last_activity_on_asc",
+            BillableGroupMembersSort::LastActivityOnDesc => "last_activity_on_desc",
+        }
+    }
+}
+
+impl ParamValue<'static> for BillableGroupMembersSort {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for billable members of a top-level group.
+///
+/// Billable members include members of subgroups and projects within the group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct BillableGroupMembers<'a> {
+    /// The group to query for billable members.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Search for members by name, username, or public email.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<BillableGroupMembersSort>,
+}
+
+impl<'a> BillableGroupMembers<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BillableGroupMembersBuilder<'a> {
+        BillableGroupMembersBuilder::default()
+    }
+}
+
+impl<'a> BillableGroupMembersBuilder<'a> {
+    fn validate(&self) -> Result<(), BillableGroupMembersBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for BillableGroupMembers<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/billable_members", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for BillableGroupMembers<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::members::{
+        BillableGroupMembers, BillableGroupMembersBuilderError, BillableGroupMembersSort,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn billable_group_members_sort_as_str() {
+        let items = &[
+            (BillableGroupMembersSort::AccessLevelAsc, "access_level_asc"),
+            (
+                BillableGroupMembersSort::AccessLevelDesc,
+                "access_level_desc",
+            ),
+            (BillableGroupMembersSort::LastJoined, "last_joined"),
+            (BillableGroupMembersSort::NameAsc, "name_asc"),
+            (BillableGroupMembersSort::NameDesc, "name_desc"),
+            (BillableGroupMembersSort::OldestJoined, "oldest_joined"),
+            (BillableGroupMembersSort::OldestSignIn, "oldest_sign_in"),
+            (BillableGroupMembersSort::RecentSignIn, "recent_sign_in"),
+            (
+                BillableGroupMembersSort::LastActivityOnAsc,
+                "last_activity_on_asc",
+            ),
+            (
+                BillableGroupMembersSort::LastActivityOnDesc,
+                "last_activity_on_desc",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = BillableGroupMembers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BillableGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        BillableGroupMembers::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = BillableGroupMembers::builder()
+            .group(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BillableGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/billable_members")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMembers::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/billable_members")
+            .add_query_params(&[("search", "name")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMembers::builder()
+            .group("group/subgroup")
+            .search("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/billable_members")
+            .add_query_params(&[("sort", "recent_sign_in")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMembers::builder()
+            .group("group/subgroup")
+            .sort(BillableGroupMembersSort::RecentSignIn)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
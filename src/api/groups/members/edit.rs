@@ -7,12 +7,12 @@
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Edit a member of a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditGroupMember<'a> {
     /// The group to add the user to.
     #[builder(setter(into))]
@@ -37,13 +37,27 @@ impl<'a> EditGroupMember<'a> {
     }
 }
 
+impl<'a> EditGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), EditGroupMemberBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditGroupMember<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members/{}", self.group, self.user).into()
+        format!(
+            "groups/{}/members/{}",
+            self.group.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -115,6 +129,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditGroupMember::builder()
+            .group("")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditGroupMemberBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a single member of a group.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GroupMember<'a> {
     /// The group to query for membership.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> GroupMember<'a> {
     }
 }
 
+impl<'a> GroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupMemberBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GroupMember<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members/{}", self.group, self.user).into()
+        format!(
+            "groups/{}/members/{}",
+            self.group.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,12 @@ mod tests {
         GroupMember::builder().group(1).user(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMember::builder().group(0).user(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupMemberBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -8,12 +8,12 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a members of a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupMembers<'a> {
     /// The group to query for membership.
     #[builder(setter(into))]
@@ -76,6 +76,12 @@ impl<'a> GroupMembersBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), GroupMembersBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for GroupMembers<'a> {
@@ -84,7 +90,7 @@ impl<'a> Endpoint for GroupMembers<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members", self.group).into()
+        format!("groups/{}/members", self.group.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -92,8 +98,16 @@ impl<'a> Endpoint for GroupMembers<'a> {
 
         params
             .push_opt("query", self.query.as_ref())
-            .extend(self.user_ids.iter().map(|&value| ("user_ids[]", value)))
-            .extend(self.skip_users.iter().map(|&value| ("skip_users[]", value)))
+            .extend(
+                self.user_ids
+                    .iter()
+                    .map(|&value| (ParamName::new("user_ids").array(), value)),
+            )
+            .extend(
+                self.skip_users
+                    .iter()
+                    .map(|&value| (ParamName::new("skip_users").array(), value)),
+            )
             .push_opt("show_seat_info", self.show_seat_info);
 
         params
@@ -119,6 +133,12 @@ mod tests {
         GroupMembers::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMembers::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupMembersBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
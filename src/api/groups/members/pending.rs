@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for pending members of a top-level group and its subgroups and projects.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct PendingGroupMembers<'a> {
+    /// The group to query for pending members.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> PendingGroupMembers<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PendingGroupMembersBuilder<'a> {
+        PendingGroupMembersBuilder::default()
+    }
+}
+
+impl<'a> PendingGroupMembersBuilder<'a> {
+    fn validate(&self) -> Result<(), PendingGroupMembersBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for PendingGroupMembers<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/pending_members", self.group.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for PendingGroupMembers<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::members::{PendingGroupMembers, PendingGroupMembersBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = PendingGroupMembers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PendingGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        PendingGroupMembers::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = PendingGroupMembers::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PendingGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/pending_members")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PendingGroupMembers::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,611 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::groups::members::{
+    AddGroupMember, EditGroupMember, GroupMembers, RemoveGroupMember,
+};
+use crate::api::users::Users;
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// A change required to make a group's membership match a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MembershipChange {
+    /// Add a user to the group.
+    Add {
+        /// The ID of the user.
+        user: u64,
+        /// The username of the user.
+        username: String,
+        /// The access level to grant.
+        access_level: AccessLevel,
+    },
+    /// Change the access level of an existing member.
+    ChangeAccess {
+        /// The ID of the user.
+        user: u64,
+        /// The username of the user.
+        username: String,
+        /// The member's current access level.
+        from: AccessLevel,
+        /// The access level to grant.
+        to: AccessLevel,
+    },
+    /// Remove a member from the group.
+    Remove {
+        /// The ID of the user.
+        user: u64,
+        /// The username of the user.
+        username: String,
+        /// The member's current access level.
+        access_level: AccessLevel,
+    },
+    /// A user in the directory does not exist on the instance.
+    ///
+    /// No action is taken for these users.
+    UnknownUser {
+        /// The username from the directory.
+        username: String,
+        /// The access level which would have been granted.
+        access_level: AccessLevel,
+    },
+}
+
+/// The fields of a group member needed for reconciliation.
+#[derive(Debug, Deserialize)]
+struct ExistingMember {
+    id: u64,
+    username: String,
+    access_level: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserId {
+    id: u64,
+}
+
+// Custom roles may report levels which are not in `AccessLevel`; use the closest level which is
+// not more privileged.
+fn access_level_from_u64(level: u64) -> AccessLevel {
+    [
+        AccessLevel::Admin,
+        AccessLevel::Owner,
+        AccessLevel::Maintainer,
+        AccessLevel::Developer,
+        AccessLevel::Reporter,
+        AccessLevel::Guest,
+        AccessLevel::Minimal,
+    ]
+    .into_iter()
+    .find(|known| known.as_u64() <= level)
+    .unwrap_or(AccessLevel::Anonymous)
+}
+
+/// Reconcile the direct members of a group against an external directory (e.g., an IdP).
+///
+/// The current direct members of the group are fetched and compared against the desired set of
+/// members by username (case-insensitively). Users in the directory are looked up by username;
+/// those which do not exist on the instance are reported as `MembershipChange::UnknownUser` and
+/// otherwise ignored.
+///
+/// Results are the changes which were made. In dry-run mode, the planned changes are returned
+/// without modifying the group.
+///
+/// Note that, by default, members which are not in the directory are removed from the group. Use
+/// `ignore_user` to protect accounts (e.g., bots) which are managed outside of the directory.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ReconcileGroupMembers<'a> {
+    /// The group to reconcile.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The desired members of the group.
+    #[builder(setter(name = "_members"), default, private)]
+    members: BTreeMap<Cow<'a, str>, AccessLevel>,
+    /// Usernames which are never changed.
+    #[builder(setter(name = "_ignore_users"), default, private)]
+    ignore_users: BTreeSet<Cow<'a, str>>,
+
+    /// Remove members which are not in the directory.
+    #[builder(default = "true")]
+    remove_unlisted: bool,
+    /// Only report the changes which would be made.
+    #[builder(default)]
+    dry_run: bool,
+}
+
+impl<'a> ReconcileGroupMembers<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> ReconcileGroupMembersBuilder<'a> {
+        ReconcileGroupMembersBuilder::default()
+    }
+}
+
+impl<'a> ReconcileGroupMembersBuilder<'a> {
+    /// A user which should be a member of the group.
+    pub fn member<U>(&mut self, username: U, access_level: AccessLevel) -> &mut Self
+    where
+        U: Into<Cow<'a, str>>,
+    {
+        self.members
+            .get_or_insert_with(BTreeMap::new)
+            .insert(username.into(), access_level);
+        self
+    }
+
+    /// Users which should be members of the group.
+    pub fn members<I, U>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (U, AccessLevel)>,
+        U: Into<Cow<'a, str>>,
+    {
+        self.members
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(username, access_level)| (username.into(), access_level)));
+        self
+    }
+
+    /// Never change the membership of a user.
+    pub fn ignore_user<U>(&mut self, username: U) -> &mut Self
+    where
+        U: Into<Cow<'a, str>>,
+    {
+        self.ignore_users
+            .get_or_insert_with(BTreeSet::new)
+            .insert(username.into());
+        self
+    }
+
+    /// Never change the membership of a set of users.
+    pub fn ignore_users<I, U>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = U>,
+        U: Into<Cow<'a, str>>,
+    {
+        self.ignore_users
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> ReconcileGroupMembers<'a> {
+    fn members_query<E>(&self) -> Result<GroupMembers<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        GroupMembers::builder()
+            .group(self.group.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    /// Compare existing members with the directory.
+    ///
+    /// Returns the changes for existing members and the directory entries which are not yet
+    /// members of the group.
+    fn plan(
+        &self,
+        existing: Vec<ExistingMember>,
+    ) -> (Vec<MembershipChange>, Vec<(&str, AccessLevel)>) {
+        let ignored = self
+            .ignore_users
+            .iter()
+            .map(|username| username.to_lowercase())
+            .collect::<BTreeSet<_>>();
+        let mut desired = self
+            .members
+            .iter()
+            .filter(|(username, _)| !ignored.contains(&username.to_lowercase()))
+            .map(|(username, &access_level)| {
+                (username.to_lowercase(), (username.as_ref(), access_level))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut changes = Vec::new();
+        for member in existing {
+            let key = member.username.to_lowercase();
+            if ignored.contains(&key) {
+                continue;
+            }
+
+            let current = access_level_from_u64(member.access_level);
+            if let Some((_, access_level)) = desired.remove(&key) {
+                if member.access_level != access_level.as_u64() {
+                    changes.push(MembershipChange::ChangeAccess {
+                        user: member.id,
+                        username: member.username,
+                        from: current,
+                        to: access_level,
+                    });
+                }
+            } else if self.remove_unlisted {
+                changes.push(MembershipChange::Remove {
+                    user: member.id,
+                    username: member.username,
+                    access_level: current,
+                });
+            }
+        }
+
+        (changes, desired.into_values().collect())
+    }
+
+    fn user_query<'b>(username: &'b str) -> Users<'b> {
+        Users::builder().username(username).build().unwrap()
+    }
+
+    fn addition(username: &str, access_level: AccessLevel, users: Vec<UserId>) -> MembershipChange {
+        if let Some(user) = users.first() {
+            MembershipChange::Add {
+                user: user.id,
+                username: username.into(),
+                access_level,
+            }
+        } else {
+            MembershipChange::UnknownUser {
+                username: username.into(),
+                access_level,
+            }
+        }
+    }
+}
+
+impl<'a, C> Query<Vec<MembershipChange>, C> for ReconcileGroupMembers<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<MembershipChange>, ApiError<C::Error>> {
+        let existing = api::paged(self.members_query()?, Pagination::All).query(client)?;
+        let (mut changes, additions) = self.plan(existing);
+
+        for (username, access_level) in additions {
+            let users = Self::user_query(username).query(client)?;
+            changes.push(Self::addition(username, access_level, users));
+        }
+
+        if !self.dry_run {
+            for change in &changes {
+                match *change {
+                    MembershipChange::Add {
+                        user,
+                        access_level,
+                        ..
+                    } => {
+                        let endpoint = AddGroupMember::builder()
+                            .group(self.group.clone())
+                            .user(user)
+                            .access_level(access_level)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query(client)?;
+                    },
+                    MembershipChange::ChangeAccess {
+                        user,
+                        to,
+                        ..
+                    } => {
+                        let endpoint = EditGroupMember::builder()
+                            .group(self.group.clone())
+                            .user(user)
+                            .access_level(to)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query(client)?;
+                    },
+                    MembershipChange::Remove {
+                        user, ..
+                    } => {
+                        let endpoint = RemoveGroupMember::builder()
+                            .group(self.group.clone())
+                            .user(user)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query(client)?;
+                    },
+                    MembershipChange::UnknownUser {
+                        ..
+                    } => (),
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<Vec<MembershipChange>, C> for ReconcileGroupMembers<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<MembershipChange>, ApiError<C::Error>> {
+        let existing = api::paged(self.members_query()?, Pagination::All)
+            .query_async(client)
+            .await?;
+        let (mut changes, additions) = self.plan(existing);
+
+        for (username, access_level) in additions {
+            let users = Self::user_query(username).query_async(client).await?;
+            changes.push(Self::addition(username, access_level, users));
+        }
+
+        if !self.dry_run {
+            for change in &changes {
+                match *change {
+                    MembershipChange::Add {
+                        user,
+                        access_level,
+                        ..
+                    } => {
+                        let endpoint = AddGroupMember::builder()
+                            .group(self.group.clone())
+                            .user(user)
+                            .access_level(access_level)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query_async(client).await?;
+                    },
+                    MembershipChange::ChangeAccess {
+                        user,
+                        to,
+                        ..
+                    } => {
+                        let endpoint = EditGroupMember::builder()
+                            .group(self.group.clone())
+                            .user(user)
+                            .access_level(to)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query_async(client).await?;
+                    },
+                    MembershipChange::Remove {
+                        user, ..
+                    } => {
+                        let endpoint = RemoveGroupMember::builder()
+                            .group(self.group.clone())
+                            .user(user)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query_async(client).await?;
+                    },
+                    MembershipChange::UnknownUser {
+                        ..
+                    } => (),
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::groups::members::{
+        MembershipChange, ReconcileGroupMembers, ReconcileGroupMembersBuilderError,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient};
+
+    use super::{access_level_from_u64, ExistingMember};
+
+    #[derive(Debug, Serialize)]
+    struct DummyMember {
+        id: u64,
+        username: &'static str,
+        access_level: u64,
+    }
+
+    fn members() -> Vec<DummyMember> {
+        vec![
+            DummyMember {
+                id: 1,
+                username: "alice",
+                access_level: 30,
+            },
+            DummyMember {
+                id: 2,
+                username: "Bob",
+                access_level: 40,
+            },
+            DummyMember {
+                id: 3,
+                username: "carol",
+                access_level: 20,
+            },
+            DummyMember {
+                id: 4,
+                username: "deploy-bot",
+                access_level: 40,
+            },
+        ]
+    }
+
+    fn existing() -> Vec<ExistingMember> {
+        members()
+            .into_iter()
+            .map(|member| {
+                ExistingMember {
+                    id: member.id,
+                    username: member.username.into(),
+                    access_level: member.access_level,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn access_level_from_u64_known() {
+        let items = &[
+            AccessLevel::Anonymous,
+            AccessLevel::Minimal,
+            AccessLevel::Guest,
+            AccessLevel::Reporter,
+            AccessLevel::Developer,
+            AccessLevel::Maintainer,
+            AccessLevel::Owner,
+            AccessLevel::Admin,
+        ];
+
+        for level in items {
+            assert_eq!(access_level_from_u64(level.as_u64()), *level);
+        }
+    }
+
+    #[test]
+    fn access_level_from_u64_unknown() {
+        assert_eq!(access_level_from_u64(25), AccessLevel::Reporter);
+        assert_eq!(access_level_from_u64(100), AccessLevel::Admin);
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = ReconcileGroupMembers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ReconcileGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        ReconcileGroupMembers::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn plan() {
+        let endpoint = ReconcileGroupMembers::builder()
+            .group("group")
+            .member("alice", AccessLevel::Developer)
+            .member("bob", AccessLevel::Reporter)
+            .member("dave", AccessLevel::Guest)
+            .ignore_user("deploy-bot")
+            .build()
+            .unwrap();
+
+        let (changes, additions) = endpoint.plan(existing());
+        assert_eq!(
+            changes,
+            [
+                MembershipChange::ChangeAccess {
+                    user: 2,
+                    username: "Bob".into(),
+                    from: AccessLevel::Maintainer,
+                    to: AccessLevel::Reporter,
+                },
+                MembershipChange::Remove {
+                    user: 3,
+                    username: "carol".into(),
+                    access_level: AccessLevel::Reporter,
+                },
+            ],
+        );
+        assert_eq!(additions, [("dave", AccessLevel::Guest)]);
+    }
+
+    #[test]
+    fn plan_keep_unlisted() {
+        let endpoint = ReconcileGroupMembers::builder()
+            .group("group")
+            .members(
+                [
+                    ("alice", AccessLevel::Developer),
+                    ("bob", AccessLevel::Maintainer),
+                ]
+                .into_iter(),
+            )
+            .remove_unlisted(false)
+            .build()
+            .unwrap();
+
+        let (changes, additions) = endpoint.plan(existing());
+        assert!(changes.is_empty());
+        assert!(additions.is_empty());
+    }
+
+    #[test]
+    fn plan_ignored_directory_entry() {
+        let endpoint = ReconcileGroupMembers::builder()
+            .group("group")
+            .member("eve", AccessLevel::Owner)
+            .ignore_users(["EVE"].into_iter())
+            .remove_unlisted(false)
+            .build()
+            .unwrap();
+
+        let (changes, additions) = endpoint.plan(existing());
+        assert!(changes.is_empty());
+        assert!(additions.is_empty());
+    }
+
+    #[test]
+    fn dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group/members")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, members());
+
+        let endpoint = ReconcileGroupMembers::builder()
+            .group("group")
+            .member("alice", AccessLevel::Maintainer)
+            .member("bob", AccessLevel::Maintainer)
+            .member("carol", AccessLevel::Reporter)
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<MembershipChange> = endpoint.query(&client).unwrap();
+        assert_eq!(
+            res,
+            [
+                MembershipChange::ChangeAccess {
+                    user: 1,
+                    username: "alice".into(),
+                    from: AccessLevel::Developer,
+                    to: AccessLevel::Maintainer,
+                },
+                MembershipChange::Remove {
+                    user: 4,
+                    username: "deploy-bot".into(),
+                    access_level: AccessLevel::Maintainer,
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn dry_run_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group/members")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, members());
+
+        let endpoint = ReconcileGroupMembers::builder()
+            .group("group")
+            .member("alice", AccessLevel::Developer)
+            .member("bob", AccessLevel::Maintainer)
+            .member("carol", AccessLevel::Reporter)
+            .ignore_user("deploy-bot")
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<MembershipChange> = endpoint.query_async(&client).await.unwrap();
+        assert!(res.is_empty());
+    }
+}
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Remove a user from a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RemoveGroupMember<'a> {
     /// The group to remove the user from.
     #[builder(setter(into))]
@@ -33,13 +33,27 @@ impl<'a> RemoveGroupMember<'a> {
     }
 }
 
+impl<'a> RemoveGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), RemoveGroupMemberBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RemoveGroupMember<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members/{}", self.group, self.user).into()
+        format!(
+            "groups/{}/members/{}",
+            self.group.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -88,6 +102,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = RemoveGroupMember::builder()
+            .group("")
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RemoveGroupMemberBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,115 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Unban a member of a top-level group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UnbanGroupMember<'a> {
+    /// The group to unban the user from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The user to unban.
+    user: u64,
+}
+
+impl<'a> UnbanGroupMember<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnbanGroupMemberBuilder<'a> {
+        UnbanGroupMemberBuilder::default()
+    }
+}
+
+impl<'a> UnbanGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), UnbanGroupMemberBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UnbanGroupMember<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/members/{}/unban",
+            self.group.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::members::{UnbanGroupMember, UnbanGroupMemberBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = UnbanGroupMember::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnbanGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_necessary() {
+        let err = UnbanGroupMember::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnbanGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = UnbanGroupMember::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnbanGroupMemberBuilderError, "user");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        UnbanGroupMember::builder()
+            .group("group")
+            .user(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = UnbanGroupMember::builder()
+            .group("")
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnbanGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/members/1/unban")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnbanGroupMember::builder()
+            .group("group/subgroup")
+            .user(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
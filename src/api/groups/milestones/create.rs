@@ -8,12 +8,12 @@ use derive_builder::Builder;
 
 use chrono::NaiveDate;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new milestone on a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateGroupMilestone<'a> {
     /// The group to create a new milestone within.
     #[builder(setter(into))]
@@ -40,13 +40,21 @@ impl<'a> CreateGroupMilestone<'a> {
     }
 }
 
+impl<'a> CreateGroupMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateGroupMilestoneBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateGroupMilestone<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/milestones", self.group).into()
+        format!("groups/{}/milestones", self.group.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -104,6 +112,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateGroupMilestone::builder()
+            .group("")
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateGroupMilestoneBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
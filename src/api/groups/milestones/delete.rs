@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a milestone within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupMilestone<'a> {
+    /// The group of the milestone.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> DeleteGroupMilestone<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupMilestoneBuilder<'a> {
+        DeleteGroupMilestoneBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteGroupMilestoneBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("milestone", self.milestone.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupMilestone<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/milestones/{}",
+            self.group.path_segment(),
+            self.milestone.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::milestones::{DeleteGroupMilestone, DeleteGroupMilestoneBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = DeleteGroupMilestone::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupMilestone::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = DeleteGroupMilestone::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupMilestoneBuilderError, "milestone");
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        DeleteGroupMilestone::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteGroupMilestone::builder()
+            .group(0)
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteGroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,253 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::milestones::MilestoneStateEvent;
+
+/// Edit a milestone within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditGroupMilestone<'a> {
+    /// The group of the milestone.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+
+    /// The new title of the milestone.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// A short description for the milestone.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// When the milestone is due.
+    #[builder(default)]
+    due_date: Option<NaiveDate>,
+    /// When the milestone starts.
+    #[builder(default)]
+    start_date: Option<NaiveDate>,
+    /// Change the state of the milestone.
+    #[builder(default)]
+    state_event: Option<MilestoneStateEvent>,
+}
+
+impl<'a> EditGroupMilestone<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupMilestoneBuilder<'a> {
+        EditGroupMilestoneBuilder::default()
+    }
+}
+
+impl<'a> EditGroupMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), EditGroupMilestoneBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("milestone", self.milestone.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditGroupMilestone<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/milestones/{}",
+            self.group.path_segment(),
+            self.milestone.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("title", self.title.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt("due_date", self.due_date)
+            .push_opt("start_date", self.start_date)
+            .push_opt("state_event", self.state_event);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::groups::milestones::{EditGroupMilestone, EditGroupMilestoneBuilderError};
+    use crate::api::projects::milestones::MilestoneStateEvent;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = EditGroupMilestone::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupMilestone::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = EditGroupMilestone::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupMilestoneBuilderError, "milestone");
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        EditGroupMilestone::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditGroupMilestone::builder()
+            .group(0)
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditGroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=description")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_due_date() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("due_date=2020-01-01")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .due_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_start_date() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("start_date=2020-01-01")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state_event() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("state_event=close")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .state_event(MilestoneStateEvent::Close)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
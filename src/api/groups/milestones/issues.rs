@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the issues assigned to a group milestone.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupMilestoneIssues<'a> {
+    /// The group of the milestone.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> GroupMilestoneIssues<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestoneIssuesBuilder<'a> {
+        GroupMilestoneIssuesBuilder::default()
+    }
+}
+
+impl<'a> GroupMilestoneIssuesBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupMilestoneIssuesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("milestone", self.milestone.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupMilestoneIssues<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/milestones/{}/issues",
+            self.group.path_segment(),
+            self.milestone.path_segment()
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for GroupMilestoneIssues<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::milestones::{GroupMilestoneIssues, GroupMilestoneIssuesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = GroupMilestoneIssues::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneIssuesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestoneIssues::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneIssuesBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = GroupMilestoneIssues::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneIssuesBuilderError, "milestone");
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        GroupMilestoneIssues::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMilestoneIssues::builder()
+            .group(0)
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupMilestoneIssuesBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones/1/issues")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestoneIssues::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
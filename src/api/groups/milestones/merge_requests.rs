@@ -0,0 +1,130 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the merge requests assigned to a group milestone.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupMilestoneMergeRequests<'a> {
+    /// The group of the milestone.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> GroupMilestoneMergeRequests<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestoneMergeRequestsBuilder<'a> {
+        GroupMilestoneMergeRequestsBuilder::default()
+    }
+}
+
+impl<'a> GroupMilestoneMergeRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupMilestoneMergeRequestsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("milestone", self.milestone.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupMilestoneMergeRequests<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/milestones/{}/merge_requests",
+            self.group.path_segment(),
+            self.milestone.path_segment()
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for GroupMilestoneMergeRequests<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::milestones::{
+        GroupMilestoneMergeRequests, GroupMilestoneMergeRequestsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = GroupMilestoneMergeRequests::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneMergeRequestsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestoneMergeRequests::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneMergeRequestsBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = GroupMilestoneMergeRequests::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            GroupMilestoneMergeRequestsBuilderError,
+            "milestone"
+        );
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        GroupMilestoneMergeRequests::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMilestoneMergeRequests::builder()
+            .group(0)
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            GroupMilestoneMergeRequestsBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones/1/merge_requests")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestoneMergeRequests::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a single milestone within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupMilestone<'a> {
+    /// The group of the milestone.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> GroupMilestone<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestoneBuilder<'a> {
+        GroupMilestoneBuilder::default()
+    }
+}
+
+impl<'a> GroupMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupMilestoneBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("milestone", self.milestone.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupMilestone<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/milestones/{}",
+            self.group.path_segment(),
+            self.milestone.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::milestones::{GroupMilestone, GroupMilestoneBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = GroupMilestone::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestone::builder().milestone(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = GroupMilestone::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneBuilderError, "milestone");
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        GroupMilestone::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMilestone::builder()
+            .group(0)
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupMilestoneBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestone::builder()
+            .group("group/subgroup")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,289 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::milestones::MilestoneState;
+
+/// Query for milestones within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupMilestones<'a> {
+    /// The group to query for milestones.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Return only the milestones having the given internal IDs.
+    #[builder(setter(name = "_iids"), default, private)]
+    iids: BTreeSet<u64>,
+    /// Filter milestones by state.
+    #[builder(default)]
+    state: Option<MilestoneState>,
+    /// Return only milestones with the given title.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// Search for milestones with a title or description matching the given string.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Include milestones from ancestor groups.
+    #[builder(default)]
+    include_ancestors: Option<bool>,
+    /// Include milestones from descendant groups and their projects.
+    #[builder(default)]
+    include_descendants: Option<bool>,
+    /// Return only milestones updated before the given time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+    /// Return only milestones updated after the given time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+}
+
+impl<'a> GroupMilestones<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestonesBuilder<'a> {
+        GroupMilestonesBuilder::default()
+    }
+}
+
+impl<'a> GroupMilestonesBuilder<'a> {
+    /// Return a milestone with an internal ID.
+    pub fn iid(&mut self, iid: u64) -> &mut Self {
+        self.iids.get_or_insert_with(BTreeSet::new).insert(iid);
+        self
+    }
+
+    /// Return milestones with one of a set of internal IDs.
+    pub fn iids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.iids.get_or_insert_with(BTreeSet::new).extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), GroupMilestonesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupMilestones<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/milestones", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .extend(
+                self.iids
+                    .iter()
+                    .map(|&value| (ParamName::new("iids").array(), value)),
+            )
+            .push_opt("state", self.state)
+            .push_opt("title", self.title.as_ref())
+            .push_opt("search", self.search.as_ref())
+            .push_opt("include_ancestors", self.include_ancestors)
+            .push_opt("include_descendants", self.include_descendants)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("updated_after", self.updated_after);
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupMilestones<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::groups::milestones::{GroupMilestones, GroupMilestonesBuilderError};
+    use crate::api::projects::milestones::MilestoneState;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestones::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestonesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupMilestones::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupMilestones::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupMilestonesBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_iids() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("iids[]", "1"), ("iids[]", "2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .iid(1)
+            .iids([1, 2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("state", "closed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .state(MilestoneState::Closed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("title", "title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestors() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("include_ancestors", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .include_ancestors(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_descendants() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("include_descendants", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .include_descendants(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/milestones")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("group/subgroup")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::packages::{PackageStatus, PackageType};
 use crate::api::{endpoint_prelude::*, ParamValue};
 use derive_builder::Builder;
@@ -46,7 +46,7 @@ impl ParamValue<'static> for PackageOrderBy {
 
 /// List packages within a group
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Packages<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -91,13 +91,21 @@ impl<'a> Packages<'a> {
     }
 }
 
+impl<'a> PackagesBuilder<'a> {
+    fn validate(&self) -> Result<(), PackagesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Packages<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/packages", self.project).into()
+        format!("groups/{}/packages", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -158,6 +166,12 @@ mod tests {
         Packages::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Packages::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PackagesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -8,9 +8,15 @@
 //!
 //! These endpoints are used for querying group projects.
 
+mod archive;
 mod projects;
 mod shared;
 
+pub use self::archive::ArchiveAction;
+pub use self::archive::ArchiveGroupProjects;
+pub use self::archive::ArchiveGroupProjectsBuilder;
+pub use self::archive::ArchiveGroupProjectsBuilderError;
+
 pub use self::projects::GroupProjects;
 pub use self::projects::GroupProjectsBuilder;
 pub use self::projects::GroupProjectsBuilderError;
@@ -0,0 +1,458 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::groups::projects::GroupProjects;
+use crate::api::projects::{ArchiveProject, UnarchiveProject};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// The archive state to apply to projects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArchiveAction {
+    /// Archive the projects.
+    Archive,
+    /// Unarchive the projects.
+    Unarchive,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for ArchiveAction {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        ArchiveAction::Archive
+    }
+}
+
+impl ArchiveAction {
+    /// Whether projects are archived after the action.
+    fn target_state(self) -> bool {
+        match self {
+            ArchiveAction::Archive => true,
+            ArchiveAction::Unarchive => false,
+        }
+    }
+}
+
+/// The fields of a project needed to decide whether to act on it.
+#[derive(Debug, Deserialize)]
+struct ProjectArchiveState {
+    id: u64,
+    #[serde(default)]
+    archived: bool,
+}
+
+/// Archive (or unarchive) all projects in a group matching a query.
+///
+/// Projects which are already in the requested state are skipped. Results are the project
+/// objects returned by the archive endpoint for each affected project. In dry-run mode, no
+/// projects are modified and the results are the listed projects which would be affected.
+///
+/// Projects are changed one at a time in the order they are listed, so the operation is not
+/// atomic. If a request fails, its error is returned and no results are available, but any
+/// projects changed before the failure remain changed. Listing the projects again will show
+/// their current state; the operation may be retried as affected projects are skipped.
+#[derive(Debug, Builder, Clone)]
+pub struct ArchiveGroupProjects<'a> {
+    /// The query selecting the projects to act upon.
+    projects: GroupProjects<'a>,
+
+    /// Whether to archive or unarchive the projects.
+    #[builder(default)]
+    action: ArchiveAction,
+    /// Only report the projects which would be affected.
+    #[builder(default)]
+    dry_run: bool,
+}
+
+impl<'a> ArchiveGroupProjects<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> ArchiveGroupProjectsBuilder<'a> {
+        ArchiveGroupProjectsBuilder::default()
+    }
+
+    /// Determine which listed projects need to change state.
+    fn candidates<E>(&self, projects: Vec<Value>) -> Result<Vec<(u64, Value)>, ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let target = self.action.target_state();
+        projects
+            .into_iter()
+            .filter_map(|project| {
+                match ProjectArchiveState::deserialize(&project) {
+                    Ok(state) if state.archived == target => None,
+                    Ok(state) => Some(Ok((state.id, project))),
+                    Err(err) => Some(Err(ApiError::data_type::<ProjectArchiveState>(err))),
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a, T, C> Query<Vec<T>, C> for ArchiveGroupProjects<'a>
+where
+    T: DeserializeOwned + 'static,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let projects = api::paged(&self.projects, Pagination::All).query(client)?;
+
+        self.candidates(projects)?
+            .into_iter()
+            .map(|(id, project)| {
+                if self.dry_run {
+                    return serde_json::from_value(project).map_err(ApiError::data_type::<T>);
+                }
+
+                match self.action {
+                    ArchiveAction::Archive => {
+                        ArchiveProject::builder()
+                            .project(id)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?
+                            .query(client)
+                    },
+                    ArchiveAction::Unarchive => {
+                        UnarchiveProject::builder()
+                            .project(id)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?
+                            .query(client)
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<'a, T, C> AsyncQuery<Vec<T>, C> for ArchiveGroupProjects<'a>
+where
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let projects = api::paged(&self.projects, Pagination::All)
+            .query_async(client)
+            .await?;
+
+        let mut results = Vec::new();
+        for (id, project) in self.candidates(projects)? {
+            let result = if self.dry_run {
+                serde_json::from_value(project).map_err(ApiError::data_type::<T>)?
+            } else {
+                match self.action {
+                    ArchiveAction::Archive => {
+                        ArchiveProject::builder()
+                            .project(id)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?
+                            .query_async(client)
+                            .await?
+                    },
+                    ArchiveAction::Unarchive => {
+                        UnarchiveProject::builder()
+                            .project(id)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?
+                            .query_async(client)
+                            .await?
+                    },
+                }
+            };
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::api::groups::projects::{
+        ArchiveAction, ArchiveGroupProjects, ArchiveGroupProjectsBuilderError, GroupProjects,
+    };
+    use crate::api::{ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct DummyProject {
+        id: u64,
+        archived: bool,
+    }
+
+    fn projects() -> Vec<DummyProject> {
+        vec![
+            DummyProject {
+                id: 1,
+                archived: false,
+            },
+            DummyProject {
+                id: 2,
+                archived: true,
+            },
+            DummyProject {
+                id: 3,
+                archived: false,
+            },
+        ]
+    }
+
+    fn list_request() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("groups/group%2Fsubgroup/projects")
+            .build()
+            .unwrap()
+    }
+
+    fn action_request(id: u64, action: &str) -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .method(Method::POST)
+            .endpoint(format!("projects/{}/{}", id, action))
+            .build()
+            .unwrap()
+    }
+
+    fn action_response(id: u64, archived: bool) -> MockResponse {
+        MockResponse::json(&json!({
+            "id": id,
+            "archived": archived,
+        }))
+    }
+
+    fn helper(action: ArchiveAction) -> ArchiveGroupProjects<'static> {
+        ArchiveGroupProjects::builder()
+            .projects(
+                GroupProjects::builder()
+                    .group("group/subgroup")
+                    .build()
+                    .unwrap(),
+            )
+            .action(action)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn archive_action_default() {
+        assert_eq!(ArchiveAction::default(), ArchiveAction::Archive);
+    }
+
+    #[test]
+    fn archive_action_target_state() {
+        let items = &[
+            (ArchiveAction::Archive, true),
+            (ArchiveAction::Unarchive, false),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.target_state(), *s);
+        }
+    }
+
+    #[test]
+    fn projects_is_necessary() {
+        let err = ArchiveGroupProjects::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ArchiveGroupProjectsBuilderError, "projects");
+    }
+
+    #[test]
+    fn projects_is_sufficient() {
+        ArchiveGroupProjects::builder()
+            .projects(GroupProjects::builder().group("group").build().unwrap())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn dry_run_archive() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/projects")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, projects());
+
+        let endpoint = ArchiveGroupProjects::builder()
+            .projects(
+                GroupProjects::builder()
+                    .group("group/subgroup")
+                    .build()
+                    .unwrap(),
+            )
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<DummyProject> = endpoint.query(&client).unwrap();
+        itertools::assert_equal(res.iter().map(|p| p.id), [1, 3]);
+    }
+
+    #[test]
+    fn dry_run_unarchive() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/projects")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, projects());
+
+        let endpoint = ArchiveGroupProjects::builder()
+            .projects(
+                GroupProjects::builder()
+                    .group("group/subgroup")
+                    .build()
+                    .unwrap(),
+            )
+            .action(ArchiveAction::Unarchive)
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<DummyProject> = endpoint.query(&client).unwrap();
+        itertools::assert_equal(res.iter().map(|p| p.id), [2]);
+    }
+
+    #[tokio::test]
+    async fn dry_run_archive_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/projects")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, projects());
+
+        let endpoint = ArchiveGroupProjects::builder()
+            .projects(
+                GroupProjects::builder()
+                    .group("group/subgroup")
+                    .build()
+                    .unwrap(),
+            )
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<DummyProject> = endpoint.query_async(&client).await.unwrap();
+        itertools::assert_equal(res.iter().map(|p| p.id), [1, 3]);
+    }
+
+    #[test]
+    fn archive() {
+        let client = MockClient::new();
+        client
+            .expect_paged(list_request(), projects())
+            .expect(action_request(1, "archive"), action_response(1, true))
+            .expect(action_request(3, "archive"), action_response(3, true));
+
+        let res: Vec<DummyProject> = helper(ArchiveAction::Archive).query(&client).unwrap();
+        assert_eq!(
+            res,
+            [
+                DummyProject {
+                    id: 1,
+                    archived: true,
+                },
+                DummyProject {
+                    id: 3,
+                    archived: true,
+                },
+            ],
+        );
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn unarchive() {
+        let client = MockClient::new();
+        client
+            .expect_paged(list_request(), projects())
+            .expect(action_request(2, "unarchive"), action_response(2, false));
+
+        let res: Vec<DummyProject> = helper(ArchiveAction::Unarchive).query(&client).unwrap();
+        assert_eq!(
+            res,
+            [DummyProject {
+                id: 2,
+                archived: false,
+            }],
+        );
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn archive_nothing_to_do() {
+        let client = MockClient::new();
+        client.expect_paged(
+            list_request(),
+            [DummyProject {
+                id: 2,
+                archived: true,
+            }],
+        );
+
+        let res: Vec<DummyProject> = helper(ArchiveAction::Archive).query(&client).unwrap();
+        assert_eq!(res, []);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn archive_stops_on_error() {
+        let client = MockClient::new();
+        client
+            .expect_paged(list_request(), projects())
+            .expect(action_request(1, "archive"), action_response(1, true))
+            .expect(
+                action_request(3, "archive"),
+                MockResponse::json(&json!({"message": "403 Forbidden"}))
+                    .status(StatusCode::FORBIDDEN),
+            );
+
+        let err = Query::<Vec<DummyProject>, _>::query(&helper(ArchiveAction::Archive), &client)
+            .unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "403 Forbidden");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        // The first project was archived before the failure.
+        let requests = client.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].method, Method::POST);
+        assert_eq!(requests[1].url.path(), "/api/v4/projects/1/archive");
+    }
+
+    #[tokio::test]
+    async fn archive_async() {
+        let client = MockClient::new();
+        client
+            .expect_paged(list_request(), projects())
+            .expect(action_request(1, "archive"), action_response(1, true))
+            .expect(action_request(3, "archive"), action_response(3, true));
+
+        let res: Vec<DummyProject> = helper(ArchiveAction::Archive)
+            .query_async(&client)
+            .await
+            .unwrap();
+        itertools::assert_equal(
+            res.iter().map(|p| (p.id, p.archived)),
+            [(1, true), (3, true)],
+        );
+        client.assert_all_consumed();
+    }
+}
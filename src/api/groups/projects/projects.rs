@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam, SortOrder, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -61,7 +61,7 @@ impl ParamValue<'static> for GroupProjectsOrderBy {
 
 /// Query projects of a group.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupProjects<'a> {
     /// The group to query for projects.
     #[builder(setter(into))]
@@ -125,13 +125,21 @@ impl<'a> GroupProjects<'a> {
     }
 }
 
+impl<'a> GroupProjectsBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupProjectsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GroupProjects<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/projects", self.group).into()
+        format!("groups/{}/projects", self.group.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -211,6 +219,12 @@ mod tests {
         GroupProjects::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupProjects::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupProjectsBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam, SortOrder, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -58,7 +58,7 @@ impl ParamValue<'static> for SharedGroupProjectsOrderBy {
 
 /// Query projects of a group.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct SharedGroupProjects<'a> {
     /// The ID or URL-encoded path of the group owned by the authenticated user.
     #[builder(setter(into))]
@@ -110,13 +110,21 @@ impl<'a> SharedGroupProjects<'a> {
     }
 }
 
+impl<'a> SharedGroupProjectsBuilder<'a> {
+    fn validate(&self) -> Result<(), SharedGroupProjectsBuilderError> {
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for SharedGroupProjects<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/projects/shared", self.id).into()
+        format!("groups/{}/projects/shared", self.id.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -194,6 +202,12 @@ mod tests {
         SharedGroupProjects::builder().id(1).build().unwrap();
     }
 
+    #[test]
+    fn id_must_be_valid() {
+        let err = SharedGroupProjects::builder().id(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SharedGroupProjectsBuilderError, "id");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
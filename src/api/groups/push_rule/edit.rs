@@ -6,14 +6,14 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Manage push rules for a group.
 ///
 /// See https://docs.gitlab.com/ee/api/groups.html#get-group-push-rules
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditGroupPushRule<'a> {
     /// The group to edit.
     #[builder(setter(into))]
@@ -84,13 +84,21 @@ impl<'a> EditGroupPushRule<'a> {
     }
 }
 
+impl<'a> EditGroupPushRuleBuilder<'a> {
+    fn validate(&self) -> Result<(), EditGroupPushRuleBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditGroupPushRule<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/push_rule", self.group).into()
+        format!("groups/{}/push_rule", self.group.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -138,6 +146,12 @@ mod tests {
         EditGroupPushRule::builder().group("group").build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditGroupPushRule::builder().group("").build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditGroupPushRuleBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::runners::{RunnerStatus, RunnerType};
 
 /// Query for runners on a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupRunners<'a> {
     /// The ID or URL-encoded path of the group.
     #[builder(setter(into))]
@@ -67,6 +67,12 @@ impl<'a> GroupRunnersBuilder<'a> {
             .extend(iter.map(|t| t.into()));
         self
     }
+
+    fn validate(&self) -> Result<(), GroupRunnersBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for GroupRunners<'a> {
@@ -75,7 +81,7 @@ impl<'a> Endpoint for GroupRunners<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/runners", self.group).into()
+        format!("groups/{}/runners", self.group.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -112,6 +118,12 @@ mod tests {
         GroupRunners::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupRunners::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupRunnersBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -7,12 +7,12 @@
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Share group with another group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ShareGroup<'a> {
     /// The ID or URL-encoded path of the group.
     #[builder(setter(into))]
@@ -33,13 +33,21 @@ impl<'a> ShareGroup<'a> {
     }
 }
 
+impl<'a> ShareGroupBuilder<'a> {
+    fn validate(&self) -> Result<(), ShareGroupBuilderError> {
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ShareGroup<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/share", self.id).into()
+        format!("groups/{}/share", self.id.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -106,6 +114,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn id_must_be_valid() {
+        let err = ShareGroup::builder()
+            .id(0)
+            .group_id(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ShareGroupBuilderError, "id");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -8,7 +8,7 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId, SortOrder};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -51,7 +51,7 @@ impl ParamValue<'static> for GroupSubgroupsOrderBy {
 
 /// Query subgroups of a group.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupSubgroups<'a> {
     /// The group to query for subgroups.
     #[builder(setter(into))]
@@ -119,6 +119,12 @@ impl<'a> GroupSubgroupsBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), GroupSubgroupsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for GroupSubgroups<'a> {
@@ -127,7 +133,7 @@ impl<'a> Endpoint for GroupSubgroups<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/subgroups", self.group).into()
+        format!("groups/{}/subgroups", self.group.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -148,7 +154,7 @@ impl<'a> Endpoint for GroupSubgroups<'a> {
             .extend(
                 self.skip_groups
                     .iter()
-                    .map(|&value| ("skip_groups[]", value)),
+                    .map(|&value| (ParamName::new("skip_groups").array(), value)),
             );
 
         params
@@ -198,6 +204,12 @@ mod tests {
         GroupSubgroups::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupSubgroups::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupSubgroupsBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Unshare a group from another group.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnshareGroup<'a> {
     /// The ID or URL-encoded path of the group
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> UnshareGroup<'a> {
     }
 }
 
+impl<'a> UnshareGroupBuilder<'a> {
+    fn validate(&self) -> Result<(), UnshareGroupBuilderError> {
+        common::validate_path_param("id", self.id.as_ref())?;
+        common::validate_path_param("group_id", self.group_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UnshareGroup<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/share/{}", self.id, self.group_id).into()
+        format!(
+            "groups/{}/share/{}",
+            self.id.path_segment(),
+            self.group_id.path_segment(),
+        )
+        .into()
     }
 }
 
@@ -67,6 +82,16 @@ mod tests {
         UnshareGroup::builder().id(1).group_id(1).build().unwrap();
     }
 
+    #[test]
+    fn id_must_be_valid() {
+        let err = UnshareGroup::builder()
+            .id(0)
+            .group_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnshareGroupBuilderError, "id");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the variables of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupVariables<'a> {
+    /// The group to query for variables.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupVariables<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupVariablesBuilder<'a> {
+        GroupVariablesBuilder::default()
+    }
+}
+
+impl<'a> GroupVariablesBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupVariablesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupVariables<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/variables", self.group.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for GroupVariables<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::variables::{GroupVariables, GroupVariablesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupVariables::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupVariablesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupVariables::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupVariables::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupVariablesBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/variables")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupVariables::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
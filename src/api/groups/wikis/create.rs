@@ -0,0 +1,175 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::wikis::WikiPageFormat;
+
+/// Create a wiki page within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateWikiPage<'a> {
+    /// The group to create the wiki page within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The title of the page.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// The content of the page.
+    #[builder(setter(into))]
+    content: Cow<'a, str>,
+
+    /// The format of the content.
+    ///
+    /// Defaults to Markdown.
+    #[builder(default)]
+    format: Option<WikiPageFormat>,
+}
+
+impl<'a> CreateWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateWikiPageBuilder<'a> {
+        CreateWikiPageBuilder::default()
+    }
+}
+
+impl<'a> CreateWikiPageBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateWikiPageBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateWikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/wikis", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", &self.title)
+            .push("content", &self.content)
+            .push_opt("format", self.format);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::wikis::{CreateWikiPage, CreateWikiPageBuilderError, WikiPageFormat};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateWikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateWikiPage::builder()
+            .title("title")
+            .content("content")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn title_is_needed() {
+        let err = CreateWikiPage::builder()
+            .group(1)
+            .content("content")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "title");
+    }
+
+    #[test]
+    fn content_is_needed() {
+        let err = CreateWikiPage::builder()
+            .group(1)
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateWikiPageBuilderError, "content");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        CreateWikiPage::builder()
+            .group(1)
+            .title("title")
+            .content("content")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = CreateWikiPage::builder()
+            .group(0)
+            .title("title")
+            .content("content")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateWikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/wikis")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title&content=some+content")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateWikiPage::builder()
+            .group("simple/group")
+            .title("title")
+            .content("some content")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_format() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/wikis")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title&content=content&format=asciidoc")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateWikiPage::builder()
+            .group("simple/group")
+            .title("title")
+            .content("content")
+            .format(WikiPageFormat::Asciidoc)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a wiki page from a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteWikiPage<'a> {
+    /// The group of the wiki page.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The slug of the wiki page.
+    #[builder(setter(into))]
+    slug: Cow<'a, str>,
+}
+
+impl<'a> DeleteWikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteWikiPageBuilder<'a> {
+        DeleteWikiPageBuilder::default()
+    }
+}
+
+impl<'a> DeleteWikiPageBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteWikiPageBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("slug", self.slug.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteWikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/wikis/{}",
+            self.group.path_segment(),
+            self.slug.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::wikis::{DeleteWikiPage, DeleteWikiPageBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_slug_are_needed() {
+        let err = DeleteWikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteWikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteWikiPage::builder().slug("home").build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteWikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn slug_is_needed() {
+        let err = DeleteWikiPage::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteWikiPageBuilderError, "slug");
+    }
+
+    #[test]
+    fn group_and_slug_are_sufficient() {
+        DeleteWikiPage::builder()
+            .group(1)
+            .slug("home")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = DeleteWikiPage::builder()
+            .group(0)
+            .slug("home")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteWikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/wikis/dir%2Fpage")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteWikiPage::builder()
+            .group("simple/group")
+            .slug("dir/page")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,162 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a wiki page within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct WikiPage<'a> {
+    /// The group of the wiki page.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The slug of the wiki page.
+    #[builder(setter(into))]
+    slug: Cow<'a, str>,
+
+    /// Render the content of the page as HTML.
+    #[builder(default)]
+    render_html: Option<bool>,
+    /// The version (commit SHA) of the page to get.
+    #[builder(setter(into), default)]
+    version: Option<Cow<'a, str>>,
+}
+
+impl<'a> WikiPage<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> WikiPageBuilder<'a> {
+        WikiPageBuilder::default()
+    }
+}
+
+impl<'a> WikiPageBuilder<'a> {
+    fn validate(&self) -> Result<(), WikiPageBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+        common::validate_path_param("slug", self.slug.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for WikiPage<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/wikis/{}",
+            self.group.path_segment(),
+            self.slug.path_segment(),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("render_html", self.render_html)
+            .push_opt("version", self.version.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::wikis::{WikiPage, WikiPageBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_slug_are_needed() {
+        let err = WikiPage::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = WikiPage::builder().slug("home").build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn slug_is_needed() {
+        let err = WikiPage::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPageBuilderError, "slug");
+    }
+
+    #[test]
+    fn group_and_slug_are_sufficient() {
+        WikiPage::builder().group(1).slug("home").build().unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = WikiPage::builder()
+            .group(0)
+            .slug("home")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, WikiPageBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/wikis/dir%2Fpage")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .group("simple/group")
+            .slug("dir/page")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_render_html() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/wikis/home")
+            .add_query_params(&[("render_html", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .group("simple/group")
+            .slug("home")
+            .render_html(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_version() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/wikis/home")
+            .add_query_params(&[("version", "0123abcd")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPage::builder()
+            .group("simple/group")
+            .slug("home")
+            .version("0123abcd")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
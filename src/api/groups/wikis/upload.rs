@@ -0,0 +1,191 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Upload an attachment to the wiki repository of a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UploadWikiAttachment<'a> {
+    /// The group to upload the attachment to.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the file.
+    #[builder(setter(into))]
+    filename: Cow<'a, str>,
+    /// The contents of the file.
+    #[builder(setter(into))]
+    contents: Cow<'a, [u8]>,
+
+    /// The branch to commit the attachment to.
+    ///
+    /// Defaults to the default branch of the wiki repository.
+    #[builder(setter(into), default)]
+    branch: Option<Cow<'a, str>>,
+}
+
+impl<'a> UploadWikiAttachment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UploadWikiAttachmentBuilder<'a> {
+        UploadWikiAttachmentBuilder::default()
+    }
+}
+
+impl<'a> UploadWikiAttachmentBuilder<'a> {
+    fn validate(&self) -> Result<(), UploadWikiAttachmentBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UploadWikiAttachment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/wikis/attachments", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = MultipartParams::default();
+
+        params
+            .push_file("file", self.filename.as_ref(), self.contents.as_ref())
+            .push_opt("branch", self.branch.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::wikis::{UploadWikiAttachment, UploadWikiAttachmentBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = UploadWikiAttachment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = UploadWikiAttachment::builder()
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "group");
+    }
+
+    #[test]
+    fn filename_is_needed() {
+        let err = UploadWikiAttachment::builder()
+            .group(1)
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "filename");
+    }
+
+    #[test]
+    fn contents_is_needed() {
+        let err = UploadWikiAttachment::builder()
+            .group(1)
+            .filename("image.png")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "contents");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        UploadWikiAttachment::builder()
+            .group(1)
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = UploadWikiAttachment::builder()
+            .group(0)
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UploadWikiAttachmentBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/wikis/attachments")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"image.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "data\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadWikiAttachment::builder()
+            .group("simple/group")
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_branch() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/wikis/attachments")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"image.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "data\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"branch\"\r\n",
+                "\r\n",
+                "main\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadWikiAttachment::builder()
+            .group("simple/group")
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .branch("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,758 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! High-level resource handles
+//!
+//! Handles are a thin layer over the endpoint builders for common operations. They remember the
+//! client and the resource being operated on so that each call does not need to spell out the
+//! project, merge request, etc. again. Anything not covered by a handle is still available
+//! through the endpoint types themselves.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use serde::Deserialize;
+//! use gitlab::Gitlab;
+//! use gitlab::api::prelude::*;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Issue {
+//!     iid: u64,
+//!     title: String,
+//! }
+//!
+//! let client = Gitlab::new("gitlab.com", "private-token").unwrap();
+//! let project = client.project("group/app");
+//!
+//! // List all of the issues in the project.
+//! let issues: Vec<Issue> = project.issues().list().unwrap();
+//! // Approve a merge request.
+//! project.mr(123).approve().unwrap();
+//! ```
+
+use serde::de::DeserializeOwned;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::issues::{CreateIssue, EditIssue, Issue, IssueStateEvent, Issues};
+use crate::api::projects::merge_requests::{
+    ApproveMergeRequest, MergeMergeRequest, MergeRequest, MergeRequests, RebaseMergeRequest,
+    UnapproveMergeRequest,
+};
+use crate::api::projects::Project;
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query, RestClient};
+
+/// Create handles for resources on a client.
+///
+/// This is implemented for all clients.
+pub trait ResourceHandles: RestClient + Sized {
+    /// A handle for a project.
+    fn project<'a, P>(&self, project: P) -> ProjectHandle<'_, 'a, Self>
+    where
+        P: Into<NameOrId<'a>>,
+    {
+        ProjectHandle {
+            client: self,
+            project: project.into(),
+        }
+    }
+}
+
+impl<C> ResourceHandles for C where C: RestClient {}
+
+/// A handle for a project.
+#[derive(Debug)]
+pub struct ProjectHandle<'c, 'a, C> {
+    client: &'c C,
+    project: NameOrId<'a>,
+}
+
+impl<'c, 'a, C> Clone for ProjectHandle<'c, 'a, C> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl<'c, 'a, C> ProjectHandle<'c, 'a, C> {
+    /// The issues of the project.
+    pub fn issues(&self) -> IssuesHandle<'c, 'a, C> {
+        IssuesHandle {
+            client: self.client,
+            project: self.project.clone(),
+        }
+    }
+
+    /// An issue within the project.
+    pub fn issue(&self, issue: u64) -> IssueHandle<'c, 'a, C> {
+        IssueHandle {
+            client: self.client,
+            project: self.project.clone(),
+            issue,
+        }
+    }
+
+    /// The merge requests of the project.
+    pub fn merge_requests(&self) -> MergeRequestsHandle<'c, 'a, C> {
+        MergeRequestsHandle {
+            client: self.client,
+            project: self.project.clone(),
+        }
+    }
+
+    /// A merge request within the project.
+    pub fn merge_request(&self, merge_request: u64) -> MergeRequestHandle<'c, 'a, C> {
+        MergeRequestHandle {
+            client: self.client,
+            project: self.project.clone(),
+            merge_request,
+        }
+    }
+
+    /// A merge request within the project.
+    ///
+    /// This is a shorthand for `merge_request`.
+    pub fn mr(&self, merge_request: u64) -> MergeRequestHandle<'c, 'a, C> {
+        self.merge_request(merge_request)
+    }
+}
+
+impl<'c, 'a, C> ProjectHandle<'c, 'a, C>
+where
+    C: RestClient,
+{
+    fn endpoint(&self) -> Result<Project<'a>, ApiError<C::Error>> {
+        Project::builder()
+            .project(self.project.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+}
+
+impl<'c, 'a, C> ProjectHandle<'c, 'a, C>
+where
+    C: Client,
+{
+    /// Fetch the project.
+    pub fn get<T>(&self) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.endpoint()?.query(self.client)
+    }
+}
+
+impl<'c, 'a, C> ProjectHandle<'c, 'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    /// Fetch the project.
+    pub async fn get_async<T>(&self) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        self.endpoint()?.query_async(self.client).await
+    }
+}
+
+/// A handle for the issues of a project.
+#[derive(Debug)]
+pub struct IssuesHandle<'c, 'a, C> {
+    client: &'c C,
+    project: NameOrId<'a>,
+}
+
+impl<'c, 'a, C> IssuesHandle<'c, 'a, C>
+where
+    C: RestClient,
+{
+    fn list_endpoint(&self) -> Result<Issues<'a>, ApiError<C::Error>> {
+        Issues::builder()
+            .project(self.project.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn create_endpoint<'t>(&self, title: &'t str) -> Result<CreateIssue<'t>, ApiError<C::Error>>
+    where
+        'a: 't,
+    {
+        CreateIssue::builder()
+            .project(self.project.clone())
+            .title(title)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+}
+
+impl<'c, 'a, C> IssuesHandle<'c, 'a, C>
+where
+    C: Client,
+{
+    /// List all issues in the project.
+    pub fn list<T>(&self) -> Result<Vec<T>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        api::paged(self.list_endpoint()?, Pagination::All).query(self.client)
+    }
+
+    /// Create an issue with the given title.
+    pub fn create<T>(&self, title: &str) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.create_endpoint(title)?.query(self.client)
+    }
+}
+
+impl<'c, 'a, C> IssuesHandle<'c, 'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    /// List all issues in the project.
+    pub async fn list_async<T>(&self) -> Result<Vec<T>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        api::paged(self.list_endpoint()?, Pagination::All)
+            .query_async(self.client)
+            .await
+    }
+
+    /// Create an issue with the given title.
+    pub async fn create_async<T>(&self, title: &str) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        self.create_endpoint(title)?.query_async(self.client).await
+    }
+}
+
+/// A handle for an issue.
+#[derive(Debug)]
+pub struct IssueHandle<'c, 'a, C> {
+    client: &'c C,
+    project: NameOrId<'a>,
+    issue: u64,
+}
+
+impl<'c, 'a, C> IssueHandle<'c, 'a, C>
+where
+    C: RestClient,
+{
+    fn endpoint(&self) -> Result<Issue<'a>, ApiError<C::Error>> {
+        Issue::builder()
+            .project(self.project.clone())
+            .issue(self.issue)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn state_endpoint(
+        &self,
+        state_event: IssueStateEvent,
+    ) -> Result<EditIssue<'a>, ApiError<C::Error>> {
+        EditIssue::builder()
+            .project(self.project.clone())
+            .issue(self.issue)
+            .state_event(state_event)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+}
+
+impl<'c, 'a, C> IssueHandle<'c, 'a, C>
+where
+    C: Client,
+{
+    /// Fetch the issue.
+    pub fn get<T>(&self) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.endpoint()?.query(self.client)
+    }
+
+    /// Close the issue.
+    pub fn close(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.state_endpoint(IssueStateEvent::Close)?).query(self.client)
+    }
+
+    /// Reopen the issue.
+    pub fn reopen(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.state_endpoint(IssueStateEvent::Reopen)?).query(self.client)
+    }
+}
+
+impl<'c, 'a, C> IssueHandle<'c, 'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    /// Fetch the issue.
+    pub async fn get_async<T>(&self) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        self.endpoint()?.query_async(self.client).await
+    }
+
+    /// Close the issue.
+    pub async fn close_async(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.state_endpoint(IssueStateEvent::Close)?)
+            .query_async(self.client)
+            .await
+    }
+
+    /// Reopen the issue.
+    pub async fn reopen_async(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.state_endpoint(IssueStateEvent::Reopen)?)
+            .query_async(self.client)
+            .await
+    }
+}
+
+/// A handle for the merge requests of a project.
+#[derive(Debug)]
+pub struct MergeRequestsHandle<'c, 'a, C> {
+    client: &'c C,
+    project: NameOrId<'a>,
+}
+
+impl<'c, 'a, C> MergeRequestsHandle<'c, 'a, C>
+where
+    C: RestClient,
+{
+    fn list_endpoint(&self) -> Result<MergeRequests<'a>, ApiError<C::Error>> {
+        MergeRequests::builder()
+            .project(self.project.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+}
+
+impl<'c, 'a, C> MergeRequestsHandle<'c, 'a, C>
+where
+    C: Client,
+{
+    /// List all merge requests in the project.
+    pub fn list<T>(&self) -> Result<Vec<T>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        api::paged(self.list_endpoint()?, Pagination::All).query(self.client)
+    }
+}
+
+impl<'c, 'a, C> MergeRequestsHandle<'c, 'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    /// List all merge requests in the project.
+    pub async fn list_async<T>(&self) -> Result<Vec<T>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        api::paged(self.list_endpoint()?, Pagination::All)
+            .query_async(self.client)
+            .await
+    }
+}
+
+/// A handle for a merge request.
+#[derive(Debug)]
+pub struct MergeRequestHandle<'c, 'a, C> {
+    client: &'c C,
+    project: NameOrId<'a>,
+    merge_request: u64,
+}
+
+impl<'c, 'a, C> MergeRequestHandle<'c, 'a, C>
+where
+    C: RestClient,
+{
+    fn endpoint(&self) -> Result<MergeRequest<'a>, ApiError<C::Error>> {
+        MergeRequest::builder()
+            .project(self.project.clone())
+            .merge_request(self.merge_request)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn approve_endpoint(&self) -> Result<ApproveMergeRequest<'a>, ApiError<C::Error>> {
+        ApproveMergeRequest::builder()
+            .project(self.project.clone())
+            .merge_request(self.merge_request)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn unapprove_endpoint(&self) -> Result<UnapproveMergeRequest<'a>, ApiError<C::Error>> {
+        UnapproveMergeRequest::builder()
+            .project(self.project.clone())
+            .merge_request(self.merge_request)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn merge_endpoint(&self) -> Result<MergeMergeRequest<'a>, ApiError<C::Error>> {
+        MergeMergeRequest::builder()
+            .project(self.project.clone())
+            .merge_request(self.merge_request)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn rebase_endpoint(&self) -> Result<RebaseMergeRequest<'a>, ApiError<C::Error>> {
+        RebaseMergeRequest::builder()
+            .project(self.project.clone())
+            .merge_request(self.merge_request)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+}
+
+impl<'c, 'a, C> MergeRequestHandle<'c, 'a, C>
+where
+    C: Client,
+{
+    /// Fetch the merge request.
+    pub fn get<T>(&self) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.endpoint()?.query(self.client)
+    }
+
+    /// Approve the merge request.
+    pub fn approve(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.approve_endpoint()?).query(self.client)
+    }
+
+    /// Remove the current user's approval of the merge request.
+    pub fn unapprove(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.unapprove_endpoint()?).query(self.client)
+    }
+
+    /// Merge the merge request.
+    pub fn merge(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.merge_endpoint()?).query(self.client)
+    }
+
+    /// Rebase the merge request onto its target branch.
+    pub fn rebase(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.rebase_endpoint()?).query(self.client)
+    }
+}
+
+impl<'c, 'a, C> MergeRequestHandle<'c, 'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    /// Fetch the merge request.
+    pub async fn get_async<T>(&self) -> Result<T, ApiError<C::Error>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        self.endpoint()?.query_async(self.client).await
+    }
+
+    /// Approve the merge request.
+    pub async fn approve_async(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.approve_endpoint()?)
+            .query_async(self.client)
+            .await
+    }
+
+    /// Remove the current user's approval of the merge request.
+    pub async fn unapprove_async(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.unapprove_endpoint()?)
+            .query_async(self.client)
+            .await
+    }
+
+    /// Merge the merge request.
+    pub async fn merge_async(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.merge_endpoint()?)
+            .query_async(self.client)
+            .await
+    }
+
+    /// Rebase the merge request onto its target branch.
+    pub async fn rebase_async(&self) -> Result<(), ApiError<C::Error>> {
+        api::ignore(self.rebase_endpoint()?)
+            .query_async(self.client)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::api::handles::ResourceHandles;
+    use crate::api::ApiError;
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+    use crate::testing::MockClient;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    #[test]
+    fn project_get() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/group%2Fapp")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!({"value": 0}));
+
+        let res: DummyResult = client.project("group/app").get().unwrap();
+        assert_eq!(res.value, 0);
+    }
+
+    #[test]
+    fn project_get_invalid() {
+        let client = MockClient::new();
+
+        let err = client.project(0).get::<DummyResult>().unwrap_err();
+        if let ApiError::InvalidEndpoint {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "invalid `project`: IDs must not be zero");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        let err = client.project("").get::<DummyResult>().unwrap_err();
+        if let ApiError::InvalidEndpoint {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "invalid `project`: names must not be empty");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn merge_request_approve_invalid() {
+        let client = MockClient::new();
+
+        let err = client.project("group/app").mr(0).approve().unwrap_err();
+        if let ApiError::InvalidEndpoint {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "invalid `merge_request`: IDs must not be zero");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn issues_list() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/group%2Fapp/issues")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = client.project("group/app").issues().list().unwrap();
+        assert_eq!(res.len(), 256);
+    }
+
+    #[test]
+    fn issues_create() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/group%2Fapp/issues")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!({"value": 1}));
+
+        let res: DummyResult = client
+            .project("group/app")
+            .issues()
+            .create("title")
+            .unwrap();
+        assert_eq!(res.value, 1);
+    }
+
+    #[test]
+    fn issue_close() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/group%2Fapp/issues/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("state_event=close")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client.project("group/app").issue(1).close().unwrap();
+    }
+
+    #[test]
+    fn issue_reopen() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/group%2Fapp/issues/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("state_event=reopen")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client.project("group/app").issue(1).reopen().unwrap();
+    }
+
+    #[test]
+    fn merge_requests_list() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/merge_requests")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=10).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = client.project(1).merge_requests().list().unwrap();
+        assert_eq!(res.len(), 11);
+    }
+
+    #[test]
+    fn merge_request_get() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/group%2Fapp/merge_requests/123")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!({"value": 2}));
+
+        let res: DummyResult = client.project("group/app").mr(123).get().unwrap();
+        assert_eq!(res.value, 2);
+    }
+
+    #[test]
+    fn merge_request_approve() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/group%2Fapp/merge_requests/123/approve")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client.project("group/app").mr(123).approve().unwrap();
+    }
+
+    #[test]
+    fn merge_request_unapprove() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/group%2Fapp/merge_requests/123/unapprove")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client
+            .project("group/app")
+            .merge_request(123)
+            .unapprove()
+            .unwrap();
+    }
+
+    #[test]
+    fn merge_request_merge() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/group%2Fapp/merge_requests/123/merge")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client.project("group/app").mr(123).merge().unwrap();
+    }
+
+    #[test]
+    fn merge_request_rebase() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/group%2Fapp/merge_requests/123/rebase")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client.project("group/app").mr(123).rebase().unwrap();
+    }
+
+    #[tokio::test]
+    async fn merge_request_approve_async() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/group%2Fapp/merge_requests/123/approve")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        client
+            .project("group/app")
+            .mr(123)
+            .approve_async()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn issues_list_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/group%2Fapp/issues")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=3).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = client
+            .project("group/app")
+            .issues()
+            .list_async()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 4);
+    }
+}
@@ -15,38 +15,28 @@ use std::borrow::Cow;
 use crate::api::common::CommaSeparatedList;
 use crate::api::ParamValue;
 
-/// Keys note results may be ordered by.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum NoteOrderBy {
-    /// Sort by creation date.
-    CreatedAt,
-    /// Sort by last updated date.
-    UpdatedAt,
-}
-
-#[allow(clippy::derivable_impls)]
-impl Default for NoteOrderBy {
-    fn default() -> Self {
-        // XXX(rust-1.62): use `#[default]`
-        NoteOrderBy::CreatedAt
-    }
-}
-
-impl NoteOrderBy {
-    fn as_str(self) -> &'static str {
-        match self {
-            NoteOrderBy::CreatedAt => "created_at",
-            NoteOrderBy::UpdatedAt => "updated_at",
-        }
-    }
-}
-
-impl ParamValue<'static> for NoteOrderBy {
-    fn as_value(&self) -> Cow<'static, str> {
-        self.as_str().into()
-    }
-}
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+mod badges;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+mod notes;
+
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub(crate) use self::badges::validate_badge_url;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::badges::BadgePlaceholders;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::badges::BadgePlaceholdersBuilder;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::badges::BadgePlaceholdersBuilderError;
+
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::notes::filter_notes;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::notes::FilteredNotes;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::notes::NoteActivityFilter;
+#[cfg(any(feature = "api-groups", feature = "api-projects"))]
+pub use self::notes::NoteOrderBy;
 
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -100,24 +90,7 @@ impl<'a, 'b: 'a> ParamValue<'a> for &'b ReactionEmoji<'a> {
 mod tests {
     use std::iter;
 
-    use super::{Labels, NoteOrderBy, ReactionEmoji};
-
-    #[test]
-    fn note_order_by_default() {
-        assert_eq!(NoteOrderBy::default(), NoteOrderBy::CreatedAt);
-    }
-
-    #[test]
-    fn note_order_by_as_str() {
-        let items = &[
-            (NoteOrderBy::CreatedAt, "created_at"),
-            (NoteOrderBy::UpdatedAt, "updated_at"),
-        ];
-
-        for (i, s) in items {
-            assert_eq!(i.as_str(), *s);
-        }
-    }
+    use super::{Labels, ReactionEmoji};
 
     #[test]
     fn labels_as_str() {
@@ -0,0 +1,208 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::iter;
+
+use derive_builder::Builder;
+
+/// The placeholders GitLab supports in badge URLs.
+const BADGE_PLACEHOLDERS: &[&str] = &[
+    "project_path",
+    "project_title",
+    "project_name",
+    "project_id",
+    "project_namespace",
+    "group_name",
+    "gitlab_server",
+    "gitlab_pages_domain",
+    "default_branch",
+    "commit_sha",
+    "latest_tag",
+];
+
+/// Iterate over the placeholder names used in a badge URL template.
+fn badge_placeholders(template: &str) -> impl Iterator<Item = &str> {
+    let mut rest = template;
+
+    iter::from_fn(move || {
+        let start = rest.find("%{")?;
+        let after = &rest[start + 2..];
+        let end = after.find('}')?;
+        rest = &after[end + 1..];
+        Some(&after[..end])
+    })
+}
+
+/// Validate the placeholders of a badge URL template from an endpoint's builder.
+///
+/// Unset URLs are ignored so that the builder can report them as missing instead.
+pub(crate) fn validate_badge_url(name: &str, url: Option<&Cow<str>>) -> Result<(), String> {
+    if let Some(url) = url {
+        if let Some(unknown) =
+            badge_placeholders(url).find(|placeholder| !BADGE_PLACEHOLDERS.contains(placeholder))
+        {
+            return Err(format!(
+                "invalid `{}`: unknown placeholder `%{{{}}}`",
+                name, unknown,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Values for placeholders in badge URLs.
+///
+/// GitLab replaces placeholders such as `%{project_path}` in badge link and image URLs when
+/// rendering them. This expands them locally so that templates can be checked without asking
+/// GitLab to render them. Placeholders without a value are left as-is.
+#[derive(Debug, Default, Builder, Clone)]
+#[builder(setter(strip_option), default)]
+pub struct BadgePlaceholders<'a> {
+    /// The value for `%{project_path}`.
+    #[builder(setter(into))]
+    project_path: Option<Cow<'a, str>>,
+    /// The value for `%{project_title}`.
+    #[builder(setter(into))]
+    project_title: Option<Cow<'a, str>>,
+    /// The value for `%{project_name}`.
+    #[builder(setter(into))]
+    project_name: Option<Cow<'a, str>>,
+    /// The value for `%{project_id}`.
+    project_id: Option<u64>,
+    /// The value for `%{project_namespace}`.
+    #[builder(setter(into))]
+    project_namespace: Option<Cow<'a, str>>,
+    /// The value for `%{group_name}`.
+    #[builder(setter(into))]
+    group_name: Option<Cow<'a, str>>,
+    /// The value for `%{gitlab_server}`.
+    #[builder(setter(into))]
+    gitlab_server: Option<Cow<'a, str>>,
+    /// The value for `%{gitlab_pages_domain}`.
+    #[builder(setter(into))]
+    gitlab_pages_domain: Option<Cow<'a, str>>,
+    /// The value for `%{default_branch}`.
+    #[builder(setter(into))]
+    default_branch: Option<Cow<'a, str>>,
+    /// The value for `%{commit_sha}`.
+    #[builder(setter(into))]
+    commit_sha: Option<Cow<'a, str>>,
+    /// The value for `%{latest_tag}`.
+    #[builder(setter(into))]
+    latest_tag: Option<Cow<'a, str>>,
+}
+
+impl<'a> BadgePlaceholders<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> BadgePlaceholdersBuilder<'a> {
+        BadgePlaceholdersBuilder::default()
+    }
+
+    fn value(&self, name: &str) -> Option<Cow<str>> {
+        let value = match name {
+            "project_path" => &self.project_path,
+            "project_title" => &self.project_title,
+            "project_name" => &self.project_name,
+            "project_id" => return self.project_id.map(|id| id.to_string().into()),
+            "project_namespace" => &self.project_namespace,
+            "group_name" => &self.group_name,
+            "gitlab_server" => &self.gitlab_server,
+            "gitlab_pages_domain" => &self.gitlab_pages_domain,
+            "default_branch" => &self.default_branch,
+            "commit_sha" => &self.commit_sha,
+            "latest_tag" => &self.latest_tag,
+            _ => return None,
+        };
+
+        value.as_deref().map(Into::into)
+    }
+
+    /// Expand the placeholders in a badge URL template.
+    pub fn expand(&self, template: &str) -> String {
+        let mut expanded = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find("%{") {
+            let after = &rest[start + 2..];
+            let end = if let Some(end) = after.find('}') {
+                end
+            } else {
+                break;
+            };
+
+            expanded.push_str(&rest[..start]);
+            if let Some(value) = self.value(&after[..end]) {
+                expanded.push_str(&value);
+            } else {
+                expanded.push_str(&rest[start..start + end + 3]);
+            }
+            rest = &after[end + 1..];
+        }
+        expanded.push_str(rest);
+
+        expanded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::helpers::{validate_badge_url, BadgePlaceholders};
+
+    #[test]
+    fn badge_placeholders_expand() {
+        let placeholders = BadgePlaceholders::builder()
+            .project_path("group/project")
+            .project_id(42)
+            .default_branch("main")
+            .build()
+            .unwrap();
+
+        let items = &[
+            ("https://example.com", "https://example.com"),
+            (
+                "https://example.com/%{project_path}/-/commits/%{default_branch}",
+                "https://example.com/group/project/-/commits/main",
+            ),
+            ("%{project_id}%{project_id}", "4242"),
+            (
+                "https://example.com/%{commit_sha}/%{unknown}",
+                "https://example.com/%{commit_sha}/%{unknown}",
+            ),
+            (
+                "https://example.com/%{project_path",
+                "https://example.com/%{project_path",
+            ),
+            ("%%{project_id}}", "%42}"),
+        ];
+
+        for (template, expanded) in items {
+            assert_eq!(placeholders.expand(template), *expanded);
+        }
+    }
+
+    #[test]
+    fn badge_url_validation() {
+        let items: &[(&str, Result<(), &str>)] = &[
+            ("https://example.com", Ok(())),
+            ("https://example.com/%{project_path}/%{commit_sha}", Ok(())),
+            ("https://example.com/%{project_path", Ok(())),
+            (
+                "https://example.com/%{project_path}/%{unknown}",
+                Err("invalid `link_url`: unknown placeholder `%{unknown}`"),
+            ),
+        ];
+
+        for (url, expected) in items {
+            assert_eq!(
+                validate_badge_url("link_url", Some(&(*url).into())),
+                expected.map_err(String::from),
+            );
+        }
+        assert_eq!(validate_badge_url("link_url", None), Ok(()));
+    }
+}
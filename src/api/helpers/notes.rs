@@ -0,0 +1,195 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::api::{
+    self, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination, ParamValue,
+    Query,
+};
+
+/// Keys note results may be ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NoteOrderBy {
+    /// Sort by creation date.
+    CreatedAt,
+    /// Sort by last updated date.
+    UpdatedAt,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for NoteOrderBy {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        NoteOrderBy::CreatedAt
+    }
+}
+
+impl NoteOrderBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            NoteOrderBy::CreatedAt => "created_at",
+            NoteOrderBy::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+impl ParamValue<'static> for NoteOrderBy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Kinds of notes to keep when listing notes.
+///
+/// GitLab does not support filtering notes server-side through the REST API, so this filter is
+/// applied to the results as they are fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NoteActivityFilter {
+    /// Keep all notes.
+    AllNotes,
+    /// Keep only comments written by users.
+    OnlyComments,
+    /// Keep only system notes (e.g., label changes, assignments, etc.).
+    OnlyActivity,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for NoteActivityFilter {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        NoteActivityFilter::AllNotes
+    }
+}
+
+impl NoteActivityFilter {
+    fn keeps(self, system: bool) -> bool {
+        match self {
+            NoteActivityFilter::AllNotes => true,
+            NoteActivityFilter::OnlyComments => !system,
+            NoteActivityFilter::OnlyActivity => system,
+        }
+    }
+
+    fn keeps_note(self, note: &Value) -> bool {
+        let system = note.get("system").and_then(Value::as_bool).unwrap_or(false);
+        self.keeps(system)
+    }
+}
+
+/// A query modifier that filters notes from a paged notes endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilteredNotes<E> {
+    endpoint: E,
+    pagination: Pagination,
+    filter: NoteActivityFilter,
+}
+
+/// Collect notes from a paged notes endpoint, keeping only those matching a filter.
+///
+/// Pagination limits apply to the notes fetched from GitLab, so fewer notes than the limit may be
+/// returned.
+pub fn filter_notes<E>(
+    endpoint: E,
+    pagination: Pagination,
+    filter: NoteActivityFilter,
+) -> FilteredNotes<E> {
+    FilteredNotes {
+        endpoint,
+        pagination,
+        filter,
+    }
+}
+
+impl<E> FilteredNotes<E> {
+    fn collect<T, C>(&self, notes: Vec<Value>) -> Result<Vec<T>, ApiError<C>>
+    where
+        T: DeserializeOwned,
+        C: std::error::Error + Send + Sync + 'static,
+    {
+        notes
+            .into_iter()
+            .filter(|note| self.filter.keeps_note(note))
+            .map(|note| serde_json::from_value(note).map_err(ApiError::data_type::<T>))
+            .collect()
+    }
+}
+
+impl<E, T, C> Query<Vec<T>, C> for FilteredNotes<E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let notes: Vec<Value> = api::paged(&self.endpoint, self.pagination).query(client)?;
+        self.collect::<T, C::Error>(notes)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<Vec<T>, C> for FilteredNotes<E>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let notes: Vec<Value> = api::paged(&self.endpoint, self.pagination)
+            .query_async(client)
+            .await?;
+        self.collect::<T, C::Error>(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::helpers::{NoteActivityFilter, NoteOrderBy};
+
+    #[test]
+    fn note_order_by_default() {
+        assert_eq!(NoteOrderBy::default(), NoteOrderBy::CreatedAt);
+    }
+
+    #[test]
+    fn note_order_by_as_str() {
+        let items = &[
+            (NoteOrderBy::CreatedAt, "created_at"),
+            (NoteOrderBy::UpdatedAt, "updated_at"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn note_activity_filter_default() {
+        assert_eq!(NoteActivityFilter::default(), NoteActivityFilter::AllNotes);
+    }
+
+    #[test]
+    fn note_activity_filter_keeps() {
+        let items = &[
+            (NoteActivityFilter::AllNotes, false, true),
+            (NoteActivityFilter::AllNotes, true, true),
+            (NoteActivityFilter::OnlyComments, false, true),
+            (NoteActivityFilter::OnlyComments, true, false),
+            (NoteActivityFilter::OnlyActivity, false, false),
+            (NoteActivityFilter::OnlyActivity, true, true),
+        ];
+
+        for (filter, system, keeps) in items {
+            assert_eq!(filter.keeps(*system), *keeps);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Instance information API endpoints.
+//!
+//! These endpoints are used for querying information about the GitLab instance, for checking
+//! which features it supports, and for checking its health.
+
+mod capabilities;
+mod health;
+mod liveness;
+mod metadata;
+mod readiness;
+mod version;
+
+pub use self::capabilities::Capabilities;
+pub use self::capabilities::Feature;
+pub use self::capabilities::InstanceVersion;
+
+pub use self::health::Health;
+pub use self::health::HealthBuilder;
+pub use self::health::HealthBuilderError;
+
+pub use self::liveness::Liveness;
+pub use self::liveness::LivenessBuilder;
+pub use self::liveness::LivenessBuilderError;
+
+pub use self::metadata::Metadata;
+pub use self::metadata::MetadataBuilder;
+pub use self::metadata::MetadataBuilderError;
+
+pub use self::readiness::Readiness;
+pub use self::readiness::ReadinessBuilder;
+pub use self::readiness::ReadinessBuilderError;
+
+pub use self::version::Version;
+pub use self::version::VersionBuilder;
+pub use self::version::VersionBuilderError;
@@ -0,0 +1,418 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+
+use crate::api::instance::Version;
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// Features which are not available on all GitLab instances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Feature {
+    /// Keyset pagination when listing groups (ordered by name).
+    KeysetPaginationGroups,
+    /// The `/metadata` endpoint.
+    MetadataEndpoint,
+    /// The `default_branch_protection_defaults` setting for groups.
+    BranchProtectionDefaults,
+    /// Epics.
+    ///
+    /// These are only available in the Enterprise Edition.
+    Epics,
+}
+
+impl Feature {
+    /// The minimum version (major, minor) of GitLab supporting the feature.
+    fn min_version(self) -> (u64, u64) {
+        match self {
+            Feature::KeysetPaginationGroups => (14, 3),
+            Feature::MetadataEndpoint => (15, 2),
+            Feature::BranchProtectionDefaults => (17, 0),
+            Feature::Epics => (0, 0),
+        }
+    }
+
+    /// Whether the feature requires the Enterprise Edition.
+    fn requires_enterprise(self) -> bool {
+        matches!(self, Feature::Epics)
+    }
+}
+
+/// The version and edition of a GitLab instance.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "VersionInfo")]
+pub struct InstanceVersion {
+    version: String,
+    revision: String,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    enterprise: bool,
+}
+
+impl InstanceVersion {
+    /// The version string as reported by the instance (e.g., `16.4.1-ee`).
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// The revision of GitLab deployed on the instance.
+    pub fn revision(&self) -> &str {
+        &self.revision
+    }
+
+    /// The major version of the instance.
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// The minor version of the instance.
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// The patch version of the instance.
+    pub fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    /// Whether the instance runs the Enterprise Edition.
+    pub fn is_enterprise(&self) -> bool {
+        self.enterprise
+    }
+
+    /// Whether the instance is at least the given version.
+    pub fn at_least(&self, major: u64, minor: u64) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    /// Whether the instance supports a feature.
+    pub fn supports(&self, feature: Feature) -> bool {
+        let (major, minor) = feature.min_version();
+        self.at_least(major, minor) && (self.enterprise || !feature.requires_enterprise())
+    }
+}
+
+/// The fields of the version endpoint's response.
+#[derive(Debug, Deserialize)]
+struct VersionInfo {
+    version: String,
+    #[serde(default)]
+    revision: String,
+    // Only reported by newer instances; older ones use an `-ee` suffix on the version.
+    #[serde(default)]
+    enterprise: Option<bool>,
+}
+
+/// An error for an unparseable version string.
+#[derive(Debug)]
+struct VersionParseError {
+    version: String,
+}
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid GitLab version `{}`", self.version)
+    }
+}
+
+impl TryFrom<VersionInfo> for InstanceVersion {
+    type Error = VersionParseError;
+
+    fn try_from(info: VersionInfo) -> Result<Self, Self::Error> {
+        let (number, suffix) = info
+            .version
+            .split_once('-')
+            .unwrap_or((info.version.as_str(), ""));
+        let mut parts = number.split('.').map(str::parse::<u64>);
+        let mut next = || {
+            parts.next().unwrap_or(Ok(0)).map_err(|_| {
+                VersionParseError {
+                    version: info.version.clone(),
+                }
+            })
+        };
+        let major = next()?;
+        let minor = next()?;
+        let patch = next()?;
+        let enterprise = info.enterprise.unwrap_or(suffix == "ee");
+
+        Ok(InstanceVersion {
+            major,
+            minor,
+            patch,
+            enterprise,
+            version: info.version,
+            revision: info.revision,
+        })
+    }
+}
+
+/// A cache of the capabilities of a GitLab instance.
+///
+/// The version of the instance is fetched the first time it is needed and reused afterwards.
+/// Clients provide one of these (see `Gitlab::capabilities`), but a `Capabilities` may be created
+/// for any client. Use `clear` to force the version to be fetched again (e.g., after an upgrade).
+#[derive(Debug, Default)]
+pub struct Capabilities {
+    instance: Mutex<Option<InstanceVersion>>,
+}
+
+impl Capabilities {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn cached(&self) -> Option<InstanceVersion> {
+        self.instance
+            .lock()
+            .expect("poisoned capabilities cache")
+            .clone()
+    }
+
+    fn store(&self, instance: InstanceVersion) -> InstanceVersion {
+        *self.instance.lock().expect("poisoned capabilities cache") = Some(instance.clone());
+        instance
+    }
+
+    /// The version of the instance.
+    pub fn instance<C>(&self, client: &C) -> Result<InstanceVersion, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        if let Some(instance) = self.cached() {
+            return Ok(instance);
+        }
+
+        let instance = Version::builder().build().unwrap().query(client)?;
+        Ok(self.store(instance))
+    }
+
+    /// The version of the instance, asynchronously.
+    pub async fn instance_async<C>(&self, client: &C) -> Result<InstanceVersion, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        if let Some(instance) = self.cached() {
+            return Ok(instance);
+        }
+
+        let instance = Version::builder()
+            .build()
+            .unwrap()
+            .query_async(client)
+            .await?;
+        Ok(self.store(instance))
+    }
+
+    /// Whether the instance supports a feature.
+    pub fn supports<C>(&self, client: &C, feature: Feature) -> Result<bool, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        Ok(self.instance(client)?.supports(feature))
+    }
+
+    /// Whether the instance supports a feature, asynchronously.
+    pub async fn supports_async<C>(
+        &self,
+        client: &C,
+        feature: Feature,
+    ) -> Result<bool, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        Ok(self.instance_async(client).await?.supports(feature))
+    }
+
+    /// Forget the cached version of the instance.
+    pub fn clear(&self) {
+        *self.instance.lock().expect("poisoned capabilities cache") = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::instance::{Capabilities, Feature, InstanceVersion};
+    use crate::api::ApiError;
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    fn parse(value: serde_json::Value) -> InstanceVersion {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn instance_version_parse() {
+        let version = parse(json!({
+            "version": "16.4.1-ee",
+            "revision": "abcdef",
+        }));
+        assert_eq!(version.version(), "16.4.1-ee");
+        assert_eq!(version.revision(), "abcdef");
+        assert_eq!(version.major(), 16);
+        assert_eq!(version.minor(), 4);
+        assert_eq!(version.patch(), 1);
+        assert!(version.is_enterprise());
+    }
+
+    #[test]
+    fn instance_version_parse_pre() {
+        let version = parse(json!({
+            "version": "17.1.0-pre",
+            "revision": "abcdef",
+        }));
+        assert_eq!(version.major(), 17);
+        assert_eq!(version.minor(), 1);
+        assert_eq!(version.patch(), 0);
+        assert!(!version.is_enterprise());
+    }
+
+    #[test]
+    fn instance_version_parse_enterprise_field() {
+        let version = parse(json!({
+            "version": "16.4.1",
+            "revision": "abcdef",
+            "enterprise": true,
+        }));
+        assert!(version.is_enterprise());
+    }
+
+    #[test]
+    fn instance_version_parse_short() {
+        let version = parse(json!({
+            "version": "16",
+        }));
+        assert_eq!(version.major(), 16);
+        assert_eq!(version.minor(), 0);
+        assert_eq!(version.patch(), 0);
+        assert_eq!(version.revision(), "");
+    }
+
+    #[test]
+    fn instance_version_parse_invalid() {
+        let err = serde_json::from_value::<InstanceVersion>(json!({
+            "version": "sixteen",
+        }))
+        .unwrap_err();
+        assert_eq!(err.to_string(), "invalid GitLab version `sixteen`");
+    }
+
+    #[test]
+    fn instance_version_supports() {
+        let ce = parse(json!({"version": "15.2.0"}));
+        let ee = parse(json!({"version": "15.2.0-ee"}));
+
+        let items = &[
+            (Feature::KeysetPaginationGroups, true, true),
+            (Feature::MetadataEndpoint, true, true),
+            (Feature::BranchProtectionDefaults, false, false),
+            (Feature::Epics, false, true),
+        ];
+
+        for (feature, in_ce, in_ee) in items {
+            assert_eq!(ce.supports(*feature), *in_ce);
+            assert_eq!(ee.supports(*feature), *in_ee);
+        }
+    }
+
+    fn mock_client(count: usize) -> MockClient {
+        let client = MockClient::new();
+        for _ in 0..count {
+            client.expect(
+                ExpectedRequest::builder()
+                    .endpoint("version")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({
+                    "version": "17.0.0-ee",
+                    "revision": "abcdef",
+                })),
+            );
+        }
+        client
+    }
+
+    #[test]
+    fn capabilities_cached() {
+        let client = mock_client(1);
+        let capabilities = Capabilities::new();
+
+        assert!(capabilities
+            .supports(&client, Feature::BranchProtectionDefaults)
+            .unwrap());
+        assert!(capabilities.supports(&client, Feature::Epics).unwrap());
+        assert_eq!(capabilities.instance(&client).unwrap().major(), 17);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn capabilities_clear() {
+        let client = mock_client(2);
+        let capabilities = Capabilities::new();
+
+        capabilities.instance(&client).unwrap();
+        capabilities.clear();
+        capabilities.instance(&client).unwrap();
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn capabilities_cached_async() {
+        let client = mock_client(1);
+        let capabilities = Capabilities::new();
+
+        assert!(capabilities
+            .supports_async(&client, Feature::MetadataEndpoint)
+            .await
+            .unwrap());
+        assert_eq!(
+            capabilities.instance_async(&client).await.unwrap().minor(),
+            0,
+        );
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn capabilities_error_not_cached() {
+        let client = MockClient::new();
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("version")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"message": "401 Unauthorized"}))
+                    .status(http::StatusCode::UNAUTHORIZED),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("version")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"version": "16.0.0"})),
+            );
+        let capabilities = Capabilities::new();
+
+        let err = capabilities.instance(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "401 Unauthorized");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert_eq!(capabilities.instance(&client).unwrap().major(), 16);
+        client.assert_all_consumed();
+    }
+}
@@ -0,0 +1,64 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query whether the instance is running.
+///
+/// This endpoint does not use the API prefix and is only accessible from IP addresses in the
+/// instance's monitoring allowlist. It returns plain text rather than JSON, so it should be
+/// queried with [`api::raw`](crate::api::raw) or [`api::ignore`](crate::api::ignore).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Health {}
+
+impl Health {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> HealthBuilder {
+        HealthBuilder::default()
+    }
+}
+
+impl Endpoint for Health {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "-/health".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::instance::Health;
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Health::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/health")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "GitLab OK");
+
+        let endpoint = Health::builder().build().unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(data, b"GitLab OK");
+    }
+}
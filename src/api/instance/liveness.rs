@@ -0,0 +1,62 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query whether the application server is alive.
+///
+/// This endpoint does not use the API prefix and is only accessible from IP addresses in the
+/// instance's monitoring allowlist.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Liveness {}
+
+impl Liveness {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LivenessBuilder {
+        LivenessBuilder::default()
+    }
+}
+
+impl Endpoint for Liveness {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "-/liveness".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::instance::Liveness;
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Liveness::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/liveness")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Liveness::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
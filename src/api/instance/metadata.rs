@@ -0,0 +1,53 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for metadata about the instance.
+///
+/// This includes the version, revision, edition, and KAS configuration of the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Metadata {}
+
+impl Metadata {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder::default()
+    }
+}
+
+impl Endpoint for Metadata {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "metadata".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::instance::Metadata;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Metadata::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("metadata").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Metadata::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query whether the instance is ready to accept traffic.
+///
+/// This endpoint does not use the API prefix and is only accessible from IP addresses in the
+/// instance's monitoring allowlist.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Readiness {
+    /// Check all services the instance depends on.
+    ///
+    /// By default, only the application server itself is checked.
+    #[builder(default)]
+    all: bool,
+}
+
+impl Readiness {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ReadinessBuilder {
+        ReadinessBuilder::default()
+    }
+}
+
+impl Endpoint for Readiness {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "-/readiness".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        if self.all {
+            params.push("all", 1u64);
+        }
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::instance::Readiness;
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Readiness::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/readiness")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Readiness::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/readiness")
+            .url_base(UrlBase::Instance)
+            .add_query_params(&[("all", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Readiness::builder().all(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the version of the instance.
+///
+/// On newer instances, this is an alias for `Metadata`.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Version {}
+
+impl Version {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> VersionBuilder {
+        VersionBuilder::default()
+    }
+}
+
+impl Endpoint for Version {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "version".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::instance::Version;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Version::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("version").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Version::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
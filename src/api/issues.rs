@@ -82,6 +82,8 @@ pub enum IssueType {
     Incident,
     /// Test case issues.
     TestCase,
+    /// Task work items.
+    Task,
 }
 
 impl IssueType {
@@ -90,6 +92,7 @@ impl IssueType {
             IssueType::Issue => "issue",
             IssueType::Incident => "incident",
             IssueType::TestCase => "test_case",
+            IssueType::Task => "task",
         }
     }
 }
@@ -223,7 +226,11 @@ impl<'a> Assignee<'a> {
                 params.push("assignee_id", *id);
             },
             Assignee::Usernames(usernames) => {
-                params.extend(usernames.iter().map(|value| ("assignee_username[]", value)));
+                params.extend(
+                    usernames
+                        .iter()
+                        .map(|value| (ParamName::new("assignee_username").array(), value)),
+                );
             },
         }
     }
@@ -497,6 +504,7 @@ mod tests {
             (IssueType::Issue, "issue"),
             (IssueType::Incident, "incident"),
             (IssueType::TestCase, "test_case"),
+            (IssueType::Task, "task"),
         ];
 
         for (i, s) in items {
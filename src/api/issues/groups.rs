@@ -11,9 +11,9 @@ use derive_builder::Builder;
 use http::Method;
 
 use crate::api::{
-    common::{CommaSeparatedList, NameOrId, SortOrder},
+    common::{self, CommaSeparatedList, NameOrId, PathParam, SortOrder},
     helpers::{Labels, ReactionEmoji},
-    Endpoint, Pageable, QueryParams,
+    Endpoint, Pageable, ParamName, QueryParams,
 };
 
 use super::{
@@ -25,7 +25,7 @@ use super::{
 ///
 /// TODO: Negation (not) filters are not yet supported.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupIssues<'a> {
     /// The group to query for issues.
     #[builder(setter(into))]
@@ -281,6 +281,12 @@ impl<'a> GroupIssuesBuilder<'a> {
             .push(scope);
         self
     }
+
+    fn validate(&self) -> Result<(), GroupIssuesBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for GroupIssues<'a> {
@@ -289,14 +295,18 @@ impl<'a> Endpoint for GroupIssues<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/issues", self.group).into()
+        format!("groups/{}/issues", self.group.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
 
         params
-            .extend(self.iids.iter().map(|&value| ("iids[]", value)))
+            .extend(
+                self.iids
+                    .iter()
+                    .map(|&value| (ParamName::new("iids").array(), value)),
+            )
             .push_opt("state", self.state)
             .push_opt("labels", self.labels.as_ref())
             .push_opt("with_labels_details", self.with_labels_details)
@@ -366,6 +376,12 @@ mod tests {
         GroupIssues::builder().group(1).build().unwrap();
     }
 
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupIssues::builder().group(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupIssuesBuilderError, "group");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -11,10 +11,10 @@ use derive_builder::Builder;
 use http::Method;
 
 use crate::api::{
-    common::{CommaSeparatedList, NameOrId, SortOrder},
+    common::{self, CommaSeparatedList, NameOrId, PathParam, SortOrder},
     helpers::{Labels, ReactionEmoji},
     issues::IssueType,
-    Endpoint, Pageable, QueryParams,
+    Endpoint, Pageable, ParamName, QueryParams,
 };
 
 use super::{
@@ -26,7 +26,7 @@ use super::{
 ///
 /// TODO: Negation (not) filters are not yet supported.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectIssues<'a> {
     /// The project to query for issues.
     #[builder(setter(into))]
@@ -283,6 +283,12 @@ impl<'a> ProjectIssuesBuilder<'a> {
             .push(scope);
         self
     }
+
+    fn validate(&self) -> Result<(), ProjectIssuesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for ProjectIssues<'a> {
@@ -291,14 +297,18 @@ impl<'a> Endpoint for ProjectIssues<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues", self.project).into()
+        format!("projects/{}/issues", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
 
         params
-            .extend(self.iids.iter().map(|&value| ("iids[]", value)))
+            .extend(
+                self.iids
+                    .iter()
+                    .map(|&value| (ParamName::new("iids").array(), value)),
+            )
             .push_opt("state", self.state)
             .push_opt("labels", self.labels.as_ref())
             .push_opt("with_labels_details", self.with_labels_details)
@@ -367,6 +377,12 @@ mod tests {
         ProjectIssues::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectIssues::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectIssuesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
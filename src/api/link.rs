@@ -0,0 +1,239 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use http::Method;
+use url::Url;
+
+use crate::api::{ApiError, Endpoint, Pageable, QueryParams, RestClient};
+
+/// Query parameters which are controlled by `api::paged`.
+const PAGINATION_PARAMS: &[&str] = &["page", "per_page"];
+
+/// An endpoint for a URL into the API given by GitLab.
+///
+/// Some responses contain URLs to related resources (e.g., the `_links` field of projects and
+/// merge requests). This endpoint may be used to follow them. The URL must point into the REST
+/// API of the client's instance. Query parameters in the URL are kept except for `page` and
+/// `per_page` so that the endpoint can be used with `api::paged`.
+#[derive(Debug, Clone)]
+pub struct ApiLink {
+    endpoint: String,
+    params: Vec<(String, String)>,
+}
+
+impl ApiLink {
+    /// Create an endpoint for a URL given by GitLab.
+    ///
+    /// Fails if the URL is not part of the REST API of the client's instance.
+    pub fn new<C>(client: &C, url: &str) -> Result<Self, ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let base = client.rest_endpoint("")?;
+        let url = Url::parse(url)?;
+
+        let same_origin = url.scheme() == base.scheme()
+            && url.host_str() == base.host_str()
+            && url.port_or_known_default() == base.port_or_known_default();
+        let endpoint = if same_origin {
+            url.path().strip_prefix(base.path())
+        } else {
+            None
+        };
+        let endpoint = endpoint.ok_or_else(|| ApiError::foreign_url(&url))?.into();
+
+        let params = url
+            .query_pairs()
+            .filter(|(key, _)| !PAGINATION_PARAMS.contains(&key.as_ref()))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+
+        Ok(Self {
+            endpoint,
+            params,
+        })
+    }
+}
+
+impl Endpoint for ApiLink {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.clone().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        for (key, value) in &self.params {
+            params.push(key, value);
+        }
+
+        params
+    }
+}
+
+impl Pageable for ApiLink {}
+
+/// Create endpoints for URLs into the API given by GitLab.
+///
+/// This is implemented for all clients.
+pub trait ApiLinks: RestClient + Sized {
+    /// Create an endpoint for a URL into the API given by GitLab.
+    ///
+    /// This allows following URLs in responses such as the `_links` field of projects. See
+    /// [`ApiLink`] for details.
+    // The client is the context for the URL rather than the value being converted.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_api_url(&self, url: &str) -> Result<ApiLink, ApiError<Self::Error>> {
+        ApiLink::new(self, url)
+    }
+}
+
+impl<C> ApiLinks for C where C: RestClient {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::{self, ApiError, ApiLink, ApiLinks, Pagination, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedRequest, MockClient};
+
+    fn assert_foreign(url: &str) {
+        let client = MockClient::new();
+        let err = ApiLink::new(&client, url).unwrap_err();
+        if let ApiError::ForeignUrl {
+            url: err_url,
+        } = err
+        {
+            assert_eq!(err_url, url);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn url_must_parse() {
+        let client = MockClient::new();
+        let err = ApiLink::new(&client, "not a url").unwrap_err();
+        if let ApiError::UrlParse {
+            ..
+        } = err
+        {
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn url_must_be_same_scheme() {
+        assert_foreign("http://gitlab.host.invalid/api/v4/projects/1");
+    }
+
+    #[test]
+    fn url_must_be_same_host() {
+        assert_foreign("https://gitlab.other.invalid/api/v4/projects/1");
+    }
+
+    #[test]
+    fn url_must_be_same_port() {
+        assert_foreign("https://gitlab.host.invalid:8443/api/v4/projects/1");
+    }
+
+    #[test]
+    fn url_must_be_in_api() {
+        assert_foreign("https://gitlab.host.invalid/group/project");
+        assert_foreign("https://gitlab.host.invalid/api/v4");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("state", "opened"), ("labels", "a,b")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApiLink::new(
+            &client,
+            "https://gitlab.host.invalid/api/v4/projects/simple%2Fproject/issues?state=opened&labels=a%2Cb",
+        )
+        .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn from_api_url() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/merge_requests/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = client
+            .from_api_url("https://gitlab.host.invalid/api/v4/projects/1/merge_requests/2")
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn from_api_url_foreign() {
+        let client = MockClient::new();
+        let url = "https://gitlab.other.invalid/api/v4/projects/1";
+        let err = client.from_api_url(url).unwrap_err();
+        if let ApiError::ForeignUrl {
+            url: err_url,
+        } = err
+        {
+            assert_eq!(err_url, url);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn endpoint_default_port() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint =
+            ApiLink::new(&client, "https://gitlab.host.invalid:443/api/v4/projects/1").unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paged() {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/1/merge_requests/1/notes")
+                .query_param("sort", "asc")
+                .build()
+                .unwrap(),
+            [json!({"id": 1}), json!({"id": 2})],
+        );
+
+        let endpoint = ApiLink::new(
+            &client,
+            "https://gitlab.host.invalid/api/v4/projects/1/merge_requests/1/notes?sort=asc&page=3&per_page=5",
+        )
+        .unwrap();
+        let notes: Vec<serde_json::Value> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(notes, [json!({"id": 1}), json!({"id": 2})]);
+        client.assert_all_consumed();
+    }
+}
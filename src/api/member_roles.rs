@@ -0,0 +1,28 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Instance member role API endpoints.
+//!
+//! These endpoints are used for managing custom member roles on self-managed instances.
+
+mod create;
+mod delete;
+mod member_roles;
+
+pub use self::create::CreateMemberRole;
+pub use self::create::CreateMemberRoleBuilder;
+pub use self::create::CreateMemberRoleBuilderError;
+pub use self::create::MemberRoleAbility;
+
+pub use self::delete::DeleteMemberRole;
+pub use self::delete::DeleteMemberRoleBuilder;
+pub use self::delete::DeleteMemberRoleBuilderError;
+
+pub use self::member_roles::MemberRoles;
+pub use self::member_roles::MemberRolesBuilder;
+pub use self::member_roles::MemberRolesBuilderError;
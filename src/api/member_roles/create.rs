@@ -0,0 +1,360 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::AccessLevel;
+use crate::api::endpoint_prelude::*;
+
+/// Abilities which may be granted by a member role on top of its base access level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum MemberRoleAbility {
+    /// Create, read, update, and delete CI/CD variables.
+    AdminCicdVariables,
+    /// Manage compliance frameworks.
+    AdminComplianceFramework,
+    /// Add or remove members of groups.
+    AdminGroupMember,
+    /// Manage integrations.
+    AdminIntegrations,
+    /// Approve merge requests.
+    AdminMergeRequest,
+    /// Manage protected branches.
+    AdminProtectedBranch,
+    /// Configure push rules.
+    AdminPushRules,
+    /// Manage runners.
+    AdminRunners,
+    /// Manage Terraform states.
+    AdminTerraformState,
+    /// Edit the status of vulnerabilities.
+    AdminVulnerability,
+    /// Manage webhooks.
+    AdminWebHook,
+    /// Archive and unarchive projects.
+    ArchiveProject,
+    /// Manage deploy tokens.
+    ManageDeployTokens,
+    /// Manage group access tokens.
+    ManageGroupAccessTokens,
+    /// Configure merge request settings.
+    ManageMergeRequestSettings,
+    /// Manage project access tokens.
+    ManageProjectAccessTokens,
+    /// Link security policy projects.
+    ManageSecurityPolicyLink,
+    /// Read project code.
+    ReadCode,
+    /// Read the compliance dashboard.
+    ReadComplianceDashboard,
+    /// Read CRM contacts.
+    ReadCrmContact,
+    /// Read project dependencies.
+    ReadDependency,
+    /// Read runners.
+    ReadRunners,
+    /// Read vulnerability reports.
+    ReadVulnerability,
+    /// Delete or restore groups.
+    RemoveGroup,
+    /// Delete projects.
+    RemoveProject,
+}
+
+impl MemberRoleAbility {
+    /// The ability as a parameter name.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            MemberRoleAbility::AdminCicdVariables => "admin_cicd_variables",
+            MemberRoleAbility::AdminComplianceFramework => "admin_compliance_framework",
+            MemberRoleAbility::AdminGroupMember => "admin_group_member",
+            MemberRoleAbility::AdminIntegrations => "admin_integrations",
+            MemberRoleAbility::AdminMergeRequest => "admin_merge_request",
+            MemberRoleAbility::AdminProtectedBranch => "admin_protected_branch",
+            MemberRoleAbility::AdminPushRules => "admin_push_rules",
+            MemberRoleAbility::AdminRunners => "admin_runners",
+            MemberRoleAbility::AdminTerraformState => "admin_terraform_state",
+            MemberRoleAbility::AdminVulnerability => "admin_vulnerability",
+            MemberRoleAbility::AdminWebHook => "admin_web_hook",
+            MemberRoleAbility::ArchiveProject => "archive_project",
+            MemberRoleAbility::ManageDeployTokens => "manage_deploy_tokens",
+            MemberRoleAbility::ManageGroupAccessTokens => "manage_group_access_tokens",
+            MemberRoleAbility::ManageMergeRequestSettings => "manage_merge_request_settings",
+            MemberRoleAbility::ManageProjectAccessTokens => "manage_project_access_tokens",
+            MemberRoleAbility::ManageSecurityPolicyLink => "manage_security_policy_link",
+            MemberRoleAbility::ReadCode => "read_code",
+            MemberRoleAbility::ReadComplianceDashboard => "read_compliance_dashboard",
+            MemberRoleAbility::ReadCrmContact => "read_crm_contact",
+            MemberRoleAbility::ReadDependency => "read_dependency",
+            MemberRoleAbility::ReadRunners => "read_runners",
+            MemberRoleAbility::ReadVulnerability => "read_vulnerability",
+            MemberRoleAbility::RemoveGroup => "remove_group",
+            MemberRoleAbility::RemoveProject => "remove_project",
+        }
+    }
+}
+
+/// Create a member role for the instance.
+///
+/// Only available on self-managed instances.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateMemberRole<'a> {
+    /// The name of the member role.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The access level the member role is based upon.
+    base_access_level: AccessLevel,
+
+    /// The description of the member role.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The abilities granted by the member role.
+    #[builder(setter(name = "_abilities"), default, private)]
+    abilities: BTreeSet<MemberRoleAbility>,
+}
+
+impl<'a> CreateMemberRole<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateMemberRoleBuilder<'a> {
+        CreateMemberRoleBuilder::default()
+    }
+}
+
+impl<'a> CreateMemberRoleBuilder<'a> {
+    /// Grant an ability.
+    pub fn ability(&mut self, ability: MemberRoleAbility) -> &mut Self {
+        self.abilities
+            .get_or_insert_with(BTreeSet::new)
+            .insert(ability);
+        self
+    }
+
+    /// Grant a set of abilities.
+    pub fn abilities<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = MemberRoleAbility>,
+    {
+        self.abilities
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateMemberRole<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "member_roles".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push("base_access_level", self.base_access_level.as_u64())
+            .push_opt("description", self.description.as_ref())
+            .extend(
+                self.abilities
+                    .iter()
+                    .map(|ability| (ability.as_str(), true)),
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::member_roles::{
+        CreateMemberRole, CreateMemberRoleBuilderError, MemberRoleAbility,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn member_role_ability_as_str() {
+        let items = &[
+            (
+                MemberRoleAbility::AdminCicdVariables,
+                "admin_cicd_variables",
+            ),
+            (
+                MemberRoleAbility::AdminComplianceFramework,
+                "admin_compliance_framework",
+            ),
+            (MemberRoleAbility::AdminGroupMember, "admin_group_member"),
+            (MemberRoleAbility::AdminIntegrations, "admin_integrations"),
+            (MemberRoleAbility::AdminMergeRequest, "admin_merge_request"),
+            (
+                MemberRoleAbility::AdminProtectedBranch,
+                "admin_protected_branch",
+            ),
+            (MemberRoleAbility::AdminPushRules, "admin_push_rules"),
+            (MemberRoleAbility::AdminRunners, "admin_runners"),
+            (
+                MemberRoleAbility::AdminTerraformState,
+                "admin_terraform_state",
+            ),
+            (MemberRoleAbility::AdminVulnerability, "admin_vulnerability"),
+            (MemberRoleAbility::AdminWebHook, "admin_web_hook"),
+            (MemberRoleAbility::ArchiveProject, "archive_project"),
+            (
+                MemberRoleAbility::ManageDeployTokens,
+                "manage_deploy_tokens",
+            ),
+            (
+                MemberRoleAbility::ManageGroupAccessTokens,
+                "manage_group_access_tokens",
+            ),
+            (
+                MemberRoleAbility::ManageMergeRequestSettings,
+                "manage_merge_request_settings",
+            ),
+            (
+                MemberRoleAbility::ManageProjectAccessTokens,
+                "manage_project_access_tokens",
+            ),
+            (
+                MemberRoleAbility::ManageSecurityPolicyLink,
+                "manage_security_policy_link",
+            ),
+            (MemberRoleAbility::ReadCode, "read_code"),
+            (
+                MemberRoleAbility::ReadComplianceDashboard,
+                "read_compliance_dashboard",
+            ),
+            (MemberRoleAbility::ReadCrmContact, "read_crm_contact"),
+            (MemberRoleAbility::ReadDependency, "read_dependency"),
+            (MemberRoleAbility::ReadRunners, "read_runners"),
+            (MemberRoleAbility::ReadVulnerability, "read_vulnerability"),
+            (MemberRoleAbility::RemoveGroup, "remove_group"),
+            (MemberRoleAbility::RemoveProject, "remove_project"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn name_and_base_access_level_are_necessary() {
+        let err = CreateMemberRole::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateMemberRoleBuilderError, "name");
+    }
+
+    #[test]
+    fn name_is_necessary() {
+        let err = CreateMemberRole::builder()
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateMemberRoleBuilderError, "name");
+    }
+
+    #[test]
+    fn base_access_level_is_necessary() {
+        let err = CreateMemberRole::builder()
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateMemberRoleBuilderError, "base_access_level");
+    }
+
+    #[test]
+    fn name_and_base_access_level_are_sufficient() {
+        CreateMemberRole::builder()
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("member_roles")
+            .form_params(&[("name", "name"), ("base_access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMemberRole::builder()
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("member_roles")
+            .form_params(&[
+                ("name", "name"),
+                ("base_access_level", "30"),
+                ("description", "description"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMemberRole::builder()
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_abilities() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("member_roles")
+            .form_params(&[
+                ("name", "name"),
+                ("base_access_level", "30"),
+                ("admin_merge_request", "true"),
+                ("read_code", "true"),
+                ("read_vulnerability", "true"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMemberRole::builder()
+            .name("name")
+            .base_access_level(AccessLevel::Developer)
+            .ability(MemberRoleAbility::ReadCode)
+            .abilities(
+                [
+                    MemberRoleAbility::AdminMergeRequest,
+                    MemberRoleAbility::ReadCode,
+                    MemberRoleAbility::ReadVulnerability,
+                ]
+                .iter()
+                .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
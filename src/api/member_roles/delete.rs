@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete a member role of the instance.
+///
+/// Only available on self-managed instances.
+#[derive(Debug, Builder, Clone)]
+pub struct DeleteMemberRole {
+    /// The ID of the member role.
+    member_role: u64,
+}
+
+impl DeleteMemberRole {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteMemberRoleBuilder {
+        DeleteMemberRoleBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteMemberRole {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("member_roles/{}", self.member_role).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::member_roles::{DeleteMemberRole, DeleteMemberRoleBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn member_role_is_necessary() {
+        let err = DeleteMemberRole::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteMemberRoleBuilderError, "member_role");
+    }
+
+    #[test]
+    fn member_role_is_sufficient() {
+        DeleteMemberRole::builder().member_role(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("member_roles/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteMemberRole::builder().member_role(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the member roles of the instance.
+///
+/// Only available on self-managed instances.
+#[derive(Debug, Builder, Clone)]
+pub struct MemberRoles {}
+
+impl MemberRoles {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MemberRolesBuilder {
+        MemberRolesBuilder::default()
+    }
+}
+
+impl Endpoint for MemberRoles {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "member_roles".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::member_roles::MemberRoles;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        MemberRoles::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("member_roles")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MemberRoles::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Namespace API endpoints.
+//!
+//! These endpoints are used for querying namespaces (users and groups) on the instance.
+
+mod exists;
+mod namespace;
+mod namespaces;
+mod suggest;
+
+pub use self::exists::NamespaceExists;
+pub use self::exists::NamespaceExistsBuilder;
+pub use self::exists::NamespaceExistsBuilderError;
+
+pub use self::namespace::Namespace;
+pub use self::namespace::NamespaceBuilder;
+pub use self::namespace::NamespaceBuilderError;
+
+pub use self::namespaces::Namespaces;
+pub use self::namespaces::NamespacesBuilder;
+pub use self::namespaces::NamespacesBuilderError;
+
+pub use self::suggest::SuggestNamespacePath;
+pub use self::suggest::SuggestNamespacePathBuilder;
+pub use self::suggest::SuggestNamespacePathBuilderError;
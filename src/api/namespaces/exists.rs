@@ -0,0 +1,109 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Query whether a namespace path is already in use.
+///
+/// If it is, GitLab also suggests alternative paths which are available.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct NamespaceExists<'a> {
+    /// The path of the namespace.
+    #[builder(setter(into))]
+    namespace: Cow<'a, str>,
+
+    /// The ID of the parent namespace.
+    ///
+    /// If not given, only top-level namespaces are checked.
+    #[builder(default)]
+    parent_id: Option<u64>,
+}
+
+impl<'a> NamespaceExists<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> NamespaceExistsBuilder<'a> {
+        NamespaceExistsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for NamespaceExists<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "namespaces/{}/exists",
+            common::path_escaped(&self.namespace),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("parent_id", self.parent_id);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::namespaces::{NamespaceExists, NamespaceExistsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn namespace_is_needed() {
+        let err = NamespaceExists::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, NamespaceExistsBuilderError, "namespace");
+    }
+
+    #[test]
+    fn namespace_is_sufficient() {
+        NamespaceExists::builder()
+            .namespace("group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("namespaces/my-group/exists")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = NamespaceExists::builder()
+            .namespace("my-group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_parent_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("namespaces/my-group/exists")
+            .add_query_params(&[("parent_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = NamespaceExists::builder()
+            .namespace("my-group")
+            .parent_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::namespaces::NamespaceExists;
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// The fields of a namespace existence check.
+#[derive(Debug, Deserialize)]
+struct Existence {
+    exists: bool,
+    #[serde(default)]
+    suggests: Vec<String>,
+}
+
+impl Existence {
+    fn into_path(self, path: &str) -> Option<String> {
+        if self.exists {
+            self.suggests.into_iter().next()
+        } else {
+            Some(path.into())
+        }
+    }
+}
+
+/// Find an available path for a new namespace.
+///
+/// If the requested path is free, it is returned as-is. Otherwise, the first alternative
+/// suggested by GitLab is returned. `None` is returned if the path is taken and GitLab has no
+/// suggestions.
+///
+/// Note that the path may be claimed by another request before it is used.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct SuggestNamespacePath<'a> {
+    /// The requested path of the namespace.
+    #[builder(setter(into))]
+    path: Cow<'a, str>,
+
+    /// The ID of the parent namespace.
+    ///
+    /// If not given, the path is checked against top-level namespaces.
+    #[builder(default)]
+    parent_id: Option<u64>,
+}
+
+impl<'a> SuggestNamespacePath<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> SuggestNamespacePathBuilder<'a> {
+        SuggestNamespacePathBuilder::default()
+    }
+
+    fn exists(&self) -> NamespaceExists<'a> {
+        let mut builder = NamespaceExists::builder();
+        builder.namespace(self.path.clone());
+        if let Some(parent_id) = self.parent_id {
+            builder.parent_id(parent_id);
+        }
+        builder.build().unwrap()
+    }
+}
+
+impl<'a, C> Query<Option<String>, C> for SuggestNamespacePath<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Option<String>, ApiError<C::Error>> {
+        let existence: Existence = self.exists().query(client)?;
+        Ok(existence.into_path(&self.path))
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<Option<String>, C> for SuggestNamespacePath<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Option<String>, ApiError<C::Error>> {
+        let existence: Existence = self.exists().query_async(client).await?;
+        Ok(existence.into_path(&self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::namespaces::{SuggestNamespacePath, SuggestNamespacePathBuilderError};
+    use crate::api::{AsyncQuery, Query};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    #[test]
+    fn path_is_needed() {
+        let err = SuggestNamespacePath::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SuggestNamespacePathBuilderError, "path");
+    }
+
+    #[test]
+    fn path_is_sufficient() {
+        SuggestNamespacePath::builder()
+            .path("group")
+            .build()
+            .unwrap();
+    }
+
+    fn mock_client(response: serde_json::Value) -> MockClient {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("namespaces/my-group/exists")
+                .query_param("parent_id", "1")
+                .build()
+                .unwrap(),
+            MockResponse::json(&response),
+        );
+        client
+    }
+
+    fn helper() -> SuggestNamespacePath<'static> {
+        SuggestNamespacePath::builder()
+            .path("my-group")
+            .parent_id(1)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn free_path() {
+        let client = mock_client(json!({"exists": false, "suggests": []}));
+        let path = helper().query(&client).unwrap();
+        assert_eq!(path.as_deref(), Some("my-group"));
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn taken_path() {
+        let client = mock_client(json!({
+            "exists": true,
+            "suggests": ["my-group1", "my-group2"],
+        }));
+        let path = helper().query(&client).unwrap();
+        assert_eq!(path.as_deref(), Some("my-group1"));
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn taken_path_without_suggestions() {
+        let client = mock_client(json!({"exists": true}));
+        let path = helper().query(&client).unwrap();
+        assert_eq!(path, None);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn taken_path_async() {
+        let client = mock_client(json!({
+            "exists": true,
+            "suggests": ["my-group1"],
+        }));
+        let path = helper().query_async(&client).await.unwrap();
+        assert_eq!(path.as_deref(), Some("my-group1"));
+        client.assert_all_consumed();
+    }
+}
@@ -0,0 +1,45 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Notification settings API endpoints.
+//!
+//! These endpoints are used for querying and modifying the notification settings of the API
+//! calling user, either globally or for specific groups and projects.
+
+mod edit;
+mod edit_group;
+mod edit_project;
+mod group;
+mod project;
+mod settings;
+
+pub use self::edit::EditNotificationSettings;
+pub use self::edit::EditNotificationSettingsBuilder;
+pub use self::edit::EditNotificationSettingsBuilderError;
+pub use self::edit::NotificationEvents;
+pub use self::edit::NotificationEventsBuilder;
+pub use self::edit::NotificationEventsBuilderError;
+pub use self::edit::NotificationLevel;
+
+pub use self::edit_group::EditGroupNotificationSettings;
+pub use self::edit_group::EditGroupNotificationSettingsBuilder;
+pub use self::edit_group::EditGroupNotificationSettingsBuilderError;
+
+pub use self::edit_project::EditProjectNotificationSettings;
+pub use self::edit_project::EditProjectNotificationSettingsBuilder;
+pub use self::edit_project::EditProjectNotificationSettingsBuilderError;
+
+pub use self::group::GroupNotificationSettings;
+pub use self::group::GroupNotificationSettingsBuilder;
+pub use self::group::GroupNotificationSettingsBuilderError;
+
+pub use self::project::ProjectNotificationSettings;
+pub use self::project::ProjectNotificationSettingsBuilder;
+pub use self::project::ProjectNotificationSettingsBuilderError;
+
+pub use self::settings::NotificationSettings;
+pub use self::settings::NotificationSettingsBuilder;
+pub use self::settings::NotificationSettingsBuilderError;
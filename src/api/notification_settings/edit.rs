@@ -0,0 +1,327 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Notification levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NotificationLevel {
+    /// Do not send any notifications.
+    Disabled,
+    /// Notify for threads the user participates in.
+    Participating,
+    /// Notify for all activity.
+    Watch,
+    /// Use the global notification setting.
+    Global,
+    /// Notify only when the user is mentioned.
+    Mention,
+    /// Notify for participating threads and selected events.
+    Custom,
+}
+
+impl NotificationLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            NotificationLevel::Disabled => "disabled",
+            NotificationLevel::Participating => "participating",
+            NotificationLevel::Watch => "watch",
+            NotificationLevel::Global => "global",
+            NotificationLevel::Mention => "mention",
+            NotificationLevel::Custom => "custom",
+        }
+    }
+}
+
+impl ParamValue<'static> for NotificationLevel {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Events to notify about when using the `custom` notification level.
+///
+/// Events which are not set are left unchanged.
+#[derive(Debug, Default, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct NotificationEvents {
+    /// Notify when a new note is created.
+    #[builder(default)]
+    new_note: Option<bool>,
+    /// Notify when a new issue is created.
+    #[builder(default)]
+    new_issue: Option<bool>,
+    /// Notify when an issue is reopened.
+    #[builder(default)]
+    reopen_issue: Option<bool>,
+    /// Notify when an issue is closed.
+    #[builder(default)]
+    close_issue: Option<bool>,
+    /// Notify when an issue is reassigned.
+    #[builder(default)]
+    reassign_issue: Option<bool>,
+    /// Notify when an issue is due.
+    #[builder(default)]
+    issue_due: Option<bool>,
+    /// Notify when a new merge request is created.
+    #[builder(default)]
+    new_merge_request: Option<bool>,
+    /// Notify when a merge request is pushed to.
+    #[builder(default)]
+    push_to_merge_request: Option<bool>,
+    /// Notify when a merge request is reopened.
+    #[builder(default)]
+    reopen_merge_request: Option<bool>,
+    /// Notify when a merge request is closed.
+    #[builder(default)]
+    close_merge_request: Option<bool>,
+    /// Notify when a merge request is reassigned.
+    #[builder(default)]
+    reassign_merge_request: Option<bool>,
+    /// Notify when a merge request is merged.
+    #[builder(default)]
+    merge_merge_request: Option<bool>,
+    /// Notify when a pipeline fails.
+    #[builder(default)]
+    failed_pipeline: Option<bool>,
+    /// Notify when a previously failing pipeline is fixed.
+    #[builder(default)]
+    fixed_pipeline: Option<bool>,
+    /// Notify when a pipeline succeeds.
+    #[builder(default)]
+    success_pipeline: Option<bool>,
+    /// Notify when a project is moved.
+    #[builder(default)]
+    moved_project: Option<bool>,
+    /// Notify when a merge request is set to merge when its pipeline succeeds.
+    #[builder(default)]
+    merge_when_pipeline_succeeds: Option<bool>,
+    /// Notify when a new epic is created.
+    #[builder(default)]
+    new_epic: Option<bool>,
+}
+
+impl NotificationEvents {
+    /// Create a builder for the events.
+    pub fn builder() -> NotificationEventsBuilder {
+        NotificationEventsBuilder::default()
+    }
+
+    pub(crate) fn add_params(&self, params: &mut FormParams) {
+        params
+            .push_opt("new_note", self.new_note)
+            .push_opt("new_issue", self.new_issue)
+            .push_opt("reopen_issue", self.reopen_issue)
+            .push_opt("close_issue", self.close_issue)
+            .push_opt("reassign_issue", self.reassign_issue)
+            .push_opt("issue_due", self.issue_due)
+            .push_opt("new_merge_request", self.new_merge_request)
+            .push_opt("push_to_merge_request", self.push_to_merge_request)
+            .push_opt("reopen_merge_request", self.reopen_merge_request)
+            .push_opt("close_merge_request", self.close_merge_request)
+            .push_opt("reassign_merge_request", self.reassign_merge_request)
+            .push_opt("merge_merge_request", self.merge_merge_request)
+            .push_opt("failed_pipeline", self.failed_pipeline)
+            .push_opt("fixed_pipeline", self.fixed_pipeline)
+            .push_opt("success_pipeline", self.success_pipeline)
+            .push_opt("moved_project", self.moved_project)
+            .push_opt(
+                "merge_when_pipeline_succeeds",
+                self.merge_when_pipeline_succeeds,
+            )
+            .push_opt("new_epic", self.new_epic);
+    }
+}
+
+/// Edit the global notification settings of the API calling user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EditNotificationSettings<'a> {
+    /// The notification level.
+    #[builder(default)]
+    level: Option<NotificationLevel>,
+    /// The email address to send notifications to.
+    #[builder(setter(into), default)]
+    notification_email: Option<Cow<'a, str>>,
+    /// The events to notify about.
+    #[builder(default)]
+    events: NotificationEvents,
+}
+
+impl<'a> EditNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditNotificationSettingsBuilder<'a> {
+        EditNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "notification_settings".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("level", self.level)
+            .push_opt("notification_email", self.notification_email.as_ref());
+        self.events.add_params(&mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::notification_settings::{
+        EditNotificationSettings, NotificationEvents, NotificationLevel,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn notification_level_as_str() {
+        let items = &[
+            (NotificationLevel::Disabled, "disabled"),
+            (NotificationLevel::Participating, "participating"),
+            (NotificationLevel::Watch, "watch"),
+            (NotificationLevel::Global, "global"),
+            (NotificationLevel::Mention, "mention"),
+            (NotificationLevel::Custom, "custom"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        EditNotificationSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("level=watch")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder()
+            .level(NotificationLevel::Watch)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_notification_email() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("notification_email=user%40example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder()
+            .notification_email("user@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "level=custom",
+                "&new_note=true",
+                "&new_issue=false",
+                "&reopen_issue=true",
+                "&close_issue=false",
+                "&reassign_issue=true",
+                "&issue_due=false",
+                "&new_merge_request=true",
+                "&push_to_merge_request=false",
+                "&reopen_merge_request=true",
+                "&close_merge_request=false",
+                "&reassign_merge_request=true",
+                "&merge_merge_request=false",
+                "&failed_pipeline=true",
+                "&fixed_pipeline=false",
+                "&success_pipeline=true",
+                "&moved_project=false",
+                "&merge_when_pipeline_succeeds=true",
+                "&new_epic=false",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditNotificationSettings::builder()
+            .level(NotificationLevel::Custom)
+            .events(
+                NotificationEvents::builder()
+                    .new_note(true)
+                    .new_issue(false)
+                    .reopen_issue(true)
+                    .close_issue(false)
+                    .reassign_issue(true)
+                    .issue_due(false)
+                    .new_merge_request(true)
+                    .push_to_merge_request(false)
+                    .reopen_merge_request(true)
+                    .close_merge_request(false)
+                    .reassign_merge_request(true)
+                    .merge_merge_request(false)
+                    .failed_pipeline(true)
+                    .fixed_pipeline(false)
+                    .success_pipeline(true)
+                    .moved_project(false)
+                    .merge_when_pipeline_succeeds(true)
+                    .new_epic(false)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
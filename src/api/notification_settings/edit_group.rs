@@ -0,0 +1,168 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::notification_settings::{NotificationEvents, NotificationLevel};
+
+/// Edit the notification settings of the API calling user for a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditGroupNotificationSettings<'a> {
+    /// The group to edit the notification settings for.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// The notification level.
+    #[builder(default)]
+    level: Option<NotificationLevel>,
+    /// The events to notify about.
+    #[builder(default)]
+    events: NotificationEvents,
+}
+
+impl<'a> EditGroupNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupNotificationSettingsBuilder<'a> {
+        EditGroupNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditGroupNotificationSettingsBuilder<'a> {
+    fn validate(&self) -> Result<(), EditGroupNotificationSettingsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditGroupNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/notification_settings", self.group.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("level", self.level);
+        self.events.add_params(&mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::notification_settings::{
+        EditGroupNotificationSettings, EditGroupNotificationSettingsBuilderError,
+        NotificationEvents, NotificationLevel,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupNotificationSettings::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupNotificationSettingsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        EditGroupNotificationSettings::builder()
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = EditGroupNotificationSettings::builder()
+            .group(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditGroupNotificationSettingsBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupNotificationSettings::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("level=mention")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupNotificationSettings::builder()
+            .group("group/subgroup")
+            .level(NotificationLevel::Mention)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/group%2Fsubgroup/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "level=custom",
+                "&failed_pipeline=true",
+                "&success_pipeline=false",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupNotificationSettings::builder()
+            .group("group/subgroup")
+            .level(NotificationLevel::Custom)
+            .events(
+                NotificationEvents::builder()
+                    .failed_pipeline(true)
+                    .success_pipeline(false)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
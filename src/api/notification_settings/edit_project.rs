@@ -0,0 +1,176 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::notification_settings::{NotificationEvents, NotificationLevel};
+
+/// Edit the notification settings of the API calling user for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditProjectNotificationSettings<'a> {
+    /// The project to edit the notification settings for.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The notification level.
+    #[builder(default)]
+    level: Option<NotificationLevel>,
+    /// The events to notify about.
+    #[builder(default)]
+    events: NotificationEvents,
+}
+
+impl<'a> EditProjectNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditProjectNotificationSettingsBuilder<'a> {
+        EditProjectNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditProjectNotificationSettingsBuilder<'a> {
+    fn validate(&self) -> Result<(), EditProjectNotificationSettingsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditProjectNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/notification_settings",
+            self.project.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("level", self.level);
+        self.events.add_params(&mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::notification_settings::{
+        EditProjectNotificationSettings, EditProjectNotificationSettingsBuilderError,
+        NotificationEvents, NotificationLevel,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditProjectNotificationSettings::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditProjectNotificationSettingsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        EditProjectNotificationSettings::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditProjectNotificationSettings::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditProjectNotificationSettingsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectNotificationSettings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("level=mention")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectNotificationSettings::builder()
+            .project("simple/project")
+            .level(NotificationLevel::Mention)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/notification_settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "level=custom",
+                "&failed_pipeline=true",
+                "&success_pipeline=false",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectNotificationSettings::builder()
+            .project("simple/project")
+            .level(NotificationLevel::Custom)
+            .events(
+                NotificationEvents::builder()
+                    .failed_pipeline(true)
+                    .success_pipeline(false)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
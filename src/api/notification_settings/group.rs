@@ -0,0 +1,95 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query the notification settings of the API calling user for a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupNotificationSettings<'a> {
+    /// The group to query.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupNotificationSettingsBuilder<'a> {
+        GroupNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> GroupNotificationSettingsBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupNotificationSettingsBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/notification_settings", self.group.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::notification_settings::{
+        GroupNotificationSettings, GroupNotificationSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupNotificationSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupNotificationSettingsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupNotificationSettings::builder()
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupNotificationSettings::builder()
+            .group(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            GroupNotificationSettingsBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/notification_settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupNotificationSettings::builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query the notification settings of the API calling user for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectNotificationSettings<'a> {
+    /// The project to query.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectNotificationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectNotificationSettingsBuilder<'a> {
+        ProjectNotificationSettingsBuilder::default()
+    }
+}
+
+impl<'a> ProjectNotificationSettingsBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectNotificationSettingsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectNotificationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/notification_settings",
+            self.project.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::notification_settings::{
+        ProjectNotificationSettings, ProjectNotificationSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectNotificationSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectNotificationSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectNotificationSettings::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectNotificationSettings::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            ProjectNotificationSettingsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/notification_settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectNotificationSettings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
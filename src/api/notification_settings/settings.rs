@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query the global notification settings of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct NotificationSettings {}
+
+impl NotificationSettings {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> NotificationSettingsBuilder {
+        NotificationSettingsBuilder::default()
+    }
+}
+
+impl Endpoint for NotificationSettings {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "notification_settings".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::notification_settings::NotificationSettings;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        NotificationSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("notification_settings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = NotificationSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
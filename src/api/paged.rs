@@ -4,6 +4,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::api::instance::Feature;
+use crate::api::{ApiError, AsyncClient, Client};
+
 mod link_header;
 mod pagination;
 
@@ -16,6 +19,13 @@ pub trait Pageable {
     fn use_keyset_pagination(&self) -> bool {
         false
     }
+
+    /// The feature an instance must support for the endpoint to use keyset pagination.
+    ///
+    /// Page-based pagination is used instead on instances which are known to lack the feature.
+    fn keyset_pagination_feature(&self) -> Option<Feature> {
+        None
+    }
 }
 
 impl<E> Pageable for &E
@@ -25,6 +35,37 @@ where
     fn use_keyset_pagination(&self) -> bool {
         (*self).use_keyset_pagination()
     }
+
+    fn keyset_pagination_feature(&self) -> Option<Feature> {
+        (*self).keyset_pagination_feature()
+    }
+}
+
+/// Whether the instance supports keyset pagination for an endpoint.
+fn keyset_pagination_supported<E, C>(endpoint: &E, client: &C) -> Result<bool, ApiError<C::Error>>
+where
+    E: Pageable,
+    C: Client,
+{
+    match (endpoint.keyset_pagination_feature(), client.capabilities()) {
+        (Some(feature), Some(capabilities)) => capabilities.supports(client, feature),
+        _ => Ok(true),
+    }
+}
+
+/// Whether the instance supports keyset pagination for an endpoint, asynchronously.
+async fn keyset_pagination_supported_async<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<bool, ApiError<C::Error>>
+where
+    E: Pageable,
+    C: AsyncClient + Sync,
+{
+    match (endpoint.keyset_pagination_feature(), client.capabilities()) {
+        (Some(feature), Some(capabilities)) => capabilities.supports_async(client, feature).await,
+        _ => Ok(true),
+    }
 }
 
 pub use self::link_header::LinkHeaderParseError;
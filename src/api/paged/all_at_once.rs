@@ -10,9 +10,9 @@ use async_trait::async_trait;
 use http::{header, Request};
 use serde::de::DeserializeOwned;
 
-use crate::api::paged::link_header;
+use crate::api::paged::{self, link_header};
 use crate::api::{
-    query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination, Query,
+    query, trace, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination, Query,
 };
 
 /// A query modifier that paginates an endpoint.
@@ -65,14 +65,18 @@ where
         let per_page_str = per_page.to_string();
 
         let results = Arc::new(Mutex::new(Vec::new()));
-        let mut next_url = None;
-        let use_keyset_pagination = self.endpoint.use_keyset_pagination();
+        let mut next_url: Option<url::Url> = None;
+        let use_keyset_pagination = self.endpoint.use_keyset_pagination()
+            && paged::keyset_pagination_supported_async(&self.endpoint, client).await?;
 
         let body = self.endpoint.body()?;
 
         loop {
-            let page_url = if let Some(url) = next_url.take() {
-                url
+            let page_url = if let Some(next_url) = next_url.take() {
+                // Only follow the query of the link so that the client's base URL is used.
+                let mut page_url = url.clone();
+                page_url.set_query(next_url.query());
+                page_url
             } else {
                 let page_str = page_num.to_string();
                 let mut page_url = url.clone();
@@ -100,7 +104,7 @@ where
             } else {
                 (req, Vec::new())
             };
-            let rsp = client.rest_async(req, data).await?;
+            let rsp = trace::request_async(req, |req| client.rest_async(req, data)).await?;
             let status = rsp.status();
 
             if use_keyset_pagination {
@@ -131,7 +135,9 @@ where
             let is_last_page = {
                 let mut locked_results = results.lock().expect("poisoned results");
                 locked_results.extend(page);
-                self.pagination.is_last_page(page_len, locked_results.len())
+                let is_last_page = self.pagination.is_last_page(page_len, locked_results.len());
+                trace::page(page_len, locked_results.len(), is_last_page);
+                is_last_page
             };
             if is_last_page {
                 break;
@@ -16,9 +16,9 @@ use query::AsyncQuery;
 use serde::de::DeserializeOwned;
 use url::Url;
 
-use crate::api::paged::link_header;
+use crate::api::paged::{self, link_header};
 use crate::api::{
-    query, ApiError, AsyncClient, Client, Endpoint, Pageable, Paged, Query, RestClient,
+    query, trace, ApiError, AsyncClient, Client, Endpoint, Pageable, Paged, Query, RestClient,
 };
 
 impl<E> Paged<E>
@@ -187,17 +187,36 @@ impl<E> LazilyPagedState<E> {
         // if it is needed, the bug manifests as Gitlab returning *all* results instead of just the
         // requested results. This can cause an infinite loop here if the number of total results
         // is exactly equal to `per_page`.
-        if self
+        let is_last_page = self
             .paged
             .pagination
-            .is_last_page(last_page_size, page_state.total_results)
-        {
+            .is_last_page(last_page_size, page_state.total_results);
+        trace::page(last_page_size, page_state.total_results, is_last_page);
+        if is_last_page {
             page_state.next_page = Page::Done;
         } else {
             page_state.next_page.next_page(next_url);
         }
     }
 
+    /// Whether the first page is yet to be fetched using keyset pagination.
+    fn is_first_keyset_page(&self) -> bool {
+        let page_state = self.page_state.read().expect("poisoned next_page");
+        page_state.next_page == Page::Keyset(KeysetPage::First)
+    }
+
+    /// Whether pages are being fetched using keyset pagination.
+    fn is_keyset(&self) -> bool {
+        let page_state = self.page_state.read().expect("poisoned next_page");
+        matches!(page_state.next_page, Page::Keyset(_))
+    }
+
+    /// Use page-based pagination because the instance does not support keyset pagination.
+    fn use_page_numbers(&self) {
+        let mut page_state = self.page_state.write().expect("poisoned next_page");
+        page_state.next_page = Page::Number(1);
+    }
+
     /// Jumps to the [`page_num`] page, if possible.
     fn set_page(&self, page_num: NonZeroU64) {
         let mut page_state = self.page_state.write().expect("poisoned next_page");
@@ -222,14 +241,17 @@ where
             return Ok(None);
         }
 
-        let url = if let Some(next_url) = next_page.next_url() {
-            next_url.clone()
+        let mut url = self
+            .paged
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.paged.endpoint.endpoint())?;
+
+        if let Some(next_url) = next_page.next_url() {
+            // Only follow the query of the link so that the client's base URL is used. Instances
+            // behind proxies may not know the URL they are being accessed through.
+            url.set_query(next_url.query());
         } else {
-            let mut url = self
-                .paged
-                .endpoint
-                .url_base()
-                .endpoint_for(client, &self.paged.endpoint.endpoint())?;
             self.paged.endpoint.parameters().add_to_url(&mut url);
 
             let per_page = self.paged.pagination.page_limit();
@@ -241,9 +263,7 @@ where
 
                 next_page.apply_to(&mut pairs);
             }
-
-            url
-        };
+        }
 
         Ok(Some(url))
     }
@@ -273,7 +293,7 @@ where
     {
         let status = rsp.status();
 
-        let next_url = if self.paged.endpoint.use_keyset_pagination() {
+        let next_url = if self.is_keyset() {
             link_header::next_page_from_headers(rsp.headers())?
         } else {
             None
@@ -308,6 +328,12 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        if self.is_first_keyset_page()
+            && !paged::keyset_pagination_supported(&self.paged.endpoint, client)?
+        {
+            self.use_page_numbers();
+        }
+
         let url = if let Some(url) = self.page_url(client)? {
             url
         } else {
@@ -316,7 +342,7 @@ where
             return Ok(Vec::new());
         };
         let (req, data) = self.build_request::<C>(url)?;
-        let rsp = client.rest(req, data)?;
+        let rsp = trace::request(req, |req| client.rest(req, data))?;
         self.process_response::<C, _>(rsp)
     }
 }
@@ -329,6 +355,12 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        if self.is_first_keyset_page()
+            && !paged::keyset_pagination_supported_async(&self.paged.endpoint, client).await?
+        {
+            self.use_page_numbers();
+        }
+
         let url = if let Some(url) = self.page_url(client)? {
             url
         } else {
@@ -337,7 +369,7 @@ where
             return Ok(Vec::new());
         };
         let (req, data) = self.build_request::<C>(url)?;
-        let rsp = client.rest_async(req, data).await?;
+        let rsp = trace::request_async(req, |req| client.rest_async(req, data)).await?;
         self.process_response::<C, _>(rsp)
     }
 }
@@ -439,13 +471,14 @@ mod tests {
     use std::num::NonZeroU64;
 
     use futures_util::TryStreamExt;
-    use http::StatusCode;
+    use http::{header, HeaderValue, StatusCode};
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
     use crate::api::endpoint_prelude::*;
-    use crate::api::{self, ApiError, Pagination};
+    use crate::api::{self, ApiError, AsyncQuery, Pagination};
     use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
 
     #[derive(Debug, Default)]
     struct Dummy {
@@ -473,6 +506,64 @@ mod tests {
         value: u8,
     }
 
+    fn keyset_link_client() -> MockClient {
+        let first_page: Vec<_> = (0..100).map(|_| json!({"value": 1})).collect();
+        let client = MockClient::new();
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("paged_dummy")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&first_page).header(
+                    header::LINK,
+                    HeaderValue::from_static(
+                        "<https://internal.invalid/internal/api/v4/paged_dummy?pagination=keyset&per_page=100&page=2>; rel=\"next\"",
+                    ),
+                ),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("paged_dummy")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!([{"value": 2}])),
+            );
+        client
+    }
+
+    #[test]
+    fn test_keyset_link_uses_client_url() {
+        let client = keyset_link_client();
+        let endpoint = Dummy {
+            with_keyset: true,
+        };
+
+        let res: Vec<DummyResult> = api::paged(endpoint, Pagination::All)
+            .iter(&client)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(res.len(), 101);
+        assert_eq!(res[100].value, 2);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn test_keyset_link_uses_client_url_async() {
+        let client = keyset_link_client();
+        let endpoint = Dummy {
+            with_keyset: true,
+        };
+
+        let res: Vec<DummyResult> = api::paged(endpoint, Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 101);
+        assert_eq!(res[100].value, 2);
+        client.assert_all_consumed();
+    }
+
     #[test]
     fn test_gitlab_non_json_response() {
         let endpoint = ExpectedUrl::builder()
@@ -10,12 +10,14 @@
 //! [`Endpoint`](../trait.Endpoint.html) trait.
 
 use std::borrow::Cow;
+use std::fmt;
+use std::io::Write;
 
 use chrono::{DateTime, NaiveDate, Utc};
-use serde_json::Value;
+use serde_json::{Map, Value};
 use url::Url;
 
-use crate::api::BodyError;
+use crate::api::{BodyError, SpooledBody};
 
 /// A trait representing a parameter value.
 pub trait ParamValue<'a> {
@@ -89,6 +91,79 @@ impl ParamValue<'static> for NaiveDate {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParamNameSegment<'a> {
+    /// A member of an array (`[]`).
+    Array,
+    /// A member of a hash (`[key]`).
+    Key(Cow<'a, str>),
+}
+
+/// The name of a structured parameter.
+///
+/// GitLab follows Rails' conventions for nested parameters: array members are indicated by `[]`
+/// and hash members by `[key]`. These may be nested, e.g., `actions[][file_path]` is the
+/// `file_path` of an entry in the `actions` array.
+///
+/// Parameter names may be used as keys for [`FormParams`], [`QueryParams`], and
+/// [`MultipartParams`] and to build up values with [`JsonParams::insert`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamName<'a> {
+    name: Cow<'a, str>,
+    segments: Vec<ParamNameSegment<'a>>,
+}
+
+impl<'a> ParamName<'a> {
+    /// Create a new parameter name.
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: name.into(),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Refer to a member of the array named so far.
+    pub fn array(mut self) -> Self {
+        self.segments.push(ParamNameSegment::Array);
+        self
+    }
+
+    /// Refer to a member of the hash named so far.
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        K: Into<Cow<'a, str>>,
+    {
+        self.segments.push(ParamNameSegment::Key(key.into()));
+        self
+    }
+}
+
+impl<'a> fmt::Display for ParamName<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        for segment in &self.segments {
+            match segment {
+                ParamNameSegment::Array => write!(f, "[]")?,
+                ParamNameSegment::Key(key) => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> From<ParamName<'a>> for Cow<'a, str> {
+    fn from(name: ParamName<'a>) -> Self {
+        if name.segments.is_empty() {
+            name.name
+        } else {
+            name.to_string().into()
+        }
+    }
+}
+
 /// A structure for form parameters.
 #[derive(Debug, Default, Clone)]
 pub struct FormParams<'a> {
@@ -143,6 +218,145 @@ impl<'a> FormParams<'a> {
     }
 }
 
+/// The boundary between parts of multipart form data.
+const MULTIPART_BOUNDARY: &str = "gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr";
+/// The content type of multipart form data using `MULTIPART_BOUNDARY`.
+const MULTIPART_CONTENT_TYPE: &str =
+    "multipart/form-data; boundary=gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr";
+
+#[derive(Debug, Clone)]
+struct MultipartPart<'a> {
+    name: Cow<'a, str>,
+    filename: Option<Cow<'a, str>>,
+    data: Cow<'a, [u8]>,
+}
+
+/// A structure for multipart form parameters.
+///
+/// This is required by endpoints which upload files.
+#[derive(Debug, Default, Clone)]
+pub struct MultipartParams<'a> {
+    parts: Vec<MultipartPart<'a>>,
+}
+
+fn escape_multipart_name(name: &str) -> Cow<str> {
+    if name.contains(['"', '\r', '\n'].as_ref()) {
+        name.replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+            .into()
+    } else {
+        name.into()
+    }
+}
+
+fn contains_subslice(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+impl<'a> MultipartParams<'a> {
+    /// Push a single parameter.
+    pub fn push<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        let data = match value.as_value() {
+            Cow::Borrowed(value) => Cow::Borrowed(value.as_bytes()),
+            Cow::Owned(value) => Cow::Owned(value.into_bytes()),
+        };
+        self.parts.push(MultipartPart {
+            name: key.into(),
+            filename: None,
+            data,
+        });
+        self
+    }
+
+    /// Push a single parameter.
+    pub fn push_opt<'b, K, V>(&mut self, key: K, value: Option<V>) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        if let Some(value) = value {
+            self.push(key, value);
+        }
+        self
+    }
+
+    /// Push a file.
+    pub fn push_file<K, F, D>(&mut self, key: K, filename: F, data: D) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, [u8]>>,
+    {
+        self.parts.push(MultipartPart {
+            name: key.into(),
+            filename: Some(filename.into()),
+            data: data.into(),
+        });
+        self
+    }
+
+    /// Encode the parameters into a request body.
+    pub fn into_body(self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut body = Vec::new();
+        self.write_body(&mut body)?;
+        Ok(Some((MULTIPART_CONTENT_TYPE, body)))
+    }
+
+    /// Encode the parameters into a request body which may be moved to disk.
+    pub fn into_spooled_body(self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        let mut body = SpooledBody::new();
+        self.write_body(&mut body)?;
+        Ok(Some((MULTIPART_CONTENT_TYPE, body)))
+    }
+
+    fn write_body<W>(self, mut body: W) -> Result<(), BodyError>
+    where
+        W: Write,
+    {
+        let delimiter = format!("--{}", MULTIPART_BOUNDARY);
+
+        for part in self.parts {
+            if contains_subslice(&part.data, delimiter.as_bytes()) {
+                return Err(BodyError::MultipartBoundary {});
+            }
+
+            body.write_all(delimiter.as_bytes())?;
+            body.write_all(b"\r\n")?;
+            let disposition = if let Some(filename) = part.filename {
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+                     Content-Type: application/octet-stream\r\n",
+                    escape_multipart_name(&part.name),
+                    escape_multipart_name(&filename),
+                )
+            } else {
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n",
+                    escape_multipart_name(&part.name),
+                )
+            };
+            body.write_all(disposition.as_bytes())?;
+            body.write_all(b"\r\n")?;
+            body.write_all(&part.data)?;
+            body.write_all(b"\r\n")?;
+        }
+
+        body.write_all(delimiter.as_bytes())?;
+        body.write_all(b"--\r\n")?;
+
+        Ok(())
+    }
+}
+
 /// A structure for JSON parameters.
 #[derive(Debug, Default, Clone)]
 #[non_exhaustive]
@@ -164,6 +378,20 @@ impl JsonParams {
         val
     }
 
+    /// Insert a value into a JSON object at the location given by a parameter name.
+    ///
+    /// Hash members create (or reuse) nested objects and array members append to arrays. As with
+    /// form parameters, an array member with further segments adds to the last element of the
+    /// array unless that element already has a value at that location.
+    pub fn insert<V>(target: &mut Value, name: &ParamName, value: V)
+    where
+        V: Into<Value>,
+    {
+        let object = object_mut(target);
+        let slot = object.entry(name.name.as_ref()).or_insert(Value::Null);
+        insert_json(slot, &name.segments, value.into());
+    }
+
     /// Encode the parameters into a request body.
     pub fn into_body(input: &Value) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         let body = serde_json::to_string(input)?;
@@ -171,6 +399,64 @@ impl JsonParams {
     }
 }
 
+fn object_mut(value: &mut Value) -> &mut Map<String, Value> {
+    if !value.is_object() {
+        *value = Value::Object(Map::new());
+    }
+    match value {
+        Value::Object(object) => object,
+        _ => unreachable!(),
+    }
+}
+
+fn array_mut(value: &mut Value) -> &mut Vec<Value> {
+    if !value.is_array() {
+        *value = Value::Array(Vec::new());
+    }
+    match value {
+        Value::Array(array) => array,
+        _ => unreachable!(),
+    }
+}
+
+fn has_json_path(value: &Value, segments: &[ParamNameSegment]) -> bool {
+    match segments.split_first() {
+        None => true,
+        Some((ParamNameSegment::Key(key), rest)) => {
+            value
+                .get(key.as_ref())
+                .map(|child| has_json_path(child, rest))
+                .unwrap_or(false)
+        },
+        // Arrays may always be appended to.
+        Some((ParamNameSegment::Array, _)) => false,
+    }
+}
+
+fn insert_json(slot: &mut Value, segments: &[ParamNameSegment], value: Value) {
+    match segments.split_first() {
+        None => *slot = value,
+        Some((ParamNameSegment::Key(key), rest)) => {
+            let child = object_mut(slot).entry(key.as_ref()).or_insert(Value::Null);
+            insert_json(child, rest, value);
+        },
+        Some((ParamNameSegment::Array, rest)) => {
+            let array = array_mut(slot);
+            let reuse_last = !rest.is_empty()
+                && array
+                    .last()
+                    .map(|last| last.is_object() && !has_json_path(last, rest))
+                    .unwrap_or(false);
+            if !reuse_last {
+                array.push(Value::Null);
+            }
+            if let Some(last) = array.last_mut() {
+                insert_json(last, rest, value);
+            }
+        },
+    }
+}
+
 /// A structure for query parameters.
 #[derive(Debug, Default, Clone)]
 pub struct QueryParams<'a> {
@@ -224,9 +510,12 @@ impl<'a> QueryParams<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
+    use chrono::{NaiveDate, TimeZone, Utc};
     use serde_json::json;
 
-    use crate::api::{JsonParams, ParamValue};
+    use crate::api::{BodyError, FormParams, JsonParams, MultipartParams, ParamName, ParamValue};
 
     #[test]
     fn bool_str() {
@@ -237,6 +526,120 @@ mod tests {
         }
     }
 
+    #[test]
+    fn datetime_str() {
+        let items = &[
+            (
+                Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+                "2024-01-02T03:04:05Z",
+            ),
+            (
+                Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()
+                    + chrono::Duration::milliseconds(678),
+                "2024-01-02T03:04:05Z",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_value(), *s);
+        }
+    }
+
+    #[test]
+    fn naive_date_str() {
+        let items = &[
+            (NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), "2024-01-02"),
+            (NaiveDate::from_ymd_opt(824, 11, 30).unwrap(), "0824-11-30"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_value(), *s);
+        }
+    }
+
+    #[test]
+    fn param_name_str() {
+        let items = &[
+            (ParamName::new("name"), "name"),
+            (ParamName::new("name").array(), "name[]"),
+            (ParamName::new("name").key("key"), "name[key]"),
+            (
+                ParamName::new("actions").array().key("file_path"),
+                "actions[][file_path]",
+            ),
+            (
+                ParamName::new("defaults").key("allowed").array(),
+                "defaults[allowed][]",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.to_string(), *s);
+            assert_eq!(Cow::from(i.clone()), *s);
+        }
+    }
+
+    #[test]
+    fn param_name_form_params() {
+        let mut params = FormParams::default();
+        params
+            .push(ParamName::new("name").key("a b"), "value")
+            .extend(
+                [1, 2]
+                    .iter()
+                    .map(|&value| (ParamName::new("ids").array(), value)),
+            );
+
+        let (_, body) = params.into_body().unwrap().unwrap();
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "name%5Ba+b%5D=value&ids%5B%5D=1&ids%5B%5D=2",
+        );
+    }
+
+    #[test]
+    fn json_params_insert() {
+        let mut value = json!({});
+        let key = ParamName::new("actions").array().key("key");
+        let value_name = ParamName::new("actions").array().key("value");
+        JsonParams::insert(&mut value, &ParamName::new("name"), "top");
+        JsonParams::insert(&mut value, &key, "k1");
+        JsonParams::insert(&mut value, &value_name, "v1");
+        JsonParams::insert(&mut value, &key, "k2");
+        JsonParams::insert(&mut value, &ParamName::new("ids").array(), 1);
+        JsonParams::insert(&mut value, &ParamName::new("ids").array(), 2);
+        JsonParams::insert(
+            &mut value,
+            &ParamName::new("defaults").key("allowed").array(),
+            30,
+        );
+        JsonParams::insert(&mut value, &ParamName::new("defaults").key("force"), true);
+
+        assert_eq!(
+            value,
+            json!({
+                "name": "top",
+                "actions": [
+                    {"key": "k1", "value": "v1"},
+                    {"key": "k2"},
+                ],
+                "ids": [1, 2],
+                "defaults": {
+                    "allowed": [30],
+                    "force": true,
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn json_params_insert_non_object() {
+        let mut value = json!(null);
+        JsonParams::insert(&mut value, &ParamName::new("name").key("key"), "value");
+
+        assert_eq!(value, json!({"name": {"key": "value"}}));
+    }
+
     #[test]
     fn json_params_clean() {
         let dirty = json!({
@@ -265,4 +668,77 @@ mod tests {
 
         assert_eq!(JsonParams::clean(dirty), clean);
     }
+
+    #[test]
+    fn multipart_content_type() {
+        assert_eq!(
+            super::MULTIPART_CONTENT_TYPE,
+            format!(
+                "multipart/form-data; boundary={}",
+                super::MULTIPART_BOUNDARY
+            ),
+        );
+    }
+
+    #[test]
+    fn multipart_params() {
+        let mut params = MultipartParams::default();
+        params
+            .push("branch", "main")
+            .push_opt("count", Some(1))
+            .push_opt::<_, bool>("skipped", None)
+            .push_file("file", "a \"quoted\"\nname.txt", &b"contents"[..]);
+
+        let (content_type, body) = params.into_body().unwrap().unwrap();
+        assert_eq!(content_type, super::MULTIPART_CONTENT_TYPE);
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            concat!(
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"branch\"\r\n",
+                "\r\n",
+                "main\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"count\"\r\n",
+                "\r\n",
+                "1\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"file\"; ",
+                "filename=\"a %22quoted%22%0Aname.txt\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "contents\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr--\r\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn multipart_params_boundary_in_data() {
+        let mut params = MultipartParams::default();
+        params.push_file(
+            "file",
+            "file.txt",
+            &b"a\r\n--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n"[..],
+        );
+
+        let err = params.into_body().unwrap_err();
+        if let BodyError::MultipartBoundary {} = err {
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn multipart_params_spooled() {
+        let mut params = MultipartParams::default();
+        params
+            .push("branch", "main")
+            .push_file("file", "file.txt", &b"contents"[..]);
+        let (_, expected) = params.clone().into_body().unwrap().unwrap();
+
+        let (content_type, body) = params.into_spooled_body().unwrap().unwrap();
+        assert_eq!(content_type, super::MULTIPART_CONTENT_TYPE);
+        assert_eq!(body.into_vec().unwrap(), expected);
+    }
 }
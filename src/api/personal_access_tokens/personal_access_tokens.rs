@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use derive_builder::Builder;
 
 use crate::api::common::NameOrId;
@@ -53,6 +53,12 @@ pub struct PersonalAccessTokens<'a> {
     /// Limit results to personal access tokens last used before a time.
     #[builder(default)]
     last_used_before: Option<DateTime<Utc>>,
+    /// Limit results to personal access tokens which expire after a date.
+    #[builder(default)]
+    expires_after: Option<NaiveDate>,
+    /// Limit results to personal access tokens which expire before a date.
+    #[builder(default)]
+    expires_before: Option<NaiveDate>,
 
     /// Limit results to personal access tokens with the given revocation state.
     #[builder(default)]
@@ -94,6 +100,8 @@ impl<'a> Endpoint for PersonalAccessTokens<'a> {
             .push_opt("created_before", self.created_before)
             .push_opt("last_used_after", self.last_used_after)
             .push_opt("last_used_before", self.last_used_before)
+            .push_opt("expires_after", self.expires_after)
+            .push_opt("expires_before", self.expires_before)
             .push_opt("revoked", self.revoked)
             .push_opt("search", self.search.as_ref())
             .push_opt("state", self.state)
@@ -107,7 +115,7 @@ impl<'a> Pageable for PersonalAccessTokens<'a> {}
 
 #[cfg(test)]
 mod tests {
-    use chrono::{TimeZone, Utc};
+    use chrono::{NaiveDate, TimeZone, Utc};
     use http::Method;
 
     use crate::api::personal_access_tokens::{PersonalAccessTokenState, PersonalAccessTokens};
@@ -212,6 +220,40 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_expires_after() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("expires_after", "2020-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .expires_after(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_before() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("expires_before", "2020-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .expires_before(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_revoked() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,541 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Clients for multiple GitLab instances.
+//!
+//! Tools which mirror data between instances need a client for each of them. A `GitlabPool`
+//! holds named clients which share the same retry configuration and keeps request statistics for
+//! each instance. The `copy_*` functions copy objects from a source client to a destination
+//! client.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bytes::Bytes;
+use http::Response;
+use serde::Deserialize;
+use url::Url;
+
+use crate::api::common::NameOrId;
+use crate::api::instance::Capabilities;
+use crate::api::projects::issues::{CreateIssue, Issue};
+use crate::api::projects::labels::{CreateLabel, Labels};
+use crate::api::retry::{self, Backoff};
+use crate::api::{self, ApiError, Client, Pagination, Query, RestClient};
+
+/// Request statistics for an instance in a pool.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InstanceStats {
+    /// The number of requests made.
+    pub requests: u64,
+    /// The number of requests which failed or returned an error status.
+    ///
+    /// Retried requests are counted once.
+    pub failures: u64,
+}
+
+struct PoolEntry<C> {
+    client: retry::Client<C>,
+    requests: AtomicU64,
+    failures: AtomicU64,
+}
+
+/// A set of named clients for multiple GitLab instances.
+///
+/// All clients share the same exponential backoff parameters.
+pub struct GitlabPool<C> {
+    backoff: Backoff,
+    clients: BTreeMap<String, PoolEntry<C>>,
+}
+
+impl<C> GitlabPool<C> {
+    /// Create an empty pool using the default backoff parameters.
+    pub fn new() -> Self {
+        Self::with_backoff(Backoff::default())
+    }
+
+    /// Create an empty pool with the given backoff parameters.
+    pub fn with_backoff(backoff: Backoff) -> Self {
+        Self {
+            backoff,
+            clients: BTreeMap::new(),
+        }
+    }
+
+    /// Add a client for an instance to the pool.
+    ///
+    /// Any existing client with the same name is replaced and its statistics are reset.
+    pub fn insert<N>(&mut self, name: N, client: C)
+    where
+        N: Into<String>,
+    {
+        self.clients.insert(
+            name.into(),
+            PoolEntry {
+                client: retry::Client::new(client, self.backoff.clone()),
+                requests: AtomicU64::new(0),
+                failures: AtomicU64::new(0),
+            },
+        );
+    }
+
+    /// Remove the client for an instance from the pool.
+    ///
+    /// Returns whether the instance was in the pool.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.clients.remove(name).is_some()
+    }
+
+    /// The names of the instances in the pool.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(AsRef::as_ref)
+    }
+
+    /// Get the client for an instance.
+    pub fn client(&self, name: &str) -> Option<PooledClient<C>> {
+        self.clients.get(name).map(|entry| {
+            PooledClient {
+                entry,
+            }
+        })
+    }
+
+    /// Get the request statistics for an instance.
+    pub fn stats(&self, name: &str) -> Option<InstanceStats> {
+        self.clients.get(name).map(|entry| {
+            InstanceStats {
+                requests: entry.requests.load(Ordering::Relaxed),
+                failures: entry.failures.load(Ordering::Relaxed),
+            }
+        })
+    }
+}
+
+impl<C> Default for GitlabPool<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A client for an instance within a pool.
+///
+/// Requests are retried according to the pool's backoff parameters and counted in the
+/// instance's statistics.
+pub struct PooledClient<'a, C> {
+    entry: &'a PoolEntry<C>,
+}
+
+impl<'a, C> RestClient for PooledClient<'a, C>
+where
+    C: RestClient,
+{
+    type Error = retry::Error<C::Error>;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.entry.client.rest_endpoint(endpoint)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.entry.client.instance_endpoint(endpoint)
+    }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        self.entry.client.capabilities()
+    }
+}
+
+impl<'a, C> Client for PooledClient<'a, C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.record(self.entry.client.rest(request, body))
+    }
+
+    fn rest_spooled(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.record(self.entry.client.rest_spooled(request, body))
+    }
+}
+
+impl<'a, C> PooledClient<'a, C>
+where
+    C: RestClient,
+{
+    /// Count a request and whether it failed in the instance's statistics.
+    fn record(
+        &self,
+        rsp: Result<Response<Bytes>, ApiError<retry::Error<C::Error>>>,
+    ) -> Result<Response<Bytes>, ApiError<retry::Error<C::Error>>> {
+        self.entry.requests.fetch_add(1, Ordering::Relaxed);
+        let failed = rsp
+            .as_ref()
+            .map(|rsp| !rsp.status().is_success())
+            .unwrap_or(true);
+        if failed {
+            self.entry.failures.fetch_add(1, Ordering::Relaxed);
+        }
+        rsp
+    }
+}
+
+/// The fields of a label needed to copy it.
+#[derive(Debug, Deserialize)]
+struct LabelInfo {
+    name: String,
+    color: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    priority: Option<u64>,
+}
+
+/// The fields of an issue needed to copy it.
+#[derive(Debug, Deserialize)]
+struct IssueInfo {
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    confidential: bool,
+}
+
+/// The fields of a created issue.
+#[derive(Debug, Deserialize)]
+struct CreatedIssue {
+    iid: u64,
+}
+
+/// Copy the labels of a project to another project.
+///
+/// Only labels defined on the source project itself are copied. Labels which already exist in
+/// the destination project (including those inherited from its groups) are skipped.
+///
+/// Returns the names of the labels which were created.
+pub fn copy_labels<'a, 'b, S, D, SP, DP>(
+    source: &S,
+    source_project: SP,
+    destination: &D,
+    destination_project: DP,
+) -> Result<Vec<String>, ApiError<S::Error>>
+where
+    S: Client,
+    D: Client<Error = S::Error>,
+    SP: Into<NameOrId<'a>>,
+    DP: Into<NameOrId<'b>>,
+{
+    let destination_project = destination_project.into();
+
+    let source_labels = Labels::builder()
+        .project(source_project)
+        .include_ancestor_groups(false)
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+    let labels: Vec<LabelInfo> = api::paged(source_labels, Pagination::All).query(source)?;
+
+    let destination_labels = Labels::builder()
+        .project(destination_project.clone())
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+    let existing: Vec<LabelInfo> =
+        api::paged(destination_labels, Pagination::All).query(destination)?;
+    let existing = existing
+        .into_iter()
+        .map(|label| label.name)
+        .collect::<BTreeSet<_>>();
+
+    let mut created = Vec::new();
+    for label in labels {
+        if existing.contains(&label.name) {
+            continue;
+        }
+
+        let mut builder = CreateLabel::builder();
+        builder
+            .project(destination_project.clone())
+            .name(label.name.clone())
+            .color(label.color);
+        if let Some(description) = label.description {
+            builder.description(description);
+        }
+        if let Some(priority) = label.priority {
+            builder.priority(priority);
+        }
+        api::ignore(builder.build().map_err(ApiError::invalid_endpoint)?).query(destination)?;
+
+        created.push(label.name);
+    }
+
+    Ok(created)
+}
+
+/// Copy an issue to another project.
+///
+/// The title, description, labels, and confidentiality of the issue are copied. Labels which do
+/// not exist in the destination project are created by GitLab. Comments, assignees, and
+/// milestones are not copied since they refer to objects which are specific to the source
+/// instance.
+///
+/// Returns the IID of the new issue.
+pub fn copy_issue<'a, 'b, S, D, SP, DP>(
+    source: &S,
+    source_project: SP,
+    issue: u64,
+    destination: &D,
+    destination_project: DP,
+) -> Result<u64, ApiError<S::Error>>
+where
+    S: Client,
+    D: Client<Error = S::Error>,
+    SP: Into<NameOrId<'a>>,
+    DP: Into<NameOrId<'b>>,
+{
+    let endpoint = Issue::builder()
+        .project(source_project)
+        .issue(issue)
+        .build()
+        .map_err(ApiError::invalid_endpoint)?;
+    let info: IssueInfo = endpoint.query(source)?;
+
+    let mut builder = CreateIssue::builder();
+    builder
+        .project(destination_project)
+        .title(info.title)
+        .labels(info.labels.into_iter().map(Cow::Owned))
+        .confidential(info.confidential);
+    if let Some(description) = info.description {
+        builder.description(description);
+    }
+    let created: CreatedIssue = builder
+        .build()
+        .map_err(ApiError::invalid_endpoint)?
+        .query(destination)?;
+
+    Ok(created.iid)
+}
+
+#[cfg(all(test, feature = "api-users"))]
+mod tests {
+    use std::time::Duration;
+
+    use http::{Method, StatusCode};
+    use serde_json::json;
+
+    use crate::api::pool::{self, GitlabPool, InstanceStats};
+    use crate::api::retry::Backoff;
+    use crate::api::users::CurrentUser;
+    use crate::api::{self, ApiError, Query};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    fn backoff() -> Backoff {
+        Backoff::builder()
+            .limit(2)
+            .init(Duration::from_secs(0))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn pool_names() {
+        let mut pool = GitlabPool::new();
+        pool.insert("source", MockClient::new());
+        pool.insert("destination", MockClient::new());
+
+        assert_eq!(pool.names().collect::<Vec<_>>(), ["destination", "source"]);
+        assert!(pool.client("source").is_some());
+        assert!(pool.client("missing").is_none());
+        assert_eq!(pool.stats("source"), Some(InstanceStats::default()));
+        assert_eq!(pool.stats("missing"), None);
+
+        assert!(pool.remove("source"));
+        assert!(!pool.remove("source"));
+        assert_eq!(pool.names().collect::<Vec<_>>(), ["destination"]);
+    }
+
+    fn current_user() -> ExpectedRequest {
+        ExpectedRequest::builder().endpoint("user").build().unwrap()
+    }
+
+    #[test]
+    fn pool_stats() {
+        let client = MockClient::new();
+        client
+            .expect(current_user(), MockResponse::json(&json!({"id": 1})))
+            .expect(
+                current_user(),
+                MockResponse::json(&json!({"message": "404 Not Found"}))
+                    .status(StatusCode::NOT_FOUND),
+            );
+        let mut pool = GitlabPool::with_backoff(backoff());
+        pool.insert("instance", client);
+
+        let client = pool.client("instance").unwrap();
+        let endpoint = CurrentUser::builder().build().unwrap();
+        api::ignore(&endpoint).query(&client).unwrap();
+        let err = api::ignore(&endpoint).query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "404 Not Found");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        assert_eq!(
+            pool.stats("instance"),
+            Some(InstanceStats {
+                requests: 2,
+                failures: 1,
+            }),
+        );
+    }
+
+    #[test]
+    fn pool_retries() {
+        let client = MockClient::new();
+        client
+            .expect(
+                current_user(),
+                MockResponse::empty().status(StatusCode::SERVICE_UNAVAILABLE),
+            )
+            .expect(current_user(), MockResponse::json(&json!({"id": 1})));
+        let mut pool = GitlabPool::with_backoff(backoff());
+        pool.insert("instance", client);
+
+        let client = pool.client("instance").unwrap();
+        let endpoint = CurrentUser::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+
+        assert_eq!(
+            pool.stats("instance"),
+            Some(InstanceStats {
+                requests: 1,
+                failures: 0,
+            }),
+        );
+    }
+
+    #[test]
+    fn copy_labels() {
+        let source = MockClient::new();
+        source.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/source%2Fproject/labels")
+                .query_param("include_ancestor_groups", "false")
+                .build()
+                .unwrap(),
+            [
+                json!({"name": "bug", "color": "#ff0000", "description": "Broken", "priority": 1}),
+                json!({"name": "feature", "color": "#00ff00", "description": null, "priority": null}),
+            ],
+        );
+        let destination = MockClient::new();
+        destination
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("projects/dest%2Fproject/labels")
+                    .build()
+                    .unwrap(),
+                [json!({"name": "feature", "color": "#0000ff"})],
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::POST)
+                    .endpoint("projects/dest%2Fproject/labels")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str(concat!(
+                        "name=bug",
+                        "&color=%23ff0000",
+                        "&description=Broken",
+                        "&priority=1",
+                    ))
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"name": "bug"})),
+            );
+
+        let mut pool = GitlabPool::new();
+        pool.insert("source", source);
+        pool.insert("destination", destination);
+
+        let created = pool::copy_labels(
+            &pool.client("source").unwrap(),
+            "source/project",
+            &pool.client("destination").unwrap(),
+            "dest/project",
+        )
+        .unwrap();
+        assert_eq!(created, ["bug"]);
+    }
+
+    #[test]
+    fn copy_issue() {
+        let source = MockClient::new();
+        source.expect(
+            ExpectedRequest::builder()
+                .endpoint("projects/source%2Fproject/issues/5")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({
+                "iid": 5,
+                "title": "Crash on startup",
+                "description": "It crashes.",
+                "labels": ["bug", "critical"],
+                "confidential": true,
+            })),
+        );
+        let destination = MockClient::new();
+        destination.expect(
+            ExpectedRequest::builder()
+                .method(Method::POST)
+                .endpoint("projects/dest%2Fproject/issues")
+                .content_type("application/x-www-form-urlencoded")
+                .body_str(concat!(
+                    "title=Crash+on+startup",
+                    "&description=It+crashes.",
+                    "&confidential=true",
+                    "&labels=bug%2Ccritical",
+                ))
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({"iid": 12})),
+        );
+
+        let iid =
+            pool::copy_issue(&source, "source/project", 5, &destination, "dest/project").unwrap();
+        assert_eq!(iid, 12);
+        source.assert_all_consumed();
+        destination.assert_all_consumed();
+    }
+
+    #[test]
+    fn copy_issue_invalid_issue() {
+        let source = MockClient::new();
+        let destination = MockClient::new();
+
+        let err = pool::copy_issue(&source, "source/project", 0, &destination, "dest/project")
+            .unwrap_err();
+        if let ApiError::InvalidEndpoint {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "invalid `issue`: IDs must not be zero");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! API prelude
+//!
+//! This module re-exports the traits and functions commonly needed to make API calls, including
+//! the [`ResourceHandles`](../handles/trait.ResourceHandles.html) trait for high-level handles and
+//! the [`ApiLinks`](../trait.ApiLinks.html) trait for following URLs given by GitLab.
+
+#[cfg(feature = "api-projects")]
+pub use crate::api::handles::ResourceHandles;
+pub use crate::api::ignore;
+pub use crate::api::paged;
+pub use crate::api::raw;
+pub use crate::api::sudo;
+pub use crate::api::with_response_meta;
+pub use crate::api::ApiError;
+pub use crate::api::ApiLinks;
+pub use crate::api::AsyncQuery;
+pub use crate::api::Pagination;
+pub use crate::api::Query;
@@ -12,15 +12,29 @@
 
 pub mod access_requests;
 pub mod access_tokens;
+pub mod analytics;
 mod archive;
+pub mod badges;
+pub mod boards;
+pub mod ci_lint;
+pub mod cluster_agents;
 mod create;
 mod delete;
 pub mod deploy_keys;
 pub mod deployments;
 mod edit;
 pub mod environments;
+pub mod error_tracking;
+mod events;
+pub mod feature_flag_user_lists;
+pub mod feature_flags;
+pub mod freeze_periods;
 pub mod hooks;
+mod housekeeping;
+pub mod integrations;
+pub mod invitations;
 pub mod issues;
+pub mod iterations;
 pub mod jobs;
 pub mod labels;
 pub mod members;
@@ -29,6 +43,7 @@ pub mod merge_trains;
 pub mod milestones;
 pub mod packages;
 pub mod pipeline_schedules;
+pub mod pipeline_triggers;
 pub mod pipelines;
 mod project;
 mod projects;
@@ -37,12 +52,21 @@ pub mod protected_tags;
 pub mod push_rule;
 pub mod registry;
 pub mod releases;
+pub mod remote_mirrors;
 pub mod repository;
 pub mod runners;
+pub mod secure_files;
+pub mod security_policies;
 mod share;
+pub mod snippets;
+mod star;
+pub mod templates;
+mod transfer;
 mod unarchive;
 mod unshare;
+mod unstar;
 pub mod variables;
+pub mod wikis;
 
 pub use self::archive::ArchiveProject;
 pub use self::archive::ArchiveProjectBuilder;
@@ -72,6 +96,15 @@ pub use self::edit::EditProject;
 pub use self::edit::EditProjectBuilder;
 pub use self::edit::EditProjectBuilderError;
 
+pub use self::events::ProjectEvents;
+pub use self::events::ProjectEventsBuilder;
+pub use self::events::ProjectEventsBuilderError;
+
+pub use self::housekeeping::HousekeepingTask;
+pub use self::housekeeping::ProjectHousekeeping;
+pub use self::housekeeping::ProjectHousekeepingBuilder;
+pub use self::housekeeping::ProjectHousekeepingBuilderError;
+
 pub use self::project::Project;
 pub use self::project::ProjectBuilder;
 pub use self::project::ProjectBuilderError;
@@ -85,6 +118,14 @@ pub use self::share::ShareProject;
 pub use self::share::ShareProjectBuilder;
 pub use self::share::ShareProjectBuilderError;
 
+pub use self::star::StarProject;
+pub use self::star::StarProjectBuilder;
+pub use self::star::StarProjectBuilderError;
+
+pub use self::transfer::TransferProject;
+pub use self::transfer::TransferProjectBuilder;
+pub use self::transfer::TransferProjectBuilderError;
+
 pub use self::unarchive::UnarchiveProject;
 pub use self::unarchive::UnarchiveProjectBuilder;
 pub use self::unarchive::UnarchiveProjectBuilderError;
@@ -92,3 +133,7 @@ pub use self::unarchive::UnarchiveProjectBuilderError;
 pub use self::unshare::UnshareProject;
 pub use self::unshare::UnshareProjectBuilder;
 pub use self::unshare::UnshareProjectBuilderError;
+
+pub use self::unstar::UnstarProject;
+pub use self::unstar::UnstarProjectBuilder;
+pub use self::unstar::UnstarProjectBuilderError;
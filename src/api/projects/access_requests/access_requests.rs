@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for access requests to a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectAccessRequests<'a> {
     /// The project to query for pipelines.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> ProjectAccessRequests<'a> {
     }
 }
 
+impl<'a> ProjectAccessRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectAccessRequestsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectAccessRequests<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_requests", self.project).into()
+        format!("projects/{}/access_requests", self.project.path_segment()).into()
     }
 }
 
@@ -55,6 +64,15 @@ mod tests {
         ProjectAccessRequests::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectAccessRequests::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectAccessRequestsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
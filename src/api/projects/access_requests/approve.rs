@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Access levels for projects.
@@ -51,7 +51,7 @@ impl ProjectAccessLevel {
 
 /// Submit approval for a user access request to a project
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectAccessRequestsApprove<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -72,6 +72,15 @@ impl<'a> ProjectAccessRequestsApprove<'a> {
     }
 }
 
+impl<'a> ProjectAccessRequestsApproveBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectAccessRequestsApproveBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user_id", self.user_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectAccessRequestsApprove<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -80,7 +89,8 @@ impl<'a> Endpoint for ProjectAccessRequestsApprove<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/access_requests/{}/approve",
-            self.project, self.user_id,
+            self.project.path_segment(),
+            self.user_id.path_segment(),
         )
         .into()
     }
@@ -195,6 +205,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectAccessRequestsApprove::builder()
+            .project(0)
+            .user_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            ProjectAccessRequestsApproveBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
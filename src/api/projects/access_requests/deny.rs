@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Deny a user access request to a project
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectAccessRequestsDeny<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -27,13 +28,27 @@ impl<'a> ProjectAccessRequestsDeny<'a> {
     }
 }
 
+impl<'a> ProjectAccessRequestsDenyBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectAccessRequestsDenyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user_id", self.user_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectAccessRequestsDeny<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_requests/{}", self.project, self.user_id).into()
+        format!(
+            "projects/{}/access_requests/{}",
+            self.project.path_segment(),
+            self.user_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -74,6 +89,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectAccessRequestsDeny::builder()
+            .project(0)
+            .user_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            ProjectAccessRequestsDenyBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Submit an access request to a project for the clients user token.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectAccessRequest<'a> {
     /// The project to query for pipelines.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> ProjectAccessRequest<'a> {
     }
 }
 
+impl<'a> ProjectAccessRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectAccessRequestBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectAccessRequest<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_requests", self.project).into()
+        format!("projects/{}/access_requests", self.project.path_segment()).into()
     }
 }
 
@@ -55,6 +64,15 @@ mod tests {
         ProjectAccessRequest::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectAccessRequest::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectAccessRequestBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get access tokens of a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectAccessTokens<'a> {
     /// The project for which to list tokens.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> ProjectAccessTokens<'a> {
     }
 }
 
+impl<'a> ProjectAccessTokensBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectAccessTokensBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectAccessTokens<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_tokens", self.project).into()
+        format!("projects/{}/access_tokens", self.project.path_segment()).into()
     }
 }
 
@@ -57,6 +66,15 @@ mod tests {
         ProjectAccessTokens::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectAccessTokens::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectAccessTokensBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
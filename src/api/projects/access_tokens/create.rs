@@ -9,7 +9,7 @@ use std::collections::BTreeSet;
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -90,7 +90,7 @@ impl From<ProjectAccessTokenAccessLevel> for AccessLevel {
 
 /// Create a new personal access token for the authenticated user.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateProjectAccessToken<'a> {
     /// The project to create the access token for.
     #[builder(setter(into))]
@@ -132,6 +132,12 @@ impl<'a> CreateProjectAccessTokenBuilder<'a> {
         self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), CreateProjectAccessTokenBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateProjectAccessToken<'a> {
@@ -140,7 +146,7 @@ impl<'a> Endpoint for CreateProjectAccessToken<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_tokens", self.project).into()
+        format!("projects/{}/access_tokens", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -154,7 +160,11 @@ impl<'a> Endpoint for CreateProjectAccessToken<'a> {
             )
             .push_opt("expires_at", self.expires_at);
 
-        params.extend(self.scopes.iter().map(|&value| ("scopes[]", value)));
+        params.extend(
+            self.scopes
+                .iter()
+                .map(|&value| (ParamName::new("scopes").array(), value)),
+        );
 
         params.into_body()
     }
@@ -263,6 +273,21 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateProjectAccessToken::builder()
+            .project(0)
+            .name("name")
+            .scope(ProjectAccessTokenScope::K8sProxy)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateProjectAccessTokenBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Revoke a project access token.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct RevokeProjectAccessToken<'a> {
     /// The ID of the project.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> RevokeProjectAccessToken<'a> {
     }
 }
 
+impl<'a> RevokeProjectAccessTokenBuilder<'a> {
+    fn validate(&self) -> Result<(), RevokeProjectAccessTokenBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RevokeProjectAccessToken<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_tokens/{}", self.project, self.id).into()
+        format!(
+            "projects/{}/access_tokens/{}",
+            self.project.path_segment(),
+            self.id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -79,6 +94,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RevokeProjectAccessToken::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            RevokeProjectAccessTokenBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
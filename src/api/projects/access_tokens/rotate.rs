@@ -7,12 +7,12 @@
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Rotate a project access token.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RotateProjectAccessToken<'a> {
     /// The ID of the project.
     #[builder(setter(into))]
@@ -31,13 +31,27 @@ impl<'a> RotateProjectAccessToken<'a> {
     }
 }
 
+impl<'a> RotateProjectAccessTokenBuilder<'a> {
+    fn validate(&self) -> Result<(), RotateProjectAccessTokenBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RotateProjectAccessToken<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/access_tokens/{}/rotate", self.project, self.id).into()
+        format!(
+            "projects/{}/access_tokens/{}/rotate",
+            self.project.path_segment(),
+            self.id.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -93,6 +107,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RotateProjectAccessToken::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            RotateProjectAccessTokenBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,17 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project analytics API endpoints.
+//!
+//! These endpoints are used for querying analytics of a project.
+
+mod dora;
+
+pub use self::dora::DoraMetric;
+pub use self::dora::DoraMetricInterval;
+pub use self::dora::ProjectDoraMetrics;
+pub use self::dora::ProjectDoraMetricsBuilder;
+pub use self::dora::ProjectDoraMetricsBuilderError;
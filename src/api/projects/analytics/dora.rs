@@ -0,0 +1,349 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::environments::EnvironmentTier;
+use crate::api::ParamValue;
+
+/// DORA metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DoraMetric {
+    /// How often deployments to production happen.
+    DeploymentFrequency,
+    /// How long it takes for a commit to reach production.
+    LeadTimeForChanges,
+    /// How long it takes to recover from an incident in production.
+    TimeToRestoreService,
+    /// The percentage of deployments which cause an incident in production.
+    ChangeFailureRate,
+}
+
+impl DoraMetric {
+    fn as_str(self) -> &'static str {
+        match self {
+            DoraMetric::DeploymentFrequency => "deployment_frequency",
+            DoraMetric::LeadTimeForChanges => "lead_time_for_changes",
+            DoraMetric::TimeToRestoreService => "time_to_restore_service",
+            DoraMetric::ChangeFailureRate => "change_failure_rate",
+        }
+    }
+}
+
+impl ParamValue<'static> for DoraMetric {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Intervals over which to aggregate DORA metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DoraMetricInterval {
+    /// Aggregate over the entire date range.
+    All,
+    /// Aggregate by month.
+    Monthly,
+    /// Aggregate by day.
+    Daily,
+}
+
+impl DoraMetricInterval {
+    fn as_str(self) -> &'static str {
+        match self {
+            DoraMetricInterval::All => "all",
+            DoraMetricInterval::Monthly => "monthly",
+            DoraMetricInterval::Daily => "daily",
+        }
+    }
+}
+
+impl ParamValue<'static> for DoraMetricInterval {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for a DORA metric of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectDoraMetrics<'a> {
+    /// The project to query for metrics.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The metric to query.
+    metric: DoraMetric,
+
+    /// The first date of the range to query.
+    ///
+    /// Defaults to 3 months ago.
+    #[builder(default)]
+    start_date: Option<NaiveDate>,
+    /// The last date of the range to query.
+    ///
+    /// Defaults to the current date.
+    #[builder(default)]
+    end_date: Option<NaiveDate>,
+    /// The interval to aggregate data over.
+    ///
+    /// Defaults to `daily`.
+    #[builder(default)]
+    interval: Option<DoraMetricInterval>,
+    /// The tiers of environments to consider.
+    ///
+    /// Defaults to `production`.
+    #[builder(setter(name = "_environment_tiers"), default, private)]
+    environment_tiers: BTreeSet<EnvironmentTier>,
+}
+
+impl<'a> ProjectDoraMetrics<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectDoraMetricsBuilder<'a> {
+        ProjectDoraMetricsBuilder::default()
+    }
+}
+
+impl<'a> ProjectDoraMetricsBuilder<'a> {
+    /// Consider environments of a tier.
+    pub fn environment_tier(&mut self, tier: EnvironmentTier) -> &mut Self {
+        self.environment_tiers
+            .get_or_insert_with(BTreeSet::new)
+            .insert(tier);
+        self
+    }
+
+    /// Consider environments of a set of tiers.
+    pub fn environment_tiers<I>(&mut self, tiers: I) -> &mut Self
+    where
+        I: Iterator<Item = EnvironmentTier>,
+    {
+        self.environment_tiers
+            .get_or_insert_with(BTreeSet::new)
+            .extend(tiers);
+        self
+    }
+
+    fn validate(&self) -> Result<(), ProjectDoraMetricsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectDoraMetrics<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/dora/metrics", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("metric", self.metric)
+            .push_opt("start_date", self.start_date)
+            .push_opt("end_date", self.end_date)
+            .push_opt("interval", self.interval)
+            .extend(
+                self.environment_tiers
+                    .iter()
+                    .map(|&value| (ParamName::new("environment_tiers").array(), value)),
+            );
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::api::projects::analytics::{
+        DoraMetric, DoraMetricInterval, ProjectDoraMetrics, ProjectDoraMetricsBuilderError,
+    };
+    use crate::api::projects::environments::EnvironmentTier;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn dora_metric_as_str() {
+        let items = &[
+            (DoraMetric::DeploymentFrequency, "deployment_frequency"),
+            (DoraMetric::LeadTimeForChanges, "lead_time_for_changes"),
+            (DoraMetric::TimeToRestoreService, "time_to_restore_service"),
+            (DoraMetric::ChangeFailureRate, "change_failure_rate"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn dora_metric_interval_as_str() {
+        let items = &[
+            (DoraMetricInterval::All, "all"),
+            (DoraMetricInterval::Monthly, "monthly"),
+            (DoraMetricInterval::Daily, "daily"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_and_metric_are_needed() {
+        let err = ProjectDoraMetrics::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectDoraMetricsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectDoraMetrics::builder()
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectDoraMetricsBuilderError, "project");
+    }
+
+    #[test]
+    fn metric_is_needed() {
+        let err = ProjectDoraMetrics::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectDoraMetricsBuilderError, "metric");
+    }
+
+    #[test]
+    fn project_and_metric_are_sufficient() {
+        ProjectDoraMetrics::builder()
+            .project(1)
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectDoraMetrics::builder()
+            .project(0)
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectDoraMetricsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dora/metrics")
+            .add_query_params(&[("metric", "deployment_frequency")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDoraMetrics::builder()
+            .project("simple/project")
+            .metric(DoraMetric::DeploymentFrequency)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_start_date() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dora/metrics")
+            .add_query_params(&[
+                ("metric", "lead_time_for_changes"),
+                ("start_date", "2024-01-01"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDoraMetrics::builder()
+            .project("simple/project")
+            .metric(DoraMetric::LeadTimeForChanges)
+            .start_date(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_end_date() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dora/metrics")
+            .add_query_params(&[
+                ("metric", "lead_time_for_changes"),
+                ("end_date", "2024-03-31"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDoraMetrics::builder()
+            .project("simple/project")
+            .metric(DoraMetric::LeadTimeForChanges)
+            .end_date(NaiveDate::from_ymd_opt(2024, 3, 31).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_interval() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dora/metrics")
+            .add_query_params(&[("metric", "change_failure_rate"), ("interval", "monthly")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDoraMetrics::builder()
+            .project("simple/project")
+            .metric(DoraMetric::ChangeFailureRate)
+            .interval(DoraMetricInterval::Monthly)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_environment_tiers() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/dora/metrics")
+            .add_query_params(&[
+                ("metric", "time_to_restore_service"),
+                ("environment_tiers[]", "production"),
+                ("environment_tiers[]", "staging"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectDoraMetrics::builder()
+            .project("simple/project")
+            .metric(DoraMetric::TimeToRestoreService)
+            .environment_tier(EnvironmentTier::Staging)
+            .environment_tiers([EnvironmentTier::Production, EnvironmentTier::Staging].into_iter())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
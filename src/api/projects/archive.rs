@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Archives the project if the user is either an administrator or the owner of this project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ArchiveProject<'a> {
     /// The project to archive.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> ArchiveProject<'a> {
     }
 }
 
+impl<'a> ArchiveProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), ArchiveProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ArchiveProject<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/archive", self.project).into()
+        format!("projects/{}/archive", self.project.path_segment()).into()
     }
 }
 
@@ -56,6 +65,12 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ArchiveProject::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ArchiveProjectBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
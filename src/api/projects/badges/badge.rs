@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a badge within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Badge<'a> {
+    /// The project to query for the badge.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+}
+
+impl<'a> Badge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BadgeBuilder<'a> {
+        BadgeBuilder::default()
+    }
+}
+
+impl<'a> BadgeBuilder<'a> {
+    fn validate(&self) -> Result<(), BadgeBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("badge", self.badge.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Badge<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/badges/{}",
+            self.project.path_segment(),
+            self.badge.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::badges::{Badge, BadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_badge_are_needed() {
+        let err = Badge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = Badge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = Badge::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn project_and_badge_are_sufficient() {
+        Badge::builder().project(1).badge(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = Badge::builder().project(0).badge(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Badge::builder()
+            .project("simple/project")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for badges within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Badges<'a> {
+    /// The project to query for badges.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter badges by name.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> Badges<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BadgesBuilder<'a> {
+        BadgesBuilder::default()
+    }
+}
+
+impl<'a> BadgesBuilder<'a> {
+    fn validate(&self) -> Result<(), BadgesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Badges<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/badges", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("name", self.name.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for Badges<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::badges::{Badges, BadgesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = Badges::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BadgesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        Badges::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = Badges::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BadgesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Badges::builder().project("simple/project").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges")
+            .add_query_params(&[("name", "coverage")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Badges::builder()
+            .project("simple/project")
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,225 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::helpers;
+
+/// Edit a badge on a project.
+///
+/// Placeholders in the URLs are checked against those supported by GitLab.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditBadge<'a> {
+    /// The project of the badge.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the badge.
+    badge: u64,
+
+    /// The URL the badge links to.
+    #[builder(setter(into), default)]
+    link_url: Option<Cow<'a, str>>,
+    /// The URL of the badge image.
+    #[builder(setter(into), default)]
+    image_url: Option<Cow<'a, str>>,
+    /// The name of the badge.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditBadgeBuilder<'a> {
+        EditBadgeBuilder::default()
+    }
+}
+
+impl<'a> EditBadgeBuilder<'a> {
+    fn validate(&self) -> Result<(), EditBadgeBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("badge", self.badge.as_ref())?;
+
+        helpers::validate_badge_url("link_url", self.link_url.as_ref().and_then(Option::as_ref))?;
+        helpers::validate_badge_url(
+            "image_url",
+            self.image_url.as_ref().and_then(Option::as_ref),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditBadge<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/badges/{}",
+            self.project.path_segment(),
+            self.badge.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("link_url", self.link_url.as_ref())
+            .push_opt("image_url", self.image_url.as_ref())
+            .push_opt("name", self.name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::badges::{EditBadge, EditBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_badge_are_needed() {
+        let err = EditBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditBadge::builder().badge(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn badge_is_needed() {
+        let err = EditBadge::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBadgeBuilderError, "badge");
+    }
+
+    #[test]
+    fn project_and_badge_are_sufficient() {
+        EditBadge::builder().project(1).badge(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditBadge::builder()
+            .project(0)
+            .badge(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn link_url_placeholders_are_validated() {
+        let err = EditBadge::builder()
+            .project(1)
+            .badge(1)
+            .link_url("https://example.com/%{unknown}")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_placeholders_are_validated() {
+        let err = EditBadge::builder()
+            .project(1)
+            .badge(1)
+            .image_url("https://example.com/%{unknown}/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBadge::builder()
+            .project("simple/project")
+            .badge(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_link_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("link_url=https%3A%2F%2Fexample.com%2F%25%7Bdefault_branch%7D")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBadge::builder()
+            .project("simple/project")
+            .badge(1)
+            .link_url("https://example.com/%{default_branch}")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_image_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("image_url=https%3A%2F%2Fexample.com%2Fbadge.svg")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBadge::builder()
+            .project("simple/project")
+            .badge(1)
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/badges/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=coverage")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBadge::builder()
+            .project("simple/project")
+            .badge(1)
+            .name("coverage")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::helpers;
+
+/// Preview how badge URLs are rendered for a project.
+///
+/// Placeholders in the URLs are replaced with values from the project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct PreviewBadge<'a> {
+    /// The project to render the badge for.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The URL the badge links to.
+    #[builder(setter(into))]
+    link_url: Cow<'a, str>,
+    /// The URL of the badge image.
+    #[builder(setter(into))]
+    image_url: Cow<'a, str>,
+}
+
+impl<'a> PreviewBadge<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PreviewBadgeBuilder<'a> {
+        PreviewBadgeBuilder::default()
+    }
+}
+
+impl<'a> PreviewBadgeBuilder<'a> {
+    fn validate(&self) -> Result<(), PreviewBadgeBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        helpers::validate_badge_url("link_url", self.link_url.as_ref())?;
+        helpers::validate_badge_url("image_url", self.image_url.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for PreviewBadge<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/badges/render", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("link_url", &self.link_url)
+            .push("image_url", &self.image_url);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::badges::{PreviewBadge, PreviewBadgeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_link_url_and_image_url_are_needed() {
+        let err = PreviewBadge::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PreviewBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PreviewBadge::builder()
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PreviewBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn link_url_is_needed() {
+        let err = PreviewBadge::builder()
+            .project(1)
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PreviewBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_is_needed() {
+        let err = PreviewBadge::builder()
+            .project(1)
+            .link_url("https://example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, PreviewBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn project_link_url_and_image_url_are_sufficient() {
+        PreviewBadge::builder()
+            .project(1)
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = PreviewBadge::builder()
+            .project(0)
+            .link_url("https://example.com")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PreviewBadgeBuilderError, "project");
+    }
+
+    #[test]
+    fn link_url_placeholders_are_validated() {
+        let err = PreviewBadge::builder()
+            .project(1)
+            .link_url("https://example.com/%{unknown}")
+            .image_url("https://example.com/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PreviewBadgeBuilderError, "link_url");
+    }
+
+    #[test]
+    fn image_url_placeholders_are_validated() {
+        let err = PreviewBadge::builder()
+            .project(1)
+            .link_url("https://example.com")
+            .image_url("https://example.com/%{unknown}/badge.svg")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PreviewBadgeBuilderError, "image_url");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/badges/render")
+            .add_query_params(&[
+                ("link_url", "https://example.com/%{project_path}"),
+                ("image_url", "https://example.com/%{project_path}/badge.svg"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PreviewBadge::builder()
+            .project("simple/project")
+            .link_url("https://example.com/%{project_path}")
+            .image_url("https://example.com/%{project_path}/badge.svg")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Project issue board API endpoints.
+//!
+//! These endpoints are used for querying and modifying issue boards of a project and their lists.
+
+mod board;
+mod boards;
+mod create;
+mod create_list;
+mod delete;
+mod delete_list;
+mod edit;
+mod edit_list;
+mod list;
+mod lists;
+
+pub use self::board::Board;
+pub use self::board::BoardBuilder;
+pub use self::board::BoardBuilderError;
+
+pub use self::boards::Boards;
+pub use self::boards::BoardsBuilder;
+pub use self::boards::BoardsBuilderError;
+
+pub use self::create::CreateBoard;
+pub use self::create::CreateBoardBuilder;
+pub use self::create::CreateBoardBuilderError;
+
+pub use self::create_list::BoardListType;
+pub use self::create_list::CreateBoardList;
+pub use self::create_list::CreateBoardListBuilder;
+pub use self::create_list::CreateBoardListBuilderError;
+
+pub use self::delete::DeleteBoard;
+pub use self::delete::DeleteBoardBuilder;
+pub use self::delete::DeleteBoardBuilderError;
+
+pub use self::delete_list::DeleteBoardList;
+pub use self::delete_list::DeleteBoardListBuilder;
+pub use self::delete_list::DeleteBoardListBuilderError;
+
+pub use self::edit::EditBoard;
+pub use self::edit::EditBoardBuilder;
+pub use self::edit::EditBoardBuilderError;
+
+pub use self::edit_list::EditBoardList;
+pub use self::edit_list::EditBoardListBuilder;
+pub use self::edit_list::EditBoardListBuilderError;
+
+pub use self::list::BoardList;
+pub use self::list::BoardListBuilder;
+pub use self::list::BoardListBuilderError;
+
+pub use self::lists::BoardLists;
+pub use self::lists::BoardListsBuilder;
+pub use self::lists::BoardListsBuilderError;
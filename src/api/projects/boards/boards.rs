@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for issue boards within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Boards<'a> {
+    /// The project to query for boards.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> Boards<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BoardsBuilder<'a> {
+        BoardsBuilder::default()
+    }
+}
+
+impl<'a> BoardsBuilder<'a> {
+    fn validate(&self) -> Result<(), BoardsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Boards<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards", self.project.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for Boards<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::boards::{Boards, BoardsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = Boards::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        Boards::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = Boards::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BoardsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/boards")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Boards::builder().project("simple/project").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
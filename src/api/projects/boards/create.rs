@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create an issue board within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateBoard<'a> {
+    /// The project to create the board within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the board.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> CreateBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateBoardBuilder<'a> {
+        CreateBoardBuilder::default()
+    }
+}
+
+impl<'a> CreateBoardBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateBoardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateBoard<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/boards", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("name", &self.name);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{CreateBoard, CreateBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateBoard::builder().name("board").build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBoardBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateBoard::builder()
+            .project(1)
+            .name("board")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateBoard::builder()
+            .project(0)
+            .name("board")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/boards")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=board")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBoard::builder()
+            .project("simple/project")
+            .name("board")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete an issue board from a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteBoard<'a> {
+    /// The project of the board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the board to delete.
+    board: u64,
+}
+
+impl<'a> DeleteBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteBoardBuilder<'a> {
+        DeleteBoardBuilder::default()
+    }
+}
+
+impl<'a> DeleteBoardBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteBoardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteBoard<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}",
+            self.project.path_segment(),
+            self.board.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{DeleteBoard, DeleteBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = DeleteBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = DeleteBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        DeleteBoard::builder().project(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteBoard::builder()
+            .project(0)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
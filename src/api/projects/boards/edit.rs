@@ -0,0 +1,316 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit an issue board within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditBoard<'a> {
+    /// The project of the board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the board to edit.
+    board: u64,
+
+    /// The new name of the board.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// Scope the board to issues assigned to a user (by ID).
+    #[builder(default)]
+    assignee_id: Option<u64>,
+    /// Scope the board to issues in a milestone (by ID).
+    #[builder(default)]
+    milestone_id: Option<u64>,
+    /// Scope the board to issues with a set of labels.
+    #[builder(setter(name = "_labels"), default, private)]
+    labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Scope the board to issues with a weight.
+    #[builder(default)]
+    weight: Option<u64>,
+    /// Whether to hide the "Open" list of the board.
+    #[builder(default)]
+    hide_backlog_list: Option<bool>,
+    /// Whether to hide the "Closed" list of the board.
+    #[builder(default)]
+    hide_closed_list: Option<bool>,
+}
+
+impl<'a> EditBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditBoardBuilder<'a> {
+        EditBoardBuilder::default()
+    }
+}
+
+impl<'a> EditBoardBuilder<'a> {
+    /// Scope the board to issues with a label.
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Scope the board to issues with a set of labels.
+    pub fn labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), EditBoardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditBoard<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}",
+            self.project.path_segment(),
+            self.board.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("assignee_id", self.assignee_id)
+            .push_opt("milestone_id", self.milestone_id)
+            .push_opt("labels", self.labels.as_ref())
+            .push_opt("weight", self.weight)
+            .push_opt("hide_backlog_list", self.hide_backlog_list)
+            .push_opt("hide_closed_list", self.hide_closed_list);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{EditBoard, EditBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = EditBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = EditBoard::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        EditBoard::builder().project(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditBoard::builder()
+            .project(0)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditBoardBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=board")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .name("board")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .assignee_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestone_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("milestone_id=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .milestone_id(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("labels=bug%2Cfeature%2Cdocs")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .label("bug")
+            .labels(["feature", "docs"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_weight() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("weight=4")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .weight(4)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_hide_backlog_list() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("hide_backlog_list=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .hide_backlog_list(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_hide_closed_list() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("hide_closed_list=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditBoard::builder()
+            .project("simple/project")
+            .board(1)
+            .hide_closed_list(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
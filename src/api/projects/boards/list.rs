@@ -0,0 +1,132 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a list of an issue board within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct BoardList<'a> {
+    /// The project of the board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+}
+
+impl<'a> BoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BoardListBuilder<'a> {
+        BoardListBuilder::default()
+    }
+}
+
+impl<'a> BoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), BoardListBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+        common::validate_path_param("list", self.list.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for BoardList<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}/lists/{}",
+            self.project.path_segment(),
+            self.board.path_segment(),
+            self.list.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::boards::{BoardList, BoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = BoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = BoardList::builder().board(1).list(2).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = BoardList::builder().project(1).list(2).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_needed() {
+        let err = BoardList::builder()
+            .project(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        BoardList::builder()
+            .project(1)
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = BoardList::builder()
+            .project(0)
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BoardListBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint("projects/simple%2Fproject/boards/1/lists/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BoardList::builder()
+            .project("simple/project")
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the lists of an issue board within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct BoardLists<'a> {
+    /// The project of the board.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+}
+
+impl<'a> BoardLists<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BoardListsBuilder<'a> {
+        BoardListsBuilder::default()
+    }
+}
+
+impl<'a> BoardListsBuilder<'a> {
+    fn validate(&self) -> Result<(), BoardListsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("board", self.board.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for BoardLists<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/boards/{}/lists",
+            self.project.path_segment(),
+            self.board.path_segment()
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for BoardLists<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::boards::{BoardLists, BoardListsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_board_are_needed() {
+        let err = BoardLists::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = BoardLists::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListsBuilderError, "project");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = BoardLists::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BoardListsBuilderError, "board");
+    }
+
+    #[test]
+    fn project_and_board_are_sufficient() {
+        BoardLists::builder().project(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = BoardLists::builder()
+            .project(0)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BoardListsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/boards/1/lists")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BoardLists::builder()
+            .project("simple/project")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project CI lint API endpoints.
+//!
+//! These endpoints are used for validating CI/CD configuration within the context of a project.
+
+mod lint;
+mod lint_config;
+
+pub use self::lint::Lint;
+pub use self::lint::LintBuilder;
+pub use self::lint::LintBuilderError;
+
+pub use self::lint_config::LintConfig;
+pub use self::lint_config::LintConfigBuilder;
+pub use self::lint_config::LintConfigBuilderError;
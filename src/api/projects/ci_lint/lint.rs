@@ -0,0 +1,196 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Validate CI/CD configuration content within the context of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Lint<'a> {
+    /// The project to validate the configuration within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The CI/CD configuration content.
+    #[builder(setter(into))]
+    content: Cow<'a, str>,
+
+    /// Run a pipeline creation simulation rather than only performing static checks.
+    #[builder(default)]
+    dry_run: Option<bool>,
+    /// Include the list of jobs which would exist in the result.
+    #[builder(default)]
+    include_jobs: Option<bool>,
+    /// The branch or tag to use as context when `dry_run` is set.
+    ///
+    /// Defaults to the project's default branch.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+}
+
+impl<'a> Lint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LintBuilder<'a> {
+        LintBuilder::default()
+    }
+}
+
+impl<'a> LintBuilder<'a> {
+    fn validate(&self) -> Result<(), LintBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Lint<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/ci/lint", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("content", &self.content)
+            .push_opt("dry_run", self.dry_run)
+            .push_opt("include_jobs", self.include_jobs)
+            .push_opt("ref", self.ref_.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::ci_lint::{Lint, LintBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_content_are_needed() {
+        let err = Lint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, LintBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = Lint::builder().content("job: {}").build().unwrap_err();
+        crate::test::assert_missing_field!(err, LintBuilderError, "project");
+    }
+
+    #[test]
+    fn content_is_needed() {
+        let err = Lint::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, LintBuilderError, "content");
+    }
+
+    #[test]
+    fn project_and_content_are_sufficient() {
+        Lint::builder()
+            .project(1)
+            .content("job: {}")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = Lint::builder()
+            .project(0)
+            .content("job: {}")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, LintBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("content=job%3A+%7B%7D")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&dry_run=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .dry_run(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_jobs() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&include_jobs=false"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .include_jobs(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("content=job%3A+%7B%7D", "&ref=main"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Lint::builder()
+            .project("simple/project")
+            .content("job: {}")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
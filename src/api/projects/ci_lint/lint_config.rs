@@ -0,0 +1,180 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Validate the existing CI/CD configuration of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct LintConfig<'a> {
+    /// The project to validate the configuration of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The commit SHA, branch, or tag to read the configuration from.
+    ///
+    /// Defaults to the head of the project's default branch.
+    #[builder(setter(into), default)]
+    content_ref: Option<Cow<'a, str>>,
+    /// Run a pipeline creation simulation rather than only performing static checks.
+    #[builder(default)]
+    dry_run: Option<bool>,
+    /// The branch or tag to use as context when `dry_run` is set.
+    ///
+    /// Defaults to the project's default branch.
+    #[builder(setter(into), default)]
+    dry_run_ref: Option<Cow<'a, str>>,
+    /// Include the list of jobs which would exist in the result.
+    #[builder(default)]
+    include_jobs: Option<bool>,
+}
+
+impl<'a> LintConfig<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LintConfigBuilder<'a> {
+        LintConfigBuilder::default()
+    }
+}
+
+impl<'a> LintConfigBuilder<'a> {
+    fn validate(&self) -> Result<(), LintConfigBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for LintConfig<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/ci/lint", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("content_ref", self.content_ref.as_ref())
+            .push_opt("dry_run", self.dry_run)
+            .push_opt("dry_run_ref", self.dry_run_ref.as_ref())
+            .push_opt("include_jobs", self.include_jobs);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::ci_lint::{LintConfig, LintConfigBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = LintConfig::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, LintConfigBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        LintConfig::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = LintConfig::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, LintConfigBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LintConfig::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_content_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("content_ref", "v1.0")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LintConfig::builder()
+            .project("simple/project")
+            .content_ref("v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("dry_run", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LintConfig::builder()
+            .project("simple/project")
+            .dry_run(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("dry_run_ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LintConfig::builder()
+            .project("simple/project")
+            .dry_run_ref("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_jobs() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/ci/lint")
+            .add_query_params(&[("include_jobs", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LintConfig::builder()
+            .project("simple/project")
+            .include_jobs(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Project cluster agent API endpoints.
+//!
+//! These endpoints are used for managing agents for Kubernetes registered with a project and
+//! their tokens.
+
+mod cluster_agent;
+mod cluster_agents;
+mod create_token;
+mod delete;
+mod register;
+mod revoke_token;
+mod token;
+mod tokens;
+
+pub use self::cluster_agent::ClusterAgent;
+pub use self::cluster_agent::ClusterAgentBuilder;
+pub use self::cluster_agent::ClusterAgentBuilderError;
+
+pub use self::cluster_agents::ClusterAgents;
+pub use self::cluster_agents::ClusterAgentsBuilder;
+pub use self::cluster_agents::ClusterAgentsBuilderError;
+
+pub use self::create_token::CreateClusterAgentToken;
+pub use self::create_token::CreateClusterAgentTokenBuilder;
+pub use self::create_token::CreateClusterAgentTokenBuilderError;
+
+pub use self::delete::DeleteClusterAgent;
+pub use self::delete::DeleteClusterAgentBuilder;
+pub use self::delete::DeleteClusterAgentBuilderError;
+
+pub use self::register::RegisterClusterAgent;
+pub use self::register::RegisterClusterAgentBuilder;
+pub use self::register::RegisterClusterAgentBuilderError;
+
+pub use self::revoke_token::RevokeClusterAgentToken;
+pub use self::revoke_token::RevokeClusterAgentTokenBuilder;
+pub use self::revoke_token::RevokeClusterAgentTokenBuilderError;
+
+pub use self::token::ClusterAgentToken;
+pub use self::token::ClusterAgentTokenBuilder;
+pub use self::token::ClusterAgentTokenBuilderError;
+
+pub use self::tokens::ClusterAgentTokens;
+pub use self::tokens::ClusterAgentTokensBuilder;
+pub use self::tokens::ClusterAgentTokensBuilderError;
@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete an agent registered with a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteClusterAgent<'a> {
+    /// The project of the agent.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the agent.
+    agent: u64,
+}
+
+impl<'a> DeleteClusterAgent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteClusterAgentBuilder<'a> {
+        DeleteClusterAgentBuilder::default()
+    }
+}
+
+impl<'a> DeleteClusterAgentBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteClusterAgentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("agent", self.agent.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteClusterAgent<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/cluster_agents/{}",
+            self.project.path_segment(),
+            self.agent.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::cluster_agents::{
+        DeleteClusterAgent, DeleteClusterAgentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_agent_are_needed() {
+        let err = DeleteClusterAgent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteClusterAgent::builder().agent(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn agent_is_needed() {
+        let err = DeleteClusterAgent::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteClusterAgentBuilderError, "agent");
+    }
+
+    #[test]
+    fn project_and_agent_are_sufficient() {
+        DeleteClusterAgent::builder()
+            .project(1)
+            .agent(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteClusterAgent::builder()
+            .project(0)
+            .agent(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/cluster_agents/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteClusterAgent::builder()
+            .project("simple/project")
+            .agent(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Register an agent with a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RegisterClusterAgent<'a> {
+    /// The project of the agent.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the agent.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> RegisterClusterAgent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RegisterClusterAgentBuilder<'a> {
+        RegisterClusterAgentBuilder::default()
+    }
+}
+
+impl<'a> RegisterClusterAgentBuilder<'a> {
+    fn validate(&self) -> Result<(), RegisterClusterAgentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for RegisterClusterAgent<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/cluster_agents", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("name", self.name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::cluster_agents::{
+        RegisterClusterAgent, RegisterClusterAgentBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = RegisterClusterAgent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RegisterClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RegisterClusterAgent::builder()
+            .name("agent")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RegisterClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = RegisterClusterAgent::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RegisterClusterAgentBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        RegisterClusterAgent::builder()
+            .project(1)
+            .name("agent")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = RegisterClusterAgent::builder()
+            .project(0)
+            .name("agent")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RegisterClusterAgentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/cluster_agents")
+            .form_params(&[("name", "agent")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RegisterClusterAgent::builder()
+            .project("simple/project")
+            .name("agent")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -272,34 +272,34 @@ impl<'a> ContainerExpirationPolicy<'a> {
     pub(crate) fn add_query<'b>(&'b self, params: &mut FormParams<'b>) {
         params
             .push_opt(
-                "container_expiration_policy_attributes[cadence]",
+                ParamName::new("container_expiration_policy_attributes").key("cadence"),
                 self.cadence,
             )
             .push_opt(
-                "container_expiration_policy_attributes[enabled]",
+                ParamName::new("container_expiration_policy_attributes").key("enabled"),
                 self.enabled,
             )
             .push_opt(
-                "container_expiration_policy_attributes[keep_n]",
+                ParamName::new("container_expiration_policy_attributes").key("keep_n"),
                 self.keep_n,
             )
             .push_opt(
-                "container_expiration_policy_attributes[older_than]",
+                ParamName::new("container_expiration_policy_attributes").key("older_than"),
                 self.older_than,
             )
             .push_opt(
-                "container_expiration_policy_attributes[name_regex_delete]",
+                ParamName::new("container_expiration_policy_attributes").key("name_regex_delete"),
                 self.name_regex_delete.as_ref(),
             )
             .push_opt(
-                "container_expiration_policy_attributes[name_regex_keep]",
+                ParamName::new("container_expiration_policy_attributes").key("name_regex_keep"),
                 self.name_regex_keep.as_ref(),
             );
 
         #[allow(deprecated)]
         {
             params.push_opt(
-                "container_expiration_policy_attributes[name_regex]",
+                ParamName::new("container_expiration_policy_attributes").key("name_regex"),
                 self.name_regex.as_ref(),
             );
         }
@@ -984,8 +984,16 @@ impl<'a> Endpoint for CreateProject<'a> {
             )
             .push_opt("lfs_enabled", self.lfs_enabled)
             .push_opt("request_access_enabled", self.request_access_enabled)
-            .extend(self.tag_list.iter().map(|value| ("tag_list[]", value)))
-            .extend(self.topics.iter().map(|value| ("topics[]", value)))
+            .extend(
+                self.tag_list
+                    .iter()
+                    .map(|value| (ParamName::new("tag_list").array(), value)),
+            )
+            .extend(
+                self.topics
+                    .iter()
+                    .map(|value| (ParamName::new("topics").array(), value)),
+            )
             .push_opt(
                 "printing_merge_request_link_enabled",
                 self.printing_merge_request_link_enabled,
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Deletes the project if the user is either an administrator or the owner of this project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteProject<'a> {
     /// The project to delete.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> DeleteProject<'a> {
     }
 }
 
+impl<'a> DeleteProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteProject<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}", self.project).into()
+        format!("projects/{}", self.project.path_segment()).into()
     }
 }
 
@@ -53,6 +62,12 @@ mod tests {
         DeleteProject::builder().project("project").build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteProject::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteProjectBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
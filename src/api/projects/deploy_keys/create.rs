@@ -4,14 +4,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new deploy key on project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateDeployKey<'a> {
     /// The project to add the deploy key to
     #[builder(setter(into))]
@@ -25,6 +26,9 @@ pub struct CreateDeployKey<'a> {
     /// Can this deploy key push to the project repository
     #[builder(default)]
     can_push: Option<bool>,
+    /// When the deploy key expires
+    #[builder(default)]
+    expires_at: Option<DateTime<Utc>>,
 }
 
 impl<'a> CreateDeployKey<'a> {
@@ -34,13 +38,21 @@ impl<'a> CreateDeployKey<'a> {
     }
 }
 
+impl<'a> CreateDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateDeployKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateDeployKey<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deploy_keys", self.project).into()
+        format!("projects/{}/deploy_keys", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -49,7 +61,8 @@ impl<'a> Endpoint for CreateDeployKey<'a> {
         params
             .push("title", self.title.as_ref())
             .push("key", self.key.as_ref())
-            .push_opt("can_push", self.can_push);
+            .push_opt("can_push", self.can_push)
+            .push_opt("expires_at", self.expires_at);
 
         params.into_body()
     }
@@ -57,6 +70,7 @@ impl<'a> Endpoint for CreateDeployKey<'a> {
 
 #[cfg(test)]
 mod tests {
+    use chrono::{TimeZone, Utc};
     use http::Method;
 
     use crate::api::projects::deploy_keys::{CreateDeployKey, CreateDeployKeyBuilderError};
@@ -109,6 +123,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateDeployKey::builder()
+            .project(0)
+            .title("title")
+            .key("ssh-rsa ABC")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateDeployKeyBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -149,4 +174,29 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/deploy_keys")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "title=title",
+                "&key=ssh-rsa+ABC",
+                "&expires_at=2024-06-01T00%3A00%3A00Z",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateDeployKey::builder()
+            .project("simple/project")
+            .title("title")
+            .key("ssh-rsa ABC")
+            .expires_at(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
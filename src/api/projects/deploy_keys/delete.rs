@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a deploy key from a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteDeployKey<'a> {
     /// The project to delete the deploy key from.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> DeleteDeployKey<'a> {
     }
 }
 
+impl<'a> DeleteDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteDeployKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deploy_key", self.deploy_key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteDeployKey<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deploy_keys/{}", self.project, self.deploy_key).into()
+        format!(
+            "projects/{}/deploy_keys/{}",
+            self.project.path_segment(),
+            self.deploy_key.path_segment()
+        )
+        .into()
     }
 }
 
@@ -74,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteDeployKey::builder()
+            .project(0)
+            .deploy_key(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteDeployKeyBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a deploy key on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeployKey<'a> {
     /// The project with the merge requset.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> DeployKey<'a> {
     }
 }
 
+impl<'a> DeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), DeployKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deploy_key", self.deploy_key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeployKey<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deploy_keys/{}", self.project, self.deploy_key).into()
+        format!(
+            "projects/{}/deploy_keys/{}",
+            self.project.path_segment(),
+            self.deploy_key.path_segment()
+        )
+        .into()
     }
 }
 
@@ -69,6 +84,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeployKey::builder()
+            .project(0)
+            .deploy_key(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeployKeyBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for deploy keys within a project.
 ///
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeployKeys<'a> {
     /// The project to query for deploy keys.
     #[builder(setter(into))]
@@ -25,13 +26,21 @@ impl<'a> DeployKeys<'a> {
     }
 }
 
+impl<'a> DeployKeysBuilder<'a> {
+    fn validate(&self) -> Result<(), DeployKeysBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeployKeys<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deploy_keys", self.project).into()
+        format!("projects/{}/deploy_keys", self.project.path_segment()).into()
     }
 }
 
@@ -54,6 +63,12 @@ mod tests {
         DeployKeys::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeployKeys::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeployKeysBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Edit a new deploy key on project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditDeployKey<'a> {
     /// The project to open the deploy key on.
     #[builder(setter(into))]
@@ -33,13 +33,27 @@ impl<'a> EditDeployKey<'a> {
     }
 }
 
+impl<'a> EditDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), EditDeployKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deploy_key", self.deploy_key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditDeployKey<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deploy_keys/{}", self.project, self.deploy_key).into()
+        format!(
+            "projects/{}/deploy_keys/{}",
+            self.project.path_segment(),
+            self.deploy_key.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -88,6 +102,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditDeployKey::builder()
+            .project(0)
+            .deploy_key(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditDeployKeyBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
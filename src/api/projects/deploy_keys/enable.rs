@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Enable a new deploy key on project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EnableDeployKey<'a> {
     /// The project to enable the deploy key on.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> EnableDeployKey<'a> {
     }
 }
 
+impl<'a> EnableDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), EnableDeployKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deploy_key", self.deploy_key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EnableDeployKey<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -34,7 +44,8 @@ impl<'a> Endpoint for EnableDeployKey<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/deploy_keys/{}/enable",
-            self.project, self.deploy_key,
+            self.project.path_segment(),
+            self.deploy_key.path_segment(),
         )
         .into()
     }
@@ -78,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EnableDeployKey::builder()
+            .project(0)
+            .deploy_key(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EnableDeployKeyBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::deployments::DeploymentStatus;
 
 /// Create a deployment for a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateDeployment<'a> {
     /// The project to edit a deployment from.
     #[builder(setter(into))]
@@ -38,13 +39,21 @@ impl<'a> CreateDeployment<'a> {
     }
 }
 
+impl<'a> CreateDeploymentBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateDeploymentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateDeployment<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deployments", self.project).into()
+        format!("projects/{}/deployments", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -168,6 +177,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateDeployment::builder()
+            .project("")
+            .environment("env")
+            .sha("0000000000000000000000000000000000000000")
+            .ref_("main")
+            .tag(false)
+            .status(DeploymentStatus::Success)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateDeploymentBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a deployment for a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteDeployment<'a> {
     /// The project to delete a deployment from.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> DeleteDeployment<'a> {
     }
 }
 
+impl<'a> DeleteDeploymentBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteDeploymentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deployment_id", self.deployment_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteDeployment<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -34,7 +44,8 @@ impl<'a> Endpoint for DeleteDeployment<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/deployments/{}",
-            self.project, self.deployment_id,
+            self.project.path_segment(),
+            self.deployment_id.path_segment(),
         )
         .into()
     }
@@ -81,6 +92,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteDeployment::builder()
+            .project("")
+            .deployment_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteDeploymentBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get a deployment for a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Deployment<'a> {
     /// The project to get a deployment from.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> Deployment<'a> {
     }
 }
 
+impl<'a> DeploymentBuilder<'a> {
+    fn validate(&self) -> Result<(), DeploymentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deployment_id", self.deployment_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Deployment<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for Deployment<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/deployments/{}",
-            self.project, self.deployment_id,
+            self.project.path_segment(),
+            self.deployment_id.path_segment(),
         )
         .into()
     }
@@ -78,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Deployment::builder()
+            .project("")
+            .deployment_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeploymentBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -7,7 +7,7 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::deployments::DeploymentStatus;
 use crate::api::ParamValue;
@@ -89,7 +89,7 @@ impl ParamValue<'static> for DeploymentStatusFilter {
 
 /// Query for deployments within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Deployments<'a> {
     /// The project to query for deployments.
     #[builder(setter(into))]
@@ -128,13 +128,21 @@ impl<'a> Deployments<'a> {
     }
 }
 
+impl<'a> DeploymentsBuilder<'a> {
+    fn validate(&self) -> Result<(), DeploymentsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Deployments<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/deployments", self.project).into()
+        format!("projects/{}/deployments", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -223,6 +231,12 @@ mod tests {
         Deployments::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Deployments::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeploymentsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
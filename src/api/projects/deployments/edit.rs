@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -43,6 +43,7 @@ impl ParamValue<'static> for DeploymentStatus {
 
 /// Edit a deployment for a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EditDeployment<'a> {
     /// The project to edit a deployment from.
     #[builder(setter(into))]
@@ -60,6 +61,15 @@ impl<'a> EditDeployment<'a> {
     }
 }
 
+impl<'a> EditDeploymentBuilder<'a> {
+    fn validate(&self) -> Result<(), EditDeploymentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("deployment_id", self.deployment_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditDeployment<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -68,7 +78,8 @@ impl<'a> Endpoint for EditDeployment<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/deployments/{}",
-            self.project, self.deployment_id,
+            self.project.path_segment(),
+            self.deployment_id.path_segment(),
         )
         .into()
     }
@@ -152,6 +163,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditDeployment::builder()
+            .project("")
+            .deployment_id(1)
+            .status(DeploymentStatus::Success)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditDeploymentBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -8,7 +8,7 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::{EnableState, NameOrId, VisibilityLevel};
+use crate::api::common::{self, EnableState, NameOrId, PathParam, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::{
     AutoDevOpsDeployStrategy, BuildGitStrategy, ContainerExpirationPolicy, FeatureAccessLevel,
@@ -17,7 +17,7 @@ use crate::api::projects::{
 
 /// Edit an existing project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditProject<'a> {
     /// The project to edit.
     #[builder(setter(into))]
@@ -326,6 +326,14 @@ impl<'a> EditProject<'a> {
     }
 }
 
+impl<'a> EditProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), EditProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> EditProjectBuilder<'a> {
     /// Add a tag.
     #[deprecated(note = "use `topic` instead")]
@@ -390,7 +398,7 @@ impl<'a> Endpoint for EditProject<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}", self.project).into()
+        format!("projects/{}", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -504,8 +512,16 @@ impl<'a> Endpoint for EditProject<'a> {
             )
             .push_opt("lfs_enabled", self.lfs_enabled)
             .push_opt("request_access_enabled", self.request_access_enabled)
-            .extend(self.tag_list.iter().map(|value| ("tag_list[]", value)))
-            .extend(self.topics.iter().map(|value| ("topics[]", value)))
+            .extend(
+                self.tag_list
+                    .iter()
+                    .map(|value| (ParamName::new("tag_list").array(), value)),
+            )
+            .extend(
+                self.topics
+                    .iter()
+                    .map(|value| (ParamName::new("topics").array(), value)),
+            )
             .push_opt("build_git_strategy", self.build_git_strategy)
             .push_opt("build_timeout", self.build_timeout)
             .push_opt(
@@ -613,6 +629,12 @@ mod tests {
         EditProject::builder().project("project").build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditProject::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditProjectBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
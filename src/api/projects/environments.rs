@@ -6,13 +6,36 @@
 
 //! Project environments API endpoints.
 //!
-//! These endpoints are used for querying environments.
+//! These endpoints are used for querying and managing environments.
 
+mod create;
+mod delete;
+mod delete_review_apps;
+mod edit;
 mod environment;
 mod environments;
+mod stop;
+mod stop_stale;
 
 pub use self::environments::EnvironmentState;
 
+pub use self::create::CreateEnvironment;
+pub use self::create::CreateEnvironmentBuilder;
+pub use self::create::CreateEnvironmentBuilderError;
+pub use self::create::EnvironmentTier;
+
+pub use self::delete::DeleteEnvironment;
+pub use self::delete::DeleteEnvironmentBuilder;
+pub use self::delete::DeleteEnvironmentBuilderError;
+
+pub use self::delete_review_apps::DeleteStoppedReviewApps;
+pub use self::delete_review_apps::DeleteStoppedReviewAppsBuilder;
+pub use self::delete_review_apps::DeleteStoppedReviewAppsBuilderError;
+
+pub use self::edit::EditEnvironment;
+pub use self::edit::EditEnvironmentBuilder;
+pub use self::edit::EditEnvironmentBuilderError;
+
 pub use self::environment::Environment;
 pub use self::environment::EnvironmentBuilder;
 pub use self::environment::EnvironmentBuilderError;
@@ -20,3 +43,11 @@ pub use self::environment::EnvironmentBuilderError;
 pub use self::environments::Environments;
 pub use self::environments::EnvironmentsBuilder;
 pub use self::environments::EnvironmentsBuilderError;
+
+pub use self::stop::StopEnvironment;
+pub use self::stop::StopEnvironmentBuilder;
+pub use self::stop::StopEnvironmentBuilderError;
+
+pub use self::stop_stale::StopStaleEnvironments;
+pub use self::stop_stale::StopStaleEnvironmentsBuilder;
+pub use self::stop_stale::StopStaleEnvironmentsBuilderError;
@@ -0,0 +1,324 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Deployment tiers of environments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum EnvironmentTier {
+    /// Production environments.
+    Production,
+    /// Staging environments.
+    Staging,
+    /// Testing environments.
+    Testing,
+    /// Development environments.
+    Development,
+    /// Other environments.
+    Other,
+}
+
+impl EnvironmentTier {
+    fn as_str(self) -> &'static str {
+        match self {
+            EnvironmentTier::Production => "production",
+            EnvironmentTier::Staging => "staging",
+            EnvironmentTier::Testing => "testing",
+            EnvironmentTier::Development => "development",
+            EnvironmentTier::Other => "other",
+        }
+    }
+}
+
+impl ParamValue<'static> for EnvironmentTier {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Create an environment within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateEnvironment<'a> {
+    /// The project to create the environment within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the environment.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// A link to the environment.
+    #[builder(setter(into), default)]
+    external_url: Option<Cow<'a, str>>,
+    /// The tier of the environment.
+    #[builder(default)]
+    tier: Option<EnvironmentTier>,
+    /// The description of the environment.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The ID of the cluster agent to associate with the environment.
+    #[builder(default)]
+    cluster_agent_id: Option<u64>,
+    /// The Kubernetes namespace to associate with the environment.
+    #[builder(setter(into), default)]
+    kubernetes_namespace: Option<Cow<'a, str>>,
+    /// The Flux resource path to associate with the environment.
+    #[builder(setter(into), default)]
+    flux_resource_path: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateEnvironmentBuilder<'a> {
+        CreateEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> CreateEnvironmentBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateEnvironmentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/environments", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push_opt("external_url", self.external_url.as_ref())
+            .push_opt("tier", self.tier)
+            .push_opt("description", self.description.as_ref())
+            .push_opt("cluster_agent_id", self.cluster_agent_id)
+            .push_opt("kubernetes_namespace", self.kubernetes_namespace.as_ref())
+            .push_opt("flux_resource_path", self.flux_resource_path.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::environments::{
+        CreateEnvironment, CreateEnvironmentBuilderError, EnvironmentTier,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn environment_tier_as_str() {
+        let items = &[
+            (EnvironmentTier::Production, "production"),
+            (EnvironmentTier::Staging, "staging"),
+            (EnvironmentTier::Testing, "testing"),
+            (EnvironmentTier::Development, "development"),
+            (EnvironmentTier::Other, "other"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateEnvironment::builder()
+            .name("review")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateEnvironment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateEnvironmentBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateEnvironment::builder()
+            .project(1)
+            .name("review")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateEnvironment::builder()
+            .project(0)
+            .name("review")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=review")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_external_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=review",
+                "&external_url=https%3A%2F%2Freview.example.com",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .external_url("https://review.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tier() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=review", "&tier=development"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .tier(EnvironmentTier::Development)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=review", "&description=desc"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cluster_agent_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=review", "&cluster_agent_id=1"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .cluster_agent_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_kubernetes_namespace() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=review", "&kubernetes_namespace=review"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .kubernetes_namespace("review")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_flux_resource_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=review", "&flux_resource_path=path"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateEnvironment::builder()
+            .project("simple/project")
+            .name("review")
+            .flux_resource_path("path")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
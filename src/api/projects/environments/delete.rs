@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete an environment within a project.
+///
+/// Only stopped environments may be deleted.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteEnvironment<'a> {
+    /// The project to delete the environment from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the environment.
+    environment: u64,
+}
+
+impl<'a> DeleteEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteEnvironmentBuilder<'a> {
+        DeleteEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> DeleteEnvironmentBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteEnvironmentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("environment", self.environment.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/{}",
+            self.project.path_segment(),
+            self.environment.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::environments::{DeleteEnvironment, DeleteEnvironmentBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_environment_are_needed() {
+        let err = DeleteEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteEnvironment::builder()
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn environment_is_needed() {
+        let err = DeleteEnvironment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteEnvironmentBuilderError, "environment");
+    }
+
+    #[test]
+    fn project_and_environment_are_sufficient() {
+        DeleteEnvironment::builder()
+            .project(1)
+            .environment(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteEnvironment::builder()
+            .project(0)
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete stopped review app environments within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct DeleteStoppedReviewApps<'a> {
+    /// The project to delete review apps from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Delete review apps stopped before this time.
+    ///
+    /// Defaults to 30 days ago.
+    #[builder(default)]
+    before: Option<DateTime<Utc>>,
+    /// The maximum number of environments to delete.
+    ///
+    /// Defaults to 100.
+    #[builder(default)]
+    limit: Option<u64>,
+    /// Only report the environments which would be deleted.
+    ///
+    /// GitLab defaults to `true` for safety, so this must be set to `false` to actually delete
+    /// anything.
+    #[builder(default)]
+    dry_run: Option<bool>,
+}
+
+impl<'a> DeleteStoppedReviewApps<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteStoppedReviewAppsBuilder<'a> {
+        DeleteStoppedReviewAppsBuilder::default()
+    }
+}
+
+impl<'a> DeleteStoppedReviewAppsBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteStoppedReviewAppsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteStoppedReviewApps<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/review_apps",
+            self.project.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("before", self.before)
+            .push_opt("limit", self.limit)
+            .push_opt("dry_run", self.dry_run);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::environments::{
+        DeleteStoppedReviewApps, DeleteStoppedReviewAppsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteStoppedReviewApps::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteStoppedReviewAppsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        DeleteStoppedReviewApps::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteStoppedReviewApps::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteStoppedReviewAppsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/environments/review_apps")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteStoppedReviewApps::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_before() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/environments/review_apps")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("before=2020-01-01T00%3A00%3A00Z")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteStoppedReviewApps::builder()
+            .project("simple/project")
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/environments/review_apps")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("limit=10")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteStoppedReviewApps::builder()
+            .project("simple/project")
+            .limit(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/environments/review_apps")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("dry_run=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteStoppedReviewApps::builder()
+            .project("simple/project")
+            .dry_run(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
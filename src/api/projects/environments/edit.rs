@@ -0,0 +1,275 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::environments::EnvironmentTier;
+
+/// Edit an environment within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditEnvironment<'a> {
+    /// The project to edit the environment within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the environment.
+    environment: u64,
+
+    /// A link to the environment.
+    #[builder(setter(into), default)]
+    external_url: Option<Cow<'a, str>>,
+    /// The tier of the environment.
+    #[builder(default)]
+    tier: Option<EnvironmentTier>,
+    /// The description of the environment.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The ID of the cluster agent to associate with the environment.
+    #[builder(default)]
+    cluster_agent_id: Option<u64>,
+    /// The Kubernetes namespace to associate with the environment.
+    #[builder(setter(into), default)]
+    kubernetes_namespace: Option<Cow<'a, str>>,
+    /// The Flux resource path to associate with the environment.
+    #[builder(setter(into), default)]
+    flux_resource_path: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditEnvironmentBuilder<'a> {
+        EditEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> EditEnvironmentBuilder<'a> {
+    fn validate(&self) -> Result<(), EditEnvironmentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("environment", self.environment.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/{}",
+            self.project.path_segment(),
+            self.environment.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("external_url", self.external_url.as_ref())
+            .push_opt("tier", self.tier)
+            .push_opt("description", self.description.as_ref())
+            .push_opt("cluster_agent_id", self.cluster_agent_id)
+            .push_opt("kubernetes_namespace", self.kubernetes_namespace.as_ref())
+            .push_opt("flux_resource_path", self.flux_resource_path.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::environments::{
+        EditEnvironment, EditEnvironmentBuilderError, EnvironmentTier,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_environment_are_needed() {
+        let err = EditEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditEnvironment::builder()
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn environment_is_needed() {
+        let err = EditEnvironment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditEnvironmentBuilderError, "environment");
+    }
+
+    #[test]
+    fn project_and_environment_are_sufficient() {
+        EditEnvironment::builder()
+            .project(1)
+            .environment(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditEnvironment::builder()
+            .project(0)
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_external_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("external_url=https%3A%2F%2Freview.example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .external_url("https://review.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_tier() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("tier=staging")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .tier(EnvironmentTier::Staging)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=desc")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cluster_agent_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("cluster_agent_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .cluster_agent_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_kubernetes_namespace() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("kubernetes_namespace=review")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .kubernetes_namespace("review")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_flux_resource_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/environments/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("flux_resource_path=path")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .flux_resource_path("path")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for an environment within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Environment<'a> {
     /// The project to query for the environment.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> Environment<'a> {
     }
 }
 
+impl<'a> EnvironmentBuilder<'a> {
+    fn validate(&self) -> Result<(), EnvironmentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("environment", self.environment.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Environment<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for Environment<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/environments/{}",
-            self.project, self.environment,
+            self.project.path_segment(),
+            self.environment.path_segment(),
         )
         .into()
     }
@@ -73,6 +84,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Environment::builder()
+            .project(0)
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EnvironmentBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
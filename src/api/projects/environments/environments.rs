@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -46,7 +46,7 @@ impl ParamValue<'static> for EnvironmentState {
 
 /// Query for environments within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Environments<'a> {
     /// The project to query for environments.
     #[builder(setter(into))]
@@ -90,6 +90,12 @@ impl<'a> EnvironmentsBuilder<'a> {
         self.name_or_search = Some(Some(NameOrSearch::Search(search.into())));
         self
     }
+
+    fn validate(&self) -> Result<(), EnvironmentsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for Environments<'a> {
@@ -98,7 +104,7 @@ impl<'a> Endpoint for Environments<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/environments", self.project).into()
+        format!("projects/{}/environments", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -135,6 +141,7 @@ mod tests {
     fn environment_state_as_str() {
         let items = &[
             (EnvironmentState::Available, "available"),
+            (EnvironmentState::Stopping, "stopping"),
             (EnvironmentState::Stopped, "stopped"),
         ];
 
@@ -154,6 +161,12 @@ mod tests {
         Environments::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Environments::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EnvironmentsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,151 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Stop an environment within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct StopEnvironment<'a> {
+    /// The project to stop the environment within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the environment.
+    environment: u64,
+
+    /// Force the environment to stop without executing `on_stop` actions.
+    #[builder(default)]
+    force: Option<bool>,
+}
+
+impl<'a> StopEnvironment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StopEnvironmentBuilder<'a> {
+        StopEnvironmentBuilder::default()
+    }
+}
+
+impl<'a> StopEnvironmentBuilder<'a> {
+    fn validate(&self) -> Result<(), StopEnvironmentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("environment", self.environment.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for StopEnvironment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/{}/stop",
+            self.project.path_segment(),
+            self.environment.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("force", self.force);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::environments::{StopEnvironment, StopEnvironmentBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_environment_are_needed() {
+        let err = StopEnvironment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, StopEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = StopEnvironment::builder()
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, StopEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn environment_is_needed() {
+        let err = StopEnvironment::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, StopEnvironmentBuilderError, "environment");
+    }
+
+    #[test]
+    fn project_and_environment_are_sufficient() {
+        StopEnvironment::builder()
+            .project(1)
+            .environment(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = StopEnvironment::builder()
+            .project(0)
+            .environment(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, StopEnvironmentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments/1/stop")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StopEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_force() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments/1/stop")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("force=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StopEnvironment::builder()
+            .project("simple/project")
+            .environment(1)
+            .force(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Stop environments within a project which have not been updated since a given time.
+///
+/// Protected environments are ignored.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct StopStaleEnvironments<'a> {
+    /// The project to stop stale environments within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// Stop environments last updated before this time.
+    before: DateTime<Utc>,
+}
+
+impl<'a> StopStaleEnvironments<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StopStaleEnvironmentsBuilder<'a> {
+        StopStaleEnvironmentsBuilder::default()
+    }
+}
+
+impl<'a> StopStaleEnvironmentsBuilder<'a> {
+    fn validate(&self) -> Result<(), StopStaleEnvironmentsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for StopStaleEnvironments<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/environments/stop_stale",
+            self.project.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("before", self.before);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::environments::{
+        StopStaleEnvironments, StopStaleEnvironmentsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_before_are_needed() {
+        let err = StopStaleEnvironments::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, StopStaleEnvironmentsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = StopStaleEnvironments::builder()
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, StopStaleEnvironmentsBuilderError, "project");
+    }
+
+    #[test]
+    fn before_is_needed() {
+        let err = StopStaleEnvironments::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, StopStaleEnvironmentsBuilderError, "before");
+    }
+
+    #[test]
+    fn project_and_before_are_sufficient() {
+        StopStaleEnvironments::builder()
+            .project(1)
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = StopStaleEnvironments::builder()
+            .project(0)
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, StopStaleEnvironmentsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/environments/stop_stale")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("before=2020-01-01T00%3A00%3A00Z")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StopStaleEnvironments::builder()
+            .project("simple/project")
+            .before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project error tracking API endpoints.
+//!
+//! These endpoints are used for managing the error tracking settings of a project and the client
+//! keys used to report errors to GitLab's integrated error tracking.
+
+mod client_keys;
+mod create_client_key;
+mod delete_client_key;
+mod edit_settings;
+mod settings;
+
+pub use self::client_keys::ErrorTrackingClientKeys;
+pub use self::client_keys::ErrorTrackingClientKeysBuilder;
+pub use self::client_keys::ErrorTrackingClientKeysBuilderError;
+
+pub use self::create_client_key::CreateErrorTrackingClientKey;
+pub use self::create_client_key::CreateErrorTrackingClientKeyBuilder;
+pub use self::create_client_key::CreateErrorTrackingClientKeyBuilderError;
+
+pub use self::delete_client_key::DeleteErrorTrackingClientKey;
+pub use self::delete_client_key::DeleteErrorTrackingClientKeyBuilder;
+pub use self::delete_client_key::DeleteErrorTrackingClientKeyBuilderError;
+
+pub use self::edit_settings::EditErrorTrackingSettings;
+pub use self::edit_settings::EditErrorTrackingSettingsBuilder;
+pub use self::edit_settings::EditErrorTrackingSettingsBuilderError;
+
+pub use self::settings::ErrorTrackingSettings;
+pub use self::settings::ErrorTrackingSettingsBuilder;
+pub use self::settings::ErrorTrackingSettingsBuilderError;
@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the error tracking client keys of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ErrorTrackingClientKeys<'a> {
+    /// The project to query.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ErrorTrackingClientKeys<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ErrorTrackingClientKeysBuilder<'a> {
+        ErrorTrackingClientKeysBuilder::default()
+    }
+}
+
+impl<'a> ErrorTrackingClientKeysBuilder<'a> {
+    fn validate(&self) -> Result<(), ErrorTrackingClientKeysBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ErrorTrackingClientKeys<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/error_tracking/client_keys",
+            self.project.path_segment()
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for ErrorTrackingClientKeys<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::error_tracking::{
+        ErrorTrackingClientKeys, ErrorTrackingClientKeysBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ErrorTrackingClientKeys::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ErrorTrackingClientKeysBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ErrorTrackingClientKeys::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ErrorTrackingClientKeys::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            ErrorTrackingClientKeysBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/error_tracking/client_keys")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ErrorTrackingClientKeys::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create an error tracking client key for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateErrorTrackingClientKey<'a> {
+    /// The project to create the key within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> CreateErrorTrackingClientKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateErrorTrackingClientKeyBuilder<'a> {
+        CreateErrorTrackingClientKeyBuilder::default()
+    }
+}
+
+impl<'a> CreateErrorTrackingClientKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateErrorTrackingClientKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateErrorTrackingClientKey<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/error_tracking/client_keys",
+            self.project.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::error_tracking::{
+        CreateErrorTrackingClientKey, CreateErrorTrackingClientKeyBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateErrorTrackingClientKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateErrorTrackingClientKeyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        CreateErrorTrackingClientKey::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateErrorTrackingClientKey::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateErrorTrackingClientKeyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/error_tracking/client_keys")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateErrorTrackingClientKey::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
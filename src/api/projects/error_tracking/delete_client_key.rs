@@ -0,0 +1,135 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete an error tracking client key from a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteErrorTrackingClientKey<'a> {
+    /// The project to delete the key from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the client key.
+    key: u64,
+}
+
+impl<'a> DeleteErrorTrackingClientKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteErrorTrackingClientKeyBuilder<'a> {
+        DeleteErrorTrackingClientKeyBuilder::default()
+    }
+}
+
+impl<'a> DeleteErrorTrackingClientKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteErrorTrackingClientKeyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteErrorTrackingClientKey<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/error_tracking/client_keys/{}",
+            self.project.path_segment(),
+            self.key.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::error_tracking::{
+        DeleteErrorTrackingClientKey, DeleteErrorTrackingClientKeyBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_key_are_needed() {
+        let err = DeleteErrorTrackingClientKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteErrorTrackingClientKeyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteErrorTrackingClientKey::builder()
+            .key(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteErrorTrackingClientKeyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn key_is_needed() {
+        let err = DeleteErrorTrackingClientKey::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteErrorTrackingClientKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn project_and_key_are_sufficient() {
+        DeleteErrorTrackingClientKey::builder()
+            .project(1)
+            .key(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteErrorTrackingClientKey::builder()
+            .project(0)
+            .key(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteErrorTrackingClientKeyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/error_tracking/client_keys/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteErrorTrackingClientKey::builder()
+            .project("simple/project")
+            .key(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
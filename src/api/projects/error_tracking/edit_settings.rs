@@ -0,0 +1,159 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit the error tracking settings of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditErrorTrackingSettings<'a> {
+    /// The project to edit.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// Whether error tracking is enabled or not.
+    active: bool,
+
+    /// Whether to use GitLab's integrated error tracking rather than Sentry.
+    #[builder(default)]
+    integrated: Option<bool>,
+}
+
+impl<'a> EditErrorTrackingSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditErrorTrackingSettingsBuilder<'a> {
+        EditErrorTrackingSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditErrorTrackingSettingsBuilder<'a> {
+    fn validate(&self) -> Result<(), EditErrorTrackingSettingsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditErrorTrackingSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/error_tracking/settings",
+            self.project.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("active", self.active)
+            .push_opt("integrated", self.integrated);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::error_tracking::{
+        EditErrorTrackingSettings, EditErrorTrackingSettingsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_active_are_needed() {
+        let err = EditErrorTrackingSettings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditErrorTrackingSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditErrorTrackingSettings::builder()
+            .active(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditErrorTrackingSettingsBuilderError, "project");
+    }
+
+    #[test]
+    fn active_is_needed() {
+        let err = EditErrorTrackingSettings::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditErrorTrackingSettingsBuilderError, "active");
+    }
+
+    #[test]
+    fn project_and_active_are_sufficient() {
+        EditErrorTrackingSettings::builder()
+            .project(1)
+            .active(true)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditErrorTrackingSettings::builder()
+            .project(0)
+            .active(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditErrorTrackingSettingsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/error_tracking/settings")
+            .form_params(&[("active", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditErrorTrackingSettings::builder()
+            .project("simple/project")
+            .active(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_integrated() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/error_tracking/settings")
+            .form_params(&[("active", "true"), ("integrated", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditErrorTrackingSettings::builder()
+            .project("simple/project")
+            .active(true)
+            .integrated(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
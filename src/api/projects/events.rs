@@ -0,0 +1,205 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::events::{EventAction, EventTargetType};
+
+/// Query for events of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectEvents<'a> {
+    /// The project to query for events.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter events by action.
+    #[builder(default)]
+    action: Option<EventAction>,
+    /// Filter events by target type.
+    #[builder(default)]
+    target_type: Option<EventTargetType>,
+    /// Only return events created before the given date.
+    #[builder(default)]
+    before: Option<NaiveDate>,
+    /// Only return events created after the given date.
+    #[builder(default)]
+    after: Option<NaiveDate>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> ProjectEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectEventsBuilder<'a> {
+        ProjectEventsBuilder::default()
+    }
+}
+
+impl<'a> ProjectEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectEventsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/events", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("action", self.action)
+            .push_opt("target_type", self.target_type)
+            .push_opt("before", self.before)
+            .push_opt("after", self.after)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::api::common::SortOrder;
+    use crate::api::events::{EventAction, EventTargetType};
+    use crate::api::projects::{ProjectEvents, ProjectEventsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectEventsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectEvents::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectEvents::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectEventsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectEvents::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_action() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/events")
+            .add_query_params(&[("action", "merged")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectEvents::builder()
+            .project("simple/project")
+            .action(EventAction::Merged)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_target_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/events")
+            .add_query_params(&[("target_type", "issue")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectEvents::builder()
+            .project("simple/project")
+            .target_type(EventTargetType::Issue)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/events")
+            .add_query_params(&[("before", "2024-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectEvents::builder()
+            .project("simple/project")
+            .before(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/events")
+            .add_query_params(&[("after", "2024-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectEvents::builder()
+            .project("simple/project")
+            .after(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/events")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectEvents::builder()
+            .project("simple/project")
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project feature flag user list API endpoints.
+//!
+//! These endpoints are used for managing lists of users which may be targeted by feature flag
+//! strategies.
+
+mod create;
+mod delete;
+mod edit;
+mod user_list;
+mod user_lists;
+
+pub use self::create::CreateFeatureFlagUserList;
+pub use self::create::CreateFeatureFlagUserListBuilder;
+pub use self::create::CreateFeatureFlagUserListBuilderError;
+
+pub use self::delete::DeleteFeatureFlagUserList;
+pub use self::delete::DeleteFeatureFlagUserListBuilder;
+pub use self::delete::DeleteFeatureFlagUserListBuilderError;
+
+pub use self::edit::EditFeatureFlagUserList;
+pub use self::edit::EditFeatureFlagUserListBuilder;
+pub use self::edit::EditFeatureFlagUserListBuilderError;
+
+pub use self::user_list::FeatureFlagUserList;
+pub use self::user_list::FeatureFlagUserListBuilder;
+pub use self::user_list::FeatureFlagUserListBuilderError;
+
+pub use self::user_lists::FeatureFlagUserLists;
+pub use self::user_lists::FeatureFlagUserListsBuilder;
+pub use self::user_lists::FeatureFlagUserListsBuilderError;
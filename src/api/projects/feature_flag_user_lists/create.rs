@@ -0,0 +1,180 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create a feature flag user list within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateFeatureFlagUserList<'a> {
+    /// The project to create the user list within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the user list.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The external user IDs in the list.
+    #[builder(setter(name = "_user_xids"), private)]
+    user_xids: CommaSeparatedList<Cow<'a, str>>,
+}
+
+impl<'a> CreateFeatureFlagUserList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateFeatureFlagUserListBuilder<'a> {
+        CreateFeatureFlagUserListBuilder::default()
+    }
+}
+
+impl<'a> CreateFeatureFlagUserListBuilder<'a> {
+    /// Add a user to the list.
+    pub fn user_xid<U>(&mut self, user_xid: U) -> &mut Self
+    where
+        U: Into<Cow<'a, str>>,
+    {
+        self.user_xids
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(user_xid.into());
+        self
+    }
+
+    /// Add multiple users to the list.
+    pub fn user_xids<I, U>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = U>,
+        U: Into<Cow<'a, str>>,
+    {
+        self.user_xids
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateFeatureFlagUserListBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateFeatureFlagUserList<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags_user_lists",
+            self.project.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push("user_xids", &self.user_xids);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flag_user_lists::{
+        CreateFeatureFlagUserList, CreateFeatureFlagUserListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_name_and_user_xids_are_needed() {
+        let err = CreateFeatureFlagUserList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateFeatureFlagUserList::builder()
+            .name("beta")
+            .user_xid("user1")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateFeatureFlagUserList::builder()
+            .project(1)
+            .user_xid("user1")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagUserListBuilderError, "name");
+    }
+
+    #[test]
+    fn user_xids_are_needed() {
+        let err = CreateFeatureFlagUserList::builder()
+            .project(1)
+            .name("beta")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagUserListBuilderError, "user_xids");
+    }
+
+    #[test]
+    fn project_name_and_user_xids_are_sufficient() {
+        CreateFeatureFlagUserList::builder()
+            .project(1)
+            .name("beta")
+            .user_xid("user1")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateFeatureFlagUserList::builder()
+            .project(0)
+            .name("beta")
+            .user_xid("user1")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateFeatureFlagUserListBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=beta", "&user_xids=user1%2Cuser2%2Cuser3"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlagUserList::builder()
+            .project("simple/project")
+            .name("beta")
+            .user_xid("user1")
+            .user_xids(["user2", "user3"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
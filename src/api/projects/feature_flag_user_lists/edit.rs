@@ -0,0 +1,213 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit a feature flag user list within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditFeatureFlagUserList<'a> {
+    /// The project to edit the user list within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the user list.
+    user_list: u64,
+
+    /// The name of the user list.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The external user IDs in the list.
+    ///
+    /// This replaces the existing set of users.
+    #[builder(setter(name = "_user_xids"), default, private)]
+    user_xids: Option<CommaSeparatedList<Cow<'a, str>>>,
+}
+
+impl<'a> EditFeatureFlagUserList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditFeatureFlagUserListBuilder<'a> {
+        EditFeatureFlagUserListBuilder::default()
+    }
+}
+
+impl<'a> EditFeatureFlagUserListBuilder<'a> {
+    /// Add a user to the list.
+    pub fn user_xid<U>(&mut self, user_xid: U) -> &mut Self
+    where
+        U: Into<Cow<'a, str>>,
+    {
+        self.user_xids
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(user_xid.into());
+        self
+    }
+
+    /// Add multiple users to the list.
+    pub fn user_xids<I, U>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = U>,
+        U: Into<Cow<'a, str>>,
+    {
+        self.user_xids
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), EditFeatureFlagUserListBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user_list", self.user_list.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditFeatureFlagUserList<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags_user_lists/{}",
+            self.project.path_segment(),
+            self.user_list.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("user_xids", self.user_xids.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flag_user_lists::{
+        EditFeatureFlagUserList, EditFeatureFlagUserListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_user_list_are_needed() {
+        let err = EditFeatureFlagUserList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditFeatureFlagUserList::builder()
+            .user_list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn user_list_is_needed() {
+        let err = EditFeatureFlagUserList::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagUserListBuilderError, "user_list");
+    }
+
+    #[test]
+    fn project_and_user_list_are_sufficient() {
+        EditFeatureFlagUserList::builder()
+            .project(1)
+            .user_list(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditFeatureFlagUserList::builder()
+            .project(0)
+            .user_list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditFeatureFlagUserListBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlagUserList::builder()
+            .project("simple/project")
+            .user_list(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=beta")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlagUserList::builder()
+            .project("simple/project")
+            .user_list(1)
+            .name("beta")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_user_xids() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("user_xids=user1%2Cuser2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlagUserList::builder()
+            .project("simple/project")
+            .user_list(1)
+            .user_xid("user1")
+            .user_xids(["user2"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a feature flag user list within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct FeatureFlagUserList<'a> {
+    /// The project to query for the user list.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the user list.
+    user_list: u64,
+}
+
+impl<'a> FeatureFlagUserList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FeatureFlagUserListBuilder<'a> {
+        FeatureFlagUserListBuilder::default()
+    }
+}
+
+impl<'a> FeatureFlagUserListBuilder<'a> {
+    fn validate(&self) -> Result<(), FeatureFlagUserListBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user_list", self.user_list.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for FeatureFlagUserList<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags_user_lists/{}",
+            self.project.path_segment(),
+            self.user_list.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::feature_flag_user_lists::{
+        FeatureFlagUserList, FeatureFlagUserListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_user_list_are_needed() {
+        let err = FeatureFlagUserList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = FeatureFlagUserList::builder()
+            .user_list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn user_list_is_needed() {
+        let err = FeatureFlagUserList::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagUserListBuilderError, "user_list");
+    }
+
+    #[test]
+    fn project_and_user_list_are_sufficient() {
+        FeatureFlagUserList::builder()
+            .project(1)
+            .user_list(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = FeatureFlagUserList::builder()
+            .project(0)
+            .user_list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, FeatureFlagUserListBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureFlagUserList::builder()
+            .project("simple/project")
+            .user_list(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for feature flag user lists within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct FeatureFlagUserLists<'a> {
+    /// The project to query for user lists.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Search for user lists with names containing a string.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+}
+
+impl<'a> FeatureFlagUserLists<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FeatureFlagUserListsBuilder<'a> {
+        FeatureFlagUserListsBuilder::default()
+    }
+}
+
+impl<'a> FeatureFlagUserListsBuilder<'a> {
+    fn validate(&self) -> Result<(), FeatureFlagUserListsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for FeatureFlagUserLists<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags_user_lists",
+            self.project.path_segment()
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("search", self.search.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for FeatureFlagUserLists<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::feature_flag_user_lists::{
+        FeatureFlagUserLists, FeatureFlagUserListsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = FeatureFlagUserLists::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FeatureFlagUserListsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        FeatureFlagUserLists::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = FeatureFlagUserLists::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, FeatureFlagUserListsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureFlagUserLists::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/feature_flags_user_lists")
+            .add_query_params(&[("search", "beta")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FeatureFlagUserLists::builder()
+            .project("simple/project")
+            .search("beta")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
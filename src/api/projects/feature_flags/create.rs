@@ -0,0 +1,428 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+use serde_json::json;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Strategies for rolling out a feature flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeatureFlagStrategyName {
+    /// Enable the flag for everyone.
+    Default,
+    /// Enable the flag for a percentage of users based on their ID.
+    ///
+    /// Uses the `groupId` and `percentage` parameters.
+    GradualRolloutUserId,
+    /// Enable the flag for specific users.
+    ///
+    /// Uses the `userIds` parameter.
+    UserWithId,
+    /// Enable the flag for the users in a feature flag user list.
+    ///
+    /// Uses the `user_list_id` field of the strategy.
+    GitlabUserList,
+    /// Enable the flag for a percentage of sessions, users, or randomly.
+    ///
+    /// Uses the `groupId`, `rollout`, and `stickiness` parameters.
+    FlexibleRollout,
+}
+
+impl FeatureFlagStrategyName {
+    fn as_str(self) -> &'static str {
+        match self {
+            FeatureFlagStrategyName::Default => "default",
+            FeatureFlagStrategyName::GradualRolloutUserId => "gradualRolloutUserId",
+            FeatureFlagStrategyName::UserWithId => "userWithId",
+            FeatureFlagStrategyName::GitlabUserList => "gitlabUserList",
+            FeatureFlagStrategyName::FlexibleRollout => "flexibleRollout",
+        }
+    }
+}
+
+/// A strategy for a feature flag.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct FeatureFlagStrategy<'a> {
+    /// The ID of an existing strategy.
+    ///
+    /// Only used when editing a feature flag.
+    #[builder(default)]
+    id: Option<u64>,
+    /// The name of the strategy.
+    ///
+    /// Required for new strategies.
+    #[builder(default)]
+    name: Option<FeatureFlagStrategyName>,
+    /// Parameters for the strategy.
+    #[builder(setter(name = "_parameters"), default, private)]
+    parameters: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+    /// The environment scopes the strategy applies to.
+    #[builder(setter(name = "_scopes"), default, private)]
+    scopes: Vec<Cow<'a, str>>,
+    /// The ID of the user list for the `gitlabUserList` strategy.
+    #[builder(default)]
+    user_list_id: Option<u64>,
+    /// Remove the (existing) strategy from the feature flag.
+    ///
+    /// Only used when editing a feature flag.
+    #[builder(default)]
+    destroy: Option<bool>,
+}
+
+impl<'a> FeatureFlagStrategy<'a> {
+    /// Create a builder for the strategy.
+    pub fn builder() -> FeatureFlagStrategyBuilder<'a> {
+        FeatureFlagStrategyBuilder::default()
+    }
+
+    pub(crate) fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "id": self.id,
+            "name": self.name.map(|n| n.as_str()),
+            "parameters": self.parameters,
+            "scopes": self.scopes
+                .iter()
+                .map(|scope| {
+                    json!({
+                        "environment_scope": scope,
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "user_list_id": self.user_list_id,
+            "_destroy": self.destroy,
+        }))
+    }
+}
+
+impl<'a> FeatureFlagStrategyBuilder<'a> {
+    /// Add a parameter for the strategy.
+    pub fn parameter<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.parameters
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Add multiple parameters for the strategy.
+    pub fn parameters<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.parameters
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Apply the strategy to an environment scope.
+    pub fn scope<S>(&mut self, scope: S) -> &mut Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.scopes.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Apply the strategy to multiple environment scopes.
+    pub fn scopes<I, S>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        self.scopes
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+/// Create a feature flag within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateFeatureFlag<'a> {
+    /// The project to create the feature flag within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The description of the feature flag.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the feature flag is active.
+    #[builder(default)]
+    active: Option<bool>,
+    /// The strategies of the feature flag.
+    #[builder(setter(name = "_strategies"), default, private)]
+    strategies: Vec<FeatureFlagStrategy<'a>>,
+}
+
+impl<'a> CreateFeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateFeatureFlagBuilder<'a> {
+        CreateFeatureFlagBuilder::default()
+    }
+
+    fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "name": self.name,
+            "description": self.description,
+            "active": self.active,
+            "strategies": self.strategies
+                .iter()
+                .map(|s| s.as_json())
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+impl<'a> CreateFeatureFlagBuilder<'a> {
+    /// Add a strategy to the feature flag.
+    pub fn strategy(&mut self, strategy: FeatureFlagStrategy<'a>) -> &mut Self {
+        self.strategies.get_or_insert_with(Vec::new).push(strategy);
+        self
+    }
+
+    /// Add multiple strategies to the feature flag.
+    pub fn strategies<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = FeatureFlagStrategy<'a>>,
+    {
+        self.strategies.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateFeatureFlagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateFeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/feature_flags", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        JsonParams::into_body(&self.as_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flags::{
+        CreateFeatureFlag, CreateFeatureFlagBuilderError, FeatureFlagStrategy,
+        FeatureFlagStrategyName,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn feature_flag_strategy_name_as_str() {
+        let items = &[
+            (FeatureFlagStrategyName::Default, "default"),
+            (
+                FeatureFlagStrategyName::GradualRolloutUserId,
+                "gradualRolloutUserId",
+            ),
+            (FeatureFlagStrategyName::UserWithId, "userWithId"),
+            (FeatureFlagStrategyName::GitlabUserList, "gitlabUserList"),
+            (FeatureFlagStrategyName::FlexibleRollout, "flexibleRollout"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn strategy_defaults_are_sufficient() {
+        FeatureFlagStrategy::builder().build().unwrap();
+    }
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateFeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateFeatureFlag::builder()
+            .name("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateFeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFeatureFlagBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateFeatureFlag::builder()
+            .project(1)
+            .name("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateFeatureFlag::builder()
+            .project(0)
+            .name("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str(r#"{"name":"new_ui"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("new_ui")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str(r#"{"description":"desc","name":"new_ui"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("new_ui")
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_active() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str(r#"{"active":false,"name":"new_ui"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("new_ui")
+            .active(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_strategies() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/feature_flags")
+            .content_type("application/json")
+            .body_str(concat!(
+                "{",
+                "\"name\":\"new_ui\",",
+                "\"strategies\":[",
+                "{",
+                "\"name\":\"flexibleRollout\",",
+                "\"parameters\":{",
+                "\"groupId\":\"default\",",
+                "\"rollout\":\"50\",",
+                "\"stickiness\":\"default\"",
+                "},",
+                "\"scopes\":[",
+                "{\"environment_scope\":\"production\"},",
+                "{\"environment_scope\":\"staging\"}",
+                "]",
+                "},",
+                "{",
+                "\"name\":\"gitlabUserList\",",
+                "\"user_list_id\":3",
+                "}",
+                "]",
+                "}",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFeatureFlag::builder()
+            .project("simple/project")
+            .name("new_ui")
+            .strategy(
+                FeatureFlagStrategy::builder()
+                    .name(FeatureFlagStrategyName::FlexibleRollout)
+                    .parameter("rollout", "50")
+                    .parameters(
+                        [("groupId", "default"), ("stickiness", "default")]
+                            .iter()
+                            .cloned(),
+                    )
+                    .scope("production")
+                    .scopes(["staging"].iter().cloned())
+                    .build()
+                    .unwrap(),
+            )
+            .strategies(
+                [FeatureFlagStrategy::builder()
+                    .name(FeatureFlagStrategyName::GitlabUserList)
+                    .user_list_id(3)
+                    .build()
+                    .unwrap()]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a feature flag within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteFeatureFlag<'a> {
+    /// The project to delete the feature flag from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    feature_flag: Cow<'a, str>,
+}
+
+impl<'a> DeleteFeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteFeatureFlagBuilder<'a> {
+        DeleteFeatureFlagBuilder::default()
+    }
+}
+
+impl<'a> DeleteFeatureFlagBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteFeatureFlagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("feature_flag", self.feature_flag.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteFeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags/{}",
+            self.project.path_segment(),
+            self.feature_flag.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flags::{DeleteFeatureFlag, DeleteFeatureFlagBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_feature_flag_are_needed() {
+        let err = DeleteFeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteFeatureFlag::builder()
+            .feature_flag("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn feature_flag_is_needed() {
+        let err = DeleteFeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFeatureFlagBuilderError, "feature_flag");
+    }
+
+    #[test]
+    fn project_and_feature_flag_are_sufficient() {
+        DeleteFeatureFlag::builder()
+            .project(1)
+            .feature_flag("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteFeatureFlag::builder()
+            .project(0)
+            .feature_flag("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/feature_flags/new_ui")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteFeatureFlag::builder()
+            .project("simple/project")
+            .feature_flag("new_ui")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
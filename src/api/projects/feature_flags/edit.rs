@@ -0,0 +1,273 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+use serde_json::json;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::feature_flags::FeatureFlagStrategy;
+
+/// Edit a feature flag within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditFeatureFlag<'a> {
+    /// The project to edit the feature flag within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    feature_flag: Cow<'a, str>,
+
+    /// The new name of the feature flag.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The description of the feature flag.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the feature flag is active.
+    #[builder(default)]
+    active: Option<bool>,
+    /// Strategies to add, update, or remove.
+    ///
+    /// Existing strategies are referenced by their `id`.
+    #[builder(setter(name = "_strategies"), default, private)]
+    strategies: Vec<FeatureFlagStrategy<'a>>,
+}
+
+impl<'a> EditFeatureFlag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditFeatureFlagBuilder<'a> {
+        EditFeatureFlagBuilder::default()
+    }
+
+    fn as_json(&self) -> serde_json::Value {
+        JsonParams::clean(json!({
+            "name": self.name,
+            "description": self.description,
+            "active": self.active,
+            "strategies": self.strategies
+                .iter()
+                .map(|s| s.as_json())
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+impl<'a> EditFeatureFlagBuilder<'a> {
+    /// Add, update, or remove a strategy of the feature flag.
+    pub fn strategy(&mut self, strategy: FeatureFlagStrategy<'a>) -> &mut Self {
+        self.strategies.get_or_insert_with(Vec::new).push(strategy);
+        self
+    }
+
+    /// Add, update, or remove multiple strategies of the feature flag.
+    pub fn strategies<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = FeatureFlagStrategy<'a>>,
+    {
+        self.strategies.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), EditFeatureFlagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("feature_flag", self.feature_flag.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditFeatureFlag<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/feature_flags/{}",
+            self.project.path_segment(),
+            self.feature_flag.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        JsonParams::into_body(&self.as_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::feature_flags::{
+        EditFeatureFlag, EditFeatureFlagBuilderError, FeatureFlagStrategy,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_feature_flag_are_needed() {
+        let err = EditFeatureFlag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditFeatureFlag::builder()
+            .feature_flag("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn feature_flag_is_needed() {
+        let err = EditFeatureFlag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFeatureFlagBuilderError, "feature_flag");
+    }
+
+    #[test]
+    fn project_and_feature_flag_are_sufficient() {
+        EditFeatureFlag::builder()
+            .project(1)
+            .feature_flag("flag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditFeatureFlag::builder()
+            .project(0)
+            .feature_flag("flag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditFeatureFlagBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/new_ui")
+            .content_type("application/json")
+            .body_str("{}")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .feature_flag("new_ui")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/new_ui")
+            .content_type("application/json")
+            .body_str(r#"{"name":"newer_ui"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .feature_flag("new_ui")
+            .name("newer_ui")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/new_ui")
+            .content_type("application/json")
+            .body_str(r#"{"description":"desc"}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .feature_flag("new_ui")
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_active() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/new_ui")
+            .content_type("application/json")
+            .body_str(r#"{"active":true}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .feature_flag("new_ui")
+            .active(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_strategies() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/feature_flags/new_ui")
+            .content_type("application/json")
+            .body_str(concat!(
+                "{",
+                "\"strategies\":[",
+                "{\"_destroy\":true,\"id\":1},",
+                "{\"id\":2,\"scopes\":[{\"environment_scope\":\"*\"}]}",
+                "]",
+                "}",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFeatureFlag::builder()
+            .project("simple/project")
+            .feature_flag("new_ui")
+            .strategy(
+                FeatureFlagStrategy::builder()
+                    .id(1)
+                    .destroy(true)
+                    .build()
+                    .unwrap(),
+            )
+            .strategies(
+                [FeatureFlagStrategy::builder()
+                    .id(2)
+                    .scope("*")
+                    .build()
+                    .unwrap()]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::pipeline_schedules::{PipelineScheduleCron, PipelineScheduleTimeZone};
+
+/// Create a new freeze period on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateFreezePeriod<'a> {
+    /// The project to create the freeze period within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// When the freeze period starts.
+    freeze_start: PipelineScheduleCron,
+    /// When the freeze period ends.
+    freeze_end: PipelineScheduleCron,
+
+    /// The timezone for the `cron` expressions.
+    ///
+    /// Defaults to `UTC`.
+    #[builder(default)]
+    cron_timezone: Option<PipelineScheduleTimeZone<'a>>,
+}
+
+impl<'a> CreateFreezePeriod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateFreezePeriodBuilder<'a> {
+        CreateFreezePeriodBuilder::default()
+    }
+}
+
+impl<'a> CreateFreezePeriodBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateFreezePeriodBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateFreezePeriod<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/freeze_periods", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("freeze_start", &self.freeze_start)
+            .push("freeze_end", &self.freeze_end)
+            .push_opt("cron_timezone", self.cron_timezone.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::freeze_periods::{
+        CreateFreezePeriod, CreateFreezePeriodBuilderError,
+    };
+    use crate::api::projects::pipeline_schedules::{
+        PipelineScheduleCron, PipelineScheduleTimeZone,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn start() -> PipelineScheduleCron {
+        PipelineScheduleCron::new("0 23 * * 5").unwrap()
+    }
+
+    fn end() -> PipelineScheduleCron {
+        PipelineScheduleCron::new("0 7 * * 1").unwrap()
+    }
+
+    #[test]
+    fn project_freeze_start_and_freeze_end_are_needed() {
+        let err = CreateFreezePeriod::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateFreezePeriod::builder()
+            .freeze_start(start())
+            .freeze_end(end())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn freeze_start_is_needed() {
+        let err = CreateFreezePeriod::builder()
+            .project(1)
+            .freeze_end(end())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "freeze_start");
+    }
+
+    #[test]
+    fn freeze_end_is_needed() {
+        let err = CreateFreezePeriod::builder()
+            .project(1)
+            .freeze_start(start())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateFreezePeriodBuilderError, "freeze_end");
+    }
+
+    #[test]
+    fn project_freeze_start_and_freeze_end_are_sufficient() {
+        CreateFreezePeriod::builder()
+            .project(1)
+            .freeze_start(start())
+            .freeze_end(end())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateFreezePeriod::builder()
+            .project(0)
+            .freeze_start(start())
+            .freeze_end(end())
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/freeze_periods")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("freeze_start=0+23+*+*+5", "&freeze_end=0+7+*+*+1",))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_start(start())
+            .freeze_end(end())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cron_timezone() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/freeze_periods")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "freeze_start=0+23+*+*+5",
+                "&freeze_end=0+7+*+*+1",
+                "&cron_timezone=Newfoundland",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_start(start())
+            .freeze_end(end())
+            .cron_timezone(PipelineScheduleTimeZone::Newfoundland)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
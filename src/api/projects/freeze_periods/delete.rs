@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a freeze period within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteFreezePeriod<'a> {
+    /// The project to delete the freeze period from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the freeze period.
+    freeze_period: u64,
+}
+
+impl<'a> DeleteFreezePeriod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteFreezePeriodBuilder<'a> {
+        DeleteFreezePeriodBuilder::default()
+    }
+}
+
+impl<'a> DeleteFreezePeriodBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteFreezePeriodBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("freeze_period", self.freeze_period.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteFreezePeriod<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/freeze_periods/{}",
+            self.project.path_segment(),
+            self.freeze_period.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::freeze_periods::{
+        DeleteFreezePeriod, DeleteFreezePeriodBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_freeze_period_are_needed() {
+        let err = DeleteFreezePeriod::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteFreezePeriod::builder()
+            .freeze_period(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn freeze_period_is_needed() {
+        let err = DeleteFreezePeriod::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteFreezePeriodBuilderError, "freeze_period");
+    }
+
+    #[test]
+    fn project_and_freeze_period_are_sufficient() {
+        DeleteFreezePeriod::builder()
+            .project(1)
+            .freeze_period(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteFreezePeriod::builder()
+            .project(0)
+            .freeze_period(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
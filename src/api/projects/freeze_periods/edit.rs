@@ -0,0 +1,205 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::pipeline_schedules::{PipelineScheduleCron, PipelineScheduleTimeZone};
+
+/// Edit a freeze period on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditFreezePeriod<'a> {
+    /// The project to edit the freeze period within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the freeze period.
+    freeze_period: u64,
+
+    /// When the freeze period starts.
+    #[builder(default)]
+    freeze_start: Option<PipelineScheduleCron>,
+    /// When the freeze period ends.
+    #[builder(default)]
+    freeze_end: Option<PipelineScheduleCron>,
+    /// The timezone for the `cron` expressions.
+    #[builder(default)]
+    cron_timezone: Option<PipelineScheduleTimeZone<'a>>,
+}
+
+impl<'a> EditFreezePeriod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditFreezePeriodBuilder<'a> {
+        EditFreezePeriodBuilder::default()
+    }
+}
+
+impl<'a> EditFreezePeriodBuilder<'a> {
+    fn validate(&self) -> Result<(), EditFreezePeriodBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("freeze_period", self.freeze_period.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditFreezePeriod<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/freeze_periods/{}",
+            self.project.path_segment(),
+            self.freeze_period.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("freeze_start", self.freeze_start.as_ref())
+            .push_opt("freeze_end", self.freeze_end.as_ref())
+            .push_opt("cron_timezone", self.cron_timezone.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::freeze_periods::{EditFreezePeriod, EditFreezePeriodBuilderError};
+    use crate::api::projects::pipeline_schedules::{
+        PipelineScheduleCron, PipelineScheduleTimeZone,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_freeze_period_are_needed() {
+        let err = EditFreezePeriod::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditFreezePeriod::builder()
+            .freeze_period(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn freeze_period_is_needed() {
+        let err = EditFreezePeriod::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditFreezePeriodBuilderError, "freeze_period");
+    }
+
+    #[test]
+    fn project_and_freeze_period_are_sufficient() {
+        EditFreezePeriod::builder()
+            .project(1)
+            .freeze_period(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditFreezePeriod::builder()
+            .project(0)
+            .freeze_period(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditFreezePeriodBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_freeze_start() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("freeze_start=0+23+*+*+5")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .freeze_start(PipelineScheduleCron::new("0 23 * * 5").unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_freeze_end() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("freeze_end=0+7+*+*+1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .freeze_end(PipelineScheduleCron::new("0 7 * * 1").unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_cron_timezone() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/freeze_periods/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("cron_timezone=UTC")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditFreezePeriod::builder()
+            .project("simple/project")
+            .freeze_period(1)
+            .cron_timezone(PipelineScheduleTimeZone::UTC)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
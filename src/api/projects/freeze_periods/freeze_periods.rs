@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for freeze periods within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct FreezePeriods<'a> {
+    /// The project to query for freeze periods.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> FreezePeriods<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FreezePeriodsBuilder<'a> {
+        FreezePeriodsBuilder::default()
+    }
+}
+
+impl<'a> FreezePeriodsBuilder<'a> {
+    fn validate(&self) -> Result<(), FreezePeriodsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for FreezePeriods<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/freeze_periods", self.project.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for FreezePeriods<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::freeze_periods::{FreezePeriods, FreezePeriodsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = FreezePeriods::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FreezePeriodsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        FreezePeriods::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = FreezePeriods::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, FreezePeriodsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/freeze_periods")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FreezePeriods::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
+use crate::api::secrets::{SecretError, SecretProvider};
 
 /// Create a new webhook for a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateHook<'a> {
     /// The project to create a webhook within.
     #[builder(setter(into))]
@@ -78,13 +79,30 @@ impl<'a> CreateHook<'a> {
     }
 }
 
+impl<'a> CreateHookBuilder<'a> {
+    /// Set the secret token from a secret provider.
+    pub fn token_from<P>(&mut self, provider: &P, name: &str) -> Result<&mut Self, SecretError>
+    where
+        P: SecretProvider + ?Sized,
+    {
+        let token = provider.secret(name)?;
+        Ok(self.token(token))
+    }
+
+    fn validate(&self) -> Result<(), CreateHookBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateHook<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/hooks", self.project).into()
+        format!("projects/{}/hooks", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -123,6 +141,7 @@ mod tests {
     use http::Method;
 
     use crate::api::projects::hooks::{CreateHook, CreateHookBuilderError};
+    use crate::api::secrets::SecretError;
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
@@ -156,6 +175,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateHook::builder()
+            .project("")
+            .url("url")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateHookBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -519,4 +548,50 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_token_from() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/hooks")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Ftest.invalid%2Fpath%3Fsome%3Dfoo",
+                "&token=secret",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let provider = |name: &str| -> Result<String, SecretError> {
+            assert_eq!(name, "hook");
+            Ok("secret".into())
+        };
+        let endpoint = CreateHook::builder()
+            .project("simple/project")
+            .url("https://test.invalid/path?some=foo")
+            .token_from(&provider, "hook")
+            .unwrap()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn token_from_error() {
+        let provider =
+            |name: &str| -> Result<String, SecretError> { Err(SecretError::not_found(name)) };
+        let err = CreateHook::builder()
+            .token_from(&provider, "hook")
+            .map(|_| ())
+            .unwrap_err();
+        if let SecretError::NotFound {
+            name,
+        } = err
+        {
+            assert_eq!(name, "hook");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
 }
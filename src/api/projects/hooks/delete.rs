@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a webhook for a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteHook<'a> {
     /// The project to delete a webhook from.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> DeleteHook<'a> {
     }
 }
 
+impl<'a> DeleteHookBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteHookBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteHook<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/hooks/{}", self.project, self.hook_id).into()
+        format!(
+            "projects/{}/hooks/{}",
+            self.project.path_segment(),
+            self.hook_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -74,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteHook::builder()
+            .project("")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteHookBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
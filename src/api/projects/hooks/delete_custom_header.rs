@@ -0,0 +1,141 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a custom header from a webhook of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteHookCustomHeader<'a> {
+    /// The project of the webhook.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the hook.
+    hook_id: u64,
+    /// The name of the header.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> DeleteHookCustomHeader<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteHookCustomHeaderBuilder<'a> {
+        DeleteHookCustomHeaderBuilder::default()
+    }
+}
+
+impl<'a> DeleteHookCustomHeaderBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteHookCustomHeaderBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteHookCustomHeader<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/hooks/{}/custom_headers/{}",
+            self.project.path_segment(),
+            self.hook_id.path_segment(),
+            self.key.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::hooks::{DeleteHookCustomHeader, DeleteHookCustomHeaderBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_necessary() {
+        let err = DeleteHookCustomHeader::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteHookCustomHeader::builder()
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "project");
+    }
+
+    #[test]
+    fn hook_id_is_necessary() {
+        let err = DeleteHookCustomHeader::builder()
+            .project("project")
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "hook_id");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = DeleteHookCustomHeader::builder()
+            .project("project")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteHookCustomHeaderBuilderError, "key");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        DeleteHookCustomHeader::builder()
+            .project("project")
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteHookCustomHeader::builder()
+            .project("")
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteHookCustomHeaderBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/hooks/1/custom_headers/X-Key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteHookCustomHeader::builder()
+            .project("simple/project")
+            .hook_id(1)
+            .key("X-Key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
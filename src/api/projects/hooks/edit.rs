@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
+use crate::api::secrets::{SecretError, SecretProvider};
 
 /// Edit an existing webhook for a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditHook<'a> {
     /// The project to edit a webhook within.
     #[builder(setter(into))]
@@ -80,13 +81,36 @@ impl<'a> EditHook<'a> {
     }
 }
 
+impl<'a> EditHookBuilder<'a> {
+    /// Set the secret token from a secret provider.
+    pub fn token_from<P>(&mut self, provider: &P, name: &str) -> Result<&mut Self, SecretError>
+    where
+        P: SecretProvider + ?Sized,
+    {
+        let token = provider.secret(name)?;
+        Ok(self.token(token))
+    }
+
+    fn validate(&self) -> Result<(), EditHookBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditHook<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/hooks/{}", self.project, self.hook_id).into()
+        format!(
+            "projects/{}/hooks/{}",
+            self.project.path_segment(),
+            self.hook_id.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -155,6 +179,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditHook::builder()
+            .project("")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditHookBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
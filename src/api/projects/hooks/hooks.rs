@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for webhooks within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Hooks<'a> {
     /// The project to query for webhooks.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> Hooks<'a> {
     }
 }
 
+impl<'a> HooksBuilder<'a> {
+    fn validate(&self) -> Result<(), HooksBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Hooks<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/hooks", self.project).into()
+        format!("projects/{}/hooks", self.project.path_segment()).into()
     }
 }
 
@@ -53,6 +62,12 @@ mod tests {
         Hooks::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Hooks::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, HooksBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
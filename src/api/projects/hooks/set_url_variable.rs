@@ -0,0 +1,171 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Set a URL variable for a webhook of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SetHookUrlVariable<'a> {
+    /// The project of the webhook.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the hook.
+    hook_id: u64,
+    /// The name of the URL variable.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+    /// The value to set.
+    #[builder(setter(into))]
+    value: Cow<'a, str>,
+}
+
+impl<'a> SetHookUrlVariable<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetHookUrlVariableBuilder<'a> {
+        SetHookUrlVariableBuilder::default()
+    }
+}
+
+impl<'a> SetHookUrlVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), SetHookUrlVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for SetHookUrlVariable<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/hooks/{}/url_variables/{}",
+            self.project.path_segment(),
+            self.hook_id.path_segment(),
+            self.key.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("value", &self.value);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::hooks::{SetHookUrlVariable, SetHookUrlVariableBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_necessary() {
+        let err = SetHookUrlVariable::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookUrlVariableBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = SetHookUrlVariable::builder()
+            .hook_id(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookUrlVariableBuilderError, "project");
+    }
+
+    #[test]
+    fn hook_id_is_necessary() {
+        let err = SetHookUrlVariable::builder()
+            .project("project")
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookUrlVariableBuilderError, "hook_id");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = SetHookUrlVariable::builder()
+            .project("project")
+            .hook_id(1)
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookUrlVariableBuilderError, "key");
+    }
+
+    #[test]
+    fn value_is_necessary() {
+        let err = SetHookUrlVariable::builder()
+            .project("project")
+            .hook_id(1)
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetHookUrlVariableBuilderError, "value");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        SetHookUrlVariable::builder()
+            .project("project")
+            .hook_id(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = SetHookUrlVariable::builder()
+            .project("")
+            .hook_id(1)
+            .key("key")
+            .value("value")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SetHookUrlVariableBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/hooks/1/url_variables/X-Key")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=some+value")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetHookUrlVariable::builder()
+            .project("simple/project")
+            .hook_id(1)
+            .key("X-Key")
+            .value("some value")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Events which may be triggered when testing a webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HookTestTrigger {
+    /// Trigger a push event.
+    PushEvents,
+    /// Trigger a tag push event.
+    TagPushEvents,
+    /// Trigger an issue event.
+    IssuesEvents,
+    /// Trigger a confidential issue event.
+    ConfidentialIssuesEvents,
+    /// Trigger a note (comment) event.
+    NoteEvents,
+    /// Trigger a merge request event.
+    MergeRequestsEvents,
+    /// Trigger a job event.
+    JobEvents,
+    /// Trigger a pipeline event.
+    PipelineEvents,
+    /// Trigger a wiki page event.
+    WikiPageEvents,
+    /// Trigger a release event.
+    ReleasesEvents,
+    /// Trigger an emoji event.
+    EmojiEvents,
+    /// Trigger a resource access token event.
+    ResourceAccessTokenEvents,
+}
+
+impl HookTestTrigger {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            HookTestTrigger::PushEvents => "push_events",
+            HookTestTrigger::TagPushEvents => "tag_push_events",
+            HookTestTrigger::IssuesEvents => "issues_events",
+            HookTestTrigger::ConfidentialIssuesEvents => "confidential_issues_events",
+            HookTestTrigger::NoteEvents => "note_events",
+            HookTestTrigger::MergeRequestsEvents => "merge_requests_events",
+            HookTestTrigger::JobEvents => "job_events",
+            HookTestTrigger::PipelineEvents => "pipeline_events",
+            HookTestTrigger::WikiPageEvents => "wiki_page_events",
+            HookTestTrigger::ReleasesEvents => "releases_events",
+            HookTestTrigger::EmojiEvents => "emoji_events",
+            HookTestTrigger::ResourceAccessTokenEvents => "resource_access_token_events",
+        }
+    }
+}
+
+impl ParamValue<'static> for HookTestTrigger {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Trigger a test event for a webhook of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct TestHook<'a> {
+    /// The project of the webhook.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the hook to test.
+    hook_id: u64,
+    /// The event to trigger.
+    trigger: HookTestTrigger,
+}
+
+impl<'a> TestHook<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TestHookBuilder<'a> {
+        TestHookBuilder::default()
+    }
+}
+
+impl<'a> TestHookBuilder<'a> {
+    fn validate(&self) -> Result<(), TestHookBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("hook_id", self.hook_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for TestHook<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/hooks/{}/test/{}",
+            self.project.path_segment(),
+            self.hook_id.path_segment(),
+            self.trigger.as_str(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::hooks::{HookTestTrigger, TestHook, TestHookBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn hook_test_trigger_as_str() {
+        let items = &[
+            (HookTestTrigger::PushEvents, "push_events"),
+            (HookTestTrigger::TagPushEvents, "tag_push_events"),
+            (HookTestTrigger::IssuesEvents, "issues_events"),
+            (
+                HookTestTrigger::ConfidentialIssuesEvents,
+                "confidential_issues_events",
+            ),
+            (HookTestTrigger::NoteEvents, "note_events"),
+            (
+                HookTestTrigger::MergeRequestsEvents,
+                "merge_requests_events",
+            ),
+            (HookTestTrigger::JobEvents, "job_events"),
+            (HookTestTrigger::PipelineEvents, "pipeline_events"),
+            (HookTestTrigger::WikiPageEvents, "wiki_page_events"),
+            (HookTestTrigger::ReleasesEvents, "releases_events"),
+            (HookTestTrigger::EmojiEvents, "emoji_events"),
+            (
+                HookTestTrigger::ResourceAccessTokenEvents,
+                "resource_access_token_events",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_hook_id_and_trigger_are_necessary() {
+        let err = TestHook::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = TestHook::builder()
+            .hook_id(1)
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "project");
+    }
+
+    #[test]
+    fn hook_id_is_necessary() {
+        let err = TestHook::builder()
+            .project("project")
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "hook_id");
+    }
+
+    #[test]
+    fn trigger_is_necessary() {
+        let err = TestHook::builder()
+            .project("project")
+            .hook_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TestHookBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_hook_id_and_trigger_are_sufficient() {
+        TestHook::builder()
+            .project("project")
+            .hook_id(1)
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = TestHook::builder()
+            .project("")
+            .hook_id(1)
+            .trigger(HookTestTrigger::PushEvents)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TestHookBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/hooks/1/test/merge_requests_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TestHook::builder()
+            .project("simple/project")
+            .hook_id(1)
+            .trigger(HookTestTrigger::MergeRequestsEvents)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
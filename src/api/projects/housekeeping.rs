@@ -0,0 +1,165 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Housekeeping tasks which may be run on a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HousekeepingTask {
+    /// Run an eager housekeeping task (e.g., a full repack).
+    Eager,
+    /// Prune unreachable objects from the repository.
+    Prune,
+}
+
+impl HousekeepingTask {
+    fn as_str(self) -> &'static str {
+        match self {
+            HousekeepingTask::Eager => "eager",
+            HousekeepingTask::Prune => "prune",
+        }
+    }
+}
+
+impl ParamValue<'static> for HousekeepingTask {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Start the housekeeping task for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectHousekeeping<'a> {
+    /// The project to run housekeeping on.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The housekeeping task to run.
+    #[builder(default)]
+    task: Option<HousekeepingTask>,
+}
+
+impl<'a> ProjectHousekeeping<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectHousekeepingBuilder<'a> {
+        ProjectHousekeepingBuilder::default()
+    }
+}
+
+impl<'a> ProjectHousekeepingBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectHousekeepingBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectHousekeeping<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/housekeeping", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("task", self.task);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{
+        HousekeepingTask, ProjectHousekeeping, ProjectHousekeepingBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn housekeeping_task_as_str() {
+        let items = &[
+            (HousekeepingTask::Eager, "eager"),
+            (HousekeepingTask::Prune, "prune"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProjectHousekeeping::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectHousekeepingBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectHousekeeping::builder()
+            .project("project")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectHousekeeping::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectHousekeepingBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/project%2Fsubproject/housekeeping")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectHousekeeping::builder()
+            .project("project/subproject")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_task() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/project%2Fsubproject/housekeeping")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("task=prune")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectHousekeeping::builder()
+            .project("project/subproject")
+            .task(HousekeepingTask::Prune)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
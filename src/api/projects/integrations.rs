@@ -0,0 +1,41 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project integration API endpoints.
+//!
+//! These endpoints are used for querying and configuring the integrations of a project.
+
+mod chat;
+mod disable;
+mod integration;
+mod integrations;
+mod jira;
+
+pub use self::chat::BranchesToBeNotified;
+pub use self::chat::ChatIntegration;
+pub use self::chat::EditChatIntegration;
+pub use self::chat::EditChatIntegrationBuilder;
+pub use self::chat::EditChatIntegrationBuilderError;
+pub use self::chat::IntegrationEvents;
+pub use self::chat::IntegrationEventsBuilder;
+pub use self::chat::IntegrationEventsBuilderError;
+
+pub use self::disable::DisableIntegration;
+pub use self::disable::DisableIntegrationBuilder;
+pub use self::disable::DisableIntegrationBuilderError;
+
+pub use self::integration::Integration;
+pub use self::integration::IntegrationBuilder;
+pub use self::integration::IntegrationBuilderError;
+
+pub use self::integrations::Integrations;
+pub use self::integrations::IntegrationsBuilder;
+pub use self::integrations::IntegrationsBuilderError;
+
+pub use self::jira::EditJiraIntegration;
+pub use self::jira::EditJiraIntegrationBuilder;
+pub use self::jira::EditJiraIntegrationBuilderError;
+pub use self::jira::JiraAuthType;
@@ -0,0 +1,507 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Chat integrations which post notifications to a webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChatIntegration {
+    /// Discord notifications.
+    Discord,
+    /// Google Chat notifications.
+    GoogleChat,
+    /// Mattermost notifications.
+    Mattermost,
+    /// Microsoft Teams notifications.
+    MicrosoftTeams,
+    /// Slack notifications.
+    Slack,
+}
+
+impl ChatIntegration {
+    fn as_str(self) -> &'static str {
+        match self {
+            ChatIntegration::Discord => "discord",
+            ChatIntegration::GoogleChat => "hangouts-chat",
+            ChatIntegration::Mattermost => "mattermost",
+            ChatIntegration::MicrosoftTeams => "microsoft-teams",
+            ChatIntegration::Slack => "slack",
+        }
+    }
+}
+
+/// The branches to send notifications for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BranchesToBeNotified {
+    /// All branches.
+    All,
+    /// The default branch.
+    Default,
+    /// Protected branches.
+    Protected,
+    /// The default branch and protected branches.
+    DefaultAndProtected,
+}
+
+impl BranchesToBeNotified {
+    fn as_str(self) -> &'static str {
+        match self {
+            BranchesToBeNotified::All => "all",
+            BranchesToBeNotified::Default => "default",
+            BranchesToBeNotified::Protected => "protected",
+            BranchesToBeNotified::DefaultAndProtected => "default_and_protected",
+        }
+    }
+}
+
+impl ParamValue<'static> for BranchesToBeNotified {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Events which trigger a notification from an integration.
+///
+/// Events which are not set are left unchanged. Not every integration supports every event.
+#[derive(Debug, Default, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct IntegrationEvents {
+    /// Notify about pushes.
+    #[builder(default)]
+    push_events: Option<bool>,
+    /// Notify about issue events.
+    #[builder(default)]
+    issues_events: Option<bool>,
+    /// Notify about confidential issue events.
+    #[builder(default)]
+    confidential_issues_events: Option<bool>,
+    /// Notify about merge request events.
+    #[builder(default)]
+    merge_requests_events: Option<bool>,
+    /// Notify about tag pushes.
+    #[builder(default)]
+    tag_push_events: Option<bool>,
+    /// Notify about new notes.
+    #[builder(default)]
+    note_events: Option<bool>,
+    /// Notify about new notes on confidential issues.
+    #[builder(default)]
+    confidential_note_events: Option<bool>,
+    /// Notify about pipeline status changes.
+    #[builder(default)]
+    pipeline_events: Option<bool>,
+    /// Notify about wiki page events.
+    #[builder(default)]
+    wiki_page_events: Option<bool>,
+    /// Notify about deployments.
+    #[builder(default)]
+    deployment_events: Option<bool>,
+}
+
+impl IntegrationEvents {
+    /// Create a builder for the events.
+    pub fn builder() -> IntegrationEventsBuilder {
+        IntegrationEventsBuilder::default()
+    }
+
+    pub(crate) fn add_params(&self, params: &mut FormParams) {
+        params
+            .push_opt("push_events", self.push_events)
+            .push_opt("issues_events", self.issues_events)
+            .push_opt(
+                "confidential_issues_events",
+                self.confidential_issues_events,
+            )
+            .push_opt("merge_requests_events", self.merge_requests_events)
+            .push_opt("tag_push_events", self.tag_push_events)
+            .push_opt("note_events", self.note_events)
+            .push_opt("confidential_note_events", self.confidential_note_events)
+            .push_opt("pipeline_events", self.pipeline_events)
+            .push_opt("wiki_page_events", self.wiki_page_events)
+            .push_opt("deployment_events", self.deployment_events);
+    }
+}
+
+/// Configure a chat integration of a project.
+///
+/// The integration is enabled if it is not already.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditChatIntegration<'a> {
+    /// The project to configure the integration within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The integration to configure.
+    integration: ChatIntegration,
+    /// The webhook URL to post notifications to.
+    #[builder(setter(into))]
+    webhook: Cow<'a, str>,
+
+    /// The username to post notifications as.
+    ///
+    /// Only supported by Slack and Mattermost.
+    #[builder(setter(into), default)]
+    username: Option<Cow<'a, str>>,
+    /// The default channel to post notifications to.
+    ///
+    /// Only supported by Slack and Mattermost.
+    #[builder(setter(into), default)]
+    channel: Option<Cow<'a, str>>,
+    /// Only send notifications for broken pipelines.
+    #[builder(default)]
+    notify_only_broken_pipelines: Option<bool>,
+    /// The branches to send notifications for.
+    #[builder(default)]
+    branches_to_be_notified: Option<BranchesToBeNotified>,
+    /// The events to send notifications for.
+    #[builder(default)]
+    events: IntegrationEvents,
+    /// Use the settings inherited from the group or instance.
+    #[builder(default)]
+    use_inherited_settings: Option<bool>,
+}
+
+impl<'a> EditChatIntegration<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditChatIntegrationBuilder<'a> {
+        EditChatIntegrationBuilder::default()
+    }
+}
+
+impl<'a> EditChatIntegrationBuilder<'a> {
+    fn validate(&self) -> Result<(), EditChatIntegrationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditChatIntegration<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/integrations/{}",
+            self.project.path_segment(),
+            self.integration.as_str(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("webhook", &self.webhook)
+            .push_opt("username", self.username.as_ref())
+            .push_opt("channel", self.channel.as_ref())
+            .push_opt(
+                "notify_only_broken_pipelines",
+                self.notify_only_broken_pipelines,
+            )
+            .push_opt("branches_to_be_notified", self.branches_to_be_notified);
+        self.events.add_params(&mut params);
+        params.push_opt("use_inherited_settings", self.use_inherited_settings);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::integrations::{
+        BranchesToBeNotified, ChatIntegration, EditChatIntegration,
+        EditChatIntegrationBuilderError, IntegrationEvents,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn chat_integration_as_str() {
+        let items = &[
+            (ChatIntegration::Discord, "discord"),
+            (ChatIntegration::GoogleChat, "hangouts-chat"),
+            (ChatIntegration::Mattermost, "mattermost"),
+            (ChatIntegration::MicrosoftTeams, "microsoft-teams"),
+            (ChatIntegration::Slack, "slack"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn branches_to_be_notified_as_str() {
+        let items = &[
+            (BranchesToBeNotified::All, "all"),
+            (BranchesToBeNotified::Default, "default"),
+            (BranchesToBeNotified::Protected, "protected"),
+            (
+                BranchesToBeNotified::DefaultAndProtected,
+                "default_and_protected",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_integration_and_webhook_are_needed() {
+        let err = EditChatIntegration::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditChatIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditChatIntegration::builder()
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditChatIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn integration_is_needed() {
+        let err = EditChatIntegration::builder()
+            .project(1)
+            .webhook("https://hooks.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditChatIntegrationBuilderError, "integration");
+    }
+
+    #[test]
+    fn webhook_is_needed() {
+        let err = EditChatIntegration::builder()
+            .project(1)
+            .integration(ChatIntegration::Slack)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditChatIntegrationBuilderError, "webhook");
+    }
+
+    #[test]
+    fn project_integration_and_webhook_are_sufficient() {
+        EditChatIntegration::builder()
+            .project(1)
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditChatIntegration::builder()
+            .project(0)
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditChatIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/microsoft-teams")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("webhook=https%3A%2F%2Fhooks.example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::MicrosoftTeams)
+            .webhook("https://hooks.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_username() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "webhook=https%3A%2F%2Fhooks.example.com",
+                "&username=gitlab",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .username("gitlab")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_channel() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "webhook=https%3A%2F%2Fhooks.example.com",
+                "&channel=builds",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .channel("builds")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_notify_only_broken_pipelines() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "webhook=https%3A%2F%2Fhooks.example.com",
+                "&notify_only_broken_pipelines=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .notify_only_broken_pipelines(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_branches_to_be_notified() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "webhook=https%3A%2F%2Fhooks.example.com",
+                "&branches_to_be_notified=protected",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .branches_to_be_notified(BranchesToBeNotified::Protected)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "webhook=https%3A%2F%2Fhooks.example.com",
+                "&push_events=false",
+                "&issues_events=true",
+                "&confidential_issues_events=false",
+                "&merge_requests_events=true",
+                "&tag_push_events=false",
+                "&note_events=true",
+                "&confidential_note_events=false",
+                "&pipeline_events=true",
+                "&wiki_page_events=false",
+                "&deployment_events=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .events(
+                IntegrationEvents::builder()
+                    .push_events(false)
+                    .issues_events(true)
+                    .confidential_issues_events(false)
+                    .merge_requests_events(true)
+                    .tag_push_events(false)
+                    .note_events(true)
+                    .confidential_note_events(false)
+                    .pipeline_events(true)
+                    .wiki_page_events(false)
+                    .deployment_events(true)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_use_inherited_settings() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "webhook=https%3A%2F%2Fhooks.example.com",
+                "&use_inherited_settings=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditChatIntegration::builder()
+            .project("simple/project")
+            .integration(ChatIntegration::Slack)
+            .webhook("https://hooks.example.com")
+            .use_inherited_settings(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
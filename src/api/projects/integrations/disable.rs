@@ -0,0 +1,124 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Disable an integration within a project.
+///
+/// The settings of the integration are removed as well.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DisableIntegration<'a> {
+    /// The project to disable the integration within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the integration (e.g., `slack` or `jira`).
+    #[builder(setter(into))]
+    integration: Cow<'a, str>,
+}
+
+impl<'a> DisableIntegration<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DisableIntegrationBuilder<'a> {
+        DisableIntegrationBuilder::default()
+    }
+}
+
+impl<'a> DisableIntegrationBuilder<'a> {
+    fn validate(&self) -> Result<(), DisableIntegrationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("integration", self.integration.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DisableIntegration<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/integrations/{}",
+            self.project.path_segment(),
+            self.integration.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::integrations::{DisableIntegration, DisableIntegrationBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_integration_are_needed() {
+        let err = DisableIntegration::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DisableIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DisableIntegration::builder()
+            .integration("slack")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DisableIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn integration_is_needed() {
+        let err = DisableIntegration::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DisableIntegrationBuilderError, "integration");
+    }
+
+    #[test]
+    fn project_and_integration_are_sufficient() {
+        DisableIntegration::builder()
+            .project(1)
+            .integration("slack")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DisableIntegration::builder()
+            .project(0)
+            .integration("slack")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DisableIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DisableIntegration::builder()
+            .project("simple/project")
+            .integration("slack")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the settings of an integration within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct Integration<'a> {
+    /// The project to query for the integration.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the integration (e.g., `slack` or `jira`).
+    #[builder(setter(into))]
+    integration: Cow<'a, str>,
+}
+
+impl<'a> Integration<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IntegrationBuilder<'a> {
+        IntegrationBuilder::default()
+    }
+}
+
+impl<'a> IntegrationBuilder<'a> {
+    fn validate(&self) -> Result<(), IntegrationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("integration", self.integration.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for Integration<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/integrations/{}",
+            self.project.path_segment(),
+            self.integration.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::integrations::{Integration, IntegrationBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_integration_are_needed() {
+        let err = Integration::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = Integration::builder()
+            .integration("slack")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn integration_is_needed() {
+        let err = Integration::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IntegrationBuilderError, "integration");
+    }
+
+    #[test]
+    fn project_and_integration_are_sufficient() {
+        Integration::builder()
+            .project(1)
+            .integration("slack")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = Integration::builder()
+            .project(0)
+            .integration("slack")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/integrations/slack")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Integration::builder()
+            .project("simple/project")
+            .integration("slack")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,453 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Authentication methods for Jira.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JiraAuthType {
+    /// Basic authentication using a username and password (or API token for Jira Cloud).
+    Basic,
+    /// A Jira personal access token (Jira Data Center and Server only).
+    PersonalAccessToken,
+}
+
+impl JiraAuthType {
+    fn as_str(self) -> &'static str {
+        match self {
+            JiraAuthType::Basic => "0",
+            JiraAuthType::PersonalAccessToken => "1",
+        }
+    }
+}
+
+impl ParamValue<'static> for JiraAuthType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Configure the Jira integration of a project.
+///
+/// The integration is enabled if it is not already.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditJiraIntegration<'a> {
+    /// The project to configure the integration within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The URL of the Jira instance.
+    #[builder(setter(into))]
+    url: Cow<'a, str>,
+
+    /// The base URL of the Jira instance's API.
+    ///
+    /// Defaults to `url` if unset.
+    #[builder(setter(into), default)]
+    api_url: Option<Cow<'a, str>>,
+    /// How to authenticate with Jira.
+    #[builder(default)]
+    jira_auth_type: Option<JiraAuthType>,
+    /// The username to authenticate with.
+    ///
+    /// Required for basic authentication.
+    #[builder(setter(into), default)]
+    username: Option<Cow<'a, str>>,
+    /// The password, API token, or personal access token to authenticate with.
+    #[builder(setter(into), default)]
+    password: Option<Cow<'a, str>>,
+    /// Keys of Jira projects to which issues are linked.
+    #[builder(setter(name = "_project_keys"), default, private)]
+    project_keys: BTreeSet<Cow<'a, str>>,
+    /// Transition Jira issues when they are closed from GitLab.
+    #[builder(default)]
+    jira_issue_transition_automatic: Option<bool>,
+    /// The IDs of transitions to move Jira issues through when they are closed.
+    ///
+    /// Multiple transitions may be separated by commas or semicolons.
+    #[builder(setter(into), default)]
+    jira_issue_transition_id: Option<Cow<'a, str>>,
+    /// Link Jira issues mentioned in commits.
+    #[builder(default)]
+    commit_events: Option<bool>,
+    /// Link Jira issues mentioned in merge requests.
+    #[builder(default)]
+    merge_requests_events: Option<bool>,
+    /// Comment on Jira issues when they are mentioned.
+    #[builder(default)]
+    comment_on_event_enabled: Option<bool>,
+    /// Show Jira issues within GitLab.
+    #[builder(default)]
+    issues_enabled: Option<bool>,
+    /// Use the settings inherited from the group or instance.
+    #[builder(default)]
+    use_inherited_settings: Option<bool>,
+}
+
+impl<'a> EditJiraIntegration<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditJiraIntegrationBuilder<'a> {
+        EditJiraIntegrationBuilder::default()
+    }
+}
+
+impl<'a> EditJiraIntegrationBuilder<'a> {
+    /// Link issues from a Jira project.
+    pub fn project_key<K>(&mut self, key: K) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+    {
+        self.project_keys
+            .get_or_insert_with(BTreeSet::new)
+            .insert(key.into());
+        self
+    }
+
+    /// Link issues from a set of Jira projects.
+    pub fn project_keys<I, K>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = K>,
+        K: Into<Cow<'a, str>>,
+    {
+        self.project_keys
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), EditJiraIntegrationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditJiraIntegration<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/integrations/jira", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("url", &self.url)
+            .push_opt("api_url", self.api_url.as_ref())
+            .push_opt("jira_auth_type", self.jira_auth_type)
+            .push_opt("username", self.username.as_ref())
+            .push_opt("password", self.password.as_ref())
+            .extend(
+                self.project_keys
+                    .iter()
+                    .map(|value| (ParamName::new("project_keys").array(), value)),
+            )
+            .push_opt(
+                "jira_issue_transition_automatic",
+                self.jira_issue_transition_automatic,
+            )
+            .push_opt(
+                "jira_issue_transition_id",
+                self.jira_issue_transition_id.as_ref(),
+            )
+            .push_opt("commit_events", self.commit_events)
+            .push_opt("merge_requests_events", self.merge_requests_events)
+            .push_opt("comment_on_event_enabled", self.comment_on_event_enabled)
+            .push_opt("issues_enabled", self.issues_enabled)
+            .push_opt("use_inherited_settings", self.use_inherited_settings);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::integrations::{
+        EditJiraIntegration, EditJiraIntegrationBuilderError, JiraAuthType,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn jira_auth_type_as_str() {
+        let items = &[
+            (JiraAuthType::Basic, "0"),
+            (JiraAuthType::PersonalAccessToken, "1"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_and_url_are_needed() {
+        let err = EditJiraIntegration::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditJiraIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditJiraIntegration::builder()
+            .url("https://jira.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditJiraIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn url_is_needed() {
+        let err = EditJiraIntegration::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditJiraIntegrationBuilderError, "url");
+    }
+
+    #[test]
+    fn project_and_url_are_sufficient() {
+        EditJiraIntegration::builder()
+            .project(1)
+            .url("https://jira.example.com")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditJiraIntegration::builder()
+            .project(0)
+            .url("https://jira.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditJiraIntegrationBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("url=https%3A%2F%2Fjira.example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_api_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&api_url=https%3A%2F%2Fapi.jira.example.com",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .api_url("https://api.jira.example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_credentials() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&jira_auth_type=0",
+                "&username=user",
+                "&password=secret",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .jira_auth_type(JiraAuthType::Basic)
+            .username("user")
+            .password("secret")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_project_keys() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&project_keys%5B%5D=ABC",
+                "&project_keys%5B%5D=DEF",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .project_key("ABC")
+            .project_keys(["ABC", "DEF"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_jira_issue_transition_automatic() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&jira_issue_transition_automatic=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .jira_issue_transition_automatic(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_jira_issue_transition_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&jira_issue_transition_id=1%2C2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .jira_issue_transition_id("1,2")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_events() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&commit_events=true",
+                "&merge_requests_events=false",
+                "&comment_on_event_enabled=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .commit_events(true)
+            .merge_requests_events(false)
+            .comment_on_event_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_issues_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&issues_enabled=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .issues_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_use_inherited_settings() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/integrations/jira")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fjira.example.com",
+                "&use_inherited_settings=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditJiraIntegration::builder()
+            .project("simple/project")
+            .url("https://jira.example.com")
+            .use_inherited_settings(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
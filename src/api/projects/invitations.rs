@@ -0,0 +1,33 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Project invitation API endpoints.
+//!
+//! These endpoints are used for inviting users to projects, including by email address before
+//! they have an account, and managing pending invitations.
+
+mod create;
+mod delete;
+mod edit;
+mod invitations;
+
+pub use self::create::CreateProjectInvitation;
+pub use self::create::CreateProjectInvitationBuilder;
+pub use self::create::CreateProjectInvitationBuilderError;
+
+pub use self::delete::DeleteProjectInvitation;
+pub use self::delete::DeleteProjectInvitationBuilder;
+pub use self::delete::DeleteProjectInvitationBuilderError;
+
+pub use self::edit::EditProjectInvitation;
+pub use self::edit::EditProjectInvitationBuilder;
+pub use self::edit::EditProjectInvitationBuilderError;
+
+pub use self::invitations::ProjectInvitations;
+pub use self::invitations::ProjectInvitationsBuilder;
+pub use self::invitations::ProjectInvitationsBuilderError;
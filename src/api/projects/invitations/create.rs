@@ -0,0 +1,361 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, AccessLevel, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+const EMAIL_OR_USER_REQUIRED: &str = "at least one email address or user ID is required";
+
+/// Invite users to a project.
+///
+/// Users may be invited by email address, even if they do not have an account yet, or by ID.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateProjectInvitation<'a> {
+    /// The project to invite users to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The email addresses to invite.
+    #[builder(setter(name = "_emails"), default, private)]
+    emails: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// The users to invite (by ID).
+    #[builder(setter(name = "_user_ids"), default, private)]
+    user_ids: Option<CommaSeparatedList<u64>>,
+    /// The access level for the invited users in the project.
+    access_level: AccessLevel,
+
+    /// When the invited users' access expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+    /// The source of the invitation.
+    #[builder(setter(into), default)]
+    invite_source: Option<Cow<'a, str>>,
+    /// The ID of a member role to assign to the invited users.
+    #[builder(default)]
+    member_role_id: Option<u64>,
+}
+
+impl<'a> CreateProjectInvitation<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateProjectInvitationBuilder<'a> {
+        CreateProjectInvitationBuilder::default()
+    }
+}
+
+impl<'a> CreateProjectInvitationBuilder<'a> {
+    /// Invite an email address.
+    pub fn email<E>(&mut self, email: E) -> &mut Self
+    where
+        E: Into<Cow<'a, str>>,
+    {
+        self.emails
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(email.into());
+        self
+    }
+
+    /// Invite a set of email addresses.
+    pub fn emails<I, E>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = E>,
+        E: Into<Cow<'a, str>>,
+    {
+        self.emails
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    /// Invite a user (by ID).
+    pub fn user(&mut self, user: u64) -> &mut Self {
+        self.user_ids
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(user);
+        self
+    }
+
+    /// Invite a set of users (by ID).
+    pub fn users<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.user_ids
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateProjectInvitationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        let has_emails = matches!(self.emails, Some(Some(_)));
+        let has_users = matches!(self.user_ids, Some(Some(_)));
+
+        if !has_emails && !has_users {
+            return Err(CreateProjectInvitationBuilderError::ValidationError(
+                EMAIL_OR_USER_REQUIRED.into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateProjectInvitation<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/invitations", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("email", self.emails.as_ref())
+            .push_opt("user_id", self.user_ids.as_ref())
+            .push("access_level", self.access_level.as_u64())
+            .push_opt("expires_at", self.expires_at)
+            .push_opt("invite_source", self.invite_source.as_ref())
+            .push_opt("member_role_id", self.member_role_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::projects::invitations::{
+        CreateProjectInvitation, CreateProjectInvitationBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    use super::EMAIL_OR_USER_REQUIRED;
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateProjectInvitation::builder()
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectInvitationBuilderError, "project");
+    }
+
+    #[test]
+    fn access_level_is_needed() {
+        let err = CreateProjectInvitation::builder()
+            .project(1)
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateProjectInvitationBuilderError,
+            "access_level",
+        );
+    }
+
+    #[test]
+    fn email_or_user_is_needed() {
+        let err = CreateProjectInvitation::builder()
+            .project(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        if let CreateProjectInvitationBuilderError::ValidationError(msg) = err {
+            assert_eq!(msg, EMAIL_OR_USER_REQUIRED);
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn project_email_and_access_level_are_sufficient() {
+        CreateProjectInvitation::builder()
+            .project(1)
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateProjectInvitation::builder()
+            .project(0)
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateProjectInvitationBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_user_and_access_level_are_sufficient() {
+        CreateProjectInvitation::builder()
+            .project(1)
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/invitations")
+            .form_params(&[("email", "user@example.com"), ("access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_emails() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/invitations")
+            .form_params(&[
+                (
+                    "email",
+                    "user@example.com,other@example.com,third@example.com",
+                ),
+                ("access_level", "30"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .emails(["other@example.com", "third@example.com"].iter().copied())
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_users() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/invitations")
+            .form_params(&[("user_id", "1,2,3"), ("access_level", "30")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectInvitation::builder()
+            .project("simple/project")
+            .user(1)
+            .users([2, 3].iter().copied())
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/invitations")
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("access_level", "30"),
+                ("expires_at", "2020-01-01"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .expires_at(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_invite_source() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/invitations")
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("access_level", "30"),
+                ("invite_source", "onboarding"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .invite_source("onboarding")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/invitations")
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("access_level", "30"),
+                ("member_role_id", "1"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .access_level(AccessLevel::Developer)
+            .member_role_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a pending invitation to a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteProjectInvitation<'a> {
+    /// The project the invitation is for.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The email address the invitation was sent to.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+}
+
+impl<'a> DeleteProjectInvitation<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectInvitationBuilder<'a> {
+        DeleteProjectInvitationBuilder::default()
+    }
+}
+
+impl<'a> DeleteProjectInvitationBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteProjectInvitationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("email", self.email.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteProjectInvitation<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/invitations/{}",
+            self.project.path_segment(),
+            self.email.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::invitations::{
+        DeleteProjectInvitation, DeleteProjectInvitationBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_email_are_needed() {
+        let err = DeleteProjectInvitation::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectInvitationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteProjectInvitation::builder()
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectInvitationBuilderError, "project");
+    }
+
+    #[test]
+    fn email_is_needed() {
+        let err = DeleteProjectInvitation::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectInvitationBuilderError, "email");
+    }
+
+    #[test]
+    fn project_and_email_are_sufficient() {
+        DeleteProjectInvitation::builder()
+            .project(1)
+            .email("user@example.com")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteProjectInvitation::builder()
+            .project(0)
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteProjectInvitationBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/invitations/user@example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit a pending invitation to a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditProjectInvitation<'a> {
+    /// The project the invitation is for.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The email address the invitation was sent to.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+
+    /// The access level for the invited user in the project.
+    #[builder(default)]
+    access_level: Option<AccessLevel>,
+    /// When the invited user's access expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> EditProjectInvitation<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditProjectInvitationBuilder<'a> {
+        EditProjectInvitationBuilder::default()
+    }
+}
+
+impl<'a> EditProjectInvitationBuilder<'a> {
+    fn validate(&self) -> Result<(), EditProjectInvitationBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("email", self.email.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditProjectInvitation<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/invitations/{}",
+            self.project.path_segment(),
+            self.email.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt(
+                "access_level",
+                self.access_level.map(|level| level.as_u64()),
+            )
+            .push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::projects::invitations::{
+        EditProjectInvitation, EditProjectInvitationBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_email_are_needed() {
+        let err = EditProjectInvitation::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectInvitationBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditProjectInvitation::builder()
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectInvitationBuilderError, "project");
+    }
+
+    #[test]
+    fn email_is_needed() {
+        let err = EditProjectInvitation::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectInvitationBuilderError, "email");
+    }
+
+    #[test]
+    fn project_and_email_are_sufficient() {
+        EditProjectInvitation::builder()
+            .project(1)
+            .email("user@example.com")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditProjectInvitation::builder()
+            .project(0)
+            .email("user@example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditProjectInvitationBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/invitations/user@example.com")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/invitations/user@example.com")
+            .form_params(&[("access_level", "40")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .access_level(AccessLevel::Maintainer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/invitations/user@example.com")
+            .form_params(&[("expires_at", "2020-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectInvitation::builder()
+            .project("simple/project")
+            .email("user@example.com")
+            .expires_at(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for pending invitations to a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectInvitations<'a> {
+    /// The project to query for invitations.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Search for invitations by the invited email address.
+    #[builder(setter(into), default)]
+    query: Option<Cow<'a, str>>,
+}
+
+impl<'a> ProjectInvitations<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectInvitationsBuilder<'a> {
+        ProjectInvitationsBuilder::default()
+    }
+}
+
+impl<'a> ProjectInvitationsBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectInvitationsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectInvitations<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/invitations", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("query", self.query.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectInvitations<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::invitations::{ProjectInvitations, ProjectInvitationsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectInvitations::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectInvitationsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectInvitations::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectInvitations::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectInvitationsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/invitations")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectInvitations::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/invitations")
+            .add_query_params(&[("query", "user@example.com")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectInvitations::builder()
+            .project("simple/project")
+            .query("user@example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,15 +8,30 @@
 //!
 //! These endpoints are used for querying projects issues.
 
+mod add_spent_time;
 pub mod awards;
 mod create;
 mod edit;
 mod issue;
 mod issues;
+pub mod links;
 mod merge_requests_closing;
 pub mod notes;
 mod related_merge_requests;
+mod reset_spent_time;
+mod reset_time_estimate;
+mod resource_iteration_events;
 mod resource_label_events;
+mod resource_milestone_events;
+mod resource_state_events;
+mod resource_weight_events;
+mod time_estimate;
+mod time_stats;
+mod user_agent_detail;
+
+pub use self::add_spent_time::AddIssueSpentTime;
+pub use self::add_spent_time::AddIssueSpentTimeBuilder;
+pub use self::add_spent_time::AddIssueSpentTimeBuilderError;
 
 pub use self::create::CreateIssue;
 pub use self::create::CreateIssueBuilder;
@@ -50,6 +65,42 @@ pub use self::related_merge_requests::RelatedMergeRequests;
 pub use self::related_merge_requests::RelatedMergeRequestsBuilder;
 pub use self::related_merge_requests::RelatedMergeRequestsBuilderError;
 
+pub use self::reset_spent_time::ResetIssueSpentTime;
+pub use self::reset_spent_time::ResetIssueSpentTimeBuilder;
+pub use self::reset_spent_time::ResetIssueSpentTimeBuilderError;
+
+pub use self::reset_time_estimate::ResetIssueTimeEstimate;
+pub use self::reset_time_estimate::ResetIssueTimeEstimateBuilder;
+pub use self::reset_time_estimate::ResetIssueTimeEstimateBuilderError;
+
+pub use self::resource_iteration_events::IssueResourceIterationEvents;
+pub use self::resource_iteration_events::IssueResourceIterationEventsBuilder;
+pub use self::resource_iteration_events::IssueResourceIterationEventsBuilderError;
+
 pub use self::resource_label_events::IssueResourceLabelEvents;
 pub use self::resource_label_events::IssueResourceLabelEventsBuilder;
 pub use self::resource_label_events::IssueResourceLabelEventsBuilderError;
+
+pub use self::resource_milestone_events::IssueResourceMilestoneEvents;
+pub use self::resource_milestone_events::IssueResourceMilestoneEventsBuilder;
+pub use self::resource_milestone_events::IssueResourceMilestoneEventsBuilderError;
+
+pub use self::resource_state_events::IssueResourceStateEvents;
+pub use self::resource_state_events::IssueResourceStateEventsBuilder;
+pub use self::resource_state_events::IssueResourceStateEventsBuilderError;
+
+pub use self::resource_weight_events::IssueResourceWeightEvents;
+pub use self::resource_weight_events::IssueResourceWeightEventsBuilder;
+pub use self::resource_weight_events::IssueResourceWeightEventsBuilderError;
+
+pub use self::time_estimate::SetIssueTimeEstimate;
+pub use self::time_estimate::SetIssueTimeEstimateBuilder;
+pub use self::time_estimate::SetIssueTimeEstimateBuilderError;
+
+pub use self::time_stats::IssueTimeStats;
+pub use self::time_stats::IssueTimeStatsBuilder;
+pub use self::time_stats::IssueTimeStatsBuilderError;
+
+pub use self::user_agent_detail::IssueUserAgentDetail;
+pub use self::user_agent_detail::IssueUserAgentDetailBuilder;
+pub use self::user_agent_detail::IssueUserAgentDetailBuilderError;
@@ -0,0 +1,199 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, HumanDuration, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Add time spent on an issue.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct AddIssueSpentTime<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+
+    /// The time spent.
+    ///
+    /// Negative durations subtract from the time spent.
+    duration: HumanDuration,
+
+    /// A summary of how the time was spent.
+    #[builder(setter(into), default)]
+    summary: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddIssueSpentTime<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddIssueSpentTimeBuilder<'a> {
+        AddIssueSpentTimeBuilder::default()
+    }
+}
+
+impl<'a> AddIssueSpentTimeBuilder<'a> {
+    fn validate(&self) -> Result<(), AddIssueSpentTimeBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for AddIssueSpentTime<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/add_spent_time",
+            self.project.path_segment(),
+            self.issue.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("duration", self.duration)
+            .push_opt("summary", self.summary.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::HumanDuration;
+    use crate::api::projects::issues::{AddIssueSpentTime, AddIssueSpentTimeBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_and_duration_are_needed() {
+        let err = AddIssueSpentTime::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = AddIssueSpentTime::builder()
+            .issue(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = AddIssueSpentTime::builder()
+            .project(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "issue");
+    }
+
+    #[test]
+    fn duration_is_needed() {
+        let err = AddIssueSpentTime::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddIssueSpentTimeBuilderError, "duration");
+    }
+
+    #[test]
+    fn project_issue_and_duration_are_sufficient() {
+        AddIssueSpentTime::builder()
+            .project(1)
+            .issue(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = AddIssueSpentTime::builder()
+            .project(0)
+            .issue(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, AddIssueSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=3h30m")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddIssueSpentTime::builder()
+            .project("simple/project")
+            .issue(1)
+            .duration(HumanDuration::from_mins(210))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_negative() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=-1h")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddIssueSpentTime::builder()
+            .project("simple/project")
+            .issue(1)
+            .duration(HumanDuration::from_hours(1).negate())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_summary() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("duration=1h", "&summary=Code+review"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddIssueSpentTime::builder()
+            .project("simple/project")
+            .issue(1)
+            .duration(HumanDuration::from_hours(1))
+            .summary("Code review")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
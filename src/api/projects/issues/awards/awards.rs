@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for awards on an issue within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct IssueAwards<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> IssueAwards<'a> {
     }
 }
 
+impl<'a> IssueAwardsBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueAwardsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for IssueAwards<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for IssueAwards<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/award_emoji",
-            self.project, self.issue,
+            self.project.path_segment(),
+            self.issue.path_segment(),
         )
         .into()
     }
@@ -71,6 +82,16 @@ mod tests {
         IssueAwards::builder().project(1).issue(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueAwards::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssueAwardsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new award on an issue on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateIssueAward<'a> {
     /// The project the issue belongs to.
     #[builder(setter(into))]
@@ -29,6 +30,15 @@ impl<'a> CreateIssueAward<'a> {
     }
 }
 
+impl<'a> CreateIssueAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateIssueAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateIssueAward<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -37,7 +47,8 @@ impl<'a> Endpoint for CreateIssueAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/award_emoji",
-            self.project, self.issue,
+            self.project.path_segment(),
+            self.issue.path_segment(),
         )
         .into()
     }
@@ -105,6 +116,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateIssueAward::builder()
+            .project(0)
+            .issue(1)
+            .name("award")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateIssueAwardBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete an existing award on an issue within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteIssueAward<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -28,6 +29,16 @@ impl<'a> DeleteIssueAward<'a> {
     }
 }
 
+impl<'a> DeleteIssueAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteIssueAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("award", self.award.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteIssueAward<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -36,7 +47,9 @@ impl<'a> Endpoint for DeleteIssueAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/award_emoji/{}",
-            self.project, self.issue, self.award,
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.award.path_segment(),
         )
         .into()
     }
@@ -96,6 +109,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteIssueAward::builder()
+            .project(0)
+            .issue(1)
+            .award(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteIssueAwardBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
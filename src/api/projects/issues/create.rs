@@ -9,13 +9,13 @@ use std::collections::BTreeSet;
 use chrono::{DateTime, NaiveDate, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::issues::IssueType;
 
 /// Create a new issue on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateIssue<'a> {
     /// The project to add the issue to.
     #[builder(setter(into))]
@@ -133,6 +133,12 @@ impl<'a> CreateIssueBuilder<'a> {
             .extend(iter.into_iter().map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), CreateIssueBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateIssue<'a> {
@@ -141,7 +147,7 @@ impl<'a> Endpoint for CreateIssue<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues", self.project).into()
+        format!("projects/{}/issues", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -158,7 +164,7 @@ impl<'a> Endpoint for CreateIssue<'a> {
             .extend(
                 self.assignee_ids
                     .iter()
-                    .map(|&value| ("assignee_ids[]", value)),
+                    .map(|&value| (ParamName::new("assignee_ids").array(), value)),
             )
             .push_opt("milestone_id", self.milestone_id)
             .push_opt("labels", self.labels.as_ref())
@@ -219,6 +225,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateIssue::builder()
+            .project(0)
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateIssueBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
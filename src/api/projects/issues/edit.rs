@@ -10,7 +10,7 @@ use std::iter;
 use chrono::{DateTime, NaiveDate, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::issues::IssueType;
 use crate::api::ParamValue;
@@ -65,7 +65,7 @@ impl<'a, 'b: 'a> ParamValue<'a> for &'b IssueLabels<'a> {
 
 /// Create a new issue on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditIssue<'a> {
     /// The project to add the issue to.
     #[builder(setter(into))]
@@ -259,6 +259,13 @@ impl<'a> EditIssueBuilder<'a> {
             .push(label.into());
         self
     }
+
+    fn validate(&self) -> Result<(), EditIssueBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for EditIssue<'a> {
@@ -267,7 +274,12 @@ impl<'a> Endpoint for EditIssue<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues/{}", self.project, self.issue).into()
+        format!(
+            "projects/{}/issues/{}",
+            self.project.path_segment(),
+            self.issue.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -292,10 +304,13 @@ impl<'a> Endpoint for EditIssue<'a> {
         if let Some(assignees) = self.assignee_ids.as_ref() {
             match assignees {
                 IssueAssignees::Unassigned => {
-                    params.push("assignee_ids[]", "0");
+                    params.push(ParamName::new("assignee_ids").array(), "0");
                 },
                 IssueAssignees::Assignees(ids) => {
-                    params.extend(ids.iter().map(|&value| ("assignee_ids[]", value)));
+                    params.extend(
+                        ids.iter()
+                            .map(|&value| (ParamName::new("assignee_ids").array(), value)),
+                    );
                 },
             }
         }
@@ -354,6 +369,16 @@ mod tests {
         EditIssue::builder().project(1).issue(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditIssue::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditIssueBuilderError, "project");
+    }
+
     #[test]
     #[allow(deprecated)]
     fn project_and_issue_iid_are_sufficient() {
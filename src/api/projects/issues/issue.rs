@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a issue within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Issue<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> Issue<'a> {
     }
 }
 
+impl<'a> IssueBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Issue<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues/{}", self.project, self.issue).into()
+        format!(
+            "projects/{}/issues/{}",
+            self.project.path_segment(),
+            self.issue.path_segment()
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,12 @@ mod tests {
         Issue::builder().project(1).issue(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Issue::builder().project(0).issue(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssueBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a link between issues.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteIssueLink<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The ID of the link.
+    issue_link_id: u64,
+}
+
+impl<'a> DeleteIssueLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteIssueLinkBuilder<'a> {
+        DeleteIssueLinkBuilder::default()
+    }
+}
+
+impl<'a> DeleteIssueLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteIssueLinkBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("issue_link_id", self.issue_link_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteIssueLink<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/links/{}",
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.issue_link_id.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::links::{DeleteIssueLink, DeleteIssueLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = DeleteIssueLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteIssueLink::builder()
+            .issue(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = DeleteIssueLink::builder()
+            .project(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueLinkBuilderError, "issue");
+    }
+
+    #[test]
+    fn issue_link_id_is_needed() {
+        let err = DeleteIssueLink::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueLinkBuilderError, "issue_link_id");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        DeleteIssueLink::builder()
+            .project(1)
+            .issue(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteIssueLink::builder()
+            .project(0)
+            .issue(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteIssueLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/issues/1/links/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteIssueLink::builder()
+            .project("simple/project")
+            .issue(1)
+            .issue_link_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
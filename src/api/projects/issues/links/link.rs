@@ -0,0 +1,137 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a link between issues.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct IssueLink<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The ID of the link.
+    issue_link_id: u64,
+}
+
+impl<'a> IssueLink<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueLinkBuilder<'a> {
+        IssueLinkBuilder::default()
+    }
+}
+
+impl<'a> IssueLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueLinkBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("issue_link_id", self.issue_link_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for IssueLink<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/links/{}",
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.issue_link_id.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::issues::links::{IssueLink, IssueLinkBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = IssueLink::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueLink::builder()
+            .issue(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = IssueLink::builder()
+            .project(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueLinkBuilderError, "issue");
+    }
+
+    #[test]
+    fn issue_link_id_is_needed() {
+        let err = IssueLink::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueLinkBuilderError, "issue_link_id");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        IssueLink::builder()
+            .project(1)
+            .issue(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueLink::builder()
+            .project(0)
+            .issue(1)
+            .issue_link_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssueLinkBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/links/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueLink::builder()
+            .project("simple/project")
+            .issue(1)
+            .issue_link_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
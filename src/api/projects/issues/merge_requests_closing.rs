@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for merge requests closing an issue
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestsClosing<'a> {
     /// The project to of the merge request.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> MergeRequestsClosing<'a> {
     }
 }
 
+impl<'a> MergeRequestsClosingBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestsClosingBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestsClosing<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues/{}/closed_by", self.project, self.issue).into()
+        format!(
+            "projects/{}/issues/{}/closed_by",
+            self.project.path_segment(),
+            self.issue.path_segment()
+        )
+        .into()
     }
 }
 
@@ -77,6 +92,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestsClosing::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestsClosingBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
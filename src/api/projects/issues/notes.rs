@@ -10,18 +10,31 @@
 
 pub mod awards;
 mod create;
+mod delete;
 mod edit;
+mod note;
 mod notes;
 
 pub use self::create::CreateIssueNote;
 pub use self::create::CreateIssueNoteBuilder;
 pub use self::create::CreateIssueNoteBuilderError;
 
+pub use self::delete::DeleteIssueNote;
+pub use self::delete::DeleteIssueNoteBuilder;
+pub use self::delete::DeleteIssueNoteBuilderError;
+
 pub use self::edit::EditIssueNote;
 pub use self::edit::EditIssueNoteBuilder;
 pub use self::edit::EditIssueNoteBuilderError;
 
+pub use self::note::IssueNote;
+pub use self::note::IssueNoteBuilder;
+pub use self::note::IssueNoteBuilderError;
+
 pub use self::notes::IssueNotes;
 pub use self::notes::IssueNotesBuilder;
 pub use self::notes::IssueNotesBuilderError;
+pub use crate::api::helpers::filter_notes;
+pub use crate::api::helpers::FilteredNotes;
+pub use crate::api::helpers::NoteActivityFilter;
 pub use crate::api::helpers::NoteOrderBy;
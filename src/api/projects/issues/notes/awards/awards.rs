@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for awards on a note on an issue within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct IssueNoteAwards<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -28,6 +29,16 @@ impl<'a> IssueNoteAwards<'a> {
     }
 }
 
+impl<'a> IssueNoteAwardsBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueNoteAwardsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for IssueNoteAwards<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -36,7 +47,9 @@ impl<'a> Endpoint for IssueNoteAwards<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/notes/{}/award_emoji",
-            self.project, self.issue, self.note,
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.note.path_segment(),
         )
         .into()
     }
@@ -98,6 +111,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueNoteAwards::builder()
+            .project(0)
+            .issue(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssueNoteAwardsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
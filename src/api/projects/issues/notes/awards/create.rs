@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new award on a note on an issue on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateIssueNoteAward<'a> {
     /// The project the issue belongs to.
     #[builder(setter(into))]
@@ -31,6 +32,16 @@ impl<'a> CreateIssueNoteAward<'a> {
     }
 }
 
+impl<'a> CreateIssueNoteAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateIssueNoteAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateIssueNoteAward<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -39,7 +50,9 @@ impl<'a> Endpoint for CreateIssueNoteAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/notes/{}/award_emoji",
-            self.project, self.issue, self.note,
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.note.path_segment(),
         )
         .into()
     }
@@ -124,6 +137,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateIssueNoteAward::builder()
+            .project(0)
+            .issue(1)
+            .note(1)
+            .name("award")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateIssueNoteAwardBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
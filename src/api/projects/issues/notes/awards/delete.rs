@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete an existing award on a note within an issue within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteIssueNoteAward<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -30,6 +31,17 @@ impl<'a> DeleteIssueNoteAward<'a> {
     }
 }
 
+impl<'a> DeleteIssueNoteAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteIssueNoteAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+        common::validate_path_param("award", self.award.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteIssueNoteAward<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -38,7 +50,10 @@ impl<'a> Endpoint for DeleteIssueNoteAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/notes/{}/award_emoji/{}",
-            self.project, self.issue, self.note, self.award,
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.note.path_segment(),
+            self.award.path_segment(),
         )
         .into()
     }
@@ -115,6 +130,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteIssueNoteAward::builder()
+            .project(0)
+            .issue(1)
+            .note(1)
+            .award(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteIssueNoteAwardBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -7,12 +7,12 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new note on an issue on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateIssueNote<'a> {
     /// The project the issue belongs to.
     #[builder(setter(into))]
@@ -44,13 +44,27 @@ impl<'a> CreateIssueNote<'a> {
     }
 }
 
+impl<'a> CreateIssueNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateIssueNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateIssueNote<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues/{}/notes", self.project, self.issue).into()
+        format!(
+            "projects/{}/issues/{}/notes",
+            self.project.path_segment(),
+            self.issue.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -127,6 +141,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateIssueNote::builder()
+            .project(0)
+            .issue(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateIssueNoteBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a note on an issue.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteIssueNote<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> DeleteIssueNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteIssueNoteBuilder<'a> {
+        DeleteIssueNoteBuilder::default()
+    }
+}
+
+impl<'a> DeleteIssueNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteIssueNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteIssueNote<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/notes/{}",
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::issues::notes::{DeleteIssueNote, DeleteIssueNoteBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_and_note_are_necessary() {
+        let err = DeleteIssueNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteIssueNote::builder()
+            .issue(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_necessary() {
+        let err = DeleteIssueNote::builder()
+            .project(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueNoteBuilderError, "issue");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = DeleteIssueNote::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteIssueNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn project_issue_and_note_are_sufficient() {
+        DeleteIssueNote::builder()
+            .project(1)
+            .issue(1)
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteIssueNote::builder()
+            .project(0)
+            .issue(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteIssueNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/issues/1/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteIssueNote::builder()
+            .project("simple/project")
+            .issue(1)
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Edit an issue note on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditIssueNote<'a> {
     /// The project to add the issue to.
     #[builder(setter(into))]
@@ -40,6 +40,16 @@ impl<'a> EditIssueNote<'a> {
     }
 }
 
+impl<'a> EditIssueNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EditIssueNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditIssueNote<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -48,7 +58,9 @@ impl<'a> Endpoint for EditIssueNote<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/notes/{}",
-            self.project, self.issue, self.note,
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.note.path_segment(),
         )
         .into()
     }
@@ -136,6 +148,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditIssueNote::builder()
+            .project(0)
+            .issue(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditIssueNoteBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
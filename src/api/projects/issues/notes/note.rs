@@ -0,0 +1,129 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query a single note on an issue.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct IssueNote<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the issue.
+    issue: u64,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> IssueNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueNoteBuilder<'a> {
+        IssueNoteBuilder::default()
+    }
+}
+
+impl<'a> IssueNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for IssueNote<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/notes/{}",
+            self.project.path_segment(),
+            self.issue.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::issues::notes::{IssueNote, IssueNoteBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_and_note_are_necessary() {
+        let err = IssueNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = IssueNote::builder().issue(1).note(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_necessary() {
+        let err = IssueNote::builder().project(1).note(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueNoteBuilderError, "issue");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = IssueNote::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn project_issue_and_note_are_sufficient() {
+        IssueNote::builder()
+            .project(1)
+            .issue(1)
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueNote::builder()
+            .project(0)
+            .issue(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssueNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueNote::builder()
+            .project("simple/project")
+            .issue(1)
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
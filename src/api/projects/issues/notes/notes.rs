@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::helpers::NoteOrderBy;
 
 /// Query for notes on an issue within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct IssueNotes<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -35,13 +35,27 @@ impl<'a> IssueNotes<'a> {
     }
 }
 
+impl<'a> IssueNotesBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueNotesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for IssueNotes<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/issues/{}/notes", self.project, self.issue).into()
+        format!(
+            "projects/{}/issues/{}/notes",
+            self.project.path_segment(),
+            self.issue.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -59,10 +73,16 @@ impl<'a> Pageable for IssueNotes<'a> {}
 
 #[cfg(test)]
 mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
     use crate::api::common::SortOrder;
-    use crate::api::projects::issues::notes::{IssueNotes, IssueNotesBuilderError, NoteOrderBy};
-    use crate::api::{self, Query};
+    use crate::api::projects::issues::notes::{
+        filter_notes, IssueNotes, IssueNotesBuilderError, NoteActivityFilter, NoteOrderBy,
+    };
+    use crate::api::{self, AsyncQuery, Pagination, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedRequest, MockClient};
 
     #[test]
     fn project_and_issue_are_necessary() {
@@ -87,6 +107,16 @@ mod tests {
         IssueNotes::builder().project(1).issue(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueNotes::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssueNotesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -138,4 +168,64 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[derive(Debug, Deserialize)]
+    struct Note {
+        id: u64,
+    }
+
+    fn mock_client() -> MockClient {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/simple%2Fproject/issues/1/notes")
+                .build()
+                .unwrap(),
+            [
+                json!({"id": 1, "system": false}),
+                json!({"id": 2, "system": true}),
+                json!({"id": 3, "system": false}),
+            ],
+        );
+        client
+    }
+
+    fn ids(notes: Vec<Note>) -> Vec<u64> {
+        notes.into_iter().map(|note| note.id).collect()
+    }
+
+    #[test]
+    fn endpoint_only_comments() {
+        let client = mock_client();
+
+        let endpoint = IssueNotes::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        let notes: Vec<Note> =
+            filter_notes(endpoint, Pagination::All, NoteActivityFilter::OnlyComments)
+                .query(&client)
+                .unwrap();
+        assert_eq!(ids(notes), [1, 3]);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn endpoint_only_activity_async() {
+        let client = mock_client();
+
+        let endpoint = IssueNotes::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        let notes: Vec<Note> =
+            filter_notes(endpoint, Pagination::All, NoteActivityFilter::OnlyActivity)
+                .query_async(&client)
+                .await
+                .unwrap();
+        assert_eq!(ids(notes), [2]);
+        client.assert_all_consumed();
+    }
 }
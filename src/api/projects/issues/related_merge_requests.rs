@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for merge requests related to an issue
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct RelatedMergeRequests<'a> {
     /// The project to of the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> RelatedMergeRequests<'a> {
     }
 }
 
+impl<'a> RelatedMergeRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), RelatedMergeRequestsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RelatedMergeRequests<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for RelatedMergeRequests<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/related_merge_requests",
-            self.project, self.issue,
+            self.project.path_segment(),
+            self.issue.path_segment(),
         )
         .into()
     }
@@ -81,6 +92,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RelatedMergeRequests::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RelatedMergeRequestsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for resource iteration events for an issue.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct IssueResourceIterationEvents<'a> {
+    /// The project to query for the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue.
+    issue: u64,
+}
+
+impl<'a> IssueResourceIterationEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueResourceIterationEventsBuilder<'a> {
+        IssueResourceIterationEventsBuilder::default()
+    }
+}
+
+impl<'a> IssueResourceIterationEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueResourceIterationEventsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for IssueResourceIterationEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/resource_iteration_events",
+            self.project.path_segment(),
+            self.issue.path_segment(),
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for IssueResourceIterationEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::issues::{
+        IssueResourceIterationEvents, IssueResourceIterationEventsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_issue_are_needed() {
+        let err = IssueResourceIterationEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            IssueResourceIterationEventsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = IssueResourceIterationEvents::builder()
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            IssueResourceIterationEventsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = IssueResourceIterationEvents::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, IssueResourceIterationEventsBuilderError, "issue");
+    }
+
+    #[test]
+    fn project_and_issue_are_sufficient() {
+        IssueResourceIterationEvents::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueResourceIterationEvents::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            IssueResourceIterationEventsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/resource_iteration_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueResourceIterationEvents::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for resource label events for an issue.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct IssueResourceLabelEvents<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> IssueResourceLabelEvents<'a> {
     }
 }
 
+impl<'a> IssueResourceLabelEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), IssueResourceLabelEventsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("issue", self.issue.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for IssueResourceLabelEvents<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for IssueResourceLabelEvents<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/issues/{}/resource_label_events",
-            self.project, self.issue,
+            self.project.path_segment(),
+            self.issue.path_segment(),
         )
         .into()
     }
@@ -83,6 +94,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssueResourceLabelEvents::builder()
+            .project(0)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            IssueResourceLabelEventsBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
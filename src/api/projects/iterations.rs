@@ -0,0 +1,326 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project iteration API endpoint.
+//!
+//! These endpoints are used for querying iterations available to a project.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// States of iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IterationState {
+    /// Iterations which have been opened.
+    Opened,
+    /// Iterations which have not yet started.
+    Upcoming,
+    /// Iterations which are currently in progress.
+    Current,
+    /// Iterations which have been closed.
+    Closed,
+    /// All iterations.
+    All,
+}
+
+impl IterationState {
+    fn as_str(self) -> &'static str {
+        match self {
+            IterationState::Opened => "opened",
+            IterationState::Upcoming => "upcoming",
+            IterationState::Current => "current",
+            IterationState::Closed => "closed",
+            IterationState::All => "all",
+        }
+    }
+}
+
+impl ParamValue<'static> for IterationState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// The scope to apply search query terms to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IterationSearchScope {
+    /// Search within iteration titles.
+    Title,
+    /// Search within iteration cadence titles.
+    CadenceTitle,
+}
+
+impl IterationSearchScope {
+    fn as_str(self) -> &'static str {
+        match self {
+            IterationSearchScope::Title => "title",
+            IterationSearchScope::CadenceTitle => "cadence_title",
+        }
+    }
+}
+
+impl ParamValue<'static> for IterationSearchScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for iterations within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectIterations<'a> {
+    /// The project to query for iterations.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter iterations by state.
+    #[builder(default)]
+    state: Option<IterationState>,
+    /// Search for iterations matching the given string.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// The scopes to look for search query within.
+    #[builder(setter(name = "_search_in"), default, private)]
+    search_in: Option<CommaSeparatedList<IterationSearchScope>>,
+    /// Include iterations from the parent group and its ancestors.
+    #[builder(default)]
+    include_ancestors: Option<bool>,
+    /// Return only iterations updated before the given time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+    /// Return only iterations updated after the given time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+}
+
+impl<'a> ProjectIterations<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectIterationsBuilder<'a> {
+        ProjectIterationsBuilder::default()
+    }
+}
+
+impl<'a> ProjectIterationsBuilder<'a> {
+    /// The scopes to look for search query within.
+    pub fn search_in(&mut self, scope: IterationSearchScope) -> &mut Self {
+        self.search_in
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(scope);
+        self
+    }
+
+    fn validate(&self) -> Result<(), ProjectIterationsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectIterations<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/iterations", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("state", self.state)
+            .push_opt("search", self.search.as_ref())
+            .push_opt("in", self.search_in.as_ref())
+            .push_opt("include_ancestors", self.include_ancestors)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("updated_after", self.updated_after);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectIterations<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::projects::iterations::{
+        IterationSearchScope, IterationState, ProjectIterations, ProjectIterationsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn iteration_state_as_str() {
+        let items = &[
+            (IterationState::Opened, "opened"),
+            (IterationState::Upcoming, "upcoming"),
+            (IterationState::Current, "current"),
+            (IterationState::Closed, "closed"),
+            (IterationState::All, "all"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn iteration_search_scope_as_str() {
+        let items = &[
+            (IterationSearchScope::Title, "title"),
+            (IterationSearchScope::CadenceTitle, "cadence_title"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectIterations::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectIterationsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectIterations::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectIterations::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectIterationsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .add_query_params(&[("state", "current")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .state(IterationState::Current)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .add_query_params(&[("search", "sprint")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .search("sprint")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search_in() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .add_query_params(&[("in", "title,cadence_title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .search_in(IterationSearchScope::Title)
+            .search_in(IterationSearchScope::CadenceTitle)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestors() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .add_query_params(&[("include_ancestors", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .include_ancestors(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/iterations")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIterations::builder()
+            .project("simple/project")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,14 +8,25 @@
 //!
 //! These endpoints are used for querying CI jobs.
 
+mod artifact_file;
+mod artifacts;
 mod cancel;
 mod erase;
 mod job;
 mod jobs;
 mod play;
 mod retry;
+mod tail;
 mod trace;
 
+pub use self::artifact_file::ArtifactFile;
+pub use self::artifact_file::ArtifactFileBuilder;
+pub use self::artifact_file::ArtifactFileBuilderError;
+
+pub use self::artifacts::JobArtifacts;
+pub use self::artifacts::JobArtifactsBuilder;
+pub use self::artifacts::JobArtifactsBuilderError;
+
 pub use self::cancel::CancelJob;
 pub use self::cancel::CancelJobBuilder;
 pub use self::cancel::CancelJobBuilderError;
@@ -44,6 +55,11 @@ pub use self::retry::RetryJob;
 pub use self::retry::RetryJobBuilder;
 pub use self::retry::RetryJobBuilderError;
 
+pub use self::tail::JobLogTail;
+pub use self::tail::JobLogTailBuilder;
+pub use self::tail::JobLogTailBuilderError;
+pub use self::tail::JobLogTailIter;
+
 pub use self::trace::JobTrace;
 pub use self::trace::JobTraceBuilder;
 pub use self::trace::JobTraceBuilderError;
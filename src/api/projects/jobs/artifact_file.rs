@@ -0,0 +1,191 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Download a single file from the artifacts of a job.
+///
+/// Only the requested file is extracted from the archive by GitLab, so this avoids downloading
+/// the entire archive. The response is the file itself; use `api::raw` to get its contents.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ArtifactFile<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the job.
+    job: u64,
+    /// The path to the file within the artifacts archive.
+    #[builder(setter(into))]
+    artifact_path: Cow<'a, str>,
+    /// A CI job token to authenticate with.
+    ///
+    /// Used to fetch artifacts from within a CI job for multi-project pipelines.
+    #[builder(setter(into), default)]
+    job_token: Option<Cow<'a, str>>,
+}
+
+impl<'a> ArtifactFile<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ArtifactFileBuilder<'a> {
+        ArtifactFileBuilder::default()
+    }
+}
+
+impl<'a> ArtifactFileBuilder<'a> {
+    fn validate(&self) -> Result<(), ArtifactFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("job", self.job.as_ref())?;
+        common::validate_path_param("artifact_path", self.artifact_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
+/// Escape each component of a path while keeping the separators.
+struct ArtifactPath<'a>(&'a str);
+
+impl<'a> fmt::Display for ArtifactPath<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, component) in self.0.split('/').enumerate() {
+            if idx != 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{}", common::path_escaped(component))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ArtifactFile<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/jobs/{}/artifacts/{}",
+            self.project.path_segment(),
+            self.job.path_segment(),
+            ArtifactPath(&self.artifact_path),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("job_token", self.job_token.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::jobs::{ArtifactFile, ArtifactFileBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_job_and_artifact_path_are_needed() {
+        let err = ArtifactFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ArtifactFileBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ArtifactFile::builder()
+            .job(1)
+            .artifact_path("coverage.xml")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ArtifactFileBuilderError, "project");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = ArtifactFile::builder()
+            .project(1)
+            .artifact_path("coverage.xml")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ArtifactFileBuilderError, "job");
+    }
+
+    #[test]
+    fn artifact_path_is_needed() {
+        let err = ArtifactFile::builder()
+            .project(1)
+            .job(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ArtifactFileBuilderError, "artifact_path");
+    }
+
+    #[test]
+    fn project_job_and_artifact_path_are_sufficient() {
+        ArtifactFile::builder()
+            .project(1)
+            .job(1)
+            .artifact_path("coverage.xml")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ArtifactFile::builder()
+            .project(0)
+            .job(1)
+            .artifact_path("coverage.xml")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ArtifactFileBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/artifacts/build/coverage%20report.xml")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ArtifactFile::builder()
+            .project("simple/project")
+            .job(1)
+            .artifact_path("build/coverage report.xml")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_job_token() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/artifacts/coverage.xml")
+            .add_query_params(&[("job_token", "token")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ArtifactFile::builder()
+            .project("simple/project")
+            .job(1)
+            .artifact_path("coverage.xml")
+            .job_token("token")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Download the artifacts archive of a job.
+///
+/// The response is the archive itself; use `api::raw` to get its contents.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct JobArtifacts<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the job.
+    job: u64,
+    /// A CI job token to authenticate with.
+    ///
+    /// Used to fetch artifacts from within a CI job for multi-project pipelines.
+    #[builder(setter(into), default)]
+    job_token: Option<Cow<'a, str>>,
+}
+
+impl<'a> JobArtifacts<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> JobArtifactsBuilder<'a> {
+        JobArtifactsBuilder::default()
+    }
+}
+
+impl<'a> JobArtifactsBuilder<'a> {
+    fn validate(&self) -> Result<(), JobArtifactsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("job", self.job.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for JobArtifacts<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/jobs/{}/artifacts",
+            self.project.path_segment(),
+            self.job.path_segment()
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("job_token", self.job_token.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::jobs::{JobArtifacts, JobArtifactsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_job_are_needed() {
+        let err = JobArtifacts::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobArtifacts::builder().job(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsBuilderError, "project");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = JobArtifacts::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobArtifactsBuilderError, "job");
+    }
+
+    #[test]
+    fn project_and_job_are_sufficient() {
+        JobArtifacts::builder().project(1).job(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = JobArtifacts::builder()
+            .project(0)
+            .job(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, JobArtifactsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/artifacts")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobArtifacts::builder()
+            .project("simple/project")
+            .job(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_job_token() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/artifacts")
+            .add_query_params(&[("job_token", "token")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobArtifacts::builder()
+            .project("simple/project")
+            .job(1)
+            .job_token("token")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
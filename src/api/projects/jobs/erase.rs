@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Erase a job.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EraseJob<'a> {
     /// The project which owns the job.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> EraseJob<'a> {
     }
 }
 
+impl<'a> EraseJobBuilder<'a> {
+    fn validate(&self) -> Result<(), EraseJobBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("job", self.job.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EraseJob<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/jobs/{}/erase", self.project, self.job).into()
+        format!(
+            "projects/{}/jobs/{}/erase",
+            self.project.path_segment(),
+            self.job.path_segment()
+        )
+        .into()
     }
 }
 
@@ -67,6 +82,12 @@ mod tests {
         EraseJob::builder().project(1).job(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EraseJob::builder().project(0).job(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EraseJobBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
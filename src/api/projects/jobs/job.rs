@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a job within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Job<'a> {
     /// The project to query for the job.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> Job<'a> {
     }
 }
 
+impl<'a> JobBuilder<'a> {
+    fn validate(&self) -> Result<(), JobBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("job", self.job.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Job<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/jobs/{}", self.project, self.job).into()
+        format!(
+            "projects/{}/jobs/{}",
+            self.project.path_segment(),
+            self.job.path_segment()
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,12 @@ mod tests {
         Job::builder().project(1).job(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Job::builder().project(0).job(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, JobBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
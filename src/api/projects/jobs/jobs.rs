@@ -8,7 +8,7 @@ use std::collections::HashSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -61,7 +61,7 @@ impl ParamValue<'static> for JobScope {
 
 /// Query for jobs within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Jobs<'a> {
     /// The project to query for jobs.
     #[builder(setter(into))]
@@ -97,6 +97,12 @@ impl<'a> JobsBuilder<'a> {
         self.scopes.get_or_insert_with(HashSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), JobsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for Jobs<'a> {
@@ -105,14 +111,18 @@ impl<'a> Endpoint for Jobs<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/jobs", self.project).into()
+        format!("projects/{}/jobs", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
 
         params
-            .extend(self.scopes.iter().map(|&value| ("scope[]", value)))
+            .extend(
+                self.scopes
+                    .iter()
+                    .map(|&value| (ParamName::new("scope").array(), value)),
+            )
             .push_opt("include_retried", self.include_retried);
 
         params
@@ -161,6 +171,12 @@ mod tests {
         Jobs::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Jobs::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, JobsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// A job variable for a manual job.
@@ -27,13 +27,24 @@ impl<'a> JobVariableAttribute<'a> {
     }
 
     fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
-        params.push("job_variables_attributes[][key]", self.key.as_ref());
-        params.push("job_variables_attributes[][value]", self.value.as_ref());
+        params.push(
+            ParamName::new("job_variables_attributes")
+                .array()
+                .key("key"),
+            self.key.as_ref(),
+        );
+        params.push(
+            ParamName::new("job_variables_attributes")
+                .array()
+                .key("value"),
+            self.value.as_ref(),
+        );
     }
 }
 
 /// Play a job.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PlayJob<'a> {
     /// The project which owns the job.
     #[builder(setter(into))]
@@ -72,6 +83,13 @@ impl<'a> PlayJobBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), PlayJobBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("job", self.job.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for PlayJob<'a> {
@@ -80,7 +98,12 @@ impl<'a> Endpoint for PlayJob<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/jobs/{}/play", self.project, self.job).into()
+        format!(
+            "projects/{}/jobs/{}/play",
+            self.project.path_segment(),
+            self.job.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -160,6 +183,12 @@ mod tests {
         PlayJob::builder().project(1).job(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PlayJob::builder().project(0).job(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PlayJobBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,490 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+use std::future::Future;
+use std::thread;
+use std::time::Duration;
+
+use bytes::Bytes;
+use derive_builder::Builder;
+use futures_util::Stream;
+use http::{header, Request, Response, StatusCode};
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::jobs::{Job, JobTrace};
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// Follow the log of a job as it runs.
+///
+/// The job's trace is fetched repeatedly, requesting only the bytes after those which have
+/// already been seen. New output is yielded as it appears, much like the log view in the web
+/// interface. Once the job has finished and all of its output has been yielded, the iteration
+/// ends.
+///
+/// Output is yielded as raw bytes since chunk boundaries may split multibyte characters.
+#[derive(Debug, Builder, Clone)]
+pub struct JobLogTail<'a> {
+    /// The project of the job.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the job.
+    job: u64,
+    /// The byte offset to start reading the log from.
+    ///
+    /// Defaults to `0`.
+    #[builder(default)]
+    offset: u64,
+    /// How long to wait between polls when no new output is available.
+    ///
+    /// Defaults to 3 seconds.
+    #[builder(default = "Duration::from_secs(3)")]
+    poll_interval: Duration,
+}
+
+impl<'a> JobLogTail<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> JobLogTailBuilder<'a> {
+        JobLogTailBuilder::default()
+    }
+
+    fn trace_request<C>(
+        &self,
+        client: &C,
+        offset: u64,
+    ) -> Result<http::request::Builder, ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let endpoint = JobTrace::builder()
+            .project(self.project.clone())
+            .job(self.job)
+            .build()
+            .map_err(ApiError::invalid_endpoint)?;
+        let url = endpoint
+            .url_base()
+            .endpoint_for(client, &endpoint.endpoint())?;
+
+        Ok(Request::builder()
+            .method(endpoint.method())
+            .uri(query::url_to_http_uri(url))
+            .header(header::RANGE, format!("bytes={}-", offset)))
+    }
+
+    fn job_endpoint<E>(&self) -> Result<Job<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Job::builder()
+            .project(self.project.clone())
+            .job(self.job)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    /// Iterate over new output from the job's log.
+    ///
+    /// The current thread sleeps between polls.
+    pub fn iter<'c, C>(&'c self, client: &'c C) -> JobLogTailIter<'a, 'c, C>
+    where
+        C: Client,
+    {
+        JobLogTailIter {
+            tail: self,
+            client,
+            state: TailState::new(self.offset),
+        }
+    }
+
+    /// Stream new output from the job's log.
+    ///
+    /// Since the crate is not tied to any specific runtime, `sleep` is used to wait between polls
+    /// (e.g., `tokio::time::sleep`).
+    pub fn iter_async<'c, C, S, F>(
+        &'c self,
+        client: &'c C,
+        sleep: S,
+    ) -> impl Stream<Item = Result<Vec<u8>, ApiError<C::Error>>> + 'c
+    where
+        C: AsyncClient + Sync,
+        S: Fn(Duration) -> F + 'c,
+        F: Future<Output = ()> + 'c,
+    {
+        let state = TailState::new(self.offset);
+        futures_util::stream::unfold((state, sleep), move |(mut state, sleep)| {
+            async move {
+                if state.done {
+                    return None;
+                }
+
+                loop {
+                    match self.poll_async(client, &mut state).await {
+                        Ok(Some(chunk)) => return Some((Ok(chunk), (state, sleep))),
+                        Ok(None) if state.done => return None,
+                        Ok(None) => sleep(self.poll_interval).await,
+                        Err(err) => {
+                            state.done = true;
+                            return Some((Err(err), (state, sleep)));
+                        },
+                    }
+                }
+            }
+        })
+    }
+
+    fn poll<C>(
+        &self,
+        client: &C,
+        state: &mut TailState,
+    ) -> Result<Option<Vec<u8>>, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        loop {
+            let req = self.trace_request(client, state.offset)?;
+            let rsp = client.rest(req, Vec::new())?;
+            if let Some(chunk) = state.receive(rsp)? {
+                return Ok(Some(chunk));
+            }
+
+            if state.finished {
+                state.done = true;
+                return Ok(None);
+            }
+
+            let status: JobStatus = self.job_endpoint()?.query(client)?;
+            // Fetch the log once more after the job finishes to pick up its final output.
+            state.finished = status.is_finished();
+            if !state.finished {
+                return Ok(None);
+            }
+        }
+    }
+
+    async fn poll_async<C>(
+        &self,
+        client: &C,
+        state: &mut TailState,
+    ) -> Result<Option<Vec<u8>>, ApiError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        loop {
+            let req = self.trace_request(client, state.offset)?;
+            let rsp = client.rest_async(req, Vec::new()).await?;
+            if let Some(chunk) = state.receive(rsp)? {
+                return Ok(Some(chunk));
+            }
+
+            if state.finished {
+                state.done = true;
+                return Ok(None);
+            }
+
+            let status: JobStatus = self.job_endpoint()?.query_async(client).await?;
+            // Fetch the log once more after the job finishes to pick up its final output.
+            state.finished = status.is_finished();
+            if !state.finished {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JobStatus {
+    status: String,
+}
+
+impl JobStatus {
+    fn is_finished(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "success" | "failed" | "canceled" | "skipped" | "manual",
+        )
+    }
+}
+
+#[derive(Debug)]
+struct TailState {
+    offset: u64,
+    finished: bool,
+    done: bool,
+}
+
+impl TailState {
+    fn new(offset: u64) -> Self {
+        Self {
+            offset,
+            finished: false,
+            done: false,
+        }
+    }
+
+    fn receive<E>(&mut self, rsp: Response<Bytes>) -> Result<Option<Vec<u8>>, ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let status = rsp.status();
+        let data = match status {
+            StatusCode::PARTIAL_CONTENT => rsp.body().as_ref(),
+            // The range was ignored; skip the output which has already been seen.
+            StatusCode::OK => rsp.body().get(self.offset as usize..).unwrap_or_default(),
+            // There is nothing past the offset yet.
+            StatusCode::RANGE_NOT_SATISFIABLE => &[],
+            _ => {
+                let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+                    v
+                } else {
+                    return Err(ApiError::server_error(status, rsp.body()));
+                };
+                return Err(ApiError::from_gitlab(v));
+            },
+        };
+
+        if data.is_empty() {
+            Ok(None)
+        } else {
+            self.offset += data.len() as u64;
+            Ok(Some(data.into()))
+        }
+    }
+}
+
+/// An iterator over new output from a job's log.
+pub struct JobLogTailIter<'a, 'c, C> {
+    tail: &'c JobLogTail<'a>,
+    client: &'c C,
+    state: TailState,
+}
+
+impl<'a, 'c, C> JobLogTailIter<'a, 'c, C> {
+    /// The offset of the next byte to read from the log.
+    pub fn offset(&self) -> u64 {
+        self.state.offset
+    }
+}
+
+impl<'a, 'c, C> Iterator for JobLogTailIter<'a, 'c, C>
+where
+    C: Client,
+{
+    type Item = Result<Vec<u8>, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.state.done {
+            match self.tail.poll(self.client, &mut self.state) {
+                Ok(Some(chunk)) => return Some(Ok(chunk)),
+                Ok(None) if self.state.done => break,
+                Ok(None) => thread::sleep(self.tail.poll_interval),
+                Err(err) => {
+                    self.state.done = true;
+                    return Some(Err(err));
+                },
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use http::{header, HeaderValue, StatusCode};
+    use serde_json::json;
+
+    use crate::api::projects::jobs::{JobLogTail, JobLogTailBuilderError};
+    use crate::api::ApiError;
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    #[test]
+    fn project_and_job_are_needed() {
+        let err = JobLogTail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobLogTailBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = JobLogTail::builder().job(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobLogTailBuilderError, "project");
+    }
+
+    #[test]
+    fn job_is_needed() {
+        let err = JobLogTail::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, JobLogTailBuilderError, "job");
+    }
+
+    #[test]
+    fn project_and_job_are_sufficient() {
+        JobLogTail::builder().project(1).job(1).build().unwrap();
+    }
+
+    fn trace() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1/trace")
+            .build()
+            .unwrap()
+    }
+
+    fn job() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("projects/simple%2Fproject/jobs/1")
+            .build()
+            .unwrap()
+    }
+
+    fn status(status: &str) -> MockResponse {
+        MockResponse::json(&json!({
+            "id": 1,
+            "status": status,
+        }))
+    }
+
+    fn partial(data: &str) -> MockResponse {
+        MockResponse::raw(data).status(StatusCode::PARTIAL_CONTENT)
+    }
+
+    fn no_data() -> MockResponse {
+        MockResponse::empty().status(StatusCode::RANGE_NOT_SATISFIABLE)
+    }
+
+    fn mock_running_job() -> MockClient {
+        let client = MockClient::new();
+        client
+            .expect(trace(), partial("hello "))
+            .expect(trace(), no_data())
+            .expect(job(), status("running"))
+            .expect(trace(), partial("world\n"))
+            .expect(trace(), no_data())
+            .expect(job(), status("success"))
+            .expect(trace(), partial("done\n"))
+            .expect(trace(), no_data());
+        client
+    }
+
+    fn tail() -> JobLogTail<'static> {
+        JobLogTail::builder()
+            .project("simple/project")
+            .job(1)
+            .poll_interval(Duration::ZERO)
+            .build()
+            .unwrap()
+    }
+
+    fn ranges(client: &MockClient) -> Vec<HeaderValue> {
+        client
+            .requests()
+            .into_iter()
+            .filter_map(|request| request.headers.get(header::RANGE).cloned())
+            .collect()
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = mock_running_job();
+        let endpoint = tail();
+
+        let chunks = endpoint
+            .iter(&client)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(chunks, [&b"hello "[..], b"world\n", b"done\n"]);
+        client.assert_all_consumed();
+        assert_eq!(
+            ranges(&client),
+            [
+                "bytes=0-",
+                "bytes=6-",
+                "bytes=6-",
+                "bytes=12-",
+                "bytes=12-",
+                "bytes=17-",
+            ],
+        );
+    }
+
+    #[test]
+    fn endpoint_offset() {
+        let client = MockClient::new();
+        client
+            .expect(trace(), MockResponse::raw("hello world\n"))
+            .expect(trace(), no_data())
+            .expect(job(), status("failed"))
+            .expect(trace(), no_data());
+        let endpoint = JobLogTail::builder()
+            .project("simple/project")
+            .job(1)
+            .offset(6)
+            .poll_interval(Duration::ZERO)
+            .build()
+            .unwrap();
+
+        let mut iter = endpoint.iter(&client);
+        assert_eq!(iter.next().unwrap().unwrap(), b"world\n");
+        assert_eq!(iter.offset(), 12);
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        client.assert_all_consumed();
+        assert_eq!(ranges(&client), ["bytes=6-", "bytes=12-", "bytes=12-"]);
+    }
+
+    #[test]
+    fn endpoint_error() {
+        let client = MockClient::new();
+        client.expect(
+            trace(),
+            MockResponse::json(&json!({
+                "message": "404 Not found",
+            }))
+            .status(StatusCode::NOT_FOUND),
+        );
+        let endpoint = tail();
+
+        let mut iter = endpoint.iter(&client);
+        let err = iter.next().unwrap().unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "404 Not found");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert!(iter.next().is_none());
+    }
+
+    #[tokio::test]
+    async fn endpoint_async() {
+        let client = mock_running_job();
+        let endpoint = tail();
+
+        let chunks = endpoint
+            .iter_async(&client, |_| async {})
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(chunks, [&b"hello "[..], b"world\n", b"done\n"]);
+        client.assert_all_consumed();
+        assert_eq!(
+            ranges(&client),
+            [
+                "bytes=0-",
+                "bytes=6-",
+                "bytes=6-",
+                "bytes=12-",
+                "bytes=12-",
+                "bytes=17-",
+            ],
+        );
+    }
+}
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a job within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct JobTrace<'a> {
     /// The project to query for the job.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> JobTrace<'a> {
     }
 }
 
+impl<'a> JobTraceBuilder<'a> {
+    fn validate(&self) -> Result<(), JobTraceBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("job", self.job.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for JobTrace<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/jobs/{}/trace", self.project, self.job).into()
+        format!(
+            "projects/{}/jobs/{}/trace",
+            self.project.path_segment(),
+            self.job.path_segment()
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,12 @@ mod tests {
         JobTrace::builder().project(1).job(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = JobTrace::builder().project(0).job(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, JobTraceBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
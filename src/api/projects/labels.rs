@@ -6,18 +6,25 @@
 
 //! Project label API endpoints.
 //!
-//! These endpoints are used for querying project labels.
+//! These endpoints are used for querying and managing project labels.
 
 mod create;
 mod delete;
+mod edit;
 mod label;
 mod labels;
 mod promote;
+mod subscribe;
+mod unsubscribe;
 
 pub use self::create::CreateLabel;
 pub use self::create::CreateLabelBuilder;
 pub use self::create::CreateLabelBuilderError;
 
+pub use self::edit::EditLabel;
+pub use self::edit::EditLabelBuilder;
+pub use self::edit::EditLabelBuilderError;
+
 pub use self::label::Label;
 pub use self::label::LabelBuilder;
 pub use self::label::LabelBuilderError;
@@ -33,3 +40,11 @@ pub use self::delete::DeleteLabelBuilderError;
 pub use self::promote::PromoteLabel;
 pub use self::promote::PromoteLabelBuilder;
 pub use self::promote::PromoteLabelBuilderError;
+
+pub use self::subscribe::SubscribeToLabel;
+pub use self::subscribe::SubscribeToLabelBuilder;
+pub use self::subscribe::SubscribeToLabelBuilderError;
+
+pub use self::unsubscribe::UnsubscribeFromLabel;
+pub use self::unsubscribe::UnsubscribeFromLabelBuilder;
+pub use self::unsubscribe::UnsubscribeFromLabelBuilderError;
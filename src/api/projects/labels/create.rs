@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a label within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateLabel<'a> {
     /// The project to create a label within.
     #[builder(setter(into))]
@@ -42,13 +42,21 @@ impl<'a> CreateLabel<'a> {
     }
 }
 
+impl<'a> CreateLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateLabelBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateLabel<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/labels", self.project).into()
+        format!("projects/{}/labels", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -118,6 +126,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateLabel::builder()
+            .project(0)
+            .name("label")
+            .color("#f100fe")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateLabelBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a label within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Label<'a> {
     /// The project to query for the label.
     #[builder(setter(into))]
@@ -34,13 +34,27 @@ impl<'a> Label<'a> {
     }
 }
 
+impl<'a> LabelBuilder<'a> {
+    fn validate(&self) -> Result<(), LabelBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Label<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/labels/{}", self.project, self.label).into()
+        format!(
+            "projects/{}/labels/{}",
+            self.project.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -81,6 +95,12 @@ mod tests {
         Label::builder().project(1).label(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Label::builder().project(0).label(1).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, LabelBuilderError, "project");
+    }
+
     #[test]
     fn label_by_id() {
         Label::builder().project(1).label(1).build().unwrap();
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for labels within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Labels<'a> {
     /// The project to query for labels.
     #[builder(setter(into))]
@@ -37,13 +37,21 @@ impl<'a> Labels<'a> {
     }
 }
 
+impl<'a> LabelsBuilder<'a> {
+    fn validate(&self) -> Result<(), LabelsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Labels<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/labels", self.project).into()
+        format!("projects/{}/labels", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -77,6 +85,12 @@ mod tests {
         Labels::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Labels::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, LabelsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Promote a label within a project to a group label.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PromoteLabel<'a> {
     /// The project to promote a label within.
     #[builder(setter(into))]
@@ -27,13 +28,27 @@ impl<'a> PromoteLabel<'a> {
     }
 }
 
+impl<'a> PromoteLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), PromoteLabelBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PromoteLabel<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/labels/{}/promote", self.project, self.label).into()
+        format!(
+            "projects/{}/labels/{}/promote",
+            self.project.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
     }
 }
 
@@ -72,6 +87,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PromoteLabel::builder()
+            .project(0)
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PromoteLabelBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,119 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Subscribe to a label within a project.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SubscribeToLabel<'a> {
+    /// The project containing the label.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+}
+
+impl<'a> SubscribeToLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SubscribeToLabelBuilder<'a> {
+        SubscribeToLabelBuilder::default()
+    }
+}
+
+impl<'a> SubscribeToLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), SubscribeToLabelBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for SubscribeToLabel<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/labels/{}/subscribe",
+            self.project.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::labels::{SubscribeToLabel, SubscribeToLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_label_are_necessary() {
+        let err = SubscribeToLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeToLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = SubscribeToLabel::builder()
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeToLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn label_is_necessary() {
+        let err = SubscribeToLabel::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, SubscribeToLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn project_and_label_are_sufficient() {
+        SubscribeToLabel::builder()
+            .project(1)
+            .label("label")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = SubscribeToLabel::builder()
+            .project(0)
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SubscribeToLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/labels/simple%2Flabel/subscribe")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SubscribeToLabel::builder()
+            .project("simple/project")
+            .label("simple/label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Unsubscribe from a label within a project.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UnsubscribeFromLabel<'a> {
+    /// The project containing the label.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+}
+
+impl<'a> UnsubscribeFromLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnsubscribeFromLabelBuilder<'a> {
+        UnsubscribeFromLabelBuilder::default()
+    }
+}
+
+impl<'a> UnsubscribeFromLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), UnsubscribeFromLabelBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("label", self.label.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UnsubscribeFromLabel<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/labels/{}/unsubscribe",
+            self.project.path_segment(),
+            self.label.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::labels::{UnsubscribeFromLabel, UnsubscribeFromLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_label_are_necessary() {
+        let err = UnsubscribeFromLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeFromLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = UnsubscribeFromLabel::builder()
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeFromLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn label_is_necessary() {
+        let err = UnsubscribeFromLabel::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UnsubscribeFromLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn project_and_label_are_sufficient() {
+        UnsubscribeFromLabel::builder()
+            .project(1)
+            .label("label")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = UnsubscribeFromLabel::builder()
+            .project(0)
+            .label("label")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnsubscribeFromLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/labels/simple%2Flabel/unsubscribe")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnsubscribeFromLabel::builder()
+            .project("simple/project")
+            .label("simple/label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,7 +8,7 @@ use chrono::NaiveDate;
 use derive_builder::Builder;
 use itertools::Itertools;
 
-use crate::api::common::{AccessLevel, CommaSeparatedList, NameOrId};
+use crate::api::common::{self, AccessLevel, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -46,7 +46,7 @@ impl ParamValue<'static> for ProjectInviteTasksToBeDone {
 
 /// Add a user as a member of a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct AddProjectMember<'a> {
     /// The project to add the user to.
     #[builder(setter(into))]
@@ -63,6 +63,9 @@ pub struct AddProjectMember<'a> {
     /// The source of the invitation.
     #[builder(setter(into), default)]
     invite_source: Option<Cow<'a, str>>,
+    /// The ID of a member role to assign to the user.
+    #[builder(default)]
+    member_role_id: Option<u64>,
     /// Tasks the inviter wants the member to focus on.
     ///
     /// Requires `tasks_project_id`.
@@ -127,6 +130,12 @@ impl<'a> AddProjectMemberBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), AddProjectMemberBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for AddProjectMember<'a> {
@@ -135,7 +144,7 @@ impl<'a> Endpoint for AddProjectMember<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/members", self.project).into()
+        format!("projects/{}/members", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -145,7 +154,8 @@ impl<'a> Endpoint for AddProjectMember<'a> {
             .push("user_id", &self.user_ids)
             .push("access_level", self.access_level.as_u64())
             .push_opt("expires_at", self.expires_at)
-            .push_opt("invite_source", self.invite_source.as_ref());
+            .push_opt("invite_source", self.invite_source.as_ref())
+            .push_opt("member_role_id", self.member_role_id);
 
         #[allow(deprecated)]
         {
@@ -155,7 +165,7 @@ impl<'a> Endpoint for AddProjectMember<'a> {
                         .iter()
                         .map(|task| task.as_str())
                         .unique()
-                        .map(|value| ("tasks_to_be_done[]", value)),
+                        .map(|value| (ParamName::new("tasks_to_be_done").array(), value)),
                 )
                 .push_opt("tasks_project_id", self.tasks_project_id);
         }
@@ -236,6 +246,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = AddProjectMember::builder()
+            .project("")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, AddProjectMemberBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -332,6 +353,31 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/members")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "user_id=1",
+                "&access_level=30",
+                "&member_role_id=1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddProjectMember::builder()
+            .project("simple/project")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .member_role_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     #[allow(deprecated)]
     fn endpoint_tasks_to_be_done() {
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a single member of a project with ancestor collapsing.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct AllProjectMember<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> AllProjectMember<'a> {
     }
 }
 
+impl<'a> AllProjectMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), AllProjectMemberBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for AllProjectMember<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/members/all/{}", self.project, self.user).into()
+        format!(
+            "projects/{}/members/all/{}",
+            self.project.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
     }
 }
 
@@ -69,6 +84,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = AllProjectMember::builder()
+            .project(0)
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, AllProjectMemberBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -8,7 +8,7 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -39,7 +39,7 @@ impl ParamValue<'static> for ProjectMemberState {
 
 /// Query a members of a project including parent group memberships.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct AllProjectMembers<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -83,6 +83,12 @@ impl<'a> AllProjectMembersBuilder<'a> {
         self.user_ids.get_or_insert_with(BTreeSet::new).extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), AllProjectMembersBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for AllProjectMembers<'a> {
@@ -91,7 +97,7 @@ impl<'a> Endpoint for AllProjectMembers<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/members/all", self.project).into()
+        format!("projects/{}/members/all", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -99,7 +105,11 @@ impl<'a> Endpoint for AllProjectMembers<'a> {
 
         params
             .push_opt("query", self.query.as_ref())
-            .extend(self.user_ids.iter().map(|&value| ("user_ids[]", value)))
+            .extend(
+                self.user_ids
+                    .iter()
+                    .map(|&value| (ParamName::new("user_ids").array(), value)),
+            )
             .push_opt("show_seat_info", self.show_seat_info)
             .push_opt("state", self.state);
 
@@ -140,6 +150,12 @@ mod tests {
         AllProjectMembers::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = AllProjectMembers::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, AllProjectMembersBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -7,12 +7,12 @@
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Edit a member of a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditProjectMember<'a> {
     /// The project to add the user to.
     #[builder(setter(into))]
@@ -37,13 +37,27 @@ impl<'a> EditProjectMember<'a> {
     }
 }
 
+impl<'a> EditProjectMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), EditProjectMemberBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditProjectMember<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/members/{}", self.project, self.user).into()
+        format!(
+            "projects/{}/members/{}",
+            self.project.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -115,6 +129,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditProjectMember::builder()
+            .project("")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditProjectMemberBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a single member of a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectMember<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -41,6 +42,15 @@ impl<'a> ProjectMember<'a> {
     }
 }
 
+impl<'a> ProjectMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectMemberBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectMember<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -48,9 +58,19 @@ impl<'a> Endpoint for ProjectMember<'a> {
 
     fn endpoint(&self) -> Cow<'static, str> {
         if self._include_ancestors {
-            format!("projects/{}/members/all/{}", self.project, self.user).into()
+            format!(
+                "projects/{}/members/all/{}",
+                self.project.path_segment(),
+                self.user.path_segment()
+            )
+            .into()
         } else {
-            format!("projects/{}/members/{}", self.project, self.user).into()
+            format!(
+                "projects/{}/members/{}",
+                self.project.path_segment(),
+                self.user.path_segment()
+            )
+            .into()
         }
     }
 }
@@ -102,6 +122,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectMember::builder()
+            .project(0)
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectMemberBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
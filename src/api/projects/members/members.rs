@@ -8,12 +8,12 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a members of a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectMembers<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -89,6 +89,12 @@ impl<'a> ProjectMembersBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), ProjectMembersBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for ProjectMembers<'a> {
@@ -98,9 +104,9 @@ impl<'a> Endpoint for ProjectMembers<'a> {
 
     fn endpoint(&self) -> Cow<'static, str> {
         if self._include_ancestors {
-            format!("projects/{}/members/all", self.project).into()
+            format!("projects/{}/members/all", self.project.path_segment()).into()
         } else {
-            format!("projects/{}/members", self.project).into()
+            format!("projects/{}/members", self.project.path_segment()).into()
         }
     }
 
@@ -109,11 +115,19 @@ impl<'a> Endpoint for ProjectMembers<'a> {
 
         params
             .push_opt("query", self.query.as_ref())
-            .extend(self.user_ids.iter().map(|&value| ("user_ids[]", value)))
+            .extend(
+                self.user_ids
+                    .iter()
+                    .map(|&value| (ParamName::new("user_ids").array(), value)),
+            )
             .push_opt("show_seat_info", self.show_seat_info);
 
         if !self._include_ancestors {
-            params.extend(self.skip_users.iter().map(|&value| ("skip_users[]", value)));
+            params.extend(
+                self.skip_users
+                    .iter()
+                    .map(|&value| (ParamName::new("skip_users").array(), value)),
+            );
         }
 
         params
@@ -146,6 +160,12 @@ mod tests {
         ProjectMembers::all_builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectMembers::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectMembersBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
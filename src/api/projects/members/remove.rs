@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Remove a user from a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RemoveProjectMember<'a> {
     /// The project to remove the user from.
     #[builder(setter(into))]
@@ -30,13 +30,27 @@ impl<'a> RemoveProjectMember<'a> {
     }
 }
 
+impl<'a> RemoveProjectMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), RemoveProjectMemberBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RemoveProjectMember<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/members/{}", self.project, self.user).into()
+        format!(
+            "projects/{}/members/{}",
+            self.project.path_segment(),
+            self.user.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -86,6 +100,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RemoveProjectMember::builder()
+            .project("")
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RemoveProjectMemberBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
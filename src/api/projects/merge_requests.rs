@@ -8,6 +8,7 @@
 //!
 //! These endpoints are used for querying projects merge requests.
 
+mod add_spent_time;
 pub mod approval_rules;
 pub mod approval_state;
 pub mod approvals;
@@ -16,8 +17,10 @@ pub mod awards;
 mod changes;
 mod commits;
 mod create;
+pub mod dependencies;
 mod diffs;
 pub mod discussions;
+pub mod draft_notes;
 mod edit;
 mod issues_closed_by;
 mod merge;
@@ -26,8 +29,20 @@ mod merge_requests;
 pub mod notes;
 pub mod pipelines;
 mod rebase;
+mod reset_spent_time;
+mod reset_time_estimate;
 mod resource_label_events;
+mod resource_milestone_events;
+mod resource_state_events;
+mod time_estimate;
+mod time_stats;
 mod unapprove;
+mod version;
+mod versions;
+
+pub use self::add_spent_time::AddMergeRequestSpentTime;
+pub use self::add_spent_time::AddMergeRequestSpentTimeBuilder;
+pub use self::add_spent_time::AddMergeRequestSpentTimeBuilderError;
 
 pub use self::approve::ApproveMergeRequest;
 pub use self::approve::ApproveMergeRequestBuilder;
@@ -88,10 +103,42 @@ pub use self::rebase::RebaseMergeRequest;
 pub use self::rebase::RebaseMergeRequestBuilder;
 pub use self::rebase::RebaseMergeRequestBuilderError;
 
+pub use self::reset_spent_time::ResetMergeRequestSpentTime;
+pub use self::reset_spent_time::ResetMergeRequestSpentTimeBuilder;
+pub use self::reset_spent_time::ResetMergeRequestSpentTimeBuilderError;
+
+pub use self::reset_time_estimate::ResetMergeRequestTimeEstimate;
+pub use self::reset_time_estimate::ResetMergeRequestTimeEstimateBuilder;
+pub use self::reset_time_estimate::ResetMergeRequestTimeEstimateBuilderError;
+
 pub use self::resource_label_events::MergeRequestResourceLabelEvents;
 pub use self::resource_label_events::MergeRequestResourceLabelEventsBuilder;
 pub use self::resource_label_events::MergeRequestResourceLabelEventsBuilderError;
 
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEvents;
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEventsBuilder;
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEventsBuilderError;
+
+pub use self::resource_state_events::MergeRequestResourceStateEvents;
+pub use self::resource_state_events::MergeRequestResourceStateEventsBuilder;
+pub use self::resource_state_events::MergeRequestResourceStateEventsBuilderError;
+
+pub use self::time_estimate::SetMergeRequestTimeEstimate;
+pub use self::time_estimate::SetMergeRequestTimeEstimateBuilder;
+pub use self::time_estimate::SetMergeRequestTimeEstimateBuilderError;
+
+pub use self::time_stats::MergeRequestTimeStats;
+pub use self::time_stats::MergeRequestTimeStatsBuilder;
+pub use self::time_stats::MergeRequestTimeStatsBuilderError;
+
 pub use self::unapprove::UnapproveMergeRequest;
 pub use self::unapprove::UnapproveMergeRequestBuilder;
 pub use self::unapprove::UnapproveMergeRequestBuilderError;
+
+pub use self::version::MergeRequestVersion;
+pub use self::version::MergeRequestVersionBuilder;
+pub use self::version::MergeRequestVersionBuilderError;
+
+pub use self::versions::MergeRequestVersions;
+pub use self::versions::MergeRequestVersionsBuilder;
+pub use self::versions::MergeRequestVersionsBuilderError;
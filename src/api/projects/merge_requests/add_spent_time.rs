@@ -0,0 +1,209 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, HumanDuration, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Add time spent on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct AddMergeRequestSpentTime<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+
+    /// The time spent.
+    ///
+    /// Negative durations subtract from the time spent.
+    duration: HumanDuration,
+
+    /// A summary of how the time was spent.
+    #[builder(setter(into), default)]
+    summary: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddMergeRequestSpentTime<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddMergeRequestSpentTimeBuilder<'a> {
+        AddMergeRequestSpentTimeBuilder::default()
+    }
+}
+
+impl<'a> AddMergeRequestSpentTimeBuilder<'a> {
+    fn validate(&self) -> Result<(), AddMergeRequestSpentTimeBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for AddMergeRequestSpentTime<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/add_spent_time",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("duration", self.duration)
+            .push_opt("summary", self.summary.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::HumanDuration;
+    use crate::api::projects::merge_requests::{
+        AddMergeRequestSpentTime, AddMergeRequestSpentTimeBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_duration_are_needed() {
+        let err = AddMergeRequestSpentTime::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AddMergeRequestSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = AddMergeRequestSpentTime::builder()
+            .merge_request(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddMergeRequestSpentTimeBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = AddMergeRequestSpentTime::builder()
+            .project(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            AddMergeRequestSpentTimeBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn duration_is_needed() {
+        let err = AddMergeRequestSpentTime::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddMergeRequestSpentTimeBuilderError, "duration");
+    }
+
+    #[test]
+    fn project_merge_request_and_duration_are_sufficient() {
+        AddMergeRequestSpentTime::builder()
+            .project(1)
+            .merge_request(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = AddMergeRequestSpentTime::builder()
+            .project(0)
+            .merge_request(1)
+            .duration(HumanDuration::from_hours(1))
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            AddMergeRequestSpentTimeBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=3h30m")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddMergeRequestSpentTime::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .duration(HumanDuration::from_mins(210))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_negative() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("duration=-1h")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddMergeRequestSpentTime::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .duration(HumanDuration::from_hours(1).negate())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_summary() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/add_spent_time")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("duration=1h", "&summary=Code+review"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddMergeRequestSpentTime::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .duration(HumanDuration::from_hours(1))
+            .summary("Code review")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
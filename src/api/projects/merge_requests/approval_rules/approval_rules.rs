@@ -6,12 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for approval rules of a merge request.
 /// See https://docs.gitlab.com/ee/api/merge_request_approvals.html#get-merge-request-level-rules
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestApprovalRules<'a> {
     /// The project to query for approval rules.
     #[builder(setter(into))]
@@ -28,6 +29,15 @@ impl<'a> MergeRequestApprovalRules<'a> {
     }
 }
 
+impl<'a> MergeRequestApprovalRulesBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestApprovalRulesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestApprovalRules<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -36,7 +46,8 @@ impl<'a> Endpoint for MergeRequestApprovalRules<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/approval_rules",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -89,6 +100,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestApprovalRules::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            MergeRequestApprovalRulesBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
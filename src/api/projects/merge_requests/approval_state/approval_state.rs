@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for approval state of a merge request.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestApprovalState<'a> {
     /// The project to query for approval state.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> MergeRequestApprovalState<'a> {
     }
 }
 
+impl<'a> MergeRequestApprovalStateBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestApprovalStateBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestApprovalState<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for MergeRequestApprovalState<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/approval_state",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -85,6 +96,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestApprovalState::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            MergeRequestApprovalStateBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
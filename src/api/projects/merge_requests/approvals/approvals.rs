@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for approvals on a merge request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestApprovals<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> MergeRequestApprovals<'a> {
     }
 }
 
+impl<'a> MergeRequestApprovalsBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestApprovalsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestApprovals<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for MergeRequestApprovals<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/approvals",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -81,6 +92,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestApprovals::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestApprovalsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Approve a merge request.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ApproveMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -37,6 +37,15 @@ impl<'a> ApproveMergeRequest<'a> {
     }
 }
 
+impl<'a> ApproveMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), ApproveMergeRequestBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ApproveMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -45,7 +54,8 @@ impl<'a> Endpoint for ApproveMergeRequest<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/approve",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -104,6 +114,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ApproveMergeRequest::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ApproveMergeRequestBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
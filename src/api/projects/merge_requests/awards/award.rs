@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for award on an merge_request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestAward<'a> {
     /// The project to query for the merge_request.
     #[builder(setter(into))]
@@ -28,6 +29,16 @@ impl<'a> MergeRequestAward<'a> {
     }
 }
 
+impl<'a> MergeRequestAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("award", self.award.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestAward<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -36,7 +47,9 @@ impl<'a> Endpoint for MergeRequestAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/award_emoji/{}",
-            self.project, self.merge_request, self.award,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.award.path_segment(),
         )
         .into()
     }
@@ -96,6 +109,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestAward::builder()
+            .project(0)
+            .merge_request(1)
+            .award(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestAwardBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
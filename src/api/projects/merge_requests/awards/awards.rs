@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for awards on a merge request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestAwards<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> MergeRequestAwards<'a> {
     }
 }
 
+impl<'a> MergeRequestAwardsBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestAwardsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestAwards<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for MergeRequestAwards<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/award_emoji",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -83,6 +94,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestAwards::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestAwardsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
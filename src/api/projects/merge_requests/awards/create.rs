@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new award on an merge_request on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestAward<'a> {
     /// The project the merge_request belongs to.
     #[builder(setter(into))]
@@ -29,6 +30,15 @@ impl<'a> CreateMergeRequestAward<'a> {
     }
 }
 
+impl<'a> CreateMergeRequestAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateMergeRequestAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateMergeRequestAward<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -37,7 +47,8 @@ impl<'a> Endpoint for CreateMergeRequestAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/award_emoji",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -111,6 +122,21 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestAward::builder()
+            .project(0)
+            .merge_request(1)
+            .name("award")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateMergeRequestAwardBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
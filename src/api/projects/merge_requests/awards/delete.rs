@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete an existing award on a merge_request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteMergeRequestAward<'a> {
     /// The project to query for the merge_request.
     #[builder(setter(into))]
@@ -28,6 +29,16 @@ impl<'a> DeleteMergeRequestAward<'a> {
     }
 }
 
+impl<'a> DeleteMergeRequestAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteMergeRequestAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("award", self.award.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteMergeRequestAward<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -36,7 +47,9 @@ impl<'a> Endpoint for DeleteMergeRequestAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/award_emoji/{}",
-            self.project, self.merge_request, self.award,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.award.path_segment(),
         )
         .into()
     }
@@ -102,6 +115,21 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteMergeRequestAward::builder()
+            .project(0)
+            .merge_request(1)
+            .award(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteMergeRequestAwardBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -8,12 +8,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Shows information of a merge request including its files and changes.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 #[deprecated(note = "Deprecated by GitLab. Use `MergeRequestDiffs` instead")]
 pub struct MergeRequestChanges<'a> {
     /// The project with the merge request.
@@ -37,6 +37,15 @@ impl<'a> MergeRequestChanges<'a> {
     }
 }
 
+impl<'a> MergeRequestChangesBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestChangesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestChanges<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -45,7 +54,8 @@ impl<'a> Endpoint for MergeRequestChanges<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/changes",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -104,6 +114,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestChanges::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestChangesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
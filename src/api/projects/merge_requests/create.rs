@@ -9,7 +9,7 @@ use std::iter;
 
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 #[derive(Debug, Clone)]
@@ -30,7 +30,10 @@ impl Assignee {
                 params.push("assignee_id", *id);
             },
             Assignee::Ids(ids) => {
-                params.extend(ids.iter().map(|&id| ("assignee_ids[]", id)));
+                params.extend(
+                    ids.iter()
+                        .map(|&id| (ParamName::new("assignee_ids").array(), id)),
+                );
             },
         }
     }
@@ -53,7 +56,10 @@ impl Reviewer {
                 params.push("reviewer_ids", "0");
             },
             Reviewer::Ids(ids) => {
-                params.extend(ids.iter().map(|&id| ("reviewer_ids[]", id)));
+                params.extend(
+                    ids.iter()
+                        .map(|&id| (ParamName::new("reviewer_ids").array(), id)),
+                );
             },
         }
     }
@@ -61,7 +67,7 @@ impl Reviewer {
 
 /// Create a new merge request on project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequest<'a> {
     /// The project to open the merge requset *from*.
     #[builder(setter(into))]
@@ -225,6 +231,12 @@ impl<'a> CreateMergeRequestBuilder<'a> {
             .extend(iter.map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), CreateMergeRequestBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateMergeRequest<'a> {
@@ -233,7 +245,7 @@ impl<'a> Endpoint for CreateMergeRequest<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/merge_requests", self.project).into()
+        format!("projects/{}/merge_requests", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -340,17 +352,28 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequest::builder()
+            .project(0)
+            .source_branch("source")
+            .target_branch("target")
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateMergeRequestBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -370,13 +393,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&assignee_ids=0",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("assignee_ids", "0"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -397,13 +419,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&assignee_id=1",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("assignee_id", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -424,14 +445,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&assignee_ids%5B%5D=1",
-                "&assignee_ids%5B%5D=2",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("assignee_ids[]", "1"),
+                ("assignee_ids[]", "2"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -453,13 +473,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&reviewer_ids=0",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("reviewer_ids", "0"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -480,13 +499,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&reviewer_ids%5B%5D=1",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("reviewer_ids[]", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -507,14 +525,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&reviewer_ids%5B%5D=1",
-                "&reviewer_ids%5B%5D=2",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("reviewer_ids[]", "1"),
+                ("reviewer_ids[]", "2"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -536,13 +553,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&description=description",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("description", "description"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -563,13 +579,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&target_project_id=1",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("target_project_id", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -590,13 +605,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&labels=label%2Clabel1%2Clabel2",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("labels", "label,label1,label2"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -618,13 +632,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&milestone_id=1",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("milestone_id", "1"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -646,13 +659,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&approvals_before_merge=2",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("approvals_before_merge", "2"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -673,13 +685,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&remove_source_branch=true",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("remove_source_branch", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -700,13 +711,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&allow_collaboration=true",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("allow_collaboration", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -727,13 +737,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&squash=false",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("squash", "false"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -755,13 +764,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/simple%2Fproject/merge_requests")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "source_branch=source%2Fbranch",
-                "&target_branch=target%2Fbranch",
-                "&title=title",
-                "&allow_maintainer_to_push=true",
-            ))
+            .form_params(&[
+                ("source_branch", "source/branch"),
+                ("target_branch", "target/branch"),
+                ("title", "title"),
+                ("allow_maintainer_to_push", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Merge request dependency API endpoints.
+//!
+//! These endpoints are used for managing merge requests which block other merge requests from
+//! being merged.
+
+mod blockees;
+mod create;
+mod delete;
+mod dependencies;
+
+pub use self::blockees::MergeRequestBlockees;
+pub use self::blockees::MergeRequestBlockeesBuilder;
+pub use self::blockees::MergeRequestBlockeesBuilderError;
+
+pub use self::create::CreateMergeRequestDependency;
+pub use self::create::CreateMergeRequestDependencyBuilder;
+pub use self::create::CreateMergeRequestDependencyBuilderError;
+
+pub use self::delete::DeleteMergeRequestDependency;
+pub use self::delete::DeleteMergeRequestDependencyBuilder;
+pub use self::delete::DeleteMergeRequestDependencyBuilderError;
+
+pub use self::dependencies::MergeRequestDependencies;
+pub use self::dependencies::MergeRequestDependenciesBuilder;
+pub use self::dependencies::MergeRequestDependenciesBuilderError;
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Make a merge request depend on another merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateMergeRequestDependency<'a> {
+    /// The project with the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request to block.
+    merge_request: u64,
+    /// The global ID of the merge request which must be merged first.
+    ///
+    /// The blocking merge request may be in any project.
+    blocking_merge_request: u64,
+}
+
+impl<'a> CreateMergeRequestDependency<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateMergeRequestDependencyBuilder<'a> {
+        CreateMergeRequestDependencyBuilder::default()
+    }
+}
+
+impl<'a> CreateMergeRequestDependencyBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateMergeRequestDependencyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateMergeRequestDependency<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/blocks",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("blocking_merge_request_id", self.blocking_merge_request);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::dependencies::{
+        CreateMergeRequestDependency, CreateMergeRequestDependencyBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateMergeRequestDependency::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDependencyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateMergeRequestDependency::builder()
+            .merge_request(1)
+            .blocking_merge_request(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDependencyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = CreateMergeRequestDependency::builder()
+            .project(1)
+            .blocking_merge_request(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDependencyBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn blocking_merge_request_is_needed() {
+        let err = CreateMergeRequestDependency::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDependencyBuilderError,
+            "blocking_merge_request"
+        );
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        CreateMergeRequestDependency::builder()
+            .project(1)
+            .merge_request(1)
+            .blocking_merge_request(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestDependency::builder()
+            .project(0)
+            .merge_request(1)
+            .blocking_merge_request(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateMergeRequestDependencyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/blocks")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("blocking_merge_request_id=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDependency::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .blocking_merge_request(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Remove a dependency from a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteMergeRequestDependency<'a> {
+    /// The project with the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the blocked merge request.
+    merge_request: u64,
+    /// The ID of the dependency to remove.
+    block: u64,
+}
+
+impl<'a> DeleteMergeRequestDependency<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteMergeRequestDependencyBuilder<'a> {
+        DeleteMergeRequestDependencyBuilder::default()
+    }
+}
+
+impl<'a> DeleteMergeRequestDependencyBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteMergeRequestDependencyBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("block", self.block.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteMergeRequestDependency<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/blocks/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.block.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::dependencies::{
+        DeleteMergeRequestDependency, DeleteMergeRequestDependencyBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = DeleteMergeRequestDependency::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDependencyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteMergeRequestDependency::builder()
+            .merge_request(1)
+            .block(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDependencyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = DeleteMergeRequestDependency::builder()
+            .project(1)
+            .block(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDependencyBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn block_is_needed() {
+        let err = DeleteMergeRequestDependency::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteMergeRequestDependencyBuilderError, "block");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        DeleteMergeRequestDependency::builder()
+            .project(1)
+            .merge_request(1)
+            .block(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteMergeRequestDependency::builder()
+            .project(0)
+            .merge_request(1)
+            .block(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteMergeRequestDependencyBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/blocks/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteMergeRequestDependency::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .block(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,10 +6,16 @@
 
 //! Project merge request discussion API endpoints.
 //!
-//! These endpoints are used for querying project merge request discussions.
+//! These endpoints are used for querying, replying to, and resolving project merge request
+//! discussions.
 
 mod create;
+mod create_note;
+mod delete_note;
+mod discussion;
 mod discussions;
+mod edit_note;
+mod resolve;
 
 pub use self::create::CreateMergeRequestDiscussion;
 pub use self::create::CreateMergeRequestDiscussionBuilder;
@@ -31,6 +37,26 @@ pub use self::create::TextPosition;
 pub use self::create::TextPositionBuilder;
 pub use self::create::TextPositionBuilderError;
 
+pub use self::create_note::CreateMergeRequestDiscussionNote;
+pub use self::create_note::CreateMergeRequestDiscussionNoteBuilder;
+pub use self::create_note::CreateMergeRequestDiscussionNoteBuilderError;
+
+pub use self::delete_note::DeleteMergeRequestDiscussionNote;
+pub use self::delete_note::DeleteMergeRequestDiscussionNoteBuilder;
+pub use self::delete_note::DeleteMergeRequestDiscussionNoteBuilderError;
+
+pub use self::discussion::MergeRequestDiscussion;
+pub use self::discussion::MergeRequestDiscussionBuilder;
+pub use self::discussion::MergeRequestDiscussionBuilderError;
+
 pub use self::discussions::MergeRequestDiscussions;
 pub use self::discussions::MergeRequestDiscussionsBuilder;
 pub use self::discussions::MergeRequestDiscussionsBuilderError;
+
+pub use self::edit_note::EditMergeRequestDiscussionNote;
+pub use self::edit_note::EditMergeRequestDiscussionNoteBuilder;
+pub use self::edit_note::EditMergeRequestDiscussionNoteBuilderError;
+
+pub use self::resolve::ResolveMergeRequestDiscussion;
+pub use self::resolve::ResolveMergeRequestDiscussionBuilder;
+pub use self::resolve::ResolveMergeRequestDiscussionBuilderError;
@@ -7,7 +7,7 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -36,6 +36,10 @@ impl ParamValue<'static> for LineType {
     }
 }
 
+fn position_param(field: &str) -> ParamName {
+    ParamName::new("position").key(field)
+}
+
 /// The line code for a discussion comment.
 #[derive(Debug, Clone, Builder)]
 pub struct LineCode<'a> {
@@ -76,15 +80,21 @@ impl<'a> LineRange<'a> {
     fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
             .push(
-                "position[line_range][start][line_code]",
+                position_param("line_range").key("start").key("line_code"),
                 self.start.line_code.as_ref(),
             )
-            .push("position[line_range][start][type]", self.start.type_)
             .push(
-                "position[line_range][end][line_code]",
+                position_param("line_range").key("start").key("type"),
+                self.start.type_,
+            )
+            .push(
+                position_param("line_range").key("end").key("line_code"),
                 self.end.line_code.as_ref(),
             )
-            .push("position[line_range][end][type]", self.end.type_);
+            .push(
+                position_param("line_range").key("end").key("type"),
+                self.end.type_,
+            );
     }
 }
 
@@ -117,10 +127,10 @@ impl<'a> TextPosition<'a> {
 
     fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
-            .push_opt("position[new_path]", self.new_path.as_ref())
-            .push_opt("position[new_line]", self.new_line)
-            .push_opt("position[old_path]", self.old_path.as_ref())
-            .push_opt("position[old_line]", self.old_line);
+            .push_opt(position_param("new_path"), self.new_path.as_ref())
+            .push_opt(position_param("new_line"), self.new_line)
+            .push_opt(position_param("old_path"), self.old_path.as_ref())
+            .push_opt(position_param("old_line"), self.old_line);
 
         if let Some(line_range) = self.line_range.as_ref() {
             line_range.add_params(params);
@@ -154,10 +164,10 @@ impl ImagePosition {
 
     fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
-            .push_opt("position[width]", self.width)
-            .push_opt("position[height]", self.height)
-            .push_opt("position[x]", self.x)
-            .push_opt("position[y]", self.y);
+            .push_opt(position_param("width"), self.width)
+            .push_opt(position_param("height"), self.height)
+            .push_opt(position_param("x"), self.x)
+            .push_opt(position_param("y"), self.y);
     }
 }
 
@@ -222,12 +232,12 @@ impl<'a> Position<'a> {
         PositionBuilder::default()
     }
 
-    fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+    pub(crate) fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
-            .push("position[base_sha]", self.base_sha.as_ref())
-            .push("position[start_sha]", self.start_sha.as_ref())
-            .push("position[head_sha]", self.head_sha.as_ref())
-            .push("position[position_type]", self.position.type_str());
+            .push(position_param("base_sha"), self.base_sha.as_ref())
+            .push(position_param("start_sha"), self.start_sha.as_ref())
+            .push(position_param("head_sha"), self.head_sha.as_ref())
+            .push(position_param("position_type"), self.position.type_str());
 
         self.position.add_params(params);
     }
@@ -235,7 +245,7 @@ impl<'a> Position<'a> {
 
 /// Create a new discussion on a merge request on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestDiscussion<'a> {
     /// The project of the merge request.
     #[builder(setter(into))]
@@ -266,6 +276,15 @@ impl<'a> CreateMergeRequestDiscussion<'a> {
     }
 }
 
+impl<'a> CreateMergeRequestDiscussionBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateMergeRequestDiscussionBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateMergeRequestDiscussion<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -274,7 +293,8 @@ impl<'a> Endpoint for CreateMergeRequestDiscussion<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/discussions",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -553,6 +573,21 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestDiscussion::builder()
+            .project(0)
+            .merge_request(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateMergeRequestDiscussionBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,231 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Reply to a discussion on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateMergeRequestDiscussionNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the discussion.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+    /// The content of the note.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+
+    /// When the note was created.
+    ///
+    /// Requires administrator or owner permissions.
+    #[builder(default)]
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> CreateMergeRequestDiscussionNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateMergeRequestDiscussionNoteBuilder<'a> {
+        CreateMergeRequestDiscussionNoteBuilder::default()
+    }
+}
+
+impl<'a> CreateMergeRequestDiscussionNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateMergeRequestDiscussionNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("discussion", self.discussion.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateMergeRequestDiscussionNote<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/discussions/{}/notes",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.discussion.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("body", self.body.as_ref())
+            .push_opt("created_at", self.created_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{
+        CreateMergeRequestDiscussionNote, CreateMergeRequestDiscussionNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateMergeRequestDiscussionNote::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateMergeRequestDiscussionNote::builder()
+            .merge_request(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = CreateMergeRequestDiscussionNote::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDiscussionNoteBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = CreateMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDiscussionNoteBuilderError,
+            "discussion"
+        );
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = CreateMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDiscussionNoteBuilderError,
+            "body"
+        );
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        CreateMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestDiscussionNote::builder()
+            .project(0)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef/notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("body=body")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDiscussionNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef/notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("body=body", "&created_at=2020-01-01T00%3A00%3A00Z"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDiscussionNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .body("body")
+            .created_at(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
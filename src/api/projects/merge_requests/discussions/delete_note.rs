@@ -0,0 +1,190 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a note within a discussion on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteMergeRequestDiscussionNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the discussion.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> DeleteMergeRequestDiscussionNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteMergeRequestDiscussionNoteBuilder<'a> {
+        DeleteMergeRequestDiscussionNoteBuilder::default()
+    }
+}
+
+impl<'a> DeleteMergeRequestDiscussionNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteMergeRequestDiscussionNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("discussion", self.discussion.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteMergeRequestDiscussionNote<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/discussions/{}/notes/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.discussion.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{
+        DeleteMergeRequestDiscussionNote, DeleteMergeRequestDiscussionNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = DeleteMergeRequestDiscussionNote::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteMergeRequestDiscussionNote::builder()
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = DeleteMergeRequestDiscussionNote::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDiscussionNoteBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = DeleteMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDiscussionNoteBuilderError,
+            "discussion"
+        );
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = DeleteMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestDiscussionNoteBuilderError,
+            "note"
+        );
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        DeleteMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteMergeRequestDiscussionNote::builder()
+            .project(0)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteMergeRequestDiscussionNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
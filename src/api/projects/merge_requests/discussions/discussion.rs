@@ -0,0 +1,144 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a discussion on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct MergeRequestDiscussion<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the discussion.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+}
+
+impl<'a> MergeRequestDiscussion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeRequestDiscussionBuilder<'a> {
+        MergeRequestDiscussionBuilder::default()
+    }
+}
+
+impl<'a> MergeRequestDiscussionBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestDiscussionBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("discussion", self.discussion.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for MergeRequestDiscussion<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/discussions/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.discussion.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::merge_requests::discussions::{
+        MergeRequestDiscussion, MergeRequestDiscussionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = MergeRequestDiscussion::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestDiscussionBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = MergeRequestDiscussion::builder()
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestDiscussionBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = MergeRequestDiscussion::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            MergeRequestDiscussionBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = MergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestDiscussionBuilderError, "discussion");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        MergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestDiscussion::builder()
+            .project(0)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestDiscussionBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestDiscussion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit a note within a discussion on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditMergeRequestDiscussionNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the discussion.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+    /// The ID of the note.
+    note: u64,
+
+    /// The new content of the note.
+    #[builder(setter(into), default)]
+    body: Option<Cow<'a, str>>,
+    /// Whether the note is resolved or not.
+    #[builder(default)]
+    resolved: Option<bool>,
+}
+
+impl<'a> EditMergeRequestDiscussionNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditMergeRequestDiscussionNoteBuilder<'a> {
+        EditMergeRequestDiscussionNoteBuilder::default()
+    }
+}
+
+impl<'a> EditMergeRequestDiscussionNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EditMergeRequestDiscussionNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("discussion", self.discussion.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditMergeRequestDiscussionNote<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/discussions/{}/notes/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.discussion.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("body", self.body.as_ref())
+            .push_opt("resolved", self.resolved);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{
+        EditMergeRequestDiscussionNote, EditMergeRequestDiscussionNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = EditMergeRequestDiscussionNote::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = EditMergeRequestDiscussionNote::builder()
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = EditMergeRequestDiscussionNote::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditMergeRequestDiscussionNoteBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = EditMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditMergeRequestDiscussionNoteBuilderError,
+            "discussion"
+        );
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = EditMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditMergeRequestDiscussionNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        EditMergeRequestDiscussionNote::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditMergeRequestDiscussionNote::builder()
+            .project(0)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditMergeRequestDiscussionNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef/notes/2")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequestDiscussionNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_body() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef/notes/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("body=body")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequestDiscussionNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(2)
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_resolved() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef/notes/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("resolved=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequestDiscussionNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .note(2)
+            .resolved(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Resolve or unresolve a discussion on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ResolveMergeRequestDiscussion<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the discussion.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+    /// Whether the discussion is resolved or not.
+    resolved: bool,
+}
+
+impl<'a> ResolveMergeRequestDiscussion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResolveMergeRequestDiscussionBuilder<'a> {
+        ResolveMergeRequestDiscussionBuilder::default()
+    }
+}
+
+impl<'a> ResolveMergeRequestDiscussionBuilder<'a> {
+    fn validate(&self) -> Result<(), ResolveMergeRequestDiscussionBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("discussion", self.discussion.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ResolveMergeRequestDiscussion<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/discussions/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.discussion.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("resolved", self.resolved);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{
+        ResolveMergeRequestDiscussion, ResolveMergeRequestDiscussionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "discussion"
+        );
+    }
+
+    #[test]
+    fn resolved_is_necessary() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "resolved"
+        );
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        ResolveMergeRequestDiscussion::builder()
+            .project(1)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ResolveMergeRequestDiscussion::builder()
+            .project(0)
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            ResolveMergeRequestDiscussionBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("resolved=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResolveMergeRequestDiscussion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_unresolve() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/discussions/deadbeef")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("resolved=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResolveMergeRequestDiscussion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .discussion("deadbeef")
+            .resolved(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Publish all draft notes on a merge request.
+///
+/// This publishes all draft notes of the current user as a single review.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct BulkPublishMergeRequestDraftNotes<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+}
+
+impl<'a> BulkPublishMergeRequestDraftNotes<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BulkPublishMergeRequestDraftNotesBuilder<'a> {
+        BulkPublishMergeRequestDraftNotesBuilder::default()
+    }
+}
+
+impl<'a> BulkPublishMergeRequestDraftNotesBuilder<'a> {
+    fn validate(&self) -> Result<(), BulkPublishMergeRequestDraftNotesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for BulkPublishMergeRequestDraftNotes<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/draft_notes/bulk_publish",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::draft_notes::{
+        BulkPublishMergeRequestDraftNotes, BulkPublishMergeRequestDraftNotesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_merge_request_are_needed() {
+        let err = BulkPublishMergeRequestDraftNotes::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkPublishMergeRequestDraftNotesBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = BulkPublishMergeRequestDraftNotes::builder()
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkPublishMergeRequestDraftNotesBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = BulkPublishMergeRequestDraftNotes::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkPublishMergeRequestDraftNotesBuilderError,
+            "merge_request",
+        );
+    }
+
+    #[test]
+    fn project_and_merge_request_are_sufficient() {
+        BulkPublishMergeRequestDraftNotes::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = BulkPublishMergeRequestDraftNotes::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            BulkPublishMergeRequestDraftNotesBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes/bulk_publish")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkPublishMergeRequestDraftNotes::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,296 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::merge_requests::discussions::Position;
+
+/// Create a draft note on a merge request.
+///
+/// Draft notes are only visible to their author until they are published.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateMergeRequestDraftNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The content of the note.
+    #[builder(setter(into))]
+    note: Cow<'a, str>,
+
+    /// A SHA referencing a commit to start the thread on.
+    #[builder(setter(into), default)]
+    commit_id: Option<Cow<'a, str>>,
+    /// The ID of a discussion to reply to.
+    #[builder(setter(into), default)]
+    in_reply_to_discussion_id: Option<Cow<'a, str>>,
+    /// Whether to resolve the discussion when the note is published.
+    #[builder(default)]
+    resolve_discussion: Option<bool>,
+    /// The location of the note in the diff.
+    #[builder(default)]
+    position: Option<Position<'a>>,
+}
+
+impl<'a> CreateMergeRequestDraftNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateMergeRequestDraftNoteBuilder<'a> {
+        CreateMergeRequestDraftNoteBuilder::default()
+    }
+}
+
+impl<'a> CreateMergeRequestDraftNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateMergeRequestDraftNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateMergeRequestDraftNote<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/draft_notes",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("note", self.note.as_ref())
+            .push_opt("commit_id", self.commit_id.as_ref())
+            .push_opt(
+                "in_reply_to_discussion_id",
+                self.in_reply_to_discussion_id.as_ref(),
+            )
+            .push_opt("resolve_discussion", self.resolve_discussion);
+
+        if let Some(position) = self.position.as_ref() {
+            position.add_params(&mut params);
+        }
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{Position, TextPosition};
+    use crate::api::projects::merge_requests::draft_notes::{
+        CreateMergeRequestDraftNote, CreateMergeRequestDraftNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_note_are_necessary() {
+        let err = CreateMergeRequestDraftNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateMergeRequestDraftNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateMergeRequestDraftNote::builder()
+            .merge_request(1)
+            .note("note")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateMergeRequestDraftNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = CreateMergeRequestDraftNote::builder()
+            .project(1)
+            .note("note")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateMergeRequestDraftNoteBuilderError,
+            "merge_request",
+        );
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = CreateMergeRequestDraftNote::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateMergeRequestDraftNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn project_merge_request_and_note_are_sufficient() {
+        CreateMergeRequestDraftNote::builder()
+            .project(1)
+            .merge_request(1)
+            .note("note")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestDraftNote::builder()
+            .project(0)
+            .merge_request(1)
+            .note("note")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateMergeRequestDraftNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("note=note")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .note("note")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_commit_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "note=note",
+                "&commit_id=0000000000000000000000000000000000000000",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .note("note")
+            .commit_id("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_in_reply_to_discussion_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("note=note", "&in_reply_to_discussion_id=deadbeef"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .note("note")
+            .in_reply_to_discussion_id("deadbeef")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_resolve_discussion() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("note=note", "&resolve_discussion=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .note("note")
+            .resolve_discussion(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_position() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "note=note",
+                "&position%5Bbase_sha%5D=0000000000000000000000000000000000000000",
+                "&position%5Bstart_sha%5D=deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                "&position%5Bhead_sha%5D=cafebabecafebabecafebabecafebabecafebabe",
+                "&position%5Bposition_type%5D=text",
+                "&position%5Bnew_path%5D=path%2Fto%2Ffile",
+                "&position%5Bnew_line%5D=10",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .note("note")
+            .position(
+                Position::builder()
+                    .base_sha("0000000000000000000000000000000000000000")
+                    .start_sha("deadbeefdeadbeefdeadbeefdeadbeefdeadbeef")
+                    .head_sha("cafebabecafebabecafebabecafebabecafebabe")
+                    .text_position(
+                        TextPosition::builder()
+                            .new_path("path/to/file")
+                            .new_line(10)
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
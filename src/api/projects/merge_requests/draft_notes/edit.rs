@@ -0,0 +1,234 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::merge_requests::discussions::Position;
+
+/// Edit a draft note on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditMergeRequestDraftNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the draft note.
+    draft_note: u64,
+
+    /// The new content of the note.
+    #[builder(setter(into), default)]
+    note: Option<Cow<'a, str>>,
+    /// The new location of the note in the diff.
+    #[builder(default)]
+    position: Option<Position<'a>>,
+}
+
+impl<'a> EditMergeRequestDraftNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditMergeRequestDraftNoteBuilder<'a> {
+        EditMergeRequestDraftNoteBuilder::default()
+    }
+}
+
+impl<'a> EditMergeRequestDraftNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EditMergeRequestDraftNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("draft_note", self.draft_note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditMergeRequestDraftNote<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/draft_notes/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.draft_note.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("note", self.note.as_ref());
+
+        if let Some(position) = self.position.as_ref() {
+            position.add_params(&mut params);
+        }
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::discussions::{ImagePosition, Position};
+    use crate::api::projects::merge_requests::draft_notes::{
+        EditMergeRequestDraftNote, EditMergeRequestDraftNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_draft_note_are_necessary() {
+        let err = EditMergeRequestDraftNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditMergeRequestDraftNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = EditMergeRequestDraftNote::builder()
+            .merge_request(1)
+            .draft_note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditMergeRequestDraftNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = EditMergeRequestDraftNote::builder()
+            .project(1)
+            .draft_note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditMergeRequestDraftNoteBuilderError,
+            "merge_request",
+        );
+    }
+
+    #[test]
+    fn draft_note_is_necessary() {
+        let err = EditMergeRequestDraftNote::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            EditMergeRequestDraftNoteBuilderError,
+            "draft_note",
+        );
+    }
+
+    #[test]
+    fn project_merge_request_and_draft_note_are_sufficient() {
+        EditMergeRequestDraftNote::builder()
+            .project(1)
+            .merge_request(1)
+            .draft_note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditMergeRequestDraftNote::builder()
+            .project(0)
+            .merge_request(1)
+            .draft_note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditMergeRequestDraftNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .draft_note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_note() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("note=note")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .draft_note(2)
+            .note("note")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_position() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/draft_notes/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "position%5Bbase_sha%5D=0000000000000000000000000000000000000000",
+                "&position%5Bstart_sha%5D=deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                "&position%5Bhead_sha%5D=cafebabecafebabecafebabecafebabecafebabe",
+                "&position%5Bposition_type%5D=image",
+                "&position%5Bx%5D=1",
+                "&position%5By%5D=2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditMergeRequestDraftNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .draft_note(2)
+            .position(
+                Position::builder()
+                    .base_sha("0000000000000000000000000000000000000000")
+                    .start_sha("deadbeefdeadbeefdeadbeefdeadbeefdeadbeef")
+                    .head_sha("cafebabecafebabecafebabecafebabecafebabe")
+                    .image_position(ImagePosition::builder().x(1).y(2).build().unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
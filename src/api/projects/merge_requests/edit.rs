@@ -8,7 +8,7 @@ use std::iter;
 
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::merge_requests::create::{Assignee, Reviewer};
 use crate::api::ParamValue;
@@ -56,7 +56,7 @@ impl ParamValue<'static> for MergeRequestStateEvent {
 
 /// Edit a new merge request on project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditMergeRequest<'a> {
     /// The project to open the merge requset *from*.
     #[builder(setter(into))]
@@ -278,6 +278,13 @@ impl<'a> EditMergeRequestBuilder<'a> {
             .push(label.into());
         self
     }
+
+    fn validate(&self) -> Result<(), EditMergeRequestBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for EditMergeRequest<'a> {
@@ -288,7 +295,8 @@ impl<'a> Endpoint for EditMergeRequest<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -378,13 +386,22 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditMergeRequest::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditMergeRequestBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("")
+            .form_params(&[])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -402,8 +419,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("target_branch=target%2Fbranch")
+            .form_params(&[("target_branch", "target/branch")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -422,8 +438,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("title=title")
+            .form_params(&[("title", "title")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -442,8 +457,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("assignee_ids=0")
+            .form_params(&[("assignee_ids", "0")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -462,8 +476,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("assignee_id=1")
+            .form_params(&[("assignee_id", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -482,8 +495,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("assignee_ids%5B%5D=1", "&assignee_ids%5B%5D=2"))
+            .form_params(&[("assignee_ids[]", "1"), ("assignee_ids[]", "2")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -503,8 +515,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("reviewer_ids=0")
+            .form_params(&[("reviewer_ids", "0")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -523,8 +534,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("reviewer_ids%5B%5D=1")
+            .form_params(&[("reviewer_ids[]", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -543,8 +553,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!("reviewer_ids%5B%5D=1", "&reviewer_ids%5B%5D=2"))
+            .form_params(&[("reviewer_ids[]", "1"), ("reviewer_ids[]", "2")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -564,8 +573,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("milestone_id=1")
+            .form_params(&[("milestone_id", "1")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -584,8 +592,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("labels=label%2Clabel1%2Clabel2")
+            .form_params(&[("labels", "label,label1,label2")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -606,8 +613,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("labels=")
+            .form_params(&[("labels", "")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -626,8 +632,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("labels=")
+            .form_params(&[("labels", "")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -646,8 +651,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("add_labels=one%2Ctwo")
+            .form_params(&[("add_labels", "one,two")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -667,8 +671,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("remove_labels=one%2Ctwo")
+            .form_params(&[("remove_labels", "one,two")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -688,8 +691,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("description=description")
+            .form_params(&[("description", "description")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -708,8 +710,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("state_event=reopen")
+            .form_params(&[("state_event", "reopen")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -728,8 +729,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("remove_source_branch=true")
+            .form_params(&[("remove_source_branch", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -748,8 +748,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("squash=false")
+            .form_params(&[("squash", "false")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -768,8 +767,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("discussion_locked=false")
+            .form_params(&[("discussion_locked", "false")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -788,8 +786,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("allow_collaboration=true")
+            .form_params(&[("allow_collaboration", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -809,8 +806,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::PUT)
             .endpoint("projects/simple%2Fproject/merge_requests/1")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("allow_maintainer_to_push=true")
+            .form_params(&[("allow_maintainer_to_push", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for issues closed by a merge request.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct IssuesClosedBy<'a> {
     /// The project to of the merge request.
     #[builder(setter(into))]
@@ -27,6 +27,15 @@ impl<'a> IssuesClosedBy<'a> {
     }
 }
 
+impl<'a> IssuesClosedByBuilder<'a> {
+    fn validate(&self) -> Result<(), IssuesClosedByBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for IssuesClosedBy<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,7 +44,8 @@ impl<'a> Endpoint for IssuesClosedBy<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/closes_issues",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -79,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = IssuesClosedBy::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, IssuesClosedByBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -10,7 +10,7 @@ use std::iter;
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder, YesNo};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder, YesNo};
 use crate::api::endpoint_prelude::*;
 use crate::api::helpers::{Labels, ReactionEmoji};
 use crate::api::ParamValue;
@@ -201,7 +201,10 @@ impl ApproverIds {
                 params.push("approver_ids", "None");
             },
             ApproverIds::AllOf(ids) => {
-                params.extend(ids.iter().map(|&id| ("approver_ids[]", id)));
+                params.extend(
+                    ids.iter()
+                        .map(|&id| (ParamName::new("approver_ids").array(), id)),
+                );
             },
         }
     }
@@ -226,13 +229,16 @@ impl<'a> ApprovedBy<'a> {
                 params.push("approved_by_ids", "None");
             },
             ApprovedBy::AllOfIds(ids) => {
-                params.extend(ids.iter().map(|&id| ("approved_by_ids[]", id)));
+                params.extend(
+                    ids.iter()
+                        .map(|&id| (ParamName::new("approved_by_ids").array(), id)),
+                );
             },
             ApprovedBy::AllOfUsernames(usernames) => {
                 params.extend(
-                    usernames
-                        .iter()
-                        .map(|username| ("approved_by_usernames[]", username)),
+                    usernames.iter().map(|username| {
+                        (ParamName::new("approved_by_usernames").array(), username)
+                    }),
                 );
             },
         }
@@ -267,7 +273,7 @@ impl<'a, 'b: 'a> ParamValue<'a> for &'b MergeRequestMilestone<'a> {
 ///
 /// TODO: Negation (not) filters are not yet supported.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeRequests<'a> {
     /// The project to query for merge requests.
     #[builder(setter(into))]
@@ -621,6 +627,12 @@ impl<'a> MergeRequestsBuilder<'a> {
     pub fn search_in(&mut self, _: MergeRequestSearchScope) -> &mut Self {
         self
     }
+
+    fn validate(&self) -> Result<(), MergeRequestsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for MergeRequests<'a> {
@@ -629,14 +641,18 @@ impl<'a> Endpoint for MergeRequests<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/merge_requests", self.project).into()
+        format!("projects/{}/merge_requests", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
 
         params
-            .extend(self.iids.iter().map(|&value| ("iids[]", value)))
+            .extend(
+                self.iids
+                    .iter()
+                    .map(|&value| (ParamName::new("iids").array(), value)),
+            )
             .push_opt("state", self.state)
             .push_opt("milestone", self.milestone.as_ref())
             .push_opt("view", self.view)
@@ -794,6 +810,12 @@ mod tests {
         MergeRequests::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequests::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
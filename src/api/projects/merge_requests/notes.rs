@@ -10,18 +10,31 @@
 
 pub mod awards;
 mod create;
+mod delete;
 mod edit;
+mod note;
 mod notes;
 
 pub use self::create::CreateMergeRequestNote;
 pub use self::create::CreateMergeRequestNoteBuilder;
 pub use self::create::CreateMergeRequestNoteBuilderError;
 
+pub use self::delete::DeleteMergeRequestNote;
+pub use self::delete::DeleteMergeRequestNoteBuilder;
+pub use self::delete::DeleteMergeRequestNoteBuilderError;
+
 pub use self::edit::EditMergeRequestNote;
 pub use self::edit::EditMergeRequestNoteBuilder;
 pub use self::edit::EditMergeRequestNoteBuilderError;
 
+pub use self::note::MergeRequestNote;
+pub use self::note::MergeRequestNoteBuilder;
+pub use self::note::MergeRequestNoteBuilderError;
+
 pub use self::notes::MergeRequestNotes;
 pub use self::notes::MergeRequestNotesBuilder;
 pub use self::notes::MergeRequestNotesBuilderError;
+pub use crate::api::helpers::filter_notes;
+pub use crate::api::helpers::FilteredNotes;
+pub use crate::api::helpers::NoteActivityFilter;
 pub use crate::api::helpers::NoteOrderBy;
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for award on a note within an merge_request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestNoteAward<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -30,6 +31,17 @@ impl<'a> MergeRequestNoteAward<'a> {
     }
 }
 
+impl<'a> MergeRequestNoteAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestNoteAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+        common::validate_path_param("award", self.award.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestNoteAward<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -38,7 +50,10 @@ impl<'a> Endpoint for MergeRequestNoteAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes/{}/award_emoji/{}",
-            self.project, self.merge_request, self.note, self.award,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.note.path_segment(),
+            self.award.path_segment(),
         )
         .into()
     }
@@ -113,6 +128,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestNoteAward::builder()
+            .project(0)
+            .merge_request(1)
+            .note(1)
+            .award(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestNoteAwardBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
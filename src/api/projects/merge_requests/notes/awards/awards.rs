@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for awards on a note on a merge request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestNoteAwards<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -28,6 +29,16 @@ impl<'a> MergeRequestNoteAwards<'a> {
     }
 }
 
+impl<'a> MergeRequestNoteAwardsBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestNoteAwardsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestNoteAwards<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -36,7 +47,9 @@ impl<'a> Endpoint for MergeRequestNoteAwards<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes/{}/award_emoji",
-            self.project, self.merge_request, self.note,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.note.path_segment(),
         )
         .into()
     }
@@ -102,6 +115,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestNoteAwards::builder()
+            .project(0)
+            .merge_request(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestNoteAwardsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new award on a note on a merge request on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestNoteAward<'a> {
     /// The project the merge request belongs to.
     #[builder(setter(into))]
@@ -31,6 +32,16 @@ impl<'a> CreateMergeRequestNoteAward<'a> {
     }
 }
 
+impl<'a> CreateMergeRequestNoteAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateMergeRequestNoteAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateMergeRequestNoteAward<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -39,7 +50,9 @@ impl<'a> Endpoint for CreateMergeRequestNoteAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes/{}/award_emoji",
-            self.project, self.merge_request, self.note,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.note.path_segment(),
         )
         .into()
     }
@@ -128,6 +141,22 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestNoteAward::builder()
+            .project(0)
+            .merge_request(1)
+            .note(1)
+            .name("award")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateMergeRequestNoteAwardBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete an existing award on a note within a merge_request within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteMergeRequestNoteAward<'a> {
     /// The project to query for the merge_request.
     #[builder(setter(into))]
@@ -30,6 +31,17 @@ impl<'a> DeleteMergeRequestNoteAward<'a> {
     }
 }
 
+impl<'a> DeleteMergeRequestNoteAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteMergeRequestNoteAwardBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+        common::validate_path_param("award", self.award.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteMergeRequestNoteAward<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -38,7 +50,10 @@ impl<'a> Endpoint for DeleteMergeRequestNoteAward<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes/{}/award_emoji/{}",
-            self.project, self.merge_request, self.note, self.award,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.note.path_segment(),
+            self.award.path_segment(),
         )
         .into()
     }
@@ -119,6 +134,22 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteMergeRequestNoteAward::builder()
+            .project(0)
+            .merge_request(1)
+            .note(1)
+            .award(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteMergeRequestNoteAwardBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
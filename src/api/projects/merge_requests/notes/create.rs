@@ -7,12 +7,12 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new note on a merge request on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestNote<'a> {
     /// The project the merge request belongs to.
     #[builder(setter(into))]
@@ -58,6 +58,13 @@ impl<'a> CreateMergeRequestNoteBuilder<'a> {
     {
         self.merge_request_diff_head_sha(merge_request_diff_sha)
     }
+
+    fn validate(&self) -> Result<(), CreateMergeRequestNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateMergeRequestNote<'a> {
@@ -68,7 +75,8 @@ impl<'a> Endpoint for CreateMergeRequestNote<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -150,6 +158,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateMergeRequestNote::builder()
+            .project(0)
+            .merge_request(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateMergeRequestNoteBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,146 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a note on a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteMergeRequestNote<'a> {
+    /// The project of the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The internal ID of the merge request.
+    merge_request: u64,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> DeleteMergeRequestNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteMergeRequestNoteBuilder<'a> {
+        DeleteMergeRequestNoteBuilder::default()
+    }
+}
+
+impl<'a> DeleteMergeRequestNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteMergeRequestNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteMergeRequestNote<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/notes/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::merge_requests::notes::{
+        DeleteMergeRequestNote, DeleteMergeRequestNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_note_are_necessary() {
+        let err = DeleteMergeRequestNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteMergeRequestNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteMergeRequestNote::builder()
+            .merge_request(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteMergeRequestNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_necessary() {
+        let err = DeleteMergeRequestNote::builder()
+            .project(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            DeleteMergeRequestNoteBuilderError,
+            "merge_request"
+        );
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = DeleteMergeRequestNote::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteMergeRequestNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn project_merge_request_and_note_are_sufficient() {
+        DeleteMergeRequestNote::builder()
+            .project(1)
+            .merge_request(1)
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteMergeRequestNote::builder()
+            .project(0)
+            .merge_request(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteMergeRequestNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/merge_requests/1/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteMergeRequestNote::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
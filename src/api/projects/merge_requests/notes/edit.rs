@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Edit a merge request note on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditMergeRequestNote<'a> {
     /// The project to add the merge request to.
     #[builder(setter(into))]
@@ -41,6 +41,16 @@ impl<'a> EditMergeRequestNote<'a> {
     }
 }
 
+impl<'a> EditMergeRequestNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EditMergeRequestNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditMergeRequestNote<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -49,7 +59,9 @@ impl<'a> Endpoint for EditMergeRequestNote<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes/{}",
-            self.project, self.merge_request, self.note,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.note.path_segment(),
         )
         .into()
     }
@@ -134,6 +146,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditMergeRequestNote::builder()
+            .project(0)
+            .merge_request(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditMergeRequestNoteBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
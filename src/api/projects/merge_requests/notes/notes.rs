@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::helpers::NoteOrderBy;
 
 /// Query for notes on an merge request within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeRequestNotes<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -35,6 +35,15 @@ impl<'a> MergeRequestNotes<'a> {
     }
 }
 
+impl<'a> MergeRequestNotesBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestNotesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestNotes<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -43,7 +52,8 @@ impl<'a> Endpoint for MergeRequestNotes<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/notes",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -63,12 +73,17 @@ impl<'a> Pageable for MergeRequestNotes<'a> {}
 
 #[cfg(test)]
 mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
     use crate::api::common::SortOrder;
     use crate::api::projects::merge_requests::notes::{
-        MergeRequestNotes, MergeRequestNotesBuilderError, NoteOrderBy,
+        filter_notes, MergeRequestNotes, MergeRequestNotesBuilderError, NoteActivityFilter,
+        NoteOrderBy,
     };
-    use crate::api::{self, Query};
+    use crate::api::{self, AsyncQuery, Pagination, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedRequest, MockClient};
 
     #[test]
     fn project_and_merge_request_are_necessary() {
@@ -100,6 +115,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestNotes::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestNotesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -151,4 +176,64 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[derive(Debug, Deserialize)]
+    struct Note {
+        id: u64,
+    }
+
+    fn mock_client() -> MockClient {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/simple%2Fproject/merge_requests/1/notes")
+                .build()
+                .unwrap(),
+            [
+                json!({"id": 1, "system": false}),
+                json!({"id": 2, "system": true}),
+                json!({"id": 3, "system": false}),
+            ],
+        );
+        client
+    }
+
+    fn ids(notes: Vec<Note>) -> Vec<u64> {
+        notes.into_iter().map(|note| note.id).collect()
+    }
+
+    #[test]
+    fn endpoint_only_comments() {
+        let client = mock_client();
+
+        let endpoint = MergeRequestNotes::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        let notes: Vec<Note> =
+            filter_notes(endpoint, Pagination::All, NoteActivityFilter::OnlyComments)
+                .query(&client)
+                .unwrap();
+        assert_eq!(ids(notes), [1, 3]);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn endpoint_only_activity_async() {
+        let client = mock_client();
+
+        let endpoint = MergeRequestNotes::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .build()
+            .unwrap();
+        let notes: Vec<Note> =
+            filter_notes(endpoint, Pagination::All, NoteActivityFilter::OnlyActivity)
+                .query_async(&client)
+                .await
+                .unwrap();
+        assert_eq!(ids(notes), [2]);
+        client.assert_all_consumed();
+    }
 }
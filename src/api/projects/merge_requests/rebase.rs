@@ -9,12 +9,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Rebase a merge request.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RebaseMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -34,6 +34,15 @@ impl<'a> RebaseMergeRequest<'a> {
     }
 }
 
+impl<'a> RebaseMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), RebaseMergeRequestBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RebaseMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -42,7 +51,8 @@ impl<'a> Endpoint for RebaseMergeRequest<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/rebase",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -98,6 +108,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RebaseMergeRequest::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RebaseMergeRequestBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Unapprove a merge request.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnapproveMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> UnapproveMergeRequest<'a> {
     }
 }
 
+impl<'a> UnapproveMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), UnapproveMergeRequestBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UnapproveMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -34,7 +44,8 @@ impl<'a> Endpoint for UnapproveMergeRequest<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/merge_requests/{}/unapprove",
-            self.project, self.merge_request,
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
         )
         .into()
     }
@@ -89,6 +100,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UnapproveMergeRequest::builder()
+            .project(0)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnapproveMergeRequestBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
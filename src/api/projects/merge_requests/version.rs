@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a single diff version of a merge request.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct MergeRequestVersion<'a> {
+    /// The project with the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the merge request.
+    merge_request: u64,
+    /// The ID of the version.
+    version: u64,
+
+    /// Return diffs as unified diffs.
+    #[builder(default)]
+    unidiff: Option<bool>,
+}
+
+impl<'a> MergeRequestVersion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeRequestVersionBuilder<'a> {
+        MergeRequestVersionBuilder::default()
+    }
+}
+
+impl<'a> MergeRequestVersionBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestVersionBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("merge_request", self.merge_request.as_ref())?;
+        common::validate_path_param("version", self.version.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for MergeRequestVersion<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/versions/{}",
+            self.project.path_segment(),
+            self.merge_request.path_segment(),
+            self.version.path_segment(),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("unidiff", self.unidiff);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::merge_requests::{
+        MergeRequestVersion, MergeRequestVersionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_version_are_needed() {
+        let err = MergeRequestVersion::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MergeRequestVersion::builder()
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = MergeRequestVersion::builder()
+            .project(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "merge_request");
+    }
+
+    #[test]
+    fn version_is_needed() {
+        let err = MergeRequestVersion::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestVersionBuilderError, "version");
+    }
+
+    #[test]
+    fn project_merge_request_and_version_are_sufficient() {
+        MergeRequestVersion::builder()
+            .project(1)
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequestVersion::builder()
+            .project(0)
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/versions/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestVersion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .version(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_unidiff() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/versions/2")
+            .add_query_params(&[("unidiff", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestVersion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .version(2)
+            .unidiff(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -10,7 +10,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::{endpoint_prelude::*, ParamValue};
 
 /// Filter merge train entries by a scope.
@@ -40,7 +40,7 @@ impl ParamValue<'static> for MergeTrainsScope {
 
 /// Get the list of merge trains for project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeTrains<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -62,13 +62,21 @@ impl<'a> MergeTrains<'a> {
     }
 }
 
+impl<'a> MergeTrainsBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeTrainsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeTrains<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/merge_trains", self.project).into()
+        format!("projects/{}/merge_trains", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -105,6 +113,12 @@ mod tests {
         MergeTrains::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeTrains::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeTrainsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint_sort() {
         let endpoint = ExpectedUrl::builder()
@@ -6,10 +6,52 @@
 
 //! Project milestone API endpoints.
 //!
-//! These endpoints are used for querying project milestones.
+//! These endpoints are used for querying and managing project milestones.
 
+mod burndown_events;
 mod create;
+mod delete;
+mod edit;
+mod issues;
+mod merge_requests;
+mod milestone;
+mod milestones;
+mod promote;
+
+pub use self::burndown_events::ProjectMilestoneBurndownEvents;
+pub use self::burndown_events::ProjectMilestoneBurndownEventsBuilder;
+pub use self::burndown_events::ProjectMilestoneBurndownEventsBuilderError;
 
 pub use self::create::CreateProjectMilestone;
 pub use self::create::CreateProjectMilestoneBuilder;
 pub use self::create::CreateProjectMilestoneBuilderError;
+
+pub use self::delete::DeleteProjectMilestone;
+pub use self::delete::DeleteProjectMilestoneBuilder;
+pub use self::delete::DeleteProjectMilestoneBuilderError;
+
+pub use self::edit::EditProjectMilestone;
+pub use self::edit::EditProjectMilestoneBuilder;
+pub use self::edit::EditProjectMilestoneBuilderError;
+pub use self::edit::MilestoneStateEvent;
+
+pub use self::issues::ProjectMilestoneIssues;
+pub use self::issues::ProjectMilestoneIssuesBuilder;
+pub use self::issues::ProjectMilestoneIssuesBuilderError;
+
+pub use self::merge_requests::ProjectMilestoneMergeRequests;
+pub use self::merge_requests::ProjectMilestoneMergeRequestsBuilder;
+pub use self::merge_requests::ProjectMilestoneMergeRequestsBuilderError;
+
+pub use self::milestone::ProjectMilestone;
+pub use self::milestone::ProjectMilestoneBuilder;
+pub use self::milestone::ProjectMilestoneBuilderError;
+
+pub use self::milestones::MilestoneState;
+pub use self::milestones::ProjectMilestones;
+pub use self::milestones::ProjectMilestonesBuilder;
+pub use self::milestones::ProjectMilestonesBuilderError;
+
+pub use self::promote::PromoteProjectMilestone;
+pub use self::promote::PromoteProjectMilestoneBuilder;
+pub use self::promote::PromoteProjectMilestoneBuilderError;
@@ -8,12 +8,12 @@ use derive_builder::Builder;
 
 use chrono::NaiveDate;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a new milestone on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateProjectMilestone<'a> {
     /// The project to create a new milestone within.
     #[builder(setter(into))]
@@ -40,13 +40,21 @@ impl<'a> CreateProjectMilestone<'a> {
     }
 }
 
+impl<'a> CreateProjectMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateProjectMilestoneBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateProjectMilestone<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/milestones", self.project).into()
+        format!("projects/{}/milestones", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -106,6 +114,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateProjectMilestone::builder()
+            .project("")
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateProjectMilestoneBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
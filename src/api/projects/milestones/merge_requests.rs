@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the merge requests assigned to a project milestone.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectMilestoneMergeRequests<'a> {
+    /// The project of the milestone.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> ProjectMilestoneMergeRequests<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectMilestoneMergeRequestsBuilder<'a> {
+        ProjectMilestoneMergeRequestsBuilder::default()
+    }
+}
+
+impl<'a> ProjectMilestoneMergeRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectMilestoneMergeRequestsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("milestone", self.milestone.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectMilestoneMergeRequests<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/milestones/{}/merge_requests",
+            self.project.path_segment(),
+            self.milestone.path_segment()
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for ProjectMilestoneMergeRequests<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::milestones::{
+        ProjectMilestoneMergeRequests, ProjectMilestoneMergeRequestsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_milestone_are_needed() {
+        let err = ProjectMilestoneMergeRequests::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectMilestoneMergeRequestsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectMilestoneMergeRequests::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectMilestoneMergeRequestsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = ProjectMilestoneMergeRequests::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectMilestoneMergeRequestsBuilderError,
+            "milestone"
+        );
+    }
+
+    #[test]
+    fn project_and_milestone_are_sufficient() {
+        ProjectMilestoneMergeRequests::builder()
+            .project(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectMilestoneMergeRequests::builder()
+            .project(0)
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            ProjectMilestoneMergeRequestsBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones/1/merge_requests")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestoneMergeRequests::builder()
+            .project("simple/project")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
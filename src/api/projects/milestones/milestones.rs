@@ -0,0 +1,306 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// States of milestones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MilestoneState {
+    /// Milestones which are still active.
+    Active,
+    /// Milestones which have been closed.
+    Closed,
+}
+
+impl MilestoneState {
+    fn as_str(self) -> &'static str {
+        match self {
+            MilestoneState::Active => "active",
+            MilestoneState::Closed => "closed",
+        }
+    }
+}
+
+impl ParamValue<'static> for MilestoneState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for milestones within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectMilestones<'a> {
+    /// The project to query for milestones.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Return only the milestones having the given internal IDs.
+    #[builder(setter(name = "_iids"), default, private)]
+    iids: BTreeSet<u64>,
+    /// Filter milestones by state.
+    #[builder(default)]
+    state: Option<MilestoneState>,
+    /// Return only milestones with the given title.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// Search for milestones with a title or description matching the given string.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Include milestones from ancestor groups.
+    #[builder(default)]
+    include_ancestors: Option<bool>,
+    /// Return only milestones updated before the given time.
+    #[builder(default)]
+    updated_before: Option<DateTime<Utc>>,
+    /// Return only milestones updated after the given time.
+    #[builder(default)]
+    updated_after: Option<DateTime<Utc>>,
+}
+
+impl<'a> ProjectMilestones<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectMilestonesBuilder<'a> {
+        ProjectMilestonesBuilder::default()
+    }
+}
+
+impl<'a> ProjectMilestonesBuilder<'a> {
+    /// Return a milestone with an internal ID.
+    pub fn iid(&mut self, iid: u64) -> &mut Self {
+        self.iids.get_or_insert_with(BTreeSet::new).insert(iid);
+        self
+    }
+
+    /// Return milestones with one of a set of internal IDs.
+    pub fn iids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.iids.get_or_insert_with(BTreeSet::new).extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), ProjectMilestonesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectMilestones<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .extend(
+                self.iids
+                    .iter()
+                    .map(|&value| (ParamName::new("iids").array(), value)),
+            )
+            .push_opt("state", self.state)
+            .push_opt("title", self.title.as_ref())
+            .push_opt("search", self.search.as_ref())
+            .push_opt("include_ancestors", self.include_ancestors)
+            .push_opt("updated_before", self.updated_before)
+            .push_opt("updated_after", self.updated_after);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectMilestones<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::projects::milestones::{
+        MilestoneState, ProjectMilestones, ProjectMilestonesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn milestone_state_as_str() {
+        let items = &[
+            (MilestoneState::Active, "active"),
+            (MilestoneState::Closed, "closed"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectMilestones::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectMilestonesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectMilestones::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectMilestones::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectMilestonesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_iids() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("iids[]", "1"), ("iids[]", "2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .iid(1)
+            .iids([1, 2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("state", "closed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .state(MilestoneState::Closed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("title", "title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestors() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("include_ancestors", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .include_ancestors(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("updated_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .updated_before(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_updated_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/milestones")
+            .add_query_params(&[("updated_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectMilestones::builder()
+            .project("simple/project")
+            .updated_after(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
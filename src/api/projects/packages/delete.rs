@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Deletes a project package.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeletePackage<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> DeletePackage<'a> {
     }
 }
 
+impl<'a> DeletePackageBuilder<'a> {
+    fn validate(&self) -> Result<(), DeletePackageBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("package_id", self.package_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeletePackage<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/packages/{}", self.project, self.package_id).into()
+        format!(
+            "projects/{}/packages/{}",
+            self.project.path_segment(),
+            self.package_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -70,6 +85,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeletePackage::builder()
+            .project(0)
+            .package_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeletePackageBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
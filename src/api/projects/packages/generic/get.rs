@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Upload a package file of a single package.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GetPackageFile<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -45,6 +46,17 @@ impl<'a> GetPackageFile<'a> {
     }
 }
 
+impl<'a> GetPackageFileBuilder<'a> {
+    fn validate(&self) -> Result<(), GetPackageFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("package_name", self.package_name.as_ref())?;
+        common::validate_path_param("package_version", self.package_version.as_ref())?;
+        common::validate_path_param("file_name", self.file_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GetPackageFile<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -53,10 +65,10 @@ impl<'a> Endpoint for GetPackageFile<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/packages/generic/{}/{}/{}",
-            self.project,
-            common::path_escaped(self.package_name.as_ref()),
-            common::path_escaped(self.package_version.as_ref()),
-            common::path_escaped(self.file_name.as_ref()),
+            self.project.path_segment(),
+            self.package_name.path_segment(),
+            self.package_version.path_segment(),
+            self.file_name.path_segment(),
         )
         .into()
     }
@@ -130,6 +142,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = GetPackageFile::builder()
+            .project(0)
+            .package_name("test_package")
+            .package_version("1.2.3")
+            .file_name("test_file.zip")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GetPackageFileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
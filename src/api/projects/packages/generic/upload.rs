@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 use derive_builder::Builder;
@@ -72,7 +72,7 @@ impl ParamValue<'static> for UploadPackageSelect {
 
 /// Upload a package file of a single package.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UploadPackageFile<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -124,6 +124,17 @@ impl<'a> UploadPackageFile<'a> {
     }
 }
 
+impl<'a> UploadPackageFileBuilder<'a> {
+    fn validate(&self) -> Result<(), UploadPackageFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("package_name", self.package_name.as_ref())?;
+        common::validate_path_param("package_version", self.package_version.as_ref())?;
+        common::validate_path_param("file_name", self.file_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UploadPackageFile<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -132,10 +143,10 @@ impl<'a> Endpoint for UploadPackageFile<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/packages/generic/{}/{}/{}",
-            self.project,
-            common::path_escaped(self.package_name.as_ref()),
-            common::path_escaped(self.package_version.as_ref()),
-            common::path_escaped(self.file_name.as_ref()),
+            self.project.path_segment(),
+            self.package_name.path_segment(),
+            self.package_version.path_segment(),
+            self.file_name.path_segment(),
         )
         .into()
     }
@@ -244,6 +255,19 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UploadPackageFile::builder()
+            .project(0)
+            .package_name("test_package")
+            .package_version("1.2.3")
+            .file_name("test_file.zip")
+            .contents(&b"contents"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UploadPackageFileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let contents = &b"contents"[..];
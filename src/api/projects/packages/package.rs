@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Get a single project package.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Package<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> Package<'a> {
     }
 }
 
+impl<'a> PackageBuilder<'a> {
+    fn validate(&self) -> Result<(), PackageBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("package_id", self.package_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Package<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/packages/{}", self.project, self.package_id).into()
+        format!(
+            "projects/{}/packages/{}",
+            self.project.path_segment(),
+            self.package_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -64,6 +79,16 @@ mod tests {
         Package::builder().project(1).package_id(2).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Package::builder()
+            .project(0)
+            .package_id(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PackageBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Delete a package file of a single package.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeletePackageFile<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -29,6 +30,16 @@ impl<'a> DeletePackageFile<'a> {
     }
 }
 
+impl<'a> DeletePackageFileBuilder<'a> {
+    fn validate(&self) -> Result<(), DeletePackageFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("package_id", self.package_id.as_ref())?;
+        common::validate_path_param("package_file_id", self.package_file_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeletePackageFile<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -37,7 +48,9 @@ impl<'a> Endpoint for DeletePackageFile<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/packages/{}/package_files/{}",
-            self.project, self.package_id, self.package_file_id,
+            self.project.path_segment(),
+            self.package_id.path_segment(),
+            self.package_file_id.path_segment(),
         )
         .into()
     }
@@ -93,6 +106,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeletePackageFile::builder()
+            .project(0)
+            .package_id(1)
+            .package_file_id(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeletePackageFileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Get a list of package files of a single package.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PackageFiles<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> PackageFiles<'a> {
     }
 }
 
+impl<'a> PackageFilesBuilder<'a> {
+    fn validate(&self) -> Result<(), PackageFilesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("package_id", self.package_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PackageFiles<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for PackageFiles<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/packages/{}/package_files",
-            self.project, self.package_id,
+            self.project.path_segment(),
+            self.package_id.path_segment(),
         )
         .into()
     }
@@ -74,6 +85,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PackageFiles::builder()
+            .project(0)
+            .package_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PackageFilesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
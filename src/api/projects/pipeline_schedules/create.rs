@@ -9,7 +9,7 @@ use std::str::FromStr;
 use derive_builder::Builder;
 use thiserror::Error;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -545,7 +545,7 @@ impl<'a> ParamValue<'a> for &'a PipelineScheduleTimeZone<'a> {
 
 /// Create a new pipeline schedule on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreatePipelineSchedule<'a> {
     /// The project to create the pipeline schedule within.
     #[builder(setter(into))]
@@ -576,13 +576,25 @@ impl<'a> CreatePipelineSchedule<'a> {
     }
 }
 
+impl<'a> CreatePipelineScheduleBuilder<'a> {
+    fn validate(&self) -> Result<(), CreatePipelineScheduleBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreatePipelineSchedule<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipeline_schedules", self.project).into()
+        format!(
+            "projects/{}/pipeline_schedules",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -876,6 +888,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreatePipelineSchedule::builder()
+            .project(0)
+            .description("desc")
+            .ref_("master")
+            .cron(PipelineScheduleCron::new("0 1 * * *").unwrap())
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreatePipelineScheduleBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a pipeline schedule from a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct DeletePipelineSchedule<'a> {
     /// The project to delete a pipeline schedule on.
     #[builder(setter(into))]
@@ -27,13 +27,27 @@ impl<'a> DeletePipelineSchedule<'a> {
     }
 }
 
+impl<'a> DeletePipelineScheduleBuilder<'a> {
+    fn validate(&self) -> Result<(), DeletePipelineScheduleBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeletePipelineSchedule<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipeline_schedules/{}", self.project, self.id).into()
+        format!(
+            "projects/{}/pipeline_schedules/{}",
+            self.project.path_segment(),
+            self.id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -77,6 +91,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeletePipelineSchedule::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeletePipelineScheduleBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::pipeline_schedules::{PipelineScheduleCron, PipelineScheduleTimeZone};
 
 /// Edit a pipeline schedule on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditPipelineSchedule<'a> {
     /// The project to edit the pipeline schedule within.
     #[builder(setter(into))]
@@ -46,13 +46,27 @@ impl<'a> EditPipelineSchedule<'a> {
     }
 }
 
+impl<'a> EditPipelineScheduleBuilder<'a> {
+    fn validate(&self) -> Result<(), EditPipelineScheduleBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditPipelineSchedule<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipeline_schedules/{}", self.project, self.id).into()
+        format!(
+            "projects/{}/pipeline_schedules/{}",
+            self.project.path_segment(),
+            self.id.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -110,6 +124,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditPipelineSchedule::builder()
+            .project(0)
+            .id(10)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditPipelineScheduleBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
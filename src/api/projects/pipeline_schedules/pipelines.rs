@@ -6,18 +6,29 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
+use crate::api::projects::pipelines::{PipelineScope, PipelineStatus};
 
 /// Query for pipelines created by a schedule.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct PipelineSchedulePipelines<'a> {
     /// The project to query for pipeline schedules.
     #[builder(setter(into))]
     project: NameOrId<'a>,
     /// The pipeline schedule id.
     id: u64,
+
+    /// The scope of pipelines to return.
+    #[builder(default)]
+    scope: Option<PipelineScope>,
+    /// Filter pipelines by their status.
+    #[builder(default)]
+    status: Option<PipelineStatus>,
+    /// Sort order for resulting pipelines.
+    #[builder(default)]
+    sort: Option<SortOrder>,
 }
 
 impl<'a> PipelineSchedulePipelines<'a> {
@@ -27,6 +38,15 @@ impl<'a> PipelineSchedulePipelines<'a> {
     }
 }
 
+impl<'a> PipelineSchedulePipelinesBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineSchedulePipelinesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PipelineSchedulePipelines<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,19 +55,33 @@ impl<'a> Endpoint for PipelineSchedulePipelines<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipeline_schedules/{}/pipelines",
-            self.project, self.id
+            self.project.path_segment(),
+            self.id.path_segment()
         )
         .into()
     }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("scope", self.scope)
+            .push_opt("status", self.status)
+            .push_opt("sort", self.sort);
+
+        params
+    }
 }
 
 impl<'a> Pageable for PipelineSchedulePipelines<'a> {}
 
 #[cfg(test)]
 mod tests {
+    use crate::api::common::SortOrder;
     use crate::api::projects::pipeline_schedules::{
         PipelineSchedulePipelines, PipelineSchedulePipelinesBuilderError,
     };
+    use crate::api::projects::pipelines::{PipelineScope, PipelineStatus};
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
@@ -84,6 +118,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineSchedulePipelines::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            PipelineSchedulePipelinesBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -99,4 +147,58 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/10/pipelines")
+            .add_query_params(&[("scope", "finished")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineSchedulePipelines::builder()
+            .project("simple/project")
+            .id(10)
+            .scope(PipelineScope::Finished)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/10/pipelines")
+            .add_query_params(&[("status", "failed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineSchedulePipelines::builder()
+            .project("simple/project")
+            .id(10)
+            .status(PipelineStatus::Failed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipeline_schedules/10/pipelines")
+            .add_query_params(&[("sort", "desc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineSchedulePipelines::builder()
+            .project("simple/project")
+            .id(10)
+            .sort(SortOrder::Descending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
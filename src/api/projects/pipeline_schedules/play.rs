@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Play of a pipeline schedule on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct PlayPipelineSchedule<'a> {
     /// The project to take ownership of a pipeline schedule on.
     #[builder(setter(into))]
@@ -27,6 +27,15 @@ impl<'a> PlayPipelineSchedule<'a> {
     }
 }
 
+impl<'a> PlayPipelineScheduleBuilder<'a> {
+    fn validate(&self) -> Result<(), PlayPipelineScheduleBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PlayPipelineSchedule<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -35,7 +44,8 @@ impl<'a> Endpoint for PlayPipelineSchedule<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipeline_schedules/{}/play",
-            self.project, self.id
+            self.project.path_segment(),
+            self.id.path_segment()
         )
         .into()
     }
@@ -81,6 +91,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PlayPipelineSchedule::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PlayPipelineScheduleBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a pipeline schedule on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct PipelineSchedule<'a> {
     /// The project to query for pipeline schedules.
     #[builder(setter(into))]
@@ -27,13 +27,27 @@ impl<'a> PipelineSchedule<'a> {
     }
 }
 
+impl<'a> PipelineScheduleBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineScheduleBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PipelineSchedule<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipeline_schedules/{}", self.project, self.id).into()
+        format!(
+            "projects/{}/pipeline_schedules/{}",
+            self.project.path_segment(),
+            self.id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -72,6 +86,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineSchedule::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelineScheduleBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
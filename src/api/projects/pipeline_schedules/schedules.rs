@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -38,7 +38,7 @@ impl ParamValue<'static> for PipelineScheduleScope {
 
 /// Query for pipeline schedules on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct PipelineSchedules<'a> {
     /// The project to query for pipeline schedules.
     #[builder(setter(into))]
@@ -56,13 +56,25 @@ impl<'a> PipelineSchedules<'a> {
     }
 }
 
+impl<'a> PipelineSchedulesBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineSchedulesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PipelineSchedules<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipeline_schedules", self.project).into()
+        format!(
+            "projects/{}/pipeline_schedules",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -107,6 +119,12 @@ mod tests {
         PipelineSchedules::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineSchedules::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelineSchedulesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
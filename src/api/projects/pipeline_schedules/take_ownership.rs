@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Take ownership of a pipeline schedule on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct TakePipelineScheduleOwnership<'a> {
     /// The project to take ownership of a pipeline schedule on.
     #[builder(setter(into))]
@@ -27,6 +27,15 @@ impl<'a> TakePipelineScheduleOwnership<'a> {
     }
 }
 
+impl<'a> TakePipelineScheduleOwnershipBuilder<'a> {
+    fn validate(&self) -> Result<(), TakePipelineScheduleOwnershipBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for TakePipelineScheduleOwnership<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -35,7 +44,8 @@ impl<'a> Endpoint for TakePipelineScheduleOwnership<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipeline_schedules/{}/take_ownership",
-            self.project, self.id
+            self.project.path_segment(),
+            self.id.path_segment()
         )
         .into()
     }
@@ -94,6 +104,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = TakePipelineScheduleOwnership::builder()
+            .project(0)
+            .id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            TakePipelineScheduleOwnershipBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::variables::ProjectVariableType;
 
 /// Add a variable to a pipeline schedule.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreatePipelineScheduleVariable<'a> {
     /// The project to add the variable to.
     #[builder(setter(into))]
@@ -37,6 +37,15 @@ impl<'a> CreatePipelineScheduleVariable<'a> {
     }
 }
 
+impl<'a> CreatePipelineScheduleVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), CreatePipelineScheduleVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreatePipelineScheduleVariable<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -45,7 +54,8 @@ impl<'a> Endpoint for CreatePipelineScheduleVariable<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipeline_schedules/{}/variables",
-            self.project, self.id
+            self.project.path_segment(),
+            self.id.path_segment()
         )
         .into()
     }
@@ -148,6 +158,22 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreatePipelineScheduleVariable::builder()
+            .project(0)
+            .id(1)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreatePipelineScheduleVariableBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a variable from a pipeline schedule.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeletePipelineScheduleVariable<'a> {
     /// The project to add the variable to.
     #[builder(setter(into))]
@@ -29,6 +30,16 @@ impl<'a> DeletePipelineScheduleVariable<'a> {
     }
 }
 
+impl<'a> DeletePipelineScheduleVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), DeletePipelineScheduleVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeletePipelineScheduleVariable<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -37,7 +48,9 @@ impl<'a> Endpoint for DeletePipelineScheduleVariable<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipeline_schedules/{}/variables/{}",
-            self.project, self.id, self.key
+            self.project.path_segment(),
+            self.id.path_segment(),
+            self.key.path_segment()
         )
         .into()
     }
@@ -109,6 +122,21 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeletePipelineScheduleVariable::builder()
+            .project(0)
+            .id(1)
+            .key("testkey")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeletePipelineScheduleVariableBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::variables::ProjectVariableType;
 
 /// Edit a variable in a pipeline schedule.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditPipelineScheduleVariable<'a> {
     /// The project to edit the variable within.
     #[builder(setter(into))]
@@ -37,6 +37,16 @@ impl<'a> EditPipelineScheduleVariable<'a> {
     }
 }
 
+impl<'a> EditPipelineScheduleVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), EditPipelineScheduleVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("id", self.id.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditPipelineScheduleVariable<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -45,7 +55,9 @@ impl<'a> Endpoint for EditPipelineScheduleVariable<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipeline_schedules/{}/variables/{}",
-            self.project, self.id, self.key
+            self.project.path_segment(),
+            self.id.path_segment(),
+            self.key.path_segment()
         )
         .into()
     }
@@ -141,6 +153,22 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditPipelineScheduleVariable::builder()
+            .project(0)
+            .id(1)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            EditPipelineScheduleVariableBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
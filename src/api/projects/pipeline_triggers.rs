@@ -0,0 +1,41 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project pipeline trigger API endpoints.
+//!
+//! These endpoints are used for managing pipeline trigger tokens and triggering pipelines with
+//! them.
+
+mod create;
+mod delete;
+mod edit;
+mod trigger;
+mod trigger_pipeline;
+mod triggers;
+
+pub use self::create::CreatePipelineTrigger;
+pub use self::create::CreatePipelineTriggerBuilder;
+pub use self::create::CreatePipelineTriggerBuilderError;
+
+pub use self::delete::DeletePipelineTrigger;
+pub use self::delete::DeletePipelineTriggerBuilder;
+pub use self::delete::DeletePipelineTriggerBuilderError;
+
+pub use self::edit::EditPipelineTrigger;
+pub use self::edit::EditPipelineTriggerBuilder;
+pub use self::edit::EditPipelineTriggerBuilderError;
+
+pub use self::trigger::PipelineTrigger;
+pub use self::trigger::PipelineTriggerBuilder;
+pub use self::trigger::PipelineTriggerBuilderError;
+
+pub use self::trigger_pipeline::TriggerPipeline;
+pub use self::trigger_pipeline::TriggerPipelineBuilder;
+pub use self::trigger_pipeline::TriggerPipelineBuilderError;
+
+pub use self::triggers::PipelineTriggers;
+pub use self::triggers::PipelineTriggersBuilder;
+pub use self::triggers::PipelineTriggersBuilderError;
@@ -0,0 +1,129 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create a new pipeline trigger token on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreatePipelineTrigger<'a> {
+    /// The project to create the pipeline trigger token within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The description of the trigger token.
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+}
+
+impl<'a> CreatePipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreatePipelineTriggerBuilder<'a> {
+        CreatePipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> CreatePipelineTriggerBuilder<'a> {
+    fn validate(&self) -> Result<(), CreatePipelineTriggerBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreatePipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/triggers", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("description", &self.description);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_triggers::{
+        CreatePipelineTrigger, CreatePipelineTriggerBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_description_are_necessary() {
+        let err = CreatePipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreatePipelineTrigger::builder()
+            .description("desc")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn description_is_necessary() {
+        let err = CreatePipelineTrigger::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePipelineTriggerBuilderError, "description");
+    }
+
+    #[test]
+    fn project_and_description_are_sufficient() {
+        CreatePipelineTrigger::builder()
+            .project(1)
+            .description("desc")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreatePipelineTrigger::builder()
+            .project(0)
+            .description("desc")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreatePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/triggers")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=desc")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePipelineTrigger::builder()
+            .project("simple/project")
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a pipeline trigger token on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeletePipelineTrigger<'a> {
+    /// The project to delete the pipeline trigger token from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline trigger token.
+    trigger: u64,
+}
+
+impl<'a> DeletePipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeletePipelineTriggerBuilder<'a> {
+        DeletePipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> DeletePipelineTriggerBuilder<'a> {
+    fn validate(&self) -> Result<(), DeletePipelineTriggerBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("trigger", self.trigger.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeletePipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/triggers/{}",
+            self.project.path_segment(),
+            self.trigger.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_triggers::{
+        DeletePipelineTrigger, DeletePipelineTriggerBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_trigger_are_needed() {
+        let err = DeletePipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeletePipelineTrigger::builder()
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn trigger_is_needed() {
+        let err = DeletePipelineTrigger::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeletePipelineTriggerBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_and_trigger_are_sufficient() {
+        DeletePipelineTrigger::builder()
+            .project(1)
+            .trigger(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeletePipelineTrigger::builder()
+            .project(0)
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeletePipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/triggers/10")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeletePipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
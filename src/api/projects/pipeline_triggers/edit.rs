@@ -0,0 +1,156 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit a pipeline trigger token on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditPipelineTrigger<'a> {
+    /// The project to edit the pipeline trigger token within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline trigger token.
+    trigger: u64,
+
+    /// The description of the trigger token.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditPipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditPipelineTriggerBuilder<'a> {
+        EditPipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> EditPipelineTriggerBuilder<'a> {
+    fn validate(&self) -> Result<(), EditPipelineTriggerBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("trigger", self.trigger.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditPipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/triggers/{}",
+            self.project.path_segment(),
+            self.trigger.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("description", self.description.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_triggers::{
+        EditPipelineTrigger, EditPipelineTriggerBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_trigger_are_necessary() {
+        let err = EditPipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = EditPipelineTrigger::builder()
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn trigger_is_necessary() {
+        let err = EditPipelineTrigger::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditPipelineTriggerBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_and_trigger_are_sufficient() {
+        EditPipelineTrigger::builder()
+            .project(1)
+            .trigger(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditPipelineTrigger::builder()
+            .project(0)
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditPipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/triggers/10")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditPipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/triggers/10")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=desc")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditPipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(10)
+            .description("desc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a pipeline trigger token on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct PipelineTrigger<'a> {
+    /// The project to query for the pipeline trigger token.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the pipeline trigger token.
+    trigger: u64,
+}
+
+impl<'a> PipelineTrigger<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PipelineTriggerBuilder<'a> {
+        PipelineTriggerBuilder::default()
+    }
+}
+
+impl<'a> PipelineTriggerBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineTriggerBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("trigger", self.trigger.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for PipelineTrigger<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/triggers/{}",
+            self.project.path_segment(),
+            self.trigger.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::pipeline_triggers::{PipelineTrigger, PipelineTriggerBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_trigger_are_needed() {
+        let err = PipelineTrigger::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = PipelineTrigger::builder().trigger(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn trigger_is_needed() {
+        let err = PipelineTrigger::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, PipelineTriggerBuilderError, "trigger");
+    }
+
+    #[test]
+    fn project_and_trigger_are_sufficient() {
+        PipelineTrigger::builder()
+            .project(1)
+            .trigger(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineTrigger::builder()
+            .project(0)
+            .trigger(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelineTriggerBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/triggers/10")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PipelineTrigger::builder()
+            .project("simple/project")
+            .trigger(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
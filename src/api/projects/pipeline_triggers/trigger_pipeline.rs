@@ -0,0 +1,212 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Trigger a pipeline on a project using a trigger token.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct TriggerPipeline<'a> {
+    /// The project to trigger the pipeline within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The trigger token (or CI job token) to authenticate with.
+    #[builder(setter(into))]
+    token: Cow<'a, str>,
+    /// The ref to run the pipeline for.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+
+    /// Variables to pass to the pipeline.
+    #[builder(setter(name = "_variables"), default, private)]
+    variables: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> TriggerPipeline<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TriggerPipelineBuilder<'a> {
+        TriggerPipelineBuilder::default()
+    }
+}
+
+impl<'a> TriggerPipelineBuilder<'a> {
+    /// Add a variable for the pipeline.
+    pub fn variable<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.variables
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Add multiple variables for the pipeline.
+    pub fn variables<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.variables
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    fn validate(&self) -> Result<(), TriggerPipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for TriggerPipeline<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/trigger/pipeline", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("token", &self.token)
+            .push("ref", &self.ref_)
+            .extend(
+                self.variables
+                    .iter()
+                    .map(|(key, value)| (ParamName::new("variables").key(key.as_ref()), value)),
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::pipeline_triggers::{TriggerPipeline, TriggerPipelineBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_token_and_ref_are_necessary() {
+        let err = TriggerPipeline::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = TriggerPipeline::builder()
+            .token("token")
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn token_is_necessary() {
+        let err = TriggerPipeline::builder()
+            .project(1)
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "token");
+    }
+
+    #[test]
+    fn ref_is_necessary() {
+        let err = TriggerPipeline::builder()
+            .project(1)
+            .token("token")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TriggerPipelineBuilderError, "ref_");
+    }
+
+    #[test]
+    fn project_token_and_ref_are_sufficient() {
+        TriggerPipeline::builder()
+            .project(1)
+            .token("token")
+            .ref_("main")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = TriggerPipeline::builder()
+            .project(0)
+            .token("token")
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TriggerPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/trigger/pipeline")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("token=token", "&ref=main"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TriggerPipeline::builder()
+            .project("simple/project")
+            .token("token")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_variables() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/trigger/pipeline")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "token=token",
+                "&ref=main",
+                "&variables%5BDEPLOY%5D=true",
+                "&variables%5BTARGET%5D=staging",
+                "&variables%5BVERSION%5D=1.0",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TriggerPipeline::builder()
+            .project("simple/project")
+            .token("token")
+            .ref_("main")
+            .variable("TARGET", "staging")
+            .variable("DEPLOY", "true")
+            .variables([("VERSION", "1.0")].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,10 +8,12 @@
 //!
 //! These endpoints are used for querying CI pipelines.
 
+mod bridges;
 mod cancel;
 mod create;
 mod delete;
 mod jobs;
+mod latest;
 mod pipeline;
 mod pipelines;
 mod retry;
@@ -19,6 +21,10 @@ mod test_report;
 mod test_report_summary;
 mod variables;
 
+pub use self::bridges::PipelineBridges;
+pub use self::bridges::PipelineBridgesBuilder;
+pub use self::bridges::PipelineBridgesBuilderError;
+
 pub use self::cancel::CancelPipeline;
 pub use self::cancel::CancelPipelineBuilder;
 pub use self::cancel::CancelPipelineBuilderError;
@@ -39,6 +45,10 @@ pub use self::jobs::PipelineJobs;
 pub use self::jobs::PipelineJobsBuilder;
 pub use self::jobs::PipelineJobsBuilderError;
 
+pub use self::latest::LatestPipeline;
+pub use self::latest::LatestPipelineBuilder;
+pub use self::latest::LatestPipelineBuilderError;
+
 pub use self::pipeline::Pipeline;
 pub use self::pipeline::PipelineBuilder;
 pub use self::pipeline::PipelineBuilderError;
@@ -62,7 +72,15 @@ pub use self::variables::PipelineVariablesBuilderError;
 pub use self::test_report::PipelineTestReport;
 pub use self::test_report::PipelineTestReportBuilder;
 pub use self::test_report::PipelineTestReportBuilderError;
+pub use self::test_report::TestCase;
+pub use self::test_report::TestCaseFailures;
+pub use self::test_report::TestCaseStatus;
+pub use self::test_report::TestReport;
+pub use self::test_report::TestSuite;
 
 pub use self::test_report_summary::PipelineTestReportSummary;
 pub use self::test_report_summary::PipelineTestReportSummaryBuilder;
 pub use self::test_report_summary::PipelineTestReportSummaryBuilderError;
+pub use self::test_report_summary::TestReportSummary;
+pub use self::test_report_summary::TestReportTotals;
+pub use self::test_report_summary::TestSuiteSummary;
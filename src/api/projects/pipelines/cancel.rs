@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Cancel a pipeline.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CancelPipeline<'a> {
     /// The project to query for the pipeline.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> CancelPipeline<'a> {
     }
 }
 
+impl<'a> CancelPipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), CancelPipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CancelPipeline<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -34,7 +44,8 @@ impl<'a> Endpoint for CancelPipeline<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipelines/{}/cancel",
-            self.project, self.pipeline,
+            self.project.path_segment(),
+            self.pipeline.path_segment(),
         )
         .into()
     }
@@ -75,6 +86,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CancelPipeline::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CancelPipelineBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// The type of a pipeline variable.
@@ -65,6 +65,7 @@ impl<'a> PipelineVariable<'a> {
 
 /// Create a new pipeline on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreatePipeline<'a> {
     /// The project to create the pipeline within.
     #[builder(setter(into))]
@@ -104,6 +105,12 @@ impl<'a> CreatePipelineBuilder<'a> {
             .extend(iter.map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), CreatePipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreatePipeline<'a> {
@@ -112,7 +119,7 @@ impl<'a> Endpoint for CreatePipeline<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipeline", self.project).into()
+        format!("projects/{}/pipeline", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -123,10 +130,16 @@ impl<'a> Endpoint for CreatePipeline<'a> {
         self.variables.iter().for_each(|variable| {
             params.extend(
                 [
-                    ("variables[][key]", variable.key.as_ref()),
-                    ("variables[][value]", variable.value.as_ref()),
                     (
-                        "variables[][variable_type]",
+                        ParamName::new("variables").array().key("key"),
+                        variable.key.as_ref(),
+                    ),
+                    (
+                        ParamName::new("variables").array().key("value"),
+                        variable.value.as_ref(),
+                    ),
+                    (
+                        ParamName::new("variables").array().key("variable_type"),
                         variable.variable_type.as_str(),
                     ),
                 ]
@@ -230,6 +243,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreatePipeline::builder()
+            .project(0)
+            .ref_("testref")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreatePipelineBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a pipeline.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeletePipeline<'a> {
     /// The project to delete the pipeline from.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> DeletePipeline<'a> {
     }
 }
 
+impl<'a> DeletePipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), DeletePipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeletePipeline<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipelines/{}", self.project, self.pipeline).into()
+        format!(
+            "projects/{}/pipelines/{}",
+            self.project.path_segment(),
+            self.pipeline.path_segment()
+        )
+        .into()
     }
 }
 
@@ -71,6 +86,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeletePipeline::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeletePipelineBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
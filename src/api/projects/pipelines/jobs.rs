@@ -8,13 +8,13 @@ use std::collections::HashSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::jobs::JobScope;
 
 /// Query for jobs within a pipeline.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct PipelineJobs<'a> {
     /// The project to query for the pipeline.
     #[builder(setter(into))]
@@ -52,6 +52,13 @@ impl<'a> PipelineJobsBuilder<'a> {
         self.scopes.get_or_insert_with(HashSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), PipelineJobsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for PipelineJobs<'a> {
@@ -60,14 +67,23 @@ impl<'a> Endpoint for PipelineJobs<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipelines/{}/jobs", self.project, self.pipeline).into()
+        format!(
+            "projects/{}/pipelines/{}/jobs",
+            self.project.path_segment(),
+            self.pipeline.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
 
         params
-            .extend(self.scopes.iter().map(|&value| ("scope[]", value)))
+            .extend(
+                self.scopes
+                    .iter()
+                    .map(|&value| (ParamName::new("scope").array(), value)),
+            )
             .push_opt("include_retried", self.include_retried);
 
         params
@@ -110,6 +126,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineJobs::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelineJobsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,114 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query the latest pipeline on a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct LatestPipeline<'a> {
+    /// The project to query for the pipeline.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// The ref to query for the latest pipeline.
+    ///
+    /// Defaults to the default branch of the project.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+}
+
+impl<'a> LatestPipeline<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LatestPipelineBuilder<'a> {
+        LatestPipelineBuilder::default()
+    }
+}
+
+impl<'a> LatestPipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), LatestPipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for LatestPipeline<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/pipelines/latest", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("ref", self.ref_.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::pipelines::{LatestPipeline, LatestPipelineBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = LatestPipeline::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, LatestPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        LatestPipeline::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = LatestPipeline::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, LatestPipelineBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipelines/latest")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LatestPipeline::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipelines/latest")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LatestPipeline::builder()
+            .project("simple/project")
+            .ref_("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a single pipeline on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Pipeline<'a> {
     /// The project to query for pipeline.
     #[builder(setter(into))]
@@ -26,13 +27,27 @@ impl<'a> Pipeline<'a> {
     }
 }
 
+impl<'a> PipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Pipeline<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipelines/{}", self.project, self.pipeline).into()
+        format!(
+            "projects/{}/pipelines/{}",
+            self.project.path_segment(),
+            self.pipeline.path_segment()
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,16 @@ mod tests {
         Pipeline::builder().project(1).pipeline(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Pipeline::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelineBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
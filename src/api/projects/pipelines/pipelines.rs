@@ -7,7 +7,7 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -209,7 +209,7 @@ impl ParamValue<'static> for PipelineSource {
 
 /// Query for pipelines within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Pipelines<'a> {
     /// The project to query for pipelines.
     #[builder(setter(into))]
@@ -233,6 +233,9 @@ pub struct Pipelines<'a> {
     /// Filter pipelines by the username of the triggering user.
     #[builder(setter(into), default)]
     username: Option<Cow<'a, str>>,
+    /// Filter pipelines by their name.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
 
     /// Order results by a given key.
     #[builder(default)]
@@ -260,13 +263,10 @@ impl<'a> Pipelines<'a> {
 }
 
 impl<'a> PipelinesBuilder<'a> {
-    /// Filter pipelines by the name of the triggering user.
-    #[deprecated(note = "use `username` instead; `name` was never accepted by GitLab")]
-    pub fn name<N>(&mut self, _: N) -> &mut Self
-    where
-        N: Into<Cow<'a, str>>,
-    {
-        self
+    fn validate(&self) -> Result<(), PipelinesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
     }
 }
 
@@ -276,7 +276,7 @@ impl<'a> Endpoint for Pipelines<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/pipelines", self.project).into()
+        format!("projects/{}/pipelines", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -289,6 +289,7 @@ impl<'a> Endpoint for Pipelines<'a> {
             .push_opt("sha", self.sha.as_ref())
             .push_opt("yaml_errors", self.yaml_errors)
             .push_opt("username", self.username.as_ref())
+            .push_opt("name", self.name.as_ref())
             .push_opt("updated_after", self.updated_after)
             .push_opt("updated_before", self.updated_before)
             .push_opt("source", self.source)
@@ -414,6 +415,12 @@ mod tests {
         Pipelines::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Pipelines::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelinesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -531,6 +538,23 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/pipelines")
+            .add_query_params(&[("name", "nightly")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Pipelines::builder()
+            .project(1)
+            .name("nightly")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_updated_before() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,14 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Retry a pipeline.
 ///
 /// Note that this only restarts failed jobs.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct RetryPipeline<'a> {
     /// The project of the pipelines.
     #[builder(setter(into))]
@@ -28,6 +29,15 @@ impl<'a> RetryPipeline<'a> {
     }
 }
 
+impl<'a> RetryPipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), RetryPipelineBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RetryPipeline<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -36,7 +46,8 @@ impl<'a> Endpoint for RetryPipeline<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipelines/{}/retry",
-            self.project, self.pipeline,
+            self.project.path_segment(),
+            self.pipeline.path_segment(),
         )
         .into()
     }
@@ -77,6 +88,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RetryPipeline::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RetryPipelineBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
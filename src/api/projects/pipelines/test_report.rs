@@ -5,12 +5,14 @@
 // except according to those terms.
 
 use derive_builder::Builder;
+use serde::Deserialize;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for the test report of a pipeline.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PipelineTestReport<'a> {
     /// The project of the pipelines.
     #[builder(setter(into))]
@@ -26,6 +28,15 @@ impl<'a> PipelineTestReport<'a> {
     }
 }
 
+impl<'a> PipelineTestReportBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineTestReportBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PipelineTestReport<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,16 +45,119 @@ impl<'a> Endpoint for PipelineTestReport<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipelines/{}/test_report",
-            self.project, self.pipeline,
+            self.project.path_segment(),
+            self.pipeline.path_segment(),
         )
         .into()
     }
 }
 
+/// The status of a test case.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TestCaseStatus {
+    /// The test case passed.
+    Success,
+    /// The test case failed.
+    Failed,
+    /// The test case was skipped.
+    Skipped,
+    /// The test case errored.
+    Error,
+    /// A status unknown to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Recent failures of a test case on the target branch.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TestCaseFailures {
+    /// The number of recent failures.
+    pub count: u64,
+    /// The branch the failures occurred on.
+    pub base_branch: String,
+}
+
+/// A test case in a pipeline test report.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TestCase {
+    /// The status of the test case.
+    pub status: TestCaseStatus,
+    /// The name of the test case.
+    pub name: String,
+    /// The class name of the test case.
+    pub classname: Option<String>,
+    /// The file containing the test case.
+    pub file: Option<String>,
+    /// How long the test case took to execute (in seconds).
+    pub execution_time: f64,
+    /// The output of the test case.
+    pub system_output: Option<String>,
+    /// The stack trace of a failed test case.
+    pub stack_trace: Option<String>,
+    /// The URL of an attachment for the test case.
+    pub attachment_url: Option<String>,
+    /// Recent failures of the test case.
+    pub recent_failures: Option<TestCaseFailures>,
+}
+
+/// A test suite in a pipeline test report.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TestSuite {
+    /// The name of the test suite.
+    pub name: String,
+    /// How long the test suite took to execute (in seconds).
+    pub total_time: f64,
+    /// The number of test cases.
+    pub total_count: u64,
+    /// The number of successful test cases.
+    pub success_count: u64,
+    /// The number of failed test cases.
+    pub failed_count: u64,
+    /// The number of skipped test cases.
+    pub skipped_count: u64,
+    /// The number of errored test cases.
+    pub error_count: u64,
+    /// An error encountered while parsing the test suite.
+    pub suite_error: Option<String>,
+    /// The test cases in the suite.
+    #[serde(default)]
+    pub test_cases: Vec<TestCase>,
+}
+
+/// The test report of a pipeline.
+///
+/// This may be used as the result type of a `PipelineTestReport` query.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TestReport {
+    /// How long the tests took to execute (in seconds).
+    pub total_time: f64,
+    /// The number of test cases.
+    pub total_count: u64,
+    /// The number of successful test cases.
+    pub success_count: u64,
+    /// The number of failed test cases.
+    pub failed_count: u64,
+    /// The number of skipped test cases.
+    pub skipped_count: u64,
+    /// The number of errored test cases.
+    pub error_count: u64,
+    /// The test suites in the report.
+    #[serde(default)]
+    pub test_suites: Vec<TestSuite>,
+}
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::api::projects::pipelines::test_report::{
-        PipelineTestReport, PipelineTestReportBuilderError,
+        PipelineTestReport, PipelineTestReportBuilderError, TestCaseStatus, TestReport,
     };
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
@@ -81,6 +195,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineTestReport::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, PipelineTestReportBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -96,4 +220,93 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_report() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipelines/1/test_report")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "total_time": 5.5,
+                "total_count": 2,
+                "success_count": 1,
+                "failed_count": 1,
+                "skipped_count": 0,
+                "error_count": 0,
+                "test_suites": [
+                    {
+                        "name": "rspec",
+                        "total_time": 5.5,
+                        "total_count": 2,
+                        "success_count": 1,
+                        "failed_count": 1,
+                        "skipped_count": 0,
+                        "error_count": 0,
+                        "suite_error": null,
+                        "test_cases": [
+                            {
+                                "status": "success",
+                                "name": "passes",
+                                "classname": "spec.passes",
+                                "file": "spec/passes_spec.rb",
+                                "execution_time": 2.5,
+                                "system_output": null,
+                                "stack_trace": null,
+                                "attachment_url": null,
+                            },
+                            {
+                                "status": "failed",
+                                "name": "fails",
+                                "classname": "spec.fails",
+                                "file": null,
+                                "execution_time": 3,
+                                "system_output": "expected true",
+                                "stack_trace": null,
+                                "attachment_url": null,
+                                "recent_failures": {
+                                    "count": 3,
+                                    "base_branch": "main",
+                                },
+                            },
+                        ],
+                    },
+                ],
+            }),
+        );
+
+        let endpoint = PipelineTestReport::builder()
+            .project("simple/project")
+            .pipeline(1)
+            .build()
+            .unwrap();
+        let report: TestReport = endpoint.query(&client).unwrap();
+        assert_eq!(report.total_count, 2);
+        assert_eq!(report.failed_count, 1);
+        assert_eq!(report.test_suites.len(), 1);
+
+        let suite = &report.test_suites[0];
+        assert_eq!(suite.name, "rspec");
+        assert_eq!(suite.suite_error, None);
+        assert_eq!(suite.test_cases.len(), 2);
+        assert_eq!(suite.test_cases[0].status, TestCaseStatus::Success);
+        assert_eq!(suite.test_cases[0].recent_failures, None);
+        assert_eq!(suite.test_cases[1].status, TestCaseStatus::Failed);
+        assert_eq!(suite.test_cases[1].execution_time, 3.);
+        assert_eq!(
+            suite.test_cases[1].system_output.as_deref(),
+            Some("expected true"),
+        );
+        let failures = suite.test_cases[1].recent_failures.as_ref().unwrap();
+        assert_eq!(failures.count, 3);
+        assert_eq!(failures.base_branch, "main");
+    }
+
+    #[test]
+    fn test_case_status_unknown() {
+        let status: TestCaseStatus = serde_json::from_value(json!("flaky")).unwrap();
+        assert_eq!(status, TestCaseStatus::Unknown);
+    }
 }
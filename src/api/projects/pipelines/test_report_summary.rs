@@ -5,12 +5,14 @@
 // except according to those terms.
 
 use derive_builder::Builder;
+use serde::Deserialize;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
-/// Query for the test report of a pipeline.
+/// Query for the test report summary of a pipeline.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PipelineTestReportSummary<'a> {
     /// The project of the pipelines.
     #[builder(setter(into))]
@@ -26,6 +28,15 @@ impl<'a> PipelineTestReportSummary<'a> {
     }
 }
 
+impl<'a> PipelineTestReportSummaryBuilder<'a> {
+    fn validate(&self) -> Result<(), PipelineTestReportSummaryBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("pipeline", self.pipeline.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for PipelineTestReportSummary<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,16 +45,77 @@ impl<'a> Endpoint for PipelineTestReportSummary<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/pipelines/{}/test_report_summary",
-            self.project, self.pipeline,
+            self.project.path_segment(),
+            self.pipeline.path_segment(),
         )
         .into()
     }
 }
 
+/// Totals across all test suites of a pipeline.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TestReportTotals {
+    /// How long the tests took to execute (in seconds).
+    pub time: f64,
+    /// The number of test cases.
+    pub count: u64,
+    /// The number of successful test cases.
+    pub success: u64,
+    /// The number of failed test cases.
+    pub failed: u64,
+    /// The number of skipped test cases.
+    pub skipped: u64,
+    /// The number of errored test cases.
+    pub error: u64,
+    /// An error encountered while parsing the test suites.
+    pub suite_error: Option<String>,
+}
+
+/// A summary of a test suite in a pipeline.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TestSuiteSummary {
+    /// The name of the test suite.
+    pub name: String,
+    /// How long the test suite took to execute (in seconds).
+    pub total_time: f64,
+    /// The number of test cases.
+    pub total_count: u64,
+    /// The number of successful test cases.
+    pub success_count: u64,
+    /// The number of failed test cases.
+    pub failed_count: u64,
+    /// The number of skipped test cases.
+    pub skipped_count: u64,
+    /// The number of errored test cases.
+    pub error_count: u64,
+    /// The IDs of the jobs which reported the test suite.
+    #[serde(default)]
+    pub build_ids: Vec<u64>,
+    /// An error encountered while parsing the test suite.
+    pub suite_error: Option<String>,
+}
+
+/// The test report summary of a pipeline.
+///
+/// This may be used as the result type of a `PipelineTestReportSummary` query.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TestReportSummary {
+    /// Totals across all test suites.
+    pub total: TestReportTotals,
+    /// Summaries for each test suite.
+    #[serde(default)]
+    pub test_suites: Vec<TestSuiteSummary>,
+}
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use crate::api::projects::pipelines::{
-        PipelineTestReportSummary, PipelineTestReportSummaryBuilderError,
+        PipelineTestReportSummary, PipelineTestReportSummaryBuilderError, TestReportSummary,
     };
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
@@ -81,6 +153,20 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = PipelineTestReportSummary::builder()
+            .project(0)
+            .pipeline(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            PipelineTestReportSummaryBuilderError,
+            "project"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -96,4 +182,52 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_summary() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/pipelines/1/test_report_summary")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "total": {
+                    "time": 1904,
+                    "count": 3363,
+                    "success": 3351,
+                    "failed": 0,
+                    "skipped": 12,
+                    "error": 0,
+                    "suite_error": null,
+                },
+                "test_suites": [
+                    {
+                        "name": "test",
+                        "total_time": 1904,
+                        "total_count": 3363,
+                        "success_count": 3351,
+                        "failed_count": 0,
+                        "skipped_count": 12,
+                        "error_count": 0,
+                        "build_ids": [66004],
+                        "suite_error": null,
+                    },
+                ],
+            }),
+        );
+
+        let endpoint = PipelineTestReportSummary::builder()
+            .project("simple/project")
+            .pipeline(1)
+            .build()
+            .unwrap();
+        let summary: TestReportSummary = endpoint.query(&client).unwrap();
+        assert_eq!(summary.total.count, 3363);
+        assert_eq!(summary.total.skipped, 12);
+        assert_eq!(summary.total.time, 1904.);
+        assert_eq!(summary.test_suites.len(), 1);
+        assert_eq!(summary.test_suites[0].name, "test");
+        assert_eq!(summary.test_suites[0].build_ids, [66004]);
+    }
 }
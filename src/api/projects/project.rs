@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific project on an instance.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Project<'a> {
     /// The project to get.
     #[builder(setter(into))]
@@ -35,13 +35,21 @@ impl<'a> Project<'a> {
     }
 }
 
+impl<'a> ProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Project<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}", self.project).into()
+        format!("projects/{}", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -73,6 +81,12 @@ mod tests {
         Project::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Project::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
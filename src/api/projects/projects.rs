@@ -297,9 +297,9 @@ impl<'a> Endpoint for Projects<'a> {
             .push_opt("include_hidden", self.include_hidden)
             .push_opt("include_pending_delete", self.include_pending_delete)
             .extend(
-                self.custom_attributes
-                    .iter()
-                    .map(|(key, value)| (format!("custom_attributes[{}]", key), value)),
+                self.custom_attributes.iter().map(|(key, value)| {
+                    (ParamName::new("custom_attributes").key(key.as_ref()), value)
+                }),
             )
             .push_opt("with_custom_attributes", self.with_custom_attributes)
             .push_opt("imported", self.imported)
@@ -12,6 +12,7 @@ mod protect;
 mod protected_branch;
 mod protected_branches;
 mod unprotect;
+mod update;
 
 pub use self::protect::ProtectBranch;
 pub use self::protect::ProtectBranchBuilder;
@@ -23,6 +24,10 @@ pub use self::unprotect::UnprotectBranch;
 pub use self::unprotect::UnprotectBranchBuilder;
 pub use self::unprotect::UnprotectBranchBuilderError;
 
+pub use self::update::UpdateProtectedBranch;
+pub use self::update::UpdateProtectedBranchBuilder;
+pub use self::update::UpdateProtectedBranchBuilderError;
+
 pub use self::protected_branch::ProtectedBranch;
 pub use self::protected_branch::ProtectedBranchBuilder;
 pub use self::protected_branch::ProtectedBranchBuilderError;
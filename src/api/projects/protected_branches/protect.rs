@@ -9,7 +9,7 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, ProtectedAccessLevelWithAccess};
+use crate::api::common::{self, NameOrId, PathParam, ProtectedAccessLevelWithAccess};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -38,13 +38,13 @@ where
     {
         match self {
             ProtectedAccess::User(user) => {
-                params.push(format!("{}[][user_id]", name), user);
+                params.push(ParamName::new(name).array().key("user_id"), user);
             },
             ProtectedAccess::Group(group) => {
-                params.push(format!("{}[][group_id]", name), group);
+                params.push(ParamName::new(name).array().key("group_id"), group);
             },
             ProtectedAccess::Level(level) => {
-                params.push(format!("{}[][access_level]", name), level);
+                params.push(ParamName::new(name).array().key("access_level"), level);
             },
         }
     }
@@ -84,7 +84,7 @@ impl<T> From<T> for ProtectedAccess<T> {
 
 /// Protect a branch or set of branches on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProtectBranch<'a> {
     /// The project to protect a branch within.
     #[builder(setter(into))]
@@ -152,6 +152,12 @@ impl<'a> ProtectBranchBuilder<'a> {
             .insert(access);
         self
     }
+
+    fn validate(&self) -> Result<(), ProtectBranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for ProtectBranch<'a> {
@@ -160,7 +166,11 @@ impl<'a> Endpoint for ProtectBranch<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/protected_branches", self.project).into()
+        format!(
+            "projects/{}/protected_branches",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -281,6 +291,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProtectBranch::builder()
+            .project(0)
+            .name("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProtectBranchBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a protected branch of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProtectedBranch<'a> {
     /// The project to query for the protected branch.
     #[builder(setter(into))]
@@ -28,6 +29,15 @@ impl<'a> ProtectedBranch<'a> {
     }
 }
 
+impl<'a> ProtectedBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), ProtectedBranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("name", self.name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProtectedBranch<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -36,8 +46,8 @@ impl<'a> Endpoint for ProtectedBranch<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/protected_branches/{}",
-            self.project,
-            common::path_escaped(&self.name)
+            self.project.path_segment(),
+            self.name.path_segment()
         )
         .into()
     }
@@ -77,6 +87,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProtectedBranch::builder()
+            .project(0)
+            .name("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProtectedBranchBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
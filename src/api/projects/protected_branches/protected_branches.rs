@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query protected branches of a project.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProtectedBranches<'a> {
     /// The project to query for protected branches.
     #[builder(setter(into))]
@@ -31,13 +31,25 @@ impl<'a> ProtectedBranches<'a> {
     }
 }
 
+impl<'a> ProtectedBranchesBuilder<'a> {
+    fn validate(&self) -> Result<(), ProtectedBranchesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProtectedBranches<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/protected_branches", self.project).into()
+        format!(
+            "projects/{}/protected_branches",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -70,6 +82,12 @@ mod tests {
         ProtectedBranches::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProtectedBranches::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProtectedBranchesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
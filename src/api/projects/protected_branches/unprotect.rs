@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Unprotect a branch in a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnprotectBranch<'a> {
     /// The project to unprotect a branch within.
     #[builder(setter(into))]
@@ -27,6 +28,15 @@ impl<'a> UnprotectBranch<'a> {
     }
 }
 
+impl<'a> UnprotectBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), UnprotectBranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("name", self.name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UnprotectBranch<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -35,8 +45,8 @@ impl<'a> Endpoint for UnprotectBranch<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/protected_branches/{}",
-            self.project,
-            common::path_escaped(&self.name),
+            self.project.path_segment(),
+            self.name.path_segment(),
         )
         .into()
     }
@@ -80,6 +90,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UnprotectBranch::builder()
+            .project(0)
+            .name("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnprotectBranchBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
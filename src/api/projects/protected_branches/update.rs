@@ -0,0 +1,376 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::{
+    self, NameOrId, PathParam, ProtectedAccessLevel, ProtectedAccessLevelWithAccess,
+};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::protected_branches::ProtectedAccess;
+
+/// Update a protected branch or set of branches on a project.
+///
+/// Existing access rules are kept unless they are removed by their ID.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UpdateProtectedBranch<'a> {
+    /// The project to update a protected branch within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name or glob of the protected branch.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// Allow all users with push access to force push.
+    #[builder(default)]
+    allow_force_push: Option<bool>,
+    /// Whether code owner approval is required to merge.
+    #[builder(default)]
+    code_owner_approval_required: Option<bool>,
+    /// Accesses to add for pushing to the branch.
+    #[builder(setter(name = "_allowed_to_push"), default, private)]
+    allowed_to_push: BTreeSet<ProtectedAccess<ProtectedAccessLevel>>,
+    /// IDs of access rules to remove for pushing to the branch.
+    #[builder(setter(name = "_remove_allowed_to_push"), default, private)]
+    remove_allowed_to_push: BTreeSet<u64>,
+    /// Accesses to add for merging into the branch.
+    #[builder(setter(name = "_allowed_to_merge"), default, private)]
+    allowed_to_merge: BTreeSet<ProtectedAccess<ProtectedAccessLevel>>,
+    /// IDs of access rules to remove for merging into the branch.
+    #[builder(setter(name = "_remove_allowed_to_merge"), default, private)]
+    remove_allowed_to_merge: BTreeSet<u64>,
+    /// Accesses to add for unprotecting the branch.
+    #[builder(setter(name = "_allowed_to_unprotect"), default, private)]
+    allowed_to_unprotect: BTreeSet<ProtectedAccess<ProtectedAccessLevelWithAccess>>,
+    /// IDs of access rules to remove for unprotecting the branch.
+    #[builder(setter(name = "_remove_allowed_to_unprotect"), default, private)]
+    remove_allowed_to_unprotect: BTreeSet<u64>,
+}
+
+impl<'a> UpdateProtectedBranch<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateProtectedBranchBuilder<'a> {
+        UpdateProtectedBranchBuilder::default()
+    }
+}
+
+impl<'a> UpdateProtectedBranchBuilder<'a> {
+    /// Add access to push to the branch.
+    pub fn allowed_to_push(&mut self, access: ProtectedAccess<ProtectedAccessLevel>) -> &mut Self {
+        self.allowed_to_push
+            .get_or_insert_with(BTreeSet::new)
+            .insert(access);
+        self
+    }
+
+    /// Remove an existing access rule for pushing to the branch.
+    pub fn remove_allowed_to_push(&mut self, id: u64) -> &mut Self {
+        self.remove_allowed_to_push
+            .get_or_insert_with(BTreeSet::new)
+            .insert(id);
+        self
+    }
+
+    /// Add access to merge into the branch.
+    pub fn allowed_to_merge(&mut self, access: ProtectedAccess<ProtectedAccessLevel>) -> &mut Self {
+        self.allowed_to_merge
+            .get_or_insert_with(BTreeSet::new)
+            .insert(access);
+        self
+    }
+
+    /// Remove an existing access rule for merging into the branch.
+    pub fn remove_allowed_to_merge(&mut self, id: u64) -> &mut Self {
+        self.remove_allowed_to_merge
+            .get_or_insert_with(BTreeSet::new)
+            .insert(id);
+        self
+    }
+
+    /// Add access to unprotect the branch.
+    pub fn allowed_to_unprotect(
+        &mut self,
+        access: ProtectedAccess<ProtectedAccessLevelWithAccess>,
+    ) -> &mut Self {
+        self.allowed_to_unprotect
+            .get_or_insert_with(BTreeSet::new)
+            .insert(access);
+        self
+    }
+
+    /// Remove an existing access rule for unprotecting the branch.
+    pub fn remove_allowed_to_unprotect(&mut self, id: u64) -> &mut Self {
+        self.remove_allowed_to_unprotect
+            .get_or_insert_with(BTreeSet::new)
+            .insert(id);
+        self
+    }
+
+    fn validate(&self) -> Result<(), UpdateProtectedBranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("name", self.name.as_ref())?;
+
+        Ok(())
+    }
+}
+
+fn add_removals<'b>(name: &'b str, ids: &BTreeSet<u64>, params: &mut FormParams<'b>) {
+    for &id in ids {
+        params
+            .push(ParamName::new(name).array().key("id"), id)
+            .push(ParamName::new(name).array().key("_destroy"), true);
+    }
+}
+
+impl<'a> Endpoint for UpdateProtectedBranch<'a> {
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/protected_branches/{}",
+            self.project.path_segment(),
+            self.name.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("allow_force_push", self.allow_force_push)
+            .push_opt(
+                "code_owner_approval_required",
+                self.code_owner_approval_required,
+            );
+
+        self.allowed_to_push
+            .iter()
+            .for_each(|value| value.add_query("allowed_to_push", &mut params));
+        add_removals("allowed_to_push", &self.remove_allowed_to_push, &mut params);
+        self.allowed_to_merge
+            .iter()
+            .for_each(|value| value.add_query("allowed_to_merge", &mut params));
+        add_removals(
+            "allowed_to_merge",
+            &self.remove_allowed_to_merge,
+            &mut params,
+        );
+        self.allowed_to_unprotect
+            .iter()
+            .for_each(|value| value.add_query("allowed_to_unprotect", &mut params));
+        add_removals(
+            "allowed_to_unprotect",
+            &self.remove_allowed_to_unprotect,
+            &mut params,
+        );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::common::{ProtectedAccessLevel, ProtectedAccessLevelWithAccess};
+    use crate::api::projects::protected_branches::{
+        ProtectedAccess, UpdateProtectedBranch, UpdateProtectedBranchBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = UpdateProtectedBranch::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateProtectedBranchBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_required() {
+        let err = UpdateProtectedBranch::builder()
+            .name("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateProtectedBranchBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_required() {
+        let err = UpdateProtectedBranch::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateProtectedBranchBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        UpdateProtectedBranch::builder()
+            .project(1)
+            .name("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = UpdateProtectedBranch::builder()
+            .project(0)
+            .name("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UpdateProtectedBranchBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/protected_branches/release%2F*")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProtectedBranch::builder()
+            .project("simple/project")
+            .name("release/*")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_allow_force_push() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/protected_branches/master")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("allow_force_push=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProtectedBranch::builder()
+            .project("simple/project")
+            .name("master")
+            .allow_force_push(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_code_owner_approval_required() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/protected_branches/master")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("code_owner_approval_required=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProtectedBranch::builder()
+            .project("simple/project")
+            .name("master")
+            .code_owner_approval_required(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_allowed_to_push() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/protected_branches/master")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "allowed_to_push%5B%5D%5Buser_id%5D=1",
+                "&allowed_to_push%5B%5D%5Bgroup_id%5D=1",
+                "&allowed_to_push%5B%5D%5Baccess_level%5D=30",
+                "&allowed_to_push%5B%5D%5Bid%5D=5",
+                "&allowed_to_push%5B%5D%5B_destroy%5D=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProtectedBranch::builder()
+            .project("simple/project")
+            .name("master")
+            .allowed_to_push(ProtectedAccess::User(1))
+            .allowed_to_push(ProtectedAccess::Group(1))
+            .allowed_to_push(ProtectedAccess::Level(ProtectedAccessLevel::Developer))
+            .remove_allowed_to_push(5)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_allowed_to_merge() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/protected_branches/master")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "allowed_to_merge%5B%5D%5Bgroup_id%5D=1",
+                "&allowed_to_merge%5B%5D%5Bid%5D=3",
+                "&allowed_to_merge%5B%5D%5B_destroy%5D=true",
+                "&allowed_to_merge%5B%5D%5Bid%5D=4",
+                "&allowed_to_merge%5B%5D%5B_destroy%5D=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProtectedBranch::builder()
+            .project("simple/project")
+            .name("master")
+            .allowed_to_merge(ProtectedAccess::Group(1))
+            .remove_allowed_to_merge(4)
+            .remove_allowed_to_merge(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_allowed_to_unprotect() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("projects/simple%2Fproject/protected_branches/master")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "allowed_to_unprotect%5B%5D%5Baccess_level%5D=60",
+                "&allowed_to_unprotect%5B%5D%5Bid%5D=2",
+                "&allowed_to_unprotect%5B%5D%5B_destroy%5D=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateProtectedBranch::builder()
+            .project("simple/project")
+            .name("master")
+            .allowed_to_unprotect(ProtectedAccess::Level(
+                ProtectedAccessLevelWithAccess::Admin,
+            ))
+            .remove_allowed_to_unprotect(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -8,11 +8,17 @@
 //!
 //! These endpoints are used for querying a project's protected tags.
 
+mod ensure;
 mod protect;
 mod protected_tag;
 mod protected_tags;
 mod unprotect;
 
+pub use self::ensure::EnsureProtectedTag;
+pub use self::ensure::EnsureProtectedTagBuilder;
+pub use self::ensure::EnsureProtectedTagBuilderError;
+pub use self::ensure::TagProtectionChange;
+
 pub use self::protect::ProtectTag;
 pub use self::protect::ProtectTagBuilder;
 pub use self::protect::ProtectTagBuilderError;
@@ -0,0 +1,352 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::common::{NameOrId, ProtectedAccessLevel};
+use crate::api::projects::protected_tags::{ProtectTag, ProtectedTags, UnprotectTag};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// The change made to ensure a tag protection rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TagProtectionChange {
+    /// The rule already existed as requested.
+    Unchanged,
+    /// The rule did not exist and was created.
+    Created,
+    /// The rule existed with different access and was replaced.
+    Replaced,
+}
+
+/// The fields of a protected tag access level needed to compare rules.
+#[derive(Debug, Deserialize)]
+struct ExistingAccess {
+    access_level: u64,
+    #[serde(default)]
+    user_id: Option<u64>,
+    #[serde(default)]
+    group_id: Option<u64>,
+    #[serde(default)]
+    deploy_key_id: Option<u64>,
+}
+
+impl ExistingAccess {
+    fn is_level(&self, level: ProtectedAccessLevel) -> bool {
+        self.access_level == level.as_u64()
+            && self.user_id.is_none()
+            && self.group_id.is_none()
+            && self.deploy_key_id.is_none()
+    }
+}
+
+/// The fields of a protected tag needed to compare rules.
+#[derive(Debug, Deserialize)]
+struct ExistingRule {
+    name: String,
+    #[serde(default)]
+    create_access_levels: Vec<ExistingAccess>,
+}
+
+/// Ensure that a protected tag rule exists with a given access level.
+///
+/// This is intended to harden release tags: by default, a `v*` rule which only allows
+/// maintainers to create matching tags is ensured.
+///
+/// GitLab cannot edit protected tag rules, so a rule with the same name but any other access
+/// (including additional user, group, or deploy key access) is removed and protected again.
+/// Matching tags are briefly unprotected while this happens.
+#[derive(Debug, Builder, Clone)]
+pub struct EnsureProtectedTag<'a> {
+    /// The project to protect tags within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name or glob of the tags to protect.
+    #[builder(setter(into), default = "\"v*\".into()")]
+    name: Cow<'a, str>,
+    /// The minimum access level required to create matching tags.
+    #[builder(default)]
+    create_access_level: ProtectedAccessLevel,
+}
+
+impl<'a> EnsureProtectedTag<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> EnsureProtectedTagBuilder<'a> {
+        EnsureProtectedTagBuilder::default()
+    }
+
+    fn rules_query<E>(&self) -> Result<ProtectedTags<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        ProtectedTags::builder()
+            .project(self.project.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn unprotect<E>(&self) -> Result<UnprotectTag<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        UnprotectTag::builder()
+            .project(self.project.clone())
+            .name(self.name.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn protect<E>(&self) -> Result<ProtectTag<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        ProtectTag::builder()
+            .project(self.project.clone())
+            .name(self.name.clone())
+            .create_access_level(self.create_access_level)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn change(&self, rules: Vec<ExistingRule>) -> TagProtectionChange {
+        if let Some(rule) = rules.into_iter().find(|rule| rule.name == self.name) {
+            let matches = rule.create_access_levels.len() == 1
+                && rule.create_access_levels[0].is_level(self.create_access_level);
+
+            if matches {
+                TagProtectionChange::Unchanged
+            } else {
+                TagProtectionChange::Replaced
+            }
+        } else {
+            TagProtectionChange::Created
+        }
+    }
+}
+
+impl<'a, C> Query<TagProtectionChange, C> for EnsureProtectedTag<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<TagProtectionChange, ApiError<C::Error>> {
+        let rules = api::paged(self.rules_query()?, Pagination::All).query(client)?;
+        let change = self.change(rules);
+
+        if change == TagProtectionChange::Replaced {
+            api::ignore(self.unprotect()?).query(client)?;
+        }
+        if change != TagProtectionChange::Unchanged {
+            api::ignore(self.protect()?).query(client)?;
+        }
+
+        Ok(change)
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<TagProtectionChange, C> for EnsureProtectedTag<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<TagProtectionChange, ApiError<C::Error>> {
+        let rules = api::paged(self.rules_query()?, Pagination::All)
+            .query_async(client)
+            .await?;
+        let change = self.change(rules);
+
+        if change == TagProtectionChange::Replaced {
+            api::ignore(self.unprotect()?).query_async(client).await?;
+        }
+        if change != TagProtectionChange::Unchanged {
+            api::ignore(self.protect()?).query_async(client).await?;
+        }
+
+        Ok(change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::json;
+
+    use crate::api::common::ProtectedAccessLevel;
+    use crate::api::projects::protected_tags::{
+        EnsureProtectedTag, EnsureProtectedTagBuilderError, TagProtectionChange,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    #[test]
+    fn project_is_needed() {
+        let err = EnsureProtectedTag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EnsureProtectedTagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        EnsureProtectedTag::builder().project(1).build().unwrap();
+    }
+
+    fn rules_request() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("projects/simple%2Fproject/protected_tags")
+            .build()
+            .unwrap()
+    }
+
+    fn protect_request(body: &str) -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/protected_tags")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(body)
+            .build()
+            .unwrap()
+    }
+
+    fn unprotect_request() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/protected_tags/v*")
+            .build()
+            .unwrap()
+    }
+
+    fn rule(name: &str, access: serde_json::Value) -> serde_json::Value {
+        json!({
+            "name": name,
+            "create_access_levels": access,
+        })
+    }
+
+    fn endpoint() -> EnsureProtectedTag<'static> {
+        EnsureProtectedTag::builder()
+            .project("simple/project")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn endpoint_unchanged() {
+        let client = MockClient::new();
+        client.expect_paged(
+            rules_request(),
+            [
+                rule("release-*", json!([{"access_level": 30}])),
+                rule(
+                    "v*",
+                    json!([{"access_level": 40, "user_id": null, "group_id": null}]),
+                ),
+            ],
+        );
+
+        let change = endpoint().query(&client).unwrap();
+        assert_eq!(change, TagProtectionChange::Unchanged);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_created() {
+        let client = MockClient::new();
+        client
+            .expect_paged(
+                rules_request(),
+                [rule("release-*", json!([{"access_level": 40}]))],
+            )
+            .expect(
+                protect_request("name=v*&create_access_level=40"),
+                MockResponse::json(&rule("v*", json!([{"access_level": 40}]))),
+            );
+
+        let change = endpoint().query(&client).unwrap();
+        assert_eq!(change, TagProtectionChange::Created);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_replaced_access_level() {
+        let client = MockClient::new();
+        client
+            .expect_paged(rules_request(), [rule("v*", json!([{"access_level": 30}]))])
+            .expect(unprotect_request(), MockResponse::empty())
+            .expect(
+                protect_request("name=v*&create_access_level=40"),
+                MockResponse::json(&rule("v*", json!([{"access_level": 40}]))),
+            );
+
+        let change = endpoint().query(&client).unwrap();
+        assert_eq!(change, TagProtectionChange::Replaced);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_replaced_extra_access() {
+        let client = MockClient::new();
+        client
+            .expect_paged(
+                rules_request(),
+                [rule(
+                    "v*",
+                    json!([
+                        {"access_level": 40},
+                        {"access_level": 40, "user_id": 1},
+                    ]),
+                )],
+            )
+            .expect(unprotect_request(), MockResponse::empty())
+            .expect(
+                protect_request("name=v*&create_access_level=40"),
+                MockResponse::json(&rule("v*", json!([{"access_level": 40}]))),
+            );
+
+        let change = endpoint().query(&client).unwrap();
+        assert_eq!(change, TagProtectionChange::Replaced);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_name_and_access_level() {
+        let client = MockClient::new();
+        client
+            .expect_paged(rules_request(), Vec::<serde_json::Value>::new())
+            .expect(
+                protect_request("name=release-*&create_access_level=0"),
+                MockResponse::json(&rule("release-*", json!([{"access_level": 0}]))),
+            );
+
+        let endpoint = EnsureProtectedTag::builder()
+            .project("simple/project")
+            .name("release-*")
+            .create_access_level(ProtectedAccessLevel::NoAccess)
+            .build()
+            .unwrap();
+        let change = endpoint.query(&client).unwrap();
+        assert_eq!(change, TagProtectionChange::Created);
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn endpoint_async() {
+        let client = MockClient::new();
+        client
+            .expect_paged(rules_request(), [rule("v*", json!([{"access_level": 60}]))])
+            .expect(unprotect_request(), MockResponse::empty())
+            .expect(
+                protect_request("name=v*&create_access_level=40"),
+                MockResponse::json(&rule("v*", json!([{"access_level": 40}]))),
+            );
+
+        let change = endpoint().query_async(&client).await.unwrap();
+        assert_eq!(change, TagProtectionChange::Replaced);
+        client.assert_all_consumed();
+    }
+}
@@ -8,13 +8,13 @@ use std::collections::BTreeSet;
 
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, ProtectedAccessLevel};
+use crate::api::common::{self, NameOrId, PathParam, ProtectedAccessLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::protected_tags::ProtectedAccess;
 
 /// Protect a tag or set of tags on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProtectTag<'a> {
     /// The project to protect a tag within.
     #[builder(setter(into))]
@@ -48,6 +48,12 @@ impl<'a> ProtectTagBuilder<'a> {
             .insert(access);
         self
     }
+
+    fn validate(&self) -> Result<(), ProtectTagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for ProtectTag<'a> {
@@ -56,7 +62,7 @@ impl<'a> Endpoint for ProtectTag<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/protected_tags", self.project).into()
+        format!("projects/{}/protected_tags", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -112,6 +118,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProtectTag::builder()
+            .project(0)
+            .name("1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProtectTagBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a protected tag of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProtectedTag<'a> {
     /// The project to query for the protected tag.
     #[builder(setter(into))]
@@ -28,6 +29,15 @@ impl<'a> ProtectedTag<'a> {
     }
 }
 
+impl<'a> ProtectedTagBuilder<'a> {
+    fn validate(&self) -> Result<(), ProtectedTagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("name", self.name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProtectedTag<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -36,8 +46,8 @@ impl<'a> Endpoint for ProtectedTag<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/protected_tags/{}",
-            self.project,
-            common::path_escaped(self.name.as_ref()),
+            self.project.path_segment(),
+            self.name.path_segment(),
         )
         .into()
     }
@@ -77,6 +87,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProtectedTag::builder()
+            .project(0)
+            .name("1.0")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProtectedTagBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query protected tags of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProtectedTags<'a> {
     /// The project to query for protected tags.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> ProtectedTags<'a> {
     }
 }
 
+impl<'a> ProtectedTagsBuilder<'a> {
+    fn validate(&self) -> Result<(), ProtectedTagsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProtectedTags<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/protected_tags", self.project).into()
+        format!("projects/{}/protected_tags", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -57,6 +66,12 @@ mod tests {
         ProtectedTags::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProtectedTags::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProtectedTagsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
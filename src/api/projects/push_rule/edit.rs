@@ -6,14 +6,14 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Manage push rules for a project.
 ///
 /// see https://docs.gitlab.com/ee/api/projects.html#get-group-push-rules
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditProjectPushRule<'a> {
     /// The project to get.
     #[builder(setter(into))]
@@ -84,13 +84,21 @@ impl<'a> EditProjectPushRule<'a> {
     }
 }
 
+impl<'a> EditProjectPushRuleBuilder<'a> {
+    fn validate(&self) -> Result<(), EditProjectPushRuleBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for EditProjectPushRule<'a> {
     fn method(&self) -> Method {
         Method::PUT
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/push_rule", self.project).into()
+        format!("projects/{}/push_rule", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -141,6 +149,15 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditProjectPushRule::builder()
+            .project("")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditProjectPushRuleBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
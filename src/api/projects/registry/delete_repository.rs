@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a registry repository within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteRepository<'a> {
     /// The project to delete from.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> DeleteRepository<'a> {
     }
 }
 
+impl<'a> DeleteRepositoryBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteRepositoryBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("repository_id", self.repository_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteRepository<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -34,7 +44,8 @@ impl<'a> Endpoint for DeleteRepository<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/registry/repositories/{}",
-            self.project, self.repository_id,
+            self.project.path_segment(),
+            self.repository_id.path_segment(),
         )
         .into()
     }
@@ -78,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteRepository::builder()
+            .project(0)
+            .repository_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteRepositoryBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a tag from a registry repository within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteRepositoryTag<'a> {
     /// The project to delete from.
     #[builder(setter(into))]
@@ -29,6 +30,16 @@ impl<'a> DeleteRepositoryTag<'a> {
     }
 }
 
+impl<'a> DeleteRepositoryTagBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteRepositoryTagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("repository_id", self.repository_id.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteRepositoryTag<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -37,9 +48,9 @@ impl<'a> Endpoint for DeleteRepositoryTag<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/registry/repositories/{}/tags/{}",
-            self.project,
-            self.repository_id,
-            common::path_escaped(&self.tag_name),
+            self.project.path_segment(),
+            self.repository_id.path_segment(),
+            self.tag_name.path_segment(),
         )
         .into()
     }
@@ -99,6 +110,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteRepositoryTag::builder()
+            .project(0)
+            .repository_id(1)
+            .tag_name("latest")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteRepositoryTagBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
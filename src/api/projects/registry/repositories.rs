@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for registry repositories within a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Repositories<'a> {
     /// The project to query for repositories.
     #[builder(setter(into))]
@@ -34,13 +34,25 @@ impl<'a> Repositories<'a> {
     }
 }
 
+impl<'a> RepositoriesBuilder<'a> {
+    fn validate(&self) -> Result<(), RepositoriesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Repositories<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/registry/repositories", self.project).into()
+        format!(
+            "projects/{}/registry/repositories",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -76,6 +88,12 @@ mod tests {
         Repositories::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Repositories::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RepositoriesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
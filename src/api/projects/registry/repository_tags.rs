@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for tags of a registry repository within a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct RepositoryTags<'a> {
     /// The project to query.
     #[builder(setter(into))]
@@ -26,6 +27,15 @@ impl<'a> RepositoryTags<'a> {
     }
 }
 
+impl<'a> RepositoryTagsBuilder<'a> {
+    fn validate(&self) -> Result<(), RepositoryTagsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("repository_id", self.repository_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for RepositoryTags<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -34,7 +44,8 @@ impl<'a> Endpoint for RepositoryTags<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/registry/repositories/{}/tags",
-            self.project, self.repository_id,
+            self.project.path_segment(),
+            self.repository_id.path_segment(),
         )
         .into()
     }
@@ -78,6 +89,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = RepositoryTags::builder()
+            .project(0)
+            .repository_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RepositoryTagsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
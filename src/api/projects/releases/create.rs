@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
@@ -61,7 +61,7 @@ impl<'a> CreateReleaseAssetLinks<'a> {
 ///
 /// Developer level access to the project is required to create a release.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateRelease<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -175,6 +175,12 @@ impl<'a> CreateReleaseBuilder<'a> {
         self.assets.get_or_insert_with(Vec::new).extend(assets);
         self
     }
+
+    fn validate(&self) -> Result<(), CreateReleaseBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateRelease<'a> {
@@ -183,7 +189,7 @@ impl<'a> Endpoint for CreateRelease<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/releases", self.project).into()
+        format!("projects/{}/releases", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -195,6 +201,7 @@ impl<'a> Endpoint for CreateRelease<'a> {
 mod tests {
     use chrono::DateTime;
     use http::Method;
+    use serde_json::json;
 
     use crate::{
         api::{
@@ -262,13 +269,22 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateRelease::builder()
+            .project(0)
+            .tag_name("1.2.3")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateReleaseBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str("{\"tag_name\":\"1.2.3\"}")
+            .json_body(json!({"tag_name": "1.2.3"}))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -286,13 +302,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"name\":\"Test\",",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({"name": "Test", "tag_name": "1.2.3"}))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -311,13 +321,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"tag_message\":\"Test message\",",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({"tag_message": "Test message", "tag_name": "1.2.3"}))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -336,13 +340,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"description\":\"Test description\",",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({"description": "Test description", "tag_name": "1.2.3"}))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -361,13 +359,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"ref\":\"abfc1234\",",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({"ref": "abfc1234", "tag_name": "1.2.3"}))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -386,17 +378,10 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"milestones\":[",
-                "\"milestone_1\",",
-                "\"milestone_2\",",
-                "\"milestone_3\"",
-                "],",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({
+                "milestones": ["milestone_1", "milestone_2", "milestone_3"],
+                "tag_name": "1.2.3",
+            }))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -416,13 +401,7 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"released_at\":\"2023-12-16T12:00:00Z\",",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({"released_at": "2023-12-16T12:00:00Z", "tag_name": "1.2.3"}))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -445,30 +424,25 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"assets\":",
-                "{",
-                "\"links\":",
-                "[",
-                "{",
-                "\"name\":\"Test url 1\",",
-                "\"url\":\"https://test.com/test-1.zip\"",
-                "},",
-                "{",
-                "\"name\":\"Test url 2\",",
-                "\"url\":\"https://test.com/test-2.zip\"",
-                "},",
-                "{",
-                "\"name\":\"Test url 3\",",
-                "\"url\":\"https://test.com/test-3.zip\"",
-                "}",
-                "]",
-                "},",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({
+                "assets": {
+                    "links": [
+                        {
+                            "name": "Test url 1",
+                            "url": "https://test.com/test-1.zip",
+                        },
+                        {
+                            "name": "Test url 2",
+                            "url": "https://test.com/test-2.zip",
+                        },
+                        {
+                            "name": "Test url 3",
+                            "url": "https://test.com/test-3.zip",
+                        },
+                    ],
+                },
+                "tag_name": "1.2.3",
+            }))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -508,23 +482,18 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"assets\":",
-                "{",
-                "\"links\":",
-                "[",
-                "{",
-                "\"direct_asset_path\":\"bin/test.zip\",",
-                "\"name\":\"Test url\",",
-                "\"url\":\"https://test.com/test.zip\"",
-                "}",
-                "]",
-                "},",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({
+                "assets": {
+                    "links": [
+                        {
+                            "direct_asset_path": "bin/test.zip",
+                            "name": "Test url",
+                            "url": "https://test.com/test.zip",
+                        },
+                    ],
+                },
+                "tag_name": "1.2.3",
+            }))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -550,23 +519,18 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("projects/1337/releases")
-            .content_type("application/json")
-            .body_str(concat!(
-                "{",
-                "\"assets\":",
-                "{",
-                "\"links\":",
-                "[",
-                "{",
-                "\"link_type\":\"other\",",
-                "\"name\":\"Test url\",",
-                "\"url\":\"https://test.com/test.zip\"",
-                "}",
-                "]",
-                "},",
-                "\"tag_name\":\"1.2.3\"",
-                "}",
-            ))
+            .json_body(json!({
+                "assets": {
+                    "links": [
+                        {
+                            "link_type": "other",
+                            "name": "Test url",
+                            "url": "https://test.com/test.zip",
+                        },
+                    ],
+                },
+                "tag_name": "1.2.3",
+            }))
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
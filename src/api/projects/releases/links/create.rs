@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
@@ -12,7 +12,7 @@ use super::LinkType;
 
 /// Create a link to an asset for a release.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateReleaseLink<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -52,6 +52,15 @@ impl<'a> CreateReleaseLink<'a> {
     }
 }
 
+impl<'a> CreateReleaseLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateReleaseLinkBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateReleaseLink<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -60,8 +69,8 @@ impl<'a> Endpoint for CreateReleaseLink<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/releases/{}/assets/links",
-            self.project,
-            common::path_escaped(self.tag_name.as_ref()),
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
         )
         .into()
     }
@@ -153,6 +162,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateReleaseLink::builder()
+            .project(0)
+            .tag_name("1.2.3")
+            .name("test")
+            .url("test.com/file")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateReleaseLinkBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Delete an asset as a link from a release.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteReleaseLink<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -31,6 +32,16 @@ impl<'a> DeleteReleaseLink<'a> {
     }
 }
 
+impl<'a> DeleteReleaseLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteReleaseLinkBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+        common::validate_path_param("link_id", self.link_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteReleaseLink<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -39,9 +50,9 @@ impl<'a> Endpoint for DeleteReleaseLink<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/releases/{}/assets/links/{}",
-            self.project,
-            common::path_escaped(self.tag_name.as_ref()),
-            self.link_id,
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
+            self.link_id.path_segment(),
         )
         .into()
     }
@@ -101,6 +112,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteReleaseLink::builder()
+            .project(0)
+            .tag_name("1.2.3")
+            .link_id(123)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteReleaseLinkBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
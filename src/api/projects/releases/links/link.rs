@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Get an asset as a link from a release.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GetReleaseLink<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -31,6 +32,16 @@ impl<'a> GetReleaseLink<'a> {
     }
 }
 
+impl<'a> GetReleaseLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), GetReleaseLinkBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+        common::validate_path_param("link_id", self.link_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for GetReleaseLink<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -39,9 +50,9 @@ impl<'a> Endpoint for GetReleaseLink<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/releases/{}/assets/links/{}",
-            self.project,
-            common::path_escaped(self.tag_name.as_ref()),
-            self.link_id,
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
+            self.link_id.path_segment(),
         )
         .into()
     }
@@ -101,6 +112,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = GetReleaseLink::builder()
+            .project(0)
+            .tag_name("1.2.3")
+            .link_id(123)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GetReleaseLinkBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -4,12 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Get assets as links from a release.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ListReleaseLinks<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -27,6 +28,15 @@ impl<'a> ListReleaseLinks<'a> {
     }
 }
 
+impl<'a> ListReleaseLinksBuilder<'a> {
+    fn validate(&self) -> Result<(), ListReleaseLinksBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ListReleaseLinks<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,8 +45,8 @@ impl<'a> Endpoint for ListReleaseLinks<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/releases/{}/assets/links",
-            self.project,
-            common::path_escaped(self.tag_name.as_ref()),
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
         )
         .into()
     }
@@ -81,6 +91,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ListReleaseLinks::builder()
+            .project(0)
+            .tag_name("1.2.3")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ListReleaseLinksBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
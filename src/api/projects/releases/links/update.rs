@@ -5,13 +5,13 @@
 // except according to those terms.
 
 use super::LinkType;
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use derive_builder::Builder;
 
 /// Update an asset as a link from a release.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UpdateReleaseLink<'a> {
     /// The project to query for the packages.
     #[builder(setter(into))]
@@ -55,6 +55,16 @@ impl<'a> UpdateReleaseLink<'a> {
     }
 }
 
+impl<'a> UpdateReleaseLinkBuilder<'a> {
+    fn validate(&self) -> Result<(), UpdateReleaseLinkBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+        common::validate_path_param("link_id", self.link_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UpdateReleaseLink<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -63,9 +73,9 @@ impl<'a> Endpoint for UpdateReleaseLink<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/releases/{}/assets/links/{}",
-            self.project,
-            common::path_escaped(self.tag_name.as_ref()),
-            self.link_id,
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
+            self.link_id.path_segment(),
         )
         .into()
     }
@@ -141,6 +151,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UpdateReleaseLink::builder()
+            .project(0)
+            .link_id(123)
+            .tag_name("1.2.3")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UpdateReleaseLinkBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{NameOrId, SortOrder};
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -46,7 +46,7 @@ impl ParamValue<'static> for ProjectReleaseOrderBy {
 
 /// Query releases of a project.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectReleases<'a> {
     /// The project to query for releases.
     #[builder(setter(into))]
@@ -71,13 +71,21 @@ impl<'a> ProjectReleases<'a> {
     }
 }
 
+impl<'a> ProjectReleasesBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectReleasesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectReleases<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/releases", self.project).into()
+        format!("projects/{}/releases", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -134,6 +142,12 @@ mod tests {
         ProjectReleases::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectReleases::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectReleasesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
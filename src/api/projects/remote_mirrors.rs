@@ -0,0 +1,41 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project remote mirror API endpoints.
+//!
+//! These endpoints are used for querying and modifying the push mirrors of a project.
+
+mod create;
+mod delete;
+mod edit;
+mod mirror;
+mod mirrors;
+mod sync;
+
+pub use self::create::CreateRemoteMirror;
+pub use self::create::CreateRemoteMirrorBuilder;
+pub use self::create::CreateRemoteMirrorBuilderError;
+pub use self::create::RemoteMirrorAuthMethod;
+
+pub use self::delete::DeleteRemoteMirror;
+pub use self::delete::DeleteRemoteMirrorBuilder;
+pub use self::delete::DeleteRemoteMirrorBuilderError;
+
+pub use self::edit::EditRemoteMirror;
+pub use self::edit::EditRemoteMirrorBuilder;
+pub use self::edit::EditRemoteMirrorBuilderError;
+
+pub use self::mirror::RemoteMirror;
+pub use self::mirror::RemoteMirrorBuilder;
+pub use self::mirror::RemoteMirrorBuilderError;
+
+pub use self::mirrors::RemoteMirrors;
+pub use self::mirrors::RemoteMirrorsBuilder;
+pub use self::mirrors::RemoteMirrorsBuilderError;
+
+pub use self::sync::SyncRemoteMirror;
+pub use self::sync::SyncRemoteMirrorBuilder;
+pub use self::sync::SyncRemoteMirrorBuilderError;
@@ -0,0 +1,345 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+use url::Url;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Authentication methods for remote mirrors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RemoteMirrorAuthMethod {
+    /// Authenticate using credentials in the URL.
+    Password,
+    /// Authenticate using an SSH key generated by GitLab.
+    SshPublicKey,
+}
+
+impl RemoteMirrorAuthMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            RemoteMirrorAuthMethod::Password => "password",
+            RemoteMirrorAuthMethod::SshPublicKey => "ssh_public_key",
+        }
+    }
+}
+
+impl ParamValue<'static> for RemoteMirrorAuthMethod {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Create a remote mirror for a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateRemoteMirror<'a> {
+    /// The project to add the remote mirror to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The URL of the remote repository.
+    ///
+    /// Credentials for password authentication are part of the URL. See
+    /// [`CreateRemoteMirrorBuilder::url_with_credentials`] to add them to a URL.
+    #[builder(setter(into))]
+    url: Cow<'a, str>,
+
+    /// Whether the mirror is enabled.
+    #[builder(default)]
+    enabled: Option<bool>,
+    /// How to authenticate with the remote repository.
+    #[builder(default)]
+    auth_method: Option<RemoteMirrorAuthMethod>,
+    /// Whether to skip refs which have diverged on the remote instead of overwriting them.
+    #[builder(default)]
+    keep_divergent_refs: Option<bool>,
+    /// Whether to only mirror protected branches.
+    #[builder(default)]
+    only_protected_branches: Option<bool>,
+    /// Only mirror branches matching the regular expression.
+    #[builder(setter(into), default)]
+    mirror_branch_regex: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateRemoteMirror<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateRemoteMirrorBuilder<'a> {
+        CreateRemoteMirrorBuilder::default()
+    }
+}
+
+impl<'a> CreateRemoteMirrorBuilder<'a> {
+    /// Set the URL of the remote repository with credentials embedded into it.
+    ///
+    /// The username and password are percent-encoded as necessary. Note that GitLab masks the
+    /// credentials when returning the URL of the mirror.
+    pub fn url_with_credentials(&mut self, url: &Url, username: &str, password: &str) -> &mut Self {
+        let mut url = url.clone();
+        // These only fail for URLs which cannot contain credentials at all (e.g., `file:` URLs).
+        let _ = url.set_username(username);
+        let _ = url.set_password(Some(password));
+        self.url = Some(String::from(url).into());
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateRemoteMirrorBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateRemoteMirror<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/remote_mirrors", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("url", &self.url)
+            .push_opt("enabled", self.enabled)
+            .push_opt("auth_method", self.auth_method)
+            .push_opt("keep_divergent_refs", self.keep_divergent_refs)
+            .push_opt("only_protected_branches", self.only_protected_branches)
+            .push_opt("mirror_branch_regex", self.mirror_branch_regex.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use url::Url;
+
+    use crate::api::projects::remote_mirrors::{
+        CreateRemoteMirror, CreateRemoteMirrorBuilderError, RemoteMirrorAuthMethod,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn remote_mirror_auth_method_as_str() {
+        let items = &[
+            (RemoteMirrorAuthMethod::Password, "password"),
+            (RemoteMirrorAuthMethod::SshPublicKey, "ssh_public_key"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_and_url_are_needed() {
+        let err = CreateRemoteMirror::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateRemoteMirror::builder()
+            .url("https://example.com/mirror.git")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn url_is_needed() {
+        let err = CreateRemoteMirror::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateRemoteMirrorBuilderError, "url");
+    }
+
+    #[test]
+    fn project_and_url_are_sufficient() {
+        CreateRemoteMirror::builder()
+            .project(1)
+            .url("https://example.com/mirror.git")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateRemoteMirror::builder()
+            .project(0)
+            .url("https://example.com/mirror.git")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("url=https%3A%2F%2Fexample.com%2Fmirror.git")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url("https://example.com/mirror.git")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_url_with_credentials() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fuser%3Ap%2540ss%253Aword%40example.com%2Fmirror.git",
+                "&auth_method=password",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let url = Url::parse("https://example.com/mirror.git").unwrap();
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url_with_credentials(&url, "user", "p@ss:word")
+            .auth_method(RemoteMirrorAuthMethod::Password)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fexample.com%2Fmirror.git",
+                "&enabled=false"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url("https://example.com/mirror.git")
+            .enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_auth_method() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fexample.com%2Fmirror.git",
+                "&auth_method=ssh_public_key"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url("https://example.com/mirror.git")
+            .auth_method(RemoteMirrorAuthMethod::SshPublicKey)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_keep_divergent_refs() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fexample.com%2Fmirror.git",
+                "&keep_divergent_refs=true"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url("https://example.com/mirror.git")
+            .keep_divergent_refs(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_only_protected_branches() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fexample.com%2Fmirror.git",
+                "&only_protected_branches=true"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url("https://example.com/mirror.git")
+            .only_protected_branches(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_mirror_branch_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "url=https%3A%2F%2Fexample.com%2Fmirror.git",
+                "&mirror_branch_regex=%5Erelease%2F"
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateRemoteMirror::builder()
+            .project("simple/project")
+            .url("https://example.com/mirror.git")
+            .mirror_branch_regex("^release/")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::remote_mirrors::RemoteMirrorAuthMethod;
+
+/// Edit a remote mirror of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditRemoteMirror<'a> {
+    /// The project with the remote mirror.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the remote mirror.
+    mirror: u64,
+
+    /// Whether the mirror is enabled.
+    #[builder(default)]
+    enabled: Option<bool>,
+    /// How to authenticate with the remote repository.
+    #[builder(default)]
+    auth_method: Option<RemoteMirrorAuthMethod>,
+    /// Whether to skip refs which have diverged on the remote instead of overwriting them.
+    #[builder(default)]
+    keep_divergent_refs: Option<bool>,
+    /// Whether to only mirror protected branches.
+    #[builder(default)]
+    only_protected_branches: Option<bool>,
+    /// Only mirror branches matching the regular expression.
+    #[builder(setter(into), default)]
+    mirror_branch_regex: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditRemoteMirror<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditRemoteMirrorBuilder<'a> {
+        EditRemoteMirrorBuilder::default()
+    }
+}
+
+impl<'a> EditRemoteMirrorBuilder<'a> {
+    fn validate(&self) -> Result<(), EditRemoteMirrorBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("mirror", self.mirror.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditRemoteMirror<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/remote_mirrors/{}",
+            self.project.path_segment(),
+            self.mirror.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("enabled", self.enabled)
+            .push_opt("auth_method", self.auth_method)
+            .push_opt("keep_divergent_refs", self.keep_divergent_refs)
+            .push_opt("only_protected_branches", self.only_protected_branches)
+            .push_opt("mirror_branch_regex", self.mirror_branch_regex.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::remote_mirrors::{
+        EditRemoteMirror, EditRemoteMirrorBuilderError, RemoteMirrorAuthMethod,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_mirror_are_needed() {
+        let err = EditRemoteMirror::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditRemoteMirror::builder().mirror(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn mirror_is_needed() {
+        let err = EditRemoteMirror::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditRemoteMirrorBuilderError, "mirror");
+    }
+
+    #[test]
+    fn project_and_mirror_are_sufficient() {
+        EditRemoteMirror::builder()
+            .project(1)
+            .mirror(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditRemoteMirror::builder()
+            .project(0)
+            .mirror(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("enabled=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_auth_method() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("auth_method=ssh_public_key")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .auth_method(RemoteMirrorAuthMethod::SshPublicKey)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_keep_divergent_refs() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("keep_divergent_refs=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .keep_divergent_refs(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_only_protected_branches() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("only_protected_branches=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .only_protected_branches(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_mirror_branch_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("mirror_branch_regex=%5Erelease%2F")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .mirror_branch_regex("^release/")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a remote mirror of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RemoteMirror<'a> {
+    /// The project of the remote mirror.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the remote mirror.
+    mirror: u64,
+}
+
+impl<'a> RemoteMirror<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoteMirrorBuilder<'a> {
+        RemoteMirrorBuilder::default()
+    }
+}
+
+impl<'a> RemoteMirrorBuilder<'a> {
+    fn validate(&self) -> Result<(), RemoteMirrorBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("mirror", self.mirror.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for RemoteMirror<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/remote_mirrors/{}",
+            self.project.path_segment(),
+            self.mirror.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::remote_mirrors::{RemoteMirror, RemoteMirrorBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_mirror_are_needed() {
+        let err = RemoteMirror::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RemoteMirror::builder().mirror(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn mirror_is_needed() {
+        let err = RemoteMirror::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoteMirrorBuilderError, "mirror");
+    }
+
+    #[test]
+    fn project_and_mirror_are_sufficient() {
+        RemoteMirror::builder()
+            .project(1)
+            .mirror(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = RemoteMirror::builder()
+            .project(0)
+            .mirror(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the remote mirrors of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RemoteMirrors<'a> {
+    /// The project to query for remote mirrors.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> RemoteMirrors<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoteMirrorsBuilder<'a> {
+        RemoteMirrorsBuilder::default()
+    }
+}
+
+impl<'a> RemoteMirrorsBuilder<'a> {
+    fn validate(&self) -> Result<(), RemoteMirrorsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for RemoteMirrors<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/remote_mirrors", self.project.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for RemoteMirrors<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::remote_mirrors::{RemoteMirrors, RemoteMirrorsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = RemoteMirrors::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoteMirrorsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        RemoteMirrors::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = RemoteMirrors::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, RemoteMirrorsBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/remote_mirrors")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoteMirrors::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Trigger a push to a remote mirror of a project.
+///
+/// The push is performed in the background. Its result is reported in the mirror's
+/// `last_update_at` and `last_error` fields.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SyncRemoteMirror<'a> {
+    /// The project with the remote mirror.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the remote mirror.
+    mirror: u64,
+}
+
+impl<'a> SyncRemoteMirror<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SyncRemoteMirrorBuilder<'a> {
+        SyncRemoteMirrorBuilder::default()
+    }
+}
+
+impl<'a> SyncRemoteMirrorBuilder<'a> {
+    fn validate(&self) -> Result<(), SyncRemoteMirrorBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("mirror", self.mirror.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for SyncRemoteMirror<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/remote_mirrors/{}/sync",
+            self.project.path_segment(),
+            self.mirror.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::remote_mirrors::{SyncRemoteMirror, SyncRemoteMirrorBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_mirror_are_needed() {
+        let err = SyncRemoteMirror::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SyncRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = SyncRemoteMirror::builder().mirror(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, SyncRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn mirror_is_needed() {
+        let err = SyncRemoteMirror::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, SyncRemoteMirrorBuilderError, "mirror");
+    }
+
+    #[test]
+    fn project_and_mirror_are_sufficient() {
+        SyncRemoteMirror::builder()
+            .project(1)
+            .mirror(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = SyncRemoteMirror::builder()
+            .project(0)
+            .mirror(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SyncRemoteMirrorBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/remote_mirrors/1/sync")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SyncRemoteMirror::builder()
+            .project("simple/project")
+            .mirror(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
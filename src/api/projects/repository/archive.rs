@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Vaild archive formats for getting the archive of a repository.
@@ -36,7 +36,7 @@ pub enum ArchiveFormat {
 /// Note: This endpoint returns raw data, so [`crate::api::raw`] is recommended to avoid the normal
 /// JSON parsing present in the typical endpoint handling.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Archive<'a> {
     /// The ID or URL-encoded path of the project.
     #[builder(setter(into))]
@@ -59,6 +59,14 @@ impl<'a> Archive<'a> {
     }
 }
 
+impl<'a> ArchiveBuilder<'a> {
+    fn validate(&self) -> Result<(), ArchiveBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Archive<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -68,7 +76,8 @@ impl<'a> Endpoint for Archive<'a> {
         let archive_format = self.format.map_or("", |f| f.as_str());
         format!(
             "projects/{}/repository/archive{}",
-            self.project, archive_format,
+            self.project.path_segment(),
+            archive_format,
         )
         .into()
     }
@@ -139,6 +148,12 @@ mod tests {
         Archive::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Archive::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ArchiveBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,13 @@
 
 //! Project repository branches API endpoints.
 //!
-//! These endpoints are used for querying a project's branches.
+//! These endpoints are used for querying and managing a project's branches.
 
 mod branch;
 mod branches;
 mod create;
+mod delete;
+mod delete_merged;
 
 pub use self::branch::Branch;
 pub use self::branch::BranchBuilder;
@@ -23,3 +25,11 @@ pub use self::branches::BranchesBuilderError;
 pub use self::create::CreateBranch;
 pub use self::create::CreateBranchBuilder;
 pub use self::create::CreateBranchBuilderError;
+
+pub use self::delete::DeleteBranch;
+pub use self::delete::DeleteBranchBuilder;
+pub use self::delete::DeleteBranchBuilderError;
+
+pub use self::delete_merged::DeleteMergedBranches;
+pub use self::delete_merged::DeleteMergedBranchesBuilder;
+pub use self::delete_merged::DeleteMergedBranchesBuilderError;
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Branch<'a> {
     /// The project to get a branch from.
     #[builder(setter(into))]
@@ -27,6 +28,15 @@ impl<'a> Branch<'a> {
     }
 }
 
+impl<'a> BranchBuilder<'a> {
+    fn validate(&self) -> Result<(), BranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("branch", self.branch.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Branch<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,8 +45,8 @@ impl<'a> Endpoint for Branch<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/branches/{}",
-            self.project,
-            common::path_escaped(&self.branch),
+            self.project.path_segment(),
+            self.branch.path_segment(),
         )
         .into()
     }
@@ -75,6 +85,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Branch::builder()
+            .project(0)
+            .branch("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BranchBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Branches<'a> {
     /// The project to get a branch from.
     #[builder(setter(into))]
@@ -36,13 +36,25 @@ impl<'a> Branches<'a> {
     }
 }
 
+impl<'a> BranchesBuilder<'a> {
+    fn validate(&self) -> Result<(), BranchesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Branches<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/branches", self.project).into()
+        format!(
+            "projects/{}/repository/branches",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -75,6 +87,12 @@ mod tests {
         Branches::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Branches::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BranchesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a branch on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateBranch<'a> {
     /// The project to create a branch on.
     #[builder(setter(into))]
@@ -30,13 +31,25 @@ impl<'a> CreateBranch<'a> {
     }
 }
 
+impl<'a> CreateBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateBranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateBranch<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/branches", self.project).into()
+        format!(
+            "projects/{}/repository/branches",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -67,6 +80,12 @@ mod tests {
         CreateBranch::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateBranch::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateBranchBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,119 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a branch from a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteBranch<'a> {
+    /// The project to delete a branch from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The branch to delete.
+    #[builder(setter(into))]
+    branch: Cow<'a, str>,
+}
+
+impl<'a> DeleteBranch<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteBranchBuilder<'a> {
+        DeleteBranchBuilder::default()
+    }
+}
+
+impl<'a> DeleteBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteBranchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("branch", self.branch.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteBranch<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/branches/{}",
+            self.project.path_segment(),
+            self.branch.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::repository::branches::{DeleteBranch, DeleteBranchBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_branch_are_necessary() {
+        let err = DeleteBranch::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBranchBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteBranch::builder()
+            .branch("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBranchBuilderError, "project");
+    }
+
+    #[test]
+    fn branch_is_necessary() {
+        let err = DeleteBranch::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteBranchBuilderError, "branch");
+    }
+
+    #[test]
+    fn project_and_branch_are_sufficient() {
+        DeleteBranch::builder()
+            .project(1)
+            .branch("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteBranch::builder()
+            .project(0)
+            .branch("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteBranchBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/repository/branches/special%2Fbranch")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteBranch::builder()
+            .project("simple/project")
+            .branch("special/branch")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete all branches which have been merged into the default branch of a project.
+///
+/// Protected branches are not deleted. The deletion happens asynchronously on the server.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteMergedBranches<'a> {
+    /// The project to delete merged branches from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> DeleteMergedBranches<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteMergedBranchesBuilder<'a> {
+        DeleteMergedBranchesBuilder::default()
+    }
+}
+
+impl<'a> DeleteMergedBranchesBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteMergedBranchesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteMergedBranches<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/merged_branches",
+            self.project.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::repository::branches::{
+        DeleteMergedBranches, DeleteMergedBranchesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteMergedBranches::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteMergedBranchesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        DeleteMergedBranches::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteMergedBranches::builder()
+            .project(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteMergedBranchesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/repository/merged_branches")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteMergedBranches::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
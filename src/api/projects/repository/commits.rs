@@ -8,17 +8,25 @@
 //!
 //! These endpoints are used for querying a project's commits.
 
+mod cherry_pick;
 mod comment;
 mod comments;
 mod commit;
 mod commits;
 mod create;
 mod create_status;
+mod diff;
+mod diff_stats;
 mod merge_requests;
 mod refs;
+mod revert;
 mod signature;
 mod statuses;
 
+pub use self::cherry_pick::CherryPickCommit;
+pub use self::cherry_pick::CherryPickCommitBuilder;
+pub use self::cherry_pick::CherryPickCommitBuilderError;
+
 pub use self::comment::CommentOnCommit;
 pub use self::comment::CommentOnCommitBuilder;
 pub use self::comment::CommentOnCommitBuilderError;
@@ -50,11 +58,24 @@ pub use self::create_status::CreateCommitStatus;
 pub use self::create_status::CreateCommitStatusBuilder;
 pub use self::create_status::CreateCommitStatusBuilderError;
 
+pub use self::diff::CommitDiff;
+pub use self::diff::CommitDiffBuilder;
+pub use self::diff::CommitDiffBuilderError;
+
+pub use self::diff_stats::CommitRangeDiffStats;
+pub use self::diff_stats::CommitRangeDiffStatsBuilder;
+pub use self::diff_stats::CommitRangeDiffStatsBuilderError;
+pub use self::diff_stats::DiffStats;
+
 pub use self::refs::CommitReferences;
 pub use self::refs::CommitReferencesBuilder;
 pub use self::refs::CommitReferencesBuilderError;
 pub use self::refs::CommitRefsType;
 
+pub use self::revert::RevertCommit;
+pub use self::revert::RevertCommitBuilder;
+pub use self::revert::RevertCommitBuilderError;
+
 pub use self::statuses::CommitStatuses;
 pub use self::statuses::CommitStatusesBuilder;
 pub use self::statuses::CommitStatusesBuilderError;
@@ -0,0 +1,204 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Cherry-pick a commit onto a branch.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CherryPickCommit<'a> {
+    /// The project to cherry-pick the commit within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The commit to cherry-pick.
+    #[builder(setter(into))]
+    commit: Cow<'a, str>,
+    /// The branch to commit the result to.
+    #[builder(setter(into))]
+    branch: Cow<'a, str>,
+
+    /// Check whether the commit can be applied without actually creating a commit.
+    #[builder(default)]
+    dry_run: Option<bool>,
+    /// A custom commit message for the new commit.
+    #[builder(setter(into), default)]
+    message: Option<Cow<'a, str>>,
+}
+
+impl<'a> CherryPickCommit<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CherryPickCommitBuilder<'a> {
+        CherryPickCommitBuilder::default()
+    }
+}
+
+impl<'a> CherryPickCommitBuilder<'a> {
+    fn validate(&self) -> Result<(), CherryPickCommitBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CherryPickCommit<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/commits/{}/cherry_pick",
+            self.project.path_segment(),
+            self.commit.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("branch", &self.branch)
+            .push_opt("dry_run", self.dry_run)
+            .push_opt("message", self.message.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::repository::commits::{
+        CherryPickCommit, CherryPickCommitBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_commit_and_branch_are_necessary() {
+        let err = CherryPickCommit::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CherryPickCommitBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CherryPickCommit::builder()
+            .commit("master")
+            .branch("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CherryPickCommitBuilderError, "project");
+    }
+
+    #[test]
+    fn commit_is_necessary() {
+        let err = CherryPickCommit::builder()
+            .project(1)
+            .branch("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CherryPickCommitBuilderError, "commit");
+    }
+
+    #[test]
+    fn branch_is_necessary() {
+        let err = CherryPickCommit::builder()
+            .project(1)
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CherryPickCommitBuilderError, "branch");
+    }
+
+    #[test]
+    fn project_commit_and_branch_are_sufficient() {
+        CherryPickCommit::builder()
+            .project(1)
+            .commit("master")
+            .branch("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CherryPickCommit::builder()
+            .project(0)
+            .commit("master")
+            .branch("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CherryPickCommitBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository/commits/nested%2Ftag/cherry_pick")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("branch=master")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CherryPickCommit::builder()
+            .project("simple/project")
+            .commit("nested/tag")
+            .branch("master")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/cherry_pick")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("branch=master", "&dry_run=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CherryPickCommit::builder()
+            .project("simple/project")
+            .commit("0000000000000000000000000000000000000000")
+            .branch("master")
+            .dry_run(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_message() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/cherry_pick")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("branch=master", "&message=custom+message"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CherryPickCommit::builder()
+            .project("simple/project")
+            .commit("0000000000000000000000000000000000000000")
+            .branch("master")
+            .message("custom message")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -37,7 +37,7 @@ impl ParamValue<'static> for LineType {
 
 /// Post a comment on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CommentOnCommit<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -67,6 +67,15 @@ impl<'a> CommentOnCommit<'a> {
     }
 }
 
+impl<'a> CommentOnCommitBuilder<'a> {
+    fn validate(&self) -> Result<(), CommentOnCommitBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CommentOnCommit<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -75,8 +84,8 @@ impl<'a> Endpoint for CommentOnCommit<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}/comments",
-            self.project,
-            common::path_escaped(&self.commit),
+            self.project.path_segment(),
+            self.commit.path_segment(),
         )
         .into()
     }
@@ -159,6 +168,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CommentOnCommit::builder()
+            .project(0)
+            .commit("master")
+            .note("note")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommentOnCommitBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for comments on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CommitComments<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -27,6 +28,15 @@ impl<'a> CommitComments<'a> {
     }
 }
 
+impl<'a> CommitCommentsBuilder<'a> {
+    fn validate(&self) -> Result<(), CommitCommentsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CommitComments<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,8 +45,8 @@ impl<'a> Endpoint for CommitComments<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}/comments",
-            self.project,
-            common::path_escaped(&self.commit),
+            self.project.path_segment(),
+            self.commit.path_segment(),
         )
         .into()
     }
@@ -80,6 +90,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CommitComments::builder()
+            .project(0)
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommitCommentsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder().endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/comments").build().unwrap();
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Commit<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -32,6 +32,15 @@ impl<'a> Commit<'a> {
     }
 }
 
+impl<'a> CommitBuilder<'a> {
+    fn validate(&self) -> Result<(), CommitBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Commit<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -40,8 +49,8 @@ impl<'a> Endpoint for Commit<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}",
-            self.project,
-            common::path_escaped(&self.commit),
+            self.project.path_segment(),
+            self.commit.path_segment(),
         )
         .into()
     }
@@ -88,6 +97,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Commit::builder()
+            .project(0)
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommitBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder().endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000").build().unwrap();
@@ -7,7 +7,7 @@
 use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -46,7 +46,7 @@ impl ParamValue<'static> for CommitsOrder {
 
 /// Query for commits in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Commits<'a> {
     /// The project to get commits from.
     #[builder(setter(into))]
@@ -93,13 +93,25 @@ impl<'a> Commits<'a> {
     }
 }
 
+impl<'a> CommitsBuilder<'a> {
+    fn validate(&self) -> Result<(), CommitsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Commits<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/commits", self.project).into()
+        format!(
+            "projects/{}/repository/commits",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -159,6 +171,12 @@ mod tests {
         Commits::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Commits::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommitsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -4,12 +4,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::io::{self, Write};
 use std::str;
 
 use derive_builder::Builder;
 use log::warn;
 
-use crate::api::common::NameOrId;
+use crate::api::body::FormWriter;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::repository::files::Encoding;
 use crate::api::ParamValue;
@@ -116,35 +118,45 @@ impl<'a> CommitAction<'a> {
         CommitActionBuilder::default()
     }
 
-    fn add_query<'b>(&'b self, params: &mut FormParams<'b>) {
-        params
-            .push("actions[][action]", self.action.as_value())
-            .push("actions[][file_path]", self.file_path.as_value())
-            .push_opt("actions[][previous_path]", self.previous_path.as_ref())
-            .push_opt(
-                "actions[][content]",
-                self.content.as_ref().map(|content| {
-                    let str_content = str::from_utf8(content);
-                    let needs_encoding = str_content.is_err();
-                    let encoding = self.encoding.unwrap_or_default();
-                    let actual_encoding = if needs_encoding && !encoding.is_binary_safe() {
-                        warn!(
-                            "forcing the encoding to {} due to utf-8 unsafe content",
-                            SAFE_ENCODING.as_str(),
-                        );
-                        SAFE_ENCODING
-                    } else {
-                        encoding
-                    };
-                    actual_encoding.encode(str_content.ok(), content)
-                }),
-            )
-            .push_opt("actions[][encoding]", self.encoding)
-            .push_opt("actions[][last_commit_id]", self.last_commit_id.as_ref())
-            .push_opt("actions[][execute_filemode]", self.execute_filemode);
+    fn write_form<W>(&self, form: &mut FormWriter<W>) -> io::Result<()>
+    where
+        W: Write,
+    {
+        form.push(action_param("action"), self.action)?
+            .push(action_param("file_path"), self.file_path.as_ref())?
+            .push_opt(action_param("previous_path"), self.previous_path.as_ref())?;
+
+        if let Some(content) = self.content.as_ref() {
+            // Encode the content directly into the body to avoid making copies of it.
+            form.push_with(action_param("content"), |writer| {
+                let str_content = str::from_utf8(content);
+                let needs_encoding = str_content.is_err();
+                let encoding = self.encoding.unwrap_or_default();
+                let actual_encoding = if needs_encoding && !encoding.is_binary_safe() {
+                    warn!(
+                        "forcing the encoding to {} due to utf-8 unsafe content",
+                        SAFE_ENCODING.as_str(),
+                    );
+                    SAFE_ENCODING
+                } else {
+                    encoding
+                };
+                actual_encoding.encode_into(str_content.ok(), content, writer)
+            })?;
+        }
+
+        form.push_opt(action_param("encoding"), self.encoding)?
+            .push_opt(action_param("last_commit_id"), self.last_commit_id.as_ref())?
+            .push_opt(action_param("execute_filemode"), self.execute_filemode)?;
+
+        Ok(())
     }
 }
 
+fn action_param(field: &str) -> ParamName {
+    ParamName::new("actions").array().key(field)
+}
+
 static CONTENT_REQUIRED_CREATE: &str = "content is required for create.";
 static CONTENT_REQUIRED_UPDATE: &str = "content is required for update.";
 
@@ -229,11 +241,35 @@ impl<'a> CreateCommit<'a> {
     pub fn builder() -> CreateCommitBuilder<'a> {
         CreateCommitBuilder::default()
     }
+
+    fn write_body<W>(&self, writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut form = FormWriter::new(writer);
+
+        form.push("branch", self.branch.as_ref())?
+            .push("commit_message", self.commit_message.as_ref())?
+            .push_opt("start_branch", self.start_branch.as_ref())?
+            .push_opt("start_sha", self.start_sha.as_ref())?
+            .push_opt("start_project", self.start_project.as_ref())?
+            .push_opt("author_email", self.author_email.as_ref())?
+            .push_opt("author_name", self.author_name.as_ref())?
+            .push_opt("stats", self.stats)?
+            .push_opt("force", self.force)?;
+
+        for action in self.actions.iter() {
+            action.write_form(&mut form)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[non_exhaustive]
 enum CreateCommitValidationError {
     AtMostOneStartItem,
+    InvalidPathParam(String),
 }
 
 static AT_MOST_ONE_START_ITEM: &str = "Specify either start_sha or start_branch, not both";
@@ -244,6 +280,9 @@ impl From<CreateCommitValidationError> for CreateCommitBuilderError {
             CreateCommitValidationError::AtMostOneStartItem => {
                 CreateCommitBuilderError::ValidationError(AT_MOST_ONE_START_ITEM.into())
             },
+            CreateCommitValidationError::InvalidPathParam(msg) => {
+                CreateCommitBuilderError::ValidationError(msg)
+            },
         }
     }
 }
@@ -265,6 +304,9 @@ impl<'a> CreateCommitBuilder<'a> {
     }
 
     fn validate(&self) -> Result<(), CreateCommitValidationError> {
+        common::validate_path_param("project", self.project.as_ref())
+            .map_err(CreateCommitValidationError::InvalidPathParam)?;
+
         let have_start_branch = self
             .start_branch
             .as_ref()
@@ -289,28 +331,23 @@ impl<'a> Endpoint for CreateCommit<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/commits", self.project).into()
+        format!(
+            "projects/{}/repository/commits",
+            self.project.path_segment()
+        )
+        .into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
-        let mut params = FormParams::default();
-
-        params
-            .push("branch", self.branch.as_ref())
-            .push("commit_message", self.commit_message.as_ref())
-            .push_opt("start_branch", self.start_branch.as_ref())
-            .push_opt("start_sha", self.start_sha.as_ref())
-            .push_opt("start_project", self.start_project.as_ref())
-            .push_opt("author_email", self.author_email.as_ref())
-            .push_opt("author_name", self.author_name.as_ref())
-            .push_opt("stats", self.stats)
-            .push_opt("force", self.force);
-
-        for action in self.actions.iter() {
-            action.add_query(&mut params);
-        }
+        let mut body = Vec::new();
+        self.write_body(&mut body)?;
+        Ok(Some(("application/x-www-form-urlencoded", body)))
+    }
 
-        params.into_body()
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        let mut body = SpooledBody::new();
+        self.write_body(&mut body)?;
+        Ok(Some(("application/x-www-form-urlencoded", body)))
     }
 }
 
@@ -447,6 +484,23 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateCommit::builder()
+            .project("")
+            .branch("source")
+            .commit_message("msg")
+            .actions([CommitAction::builder()
+                .action(CommitActionType::Create)
+                .file_path("foo/bar")
+                .content(&b"content"[..])
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateCommitBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -774,4 +828,73 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_binary_content() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/repository/commits")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "branch=master",
+                "&commit_message=message",
+                "&actions%5B%5D%5Baction%5D=create",
+                "&actions%5B%5D%5Bfile_path%5D=foo%2Fbar",
+                "&actions%5B%5D%5Bcontent%5D=%2B%2F%2B%2F%2Fw%3D%3D",
+                "&actions%5B%5D%5Bencoding%5D=base64",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCommit::builder()
+            .project("simple/project")
+            .branch("master")
+            .commit_message("message")
+            .action(
+                CommitAction::builder()
+                    .action(CommitActionType::Create)
+                    .file_path("foo/bar")
+                    .content(&b"\xfb\xff\xbf\xff"[..])
+                    .encoding(Encoding::Base64)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn spooled_body_matches_body() {
+        let content = vec![0xfe; 4096];
+        let endpoint = CreateCommit::builder()
+            .project("simple/project")
+            .branch("master")
+            .commit_message("message")
+            .action(
+                CommitAction::builder()
+                    .action(CommitActionType::Create)
+                    .file_path("foo/bar")
+                    .content(&content[..])
+                    .build()
+                    .unwrap(),
+            )
+            .action(
+                CommitAction::builder()
+                    .action(CommitActionType::Update)
+                    .file_path("foo/baz")
+                    .content(&b"text content"[..])
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let (mime, body) = endpoint.body().unwrap().unwrap();
+        let (spooled_mime, spooled) = endpoint.spooled_body().unwrap().unwrap();
+        assert_eq!(mime, spooled_mime);
+        assert_eq!(body.len() as u64, spooled.len());
+        assert_eq!(body, spooled.into_vec().unwrap());
+    }
 }
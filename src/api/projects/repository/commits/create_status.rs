@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -46,7 +46,7 @@ impl ParamValue<'static> for CommitStatusState {
 
 /// Post a comment on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateCommitStatus<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -85,6 +85,15 @@ impl<'a> CreateCommitStatus<'a> {
     }
 }
 
+impl<'a> CreateCommitStatusBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateCommitStatusBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateCommitStatus<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -96,8 +105,8 @@ impl<'a> Endpoint for CreateCommitStatus<'a> {
             // exists in this namespace.
             // https://gitlab.com/gitlab-org/gitlab/-/issues/217412
             "projects/{}/statuses/{}",
-            self.project,
-            common::path_escaped(&self.commit),
+            self.project.path_segment(),
+            self.commit.path_segment(),
         )
         .into()
     }
@@ -189,6 +198,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateCommitStatus::builder()
+            .project(0)
+            .commit("master")
+            .state(CommitStatusState::Pending)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateCommitStatusBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
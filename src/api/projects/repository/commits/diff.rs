@@ -0,0 +1,147 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Get the diff of a commit in a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CommitDiff<'a> {
+    /// The project to get a commit from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The commit to get the diff of.
+    #[builder(setter(into))]
+    commit: Cow<'a, str>,
+
+    /// Return diffs as unified diffs.
+    #[builder(default)]
+    unidiff: Option<bool>,
+}
+
+impl<'a> CommitDiff<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CommitDiffBuilder<'a> {
+        CommitDiffBuilder::default()
+    }
+}
+
+impl<'a> CommitDiffBuilder<'a> {
+    fn validate(&self) -> Result<(), CommitDiffBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CommitDiff<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/commits/{}/diff",
+            self.project.path_segment(),
+            self.commit.path_segment(),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("unidiff", self.unidiff);
+
+        params
+    }
+}
+
+impl<'a> Pageable for CommitDiff<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository::commits::{CommitDiff, CommitDiffBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_commit_are_needed() {
+        let err = CommitDiff::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CommitDiffBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CommitDiff::builder().commit("master").build().unwrap_err();
+        crate::test::assert_missing_field!(err, CommitDiffBuilderError, "project");
+    }
+
+    #[test]
+    fn commit_is_needed() {
+        let err = CommitDiff::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CommitDiffBuilderError, "commit");
+    }
+
+    #[test]
+    fn project_and_commit_are_sufficient() {
+        CommitDiff::builder()
+            .project(1)
+            .commit("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CommitDiff::builder()
+            .project(0)
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommitDiffBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits/0000000000000000000000000000000000000000/diff")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CommitDiff::builder()
+            .project("simple/project")
+            .commit("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_unidiff() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/commits/master/diff")
+            .add_query_params(&[("unidiff", "true")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CommitDiff::builder()
+            .project("simple/project")
+            .commit("master")
+            .unidiff(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
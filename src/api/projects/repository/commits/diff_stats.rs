@@ -0,0 +1,382 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde::Deserialize;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::repository::commits::{CommitDiff, Commits};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// Line counts for a set of changed files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiffStats {
+    /// The number of changed files.
+    pub files: u64,
+    /// The number of added lines.
+    pub additions: u64,
+    /// The number of deleted lines.
+    pub deletions: u64,
+}
+
+impl DiffStats {
+    fn add_diff(&mut self, diff: &str) {
+        self.files += 1;
+        for line in diff.lines() {
+            if line.starts_with('+') {
+                self.additions += 1;
+            } else if line.starts_with('-') {
+                self.deletions += 1;
+            }
+        }
+    }
+}
+
+/// The fields of a commit needed to gather its diff.
+#[derive(Debug, Deserialize)]
+struct CommitInfo {
+    id: String,
+    #[serde(default)]
+    parent_ids: Vec<String>,
+}
+
+/// The fields of a diff entry needed to count its changes.
+#[derive(Debug, Deserialize)]
+struct DiffEntry {
+    old_path: String,
+    new_path: String,
+    #[serde(default)]
+    deleted_file: bool,
+    #[serde(default)]
+    diff: String,
+}
+
+impl DiffEntry {
+    fn file_type(&self) -> &str {
+        let path = if self.deleted_file {
+            &self.old_path
+        } else {
+            &self.new_path
+        };
+        let name = path.rsplit('/').next().unwrap_or(path);
+
+        match name.rfind('.') {
+            // Dotfiles such as `.gitignore` do not have an extension.
+            Some(idx) if idx > 0 => &name[idx + 1..],
+            _ => "",
+        }
+    }
+}
+
+/// Aggregate added and deleted lines per file type across a range of commits.
+///
+/// Commits in the range are listed and the diff of each is fetched. Changes are grouped by the
+/// extension of the changed file (without the leading `.`). Files without an extension are
+/// collected under the empty string. A file changed by multiple commits is counted once per
+/// commit.
+///
+/// Diffs which GitLab considers too large to return have no content and only count towards the
+/// number of files.
+#[derive(Debug, Builder, Clone)]
+pub struct CommitRangeDiffStats<'a> {
+    /// The project to gather statistics from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The range of commits to consider (e.g., `v1.0..v1.1`).
+    ///
+    /// Any value accepted by the `ref_name` parameter of the commits query may be used.
+    #[builder(setter(into))]
+    range: Cow<'a, str>,
+    /// Whether to skip merge commits.
+    ///
+    /// The diff of a merge commit is against its first parent and would count changes which are
+    /// also part of the merged commits. Defaults to `true`.
+    #[builder(default = "true")]
+    skip_merges: bool,
+}
+
+impl<'a> CommitRangeDiffStats<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> CommitRangeDiffStatsBuilder<'a> {
+        CommitRangeDiffStatsBuilder::default()
+    }
+
+    fn commits<E>(&self) -> Result<Commits<'a>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Commits::builder()
+            .project(self.project.clone())
+            .ref_name(self.range.clone())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn diff<'b, E>(&self, commit: &'b str) -> Result<CommitDiff<'b>, ApiError<E>>
+    where
+        'a: 'b,
+        E: Error + Send + Sync + 'static,
+    {
+        CommitDiff::builder()
+            .project(self.project.clone())
+            .commit(commit)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn should_count(&self, commit: &CommitInfo) -> bool {
+        !self.skip_merges || commit.parent_ids.len() < 2
+    }
+}
+
+fn aggregate(stats: &mut BTreeMap<String, DiffStats>, entries: Vec<DiffEntry>) {
+    for entry in entries {
+        stats
+            .entry(entry.file_type().into())
+            .or_default()
+            .add_diff(&entry.diff);
+    }
+}
+
+impl<'a, C> Query<BTreeMap<String, DiffStats>, C> for CommitRangeDiffStats<'a>
+where
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<BTreeMap<String, DiffStats>, ApiError<C::Error>> {
+        let commits: Vec<CommitInfo> =
+            api::paged(self.commits()?, Pagination::All).query(client)?;
+
+        let mut stats = BTreeMap::new();
+        for commit in commits.iter().filter(|commit| self.should_count(commit)) {
+            let entries = api::paged(self.diff(&commit.id)?, Pagination::All).query(client)?;
+            aggregate(&mut stats, entries);
+        }
+
+        Ok(stats)
+    }
+}
+
+#[async_trait]
+impl<'a, C> AsyncQuery<BTreeMap<String, DiffStats>, C> for CommitRangeDiffStats<'a>
+where
+    C: AsyncClient + Sync,
+{
+    async fn query_async(
+        &self,
+        client: &C,
+    ) -> Result<BTreeMap<String, DiffStats>, ApiError<C::Error>> {
+        let commits: Vec<CommitInfo> = api::paged(self.commits()?, Pagination::All)
+            .query_async(client)
+            .await?;
+
+        let mut stats = BTreeMap::new();
+        for commit in commits.iter().filter(|commit| self.should_count(commit)) {
+            let entries = api::paged(self.diff(&commit.id)?, Pagination::All)
+                .query_async(client)
+                .await?;
+            aggregate(&mut stats, entries);
+        }
+
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::json;
+
+    use crate::api::projects::repository::commits::{
+        CommitRangeDiffStats, CommitRangeDiffStatsBuilderError, DiffStats,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::testing::{ExpectedRequest, MockClient};
+
+    #[test]
+    fn project_and_range_are_needed() {
+        let err = CommitRangeDiffStats::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CommitRangeDiffStatsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CommitRangeDiffStats::builder()
+            .range("v1..v2")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CommitRangeDiffStatsBuilderError, "project");
+    }
+
+    #[test]
+    fn range_is_needed() {
+        let err = CommitRangeDiffStats::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CommitRangeDiffStatsBuilderError, "range");
+    }
+
+    #[test]
+    fn project_and_range_are_sufficient() {
+        CommitRangeDiffStats::builder()
+            .project(1)
+            .range("v1..v2")
+            .build()
+            .unwrap();
+    }
+
+    fn diff(path: &str, diff: &str) -> serde_json::Value {
+        json!({
+            "old_path": path,
+            "new_path": path,
+            "new_file": false,
+            "renamed_file": false,
+            "deleted_file": false,
+            "diff": diff,
+        })
+    }
+
+    fn mock_client(skip_merges: bool) -> MockClient {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/simple%2Fproject/repository/commits")
+                .query_param("ref_name", "v1..v2")
+                .build()
+                .unwrap(),
+            [
+                json!({"id": "merge", "parent_ids": ["first", "second"]}),
+                json!({"id": "first", "parent_ids": ["base"]}),
+                json!({"id": "second", "parent_ids": ["base"]}),
+            ],
+        );
+        if !skip_merges {
+            client.expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("projects/simple%2Fproject/repository/commits/merge/diff")
+                    .build()
+                    .unwrap(),
+                [diff("src/lib.rs", "@@ -1 +1 @@\n-old\n+new\n")],
+            );
+        }
+        client
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("projects/simple%2Fproject/repository/commits/first/diff")
+                    .build()
+                    .unwrap(),
+                [
+                    diff("src/lib.rs", "@@ -1 +1,2 @@\n-old\n+new\n+line\n"),
+                    diff(
+                        "README.md",
+                        "@@ -0,0 +1 @@\n+docs\n\\ No newline at end of file\n",
+                    ),
+                    json!({
+                        "old_path": "src/old.rs",
+                        "new_path": "src/old.rs",
+                        "deleted_file": true,
+                        "diff": "@@ -1,2 +0,0 @@\n-a\n-b\n",
+                    }),
+                ],
+            )
+            .expect_paged(
+                ExpectedRequest::builder()
+                    .endpoint("projects/simple%2Fproject/repository/commits/second/diff")
+                    .build()
+                    .unwrap(),
+                [
+                    diff("Makefile", "@@ -1 +1 @@\n-all:\n+all: build\n"),
+                    diff(".gitignore", "@@ -1 +1,2 @@\n /target\n+*.swp\n"),
+                    diff("src/big.rs", ""),
+                ],
+            );
+        client
+    }
+
+    fn expected(merge: bool) -> BTreeMap<String, DiffStats> {
+        let rs = if merge {
+            DiffStats {
+                files: 4,
+                additions: 3,
+                deletions: 4,
+            }
+        } else {
+            DiffStats {
+                files: 3,
+                additions: 2,
+                deletions: 3,
+            }
+        };
+
+        [
+            (
+                String::new(),
+                DiffStats {
+                    files: 2,
+                    additions: 2,
+                    deletions: 1,
+                },
+            ),
+            (
+                "md".into(),
+                DiffStats {
+                    files: 1,
+                    additions: 1,
+                    deletions: 0,
+                },
+            ),
+            ("rs".into(), rs),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = mock_client(true);
+        let endpoint = CommitRangeDiffStats::builder()
+            .project("simple/project")
+            .range("v1..v2")
+            .build()
+            .unwrap();
+        let stats = endpoint.query(&client).unwrap();
+        assert_eq!(stats, expected(false));
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn endpoint_merges() {
+        let client = mock_client(false);
+        let endpoint = CommitRangeDiffStats::builder()
+            .project("simple/project")
+            .range("v1..v2")
+            .skip_merges(false)
+            .build()
+            .unwrap();
+        let stats = endpoint.query(&client).unwrap();
+        assert_eq!(stats, expected(true));
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn endpoint_async() {
+        let client = mock_client(true);
+        let endpoint = CommitRangeDiffStats::builder()
+            .project("simple/project")
+            .range("v1..v2")
+            .build()
+            .unwrap();
+        let stats = endpoint.query_async(&client).await.unwrap();
+        assert_eq!(stats, expected(false));
+        client.assert_all_consumed();
+    }
+}
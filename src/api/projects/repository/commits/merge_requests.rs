@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get a list of merge requests related to the specified commit.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequests<'a> {
     /// The project to get commits from.
     #[builder(setter(into))]
@@ -30,6 +31,15 @@ impl<'a> MergeRequests<'a> {
     }
 }
 
+impl<'a> MergeRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), MergeRequestsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("sha", self.sha.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for MergeRequests<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -38,7 +48,8 @@ impl<'a> Endpoint for MergeRequests<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}/merge_requests",
-            self.project, self.sha,
+            self.project.path_segment(),
+            self.sha.path_segment(),
         )
         .into()
     }
@@ -82,6 +93,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = MergeRequests::builder()
+            .project(0)
+            .sha("123")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, MergeRequestsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
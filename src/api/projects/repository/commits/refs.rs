@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::{endpoint_prelude::*, ParamValue};
 
 /// Commit reference types
@@ -39,7 +39,7 @@ impl ParamValue<'static> for CommitRefsType {
 
 /// Post a comment on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CommitReferences<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -59,6 +59,15 @@ impl<'a> CommitReferences<'a> {
     }
 }
 
+impl<'a> CommitReferencesBuilder<'a> {
+    fn validate(&self) -> Result<(), CommitReferencesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("sha", self.sha.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CommitReferences<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -67,7 +76,8 @@ impl<'a> Endpoint for CommitReferences<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}/refs",
-            self.project, self.sha,
+            self.project.path_segment(),
+            self.sha.path_segment(),
         )
         .into()
     }
@@ -130,6 +140,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CommitReferences::builder()
+            .project(0)
+            .sha("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommitReferencesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific signature in a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Signature<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -27,6 +28,15 @@ impl<'a> Signature<'a> {
     }
 }
 
+impl<'a> SignatureBuilder<'a> {
+    fn validate(&self) -> Result<(), SignatureBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Signature<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,8 +45,8 @@ impl<'a> Endpoint for Signature<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}/signature",
-            self.project,
-            common::path_escaped(&self.commit),
+            self.project.path_segment(),
+            self.commit.path_segment(),
         )
         .into()
     }
@@ -75,6 +85,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Signature::builder()
+            .project(0)
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SignatureBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
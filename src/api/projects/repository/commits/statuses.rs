@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for statuses on a specific commit in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CommitStatuses<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -41,6 +41,15 @@ impl<'a> CommitStatuses<'a> {
     }
 }
 
+impl<'a> CommitStatusesBuilder<'a> {
+    fn validate(&self) -> Result<(), CommitStatusesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("commit", self.commit.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CommitStatuses<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -49,8 +58,8 @@ impl<'a> Endpoint for CommitStatuses<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/commits/{}/statuses",
-            self.project,
-            common::path_escaped(&self.commit),
+            self.project.path_segment(),
+            self.commit.path_segment(),
         )
         .into()
     }
@@ -106,6 +115,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CommitStatuses::builder()
+            .project(0)
+            .commit("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CommitStatusesBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
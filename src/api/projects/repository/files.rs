@@ -8,6 +8,7 @@
 //!
 //! These endpoints are used for querying a project's files.
 
+mod blame;
 mod create;
 mod delete;
 mod file;
@@ -18,6 +19,10 @@ pub use self::file::File;
 pub use self::file::FileBuilder;
 pub use self::file::FileBuilderError;
 
+pub use self::blame::FileBlame;
+pub use self::blame::FileBlameBuilder;
+pub use self::blame::FileBlameBuilderError;
+
 pub use self::create::CreateFile;
 pub use self::create::CreateFileBuilder;
 pub use self::create::CreateFileBuilderError;
@@ -0,0 +1,187 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Get blame information for a file in a repository.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct FileBlame<'a> {
+    /// The project to get a file within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The path to the file in the repository.
+    ///
+    /// This is automatically escaped as needed.
+    #[builder(setter(into))]
+    file_path: Cow<'a, str>,
+    /// The ref to get blame information from.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+
+    /// The range of lines to blame.
+    #[builder(setter(name = "_range"), default, private)]
+    range: Option<(u64, u64)>,
+}
+
+impl<'a> FileBlame<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FileBlameBuilder<'a> {
+        FileBlameBuilder::default()
+    }
+}
+
+impl<'a> FileBlameBuilder<'a> {
+    /// Only blame the lines from `start` to `end` (inclusive, 1-based).
+    pub fn range(&mut self, start: u64, end: u64) -> &mut Self {
+        self.range = Some(Some((start, end)));
+        self
+    }
+
+    fn validate(&self) -> Result<(), FileBlameBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for FileBlame<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/files/{}/blame",
+            self.project.path_segment(),
+            self.file_path.path_segment(),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push("ref", &self.ref_);
+
+        if let Some((start, end)) = self.range {
+            params
+                .push(ParamName::new("range").key("start"), start)
+                .push(ParamName::new("range").key("end"), end);
+        }
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::repository::files::{FileBlame, FileBlameBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = FileBlame::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FileBlameBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_required() {
+        let err = FileBlame::builder()
+            .file_path("new/file")
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, FileBlameBuilderError, "project");
+    }
+
+    #[test]
+    fn file_path_is_required() {
+        let err = FileBlame::builder()
+            .project(1)
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, FileBlameBuilderError, "file_path");
+    }
+
+    #[test]
+    fn ref_is_required() {
+        let err = FileBlame::builder()
+            .project(1)
+            .file_path("new/file")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, FileBlameBuilderError, "ref_");
+    }
+
+    #[test]
+    fn sufficient_parameters() {
+        FileBlame::builder()
+            .project(1)
+            .file_path("new/file")
+            .ref_("master")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = FileBlame::builder()
+            .project(0)
+            .file_path("new/file")
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, FileBlameBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/files/path%2Fto%2Ffile/blame")
+            .add_query_params(&[("ref", "branch")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FileBlame::builder()
+            .project("simple/project")
+            .file_path("path/to/file")
+            .ref_("branch")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_range() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/repository/files/path%2Fto%2Ffile/blame")
+            .add_query_params(&[
+                ("ref", "branch"),
+                ("range[start]", "10"),
+                ("range[end]", "20"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FileBlame::builder()
+            .project("simple/project")
+            .file_path("path/to/file")
+            .ref_("branch")
+            .range(10, 20)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -4,13 +4,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::io::{self, Write};
 use std::str;
 
-use base64::Engine;
+use base64::write::EncoderWriter;
 use derive_builder::Builder;
 use log::warn;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::body::FormWriter;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -44,18 +46,29 @@ impl Encoding {
         }
     }
 
-    pub(crate) fn encode<'a>(self, as_string: Option<&'a str>, content: &'a [u8]) -> Cow<'a, str> {
+    pub(crate) fn encode_into<W>(
+        self,
+        as_string: Option<&str>,
+        content: &[u8],
+        mut writer: W,
+    ) -> io::Result<()>
+    where
+        W: Write,
+    {
         match self {
             Encoding::Text => {
                 if let Some(string) = as_string {
-                    string.into()
+                    writer.write_all(string.as_bytes())
                 } else {
                     panic!("attempting to encode non-utf8 content using text!");
                 }
             },
             Encoding::Base64 => {
                 let engine = base64::engine::general_purpose::STANDARD;
-                engine.encode(content).into()
+                let mut encoder = EncoderWriter::new(writer, &engine);
+                encoder.write_all(content)?;
+                encoder.finish()?;
+                Ok(())
             },
         }
     }
@@ -76,7 +89,7 @@ impl ParamValue<'static> for Encoding {
 
 /// Create a new file in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateFile<'a> {
     /// The project to create a file within.
     #[builder(setter(into))]
@@ -124,34 +137,19 @@ impl<'a> CreateFile<'a> {
     pub fn builder() -> CreateFileBuilder<'a> {
         CreateFileBuilder::default()
     }
-}
-
-const SAFE_ENCODING: Encoding = Encoding::Base64;
-
-impl<'a> Endpoint for CreateFile<'a> {
-    fn method(&self) -> Method {
-        Method::POST
-    }
 
-    fn endpoint(&self) -> Cow<'static, str> {
-        format!(
-            "projects/{}/repository/files/{}",
-            self.project,
-            common::path_escaped(&self.file_path),
-        )
-        .into()
-    }
-
-    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
-        let mut params = FormParams::default();
+    fn write_body<W>(&self, writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut form = FormWriter::new(writer);
 
-        params
-            .push("branch", &self.branch)
-            .push("commit_message", &self.commit_message)
-            .push_opt("start_branch", self.start_branch.as_ref())
-            .push_opt("author_email", self.author_email.as_ref())
-            .push_opt("author_name", self.author_name.as_ref())
-            .push_opt("execute_filemode", self.execute_filemode);
+        form.push("branch", self.branch.as_ref())?
+            .push("commit_message", self.commit_message.as_ref())?
+            .push_opt("start_branch", self.start_branch.as_ref())?
+            .push_opt("author_email", self.author_email.as_ref())?
+            .push_opt("author_name", self.author_name.as_ref())?
+            .push_opt("execute_filemode", self.execute_filemode)?;
 
         let content = str::from_utf8(&self.content);
         let needs_encoding = content.is_err();
@@ -165,11 +163,13 @@ impl<'a> Endpoint for CreateFile<'a> {
         } else {
             encoding
         };
-        params.push(
-            "content",
-            actual_encoding.encode(content.ok(), &self.content),
-        );
-        self.encoding
+        // Encode the content directly into the body to avoid making copies of it.
+        form.push_with("content", |writer| {
+            actual_encoding.encode_into(content.ok(), &self.content, writer)
+        })?;
+
+        let encoding = self
+            .encoding
             // Use the actual encoding.
             .map(|_| actual_encoding)
             // Force the encoding if we're not using the default.
@@ -179,10 +179,48 @@ impl<'a> Endpoint for CreateFile<'a> {
                 } else {
                     None
                 }
-            })
-            .map(|value| params.push("encoding", value));
+            });
+        form.push_opt("encoding", encoding)?;
+
+        Ok(())
+    }
+}
 
-        params.into_body()
+impl<'a> CreateFileBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
+const SAFE_ENCODING: Encoding = Encoding::Base64;
+
+impl<'a> Endpoint for CreateFile<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/files/{}",
+            self.project.path_segment(),
+            self.file_path.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut body = Vec::new();
+        self.write_body(&mut body)?;
+        Ok(Some(("application/x-www-form-urlencoded", body)))
+    }
+
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        let mut body = SpooledBody::new();
+        self.write_body(&mut body)?;
+        Ok(Some(("application/x-www-form-urlencoded", body)))
     }
 }
 
@@ -191,7 +229,7 @@ mod tests {
     use http::Method;
 
     use crate::api::projects::repository::files::{CreateFile, CreateFileBuilderError, Encoding};
-    use crate::api::{self, Query};
+    use crate::api::{self, Endpoint, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
     #[test]
@@ -211,20 +249,26 @@ mod tests {
     #[test]
     fn encoding_encode_text() {
         let encoding = Encoding::Text;
-        assert_eq!(encoding.encode(Some("foo"), b"foo"), "foo");
+        let mut data = Vec::new();
+        encoding
+            .encode_into(Some("foo"), b"foo", &mut data)
+            .unwrap();
+        assert_eq!(data, b"foo");
     }
 
     #[test]
     #[should_panic = "attempting to encode non-utf8 content using text!"]
     fn encoding_encode_text_bad() {
         let encoding = Encoding::Text;
-        encoding.encode(None, b"\xff");
+        let _ = encoding.encode_into(None, b"\xff", Vec::new());
     }
 
     #[test]
     fn encoding_encode_base64() {
         let encoding = Encoding::Base64;
-        assert_eq!(encoding.encode(None, b"foo"), "Zm9v");
+        let mut data = Vec::new();
+        encoding.encode_into(None, b"foo", &mut data).unwrap();
+        assert_eq!(data, b"Zm9v");
     }
 
     #[test]
@@ -314,6 +358,19 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateFile::builder()
+            .project(0)
+            .file_path("new/file")
+            .branch("master")
+            .commit_message("commit message")
+            .content(&b"contents"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateFileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -506,4 +563,23 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn spooled_body_matches_body() {
+        let content = vec![0xfe; 4096];
+        let endpoint = CreateFile::builder()
+            .project("simple/project")
+            .file_path("path/to/file")
+            .branch("branch")
+            .content(&content[..])
+            .commit_message("commit message")
+            .build()
+            .unwrap();
+
+        let (mime, body) = endpoint.body().unwrap().unwrap();
+        let (spooled_mime, spooled) = endpoint.spooled_body().unwrap().unwrap();
+        assert_eq!(mime, spooled_mime);
+        assert_eq!(body.len() as u64, spooled.len());
+        assert_eq!(body, spooled.into_vec().unwrap());
+    }
 }
@@ -2,12 +2,12 @@ use std::str;
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete a file in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct DeleteFile<'a> {
     /// The project to delete a file within.
     #[builder(setter(into))]
@@ -47,6 +47,15 @@ impl<'a> DeleteFile<'a> {
     }
 }
 
+impl<'a> DeleteFileBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for DeleteFile<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -55,8 +64,8 @@ impl<'a> Endpoint for DeleteFile<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/files/{}",
-            self.project,
-            common::path_escaped(&self.file_path),
+            self.project.path_segment(),
+            self.file_path.path_segment(),
         )
         .into()
     }
@@ -145,6 +154,18 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteFile::builder()
+            .project(0)
+            .file_path("new/file")
+            .branch("master")
+            .commit_message("commit message")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteFileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get a single file from repository.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct File<'a> {
     /// The project to get a file within.
     #[builder(setter(into))]
@@ -32,6 +33,15 @@ impl<'a> File<'a> {
     }
 }
 
+impl<'a> FileBuilder<'a> {
+    fn validate(&self) -> Result<(), FileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for File<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -40,8 +50,8 @@ impl<'a> Endpoint for File<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/files/{}",
-            self.project,
-            common::path_escaped(&self.file_path),
+            self.project.path_segment(),
+            self.file_path.path_segment(),
         )
         .into()
     }
@@ -109,6 +119,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = File::builder()
+            .project(0)
+            .file_path("new/file")
+            .ref_("master")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, FileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get raw file from repository.
@@ -14,7 +14,7 @@ use crate::api::endpoint_prelude::*;
 /// Note: This endpoint returns raw data, so [`crate::api::raw`] is recommended to avoid the normal
 /// JSON parsing present in the typical endpoint handling.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct FileRaw<'a> {
     /// The project to get a file within.
     #[builder(setter(into))]
@@ -41,6 +41,15 @@ impl<'a> FileRaw<'a> {
     }
 }
 
+impl<'a> FileRawBuilder<'a> {
+    fn validate(&self) -> Result<(), FileRawBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for FileRaw<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -49,8 +58,8 @@ impl<'a> Endpoint for FileRaw<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/files/{}/raw",
-            self.project,
-            common::path_escaped(&self.file_path),
+            self.project.path_segment(),
+            self.file_path.path_segment(),
         )
         .into()
     }
@@ -104,6 +113,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = FileRaw::builder()
+            .project(0)
+            .file_path("new/file")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, FileRawBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -4,18 +4,20 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::io::{self, Write};
 use std::str;
 
 use derive_builder::Builder;
 use log::warn;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::body::FormWriter;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::repository::files::Encoding;
 
 /// Update a file in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UpdateFile<'a> {
     /// The project to update a file within.
     #[builder(setter(into))]
@@ -67,35 +69,20 @@ impl<'a> UpdateFile<'a> {
     pub fn builder() -> UpdateFileBuilder<'a> {
         UpdateFileBuilder::default()
     }
-}
-
-const SAFE_ENCODING: Encoding = Encoding::Base64;
-
-impl<'a> Endpoint for UpdateFile<'a> {
-    fn method(&self) -> Method {
-        Method::PUT
-    }
 
-    fn endpoint(&self) -> Cow<'static, str> {
-        format!(
-            "projects/{}/repository/files/{}",
-            self.project,
-            common::path_escaped(&self.file_path),
-        )
-        .into()
-    }
-
-    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
-        let mut params = FormParams::default();
+    fn write_body<W>(&self, writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut form = FormWriter::new(writer);
 
-        params
-            .push("branch", &self.branch)
-            .push("commit_message", &self.commit_message)
-            .push_opt("start_branch", self.start_branch.as_ref())
-            .push_opt("author_email", self.author_email.as_ref())
-            .push_opt("author_name", self.author_name.as_ref())
-            .push_opt("execute_filemode", self.execute_filemode)
-            .push_opt("last_commit_id", self.last_commit_id.as_ref());
+        form.push("branch", self.branch.as_ref())?
+            .push("commit_message", self.commit_message.as_ref())?
+            .push_opt("start_branch", self.start_branch.as_ref())?
+            .push_opt("author_email", self.author_email.as_ref())?
+            .push_opt("author_name", self.author_name.as_ref())?
+            .push_opt("execute_filemode", self.execute_filemode)?
+            .push_opt("last_commit_id", self.last_commit_id.as_ref())?;
 
         let content = str::from_utf8(&self.content);
         let needs_encoding = content.is_err();
@@ -109,12 +96,12 @@ impl<'a> Endpoint for UpdateFile<'a> {
         } else {
             encoding
         };
-        params.push(
-            "content",
-            actual_encoding.encode(content.ok(), &self.content),
-        );
+        // Encode the content directly into the body to avoid making copies of it.
+        form.push_with("content", |writer| {
+            actual_encoding.encode_into(content.ok(), &self.content, writer)
+        })?;
 
-        if let Some(value) = self
+        let encoding = self
             .encoding
             // Use the actual encoding.
             .map(|_| actual_encoding)
@@ -125,12 +112,48 @@ impl<'a> Endpoint for UpdateFile<'a> {
                 } else {
                     None
                 }
-            })
-        {
-            params.push("encoding", value);
-        }
+            });
+        form.push_opt("encoding", encoding)?;
 
-        params.into_body()
+        Ok(())
+    }
+}
+
+impl<'a> UpdateFileBuilder<'a> {
+    fn validate(&self) -> Result<(), UpdateFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
+const SAFE_ENCODING: Encoding = Encoding::Base64;
+
+impl<'a> Endpoint for UpdateFile<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/files/{}",
+            self.project.path_segment(),
+            self.file_path.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut body = Vec::new();
+        self.write_body(&mut body)?;
+        Ok(Some(("application/x-www-form-urlencoded", body)))
+    }
+
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        let mut body = SpooledBody::new();
+        self.write_body(&mut body)?;
+        Ok(Some(("application/x-www-form-urlencoded", body)))
     }
 }
 
@@ -139,7 +162,7 @@ mod tests {
     use http::Method;
 
     use crate::api::projects::repository::files::{Encoding, UpdateFile, UpdateFileBuilderError};
-    use crate::api::{self, Query};
+    use crate::api::{self, Endpoint, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
 
     #[test]
@@ -220,6 +243,19 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UpdateFile::builder()
+            .project(0)
+            .file_path("new/file")
+            .branch("master")
+            .commit_message("commit message")
+            .content(&b"contents"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UpdateFileBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -440,4 +476,24 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn spooled_body_matches_body() {
+        let content = vec![0xfe; 4096];
+        let endpoint = UpdateFile::builder()
+            .project("simple/project")
+            .file_path("path/to/file")
+            .branch("branch")
+            .content(&content[..])
+            .commit_message("commit message")
+            .last_commit_id("deadbeef")
+            .build()
+            .unwrap();
+
+        let (mime, body) = endpoint.body().unwrap().unwrap();
+        let (spooled_mime, spooled) = endpoint.spooled_body().unwrap().unwrap();
+        assert_eq!(mime, spooled_mime);
+        assert_eq!(body.len() as u64, spooled.len());
+        assert_eq!(body, spooled.into_vec().unwrap());
+    }
 }
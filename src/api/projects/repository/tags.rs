@@ -9,6 +9,7 @@
 //! These endpoints are used for interacting with a project's git tags.
 
 mod create;
+mod delete;
 mod tag;
 mod tags;
 
@@ -16,6 +17,10 @@ pub use self::create::CreateTag;
 pub use self::create::CreateTagBuilder;
 pub use self::create::CreateTagBuilderError;
 
+pub use self::delete::DeleteTag;
+pub use self::delete::DeleteTagBuilder;
+pub use self::delete::DeleteTagBuilderError;
+
 pub use self::tag::Tag;
 pub use self::tag::TagBuilder;
 pub use self::tag::TagBuilderError;
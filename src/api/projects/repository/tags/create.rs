@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Create a tag on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateTag<'a> {
     /// The project to create a tag on.
     #[builder(setter(into))]
@@ -34,13 +34,21 @@ impl<'a> CreateTag<'a> {
     }
 }
 
+impl<'a> CreateTagBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateTagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateTag<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/tags", self.project).into()
+        format!("projects/{}/repository/tags", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -103,6 +111,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateTag::builder()
+            .project(0)
+            .tag_name("a-tag-name")
+            .ref_("0000000000000000000000000000000000000000")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateTagBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
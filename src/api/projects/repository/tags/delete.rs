@@ -0,0 +1,116 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a tag from a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteTag<'a> {
+    /// The project to delete a tag from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The tag to delete.
+    #[builder(setter(into))]
+    tag_name: Cow<'a, str>,
+}
+
+impl<'a> DeleteTag<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteTagBuilder<'a> {
+        DeleteTagBuilder::default()
+    }
+}
+
+impl<'a> DeleteTagBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteTagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteTag<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/tags/{}",
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::repository::tags::{DeleteTag, DeleteTagBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_tag_are_necessary() {
+        let err = DeleteTag::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteTagBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteTag::builder().tag_name("master").build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteTagBuilderError, "project");
+    }
+
+    #[test]
+    fn tag_is_necessary() {
+        let err = DeleteTag::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteTagBuilderError, "tag_name");
+    }
+
+    #[test]
+    fn project_and_branch_are_sufficient() {
+        DeleteTag::builder()
+            .project(1)
+            .tag_name("a-tag")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteTag::builder()
+            .project(0)
+            .tag_name("a-tag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteTagBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/repository/tags/a-tag")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteTag::builder()
+            .project("simple/project")
+            .tag_name("a-tag")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
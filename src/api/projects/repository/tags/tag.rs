@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Tag<'a> {
     /// The project to get a atg from.
     #[builder(setter(into))]
@@ -27,6 +28,15 @@ impl<'a> Tag<'a> {
     }
 }
 
+impl<'a> TagBuilder<'a> {
+    fn validate(&self) -> Result<(), TagBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("tag_name", self.tag_name.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Tag<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -35,8 +45,8 @@ impl<'a> Endpoint for Tag<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/repository/tags/{}",
-            self.project,
-            common::path_escaped(&self.tag_name),
+            self.project.path_segment(),
+            self.tag_name.path_segment(),
         )
         .into()
     }
@@ -71,6 +81,16 @@ mod tests {
         Tag::builder().project(1).tag_name("a-tag").build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Tag::builder()
+            .project(0)
+            .tag_name("a-tag")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TagBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::SortOrder;
+use crate::api::common::{self, PathParam, SortOrder};
 use crate::api::endpoint_prelude::*;
 use crate::api::{common::NameOrId, ParamValue};
 
@@ -48,7 +48,7 @@ impl ParamValue<'static> for TagsOrderBy {
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Tags<'a> {
     /// The project to get a branch from.
     #[builder(setter(into))]
@@ -73,13 +73,21 @@ impl<'a> Tags<'a> {
     }
 }
 
+impl<'a> TagsBuilder<'a> {
+    fn validate(&self) -> Result<(), TagsBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Tags<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/tags", self.project).into()
+        format!("projects/{}/repository/tags", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -132,6 +140,12 @@ mod tests {
         Tags::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Tags::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TagsBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,12 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get the tree of a given path.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Tree<'a> {
     /// The ID or URL-encoded path of the project.
     #[builder(setter(into))]
@@ -34,13 +34,21 @@ impl<'a> Tree<'a> {
     }
 }
 
+impl<'a> TreeBuilder<'a> {
+    fn validate(&self) -> Result<(), TreeBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for Tree<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/repository/tree", self.project).into()
+        format!("projects/{}/repository/tree", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -76,6 +84,12 @@ mod tests {
         Tree::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = Tree::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TreeBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,10 +6,12 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Disable a runner on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DisableProjectRunner {
     /// The ID or URL-encoded path of the project.
     project: u64,
@@ -24,13 +26,27 @@ impl DisableProjectRunner {
     }
 }
 
+impl DisableProjectRunnerBuilder {
+    fn validate(&self) -> Result<(), DisableProjectRunnerBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("runner", self.runner.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl Endpoint for DisableProjectRunner {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/runners/{}", self.project, self.runner).into()
+        format!(
+            "projects/{}/runners/{}",
+            self.project.path_segment(),
+            self.runner.path_segment()
+        )
+        .into()
     }
 }
 
@@ -75,6 +91,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = DisableProjectRunner::builder()
+            .project(0)
+            .runner(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DisableProjectRunnerBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,10 +6,12 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Enable a runner on a project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EnableProjectRunner {
     /// The ID or URL-encoded path of the project.
     project: u64,
@@ -24,13 +26,21 @@ impl EnableProjectRunner {
     }
 }
 
+impl EnableProjectRunnerBuilder {
+    fn validate(&self) -> Result<(), EnableProjectRunnerBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl Endpoint for EnableProjectRunner {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/runners", self.project).into()
+        format!("projects/{}/runners", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -83,6 +93,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = EnableProjectRunner::builder()
+            .project(0)
+            .runner(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EnableProjectRunnerBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::common::{self, CommaSeparatedList, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::runners::{RunnerStatus, RunnerType};
 
 /// Query for runners on a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectRunners<'a> {
     /// The ID or URL-encoded path of the project.
     #[builder(setter(into))]
@@ -67,6 +67,12 @@ impl<'a> ProjectRunnersBuilder<'a> {
             .extend(iter.map(|t| t.into()));
         self
     }
+
+    fn validate(&self) -> Result<(), ProjectRunnersBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for ProjectRunners<'a> {
@@ -75,7 +81,7 @@ impl<'a> Endpoint for ProjectRunners<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/runners", self.project).into()
+        format!("projects/{}/runners", self.project.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -112,6 +118,12 @@ mod tests {
         ProjectRunners::builder().project(1).build().unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectRunners::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectRunnersBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project secure file API endpoints.
+//!
+//! These endpoints are used for managing files which are made available to CI jobs (e.g., signing
+//! keys) without being committed to the repository.
+
+mod create;
+mod delete;
+mod download;
+mod secure_file;
+mod secure_files;
+
+pub use self::create::CreateSecureFile;
+pub use self::create::CreateSecureFileBuilder;
+pub use self::create::CreateSecureFileBuilderError;
+
+pub use self::delete::DeleteSecureFile;
+pub use self::delete::DeleteSecureFileBuilder;
+pub use self::delete::DeleteSecureFileBuilderError;
+
+pub use self::download::DownloadSecureFile;
+pub use self::download::DownloadSecureFileBuilder;
+pub use self::download::DownloadSecureFileBuilderError;
+
+pub use self::secure_file::SecureFile;
+pub use self::secure_file::SecureFileBuilder;
+pub use self::secure_file::SecureFileBuilderError;
+
+pub use self::secure_files::SecureFiles;
+pub use self::secure_files::SecureFilesBuilder;
+pub use self::secure_files::SecureFilesBuilderError;
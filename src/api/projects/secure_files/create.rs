@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Upload a secure file to a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateSecureFile<'a> {
+    /// The project to upload the secure file to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the file.
+    ///
+    /// This must be unique within the project.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The contents of the file.
+    #[builder(setter(into))]
+    contents: Cow<'a, [u8]>,
+}
+
+impl<'a> CreateSecureFile<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateSecureFileBuilder<'a> {
+        CreateSecureFileBuilder::default()
+    }
+
+    fn params(&self) -> MultipartParams<'_> {
+        let mut params = MultipartParams::default();
+
+        params.push("name", self.name.as_ref()).push_file(
+            "file",
+            self.name.as_ref(),
+            self.contents.as_ref(),
+        );
+
+        params
+    }
+}
+
+impl<'a> CreateSecureFileBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateSecureFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateSecureFile<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/secure_files", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.params().into_body()
+    }
+
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        self.params().into_spooled_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::secure_files::{CreateSecureFile, CreateSecureFileBuilderError};
+    use crate::api::{self, Endpoint, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = CreateSecureFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateSecureFile::builder()
+            .name("signing.keystore")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateSecureFile::builder()
+            .project(1)
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSecureFileBuilderError, "name");
+    }
+
+    #[test]
+    fn contents_is_needed() {
+        let err = CreateSecureFile::builder()
+            .project(1)
+            .name("signing.keystore")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSecureFileBuilderError, "contents");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        CreateSecureFile::builder()
+            .project(1)
+            .name("signing.keystore")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateSecureFile::builder()
+            .project(0)
+            .name("signing.keystore")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/secure_files")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"name\"\r\n",
+                "\r\n",
+                "signing.keystore\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"signing.keystore\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "data\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateSecureFile::builder()
+            .project("simple/project")
+            .name("signing.keystore")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn spooled_body_matches_body() {
+        let content = vec![0xfe; 4096];
+        let endpoint = CreateSecureFile::builder()
+            .project("simple/project")
+            .name("signing.keystore")
+            .contents(&content[..])
+            .build()
+            .unwrap();
+
+        let (mime, body) = endpoint.body().unwrap().unwrap();
+        let (spooled_mime, spooled) = endpoint.spooled_body().unwrap().unwrap();
+        assert_eq!(mime, spooled_mime);
+        assert_eq!(body.len() as u64, spooled.len());
+        assert_eq!(body, spooled.into_vec().unwrap());
+    }
+}
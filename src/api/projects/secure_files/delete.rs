@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a secure file within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteSecureFile<'a> {
+    /// The project to delete the secure file from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the secure file.
+    secure_file: u64,
+}
+
+impl<'a> DeleteSecureFile<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteSecureFileBuilder<'a> {
+        DeleteSecureFileBuilder::default()
+    }
+}
+
+impl<'a> DeleteSecureFileBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteSecureFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("secure_file", self.secure_file.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteSecureFile<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/secure_files/{}",
+            self.project.path_segment(),
+            self.secure_file.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::secure_files::{DeleteSecureFile, DeleteSecureFileBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_secure_file_are_needed() {
+        let err = DeleteSecureFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DeleteSecureFile::builder()
+            .secure_file(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn secure_file_is_needed() {
+        let err = DeleteSecureFile::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteSecureFileBuilderError, "secure_file");
+    }
+
+    #[test]
+    fn project_and_secure_file_are_sufficient() {
+        DeleteSecureFile::builder()
+            .project(1)
+            .secure_file(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteSecureFile::builder()
+            .project(0)
+            .secure_file(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/secure_files/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSecureFile::builder()
+            .project("simple/project")
+            .secure_file(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
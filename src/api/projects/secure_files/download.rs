@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Download a secure file within a project.
+///
+/// Note: This endpoint returns raw data, so [`crate::api::raw`] is recommended to avoid the normal
+/// JSON parsing present in the typical endpoint handling.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DownloadSecureFile<'a> {
+    /// The project to download the secure file from.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the secure file.
+    secure_file: u64,
+}
+
+impl<'a> DownloadSecureFile<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DownloadSecureFileBuilder<'a> {
+        DownloadSecureFileBuilder::default()
+    }
+}
+
+impl<'a> DownloadSecureFileBuilder<'a> {
+    fn validate(&self) -> Result<(), DownloadSecureFileBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("secure_file", self.secure_file.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DownloadSecureFile<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/secure_files/{}/download",
+            self.project.path_segment(),
+            self.secure_file.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::secure_files::{DownloadSecureFile, DownloadSecureFileBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_secure_file_are_needed() {
+        let err = DownloadSecureFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DownloadSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = DownloadSecureFile::builder()
+            .secure_file(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DownloadSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn secure_file_is_needed() {
+        let err = DownloadSecureFile::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DownloadSecureFileBuilderError, "secure_file");
+    }
+
+    #[test]
+    fn project_and_secure_file_are_sufficient() {
+        DownloadSecureFile::builder()
+            .project(1)
+            .secure_file(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DownloadSecureFile::builder()
+            .project(0)
+            .secure_file(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DownloadSecureFileBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/secure_files/1/download")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DownloadSecureFile::builder()
+            .project("simple/project")
+            .secure_file(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for secure files within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SecureFiles<'a> {
+    /// The project to query for secure files.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> SecureFiles<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SecureFilesBuilder<'a> {
+        SecureFilesBuilder::default()
+    }
+}
+
+impl<'a> SecureFilesBuilder<'a> {
+    fn validate(&self) -> Result<(), SecureFilesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for SecureFiles<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/secure_files", self.project.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for SecureFiles<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::secure_files::{SecureFiles, SecureFilesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = SecureFiles::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SecureFilesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        SecureFiles::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = SecureFiles::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, SecureFilesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/secure_files")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SecureFiles::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
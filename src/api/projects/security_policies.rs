@@ -0,0 +1,34 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Security policy project helpers.
+//!
+//! Security policies are stored in a `policy.yml` file within a security policy project. These
+//! types describe scan execution and scan result policies and `SecurityPolicyFile` reads and
+//! writes the file using the repository file and commit endpoints.
+
+mod file;
+mod policies;
+
+pub use self::file::JsonPolicyFormat;
+pub use self::file::PolicyFormat;
+pub use self::file::SecurityPolicyError;
+pub use self::file::SecurityPolicyFile;
+pub use self::file::SecurityPolicyFileBuilder;
+pub use self::file::SecurityPolicyFileBuilderError;
+pub use self::file::DEFAULT_POLICY_PATH;
+
+pub use self::policies::ScanExecutionAction;
+pub use self::policies::ScanExecutionPolicy;
+pub use self::policies::ScanExecutionRule;
+pub use self::policies::ScanExecutionRuleType;
+pub use self::policies::ScanResultAction;
+pub use self::policies::ScanResultActionType;
+pub use self::policies::ScanResultPolicy;
+pub use self::policies::ScanResultRule;
+pub use self::policies::ScanResultRuleType;
+pub use self::policies::ScanType;
+pub use self::policies::SecurityPolicies;
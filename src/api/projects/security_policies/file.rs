@@ -0,0 +1,538 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::borrow::Cow;
+use std::error::Error;
+
+use derive_builder::Builder;
+use thiserror::Error;
+
+use crate::api::common::NameOrId;
+use crate::api::projects::repository::commits::{CommitAction, CommitActionType, CreateCommit};
+use crate::api::projects::repository::files::FileRaw;
+use crate::api::projects::security_policies::SecurityPolicies;
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// The path to the policy file within a security policy project.
+pub const DEFAULT_POLICY_PATH: &str = ".gitlab/security-policies/policy.yml";
+
+/// Errors which may occur when reading or writing a security policy file.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SecurityPolicyError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// An error communicating with GitLab.
+    #[error("{}", source)]
+    Api {
+        /// The source of the error.
+        #[from]
+        source: ApiError<E>,
+    },
+    /// The policy file could not be parsed or formatted.
+    #[error("failed to handle the policy file format: {}", source)]
+    Format {
+        /// The source of the error.
+        source: Box<dyn Error + Send + Sync + 'static>,
+    },
+}
+
+impl<E> SecurityPolicyError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn format<F>(source: F) -> Self
+    where
+        F: Error + Send + Sync + 'static,
+    {
+        SecurityPolicyError::Format {
+            source: Box::new(source),
+        }
+    }
+}
+
+/// A file format for security policies.
+///
+/// GitLab stores policies as YAML. This crate does not depend on a YAML parser, so this trait may
+/// be implemented using one (e.g., `serde_yaml`) in order to read files written by GitLab itself.
+pub trait PolicyFormat {
+    /// The error returned when parsing or formatting fails.
+    type Error: Error + Send + Sync + 'static;
+
+    /// Parse the contents of a policy file.
+    fn parse(&self, data: &[u8]) -> Result<SecurityPolicies, Self::Error>;
+    /// Format policies for storage in a policy file.
+    fn format(&self, policies: &SecurityPolicies) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// Read and write policy files using JSON.
+///
+/// JSON is a subset of YAML, so files written using this format are understood by GitLab.
+/// However, only files which use JSON syntax may be read. Policy files written by GitLab use
+/// block-style YAML and require a YAML-aware `PolicyFormat` instead.
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct JsonPolicyFormat;
+
+impl PolicyFormat for JsonPolicyFormat {
+    type Error = serde_json::Error;
+
+    fn parse(&self, data: &[u8]) -> Result<SecurityPolicies, Self::Error> {
+        serde_json::from_slice(data)
+    }
+
+    fn format(&self, policies: &SecurityPolicies) -> Result<Vec<u8>, Self::Error> {
+        let mut data = serde_json::to_vec_pretty(policies)?;
+        data.push(b'\n');
+        Ok(data)
+    }
+}
+
+/// The policy file within a security policy project.
+///
+/// Policies are read through the raw file endpoint and written by committing to the given branch.
+/// To update a file, read it, modify the policies, and then write it (or create it if it did not
+/// exist).
+#[derive(Debug, Builder, Clone)]
+pub struct SecurityPolicyFile<'a> {
+    /// The security policy project.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The branch to read from and commit to.
+    #[builder(setter(into))]
+    branch: Cow<'a, str>,
+    /// The path to the policy file.
+    ///
+    /// Defaults to `.gitlab/security-policies/policy.yml`.
+    #[builder(setter(into), default = "DEFAULT_POLICY_PATH.into()")]
+    file_path: Cow<'a, str>,
+}
+
+impl<'a> SecurityPolicyFile<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> SecurityPolicyFileBuilder<'a> {
+        SecurityPolicyFileBuilder::default()
+    }
+
+    fn raw_file<E>(&self) -> Result<FileRaw, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        FileRaw::builder()
+            .project(self.project.clone())
+            .file_path(self.file_path.as_ref())
+            .ref_(self.branch.as_ref())
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn commit<'b, E>(
+        &'b self,
+        action: CommitActionType,
+        content: &'b [u8],
+        commit_message: &'b str,
+    ) -> Result<CreateCommit<'b>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        CreateCommit::builder()
+            .project(self.project.clone())
+            .branch(self.branch.as_ref())
+            .commit_message(commit_message)
+            .action(
+                CommitAction::builder()
+                    .action(action)
+                    .file_path(self.file_path.as_ref())
+                    .content(content)
+                    .build()
+                    .map_err(ApiError::invalid_endpoint)?,
+            )
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn parse<F, E>(
+        format: &F,
+        data: Result<Vec<u8>, ApiError<E>>,
+    ) -> Result<Option<SecurityPolicies>, SecurityPolicyError<E>>
+    where
+        F: PolicyFormat + ?Sized,
+        E: Error + Send + Sync + 'static,
+    {
+        match data {
+            Ok(data) => {
+                format
+                    .parse(&data)
+                    .map(Some)
+                    .map_err(SecurityPolicyError::format)
+            },
+            Err(err) if is_not_found(&err) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Read the policies from the file.
+    ///
+    /// Returns `None` if the file does not exist.
+    pub fn read<C, F>(
+        &self,
+        client: &C,
+        format: &F,
+    ) -> Result<Option<SecurityPolicies>, SecurityPolicyError<C::Error>>
+    where
+        C: Client,
+        F: PolicyFormat + ?Sized,
+    {
+        Self::parse(format, api::raw(self.raw_file()?).query(client))
+    }
+
+    /// Read the policies from the file asynchronously.
+    ///
+    /// Returns `None` if the file does not exist.
+    pub async fn read_async<C, F>(
+        &self,
+        client: &C,
+        format: &F,
+    ) -> Result<Option<SecurityPolicies>, SecurityPolicyError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        F: PolicyFormat + ?Sized,
+    {
+        Self::parse(format, api::raw(self.raw_file()?).query_async(client).await)
+    }
+
+    /// Create the file with the given policies.
+    pub fn create<C, F>(
+        &self,
+        client: &C,
+        format: &F,
+        policies: &SecurityPolicies,
+        commit_message: &str,
+    ) -> Result<(), SecurityPolicyError<C::Error>>
+    where
+        C: Client,
+        F: PolicyFormat + ?Sized,
+    {
+        let content = format
+            .format(policies)
+            .map_err(SecurityPolicyError::format)?;
+        let endpoint = self.commit(CommitActionType::Create, &content, commit_message)?;
+        Ok(api::ignore(endpoint).query(client)?)
+    }
+
+    /// Create the file with the given policies asynchronously.
+    pub async fn create_async<C, F>(
+        &self,
+        client: &C,
+        format: &F,
+        policies: &SecurityPolicies,
+        commit_message: &str,
+    ) -> Result<(), SecurityPolicyError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        F: PolicyFormat + ?Sized,
+    {
+        let content = format
+            .format(policies)
+            .map_err(SecurityPolicyError::format)?;
+        let endpoint = self.commit(CommitActionType::Create, &content, commit_message)?;
+        Ok(api::ignore(endpoint).query_async(client).await?)
+    }
+
+    /// Replace the contents of the existing file with the given policies.
+    pub fn write<C, F>(
+        &self,
+        client: &C,
+        format: &F,
+        policies: &SecurityPolicies,
+        commit_message: &str,
+    ) -> Result<(), SecurityPolicyError<C::Error>>
+    where
+        C: Client,
+        F: PolicyFormat + ?Sized,
+    {
+        let content = format
+            .format(policies)
+            .map_err(SecurityPolicyError::format)?;
+        let endpoint = self.commit(CommitActionType::Update, &content, commit_message)?;
+        Ok(api::ignore(endpoint).query(client)?)
+    }
+
+    /// Replace the contents of the existing file with the given policies asynchronously.
+    pub async fn write_async<C, F>(
+        &self,
+        client: &C,
+        format: &F,
+        policies: &SecurityPolicies,
+        commit_message: &str,
+    ) -> Result<(), SecurityPolicyError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+        F: PolicyFormat + ?Sized,
+    {
+        let content = format
+            .format(policies)
+            .map_err(SecurityPolicyError::format)?;
+        let endpoint = self.commit(CommitActionType::Update, &content, commit_message)?;
+        Ok(api::ignore(endpoint).query_async(client).await?)
+    }
+}
+
+fn is_not_found<E>(err: &ApiError<E>) -> bool
+where
+    E: Error + Send + Sync + 'static,
+{
+    if let ApiError::Gitlab {
+        msg,
+    } = err
+    {
+        msg.starts_with("404")
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+
+    use crate::api::projects::security_policies::{
+        JsonPolicyFormat, PolicyFormat, ScanExecutionAction, ScanExecutionPolicy,
+        ScanExecutionRule, ScanExecutionRuleType, ScanType, SecurityPolicies, SecurityPolicyError,
+        SecurityPolicyFile, SecurityPolicyFileBuilderError,
+    };
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn policies() -> SecurityPolicies {
+        let mut rule = ScanExecutionRule::new(ScanExecutionRuleType::Pipeline);
+        rule.branches.push("main".into());
+
+        SecurityPolicies {
+            scan_execution_policy: vec![ScanExecutionPolicy {
+                name: "SAST".into(),
+                enabled: true,
+                rules: vec![rule],
+                actions: vec![ScanExecutionAction::new(ScanType::Sast)],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    const POLICIES_JSON: &str = concat!(
+        "{\"scan_execution_policy\":[{\"name\":\"SAST\",\"enabled\":true,",
+        "\"rules\":[{\"type\":\"pipeline\",\"branches\":[\"main\"]}],",
+        "\"actions\":[{\"scan\":\"sast\"}]}]}",
+    );
+
+    #[test]
+    fn project_and_branch_are_needed() {
+        let err = SecurityPolicyFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SecurityPolicyFileBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = SecurityPolicyFile::builder()
+            .branch("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SecurityPolicyFileBuilderError, "project");
+    }
+
+    #[test]
+    fn branch_is_needed() {
+        let err = SecurityPolicyFile::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SecurityPolicyFileBuilderError, "branch");
+    }
+
+    #[test]
+    fn project_and_branch_are_sufficient() {
+        SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn json_format_round_trip() {
+        let format = JsonPolicyFormat;
+        let data = format.format(&policies()).unwrap();
+        assert_eq!(format.parse(&data).unwrap(), policies());
+    }
+
+    #[test]
+    fn read() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint(
+                "projects/security%2Fpolicies/repository/files/.gitlab%2Fsecurity-policies%2Fpolicy.yml/raw",
+            )
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, POLICIES_JSON);
+
+        let file = SecurityPolicyFile::builder()
+            .project("security/policies")
+            .branch("main")
+            .build()
+            .unwrap();
+        let read = file.read(&client, &JsonPolicyFormat).unwrap();
+        assert_eq!(read, Some(policies()));
+    }
+
+    #[test]
+    fn read_missing() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/repository/files/policy.yml/raw")
+            .add_query_params(&[("ref", "main")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, r#"{"message":"404 File Not Found"}"#);
+
+        let file = SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .file_path("policy.yml")
+            .build()
+            .unwrap();
+        let read = file.read(&client, &JsonPolicyFormat).unwrap();
+        assert_eq!(read, None);
+    }
+
+    #[test]
+    fn read_invalid() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/repository/files/policy.yml/raw")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "scan_execution_policy: []");
+
+        let file = SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .file_path("policy.yml")
+            .build()
+            .unwrap();
+        let err = file.read(&client, &JsonPolicyFormat).unwrap_err();
+        if let SecurityPolicyError::Format {
+            ..
+        } = err
+        {
+            // expected
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn read_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/1/repository/files/policy.yml/raw")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, POLICIES_JSON);
+
+        let file = SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .file_path("policy.yml")
+            .build()
+            .unwrap();
+        let read = file.read_async(&client, &JsonPolicyFormat).await.unwrap();
+        assert_eq!(read, Some(policies()));
+    }
+
+    fn commit_body(action: &str) -> String {
+        let content = JsonPolicyFormat.format(&policies()).unwrap();
+        let content: String = url::form_urlencoded::byte_serialize(&content).collect();
+        format!(
+            concat!(
+                "branch=main",
+                "&commit_message=update+policies",
+                "&actions%5B%5D%5Baction%5D={}",
+                "&actions%5B%5D%5Bfile_path%5D=policy.yml",
+                "&actions%5B%5D%5Bcontent%5D={}",
+            ),
+            action, content,
+        )
+    }
+
+    #[test]
+    fn create() {
+        let body = commit_body("create");
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/1/repository/commits")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(&body)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let file = SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .file_path("policy.yml")
+            .build()
+            .unwrap();
+        file.create(&client, &JsonPolicyFormat, &policies(), "update policies")
+            .unwrap();
+    }
+
+    #[test]
+    fn write() {
+        let body = commit_body("update");
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/1/repository/commits")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(&body)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let file = SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .file_path("policy.yml")
+            .build()
+            .unwrap();
+        file.write(&client, &JsonPolicyFormat, &policies(), "update policies")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn write_async() {
+        let body = commit_body("update");
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/1/repository/commits")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(&body)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let file = SecurityPolicyFile::builder()
+            .project(1)
+            .branch("main")
+            .file_path("policy.yml")
+            .build()
+            .unwrap();
+        file.write_async(&client, &JsonPolicyFormat, &policies(), "update policies")
+            .await
+            .unwrap();
+    }
+}
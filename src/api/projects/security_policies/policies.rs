@@ -0,0 +1,472 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The contents of a security policy project's `policy.yml` file.
+///
+/// Keys which are not otherwise represented are preserved in `extra` so that files may be
+/// rewritten without losing information.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityPolicies {
+    /// Policies which enforce scans in pipelines.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scan_execution_policy: Vec<ScanExecutionPolicy>,
+    /// Policies which require approvals based on scan results.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scan_result_policy: Vec<ScanResultPolicy>,
+    /// Other keys in the file.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// A policy which enforces security scans in pipelines.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanExecutionPolicy {
+    /// The name of the policy.
+    pub name: String,
+    /// A description of the policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the policy is enforced or not.
+    pub enabled: bool,
+    /// The conditions which trigger the policy.
+    #[serde(default)]
+    pub rules: Vec<ScanExecutionRule>,
+    /// The scans to run when the policy is triggered.
+    #[serde(default)]
+    pub actions: Vec<ScanExecutionAction>,
+    /// Other keys in the policy (e.g., `policy_scope`).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Triggers for scan execution policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ScanExecutionRuleType {
+    /// Run scans in pipelines for matching branches.
+    Pipeline,
+    /// Run scans on a schedule.
+    Schedule,
+    /// Run scans through a Kubernetes agent.
+    Agent,
+}
+
+/// A condition which triggers a scan execution policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanExecutionRule {
+    /// The type of the rule.
+    #[serde(rename = "type")]
+    pub type_: ScanExecutionRuleType,
+    /// The branches the rule applies to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<String>,
+    /// The kind of branches the rule applies to (e.g., `default`, `protected`, or `all`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_type: Option<String>,
+    /// The cron schedule for `schedule` rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cadence: Option<String>,
+    /// The timezone for the `cadence`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Other keys in the rule (e.g., `branch_exceptions` or `agents`).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ScanExecutionRule {
+    /// Create a rule of the given type.
+    pub fn new(type_: ScanExecutionRuleType) -> Self {
+        Self {
+            type_,
+            branches: Vec::new(),
+            branch_type: None,
+            cadence: None,
+            timezone: None,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+/// Security scanners which may be enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ScanType {
+    /// Dynamic application security testing.
+    Dast,
+    /// Secret detection.
+    SecretDetection,
+    /// Static application security testing.
+    Sast,
+    /// Infrastructure as code scanning.
+    SastIac,
+    /// Container scanning.
+    ContainerScanning,
+    /// Dependency scanning.
+    DependencyScanning,
+    /// Cluster image scanning.
+    ClusterImageScanning,
+}
+
+/// A scan to run when a scan execution policy is triggered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanExecutionAction {
+    /// The scan to run.
+    pub scan: ScanType,
+    /// The DAST site profile to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_profile: Option<String>,
+    /// The DAST scanner profile to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scanner_profile: Option<String>,
+    /// CI variables to set for the scan.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+    /// Runner tags to use for the scan.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Other keys in the action (e.g., `scan_settings`).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ScanExecutionAction {
+    /// Create an action running the given scan.
+    pub fn new(scan: ScanType) -> Self {
+        Self {
+            scan,
+            site_profile: None,
+            scanner_profile: None,
+            variables: BTreeMap::new(),
+            tags: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+/// A policy which requires approvals on merge requests based on scan results.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanResultPolicy {
+    /// The name of the policy.
+    pub name: String,
+    /// A description of the policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the policy is enforced or not.
+    pub enabled: bool,
+    /// The conditions which trigger the policy.
+    #[serde(default)]
+    pub rules: Vec<ScanResultRule>,
+    /// The actions to take when the policy is triggered.
+    #[serde(default)]
+    pub actions: Vec<ScanResultAction>,
+    /// Other keys in the policy (e.g., `approval_settings` or `policy_scope`).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Triggers for scan result policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ScanResultRuleType {
+    /// Findings from security scanners.
+    ScanFinding,
+    /// Findings from license scanning.
+    LicenseFinding,
+    /// Any merge request (e.g., with unsigned commits).
+    AnyMergeRequest,
+}
+
+/// A condition which triggers a scan result policy.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanResultRule {
+    /// The type of the rule.
+    #[serde(rename = "type")]
+    pub type_: ScanResultRuleType,
+    /// The branches the rule applies to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<String>,
+    /// The kind of branches the rule applies to (e.g., `default`, `protected`, or `all`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_type: Option<String>,
+    /// The scanners to consider for `scan_finding` rules.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scanners: Vec<String>,
+    /// The number of vulnerabilities allowed before the rule applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vulnerabilities_allowed: Option<u64>,
+    /// The severity levels to consider.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub severity_levels: Vec<String>,
+    /// The vulnerability states to consider.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vulnerability_states: Vec<String>,
+    /// Whether `license_types` is a deny list (`true`) or an allow list (`false`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_on_inclusion_license: Option<bool>,
+    /// The licenses to consider for `license_finding` rules.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub license_types: Vec<String>,
+    /// The license states to consider (e.g., `newly_detected` or `detected`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub license_states: Vec<String>,
+    /// The commits to consider for `any_merge_request` rules (e.g., `any` or `unsigned`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits: Option<String>,
+    /// Other keys in the rule (e.g., `branch_exceptions` or `vulnerability_attributes`).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ScanResultRule {
+    /// Create a rule of the given type.
+    pub fn new(type_: ScanResultRuleType) -> Self {
+        Self {
+            type_,
+            branches: Vec::new(),
+            branch_type: None,
+            scanners: Vec::new(),
+            vulnerabilities_allowed: None,
+            severity_levels: Vec::new(),
+            vulnerability_states: Vec::new(),
+            match_on_inclusion_license: None,
+            license_types: Vec::new(),
+            license_states: Vec::new(),
+            commits: None,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+/// Actions for scan result policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ScanResultActionType {
+    /// Require approval of the merge request.
+    RequireApproval,
+    /// Post a bot comment on the merge request.
+    SendBotMessage,
+}
+
+/// An action to take when a scan result policy is triggered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanResultAction {
+    /// The type of the action.
+    #[serde(rename = "type")]
+    pub type_: ScanResultActionType,
+    /// The number of approvals required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approvals_required: Option<u64>,
+    /// The usernames of users who may approve.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub user_approvers: Vec<String>,
+    /// The IDs of users who may approve.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub user_approvers_ids: Vec<u64>,
+    /// The paths of groups whose members may approve.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_approvers: Vec<String>,
+    /// The IDs of groups whose members may approve.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_approvers_ids: Vec<u64>,
+    /// The roles which may approve.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub role_approvers: Vec<String>,
+    /// Other keys in the action (e.g., `enabled` for bot messages).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ScanResultAction {
+    /// Create an action of the given type.
+    pub fn new(type_: ScanResultActionType) -> Self {
+        Self {
+            type_,
+            approvals_required: None,
+            user_approvers: Vec::new(),
+            user_approvers_ids: Vec::new(),
+            group_approvers: Vec::new(),
+            group_approvers_ids: Vec::new(),
+            role_approvers: Vec::new(),
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::projects::security_policies::{
+        ScanExecutionAction, ScanExecutionPolicy, ScanExecutionRule, ScanExecutionRuleType,
+        ScanResultAction, ScanResultActionType, ScanResultPolicy, ScanResultRule,
+        ScanResultRuleType, ScanType, SecurityPolicies,
+    };
+
+    #[test]
+    fn scan_execution_policy_round_trip() {
+        let input = json!({
+            "scan_execution_policy": [
+                {
+                    "name": "Enforce DAST",
+                    "description": "Run DAST on main",
+                    "enabled": true,
+                    "rules": [
+                        {
+                            "type": "pipeline",
+                            "branches": ["main"],
+                        },
+                    ],
+                    "actions": [
+                        {
+                            "scan": "dast",
+                            "site_profile": "site",
+                            "scanner_profile": "scanner",
+                        },
+                    ],
+                    "policy_scope": {
+                        "compliance_frameworks": [{"id": 1}],
+                    },
+                },
+            ],
+        });
+
+        let policies: SecurityPolicies = serde_json::from_value(input.clone()).unwrap();
+        let policy = &policies.scan_execution_policy[0];
+        assert_eq!(policy.name, "Enforce DAST");
+        assert_eq!(policy.rules[0].type_, ScanExecutionRuleType::Pipeline);
+        assert_eq!(policy.actions[0].scan, ScanType::Dast);
+        assert!(policy.extra.contains_key("policy_scope"));
+
+        assert_eq!(serde_json::to_value(&policies).unwrap(), input);
+    }
+
+    #[test]
+    fn scan_result_policy_round_trip() {
+        let input = json!({
+            "scan_result_policy": [
+                {
+                    "name": "Critical vulnerabilities",
+                    "enabled": true,
+                    "rules": [
+                        {
+                            "type": "scan_finding",
+                            "branch_type": "protected",
+                            "scanners": ["sast"],
+                            "vulnerabilities_allowed": 0,
+                            "severity_levels": ["critical"],
+                            "vulnerability_states": ["newly_detected"],
+                        },
+                    ],
+                    "actions": [
+                        {
+                            "type": "require_approval",
+                            "approvals_required": 1,
+                            "role_approvers": ["maintainer"],
+                        },
+                    ],
+                },
+            ],
+        });
+
+        let policies: SecurityPolicies = serde_json::from_value(input.clone()).unwrap();
+        let policy = &policies.scan_result_policy[0];
+        assert_eq!(policy.rules[0].type_, ScanResultRuleType::ScanFinding);
+        assert_eq!(policy.rules[0].vulnerabilities_allowed, Some(0));
+        assert_eq!(
+            policy.actions[0].type_,
+            ScanResultActionType::RequireApproval,
+        );
+
+        assert_eq!(serde_json::to_value(&policies).unwrap(), input);
+    }
+
+    #[test]
+    fn construct_policies() {
+        let mut rule = ScanExecutionRule::new(ScanExecutionRuleType::Schedule);
+        rule.cadence = Some("0 0 * * *".into());
+        rule.branch_type = Some("default".into());
+
+        let mut approval = ScanResultAction::new(ScanResultActionType::RequireApproval);
+        approval.approvals_required = Some(2);
+        approval.group_approvers.push("security".into());
+
+        let mut license = ScanResultRule::new(ScanResultRuleType::LicenseFinding);
+        license.match_on_inclusion_license = Some(true);
+        license.license_types.push("GPL-3.0".into());
+
+        let policies = SecurityPolicies {
+            scan_execution_policy: vec![ScanExecutionPolicy {
+                name: "Nightly".into(),
+                enabled: true,
+                rules: vec![rule],
+                actions: vec![ScanExecutionAction::new(ScanType::SecretDetection)],
+                ..Default::default()
+            }],
+            scan_result_policy: vec![ScanResultPolicy {
+                name: "Licenses".into(),
+                enabled: false,
+                rules: vec![license],
+                actions: vec![approval],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&policies).unwrap(),
+            json!({
+                "scan_execution_policy": [
+                    {
+                        "name": "Nightly",
+                        "enabled": true,
+                        "rules": [
+                            {
+                                "type": "schedule",
+                                "branch_type": "default",
+                                "cadence": "0 0 * * *",
+                            },
+                        ],
+                        "actions": [
+                            {
+                                "scan": "secret_detection",
+                            },
+                        ],
+                    },
+                ],
+                "scan_result_policy": [
+                    {
+                        "name": "Licenses",
+                        "enabled": false,
+                        "rules": [
+                            {
+                                "type": "license_finding",
+                                "match_on_inclusion_license": true,
+                                "license_types": ["GPL-3.0"],
+                            },
+                        ],
+                        "actions": [
+                            {
+                                "type": "require_approval",
+                                "approvals_required": 2,
+                                "group_approvers": ["security"],
+                            },
+                        ],
+                    },
+                ],
+            }),
+        );
+    }
+}
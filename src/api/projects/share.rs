@@ -7,21 +7,21 @@
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Share a project with a group.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ShareProject<'a> {
-    /// The project to add the user to.
+    /// The project to share.
     #[builder(setter(into))]
     project: NameOrId<'a>,
-    /// The group to add to the project.
+    /// The ID of the group to share the project with.
     group_id: u64,
     /// The access level for the group within the project.
     group_access: AccessLevel,
-    /// When the user's access expires.
+    /// When the group's access expires.
     #[builder(default)]
     expires_at: Option<NaiveDate>,
 }
@@ -33,13 +33,21 @@ impl<'a> ShareProject<'a> {
     }
 }
 
+impl<'a> ShareProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), ShareProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ShareProject<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/share", self.project).into()
+        format!("projects/{}/share", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -110,6 +118,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ShareProject::builder()
+            .project(0)
+            .group_id(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ShareProjectBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,29 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Project snippet API endpoints.
+//!
+//! These endpoints are used for querying project snippets and the contents of their
+//! repositories.
+
+mod file_raw;
+pub mod notes;
+mod snippet;
+mod snippets;
+
+pub use self::file_raw::ProjectSnippetFileRaw;
+pub use self::file_raw::ProjectSnippetFileRawBuilder;
+pub use self::file_raw::ProjectSnippetFileRawBuilderError;
+
+pub use self::snippet::ProjectSnippet;
+pub use self::snippet::ProjectSnippetBuilder;
+pub use self::snippet::ProjectSnippetBuilderError;
+
+pub use self::snippets::ProjectSnippets;
+pub use self::snippets::ProjectSnippetsBuilder;
+pub use self::snippets::ProjectSnippetsBuilderError;
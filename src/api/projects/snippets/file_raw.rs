@@ -0,0 +1,169 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Get a raw file from a project snippet's repository.
+///
+/// Note: This endpoint returns raw data, so [`crate::api::raw`] is recommended to avoid the normal
+/// JSON parsing present in the typical endpoint handling.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectSnippetFileRaw<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+    /// The ref to get the file from.
+    ///
+    /// This may be a branch name or a commit.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+    /// The path to the file in the snippet's repository.
+    ///
+    /// This is automatically escaped as needed.
+    #[builder(setter(into))]
+    file_path: Cow<'a, str>,
+}
+
+impl<'a> ProjectSnippetFileRaw<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectSnippetFileRawBuilder<'a> {
+        ProjectSnippetFileRawBuilder::default()
+    }
+}
+
+impl<'a> ProjectSnippetFileRawBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectSnippetFileRawBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+        common::validate_path_param("ref_", self.ref_.as_ref())?;
+        common::validate_path_param("file_path", self.file_path.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectSnippetFileRaw<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/files/{}/{}/raw",
+            self.project.path_segment(),
+            self.snippet.path_segment(),
+            self.ref_.path_segment(),
+            self.file_path.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::snippets::{
+        ProjectSnippetFileRaw, ProjectSnippetFileRawBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = ProjectSnippetFileRaw::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetFileRawBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectSnippetFileRaw::builder()
+            .snippet(1)
+            .ref_("main")
+            .file_path("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetFileRawBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_needed() {
+        let err = ProjectSnippetFileRaw::builder()
+            .project(1)
+            .ref_("main")
+            .file_path("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetFileRawBuilderError, "snippet");
+    }
+
+    #[test]
+    fn ref_is_needed() {
+        let err = ProjectSnippetFileRaw::builder()
+            .project(1)
+            .snippet(1)
+            .file_path("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetFileRawBuilderError, "ref_");
+    }
+
+    #[test]
+    fn file_path_is_needed() {
+        let err = ProjectSnippetFileRaw::builder()
+            .project(1)
+            .snippet(1)
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetFileRawBuilderError, "file_path");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        ProjectSnippetFileRaw::builder()
+            .project(1)
+            .snippet(1)
+            .ref_("main")
+            .file_path("file.txt")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectSnippetFileRaw::builder()
+            .project(0)
+            .snippet(1)
+            .ref_("main")
+            .file_path("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectSnippetFileRawBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1/files/main/path%2Fto%2Ffile.txt/raw")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSnippetFileRaw::builder()
+            .project("simple/project")
+            .snippet(1)
+            .ref_("main")
+            .file_path("path/to/file.txt")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
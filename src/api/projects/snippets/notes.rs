@@ -0,0 +1,39 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project snippet note API endpoints.
+//!
+//! These endpoints are used for querying and managing project snippet notes.
+
+mod create;
+mod delete;
+mod edit;
+mod note;
+mod notes;
+
+pub use self::create::CreateProjectSnippetNote;
+pub use self::create::CreateProjectSnippetNoteBuilder;
+pub use self::create::CreateProjectSnippetNoteBuilderError;
+
+pub use self::delete::DeleteProjectSnippetNote;
+pub use self::delete::DeleteProjectSnippetNoteBuilder;
+pub use self::delete::DeleteProjectSnippetNoteBuilderError;
+
+pub use self::edit::EditProjectSnippetNote;
+pub use self::edit::EditProjectSnippetNoteBuilder;
+pub use self::edit::EditProjectSnippetNoteBuilderError;
+
+pub use self::note::ProjectSnippetNote;
+pub use self::note::ProjectSnippetNoteBuilder;
+pub use self::note::ProjectSnippetNoteBuilderError;
+
+pub use self::notes::ProjectSnippetNotes;
+pub use self::notes::ProjectSnippetNotesBuilder;
+pub use self::notes::ProjectSnippetNotesBuilderError;
+pub use crate::api::helpers::filter_notes;
+pub use crate::api::helpers::FilteredNotes;
+pub use crate::api::helpers::NoteActivityFilter;
+pub use crate::api::helpers::NoteOrderBy;
@@ -0,0 +1,184 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Create a new note on a project snippet.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateProjectSnippetNote<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+    /// The content of the note.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+
+    /// The creation date of the note.
+    ///
+    /// Requires administrator or owner permissions.
+    #[builder(default)]
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> CreateProjectSnippetNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateProjectSnippetNoteBuilder<'a> {
+        CreateProjectSnippetNoteBuilder::default()
+    }
+}
+
+impl<'a> CreateProjectSnippetNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateProjectSnippetNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateProjectSnippetNote<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/notes",
+            self.project.path_segment(),
+            self.snippet.path_segment()
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("body", self.body.as_ref())
+            .push_opt("created_at", self.created_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::snippets::notes::{
+        CreateProjectSnippetNote, CreateProjectSnippetNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_snippet_and_body_are_necessary() {
+        let err = CreateProjectSnippetNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateProjectSnippetNote::builder()
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = CreateProjectSnippetNote::builder()
+            .project(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectSnippetNoteBuilderError, "snippet");
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = CreateProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectSnippetNoteBuilderError, "body");
+    }
+
+    #[test]
+    fn project_snippet_and_body_are_sufficient() {
+        CreateProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateProjectSnippetNote::builder()
+            .project(0)
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreateProjectSnippetNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/snippets/1/notes")
+            .form_params(&[("body", "body")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectSnippetNote::builder()
+            .project("simple/project")
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/snippets/1/notes")
+            .form_params(&[("body", "body"), ("created_at", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectSnippetNote::builder()
+            .project("simple/project")
+            .snippet(1)
+            .body("body")
+            .created_at(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a note on a project snippet.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteProjectSnippetNote<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> DeleteProjectSnippetNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteProjectSnippetNoteBuilder<'a> {
+        DeleteProjectSnippetNoteBuilder::default()
+    }
+}
+
+impl<'a> DeleteProjectSnippetNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), DeleteProjectSnippetNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for DeleteProjectSnippetNote<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/notes/{}",
+            self.project.path_segment(),
+            self.snippet.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::snippets::notes::{
+        DeleteProjectSnippetNote, DeleteProjectSnippetNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_snippet_and_note_are_necessary() {
+        let err = DeleteProjectSnippetNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = DeleteProjectSnippetNote::builder()
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = DeleteProjectSnippetNote::builder()
+            .project(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectSnippetNoteBuilderError, "snippet");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = DeleteProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteProjectSnippetNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn project_snippet_and_note_are_sufficient() {
+        DeleteProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = DeleteProjectSnippetNote::builder()
+            .project(0)
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            DeleteProjectSnippetNoteBuilderError,
+            "project"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/snippets/1/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteProjectSnippetNote::builder()
+            .project("simple/project")
+            .snippet(1)
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Edit a note on a project snippet.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct EditProjectSnippetNote<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+    /// The ID of the note.
+    note: u64,
+
+    /// The content of the note.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+}
+
+impl<'a> EditProjectSnippetNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditProjectSnippetNoteBuilder<'a> {
+        EditProjectSnippetNoteBuilder::default()
+    }
+}
+
+impl<'a> EditProjectSnippetNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), EditProjectSnippetNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for EditProjectSnippetNote<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/notes/{}",
+            self.project.path_segment(),
+            self.snippet.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("body", self.body.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::snippets::notes::{
+        EditProjectSnippetNote, EditProjectSnippetNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_snippet_note_and_body_are_necessary() {
+        let err = EditProjectSnippetNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = EditProjectSnippetNote::builder()
+            .snippet(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = EditProjectSnippetNote::builder()
+            .project(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectSnippetNoteBuilderError, "snippet");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = EditProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectSnippetNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = EditProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditProjectSnippetNoteBuilderError, "body");
+    }
+
+    #[test]
+    fn project_snippet_note_and_body_are_sufficient() {
+        EditProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = EditProjectSnippetNote::builder()
+            .project(0)
+            .snippet(1)
+            .note(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, EditProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/snippets/1/notes/2")
+            .form_params(&[("body", "body")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProjectSnippetNote::builder()
+            .project("simple/project")
+            .snippet(1)
+            .note(2)
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query a single note on a project snippet.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectSnippetNote<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+    /// The ID of the note.
+    note: u64,
+}
+
+impl<'a> ProjectSnippetNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectSnippetNoteBuilder<'a> {
+        ProjectSnippetNoteBuilder::default()
+    }
+}
+
+impl<'a> ProjectSnippetNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectSnippetNoteBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+        common::validate_path_param("note", self.note.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectSnippetNote<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/notes/{}",
+            self.project.path_segment(),
+            self.snippet.path_segment(),
+            self.note.path_segment(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::snippets::notes::{
+        ProjectSnippetNote, ProjectSnippetNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_snippet_and_note_are_necessary() {
+        let err = ProjectSnippetNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProjectSnippetNote::builder()
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = ProjectSnippetNote::builder()
+            .project(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNoteBuilderError, "snippet");
+    }
+
+    #[test]
+    fn note_is_necessary() {
+        let err = ProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNoteBuilderError, "note");
+    }
+
+    #[test]
+    fn project_snippet_and_note_are_sufficient() {
+        ProjectSnippetNote::builder()
+            .project(1)
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectSnippetNote::builder()
+            .project(0)
+            .snippet(1)
+            .note(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectSnippetNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1/notes/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSnippetNote::builder()
+            .project("simple/project")
+            .snippet(1)
+            .note(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,177 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::helpers::NoteOrderBy;
+
+/// Query for notes on a project snippet.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectSnippetNotes<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<NoteOrderBy>,
+    /// The sort order for returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> ProjectSnippetNotes<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectSnippetNotesBuilder<'a> {
+        ProjectSnippetNotesBuilder::default()
+    }
+}
+
+impl<'a> ProjectSnippetNotesBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectSnippetNotesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectSnippetNotes<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/notes",
+            self.project.path_segment(),
+            self.snippet.path_segment()
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectSnippetNotes<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::projects::snippets::notes::{
+        NoteOrderBy, ProjectSnippetNotes, ProjectSnippetNotesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_snippet_are_necessary() {
+        let err = ProjectSnippetNotes::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNotesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = ProjectSnippetNotes::builder()
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNotesBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = ProjectSnippetNotes::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetNotesBuilderError, "snippet");
+    }
+
+    #[test]
+    fn project_and_snippet_are_sufficient() {
+        ProjectSnippetNotes::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectSnippetNotes::builder()
+            .project(0)
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectSnippetNotesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1/notes")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSnippetNotes::builder()
+            .project("simple/project")
+            .snippet(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1/notes")
+            .add_query_params(&[("order_by", "updated_at")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSnippetNotes::builder()
+            .project("simple/project")
+            .snippet(1)
+            .order_by(NoteOrderBy::UpdatedAt)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1/notes")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSnippetNotes::builder()
+            .project("simple/project")
+            .snippet(1)
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for a snippet within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectSnippet<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+}
+
+impl<'a> ProjectSnippet<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectSnippetBuilder<'a> {
+        ProjectSnippetBuilder::default()
+    }
+}
+
+impl<'a> ProjectSnippetBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectSnippetBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("snippet", self.snippet.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectSnippet<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}",
+            self.project.path_segment(),
+            self.snippet.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::snippets::{ProjectSnippet, ProjectSnippetBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_snippet_are_needed() {
+        let err = ProjectSnippet::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectSnippet::builder().snippet(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_needed() {
+        let err = ProjectSnippet::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSnippetBuilderError, "snippet");
+    }
+
+    #[test]
+    fn project_and_snippet_are_sufficient() {
+        ProjectSnippet::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectSnippet::builder()
+            .project(0)
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectSnippetBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSnippet::builder()
+            .project("simple/project")
+            .snippet(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
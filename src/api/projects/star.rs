@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Star a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct StarProject<'a> {
+    /// The project to star.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> StarProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StarProjectBuilder<'a> {
+        StarProjectBuilder::default()
+    }
+}
+
+impl<'a> StarProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), StarProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for StarProject<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/star", self.project.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{StarProject, StarProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = StarProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, StarProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        StarProject::builder().project("project").build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = StarProject::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, StarProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/project%2Fsubproject/star")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StarProject::builder()
+            .project("project/subproject")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,22 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project template API endpoints.
+//!
+//! These endpoints are used for querying templates available to a project, including issue and
+//! merge request description templates.
+
+mod template;
+mod templates;
+
+pub use self::template::ProjectTemplate;
+pub use self::template::ProjectTemplateBuilder;
+pub use self::template::ProjectTemplateBuilderError;
+
+pub use self::templates::ProjectTemplateType;
+pub use self::templates::ProjectTemplates;
+pub use self::templates::ProjectTemplatesBuilder;
+pub use self::templates::ProjectTemplatesBuilderError;
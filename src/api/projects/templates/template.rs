@@ -0,0 +1,223 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::templates::ProjectTemplateType;
+
+/// Query for a single template available to a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectTemplate<'a> {
+    /// The project to query for the template.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The type of the template.
+    type_: ProjectTemplateType,
+    /// The key of the template.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The ID of the project in which the template is stored.
+    ///
+    /// Useful when templates with the same name exist in multiple projects.
+    #[builder(default)]
+    source_template_project_id: Option<u64>,
+    /// The project name to substitute into a license template.
+    #[builder(setter(into), default)]
+    license_project: Option<Cow<'a, str>>,
+    /// The full name of the copyright holder to substitute into a license template.
+    #[builder(setter(into), default)]
+    fullname: Option<Cow<'a, str>>,
+}
+
+impl<'a> ProjectTemplate<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectTemplateBuilder<'a> {
+        ProjectTemplateBuilder::default()
+    }
+}
+
+impl<'a> ProjectTemplateBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectTemplateBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("name", self.name.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectTemplate<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/templates/{}/{}",
+            self.project.path_segment(),
+            self.type_.as_str(),
+            self.name.path_segment(),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt(
+                "source_template_project_id",
+                self.source_template_project_id,
+            )
+            .push_opt("project", self.license_project.as_ref())
+            .push_opt("fullname", self.fullname.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::templates::{
+        ProjectTemplate, ProjectTemplateBuilderError, ProjectTemplateType,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_type_and_name_are_needed() {
+        let err = ProjectTemplate::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplateBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectTemplate::builder()
+            .type_(ProjectTemplateType::Issues)
+            .name("bug")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplateBuilderError, "project");
+    }
+
+    #[test]
+    fn type_is_needed() {
+        let err = ProjectTemplate::builder()
+            .project(1)
+            .name("bug")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplateBuilderError, "type_");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = ProjectTemplate::builder()
+            .project(1)
+            .type_(ProjectTemplateType::Issues)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplateBuilderError, "name");
+    }
+
+    #[test]
+    fn project_type_and_name_are_sufficient() {
+        ProjectTemplate::builder()
+            .project(1)
+            .type_(ProjectTemplateType::Issues)
+            .name("bug")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectTemplate::builder()
+            .project(0)
+            .type_(ProjectTemplateType::Issues)
+            .name("bug")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectTemplateBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/templates/issues/Bug%20Report")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectTemplate::builder()
+            .project("simple/project")
+            .type_(ProjectTemplateType::Issues)
+            .name("Bug Report")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_source_template_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/templates/merge_requests/default")
+            .add_query_params(&[("source_template_project_id", "10")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectTemplate::builder()
+            .project("simple/project")
+            .type_(ProjectTemplateType::MergeRequests)
+            .name("default")
+            .source_template_project_id(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_license_project() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/templates/licenses/mit")
+            .add_query_params(&[("project", "My Project")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectTemplate::builder()
+            .project("simple/project")
+            .type_(ProjectTemplateType::Licenses)
+            .name("mit")
+            .license_project("My Project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_fullname() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/templates/licenses/mit")
+            .add_query_params(&[("fullname", "A. Person")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectTemplate::builder()
+            .project("simple/project")
+            .type_(ProjectTemplateType::Licenses)
+            .name("mit")
+            .fullname("A. Person")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
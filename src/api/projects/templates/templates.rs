@@ -0,0 +1,166 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Types of templates available to a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProjectTemplateType {
+    /// `Dockerfile` templates.
+    Dockerfiles,
+    /// `.gitignore` templates.
+    Gitignores,
+    /// `.gitlab-ci.yml` templates.
+    GitlabCiYmls,
+    /// License templates.
+    Licenses,
+    /// Issue description templates.
+    Issues,
+    /// Merge request description templates.
+    MergeRequests,
+}
+
+impl ProjectTemplateType {
+    /// The template type as a path component.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            ProjectTemplateType::Dockerfiles => "dockerfiles",
+            ProjectTemplateType::Gitignores => "gitignores",
+            ProjectTemplateType::GitlabCiYmls => "gitlab_ci_ymls",
+            ProjectTemplateType::Licenses => "licenses",
+            ProjectTemplateType::Issues => "issues",
+            ProjectTemplateType::MergeRequests => "merge_requests",
+        }
+    }
+}
+
+/// Query for templates of a given type available to a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectTemplates<'a> {
+    /// The project to query for templates.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The type of template to list.
+    type_: ProjectTemplateType,
+}
+
+impl<'a> ProjectTemplates<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectTemplatesBuilder<'a> {
+        ProjectTemplatesBuilder::default()
+    }
+}
+
+impl<'a> ProjectTemplatesBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectTemplatesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectTemplates<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/templates/{}",
+            self.project.path_segment(),
+            self.type_.as_str()
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for ProjectTemplates<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::templates::{
+        ProjectTemplateType, ProjectTemplates, ProjectTemplatesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_template_type_as_str() {
+        let items = &[
+            (ProjectTemplateType::Dockerfiles, "dockerfiles"),
+            (ProjectTemplateType::Gitignores, "gitignores"),
+            (ProjectTemplateType::GitlabCiYmls, "gitlab_ci_ymls"),
+            (ProjectTemplateType::Licenses, "licenses"),
+            (ProjectTemplateType::Issues, "issues"),
+            (ProjectTemplateType::MergeRequests, "merge_requests"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_and_type_are_needed() {
+        let err = ProjectTemplates::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplatesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectTemplates::builder()
+            .type_(ProjectTemplateType::Issues)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplatesBuilderError, "project");
+    }
+
+    #[test]
+    fn type_is_needed() {
+        let err = ProjectTemplates::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectTemplatesBuilderError, "type_");
+    }
+
+    #[test]
+    fn project_and_type_are_sufficient() {
+        ProjectTemplates::builder()
+            .project(1)
+            .type_(ProjectTemplateType::Issues)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectTemplates::builder()
+            .project(0)
+            .type_(ProjectTemplateType::Issues)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectTemplatesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/templates/merge_requests")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectTemplates::builder()
+            .project("simple/project")
+            .type_(ProjectTemplateType::MergeRequests)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
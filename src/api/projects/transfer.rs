@@ -0,0 +1,145 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Transfer a project to a new namespace.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct TransferProject<'a> {
+    /// The project to transfer.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The namespace to transfer the project to.
+    #[builder(setter(into))]
+    namespace: NameOrId<'a>,
+}
+
+impl<'a> TransferProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TransferProjectBuilder<'a> {
+        TransferProjectBuilder::default()
+    }
+}
+
+impl<'a> TransferProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), TransferProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for TransferProject<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/transfer", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("namespace", &self.namespace);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{TransferProject, TransferProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_namespace_are_necessary() {
+        let err = TransferProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = TransferProject::builder()
+            .namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn namespace_is_necessary() {
+        let err = TransferProject::builder()
+            .project("project")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TransferProjectBuilderError, "namespace");
+    }
+
+    #[test]
+    fn project_and_namespace_are_sufficient() {
+        TransferProject::builder()
+            .project("project")
+            .namespace("group")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = TransferProject::builder()
+            .project(0)
+            .namespace("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, TransferProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/project%2Fsubproject/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace=new%2Fgroup")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferProject::builder()
+            .project("project/subproject")
+            .namespace("new/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/project%2Fsubproject/transfer")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TransferProject::builder()
+            .project("project/subproject")
+            .namespace(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
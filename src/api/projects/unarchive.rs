@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Ununarchives the project if the user is either an administrator or the owner of this project.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnarchiveProject<'a> {
     /// The project to unarchive.
     #[builder(setter(into))]
@@ -24,13 +25,21 @@ impl<'a> UnarchiveProject<'a> {
     }
 }
 
+impl<'a> UnarchiveProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), UnarchiveProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UnarchiveProject<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/unarchive", self.project).into()
+        format!("projects/{}/unarchive", self.project.path_segment()).into()
     }
 }
 
@@ -56,6 +65,12 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UnarchiveProject::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnarchiveProjectBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,13 +6,14 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Unshare a project with a group.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnshareProject<'a> {
-    /// The project to add the user to.
+    /// The project to stop sharing.
     #[builder(setter(into))]
     project: NameOrId<'a>,
     /// The group to remove from the project.
@@ -26,13 +27,27 @@ impl<'a> UnshareProject<'a> {
     }
 }
 
+impl<'a> UnshareProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), UnshareProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("group_id", self.group_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UnshareProject<'a> {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/share/{}", self.project, self.group_id).into()
+        format!(
+            "projects/{}/share/{}",
+            self.project.path_segment(),
+            self.group_id.path_segment(),
+        )
+        .into()
     }
 }
 
@@ -65,6 +80,26 @@ mod tests {
         crate::test::assert_missing_field!(err, UnshareProjectBuilderError, "group_id");
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UnshareProject::builder()
+            .project(0)
+            .group_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnshareProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn group_id_must_be_valid() {
+        let err = UnshareProject::builder()
+            .project("project")
+            .group_id(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnshareProjectBuilderError, "group_id");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Unstar a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UnstarProject<'a> {
+    /// The project to unstar.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> UnstarProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnstarProjectBuilder<'a> {
+        UnstarProjectBuilder::default()
+    }
+}
+
+impl<'a> UnstarProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), UnstarProjectBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UnstarProject<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/unstar", self.project.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{UnstarProject, UnstarProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_necessary() {
+        let err = UnstarProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnstarProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        UnstarProject::builder().project("project").build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = UnstarProject::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UnstarProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/project%2Fsubproject/unstar")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnstarProject::builder()
+            .project("project/subproject")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::NameOrId;
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -43,7 +43,7 @@ impl ParamValue<'static> for ProjectVariableType {
 
 /// Add a variable to a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateProjectVariable<'a> {
     /// The project to add the variable to.
     #[builder(setter(into))]
@@ -81,13 +81,21 @@ impl<'a> CreateProjectVariable<'a> {
     }
 }
 
+impl<'a> CreateProjectVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateProjectVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for CreateProjectVariable<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("projects/{}/variables", self.project).into()
+        format!("projects/{}/variables", self.project.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -175,6 +183,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = CreateProjectVariable::builder()
+            .project(0)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateProjectVariableBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
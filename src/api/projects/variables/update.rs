@@ -6,13 +6,13 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::projects::variables::{ProjectVariableFilter, ProjectVariableType};
 
 /// Edit a variable of a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UpdateProjectVariable<'a> {
     /// The project to edit the variable on.
     #[builder(setter(into))]
@@ -53,6 +53,15 @@ impl<'a> UpdateProjectVariable<'a> {
     }
 }
 
+impl<'a> UpdateProjectVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), UpdateProjectVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UpdateProjectVariable<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -61,8 +70,8 @@ impl<'a> Endpoint for UpdateProjectVariable<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/variables/{}",
-            self.project,
-            common::path_escaped(&self.key),
+            self.project.path_segment(),
+            self.key.path_segment(),
         )
         .into()
     }
@@ -144,6 +153,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = UpdateProjectVariable::builder()
+            .project(0)
+            .key("testkey")
+            .value("testvalue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UpdateProjectVariableBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
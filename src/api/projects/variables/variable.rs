@@ -6,7 +6,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{self, NameOrId};
+use crate::api::common::{self, NameOrId, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Filter parameters.
@@ -25,13 +25,16 @@ impl<'a> ProjectVariableFilter<'a> {
     }
 
     pub(crate) fn add_query<'b>(&'b self, params: &mut FormParams<'b>) {
-        params.push_opt("filter[environment_scope]", self.environment_scope.as_ref());
+        params.push_opt(
+            ParamName::new("filter").key("environment_scope"),
+            self.environment_scope.as_ref(),
+        );
     }
 }
 
 /// Get the variable from a project.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectVariable<'a> {
     /// The project to get the variable from.
     #[builder(setter(into))]
@@ -51,6 +54,15 @@ impl<'a> ProjectVariable<'a> {
     }
 }
 
+impl<'a> ProjectVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectVariableBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+        common::validate_path_param("key", self.key.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for ProjectVariable<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -59,8 +71,8 @@ impl<'a> Endpoint for ProjectVariable<'a> {
     fn endpoint(&self) -> Cow<'static, str> {
         format!(
             "projects/{}/variables/{}",
-            self.project,
-            common::path_escaped(self.key.as_ref()),
+            self.project.path_segment(),
+            self.key.path_segment(),
         )
         .into()
     }
@@ -116,6 +128,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectVariable::builder()
+            .project(0)
+            .key("testkey")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectVariableBuilderError, "project");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
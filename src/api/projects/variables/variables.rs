@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the variables of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectVariables<'a> {
+    /// The project to query for variables.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectVariables<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectVariablesBuilder<'a> {
+        ProjectVariablesBuilder::default()
+    }
+}
+
+impl<'a> ProjectVariablesBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectVariablesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectVariables<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/variables", self.project.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for ProjectVariables<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::variables::{ProjectVariables, ProjectVariablesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectVariables::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectVariablesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectVariables::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectVariables::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectVariablesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/variables")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVariables::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for wiki pages within a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct WikiPages<'a> {
+    /// The project to query for wiki pages.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Include the content of the pages.
+    #[builder(default)]
+    with_content: Option<bool>,
+}
+
+impl<'a> WikiPages<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> WikiPagesBuilder<'a> {
+        WikiPagesBuilder::default()
+    }
+}
+
+impl<'a> WikiPagesBuilder<'a> {
+    fn validate(&self) -> Result<(), WikiPagesBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for WikiPages<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/wikis", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("with_content", self.with_content);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::wikis::{WikiPages, WikiPagesBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = WikiPages::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, WikiPagesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        WikiPages::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = WikiPages::builder().project(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, WikiPagesBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/wikis")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPages::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_content() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/wikis")
+            .add_query_params(&[("with_content", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = WikiPages::builder()
+            .project("simple/project")
+            .with_content(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Upload an attachment to the wiki repository of a project.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UploadWikiAttachment<'a> {
+    /// The project to upload the attachment to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the file.
+    #[builder(setter(into))]
+    filename: Cow<'a, str>,
+    /// The contents of the file.
+    #[builder(setter(into))]
+    contents: Cow<'a, [u8]>,
+
+    /// The branch to commit the attachment to.
+    ///
+    /// Defaults to the default branch of the wiki repository.
+    #[builder(setter(into), default)]
+    branch: Option<Cow<'a, str>>,
+}
+
+impl<'a> UploadWikiAttachment<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UploadWikiAttachmentBuilder<'a> {
+        UploadWikiAttachmentBuilder::default()
+    }
+
+    fn params(&self) -> MultipartParams<'_> {
+        let mut params = MultipartParams::default();
+
+        params
+            .push_file("file", self.filename.as_ref(), self.contents.as_ref())
+            .push_opt("branch", self.branch.as_ref());
+
+        params
+    }
+}
+
+impl<'a> UploadWikiAttachmentBuilder<'a> {
+    fn validate(&self) -> Result<(), UploadWikiAttachmentBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UploadWikiAttachment<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/wikis/attachments", self.project.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.params().into_body()
+    }
+
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        self.params().into_spooled_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::wikis::{UploadWikiAttachment, UploadWikiAttachmentBuilderError};
+    use crate::api::{self, Endpoint, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = UploadWikiAttachment::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = UploadWikiAttachment::builder()
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "project");
+    }
+
+    #[test]
+    fn filename_is_needed() {
+        let err = UploadWikiAttachment::builder()
+            .project(1)
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "filename");
+    }
+
+    #[test]
+    fn contents_is_needed() {
+        let err = UploadWikiAttachment::builder()
+            .project(1)
+            .filename("image.png")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadWikiAttachmentBuilderError, "contents");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        UploadWikiAttachment::builder()
+            .project(1)
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = UploadWikiAttachment::builder()
+            .project(0)
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UploadWikiAttachmentBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/wikis/attachments")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"image.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "data\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadWikiAttachment::builder()
+            .project("simple/project")
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_branch() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/wikis/attachments")
+            .content_type("multipart/form-data; boundary=gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr")
+            .body_str(concat!(
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"image.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "data\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr\r\n",
+                "Content-Disposition: form-data; name=\"branch\"\r\n",
+                "\r\n",
+                "main\r\n",
+                "--gitlab-rs-multipart-XbUnOV3Ys0cgoHPj4mtr--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadWikiAttachment::builder()
+            .project("simple/project")
+            .filename("image.png")
+            .contents(&b"data"[..])
+            .branch("main")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn spooled_body_matches_body() {
+        let content = vec![0xfe; 4096];
+        let endpoint = UploadWikiAttachment::builder()
+            .project("simple/project")
+            .filename("image.png")
+            .contents(&content[..])
+            .branch("main")
+            .build()
+            .unwrap();
+
+        let (mime, body) = endpoint.body().unwrap().unwrap();
+        let (spooled_mime, spooled) = endpoint.spooled_body().unwrap().unwrap();
+        assert_eq!(mime, spooled_mime);
+        assert_eq!(body.len() as u64, spooled.len());
+        assert_eq!(body, spooled.into_vec().unwrap());
+    }
+}
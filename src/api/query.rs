@@ -5,10 +5,12 @@
 // except according to those terms.
 
 use async_trait::async_trait;
-use http::Uri;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, Response, Uri};
 use url::Url;
 
-use crate::api::{ApiError, AsyncClient, Client};
+use crate::api::{trace, ApiError, AsyncClient, Client, Endpoint};
 
 pub fn url_to_http_uri(url: Url) -> Uri {
     url.as_str()
@@ -16,6 +18,54 @@ pub fn url_to_http_uri(url: Url) -> Uri {
         .expect("failed to parse a url::Url as an http::Uri")
 }
 
+/// Send a request for an endpoint with its body.
+pub fn rest<E, C>(
+    client: &C,
+    req: RequestBuilder,
+    endpoint: &E,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: Client,
+{
+    if let Some((mime, body)) = endpoint.spooled_body()? {
+        let req = req.header(header::CONTENT_TYPE, mime);
+        return trace::request(req, |req| client.rest_spooled(req, body));
+    }
+
+    let (req, data) = if let Some((mime, data)) = endpoint.body()? {
+        let req = req.header(header::CONTENT_TYPE, mime);
+        (req, data)
+    } else {
+        (req, Vec::new())
+    };
+    trace::request(req, |req| client.rest(req, data))
+}
+
+/// Send a request for an endpoint with its body asynchronously.
+pub async fn rest_async<E, C>(
+    client: &C,
+    req: RequestBuilder,
+    endpoint: &E,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    E: Endpoint + Sync + ?Sized,
+    C: AsyncClient + Sync,
+{
+    if let Some((mime, body)) = endpoint.spooled_body()? {
+        let req = req.header(header::CONTENT_TYPE, mime);
+        return trace::request_async(req, |req| client.rest_spooled_async(req, body)).await;
+    }
+
+    let (req, data) = if let Some((mime, data)) = endpoint.body()? {
+        let req = req.header(header::CONTENT_TYPE, mime);
+        (req, data)
+    } else {
+        (req, Vec::new())
+    };
+    trace::request_async(req, |req| client.rest_async(req, data)).await
+}
+
 /// A trait which represents a query which may be made to a GitLab client.
 pub trait Query<T, C>
 where
@@ -5,7 +5,7 @@
 // except according to those terms.
 
 use async_trait::async_trait;
-use http::{header, Request};
+use http::Request;
 
 use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
 
@@ -37,13 +37,7 @@ where
         let req = Request::builder()
             .method(self.endpoint.method())
             .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest(req, data)?;
+        let rsp = query::rest(client, req, &self.endpoint)?;
         let status = rsp.status();
         if !status.is_success() {
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
@@ -78,13 +72,7 @@ where
         let req = Request::builder()
             .method(self.endpoint.method())
             .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest_async(req, data).await?;
+        let rsp = query::rest_async(client, req, &self.endpoint).await?;
         let status = rsp.status();
         if !status.is_success() {
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
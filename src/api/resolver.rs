@@ -0,0 +1,557 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Path to ID resolution
+//!
+//! Many scripts refer to groups, projects, and users by their human-readable paths. While
+//! endpoints accept paths directly, some parameters (e.g., `target_project_id`) require numeric
+//! IDs. The [`Resolver`] looks up IDs on demand and caches them so that repeated lookups for the
+//! same path do not require additional requests.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::{Mutex, MutexGuard};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::api::groups::Group;
+use crate::api::projects::Project;
+use crate::api::users::Users;
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Query};
+
+/// The default number of paths to keep in the cache.
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+/// Errors which may occur when resolving a path.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ResolveError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The path is empty.
+    #[error("cannot resolve an empty path")]
+    EmptyPath {},
+    /// No user has the requested username.
+    #[error("no user with the username `{}`", username)]
+    UserNotFound {
+        /// The username which was requested.
+        username: String,
+    },
+    /// The API returned an error.
+    #[error("{}", source)]
+    Api {
+        /// The source of the error.
+        #[from]
+        source: ApiError<E>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Group,
+    Project,
+    User,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamespaceInfo {
+    id: u64,
+    full_path: String,
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProjectInfo {
+    id: u64,
+    namespace: Option<NamespaceInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdInfo {
+    id: u64,
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+    entries: HashMap<(Kind, String), (u64, u64)>,
+    tick: u64,
+}
+
+impl Lru {
+    fn get(&mut self, kind: Kind, path: &str) -> Option<u64> {
+        self.tick += 1;
+        let tick = self.tick;
+        self.entries
+            .get_mut(&(kind, path.into()))
+            .map(|(id, used)| {
+                *used = tick;
+                *id
+            })
+    }
+
+    fn insert(&mut self, capacity: usize, kind: Kind, path: String, id: u64) {
+        if capacity == 0 {
+            return;
+        }
+
+        self.tick += 1;
+        let key = (kind, path);
+        if !self.entries.contains_key(&key) && self.entries.len() >= capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (id, self.tick));
+    }
+}
+
+/// A cache of IDs for group, project, and user paths.
+///
+/// Entries are looked up on demand and the least recently used entries are evicted once the cache
+/// is full. Paths are compared exactly, so differently-cased paths are looked up separately.
+#[derive(Debug)]
+pub struct Resolver {
+    capacity: usize,
+    cache: Mutex<Lru>,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver {
+    /// Create a resolver with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Create a resolver which keeps at most `capacity` paths.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            cache: Mutex::new(Lru::default()),
+        }
+    }
+
+    /// The number of cached paths.
+    pub fn len(&self) -> usize {
+        self.cache().entries.len()
+    }
+
+    /// Whether the cache is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached paths.
+    pub fn clear(&self) {
+        *self.cache() = Lru::default();
+    }
+
+    fn cache(&self) -> MutexGuard<Lru> {
+        self.cache
+            .lock()
+            .expect("poisoned lock on the resolver cache")
+    }
+
+    fn cached<E>(&self, kind: Kind, path: &str) -> Result<Option<u64>, ResolveError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        if path.is_empty() {
+            return Err(ResolveError::EmptyPath {});
+        }
+
+        Ok(self.cache().get(kind, path))
+    }
+
+    fn store(&self, kind: Kind, path: &str, id: u64) {
+        self.cache().insert(self.capacity, kind, path.into(), id);
+    }
+
+    fn store_project(&self, path: &str, project: ProjectInfo) -> u64 {
+        let mut cache = self.cache();
+        if let Some(namespace) = project.namespace {
+            if namespace.kind == "group" {
+                cache.insert(
+                    self.capacity,
+                    Kind::Group,
+                    namespace.full_path,
+                    namespace.id,
+                );
+            }
+        }
+        cache.insert(self.capacity, Kind::Project, path.into(), project.id);
+
+        project.id
+    }
+
+    fn store_user<E>(&self, username: &str, users: Vec<IdInfo>) -> Result<u64, ResolveError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let user = users.into_iter().next().ok_or_else(|| {
+            ResolveError::UserNotFound {
+                username: username.into(),
+            }
+        })?;
+        self.store(Kind::User, username, user.id);
+
+        Ok(user.id)
+    }
+
+    fn group_endpoint<E>(path: &str) -> Result<Group, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Group::builder()
+            .group(path)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn project_endpoint<E>(path: &str) -> Result<Project, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Project::builder()
+            .project(path)
+            .build()
+            .map_err(ApiError::invalid_endpoint)
+    }
+
+    fn users_endpoint(username: &str) -> Users {
+        Users::builder().username(username).build().unwrap()
+    }
+
+    /// Resolve the ID of a group from its full path.
+    pub fn group_id<C>(&self, client: &C, path: &str) -> Result<u64, ResolveError<C::Error>>
+    where
+        C: Client,
+    {
+        if let Some(id) = self.cached(Kind::Group, path)? {
+            return Ok(id);
+        }
+
+        let group: IdInfo = Self::group_endpoint(path)?.query(client)?;
+        self.store(Kind::Group, path, group.id);
+
+        Ok(group.id)
+    }
+
+    /// Resolve the ID of a group from its full path asynchronously.
+    pub async fn group_id_async<C>(
+        &self,
+        client: &C,
+        path: &str,
+    ) -> Result<u64, ResolveError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        if let Some(id) = self.cached(Kind::Group, path)? {
+            return Ok(id);
+        }
+
+        let group: IdInfo = Self::group_endpoint(path)?.query_async(client).await?;
+        self.store(Kind::Group, path, group.id);
+
+        Ok(group.id)
+    }
+
+    /// Resolve the ID of a project from its full path.
+    ///
+    /// The ID of the project's group is cached as well.
+    pub fn project_id<C>(&self, client: &C, path: &str) -> Result<u64, ResolveError<C::Error>>
+    where
+        C: Client,
+    {
+        if let Some(id) = self.cached(Kind::Project, path)? {
+            return Ok(id);
+        }
+
+        let project = Self::project_endpoint(path)?.query(client)?;
+
+        Ok(self.store_project(path, project))
+    }
+
+    /// Resolve the ID of a project from its full path asynchronously.
+    ///
+    /// The ID of the project's group is cached as well.
+    pub async fn project_id_async<C>(
+        &self,
+        client: &C,
+        path: &str,
+    ) -> Result<u64, ResolveError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        if let Some(id) = self.cached(Kind::Project, path)? {
+            return Ok(id);
+        }
+
+        let project = Self::project_endpoint(path)?.query_async(client).await?;
+
+        Ok(self.store_project(path, project))
+    }
+
+    /// Resolve the ID of a user from their username.
+    pub fn user_id<C>(&self, client: &C, username: &str) -> Result<u64, ResolveError<C::Error>>
+    where
+        C: Client,
+    {
+        if let Some(id) = self.cached(Kind::User, username)? {
+            return Ok(id);
+        }
+
+        let users = Self::users_endpoint(username).query(client)?;
+
+        self.store_user(username, users)
+    }
+
+    /// Resolve the ID of a user from their username asynchronously.
+    pub async fn user_id_async<C>(
+        &self,
+        client: &C,
+        username: &str,
+    ) -> Result<u64, ResolveError<C::Error>>
+    where
+        C: AsyncClient + Sync,
+    {
+        if let Some(id) = self.cached(Kind::User, username)? {
+            return Ok(id);
+        }
+
+        let users = Self::users_endpoint(username).query_async(client).await?;
+
+        self.store_user(username, users)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::resolver::{ResolveError, Resolver};
+    use crate::api::ApiError;
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    #[test]
+    fn group_id_is_cached() {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("groups/group%2Fsubgroup")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({"id": 4})),
+        );
+
+        let resolver = Resolver::new();
+        assert_eq!(resolver.group_id(&client, "group/subgroup").unwrap(), 4);
+        assert_eq!(resolver.group_id(&client, "group/subgroup").unwrap(), 4);
+        assert_eq!(resolver.len(), 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn project_id_caches_namespace() {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("projects/group%2Fproject")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({
+                "id": 7,
+                "namespace": {
+                    "id": 3,
+                    "full_path": "group",
+                    "kind": "group",
+                },
+            })),
+        );
+
+        let resolver = Resolver::new();
+        assert_eq!(resolver.project_id(&client, "group/project").unwrap(), 7);
+        assert_eq!(resolver.project_id(&client, "group/project").unwrap(), 7);
+        assert_eq!(resolver.group_id(&client, "group").unwrap(), 3);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn project_id_user_namespace() {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("projects/user%2Fproject")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({
+                "id": 7,
+                "namespace": {
+                    "id": 3,
+                    "full_path": "user",
+                    "kind": "user",
+                },
+            })),
+        );
+
+        let resolver = Resolver::new();
+        assert_eq!(resolver.project_id(&client, "user/project").unwrap(), 7);
+        assert_eq!(resolver.len(), 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn user_id() {
+        let client = MockClient::new();
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("users")
+                    .query_param("username", "user")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!([{"id": 2}])),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("users")
+                    .query_param("username", "nobody")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!([])),
+            );
+
+        let resolver = Resolver::new();
+        assert_eq!(resolver.user_id(&client, "user").unwrap(), 2);
+        assert_eq!(resolver.user_id(&client, "user").unwrap(), 2);
+        let err = resolver.user_id(&client, "nobody").unwrap_err();
+        if let ResolveError::UserNotFound {
+            username,
+        } = err
+        {
+            assert_eq!(username, "nobody");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn empty_path() {
+        let client = MockClient::new();
+
+        let resolver = Resolver::new();
+        let err = resolver.group_id(&client, "").unwrap_err();
+        if let ResolveError::EmptyPath {} = err {
+            // expected
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let client = MockClient::new();
+        client
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("groups/group")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"message": "404 Group Not Found"}))
+                    .status(StatusCode::NOT_FOUND),
+            )
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("groups/group")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"id": 1})),
+            );
+
+        let resolver = Resolver::new();
+        let err = resolver.group_id(&client, "group").unwrap_err();
+        if let ResolveError::Api {
+            source: ApiError::Gitlab {
+                msg,
+            },
+        } = err
+        {
+            assert_eq!(msg, "404 Group Not Found");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert!(resolver.is_empty());
+        assert_eq!(resolver.group_id(&client, "group").unwrap(), 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn least_recently_used_is_evicted() {
+        let client = MockClient::new();
+        for (path, id) in [("a", 1), ("b", 2), ("a", 1)] {
+            client.expect(
+                ExpectedRequest::builder()
+                    .endpoint(format!("groups/{}", path))
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({ "id": id })),
+            );
+        }
+
+        let resolver = Resolver::with_capacity(1);
+        assert_eq!(resolver.group_id(&client, "a").unwrap(), 1);
+        assert_eq!(resolver.group_id(&client, "b").unwrap(), 2);
+        assert_eq!(resolver.len(), 1);
+        assert_eq!(resolver.group_id(&client, "b").unwrap(), 2);
+        assert_eq!(resolver.group_id(&client, "a").unwrap(), 1);
+
+        resolver.clear();
+        assert!(resolver.is_empty());
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn project_id_async() {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("projects/group%2Fproject")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({"id": 7, "namespace": null})),
+        );
+
+        let resolver = Resolver::new();
+        assert_eq!(
+            resolver
+                .project_id_async(&client, "group/project")
+                .await
+                .unwrap(),
+            7,
+        );
+        assert_eq!(
+            resolver
+                .project_id_async(&client, "group/project")
+                .await
+                .unwrap(),
+            7,
+        );
+        client.assert_all_consumed();
+    }
+}
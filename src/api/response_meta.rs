@@ -0,0 +1,332 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{header, HeaderMap, Request, Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
+
+/// A query modifier that returns response metadata along with the data from the endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithResponseMeta<E> {
+    endpoint: E,
+}
+
+/// Return the response metadata along with the data from the endpoint.
+pub fn with_response_meta<E>(endpoint: E) -> WithResponseMeta<E> {
+    WithResponseMeta {
+        endpoint,
+    }
+}
+
+/// Data from an endpoint along with metadata from the response.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta<T> {
+    data: T,
+    status: StatusCode,
+    headers: HeaderMap,
+}
+
+impl<T> ResponseMeta<T> {
+    /// The data returned by the endpoint.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// Extract the data returned by the endpoint.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// The status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The value of a header, if it is present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    fn header_u64(&self, name: &str) -> Option<u64> {
+        self.header(name).and_then(|value| value.parse().ok())
+    }
+
+    /// The total number of items for a paginated endpoint.
+    ///
+    /// GitLab omits this for large collections.
+    pub fn total(&self) -> Option<u64> {
+        self.header_u64("x-total")
+    }
+
+    /// The total number of pages for a paginated endpoint.
+    ///
+    /// GitLab omits this for large collections.
+    pub fn total_pages(&self) -> Option<u64> {
+        self.header_u64("x-total-pages")
+    }
+
+    /// The next page for a paginated endpoint.
+    pub fn next_page(&self) -> Option<u64> {
+        self.header_u64("x-next-page")
+    }
+
+    /// The `ETag` of the response.
+    pub fn etag(&self) -> Option<&str> {
+        self.header(header::ETAG.as_str())
+    }
+}
+
+fn response_meta<T, E>(rsp: Response<Bytes>) -> Result<ResponseMeta<T>, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.body()));
+    };
+    if !status.is_success() {
+        return Err(ApiError::from_gitlab(v));
+    } else if status == StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(header::LOCATION),
+        ));
+    }
+
+    let data = serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)?;
+    let (parts, _) = rsp.into_parts();
+
+    Ok(ResponseMeta {
+        data,
+        status,
+        headers: parts.headers,
+    })
+}
+
+impl<E, T, C> Query<ResponseMeta<T>, C> for WithResponseMeta<E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<ResponseMeta<T>, ApiError<C::Error>> {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        let rsp = query::rest(client, req, &self.endpoint)?;
+
+        response_meta(rsp)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<ResponseMeta<T>, C> for WithResponseMeta<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<ResponseMeta<T>, ApiError<C::Error>> {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        let rsp = query::rest_async(client, req, &self.endpoint).await?;
+
+        response_meta(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{header, HeaderName, HeaderValue, StatusCode};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query, ResponseMeta};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    fn mock_client() -> MockClient {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("dummy")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({"value": 0}))
+                .header(
+                    HeaderName::from_static("x-total"),
+                    HeaderValue::from_static("42"),
+                )
+                .header(
+                    HeaderName::from_static("x-total-pages"),
+                    HeaderValue::from_static("3"),
+                )
+                .header(
+                    HeaderName::from_static("x-next-page"),
+                    HeaderValue::from_static("2"),
+                )
+                .header(header::ETAG, HeaderValue::from_static("W/\"abc\"")),
+        );
+        client
+    }
+
+    fn check(meta: ResponseMeta<DummyResult>) {
+        assert_eq!(meta.status(), StatusCode::OK);
+        assert_eq!(meta.total(), Some(42));
+        assert_eq!(meta.total_pages(), Some(3));
+        assert_eq!(meta.next_page(), Some(2));
+        assert_eq!(meta.etag(), Some("W/\"abc\""));
+        assert_eq!(meta.header("x-total"), Some("42"));
+        assert_eq!(meta.header("x-missing"), None);
+        assert_eq!(meta.data().value, 0);
+        assert_eq!(meta.into_data().value, 0);
+    }
+
+    #[test]
+    fn test_gitlab_with_response_meta() {
+        let client = mock_client();
+
+        let meta: ResponseMeta<DummyResult> =
+            api::with_response_meta(Dummy).query(&client).unwrap();
+        check(meta);
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_with_response_meta_async() {
+        let client = mock_client();
+
+        let meta: ResponseMeta<DummyResult> = api::with_response_meta(Dummy)
+            .query_async(&client)
+            .await
+            .unwrap();
+        check(meta);
+    }
+
+    #[test]
+    fn test_gitlab_with_response_meta_value() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!({"value": 0}));
+
+        let meta: ResponseMeta<serde_json::Value> =
+            api::with_response_meta(Dummy).query(&client).unwrap();
+        assert_eq!(meta.total(), None);
+        assert_eq!(meta.etag(), None);
+        assert_eq!(meta.into_data(), json!({"value": 0}));
+    }
+
+    #[test]
+    fn test_gitlab_with_response_meta_non_json_response() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "not json");
+
+        let res: Result<ResponseMeta<DummyResult>, _> =
+            api::with_response_meta(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::GitlabService {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusCode::OK);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_with_response_meta_error_message() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<ResponseMeta<DummyResult>, _> =
+            api::with_response_meta(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_gitlab_with_response_meta_bad_deserialization() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "not_value": 0,
+            }),
+        );
+
+        let res: Result<ResponseMeta<DummyResult>, _> =
+            api::with_response_meta(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::DataType {
+            source,
+            typename,
+        } = err
+        {
+            assert_eq!(format!("{}", source), "missing field `value`");
+            assert_eq!(typename, "gitlab::api::response_meta::tests::DummyResult");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use derive_builder::Builder;
 use thiserror::Error;
 
 use crate::api;
+use crate::api::instance::Capabilities;
 
 /// Parameters for retrying queries with an exponential backoff.
 #[derive(Debug, Builder, Clone)]
@@ -71,10 +72,12 @@ impl Backoff {
     {
         iter::repeat(())
             .take(self.limit)
-            .scan(self.init, |timeout, _| {
+            .enumerate()
+            .scan(self.init, |timeout, (attempt, _)| {
                 match tryf() {
                     Ok(rsp) => {
                         if rsp.status().is_server_error() {
+                            api::trace::retry(attempt + 1, *timeout);
                             thread::sleep(*timeout);
                             *timeout = timeout.mul_f64(self.scale);
                             Some(None)
@@ -84,6 +87,7 @@ impl Backoff {
                     },
                     Err(err) => {
                         if should_backoff(&err) {
+                            api::trace::retry(attempt + 1, *timeout);
                             thread::sleep(*timeout);
                             *timeout = timeout.mul_f64(self.scale);
                             Some(None)
@@ -179,6 +183,52 @@ where
             .instance_endpoint(endpoint)
             .map_err(|e| e.map_client(Error::inner))
     }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        self.client.capabilities()
+    }
+}
+
+/// The number of previous attempts of a request sent through a retrying client.
+///
+/// This is attached to each attempt as a request extension so that the underlying client can
+/// report it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Attempt(pub(crate) usize);
+
+impl Attempt {
+    /// The number of times a request has been retried.
+    #[cfg_attr(not(feature = "client_api"), allow(dead_code))]
+    pub(crate) fn retries(request: &http::request::Builder) -> usize {
+        request
+            .extensions_ref()
+            .and_then(|extensions| extensions.get::<Self>())
+            .map(|attempt| attempt.0)
+            .unwrap_or(0)
+    }
+}
+
+/// Copy a request for another attempt.
+fn attempt_request(request: &http::request::Builder, attempt: usize) -> http::request::Builder {
+    let mut builder = http::request::Request::builder();
+    if let Some(method) = request.method_ref() {
+        builder = builder.method(method);
+    }
+    if let Some(uri) = request.uri_ref() {
+        builder = builder.uri(uri);
+    }
+    if let Some(version) = request.version_ref() {
+        builder = builder.version(*version);
+    }
+    if let Some(headers) = request.headers_ref() {
+        for (key, value) in headers.iter() {
+            builder = builder.header(key, value);
+        }
+    }
+    // Ignore extensions for now. Can be handled once this is released:
+    // https://github.com/hyperium/http/pull/497
+
+    builder.extension(Attempt(attempt))
 }
 
 impl<C> api::Client for Client<C>
@@ -190,36 +240,44 @@ where
         request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        let mut attempt = 0;
         self.backoff.retry(|| {
-            let mut builder = http::request::Request::builder();
-            if let Some(method) = request.method_ref() {
-                builder = builder.method(method);
-            }
-            if let Some(uri) = request.uri_ref() {
-                builder = builder.uri(uri);
-            }
-            if let Some(version) = request.version_ref() {
-                builder = builder.version(*version);
-            }
-            if let Some(headers) = request.headers_ref() {
-                for (key, value) in headers.iter() {
-                    builder = builder.header(key, value);
-                }
-            }
-            // Ignore extensions for now. Can be handled once this is released:
-            // https://github.com/hyperium/http/pull/497
+            let builder = attempt_request(&request, attempt);
+            attempt += 1;
 
             self.client.rest(builder, body.clone())
         })
     }
+
+    fn rest_spooled(
+        &self,
+        request: http::request::Builder,
+        mut body: api::SpooledBody,
+    ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        let mut attempt = 0;
+        self.backoff.retry(|| {
+            let builder = attempt_request(&request, attempt);
+            attempt += 1;
+
+            // Each attempt consumes its body, so send a copy to keep the original for retries.
+            let body = body.try_clone().map_err(api::BodyError::from)?;
+            self.client.rest_spooled(builder, body)
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use std::cell::RefCell;
+    use std::io::Write;
+    use std::time::Duration;
+
+    use bytes::Bytes;
     use http::{Response, StatusCode};
     use serde::Deserialize;
     use serde_json::json;
     use thiserror::Error;
+    use url::Url;
 
     use crate::api::endpoint_prelude::*;
     use crate::api::{self, retry, ApiError, Query};
@@ -357,6 +415,24 @@ mod test {
         }
     }
 
+    struct SpooledDummy;
+
+    impl Endpoint for SpooledDummy {
+        fn method(&self) -> Method {
+            Method::POST
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn spooled_body(&self) -> Result<Option<(&'static str, api::SpooledBody)>, BodyError> {
+            let mut body = api::SpooledBody::with_threshold(4);
+            body.write_all(b"spooled content")?;
+            Ok(Some(("text/plain", body)))
+        }
+    }
+
     #[derive(Debug, Deserialize)]
     struct DummyResult {
         value: u8,
@@ -428,4 +504,90 @@ mod test {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[derive(Default)]
+    struct AttemptClient {
+        attempts: RefCell<Vec<usize>>,
+        spooled: RefCell<Vec<Vec<u8>>>,
+    }
+
+    impl api::RestClient for AttemptClient {
+        type Error = BogusError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse("https://gitlab.host.invalid/api/v4/")?.join(endpoint)?)
+        }
+    }
+
+    impl api::Client for AttemptClient {
+        fn rest(
+            &self,
+            request: http::request::Builder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.respond(&request)
+        }
+
+        fn rest_spooled(
+            &self,
+            request: http::request::Builder,
+            body: api::SpooledBody,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            assert!(body.is_spooled());
+            self.spooled
+                .borrow_mut()
+                .push(body.into_vec().map_err(BodyError::from)?);
+            self.respond(&request)
+        }
+    }
+
+    impl AttemptClient {
+        fn respond(
+            &self,
+            request: &http::request::Builder,
+        ) -> Result<Response<Bytes>, ApiError<BogusError>> {
+            let mut attempts = self.attempts.borrow_mut();
+            attempts.push(retry::Attempt::retries(request));
+            let status = if attempts.len() < 3 {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::OK
+            };
+            Ok(Response::builder()
+                .status(status)
+                .body(r#"{"value": 0}"#.into())
+                .unwrap())
+        }
+    }
+
+    #[test]
+    fn retry_client_attempts() {
+        let backoff = retry::Backoff::builder()
+            .init(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = retry::Client::new(AttemptClient::default(), backoff);
+
+        let res: DummyResult = Dummy.query(&client).unwrap();
+        assert_eq!(res.value, 0);
+        assert_eq!(*client.client.attempts.borrow(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn retry_client_spooled() {
+        let backoff = retry::Backoff::builder()
+            .init(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = retry::Client::new(AttemptClient::default(), backoff);
+
+        let res: DummyResult = SpooledDummy.query(&client).unwrap();
+        assert_eq!(res.value, 0);
+        assert_eq!(*client.client.attempts.borrow(), [0, 1, 2]);
+        let spooled = client.client.spooled.borrow();
+        assert_eq!(spooled.len(), 3);
+        for body in spooled.iter() {
+            assert_eq!(body, b"spooled content");
+        }
+    }
 }
@@ -20,9 +20,10 @@ mod reset_authentication_token;
 mod reset_authentication_token_by_token;
 mod runner;
 mod runners;
+mod stale;
 mod verify;
 
-const MAX_MAINTENANCE_NOTE_LENGTH: usize = 1024;
+pub(crate) const MAX_MAINTENANCE_NOTE_LENGTH: usize = 1024;
 
 pub use self::all_runners::AllRunners;
 pub use self::all_runners::AllRunnersBuilder;
@@ -69,6 +70,11 @@ pub use self::runners::Runners;
 pub use self::runners::RunnersBuilder;
 pub use self::runners::RunnersBuilderError;
 
+pub use self::stale::CleanupStaleRunners;
+pub use self::stale::CleanupStaleRunnersBuilder;
+pub use self::stale::CleanupStaleRunnersBuilderError;
+pub use self::stale::StaleRunnerAction;
+
 pub use self::verify::VerifyRunner;
 pub use self::verify::VerifyRunnerBuilder;
 pub use self::verify::VerifyRunnerBuilderError;
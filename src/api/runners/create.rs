@@ -31,10 +31,16 @@ pub struct RunnerMetadata<'a> {
 impl<'a> RunnerMetadata<'a> {
     fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
-            .push_opt("info[name]", self.name.as_ref())
-            .push_opt("info[version]", self.version.as_ref())
-            .push_opt("info[platform]", self.platform.as_ref())
-            .push_opt("info[architecture]", self.architecture.as_ref());
+            .push_opt(ParamName::new("info").key("name"), self.name.as_ref())
+            .push_opt(ParamName::new("info").key("version"), self.version.as_ref())
+            .push_opt(
+                ParamName::new("info").key("platform"),
+                self.platform.as_ref(),
+            )
+            .push_opt(
+                ParamName::new("info").key("architecture"),
+                self.architecture.as_ref(),
+            );
     }
 }
 
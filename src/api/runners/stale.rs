@@ -0,0 +1,365 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::runners::{AllRunners, DeleteRunner, EditRunner, Runner, RunnerType};
+use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Pagination, Query};
+
+/// The action to take on stale runners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StaleRunnerAction {
+    /// Pause the runners so that they no longer receive jobs.
+    Pause,
+    /// Delete the runners.
+    Delete,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for StaleRunnerAction {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        StaleRunnerAction::Pause
+    }
+}
+
+/// The fields of a runner needed to decide whether it is stale.
+#[derive(Debug, Deserialize)]
+struct RunnerActivity {
+    id: u64,
+    #[serde(default)]
+    contacted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl RunnerActivity {
+    fn is_stale(&self, cutoff: DateTime<Utc>) -> bool {
+        // Runners which have never contacted GitLab are judged by when they were registered so
+        // that freshly-registered runners are left alone.
+        self.contacted_at
+            .or(self.created_at)
+            .map(|last_seen| last_seen < cutoff)
+            .unwrap_or(false)
+    }
+}
+
+fn default_runners() -> AllRunners<'static> {
+    AllRunners::builder()
+        .type_(RunnerType::Instance)
+        .build()
+        .unwrap()
+}
+
+/// Pause (or delete) runners which have not contacted GitLab recently.
+///
+/// Runners are listed using the `runners` query (instance runners by default). The runner list
+/// does not include the last contact time, so each runner's details are fetched to determine its
+/// `contacted_at` time. Runners which have never contacted GitLab are judged by their creation
+/// time instead.
+///
+/// Results are the details of each stale runner as fetched before any action was taken. In
+/// dry-run mode, no runners are modified.
+///
+/// Note that listing all runners requires administrator privileges.
+#[derive(Debug, Builder, Clone)]
+pub struct CleanupStaleRunners<'a> {
+    /// The query selecting the runners to consider.
+    ///
+    /// Defaults to all instance runners.
+    #[builder(default = "default_runners()")]
+    runners: AllRunners<'a>,
+    /// Runners which last contacted GitLab before this time are considered stale.
+    ///
+    /// See also `older_than_days`.
+    contacted_before: DateTime<Utc>,
+
+    /// What to do with stale runners.
+    #[builder(default)]
+    action: StaleRunnerAction,
+    /// Only report the runners which would be affected.
+    #[builder(default)]
+    dry_run: bool,
+}
+
+impl<'a> CleanupStaleRunners<'a> {
+    /// Create a builder for the helper.
+    pub fn builder() -> CleanupStaleRunnersBuilder<'a> {
+        CleanupStaleRunnersBuilder::default()
+    }
+}
+
+impl<'a> CleanupStaleRunnersBuilder<'a> {
+    /// Consider runners which have not contacted GitLab for the given number of days as stale.
+    pub fn older_than_days(&mut self, days: u32) -> &mut Self {
+        self.contacted_before(Utc::now() - Duration::days(days.into()))
+    }
+}
+
+fn runner_activity<E>(runner: &Value) -> Result<RunnerActivity, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    RunnerActivity::deserialize(runner).map_err(ApiError::data_type::<RunnerActivity>)
+}
+
+fn has_activity(runner: &Value) -> bool {
+    runner.get("contacted_at").is_some()
+}
+
+impl<'a, T, C> Query<Vec<T>, C> for CleanupStaleRunners<'a>
+where
+    T: DeserializeOwned + 'static,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let runners: Vec<Value> = api::paged(&self.runners, Pagination::All).query(client)?;
+
+        let mut results = Vec::new();
+        for runner in runners {
+            let runner = if has_activity(&runner) {
+                runner
+            } else {
+                let id = runner_activity(&runner)?.id;
+                Runner::builder()
+                    .runner(id)
+                    .build()
+                    .map_err(ApiError::invalid_endpoint)?
+                    .query(client)?
+            };
+            let activity = runner_activity(&runner)?;
+            if !activity.is_stale(self.contacted_before) {
+                continue;
+            }
+
+            if !self.dry_run {
+                match self.action {
+                    StaleRunnerAction::Pause => {
+                        let endpoint = EditRunner::builder()
+                            .runner(activity.id)
+                            .paused(true)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query(client)?;
+                    },
+                    StaleRunnerAction::Delete => {
+                        let endpoint = DeleteRunner::builder()
+                            .runner(activity.id)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query(client)?;
+                    },
+                }
+            }
+
+            results.push(serde_json::from_value(runner).map_err(ApiError::data_type::<T>)?);
+        }
+
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl<'a, T, C> AsyncQuery<Vec<T>, C> for CleanupStaleRunners<'a>
+where
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let runners: Vec<Value> = api::paged(&self.runners, Pagination::All)
+            .query_async(client)
+            .await?;
+
+        let mut results = Vec::new();
+        for runner in runners {
+            let runner = if has_activity(&runner) {
+                runner
+            } else {
+                let id = runner_activity(&runner)?.id;
+                Runner::builder()
+                    .runner(id)
+                    .build()
+                    .map_err(ApiError::invalid_endpoint)?
+                    .query_async(client)
+                    .await?
+            };
+            let activity = runner_activity(&runner)?;
+            if !activity.is_stale(self.contacted_before) {
+                continue;
+            }
+
+            if !self.dry_run {
+                match self.action {
+                    StaleRunnerAction::Pause => {
+                        let endpoint = EditRunner::builder()
+                            .runner(activity.id)
+                            .paused(true)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query_async(client).await?;
+                    },
+                    StaleRunnerAction::Delete => {
+                        let endpoint = DeleteRunner::builder()
+                            .runner(activity.id)
+                            .build()
+                            .map_err(ApiError::invalid_endpoint)?;
+                        api::ignore(endpoint).query_async(client).await?;
+                    },
+                }
+            }
+
+            results.push(serde_json::from_value(runner).map_err(ApiError::data_type::<T>)?);
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde::{Deserialize, Serialize};
+
+    use crate::api::runners::{
+        AllRunners, CleanupStaleRunners, CleanupStaleRunnersBuilderError, RunnerStatus,
+        StaleRunnerAction,
+    };
+    use crate::api::{AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct DummyRunner {
+        id: u64,
+        contacted_at: Option<String>,
+        created_at: Option<String>,
+    }
+
+    fn runners() -> Vec<DummyRunner> {
+        vec![
+            DummyRunner {
+                id: 1,
+                contacted_at: Some("2020-01-01T00:00:00Z".into()),
+                created_at: Some("2019-01-01T00:00:00Z".into()),
+            },
+            DummyRunner {
+                id: 2,
+                contacted_at: Some("2024-01-01T00:00:00Z".into()),
+                created_at: Some("2019-01-01T00:00:00Z".into()),
+            },
+            DummyRunner {
+                id: 3,
+                contacted_at: None,
+                created_at: Some("2019-01-01T00:00:00Z".into()),
+            },
+            DummyRunner {
+                id: 4,
+                contacted_at: None,
+                created_at: Some("2024-01-01T00:00:00Z".into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn stale_runner_action_default() {
+        assert_eq!(StaleRunnerAction::default(), StaleRunnerAction::Pause);
+    }
+
+    #[test]
+    fn contacted_before_is_necessary() {
+        let err = CleanupStaleRunners::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CleanupStaleRunnersBuilderError,
+            "contacted_before"
+        );
+    }
+
+    #[test]
+    fn contacted_before_is_sufficient() {
+        CleanupStaleRunners::builder()
+            .contacted_before(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn older_than_days_is_sufficient() {
+        CleanupStaleRunners::builder()
+            .older_than_days(90)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn dry_run() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("type", "instance_type")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, runners());
+
+        let endpoint = CleanupStaleRunners::builder()
+            .contacted_before(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+            .action(StaleRunnerAction::Delete)
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<DummyRunner> = endpoint.query(&client).unwrap();
+        itertools::assert_equal(res.iter().map(|r| r.id), [1, 3]);
+    }
+
+    #[test]
+    fn dry_run_custom_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("status", "offline")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, runners());
+
+        let endpoint = CleanupStaleRunners::builder()
+            .runners(
+                AllRunners::builder()
+                    .status(RunnerStatus::Offline)
+                    .build()
+                    .unwrap(),
+            )
+            .contacted_before(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<DummyRunner> = endpoint.query(&client).unwrap();
+        itertools::assert_equal(res.iter().map(|r| r.id), [1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn dry_run_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("runners/all")
+            .add_query_params(&[("type", "instance_type")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(endpoint, runners());
+
+        let endpoint = CleanupStaleRunners::builder()
+            .contacted_before(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let res: Vec<DummyRunner> = endpoint.query_async(&client).await.unwrap();
+        itertools::assert_equal(res.iter().map(|r| r.id), [1, 3]);
+    }
+}
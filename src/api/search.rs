@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Search API endpoints.
+//!
+//! These endpoints are used for searching for objects across the instance, within a group, or
+//! within a project.
+
+mod group;
+mod project;
+mod search;
+
+pub use self::group::GroupSearch;
+pub use self::group::GroupSearchBuilder;
+pub use self::group::GroupSearchBuilderError;
+
+pub use self::project::ProjectSearch;
+pub use self::project::ProjectSearchBuilder;
+pub use self::project::ProjectSearchBuilderError;
+
+pub use self::search::Search;
+pub use self::search::SearchBuilder;
+pub use self::search::SearchBuilderError;
+pub use self::search::SearchOrderBy;
+pub use self::search::SearchScope;
+pub use self::search::SearchState;
@@ -0,0 +1,200 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::search::{SearchOrderBy, SearchScope, SearchState};
+
+/// Search within a group.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupSearch<'a> {
+    /// The group to search within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The scope to search within.
+    scope: SearchScope,
+    /// The search query.
+    #[builder(setter(into))]
+    search: Cow<'a, str>,
+
+    /// Filter results by state.
+    ///
+    /// Only supported by the issues and merge requests scopes.
+    #[builder(default)]
+    state: Option<SearchState>,
+    /// Filter results by confidentiality.
+    ///
+    /// Only supported by the issues scope.
+    #[builder(default)]
+    confidential: Option<bool>,
+
+    /// Order results by a given key.
+    ///
+    /// Only supported by the issues and merge requests scopes.
+    #[builder(default)]
+    order_by: Option<SearchOrderBy>,
+    /// The sort order for return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> GroupSearch<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupSearchBuilder<'a> {
+        GroupSearchBuilder::default()
+    }
+}
+
+impl<'a> GroupSearchBuilder<'a> {
+    fn validate(&self) -> Result<(), GroupSearchBuilderError> {
+        common::validate_path_param("group", self.group.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for GroupSearch<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/search", self.group.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("scope", self.scope)
+            .push("search", &self.search)
+            .push_opt("state", self.state)
+            .push_opt("confidential", self.confidential)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupSearch<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::search::{
+        GroupSearch, GroupSearchBuilderError, SearchOrderBy, SearchScope, SearchState,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_scope_and_search_are_needed() {
+        let err = GroupSearch::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSearchBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupSearch::builder()
+            .scope(SearchScope::Projects)
+            .search("query")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSearchBuilderError, "group");
+    }
+
+    #[test]
+    fn scope_is_needed() {
+        let err = GroupSearch::builder()
+            .group(1)
+            .search("query")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSearchBuilderError, "scope");
+    }
+
+    #[test]
+    fn search_is_needed() {
+        let err = GroupSearch::builder()
+            .group(1)
+            .scope(SearchScope::Projects)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupSearchBuilderError, "search");
+    }
+
+    #[test]
+    fn group_scope_and_search_are_sufficient() {
+        GroupSearch::builder()
+            .group(1)
+            .scope(SearchScope::Projects)
+            .search("query")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_must_be_valid() {
+        let err = GroupSearch::builder()
+            .group(0)
+            .scope(SearchScope::Projects)
+            .search("query")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, GroupSearchBuilderError, "group");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/search")
+            .add_query_params(&[("scope", "projects"), ("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupSearch::builder()
+            .group("simple/group")
+            .scope(SearchScope::Projects)
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/search")
+            .add_query_params(&[
+                ("scope", "issues"),
+                ("search", "query"),
+                ("state", "closed"),
+                ("confidential", "false"),
+                ("order_by", "created_at"),
+                ("sort", "desc"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupSearch::builder()
+            .group("simple/group")
+            .scope(SearchScope::Issues)
+            .search("query")
+            .state(SearchState::Closed)
+            .confidential(false)
+            .order_by(SearchOrderBy::CreatedAt)
+            .sort(SortOrder::Descending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
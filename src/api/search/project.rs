@@ -0,0 +1,226 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::search::{SearchOrderBy, SearchScope, SearchState};
+
+/// Search within a project.
+///
+/// Searching for projects is not supported.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectSearch<'a> {
+    /// The project to search within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The scope to search within.
+    scope: SearchScope,
+    /// The search query.
+    #[builder(setter(into))]
+    search: Cow<'a, str>,
+
+    /// The branch or tag to search within.
+    ///
+    /// Only supported by the commits, blobs, and wiki blobs scopes. Defaults to the default
+    /// branch of the project.
+    #[builder(setter(into), default)]
+    ref_: Option<Cow<'a, str>>,
+    /// Filter results by state.
+    ///
+    /// Only supported by the issues and merge requests scopes.
+    #[builder(default)]
+    state: Option<SearchState>,
+    /// Filter results by confidentiality.
+    ///
+    /// Only supported by the issues scope.
+    #[builder(default)]
+    confidential: Option<bool>,
+
+    /// Order results by a given key.
+    ///
+    /// Only supported by the issues and merge requests scopes.
+    #[builder(default)]
+    order_by: Option<SearchOrderBy>,
+    /// The sort order for return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> ProjectSearch<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectSearchBuilder<'a> {
+        ProjectSearchBuilder::default()
+    }
+}
+
+impl<'a> ProjectSearchBuilder<'a> {
+    fn validate(&self) -> Result<(), ProjectSearchBuilderError> {
+        common::validate_path_param("project", self.project.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for ProjectSearch<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/search", self.project.path_segment()).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("scope", self.scope)
+            .push("search", &self.search)
+            .push_opt("ref", self.ref_.as_ref())
+            .push_opt("state", self.state)
+            .push_opt("confidential", self.confidential)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectSearch<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::search::{
+        ProjectSearch, ProjectSearchBuilderError, SearchOrderBy, SearchScope, SearchState,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_scope_and_search_are_needed() {
+        let err = ProjectSearch::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSearchBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectSearch::builder()
+            .scope(SearchScope::Issues)
+            .search("query")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSearchBuilderError, "project");
+    }
+
+    #[test]
+    fn scope_is_needed() {
+        let err = ProjectSearch::builder()
+            .project(1)
+            .search("query")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSearchBuilderError, "scope");
+    }
+
+    #[test]
+    fn search_is_needed() {
+        let err = ProjectSearch::builder()
+            .project(1)
+            .scope(SearchScope::Issues)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectSearchBuilderError, "search");
+    }
+
+    #[test]
+    fn project_scope_and_search_are_sufficient() {
+        ProjectSearch::builder()
+            .project(1)
+            .scope(SearchScope::Issues)
+            .search("query")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn project_must_be_valid() {
+        let err = ProjectSearch::builder()
+            .project(0)
+            .scope(SearchScope::Issues)
+            .search("query")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ProjectSearchBuilderError, "project");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/search")
+            .add_query_params(&[("scope", "issues"), ("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSearch::builder()
+            .project("simple/project")
+            .scope(SearchScope::Issues)
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/search")
+            .add_query_params(&[
+                ("scope", "merge_requests"),
+                ("search", "query"),
+                ("state", "opened"),
+                ("order_by", "created_at"),
+                ("sort", "asc"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSearch::builder()
+            .project("simple/project")
+            .scope(SearchScope::MergeRequests)
+            .search("query")
+            .state(SearchState::Opened)
+            .order_by(SearchOrderBy::CreatedAt)
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_ref() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/search")
+            .add_query_params(&[("scope", "blobs"), ("search", "query"), ("ref", "v1.0")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectSearch::builder()
+            .project("simple/project")
+            .scope(SearchScope::Blobs)
+            .search("query")
+            .ref_("v1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
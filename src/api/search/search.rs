@@ -0,0 +1,369 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::SortOrder;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The kinds of objects which may be searched for.
+///
+/// Not all scopes are available for every search endpoint. Global and group searches do not
+/// support searching snippets and project searches do not support searching projects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SearchScope {
+    /// Search for projects.
+    Projects,
+    /// Search for issues.
+    Issues,
+    /// Search for merge requests.
+    MergeRequests,
+    /// Search for milestones.
+    Milestones,
+    /// Search for wiki content.
+    WikiBlobs,
+    /// Search for commits.
+    Commits,
+    /// Search for repository content.
+    Blobs,
+    /// Search for users.
+    Users,
+    /// Search for comments.
+    Notes,
+}
+
+impl SearchScope {
+    fn as_str(self) -> &'static str {
+        match self {
+            SearchScope::Projects => "projects",
+            SearchScope::Issues => "issues",
+            SearchScope::MergeRequests => "merge_requests",
+            SearchScope::Milestones => "milestones",
+            SearchScope::WikiBlobs => "wiki_blobs",
+            SearchScope::Commits => "commits",
+            SearchScope::Blobs => "blobs",
+            SearchScope::Users => "users",
+            SearchScope::Notes => "notes",
+        }
+    }
+}
+
+impl ParamValue<'static> for SearchScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Filters for the state of search results.
+///
+/// Only supported by the issues and merge requests scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SearchState {
+    /// Filter results that are open.
+    Opened,
+    /// Filter results that are closed.
+    Closed,
+}
+
+impl SearchState {
+    fn as_str(self) -> &'static str {
+        match self {
+            SearchState::Opened => "opened",
+            SearchState::Closed => "closed",
+        }
+    }
+}
+
+impl ParamValue<'static> for SearchState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Keys search results may be ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SearchOrderBy {
+    /// Order by the creation date of the results.
+    CreatedAt,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for SearchOrderBy {
+    fn default() -> Self {
+        // XXX(rust-1.62): use `#[default]`
+        SearchOrderBy::CreatedAt
+    }
+}
+
+impl SearchOrderBy {
+    fn as_str(self) -> &'static str {
+        match self {
+            SearchOrderBy::CreatedAt => "created_at",
+        }
+    }
+}
+
+impl ParamValue<'static> for SearchOrderBy {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Search across the entire instance.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Search<'a> {
+    /// The scope to search within.
+    scope: SearchScope,
+    /// The search query.
+    #[builder(setter(into))]
+    search: Cow<'a, str>,
+
+    /// Filter results by state.
+    ///
+    /// Only supported by the issues and merge requests scopes.
+    #[builder(default)]
+    state: Option<SearchState>,
+    /// Filter results by confidentiality.
+    ///
+    /// Only supported by the issues scope.
+    #[builder(default)]
+    confidential: Option<bool>,
+
+    /// Order results by a given key.
+    ///
+    /// Only supported by the issues and merge requests scopes.
+    #[builder(default)]
+    order_by: Option<SearchOrderBy>,
+    /// The sort order for return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> Search<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SearchBuilder<'a> {
+        SearchBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Search<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "search".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("scope", self.scope)
+            .push("search", &self.search)
+            .push_opt("state", self.state)
+            .push_opt("confidential", self.confidential)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for Search<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::search::{Search, SearchBuilderError, SearchOrderBy, SearchScope, SearchState};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn search_scope_as_str() {
+        let items = &[
+            (SearchScope::Projects, "projects"),
+            (SearchScope::Issues, "issues"),
+            (SearchScope::MergeRequests, "merge_requests"),
+            (SearchScope::Milestones, "milestones"),
+            (SearchScope::WikiBlobs, "wiki_blobs"),
+            (SearchScope::Commits, "commits"),
+            (SearchScope::Blobs, "blobs"),
+            (SearchScope::Users, "users"),
+            (SearchScope::Notes, "notes"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn search_state_as_str() {
+        let items = &[
+            (SearchState::Opened, "opened"),
+            (SearchState::Closed, "closed"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn search_order_by_default() {
+        assert_eq!(SearchOrderBy::default(), SearchOrderBy::CreatedAt);
+    }
+
+    #[test]
+    fn search_order_by_as_str() {
+        let items = &[(SearchOrderBy::CreatedAt, "created_at")];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn scope_and_search_are_needed() {
+        let err = Search::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SearchBuilderError, "scope");
+    }
+
+    #[test]
+    fn scope_is_needed() {
+        let err = Search::builder().search("query").build().unwrap_err();
+        crate::test::assert_missing_field!(err, SearchBuilderError, "scope");
+    }
+
+    #[test]
+    fn search_is_needed() {
+        let err = Search::builder()
+            .scope(SearchScope::Issues)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SearchBuilderError, "search");
+    }
+
+    #[test]
+    fn scope_and_search_are_sufficient() {
+        Search::builder()
+            .scope(SearchScope::Issues)
+            .search("query")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[("scope", "projects"), ("search", "query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Search::builder()
+            .scope(SearchScope::Projects)
+            .search("query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[
+                ("scope", "issues"),
+                ("search", "query"),
+                ("state", "opened"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Search::builder()
+            .scope(SearchScope::Issues)
+            .search("query")
+            .state(SearchState::Opened)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_confidential() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[
+                ("scope", "issues"),
+                ("search", "query"),
+                ("confidential", "true"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Search::builder()
+            .scope(SearchScope::Issues)
+            .search("query")
+            .confidential(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[
+                ("scope", "merge_requests"),
+                ("search", "query"),
+                ("order_by", "created_at"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Search::builder()
+            .scope(SearchScope::MergeRequests)
+            .search("query")
+            .order_by(SearchOrderBy::CreatedAt)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("search")
+            .add_query_params(&[
+                ("scope", "merge_requests"),
+                ("search", "query"),
+                ("sort", "asc"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Search::builder()
+            .scope(SearchScope::MergeRequests)
+            .search("query")
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,343 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Secret providers
+//!
+//! Webhook secret tokens should not need to be passed around as plaintext arguments. This module
+//! provides a `SecretProvider` trait which can be used to look up secrets by name when creating or
+//! editing hooks and when verifying incoming webhook deliveries.
+//!
+//! Providers for environment variables and files are provided. Other stores (e.g., Vault) may be
+//! used by implementing the trait or by using a closure.
+
+use std::env;
+use std::error::Error as StdError;
+use std::fs;
+use std::io;
+use std::path::{self, Component, Path, PathBuf};
+
+use thiserror::Error;
+
+/// The header GitLab uses to deliver a webhook's secret token.
+pub const WEBHOOK_TOKEN_HEADER: &str = "X-Gitlab-Token";
+
+/// Errors which may occur when looking up a secret.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SecretError {
+    /// The secret does not exist in the provider.
+    #[error("secret `{}` not found", name)]
+    NotFound {
+        /// The name of the secret.
+        name: String,
+    },
+    /// The name of the secret is not valid for the provider.
+    #[error("invalid secret name `{}`", name)]
+    InvalidName {
+        /// The name of the secret.
+        name: String,
+    },
+    /// The secret could not be read.
+    #[error("failed to read secret `{}`: {}", name, source)]
+    Io {
+        /// The name of the secret.
+        name: String,
+        /// The source of the error.
+        source: io::Error,
+    },
+    /// The secret is not valid UTF-8.
+    #[error("secret `{}` is not valid UTF-8", name)]
+    NotUnicode {
+        /// The name of the secret.
+        name: String,
+    },
+    /// A provider-specific error.
+    #[error("secret provider error: {}", source)]
+    Provider {
+        /// The source of the error.
+        source: Box<dyn StdError + Send + Sync + 'static>,
+    },
+}
+
+impl SecretError {
+    /// Create an error for a secret which does not exist.
+    pub fn not_found<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        SecretError::NotFound {
+            name: name.into(),
+        }
+    }
+
+    /// Wrap a provider-specific error.
+    pub fn provider<E>(source: E) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        SecretError::Provider {
+            source: Box::new(source),
+        }
+    }
+}
+
+/// A source of named secrets.
+pub trait SecretProvider {
+    /// Look up a secret by name.
+    fn secret(&self, name: &str) -> Result<String, SecretError>;
+}
+
+impl<F> SecretProvider for F
+where
+    F: Fn(&str) -> Result<String, SecretError>,
+{
+    fn secret(&self, name: &str) -> Result<String, SecretError> {
+        self(name)
+    }
+}
+
+/// A secret provider which reads secrets from environment variables.
+#[derive(Debug, Default, Clone)]
+pub struct EnvSecretProvider {
+    prefix: String,
+}
+
+impl EnvSecretProvider {
+    /// Read secrets from environment variables with the given name.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read secrets from environment variables with a prefix prepended to the name.
+    pub fn with_prefix<P>(prefix: P) -> Self
+    where
+        P: Into<String>,
+    {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl SecretProvider for EnvSecretProvider {
+    fn secret(&self, name: &str) -> Result<String, SecretError> {
+        let var = format!("{}{}", self.prefix, name);
+        match env::var(&var) {
+            Ok(value) => Ok(value),
+            Err(env::VarError::NotPresent) => Err(SecretError::not_found(var)),
+            Err(env::VarError::NotUnicode(_)) => {
+                Err(SecretError::NotUnicode {
+                    name: var,
+                })
+            },
+        }
+    }
+}
+
+/// A secret provider which reads secrets from files within a directory.
+///
+/// Each secret is stored in a file named after the secret. Trailing newlines are removed. This
+/// matches the layout used by Docker and Kubernetes secret mounts. Secret names must be plain file
+/// names; names which would refer to files outside of the directory are rejected.
+#[derive(Debug, Clone)]
+pub struct FileSecretProvider {
+    root: PathBuf,
+}
+
+impl FileSecretProvider {
+    /// Read secrets from files in the given directory.
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            root: root.into(),
+        }
+    }
+}
+
+impl SecretProvider for FileSecretProvider {
+    fn secret(&self, name: &str) -> Result<String, SecretError> {
+        let mut components = Path::new(name).components();
+        let is_file_name = matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None),
+        );
+        if !is_file_name || name.chars().any(path::is_separator) {
+            return Err(SecretError::InvalidName {
+                name: name.into(),
+            });
+        }
+
+        let path = self.root.join(name);
+        let contents = fs::read(path).map_err(|err| {
+            if err.kind() == io::ErrorKind::NotFound {
+                SecretError::not_found(name)
+            } else {
+                SecretError::Io {
+                    name: name.into(),
+                    source: err,
+                }
+            }
+        })?;
+        let mut value = String::from_utf8(contents).map_err(|_| {
+            SecretError::NotUnicode {
+                name: name.into(),
+            }
+        })?;
+        let len = value.trim_end_matches(['\r', '\n']).len();
+        value.truncate(len);
+        Ok(value)
+    }
+}
+
+/// Verify the secret token of an incoming webhook delivery.
+///
+/// The `token` is the value of the `X-Gitlab-Token` header (see `WEBHOOK_TOKEN_HEADER`) and
+/// `name` is the name of the expected secret within the provider. Returns `false` if the header
+/// is missing or does not match. The comparison is performed in constant time.
+pub fn verify_webhook_token<P>(
+    provider: &P,
+    name: &str,
+    token: Option<&str>,
+) -> Result<bool, SecretError>
+where
+    P: SecretProvider + ?Sized,
+{
+    let expected = provider.secret(name)?;
+    Ok(if let Some(token) = token {
+        constant_time_eq(expected.as_bytes(), token.as_bytes())
+    } else {
+        false
+    })
+}
+
+fn constant_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    lhs.iter().zip(rhs).fold(0, |acc, (l, r)| acc | (l ^ r)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::api::secrets::{
+        self, EnvSecretProvider, FileSecretProvider, SecretError, SecretProvider,
+    };
+
+    fn static_provider(name: &str) -> Result<String, SecretError> {
+        if name == "hook" {
+            Ok("s3cr3t".into())
+        } else {
+            Err(SecretError::not_found(name))
+        }
+    }
+
+    #[test]
+    fn closure_provider() {
+        assert_eq!(static_provider.secret("hook").unwrap(), "s3cr3t");
+        let err = static_provider.secret("missing").unwrap_err();
+        if let SecretError::NotFound {
+            name,
+        } = err
+        {
+            assert_eq!(name, "missing");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn env_provider() {
+        env::set_var("GITLAB_TEST_SECRETS_HOOK", "from-env");
+        let provider = EnvSecretProvider::with_prefix("GITLAB_TEST_SECRETS_");
+        assert_eq!(provider.secret("HOOK").unwrap(), "from-env");
+
+        let err = provider.secret("MISSING").unwrap_err();
+        if let SecretError::NotFound {
+            name,
+        } = err
+        {
+            assert_eq!(name, "GITLAB_TEST_SECRETS_MISSING");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn file_provider() {
+        let root = env::temp_dir().join(format!("gitlab-secrets-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("hook"), "from-file\n").unwrap();
+
+        let provider = FileSecretProvider::new(&root);
+        assert_eq!(provider.secret("hook").unwrap(), "from-file");
+
+        let err = provider.secret("missing").unwrap_err();
+        if let SecretError::NotFound {
+            name,
+        } = err
+        {
+            assert_eq!(name, "missing");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn file_provider_invalid_names() {
+        let root = env::temp_dir().join(format!("gitlab-secrets-names-{}", std::process::id()));
+        let inner = root.join("inner");
+        fs::create_dir_all(&inner).unwrap();
+        fs::write(root.join("hook"), "outside\n").unwrap();
+        fs::write(inner.join("hook"), "inside\n").unwrap();
+
+        let provider = FileSecretProvider::new(&inner);
+        let outside = root.join("hook");
+        let outside = outside.to_str().unwrap();
+        let names = [
+            "",
+            ".",
+            "..",
+            "../hook",
+            "./hook",
+            "hook/",
+            "sub/hook",
+            "sub/../hook",
+            outside,
+        ];
+
+        for name in names.iter() {
+            let err = provider.secret(name).unwrap_err();
+            if let SecretError::InvalidName {
+                name: err_name,
+            } = err
+            {
+                assert_eq!(err_name, *name);
+            } else {
+                panic!("unexpected error for `{}`: {:?}", name, err);
+            }
+        }
+        assert_eq!(provider.secret("hook").unwrap(), "inside");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn verify_webhook_token() {
+        assert!(secrets::verify_webhook_token(&static_provider, "hook", Some("s3cr3t")).unwrap());
+        assert!(!secrets::verify_webhook_token(&static_provider, "hook", Some("s3cr3T")).unwrap());
+        assert!(!secrets::verify_webhook_token(&static_provider, "hook", Some("s3cr3")).unwrap());
+        assert!(!secrets::verify_webhook_token(&static_provider, "hook", None).unwrap());
+        secrets::verify_webhook_token(&static_provider, "missing", Some("s3cr3t")).unwrap_err();
+    }
+}
@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sidekiq metrics API endpoints.
+//!
+//! These endpoints are used for monitoring the background job processing of the GitLab instance.
+//! They require an administrator token.
+
+mod compound_metrics;
+mod job_stats;
+mod process_metrics;
+mod queue_metrics;
+
+pub use self::compound_metrics::CompoundMetrics;
+pub use self::compound_metrics::CompoundMetricsBuilder;
+pub use self::compound_metrics::CompoundMetricsBuilderError;
+
+pub use self::job_stats::JobStats;
+pub use self::job_stats::JobStatsBuilder;
+pub use self::job_stats::JobStatsBuilderError;
+
+pub use self::process_metrics::ProcessMetrics;
+pub use self::process_metrics::ProcessMetricsBuilder;
+pub use self::process_metrics::ProcessMetricsBuilderError;
+
+pub use self::queue_metrics::QueueMetrics;
+pub use self::queue_metrics::QueueMetricsBuilder;
+pub use self::queue_metrics::QueueMetricsBuilderError;
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the queue, process, and job metrics of Sidekiq at once.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CompoundMetrics {}
+
+impl CompoundMetrics {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CompoundMetricsBuilder {
+        CompoundMetricsBuilder::default()
+    }
+}
+
+impl Endpoint for CompoundMetrics {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "sidekiq/compound_metrics".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::sidekiq::CompoundMetrics;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        CompoundMetrics::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("sidekiq/compound_metrics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CompoundMetrics::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
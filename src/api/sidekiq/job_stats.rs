@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for statistics about Sidekiq jobs.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct JobStats {}
+
+impl JobStats {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> JobStatsBuilder {
+        JobStatsBuilder::default()
+    }
+}
+
+impl Endpoint for JobStats {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "sidekiq/job_stats".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::sidekiq::JobStats;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        JobStats::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("sidekiq/job_stats")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = JobStats::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
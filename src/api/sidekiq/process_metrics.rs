@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for information about each Sidekiq process.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ProcessMetrics {}
+
+impl ProcessMetrics {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProcessMetricsBuilder {
+        ProcessMetricsBuilder::default()
+    }
+}
+
+impl Endpoint for ProcessMetrics {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "sidekiq/process_metrics".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::sidekiq::ProcessMetrics;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        ProcessMetrics::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("sidekiq/process_metrics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProcessMetrics::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the backlog and latency of each Sidekiq queue.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct QueueMetrics {}
+
+impl QueueMetrics {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> QueueMetricsBuilder {
+        QueueMetricsBuilder::default()
+    }
+}
+
+impl Endpoint for QueueMetrics {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "sidekiq/queue_metrics".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::sidekiq::QueueMetrics;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        QueueMetrics::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("sidekiq/queue_metrics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = QueueMetrics::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Snippet API endpoints.
+//!
+//! These endpoints are used for querying snippets and the contents of their
+//! repositories.
+
+mod file_raw;
+mod snippet;
+mod snippets;
+mod user_agent_detail;
+
+pub use self::file_raw::SnippetFileRaw;
+pub use self::file_raw::SnippetFileRawBuilder;
+pub use self::file_raw::SnippetFileRawBuilderError;
+
+pub use self::snippet::Snippet;
+pub use self::snippet::SnippetBuilder;
+pub use self::snippet::SnippetBuilderError;
+
+pub use self::snippets::Snippets;
+pub use self::snippets::SnippetsBuilder;
+pub use self::snippets::SnippetsBuilderError;
+
+pub use self::user_agent_detail::SnippetUserAgentDetail;
+pub use self::user_agent_detail::SnippetUserAgentDetailBuilder;
+pub use self::user_agent_detail::SnippetUserAgentDetailBuilderError;
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Get a raw file from a snippet's repository.
+///
+/// Note: This endpoint returns raw data, so [`crate::api::raw`] is recommended to avoid the normal
+/// JSON parsing present in the typical endpoint handling.
+#[derive(Debug, Builder, Clone)]
+pub struct SnippetFileRaw<'a> {
+    /// The ID of the snippet.
+    snippet: u64,
+    /// The ref to get the file from.
+    ///
+    /// This may be a branch name or a commit.
+    #[builder(setter(into))]
+    ref_: Cow<'a, str>,
+    /// The path to the file in the snippet's repository.
+    ///
+    /// This is automatically escaped as needed.
+    #[builder(setter(into))]
+    file_path: Cow<'a, str>,
+}
+
+impl<'a> SnippetFileRaw<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SnippetFileRawBuilder<'a> {
+        SnippetFileRawBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SnippetFileRaw<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "snippets/{}/files/{}/{}/raw",
+            self.snippet,
+            common::path_escaped(&self.ref_),
+            common::path_escaped(&self.file_path),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::snippets::{SnippetFileRaw, SnippetFileRawBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn all_parameters_are_needed() {
+        let err = SnippetFileRaw::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetFileRawBuilderError, "snippet");
+    }
+
+    #[test]
+    fn snippet_is_needed() {
+        let err = SnippetFileRaw::builder()
+            .ref_("main")
+            .file_path("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetFileRawBuilderError, "snippet");
+    }
+
+    #[test]
+    fn ref_is_needed() {
+        let err = SnippetFileRaw::builder()
+            .snippet(1)
+            .file_path("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetFileRawBuilderError, "ref_");
+    }
+
+    #[test]
+    fn file_path_is_needed() {
+        let err = SnippetFileRaw::builder()
+            .snippet(1)
+            .ref_("main")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetFileRawBuilderError, "file_path");
+    }
+
+    #[test]
+    fn all_parameters_are_sufficient() {
+        SnippetFileRaw::builder()
+            .snippet(1)
+            .ref_("main")
+            .file_path("file.txt")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("snippets/1/files/0123abcd/path%2Fto%2Ffile.txt/raw")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SnippetFileRaw::builder()
+            .snippet(1)
+            .ref_("0123abcd")
+            .file_path("path/to/file.txt")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for a snippet.
+#[derive(Debug, Builder, Clone, Copy)]
+pub struct Snippet {
+    /// The ID of the snippet.
+    snippet: u64,
+}
+
+impl Snippet {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SnippetBuilder {
+        SnippetBuilder::default()
+    }
+}
+
+impl Endpoint for Snippet {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("snippets/{}", self.snippet).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::snippets::{Snippet, SnippetBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn snippet_is_needed() {
+        let err = Snippet::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetBuilderError, "snippet");
+    }
+
+    #[test]
+    fn snippet_is_sufficient() {
+        Snippet::builder().snippet(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("snippets/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Snippet::builder().snippet(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for snippets of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Snippets {}
+
+impl Snippets {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SnippetsBuilder {
+        SnippetsBuilder::default()
+    }
+}
+
+impl Endpoint for Snippets {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "snippets".into()
+    }
+}
+
+impl Pageable for Snippets {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::snippets::Snippets;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Snippets::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("snippets").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Snippets::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
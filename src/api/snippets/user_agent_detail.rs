@@ -0,0 +1,71 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the user agent details of a snippet.
+///
+/// This is used for spam detection and requires administrator access.
+#[derive(Debug, Builder, Clone, Copy)]
+pub struct SnippetUserAgentDetail {
+    /// The ID of the snippet.
+    snippet: u64,
+}
+
+impl SnippetUserAgentDetail {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SnippetUserAgentDetailBuilder {
+        SnippetUserAgentDetailBuilder::default()
+    }
+}
+
+impl Endpoint for SnippetUserAgentDetail {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("snippets/{}/user_agent_detail", self.snippet).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::snippets::{SnippetUserAgentDetail, SnippetUserAgentDetailBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn snippet_is_needed() {
+        let err = SnippetUserAgentDetail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetUserAgentDetailBuilderError, "snippet");
+    }
+
+    #[test]
+    fn snippet_is_sufficient() {
+        SnippetUserAgentDetail::builder()
+            .snippet(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("snippets/1/user_agent_detail")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SnippetUserAgentDetail::builder()
+            .snippet(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
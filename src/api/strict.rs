@@ -0,0 +1,338 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use http::Request;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
+
+/// The maximum length of the JSON excerpt included in errors.
+const SNIPPET_LENGTH: usize = 80;
+
+/// A query modifier that strictly validates the data returned from the endpoint.
+///
+/// Deserialization errors report the path to the offending value along with an excerpt of it.
+/// Fields in the response which are not part of the expected data type are also reported as
+/// errors. This is useful to notice changes in the data returned by different GitLab versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Strict<E> {
+    endpoint: E,
+}
+
+/// Strictly validate the data returned from the endpoint.
+pub fn strict<E>(endpoint: E) -> Strict<E> {
+    Strict {
+        endpoint,
+    }
+}
+
+fn snippet(value: &Value, path: &Path) -> Option<String> {
+    let mut value = value;
+    for segment in path.iter() {
+        value = match segment {
+            Segment::Seq {
+                index,
+            } => value.get(index)?,
+            Segment::Map {
+                key,
+            } => value.get(key)?,
+            Segment::Enum {
+                variant,
+            } => value.get(variant).unwrap_or(value),
+            Segment::Unknown => return None,
+        };
+    }
+
+    let snippet = value.to_string();
+    Some(if snippet.chars().count() > SNIPPET_LENGTH {
+        let truncated: String = snippet.chars().take(SNIPPET_LENGTH).collect();
+        format!("{}...", truncated)
+    } else {
+        snippet
+    })
+}
+
+fn deserialize_strict<T, E>(value: Value) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync + 'static,
+{
+    let mut unknown_fields = Vec::new();
+    let mut record_unknown = |path: serde_ignored::Path| unknown_fields.push(path.to_string());
+    let de = serde_ignored::Deserializer::new(&value, &mut record_unknown);
+    let data = serde_path_to_error::deserialize(de).map_err(|err| {
+        let path = err.path().to_string();
+        let snippet = snippet(&value, err.path());
+        ApiError::strict_data_type::<T>(err.into_inner(), path, snippet)
+    })?;
+
+    if unknown_fields.is_empty() {
+        Ok(data)
+    } else {
+        Err(ApiError::unknown_fields::<T>(unknown_fields))
+    }
+}
+
+fn check_response<E>(rsp: &http::Response<bytes::Bytes>) -> Result<Value, ApiError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    let status = rsp.status();
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.body()));
+    };
+    if !status.is_success() {
+        return Err(ApiError::from_gitlab(v));
+    } else if status == http::StatusCode::MOVED_PERMANENTLY {
+        return Err(ApiError::moved_permanently(
+            rsp.headers().get(http::header::LOCATION),
+        ));
+    }
+
+    Ok(v)
+}
+
+impl<E, T, C> Query<T, C> for Strict<E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        let rsp = query::rest(client, req, &self.endpoint)?;
+
+        deserialize_strict(check_response(&rsp)?)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<T, C> for Strict<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        let rsp = query::rest_async(client, req, &self.endpoint).await?;
+
+        deserialize_strict(check_response(&rsp)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyItem {
+        value: u8,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        name: String,
+        items: Vec<DummyItem>,
+    }
+
+    fn client(data: serde_json::Value) -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        SingleTestClient::new_json(endpoint, &data)
+    }
+
+    #[test]
+    fn test_strict() {
+        let client = client(json!({
+            "name": "dummy",
+            "items": [{"value": 0}, {"value": 1}],
+        }));
+
+        let res: DummyResult = api::strict(Dummy).query(&client).unwrap();
+        assert_eq!(res.name, "dummy");
+        assert_eq!(res.items.len(), 2);
+        assert_eq!(res.items[1].value, 1);
+    }
+
+    #[tokio::test]
+    async fn test_strict_async() {
+        let client = client(json!({
+            "name": "dummy",
+            "items": [{"value": 0}],
+        }));
+
+        let res: DummyResult = api::strict(Dummy).query_async(&client).await.unwrap();
+        assert_eq!(res.name, "dummy");
+        assert_eq!(res.items[0].value, 0);
+    }
+
+    #[test]
+    fn test_strict_unknown_fields() {
+        let client = client(json!({
+            "name": "dummy",
+            "extra": true,
+            "items": [{"value": 0}, {"value": 1, "new_field": "new"}],
+        }));
+
+        let res: Result<DummyResult, _> = api::strict(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::UnknownFields {
+            fields,
+            typename,
+        } = err
+        {
+            assert_eq!(fields, ["extra", "items.1.new_field"]);
+            assert_eq!(typename, "gitlab::api::strict::tests::DummyResult");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_strict_bad_deserialization() {
+        let client = client(json!({
+            "name": "dummy",
+            "items": [{"value": 0}, {"value": "one"}],
+        }));
+
+        let res: Result<DummyResult, _> = api::strict(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::StrictDataType {
+            source,
+            path,
+            snippet,
+            typename,
+        } = err
+        {
+            assert_eq!(
+                format!("{}", source),
+                "invalid type: string \"one\", expected u8",
+            );
+            assert_eq!(path, "items[1].value");
+            assert_eq!(snippet.as_deref(), Some("\"one\""));
+            assert_eq!(typename, "gitlab::api::strict::tests::DummyResult");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_strict_missing_field() {
+        let client = client(json!({
+            "name": "dummy",
+            "items": [{"value": 0}, {}],
+        }));
+
+        let res: Result<DummyResult, _> = api::strict(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::StrictDataType {
+            source,
+            path,
+            snippet,
+            ..
+        } = err
+        {
+            assert_eq!(format!("{}", source), "missing field `value`");
+            assert_eq!(path, "items[1]");
+            assert_eq!(snippet.as_deref(), Some("{}"));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_strict_snippet_truncated() {
+        let name = "a".repeat(100);
+        let client = client(json!({
+            "name": "dummy",
+            "items": {"name": name},
+        }));
+
+        let res: Result<DummyResult, _> = api::strict(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::StrictDataType {
+            path,
+            snippet,
+            ..
+        } = err
+        {
+            assert_eq!(path, "items");
+            let snippet = snippet.unwrap();
+            assert!(snippet.starts_with("{\"name\":\"aaa"));
+            assert!(snippet.ends_with("..."));
+            assert_eq!(snippet.len(), 83);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_strict_error_message() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<DummyResult, _> = api::strict(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -5,6 +5,7 @@
 // except according to those terms.
 
 use crate::api::endpoint_prelude::*;
+use crate::api::instance::Feature;
 
 /// A `sudo` modifier that can be applied to any endpoint.
 #[derive(Debug, Clone)]
@@ -75,6 +76,10 @@ where
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         self.endpoint.body()
     }
+
+    fn spooled_body(&self) -> Result<Option<(&'static str, SpooledBody)>, BodyError> {
+        self.endpoint.spooled_body()
+    }
 }
 
 impl<'a, E> Pageable for Sudo<'a, E>
@@ -84,6 +89,10 @@ where
     fn use_keyset_pagination(&self) -> bool {
         self.endpoint.use_keyset_pagination()
     }
+
+    fn keyset_pagination_feature(&self) -> Option<Feature> {
+        self.endpoint.keyset_pagination_feature()
+    }
 }
 
 #[cfg(test)]
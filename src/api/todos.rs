@@ -0,0 +1,31 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! To-do item API endpoints.
+//!
+//! These endpoints are used for querying and resolving the to-do items of the
+//! current user.
+
+mod mark_all_done;
+mod mark_done;
+mod todos;
+
+pub use self::mark_all_done::MarkAllTodosAsDone;
+pub use self::mark_all_done::MarkAllTodosAsDoneBuilder;
+pub use self::mark_all_done::MarkAllTodosAsDoneBuilderError;
+
+pub use self::mark_done::MarkTodoAsDone;
+pub use self::mark_done::MarkTodoAsDoneBuilder;
+pub use self::mark_done::MarkTodoAsDoneBuilderError;
+
+pub use self::todos::TodoAction;
+pub use self::todos::TodoState;
+pub use self::todos::TodoTargetType;
+pub use self::todos::Todos;
+pub use self::todos::TodosBuilder;
+pub use self::todos::TodosBuilderError;
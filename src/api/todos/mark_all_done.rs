@@ -0,0 +1,57 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Mark all pending to-do items of the current user as done.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct MarkAllTodosAsDone {}
+
+impl MarkAllTodosAsDone {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MarkAllTodosAsDoneBuilder {
+        MarkAllTodosAsDoneBuilder::default()
+    }
+}
+
+impl Endpoint for MarkAllTodosAsDone {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "todos/mark_as_done".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::todos::MarkAllTodosAsDone;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        MarkAllTodosAsDone::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("todos/mark_as_done")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MarkAllTodosAsDone::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Mark a to-do item as done.
+#[derive(Debug, Builder, Clone, Copy)]
+pub struct MarkTodoAsDone {
+    /// The ID of the to-do item.
+    todo: u64,
+}
+
+impl MarkTodoAsDone {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MarkTodoAsDoneBuilder {
+        MarkTodoAsDoneBuilder::default()
+    }
+}
+
+impl Endpoint for MarkTodoAsDone {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("todos/{}/mark_as_done", self.todo).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::todos::{MarkTodoAsDone, MarkTodoAsDoneBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn todo_is_necessary() {
+        let err = MarkTodoAsDone::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MarkTodoAsDoneBuilderError, "todo");
+    }
+
+    #[test]
+    fn todo_is_sufficient() {
+        MarkTodoAsDone::builder().todo(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("todos/1/mark_as_done")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MarkTodoAsDone::builder().todo(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,347 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Actions which cause a to-do item to be created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TodoAction {
+    /// The user was assigned to a resource.
+    Assigned,
+    /// The user was mentioned.
+    Mentioned,
+    /// A pipeline failed.
+    BuildFailed,
+    /// The user added a to-do item manually.
+    Marked,
+    /// The user's approval is required.
+    ApprovalRequired,
+    /// A merge request can no longer be merged.
+    Unmergeable,
+    /// The user was mentioned at the start of a comment.
+    DirectlyAddressed,
+    /// A merge request was removed from a merge train.
+    MergeTrainRemoved,
+    /// A user requested access to a resource the user can grant access to.
+    MemberAccessRequested,
+    /// A review was requested from the user.
+    ReviewRequested,
+}
+
+impl TodoAction {
+    /// The action as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            TodoAction::Assigned => "assigned",
+            TodoAction::Mentioned => "mentioned",
+            TodoAction::BuildFailed => "build_failed",
+            TodoAction::Marked => "marked",
+            TodoAction::ApprovalRequired => "approval_required",
+            TodoAction::Unmergeable => "unmergeable",
+            TodoAction::DirectlyAddressed => "directly_addressed",
+            TodoAction::MergeTrainRemoved => "merge_train_removed",
+            TodoAction::MemberAccessRequested => "member_access_requested",
+            TodoAction::ReviewRequested => "review_requested",
+        }
+    }
+}
+
+impl ParamValue<'static> for TodoAction {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// States of to-do items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TodoState {
+    /// The to-do item still needs attention.
+    Pending,
+    /// The to-do item has been handled.
+    Done,
+}
+
+impl TodoState {
+    /// The state as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            TodoState::Pending => "pending",
+            TodoState::Done => "done",
+        }
+    }
+}
+
+impl ParamValue<'static> for TodoState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Types of resources to-do items may target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TodoTargetType {
+    /// Issues.
+    Issue,
+    /// Merge requests.
+    MergeRequest,
+    /// Commits.
+    Commit,
+    /// Epics.
+    Epic,
+    /// Designs.
+    Design,
+    /// Alerts.
+    Alert,
+    /// Projects.
+    Project,
+    /// Namespaces (groups).
+    Namespace,
+    /// Vulnerabilities.
+    Vulnerability,
+    /// Wiki pages.
+    WikiPage,
+}
+
+impl TodoTargetType {
+    /// The target type as a query parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            TodoTargetType::Issue => "Issue",
+            TodoTargetType::MergeRequest => "MergeRequest",
+            TodoTargetType::Commit => "Commit",
+            TodoTargetType::Epic => "Epic",
+            TodoTargetType::Design => "DesignManagement::Design",
+            TodoTargetType::Alert => "AlertManagement::Alert",
+            TodoTargetType::Project => "Project",
+            TodoTargetType::Namespace => "Namespace",
+            TodoTargetType::Vulnerability => "Vulnerability",
+            TodoTargetType::WikiPage => "WikiPage::Meta",
+        }
+    }
+}
+
+impl ParamValue<'static> for TodoTargetType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for to-do items of the current user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Todos {
+    /// Filter to-do items by the action which created them.
+    #[builder(default)]
+    action: Option<TodoAction>,
+    /// Filter to-do items by the ID of the user which caused them.
+    #[builder(default)]
+    author_id: Option<u64>,
+    /// Filter to-do items by project ID.
+    #[builder(default)]
+    project_id: Option<u64>,
+    /// Filter to-do items by group ID.
+    #[builder(default)]
+    group_id: Option<u64>,
+    /// Filter to-do items by state.
+    #[builder(default)]
+    state: Option<TodoState>,
+    /// Filter to-do items by the type of their target.
+    #[builder(default)]
+    type_: Option<TodoTargetType>,
+}
+
+impl Todos {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TodosBuilder {
+        TodosBuilder::default()
+    }
+}
+
+impl Endpoint for Todos {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "todos".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("action", self.action)
+            .push_opt("author_id", self.author_id)
+            .push_opt("project_id", self.project_id)
+            .push_opt("group_id", self.group_id)
+            .push_opt("state", self.state)
+            .push_opt("type", self.type_);
+
+        params
+    }
+}
+
+impl Pageable for Todos {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::todos::{TodoAction, TodoState, TodoTargetType, Todos};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn todo_action_as_str() {
+        let items = &[
+            (TodoAction::Assigned, "assigned"),
+            (TodoAction::Mentioned, "mentioned"),
+            (TodoAction::BuildFailed, "build_failed"),
+            (TodoAction::Marked, "marked"),
+            (TodoAction::ApprovalRequired, "approval_required"),
+            (TodoAction::Unmergeable, "unmergeable"),
+            (TodoAction::DirectlyAddressed, "directly_addressed"),
+            (TodoAction::MergeTrainRemoved, "merge_train_removed"),
+            (TodoAction::MemberAccessRequested, "member_access_requested"),
+            (TodoAction::ReviewRequested, "review_requested"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn todo_state_as_str() {
+        let items = &[(TodoState::Pending, "pending"), (TodoState::Done, "done")];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn todo_target_type_as_str() {
+        let items = &[
+            (TodoTargetType::Issue, "Issue"),
+            (TodoTargetType::MergeRequest, "MergeRequest"),
+            (TodoTargetType::Commit, "Commit"),
+            (TodoTargetType::Epic, "Epic"),
+            (TodoTargetType::Design, "DesignManagement::Design"),
+            (TodoTargetType::Alert, "AlertManagement::Alert"),
+            (TodoTargetType::Project, "Project"),
+            (TodoTargetType::Namespace, "Namespace"),
+            (TodoTargetType::Vulnerability, "Vulnerability"),
+            (TodoTargetType::WikiPage, "WikiPage::Meta"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Todos::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("todos").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_action() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("todos")
+            .add_query_params(&[("action", "review_requested")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder()
+            .action(TodoAction::ReviewRequested)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_author_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("todos")
+            .add_query_params(&[("author_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder().author_id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("todos")
+            .add_query_params(&[("project_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder().project_id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_group_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("todos")
+            .add_query_params(&[("group_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder().group_id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("todos")
+            .add_query_params(&[("state", "done")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder().state(TodoState::Done).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("todos")
+            .add_query_params(&[("type", "MergeRequest")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Todos::builder()
+            .type_(TodoTargetType::MergeRequest)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
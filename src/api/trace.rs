@@ -0,0 +1,303 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tracing support for API requests.
+//!
+//! With the `tracing` feature, each request is wrapped in a `gitlab_request` span which records
+//! the method, path, response status, number of retries, and elapsed time. Pages fetched by
+//! paginated queries and retries by [`retry::Client`](crate::api::retry::Client) are emitted as
+//! events within the span. Without the feature, these functions do nothing.
+
+use std::future::Future;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::Response;
+
+#[cfg(feature = "tracing")]
+mod imp {
+    use std::time::Instant;
+
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::Response;
+    use tracing::field::Empty;
+    use tracing::Span;
+
+    pub(super) struct RequestSpan {
+        span: Span,
+        start: Instant,
+    }
+
+    impl RequestSpan {
+        pub(super) fn new(req: &RequestBuilder) -> Self {
+            let method = req.method_ref().map(|method| method.as_str()).unwrap_or("");
+            let path = req.uri_ref().map(|uri| uri.path()).unwrap_or("");
+            let span = tracing::debug_span!(
+                target: "gitlab",
+                "gitlab_request",
+                method,
+                path,
+                status = Empty,
+                retries = 0u64,
+                elapsed_ms = Empty,
+            );
+
+            Self {
+                span,
+                start: Instant::now(),
+            }
+        }
+
+        pub(super) fn span(&self) -> &Span {
+            &self.span
+        }
+
+        pub(super) fn finish<E>(&self, rsp: &Result<Response<Bytes>, E>) {
+            if let Ok(rsp) = rsp {
+                self.span.record("status", rsp.status().as_u16());
+            }
+            self.span
+                .record("elapsed_ms", self.start.elapsed().as_millis() as u64);
+        }
+    }
+}
+
+/// Send a request within a span.
+pub(crate) fn request<F, E>(req: RequestBuilder, send: F) -> Result<Response<Bytes>, E>
+where
+    F: FnOnce(RequestBuilder) -> Result<Response<Bytes>, E>,
+{
+    #[cfg(feature = "tracing")]
+    {
+        let span = imp::RequestSpan::new(&req);
+        let rsp = span.span().in_scope(|| send(req));
+        span.finish(&rsp);
+        rsp
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    send(req)
+}
+
+/// Send a request asynchronously within a span.
+pub(crate) async fn request_async<F, Fut, E>(
+    req: RequestBuilder,
+    send: F,
+) -> Result<Response<Bytes>, E>
+where
+    F: FnOnce(RequestBuilder) -> Fut,
+    Fut: Future<Output = Result<Response<Bytes>, E>>,
+{
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::Instrument;
+
+        let span = imp::RequestSpan::new(&req);
+        let rsp = send(req).instrument(span.span().clone()).await;
+        span.finish(&rsp);
+        rsp
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    send(req).await
+}
+
+/// Note that a request is being retried.
+pub(crate) fn retry(attempt: usize, delay: Duration) {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::Span::current().record("retries", attempt as u64);
+        tracing::debug!(
+            target: "gitlab",
+            attempt,
+            delay_ms = delay.as_millis() as u64,
+            "retrying request",
+        );
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = (attempt, delay);
+}
+
+/// Note that a page of results has been received.
+pub(crate) fn page(items: usize, total: usize, last: bool) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(target: "gitlab", items, total, last, "received page");
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = (items, total, last);
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use http::StatusCode;
+    use serde_json::json;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use tracing_core::span::Current;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::retry::{self, Backoff};
+    use crate::api::{self, Pageable, Pagination, Query};
+    use crate::testing::{ExpectedRequest, MockClient, MockResponse};
+
+    type Fields = BTreeMap<String, String>;
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl<'a> Visit for FieldVisitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name().into(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().into(), value.into());
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorded {
+        spans: Vec<(&'static Metadata<'static>, Fields)>,
+        events: Vec<Fields>,
+        stack: Vec<Id>,
+    }
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        recorded: Arc<Mutex<Recorded>>,
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes) -> Id {
+            let mut fields = Fields::new();
+            span.record(&mut FieldVisitor(&mut fields));
+            let mut recorded = self.recorded.lock().unwrap();
+            recorded.spans.push((span.metadata(), fields));
+            Id::from_u64(recorded.spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record) {
+            let mut recorded = self.recorded.lock().unwrap();
+            let (_, fields) = &mut recorded.spans[span.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor(fields));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event) {
+            let mut fields = Fields::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            self.recorded.lock().unwrap().events.push(fields);
+        }
+
+        fn enter(&self, span: &Id) {
+            self.recorded.lock().unwrap().stack.push(span.clone());
+        }
+
+        fn exit(&self, _: &Id) {
+            self.recorded.lock().unwrap().stack.pop();
+        }
+
+        fn current_span(&self) -> Current {
+            let recorded = self.recorded.lock().unwrap();
+            if let Some(id) = recorded.stack.last() {
+                let (metadata, _) = recorded.spans[id.into_u64() as usize - 1];
+                Current::new(id.clone(), metadata)
+            } else {
+                Current::none()
+            }
+        }
+    }
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    fn dummy() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("dummy")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn request_span() {
+        let client = MockClient::new();
+        client
+            .expect(
+                dummy(),
+                MockResponse::empty().status(StatusCode::SERVICE_UNAVAILABLE),
+            )
+            .expect(dummy(), MockResponse::json(&json!({})));
+        let backoff = Backoff::builder()
+            .init(Duration::from_secs(0))
+            .build()
+            .unwrap();
+        let client = retry::Client::new(client, backoff);
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            api::ignore(Dummy).query(&client).unwrap();
+        });
+
+        let recorded = recorder.recorded.lock().unwrap();
+        assert_eq!(recorded.spans.len(), 1);
+        let (metadata, fields) = &recorded.spans[0];
+        assert_eq!(metadata.name(), "gitlab_request");
+        assert_eq!(fields["method"], "GET");
+        assert_eq!(fields["path"], "/api/v4/dummy");
+        assert_eq!(fields["status"], "200");
+        assert_eq!(fields["retries"], "1");
+        assert!(fields.contains_key("elapsed_ms"));
+
+        assert_eq!(recorded.events.len(), 1);
+        assert_eq!(recorded.events[0]["message"], "retrying request");
+        assert_eq!(recorded.events[0]["attempt"], "1");
+    }
+
+    #[test]
+    fn page_events() {
+        let client = MockClient::new();
+        client.expect_paged(dummy(), [json!({}), json!({})]);
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let _: Vec<serde_json::Value> =
+                api::paged(Dummy, Pagination::All).query(&client).unwrap();
+        });
+
+        let recorded = recorder.recorded.lock().unwrap();
+        assert_eq!(recorded.spans.len(), 1);
+        assert_eq!(recorded.events.len(), 1);
+        let event = &recorded.events[0];
+        assert_eq!(event["message"], "received page");
+        assert_eq!(event["items"], "2");
+        assert_eq!(event["total"], "2");
+        assert_eq!(event["last"], "true");
+    }
+}
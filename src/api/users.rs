@@ -10,32 +10,76 @@
 //!
 //! These endpoints are used for querying and modifying users and their resources.
 
+mod ban;
 mod create;
+mod create_runner;
 mod current_user;
+mod current_user_status;
+mod disable_two_factor;
+mod events;
+pub mod gpg_keys;
 pub mod impersonation_tokens;
+pub mod keys;
 pub mod personal_access_tokens;
 mod projects;
+mod set_status;
+mod status;
+mod unban;
 mod user;
 mod users;
 
+pub use self::ban::BanUser;
+pub use self::ban::BanUserBuilder;
+pub use self::ban::BanUserBuilderError;
+
 pub use self::create::CreateUser;
 pub use self::create::CreateUserBuilder;
 pub use self::create::CreateUserBuilderError;
 pub use self::create::NewUserPassword;
 
+pub use self::create_runner::CreateUserRunner;
+pub use self::create_runner::CreateUserRunnerBuilder;
+pub use self::create_runner::CreateUserRunnerBuilderError;
+
 pub use self::projects::UserProjects;
 pub use self::projects::UserProjectsBuilder;
 pub use self::projects::UserProjectsBuilderError;
 pub use self::projects::UserProjectsOrderBy;
 
+pub use self::set_status::SetCurrentUserStatus;
+pub use self::set_status::SetCurrentUserStatusBuilder;
+pub use self::set_status::SetCurrentUserStatusBuilderError;
+pub use self::set_status::UserAvailability;
+pub use self::set_status::UserStatusClearAfter;
+
+pub use self::status::UserStatus;
+pub use self::status::UserStatusBuilder;
+pub use self::status::UserStatusBuilderError;
+
+pub use self::unban::UnbanUser;
+pub use self::unban::UnbanUserBuilder;
+pub use self::unban::UnbanUserBuilderError;
+
 pub use self::user::User;
 pub use self::user::UserBuilder;
 pub use self::user::UserBuilderError;
 
+pub use self::disable_two_factor::DisableUserTwoFactor;
+pub use self::disable_two_factor::DisableUserTwoFactorBuilder;
+pub use self::disable_two_factor::DisableUserTwoFactorBuilderError;
+
+pub use self::events::UserEvents;
+pub use self::events::UserEventsBuilder;
+pub use self::events::UserEventsBuilderError;
+
 pub use self::current_user::CurrentUser;
 pub use self::current_user::CurrentUserBuilder;
 pub use self::current_user::CurrentUserBuilderError;
 
+pub use self::current_user_status::CurrentUserStatus;
+pub use self::current_user_status::CurrentUserStatusBuilder;
+pub use self::current_user_status::CurrentUserStatusBuilderError;
+
 pub use self::users::ExternalProvider;
 pub use self::users::ExternalProviderBuilder;
 pub use self::users::ExternalProviderBuilderError;
@@ -0,0 +1,84 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Ban a user.
+///
+/// Requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct BanUser {
+    /// The ID of the user.
+    user: u64,
+}
+
+impl BanUser {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BanUserBuilder {
+        BanUserBuilder::default()
+    }
+}
+
+impl BanUserBuilder {
+    fn validate(&self) -> Result<(), BanUserBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for BanUser {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/ban", self.user.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{BanUser, BanUserBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = BanUser::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BanUserBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        BanUser::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = BanUser::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, BanUserBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/ban")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BanUser::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -287,13 +287,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&password=test-password",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("password", "test-password"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -313,13 +312,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -339,13 +337,12 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&force_random_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("force_random_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -365,14 +362,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&reset_password=true",
-                "&force_random_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("reset_password", "true"),
+                ("force_random_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -392,14 +388,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&skip_confirmation=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("skip_confirmation", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -420,14 +415,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&admin=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("admin", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -448,14 +442,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&auditor=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("auditor", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -476,14 +469,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&external=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("external", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -504,14 +496,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&group_id_for_saml=1",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("group_id_for_saml", "1"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -532,14 +523,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&note=admin+notes",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("note", "admin notes"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -560,15 +550,14 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&reset_password=true",
-                "&extern_uid=foobar",
-                "&provider=magic",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("reset_password", "true"),
+                ("extern_uid", "foobar"),
+                ("provider", "magic"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -595,14 +584,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&color_scheme_id=1",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("color_scheme_id", "1"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -623,14 +611,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&private_profile=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("private_profile", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -651,14 +638,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&theme_id=1",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("theme_id", "1"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -679,14 +665,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&view_diffs_file_by_file=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("view_diffs_file_by_file", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -707,14 +692,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&can_create_group=true",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("can_create_group", "true"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -735,14 +719,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&extra_shared_runners_minutes_limit=10",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("extra_shared_runners_minutes_limit", "10"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -763,14 +746,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&projects_limit=100",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("projects_limit", "100"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -791,14 +773,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&shared_runners_minutes_limit=1000",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("shared_runners_minutes_limit", "1000"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -819,14 +800,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&bio=bio",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("bio", "bio"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -847,14 +827,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&commit_email=commit%40example.com",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("commit_email", "commit@example.com"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -875,14 +854,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&linkedin=linkedin_url",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("linkedin", "linkedin_url"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -903,14 +881,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&location=home",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("location", "home"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -931,14 +908,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&organization=mywork",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("organization", "mywork"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -959,14 +935,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&pronouns=robot",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("pronouns", "robot"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -987,14 +962,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&public_email=public%40example.com",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("public_email", "public@example.com"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1015,14 +989,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&skype=skype_url",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("skype", "skype_url"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1043,14 +1016,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&twitter=twitter_handle",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("twitter", "twitter_handle"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1071,14 +1043,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&discord=discord_username",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("discord", "discord_username"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -1099,14 +1070,13 @@ mod tests {
         let endpoint = ExpectedUrl::builder()
             .method(Method::POST)
             .endpoint("users")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str(concat!(
-                "email=user%40example.com",
-                "&name=name",
-                "&username=username",
-                "&website_url=homepage",
-                "&reset_password=true",
-            ))
+            .form_params(&[
+                ("email", "user@example.com"),
+                ("name", "name"),
+                ("username", "username"),
+                ("website_url", "homepage"),
+                ("reset_password", "true"),
+            ])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -0,0 +1,339 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::CommaSeparatedList;
+use crate::api::endpoint_prelude::*;
+use crate::api::runners::{RunnerAccessLevel, RunnerType, MAX_MAINTENANCE_NOTE_LENGTH};
+
+/// Create a runner owned by the current user.
+///
+/// This replaces registration tokens. The response contains the authentication token to use
+/// when registering the runner.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateUserRunner<'a> {
+    /// The scope of the runner.
+    runner_type: RunnerType,
+    /// The ID of the group to create the runner for.
+    ///
+    /// Required for group runners.
+    #[builder(default)]
+    group_id: Option<u64>,
+    /// The ID of the project to create the runner for.
+    ///
+    /// Required for project runners.
+    #[builder(default)]
+    project_id: Option<u64>,
+
+    /// The description of the runner.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// Whether the runner should ignore new jobs or not.
+    #[builder(default)]
+    paused: Option<bool>,
+    /// Whether the runner is locked or not.
+    #[builder(default)]
+    locked: Option<bool>,
+    /// Whether the runner can execute untagged jobs or not.
+    #[builder(default)]
+    run_untagged: Option<bool>,
+    /// Set the tags for the runner.
+    #[builder(setter(name = "_tag_list"), default, private)]
+    tag_list: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// The access level of the runner.
+    #[builder(default)]
+    access_level: Option<RunnerAccessLevel>,
+    /// The maximum timeout allowed on the runner (in seconds).
+    #[builder(default)]
+    maximum_timeout: Option<u64>,
+    /// Maintenance note for the runner.
+    ///
+    /// Maximum size is 1024.
+    #[builder(setter(into), default)]
+    maintenance_note: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateUserRunner<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateUserRunnerBuilder<'a> {
+        CreateUserRunnerBuilder::default()
+    }
+}
+
+impl<'a> CreateUserRunnerBuilder<'a> {
+    /// Add a tag to the runner.
+    pub fn tag<T>(&mut self, tag: T) -> &mut Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(tag.into());
+        self
+    }
+
+    /// Add multiple tags to the runner.
+    pub fn tags<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<Cow<'a, str>>,
+    {
+        self.tag_list
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.map(|t| t.into()));
+        self
+    }
+
+    fn validate(&self) -> Result<(), CreateUserRunnerBuilderError> {
+        let has_group = matches!(self.group_id, Some(Some(_)));
+        let has_project = matches!(self.project_id, Some(Some(_)));
+
+        let error = match self.runner_type {
+            Some(RunnerType::Group) if !has_group => {
+                Some("`group_id` is required for group runners")
+            },
+            Some(RunnerType::Project) if !has_project => {
+                Some("`project_id` is required for project runners")
+            },
+            Some(RunnerType::Instance) | Some(RunnerType::Project) if has_group => {
+                Some("`group_id` is only valid for group runners")
+            },
+            Some(RunnerType::Instance) | Some(RunnerType::Group) if has_project => {
+                Some("`project_id` is only valid for project runners")
+            },
+            _ => None,
+        };
+        if let Some(error) = error {
+            return Err(CreateUserRunnerBuilderError::ValidationError(error.into()));
+        }
+
+        if let Some(Some(maintenance_note)) = self.maintenance_note.as_ref() {
+            if maintenance_note.len() > MAX_MAINTENANCE_NOTE_LENGTH {
+                return Err(format!(
+                    "`maintenance_note` may be at most {} bytes",
+                    MAX_MAINTENANCE_NOTE_LENGTH,
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateUserRunner<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/runners".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("runner_type", self.runner_type)
+            .push_opt("group_id", self.group_id)
+            .push_opt("project_id", self.project_id)
+            .push_opt("description", self.description.as_ref())
+            .push_opt("paused", self.paused)
+            .push_opt("locked", self.locked)
+            .push_opt("run_untagged", self.run_untagged)
+            .push_opt("tag_list", self.tag_list.as_ref())
+            .push_opt("access_level", self.access_level)
+            .push_opt("maximum_timeout", self.maximum_timeout)
+            .push_opt("maintenance_note", self.maintenance_note.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::runners::{RunnerAccessLevel, RunnerType, MAX_MAINTENANCE_NOTE_LENGTH};
+    use crate::api::users::{CreateUserRunner, CreateUserRunnerBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn assert_validation_error(err: CreateUserRunnerBuilderError, expected: &str) {
+        if let CreateUserRunnerBuilderError::ValidationError(message) = err {
+            assert_eq!(message, expected);
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn runner_type_is_required() {
+        let err = CreateUserRunner::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserRunnerBuilderError, "runner_type");
+    }
+
+    #[test]
+    fn runner_type_is_sufficient_for_instance() {
+        CreateUserRunner::builder()
+            .runner_type(RunnerType::Instance)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn group_id_is_required_for_group() {
+        let err = CreateUserRunner::builder()
+            .runner_type(RunnerType::Group)
+            .build()
+            .unwrap_err();
+        assert_validation_error(err, "`group_id` is required for group runners");
+    }
+
+    #[test]
+    fn project_id_is_required_for_project() {
+        let err = CreateUserRunner::builder()
+            .runner_type(RunnerType::Project)
+            .build()
+            .unwrap_err();
+        assert_validation_error(err, "`project_id` is required for project runners");
+    }
+
+    #[test]
+    fn group_id_is_only_for_group() {
+        let err = CreateUserRunner::builder()
+            .runner_type(RunnerType::Instance)
+            .group_id(1)
+            .build()
+            .unwrap_err();
+        assert_validation_error(err, "`group_id` is only valid for group runners");
+    }
+
+    #[test]
+    fn project_id_is_only_for_project() {
+        let err = CreateUserRunner::builder()
+            .runner_type(RunnerType::Group)
+            .group_id(1)
+            .project_id(1)
+            .build()
+            .unwrap_err();
+        assert_validation_error(err, "`project_id` is only valid for project runners");
+    }
+
+    #[test]
+    fn maintenance_note_length() {
+        let too_long = format!("{:width$}", "note", width = MAX_MAINTENANCE_NOTE_LENGTH + 1);
+        let err = CreateUserRunner::builder()
+            .runner_type(RunnerType::Instance)
+            .maintenance_note(too_long)
+            .build()
+            .unwrap_err();
+        assert_validation_error(
+            err,
+            &format!(
+                "`maintenance_note` may be at most {} bytes",
+                MAX_MAINTENANCE_NOTE_LENGTH,
+            ),
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("runner_type=instance_type")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserRunner::builder()
+            .runner_type(RunnerType::Instance)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_group_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("runner_type=group_type", "&group_id=1"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserRunner::builder()
+            .runner_type(RunnerType::Group)
+            .group_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("runner_type=project_type", "&project_id=1"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserRunner::builder()
+            .runner_type(RunnerType::Project)
+            .project_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/runners")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "runner_type=instance_type",
+                "&description=desc",
+                "&paused=true",
+                "&locked=false",
+                "&run_untagged=false",
+                "&tag_list=tag2%2Ctag1%2Ctag3",
+                "&access_level=ref_protected",
+                "&maximum_timeout=3600",
+                "&maintenance_note=note",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserRunner::builder()
+            .runner_type(RunnerType::Instance)
+            .description("desc")
+            .paused(true)
+            .locked(false)
+            .run_untagged(false)
+            .tag("tag2")
+            .tags(["tag1", "tag3"].iter().cloned())
+            .access_level(RunnerAccessLevel::RefProtected)
+            .maximum_timeout(3600)
+            .maintenance_note("note")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
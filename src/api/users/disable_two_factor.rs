@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Disable two-factor authentication for a user.
+///
+/// Requires administrator privileges. This may be used to recover accounts which have lost access
+/// to their two-factor authentication device and recovery codes.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DisableUserTwoFactor {
+    /// The ID of the user.
+    user: u64,
+}
+
+impl DisableUserTwoFactor {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DisableUserTwoFactorBuilder {
+        DisableUserTwoFactorBuilder::default()
+    }
+}
+
+impl DisableUserTwoFactorBuilder {
+    fn validate(&self) -> Result<(), DisableUserTwoFactorBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for DisableUserTwoFactor {
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/disable_two_factor", self.user.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{DisableUserTwoFactor, DisableUserTwoFactorBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = DisableUserTwoFactor::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DisableUserTwoFactorBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        DisableUserTwoFactor::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = DisableUserTwoFactor::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DisableUserTwoFactorBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PATCH)
+            .endpoint("users/1/disable_two_factor")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DisableUserTwoFactor::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
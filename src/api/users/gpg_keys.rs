@@ -0,0 +1,52 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! User GPG key API endpoints.
+//!
+//! These endpoints are used for querying and managing the GPG keys of users.
+
+mod create;
+mod create_current_user_gpg_key;
+mod current_user_gpg_key;
+mod current_user_gpg_keys;
+mod delete;
+mod delete_current_user_gpg_key;
+mod gpg_key;
+mod gpg_keys;
+
+pub use self::create::CreateUserGpgKey;
+pub use self::create::CreateUserGpgKeyBuilder;
+pub use self::create::CreateUserGpgKeyBuilderError;
+
+pub use self::create_current_user_gpg_key::CreateCurrentUserGpgKey;
+pub use self::create_current_user_gpg_key::CreateCurrentUserGpgKeyBuilder;
+pub use self::create_current_user_gpg_key::CreateCurrentUserGpgKeyBuilderError;
+
+pub use self::current_user_gpg_key::CurrentUserGpgKey;
+pub use self::current_user_gpg_key::CurrentUserGpgKeyBuilder;
+pub use self::current_user_gpg_key::CurrentUserGpgKeyBuilderError;
+
+pub use self::current_user_gpg_keys::CurrentUserGpgKeys;
+pub use self::current_user_gpg_keys::CurrentUserGpgKeysBuilder;
+pub use self::current_user_gpg_keys::CurrentUserGpgKeysBuilderError;
+
+pub use self::delete::DeleteUserGpgKey;
+pub use self::delete::DeleteUserGpgKeyBuilder;
+pub use self::delete::DeleteUserGpgKeyBuilderError;
+
+pub use self::delete_current_user_gpg_key::DeleteCurrentUserGpgKey;
+pub use self::delete_current_user_gpg_key::DeleteCurrentUserGpgKeyBuilder;
+pub use self::delete_current_user_gpg_key::DeleteCurrentUserGpgKeyBuilderError;
+
+pub use self::gpg_key::UserGpgKey;
+pub use self::gpg_key::UserGpgKeyBuilder;
+pub use self::gpg_key::UserGpgKeyBuilderError;
+
+pub use self::gpg_keys::UserGpgKeys;
+pub use self::gpg_keys::UserGpgKeysBuilder;
+pub use self::gpg_keys::UserGpgKeysBuilderError;
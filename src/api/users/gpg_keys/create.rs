@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Add a GPG key for a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateUserGpgKey<'a> {
+    /// The user to add the GPG key to.
+    user: u64,
+    /// The ASCII-armored public key.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> CreateUserGpgKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateUserGpgKeyBuilder<'a> {
+        CreateUserGpgKeyBuilder::default()
+    }
+}
+
+impl<'a> CreateUserGpgKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateUserGpgKeyBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateUserGpgKey<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/gpg_keys", self.user.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("key", &self.key);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::gpg_keys::{CreateUserGpgKey, CreateUserGpgKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_key_are_necessary() {
+        let err = CreateUserGpgKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserGpgKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = CreateUserGpgKey::builder()
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserGpgKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = CreateUserGpgKey::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserGpgKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn user_and_key_are_sufficient() {
+        CreateUserGpgKey::builder()
+            .user(1)
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = CreateUserGpgKey::builder()
+            .user(0)
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateUserGpgKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/gpg_keys")
+            .form_params(&[("key", "-----BEGIN PGP PUBLIC KEY BLOCK-----")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserGpgKey::builder()
+            .user(1)
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
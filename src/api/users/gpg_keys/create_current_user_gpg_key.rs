@@ -0,0 +1,84 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Add a GPG key for the authenticated user.
+#[derive(Debug, Builder, Clone)]
+pub struct CreateCurrentUserGpgKey<'a> {
+    /// The ASCII-armored public key.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+}
+
+impl<'a> CreateCurrentUserGpgKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateCurrentUserGpgKeyBuilder<'a> {
+        CreateCurrentUserGpgKeyBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateCurrentUserGpgKey<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/gpg_keys".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("key", &self.key);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::gpg_keys::{
+        CreateCurrentUserGpgKey, CreateCurrentUserGpgKeyBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_necessary() {
+        let err = CreateCurrentUserGpgKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserGpgKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_sufficient() {
+        CreateCurrentUserGpgKey::builder()
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/gpg_keys")
+            .form_params(&[("key", "-----BEGIN PGP PUBLIC KEY BLOCK-----")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserGpgKey::builder()
+            .key("-----BEGIN PGP PUBLIC KEY BLOCK-----")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Get a single GPG key of the authenticated user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CurrentUserGpgKey {
+    /// The ID of the GPG key.
+    key_id: u64,
+}
+
+impl CurrentUserGpgKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentUserGpgKeyBuilder {
+        CurrentUserGpgKeyBuilder::default()
+    }
+}
+
+impl CurrentUserGpgKeyBuilder {
+    fn validate(&self) -> Result<(), CurrentUserGpgKeyBuilderError> {
+        common::validate_path_param("key_id", self.key_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for CurrentUserGpgKey {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/gpg_keys/{}", self.key_id.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::gpg_keys::{CurrentUserGpgKey, CurrentUserGpgKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_id_is_necessary() {
+        let err = CurrentUserGpgKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CurrentUserGpgKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn key_id_is_sufficient() {
+        CurrentUserGpgKey::builder().key_id(2).build().unwrap();
+    }
+
+    #[test]
+    fn key_id_must_be_valid() {
+        let err = CurrentUserGpgKey::builder().key_id(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CurrentUserGpgKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("user/gpg_keys/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentUserGpgKey::builder().key_id(2).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
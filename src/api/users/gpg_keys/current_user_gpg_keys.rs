@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the GPG keys of the authenticated user.
+#[derive(Debug, Builder, Clone)]
+pub struct CurrentUserGpgKeys {}
+
+impl CurrentUserGpgKeys {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentUserGpgKeysBuilder {
+        CurrentUserGpgKeysBuilder::default()
+    }
+}
+
+impl Endpoint for CurrentUserGpgKeys {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/gpg_keys".into()
+    }
+}
+
+impl Pageable for CurrentUserGpgKeys {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::gpg_keys::CurrentUserGpgKeys;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        CurrentUserGpgKeys::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("user/gpg_keys")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentUserGpgKeys::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
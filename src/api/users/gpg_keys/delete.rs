@@ -0,0 +1,114 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete a GPG key of a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteUserGpgKey {
+    /// The user to delete the GPG key of.
+    user: u64,
+    /// The ID of the GPG key.
+    key_id: u64,
+}
+
+impl DeleteUserGpgKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteUserGpgKeyBuilder {
+        DeleteUserGpgKeyBuilder::default()
+    }
+}
+
+impl DeleteUserGpgKeyBuilder {
+    fn validate(&self) -> Result<(), DeleteUserGpgKeyBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+        common::validate_path_param("key_id", self.key_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for DeleteUserGpgKey {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "users/{}/gpg_keys/{}",
+            self.user.path_segment(),
+            self.key_id.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::gpg_keys::{DeleteUserGpgKey, DeleteUserGpgKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_key_id_are_necessary() {
+        let err = DeleteUserGpgKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserGpgKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = DeleteUserGpgKey::builder().key_id(2).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserGpgKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn key_id_is_necessary() {
+        let err = DeleteUserGpgKey::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserGpgKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn user_and_key_id_are_sufficient() {
+        DeleteUserGpgKey::builder()
+            .user(1)
+            .key_id(2)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = DeleteUserGpgKey::builder()
+            .user(0)
+            .key_id(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteUserGpgKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("users/1/gpg_keys/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteUserGpgKey::builder()
+            .user(1)
+            .key_id(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the GPG keys of a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UserGpgKeys {
+    /// The user to list the GPG keys of.
+    user: u64,
+}
+
+impl UserGpgKeys {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserGpgKeysBuilder {
+        UserGpgKeysBuilder::default()
+    }
+}
+
+impl UserGpgKeysBuilder {
+    fn validate(&self) -> Result<(), UserGpgKeysBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for UserGpgKeys {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/gpg_keys", self.user.path_segment()).into()
+    }
+}
+
+impl Pageable for UserGpgKeys {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::gpg_keys::{UserGpgKeys, UserGpgKeysBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_necessary() {
+        let err = UserGpgKeys::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserGpgKeysBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserGpgKeys::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = UserGpgKeys::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UserGpgKeysBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/gpg_keys")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserGpgKeys::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -9,6 +9,7 @@ use std::collections::HashSet;
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -20,6 +21,28 @@ pub enum ImpersonationTokenScope {
     Api,
     /// Access to read the user information.
     ReadUser,
+    /// Access read-only API endpoints.
+    ReadApi,
+    /// Read access to repositories.
+    ReadRepository,
+    /// Write access to repositories.
+    WriteRepository,
+    /// Read access to Docker registries.
+    ReadRegistry,
+    /// Write access to Docker registries.
+    WriteRegistry,
+    /// Permission to `sudo` as other users (administrator only).
+    Sudo,
+    /// Permission to access administrator API actions.
+    AdminMode,
+    /// Permission to create instance runners.
+    CreateRunner,
+    /// Access to AI features (GitLab Duo for JetBrains).
+    AiFeatures,
+    /// Access to perform Kubernetes API calls.
+    K8sProxy,
+    /// Access to the Service Ping payload.
+    ReadServicePing,
 }
 
 impl ImpersonationTokenScope {
@@ -28,6 +51,17 @@ impl ImpersonationTokenScope {
         match self {
             ImpersonationTokenScope::Api => "api",
             ImpersonationTokenScope::ReadUser => "read_user",
+            ImpersonationTokenScope::ReadApi => "read_api",
+            ImpersonationTokenScope::ReadRepository => "read_repository",
+            ImpersonationTokenScope::WriteRepository => "write_repository",
+            ImpersonationTokenScope::ReadRegistry => "read_registry",
+            ImpersonationTokenScope::WriteRegistry => "write_registry",
+            ImpersonationTokenScope::Sudo => "sudo",
+            ImpersonationTokenScope::AdminMode => "admin_mode",
+            ImpersonationTokenScope::CreateRunner => "create_runner",
+            ImpersonationTokenScope::AiFeatures => "ai_features",
+            ImpersonationTokenScope::K8sProxy => "k8s_proxy",
+            ImpersonationTokenScope::ReadServicePing => "read_service_ping",
         }
     }
 }
@@ -40,7 +74,7 @@ impl ParamValue<'static> for ImpersonationTokenScope {
 
 /// Create a new impersonation token for a user.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateImpersonationToken<'a> {
     /// The user to create an impersonation token for.
     user: u64,
@@ -78,6 +112,12 @@ impl<'a> CreateImpersonationTokenBuilder<'a> {
         self.scopes.get_or_insert_with(HashSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), CreateImpersonationTokenBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreateImpersonationToken<'a> {
@@ -86,7 +126,7 @@ impl<'a> Endpoint for CreateImpersonationToken<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/impersonation_tokens", self.user).into()
+        format!("users/{}/impersonation_tokens", self.user.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -96,7 +136,11 @@ impl<'a> Endpoint for CreateImpersonationToken<'a> {
             .push("name", &self.name)
             .push_opt("expires_at", self.expires_at);
 
-        params.extend(self.scopes.iter().map(|&value| ("scopes[]", value)));
+        params.extend(
+            self.scopes
+                .iter()
+                .map(|&value| (ParamName::new("scopes").array(), value)),
+        );
 
         params.into_body()
     }
@@ -118,6 +162,20 @@ mod tests {
         let items = &[
             (ImpersonationTokenScope::Api, "api"),
             (ImpersonationTokenScope::ReadUser, "read_user"),
+            (ImpersonationTokenScope::ReadApi, "read_api"),
+            (ImpersonationTokenScope::ReadRepository, "read_repository"),
+            (ImpersonationTokenScope::WriteRepository, "write_repository"),
+            (ImpersonationTokenScope::ReadRegistry, "read_registry"),
+            (ImpersonationTokenScope::WriteRegistry, "write_registry"),
+            (ImpersonationTokenScope::Sudo, "sudo"),
+            (ImpersonationTokenScope::AdminMode, "admin_mode"),
+            (ImpersonationTokenScope::CreateRunner, "create_runner"),
+            (ImpersonationTokenScope::AiFeatures, "ai_features"),
+            (ImpersonationTokenScope::K8sProxy, "k8s_proxy"),
+            (
+                ImpersonationTokenScope::ReadServicePing,
+                "read_service_ping",
+            ),
         ];
 
         for (i, s) in items {
@@ -171,6 +229,17 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = CreateImpersonationToken::builder()
+            .user(0)
+            .name("name")
+            .scope(ImpersonationTokenScope::ReadUser)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateImpersonationTokenBuilderError, "user");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
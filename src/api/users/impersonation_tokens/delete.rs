@@ -6,10 +6,12 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Delete an impersonation token.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteImpersonationToken {
     /// The user to delete an impersonation token of.
     user: u64,
@@ -24,13 +26,27 @@ impl DeleteImpersonationToken {
     }
 }
 
+impl DeleteImpersonationTokenBuilder {
+    fn validate(&self) -> Result<(), DeleteImpersonationTokenBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+        common::validate_path_param("token_id", self.token_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl Endpoint for DeleteImpersonationToken {
     fn method(&self) -> Method {
         Method::DELETE
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/impersonation_tokens/{}", self.user, self.token_id).into()
+        format!(
+            "users/{}/impersonation_tokens/{}",
+            self.user.path_segment(),
+            self.token_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -77,6 +93,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = DeleteImpersonationToken::builder()
+            .user(0)
+            .token_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteImpersonationTokenBuilderError, "user");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
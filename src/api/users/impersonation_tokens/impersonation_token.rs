@@ -6,10 +6,12 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Get a single impersonation token of a user.
 #[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ImpersonationToken {
     /// The user to create an impersonation token for.
     user: u64,
@@ -24,13 +26,27 @@ impl ImpersonationToken {
     }
 }
 
+impl ImpersonationTokenBuilder {
+    fn validate(&self) -> Result<(), ImpersonationTokenBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+        common::validate_path_param("token_id", self.token_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl Endpoint for ImpersonationToken {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/impersonation_tokens/{}", self.user, self.token_id).into()
+        format!(
+            "users/{}/impersonation_tokens/{}",
+            self.user.path_segment(),
+            self.token_id.path_segment()
+        )
+        .into()
     }
 }
 
@@ -74,6 +90,16 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = ImpersonationToken::builder()
+            .user(0)
+            .token_id(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ImpersonationTokenBuilderError, "user");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -6,6 +6,7 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -40,7 +41,7 @@ impl ParamValue<'static> for ImpersonationTokenState {
 
 /// Get impersonation tokens of a user.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ImpersonationTokens {
     /// The user to list impersonation tokens for.
     user: u64,
@@ -56,13 +57,21 @@ impl ImpersonationTokens {
     }
 }
 
+impl ImpersonationTokensBuilder {
+    fn validate(&self) -> Result<(), ImpersonationTokensBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl Endpoint for ImpersonationTokens {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/impersonation_tokens", self.user).into()
+        format!("users/{}/impersonation_tokens", self.user.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -110,6 +119,12 @@ mod tests {
         ImpersonationTokens::builder().user(1).build().unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = ImpersonationTokens::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, ImpersonationTokensBuilderError, "user");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -0,0 +1,53 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! User SSH key API endpoints.
+//!
+//! These endpoints are used for querying and managing the SSH keys of users.
+
+mod create;
+mod create_current_user_key;
+mod current_user_key;
+mod current_user_keys;
+mod delete;
+mod delete_current_user_key;
+mod key;
+mod keys;
+
+pub use self::create::CreateUserKey;
+pub use self::create::CreateUserKeyBuilder;
+pub use self::create::CreateUserKeyBuilderError;
+pub use self::create::SshKeyUsageType;
+
+pub use self::create_current_user_key::CreateCurrentUserKey;
+pub use self::create_current_user_key::CreateCurrentUserKeyBuilder;
+pub use self::create_current_user_key::CreateCurrentUserKeyBuilderError;
+
+pub use self::current_user_key::CurrentUserKey;
+pub use self::current_user_key::CurrentUserKeyBuilder;
+pub use self::current_user_key::CurrentUserKeyBuilderError;
+
+pub use self::current_user_keys::CurrentUserKeys;
+pub use self::current_user_keys::CurrentUserKeysBuilder;
+pub use self::current_user_keys::CurrentUserKeysBuilderError;
+
+pub use self::delete::DeleteUserKey;
+pub use self::delete::DeleteUserKeyBuilder;
+pub use self::delete::DeleteUserKeyBuilderError;
+
+pub use self::delete_current_user_key::DeleteCurrentUserKey;
+pub use self::delete_current_user_key::DeleteCurrentUserKeyBuilder;
+pub use self::delete_current_user_key::DeleteCurrentUserKeyBuilderError;
+
+pub use self::key::UserKey;
+pub use self::key::UserKeyBuilder;
+pub use self::key::UserKeyBuilderError;
+
+pub use self::keys::UserKeys;
+pub use self::keys::UserKeysBuilder;
+pub use self::keys::UserKeysBuilderError;
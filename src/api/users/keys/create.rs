@@ -0,0 +1,245 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The usage type of an SSH key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SshKeyUsageType {
+    /// The key may be used for authentication.
+    Auth,
+    /// The key may be used for signing.
+    Signing,
+    /// The key may be used for authentication and signing.
+    AuthAndSigning,
+}
+
+impl SshKeyUsageType {
+    fn as_str(self) -> &'static str {
+        match self {
+            SshKeyUsageType::Auth => "auth",
+            SshKeyUsageType::Signing => "signing",
+            SshKeyUsageType::AuthAndSigning => "auth_and_signing",
+        }
+    }
+}
+
+impl ParamValue<'static> for SshKeyUsageType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Add an SSH key for a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateUserKey<'a> {
+    /// The user to add the SSH key to.
+    user: u64,
+    /// The title of the key.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// The public key.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+
+    /// When the key expires.
+    #[builder(default)]
+    expires_at: Option<DateTime<Utc>>,
+    /// What the key may be used for.
+    #[builder(default)]
+    usage_type: Option<SshKeyUsageType>,
+}
+
+impl<'a> CreateUserKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateUserKeyBuilder<'a> {
+        CreateUserKeyBuilder::default()
+    }
+}
+
+impl<'a> CreateUserKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), CreateUserKeyBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CreateUserKey<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/keys", self.user.path_segment()).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", &self.title)
+            .push("key", &self.key)
+            .push_opt("expires_at", self.expires_at)
+            .push_opt("usage_type", self.usage_type);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::users::keys::{CreateUserKey, CreateUserKeyBuilderError, SshKeyUsageType};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn ssh_key_usage_type_as_str() {
+        let items = &[
+            (SshKeyUsageType::Auth, "auth"),
+            (SshKeyUsageType::Signing, "signing"),
+            (SshKeyUsageType::AuthAndSigning, "auth_and_signing"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn user_title_and_key_are_necessary() {
+        let err = CreateUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = CreateUserKey::builder()
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn title_is_necessary() {
+        let err = CreateUserKey::builder()
+            .user(1)
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserKeyBuilderError, "title");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = CreateUserKey::builder()
+            .user(1)
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn user_title_and_key_are_sufficient() {
+        CreateUserKey::builder()
+            .user(1)
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = CreateUserKey::builder()
+            .user(0)
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CreateUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/keys")
+            .form_params(&[("title", "title"), ("key", "ssh-ed25519 AAAA")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserKey::builder()
+            .user(1)
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/keys")
+            .form_params(&[
+                ("title", "title"),
+                ("key", "ssh-ed25519 AAAA"),
+                ("expires_at", "2030-01-01T00:00:00Z"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserKey::builder()
+            .user(1)
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .expires_at(Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_usage_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/keys")
+            .form_params(&[
+                ("title", "title"),
+                ("key", "ssh-ed25519 AAAA"),
+                ("usage_type", "signing"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserKey::builder()
+            .user(1)
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .usage_type(SshKeyUsageType::Signing)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,168 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::users::keys::SshKeyUsageType;
+
+/// Add an SSH key for the authenticated user.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CreateCurrentUserKey<'a> {
+    /// The title of the key.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// The public key.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+
+    /// When the key expires.
+    #[builder(default)]
+    expires_at: Option<DateTime<Utc>>,
+    /// What the key may be used for.
+    #[builder(default)]
+    usage_type: Option<SshKeyUsageType>,
+}
+
+impl<'a> CreateCurrentUserKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateCurrentUserKeyBuilder<'a> {
+        CreateCurrentUserKeyBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateCurrentUserKey<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/keys".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", &self.title)
+            .push("key", &self.key)
+            .push_opt("expires_at", self.expires_at)
+            .push_opt("usage_type", self.usage_type);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::users::keys::{
+        CreateCurrentUserKey, CreateCurrentUserKeyBuilderError, SshKeyUsageType,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn title_and_key_are_necessary() {
+        let err = CreateCurrentUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserKeyBuilderError, "title");
+    }
+
+    #[test]
+    fn title_is_necessary() {
+        let err = CreateCurrentUserKey::builder()
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserKeyBuilderError, "title");
+    }
+
+    #[test]
+    fn key_is_necessary() {
+        let err = CreateCurrentUserKey::builder()
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn title_and_key_are_sufficient() {
+        CreateCurrentUserKey::builder()
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/keys")
+            .form_params(&[("title", "title"), ("key", "ssh-ed25519 AAAA")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserKey::builder()
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/keys")
+            .form_params(&[
+                ("title", "title"),
+                ("key", "ssh-ed25519 AAAA"),
+                ("expires_at", "2030-01-01T00:00:00Z"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserKey::builder()
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .expires_at(Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_usage_type() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/keys")
+            .form_params(&[
+                ("title", "title"),
+                ("key", "ssh-ed25519 AAAA"),
+                ("usage_type", "signing"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserKey::builder()
+            .title("title")
+            .key("ssh-ed25519 AAAA")
+            .usage_type(SshKeyUsageType::Signing)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Get a single SSH key of the authenticated user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CurrentUserKey {
+    /// The ID of the SSH key.
+    key_id: u64,
+}
+
+impl CurrentUserKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentUserKeyBuilder {
+        CurrentUserKeyBuilder::default()
+    }
+}
+
+impl CurrentUserKeyBuilder {
+    fn validate(&self) -> Result<(), CurrentUserKeyBuilderError> {
+        common::validate_path_param("key_id", self.key_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for CurrentUserKey {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/keys/{}", self.key_id.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::keys::{CurrentUserKey, CurrentUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_id_is_necessary() {
+        let err = CurrentUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CurrentUserKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn key_id_is_sufficient() {
+        CurrentUserKey::builder().key_id(2).build().unwrap();
+    }
+
+    #[test]
+    fn key_id_must_be_valid() {
+        let err = CurrentUserKey::builder().key_id(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, CurrentUserKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("user/keys/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentUserKey::builder().key_id(2).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
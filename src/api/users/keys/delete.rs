@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete an SSH key of a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteUserKey {
+    /// The user to delete the SSH key of.
+    user: u64,
+    /// The ID of the SSH key.
+    key_id: u64,
+}
+
+impl DeleteUserKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteUserKeyBuilder {
+        DeleteUserKeyBuilder::default()
+    }
+}
+
+impl DeleteUserKeyBuilder {
+    fn validate(&self) -> Result<(), DeleteUserKeyBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+        common::validate_path_param("key_id", self.key_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for DeleteUserKey {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "users/{}/keys/{}",
+            self.user.path_segment(),
+            self.key_id.path_segment()
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::keys::{DeleteUserKey, DeleteUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_key_id_are_necessary() {
+        let err = DeleteUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_necessary() {
+        let err = DeleteUserKey::builder().key_id(2).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn key_id_is_necessary() {
+        let err = DeleteUserKey::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn user_and_key_id_are_sufficient() {
+        DeleteUserKey::builder().user(1).key_id(2).build().unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = DeleteUserKey::builder()
+            .user(0)
+            .key_id(2)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("users/1/keys/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteUserKey::builder().user(1).key_id(2).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,85 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Delete an SSH key of the authenticated user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteCurrentUserKey {
+    /// The ID of the SSH key.
+    key_id: u64,
+}
+
+impl DeleteCurrentUserKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteCurrentUserKeyBuilder {
+        DeleteCurrentUserKeyBuilder::default()
+    }
+}
+
+impl DeleteCurrentUserKeyBuilder {
+    fn validate(&self) -> Result<(), DeleteCurrentUserKeyBuilderError> {
+        common::validate_path_param("key_id", self.key_id.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for DeleteCurrentUserKey {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/keys/{}", self.key_id.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::keys::{DeleteCurrentUserKey, DeleteCurrentUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_id_is_necessary() {
+        let err = DeleteCurrentUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteCurrentUserKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn key_id_is_sufficient() {
+        DeleteCurrentUserKey::builder().key_id(2).build().unwrap();
+    }
+
+    #[test]
+    fn key_id_must_be_valid() {
+        let err = DeleteCurrentUserKey::builder()
+            .key_id(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(err, DeleteCurrentUserKeyBuilderError, "key_id");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("user/keys/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteCurrentUserKey::builder().key_id(2).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, NameOrId, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query for the SSH keys of a user.
+#[derive(Debug, Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UserKeys<'a> {
+    /// The user to list the SSH keys of.
+    #[builder(setter(into))]
+    user: NameOrId<'a>,
+}
+
+impl<'a> UserKeys<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserKeysBuilder<'a> {
+        UserKeysBuilder::default()
+    }
+}
+
+impl<'a> UserKeysBuilder<'a> {
+    fn validate(&self) -> Result<(), UserKeysBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for UserKeys<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/keys", self.user.path_segment()).into()
+    }
+}
+
+impl<'a> Pageable for UserKeys<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::keys::{UserKeys, UserKeysBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_necessary() {
+        let err = UserKeys::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserKeysBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserKeys::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = UserKeys::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UserKeysBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/user/keys")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserKeys::builder().user("user").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
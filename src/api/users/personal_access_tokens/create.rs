@@ -91,7 +91,11 @@ impl<'a> Endpoint for CreatePersonalAccessToken<'a> {
             .push("name", &self.name)
             .push_opt("expires_at", self.expires_at);
 
-        params.extend(self.scopes.iter().map(|&value| ("scopes[]", value)));
+        params.extend(
+            self.scopes
+                .iter()
+                .map(|&value| (ParamName::new("scopes").array(), value)),
+        );
 
         params.into_body()
     }
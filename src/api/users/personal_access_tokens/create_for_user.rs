@@ -9,6 +9,7 @@ use std::collections::BTreeSet;
 use chrono::NaiveDate;
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -81,7 +82,7 @@ impl ParamValue<'static> for PersonalAccessTokenScope {
 
 /// Create a new personal access token for a user.
 #[derive(Debug, Builder, Clone)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreatePersonalAccessTokenForUser<'a> {
     /// The user to create a personal access token for.
     user: u64,
@@ -119,6 +120,12 @@ impl<'a> CreatePersonalAccessTokenForUserBuilder<'a> {
         self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), CreatePersonalAccessTokenForUserBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
 }
 
 impl<'a> Endpoint for CreatePersonalAccessTokenForUser<'a> {
@@ -127,7 +134,7 @@ impl<'a> Endpoint for CreatePersonalAccessTokenForUser<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/personal_access_tokens", self.user).into()
+        format!("users/{}/personal_access_tokens", self.user.path_segment()).into()
     }
 
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
@@ -137,7 +144,11 @@ impl<'a> Endpoint for CreatePersonalAccessTokenForUser<'a> {
             .push("name", &self.name)
             .push_opt("expires_at", self.expires_at);
 
-        params.extend(self.scopes.iter().map(|&value| ("scopes[]", value)));
+        params.extend(
+            self.scopes
+                .iter()
+                .map(|&value| (ParamName::new("scopes").array(), value)),
+        );
 
         params.into_body()
     }
@@ -248,6 +259,21 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = CreatePersonalAccessTokenForUser::builder()
+            .user(0)
+            .name("name")
+            .scope(PersonalAccessTokenScope::ReadUser)
+            .build()
+            .unwrap_err();
+        crate::test::assert_invalid_path_param!(
+            err,
+            CreatePersonalAccessTokenForUserBuilderError,
+            "user"
+        );
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
@@ -10,7 +10,7 @@
 
 use derive_builder::Builder;
 
-use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
+use crate::api::common::{self, AccessLevel, NameOrId, PathParam, SortOrder, VisibilityLevel};
 use crate::api::endpoint_prelude::*;
 use crate::api::ParamValue;
 
@@ -65,7 +65,7 @@ impl ParamValue<'static> for UserProjectsOrderBy {
 
 /// Query projects of a user.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UserProjects<'a> {
     /// The user to query for projects.
     #[builder(setter(into))]
@@ -120,13 +120,21 @@ impl<'a> UserProjects<'a> {
     }
 }
 
+impl<'a> UserProjectsBuilder<'a> {
+    fn validate(&self) -> Result<(), UserProjectsBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl<'a> Endpoint for UserProjects<'a> {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}/projects", self.user).into()
+        format!("users/{}/projects", self.user.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -203,6 +211,12 @@ mod tests {
         UserProjects::builder().user(1).build().unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = UserProjects::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UserProjectsBuilderError, "user");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
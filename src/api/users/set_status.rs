@@ -0,0 +1,256 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The availability of a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UserAvailability {
+    /// No availability is indicated.
+    NotSet,
+    /// The user is busy.
+    Busy,
+}
+
+impl UserAvailability {
+    fn as_str(self) -> &'static str {
+        match self {
+            UserAvailability::NotSet => "not_set",
+            UserAvailability::Busy => "busy",
+        }
+    }
+}
+
+impl ParamValue<'static> for UserAvailability {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// When to automatically clear a user status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UserStatusClearAfter {
+    /// Clear the status after 30 minutes.
+    ThirtyMinutes,
+    /// Clear the status after 3 hours.
+    ThreeHours,
+    /// Clear the status after 8 hours.
+    EightHours,
+    /// Clear the status after 1 day.
+    OneDay,
+    /// Clear the status after 3 days.
+    ThreeDays,
+    /// Clear the status after 7 days.
+    SevenDays,
+    /// Clear the status after 30 days.
+    ThirtyDays,
+}
+
+impl UserStatusClearAfter {
+    fn as_str(self) -> &'static str {
+        match self {
+            UserStatusClearAfter::ThirtyMinutes => "30_minutes",
+            UserStatusClearAfter::ThreeHours => "3_hours",
+            UserStatusClearAfter::EightHours => "8_hours",
+            UserStatusClearAfter::OneDay => "1_day",
+            UserStatusClearAfter::ThreeDays => "3_days",
+            UserStatusClearAfter::SevenDays => "7_days",
+            UserStatusClearAfter::ThirtyDays => "30_days",
+        }
+    }
+}
+
+impl ParamValue<'static> for UserStatusClearAfter {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Set the status of the API calling user.
+///
+/// Note that this endpoint uses `PUT` and GitLab clears any of `emoji`, `message`, and
+/// `clear_status_after` which are not given.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct SetCurrentUserStatus<'a> {
+    /// The name of the emoji to use for the status.
+    ///
+    /// GitLab uses `speech_balloon` if this is not set.
+    #[builder(setter(into), default)]
+    emoji: Option<Cow<'a, str>>,
+    /// The message for the status.
+    #[builder(setter(into), default)]
+    message: Option<Cow<'a, str>>,
+    /// The availability of the user.
+    #[builder(default)]
+    availability: Option<UserAvailability>,
+    /// When to automatically clear the status.
+    #[builder(default)]
+    clear_status_after: Option<UserStatusClearAfter>,
+}
+
+impl<'a> SetCurrentUserStatus<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetCurrentUserStatusBuilder<'a> {
+        SetCurrentUserStatusBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SetCurrentUserStatus<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/status".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("emoji", self.emoji.as_ref())
+            .push_opt("message", self.message.as_ref())
+            .push_opt("availability", self.availability)
+            .push_opt("clear_status_after", self.clear_status_after);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{SetCurrentUserStatus, UserAvailability, UserStatusClearAfter};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_availability_as_str() {
+        let items = &[
+            (UserAvailability::NotSet, "not_set"),
+            (UserAvailability::Busy, "busy"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn user_status_clear_after_as_str() {
+        let items = &[
+            (UserStatusClearAfter::ThirtyMinutes, "30_minutes"),
+            (UserStatusClearAfter::ThreeHours, "3_hours"),
+            (UserStatusClearAfter::EightHours, "8_hours"),
+            (UserStatusClearAfter::OneDay, "1_day"),
+            (UserStatusClearAfter::ThreeDays, "3_days"),
+            (UserStatusClearAfter::SevenDays, "7_days"),
+            (UserStatusClearAfter::ThirtyDays, "30_days"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn defaults_are_sufficient() {
+        SetCurrentUserStatus::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetCurrentUserStatus::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_emoji() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("emoji=coffee")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetCurrentUserStatus::builder()
+            .emoji("coffee")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_message() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("message=Out+to+lunch")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetCurrentUserStatus::builder()
+            .message("Out to lunch")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_availability() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("availability=busy")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetCurrentUserStatus::builder()
+            .availability(UserAvailability::Busy)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_clear_status_after() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("clear_status_after=8_hours")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetCurrentUserStatus::builder()
+            .clear_status_after(UserStatusClearAfter::EightHours)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, PathParam};
+use crate::api::endpoint_prelude::*;
+
+/// Query the status of a user.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UserStatus {
+    /// The ID of the user.
+    user: u64,
+}
+
+impl UserStatus {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserStatusBuilder {
+        UserStatusBuilder::default()
+    }
+}
+
+impl UserStatusBuilder {
+    fn validate(&self) -> Result<(), UserStatusBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
+impl Endpoint for UserStatus {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/status", self.user.path_segment()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::{UserStatus, UserStatusBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserStatus::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserStatusBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserStatus::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn user_must_be_valid() {
+        let err = UserStatus::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UserStatusBuilderError, "user");
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/status")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStatus::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
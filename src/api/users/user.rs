@@ -6,11 +6,12 @@
 
 use derive_builder::Builder;
 
+use crate::api::common::{self, PathParam};
 use crate::api::endpoint_prelude::*;
 
 /// Query a user by ID.
 #[derive(Debug, Clone, Copy, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct User {
     /// The ID of the user.
     user: u64,
@@ -27,13 +28,21 @@ impl User {
     }
 }
 
+impl UserBuilder {
+    fn validate(&self) -> Result<(), UserBuilderError> {
+        common::validate_path_param("user", self.user.as_ref())?;
+
+        Ok(())
+    }
+}
+
 impl Endpoint for User {
     fn method(&self) -> Method {
         Method::GET
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("users/{}", self.user).into()
+        format!("users/{}", self.user.path_segment()).into()
     }
 
     fn parameters(&self) -> QueryParams {
@@ -62,6 +71,12 @@ mod tests {
         User::builder().user(1).build().unwrap();
     }
 
+    #[test]
+    fn user_must_be_valid() {
+        let err = User::builder().user(0).build().unwrap_err();
+        crate::test::assert_invalid_path_param!(err, UserBuilderError, "user");
+    }
+
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder().endpoint("users/1").build().unwrap();
@@ -231,9 +231,9 @@ impl<'a> Endpoint for Users<'a> {
             .push_opt("created_before", self.created_before)
             .push_opt("created_after", self.created_after)
             .extend(
-                self.custom_attributes
-                    .iter()
-                    .map(|(key, value)| (format!("custom_attributes[{}]", key), value)),
+                self.custom_attributes.iter().map(|(key, value)| {
+                    (ParamName::new("custom_attributes").key(key.as_ref()), value)
+                }),
             )
             .push_opt("with_custom_attributes", self.with_custom_attributes)
             .push_opt("order_by", self.order_by)
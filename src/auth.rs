@@ -4,13 +4,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use http::{HeaderMap, HeaderValue};
+use std::borrow::Cow;
+
+use http::{HeaderMap, HeaderValue, Method};
 use log::error;
 use thiserror::Error;
 
 use crate::api::job::Job;
-use crate::api::users::CurrentUser;
-use crate::api::{self, AsyncQuery, Query};
+use crate::api::{self, AsyncQuery, Endpoint, Query};
 
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -24,6 +25,22 @@ pub enum AuthError {
 
 type AuthResult<T> = Result<T, AuthError>;
 
+/// Query the current user to check a token.
+///
+/// This is the same as `api::users::CurrentUser`, but is available without the `api-users`
+/// feature.
+struct CurrentUser;
+
+impl Endpoint for CurrentUser {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user".into()
+    }
+}
+
 /// A Gitlab API token
 ///
 /// Gitlab supports two kinds of tokens
@@ -87,7 +104,7 @@ impl Auth {
                 api::ignore(Job::builder().build().unwrap()).query(api)?;
             },
             Self::Token(_) | Self::OAuth2(_) => {
-                api::ignore(CurrentUser::builder().build().unwrap()).query(api)?;
+                api::ignore(CurrentUser).query(api)?;
             },
         }
 
@@ -109,9 +126,7 @@ impl Auth {
                     .await?;
             },
             Self::Token(_) | Self::OAuth2(_) => {
-                api::ignore(CurrentUser::builder().build().unwrap())
-                    .query_async(api)
-                    .await?;
+                api::ignore(CurrentUser).query_async(api).await?;
             },
         }
 
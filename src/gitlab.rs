@@ -7,11 +7,16 @@
 use std::any;
 use std::convert::TryInto;
 use std::fmt::{self, Debug};
+use std::io::{self, Read};
+use std::iter;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::stream;
 use graphql_client::{GraphQLQuery, QueryBody, Response};
-use http::{HeaderMap, Response as HttpResponse};
+use http::{header, HeaderMap, Method, Response as HttpResponse, StatusCode};
 use itertools::Itertools;
 use log::{debug, error, info};
 use reqwest::blocking::Client;
@@ -25,6 +30,7 @@ use url::Url;
 use reqwest::Identity as TlsIdentity;
 
 use crate::api;
+use crate::api::instance::Capabilities;
 use crate::auth::{Auth, AuthError};
 
 #[derive(Debug, Error)]
@@ -92,6 +98,120 @@ impl GitlabError {
 
 type GitlabResult<T> = Result<T, GitlabError>;
 
+/// The default prefix of the REST API relative to the root of an instance.
+const DEFAULT_API_PREFIX: &str = "api/v4";
+
+/// The URLs used to communicate with an instance.
+#[derive(Debug, Clone)]
+struct ApiUrls {
+    instance: Url,
+    rest: Url,
+    graphql: Url,
+}
+
+impl ApiUrls {
+    fn new(
+        protocol: &str,
+        host: &str,
+        relative_url_root: &str,
+        api_prefix: &str,
+    ) -> GitlabResult<Self> {
+        let root = relative_url_root.trim_matches('/');
+        let instance = if root.is_empty() {
+            Url::parse(&format!("{}://{}/", protocol, host))?
+        } else {
+            Url::parse(&format!("{}://{}/{}/", protocol, host, root))?
+        };
+        let prefix = api_prefix.trim_matches('/');
+        // Joining an empty prefix would be `/`, which would drop the relative URL root.
+        let rest = if prefix.is_empty() {
+            instance.clone()
+        } else {
+            instance.join(&format!("{}/", prefix))?
+        };
+        let graphql = instance.join("api/graphql")?;
+
+        Ok(Self {
+            instance,
+            rest,
+            graphql,
+        })
+    }
+
+    fn for_host(protocol: &str, host: &str) -> GitlabResult<Self> {
+        Self::new(protocol, host, "", DEFAULT_API_PREFIX)
+    }
+}
+
+/// Statistics about a completed REST request.
+///
+/// See [`GitlabBuilder::on_request_complete`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestStats {
+    /// The method of the request.
+    pub method: Method,
+    /// The path of the request URL.
+    ///
+    /// This is the path as sent to the instance (e.g., `/api/v4/projects/1/issues`) without the
+    /// query string.
+    pub path: String,
+    /// The status of the response.
+    ///
+    /// This is `None` if no response was received.
+    pub status: Option<StatusCode>,
+    /// How long the request took.
+    pub duration: Duration,
+    /// The number of times the request had been retried before this attempt.
+    ///
+    /// Requests are only retried when sent through [`api::retry::Client`].
+    pub retries: usize,
+    /// The size of the response body in bytes.
+    ///
+    /// This is `None` if no response was received.
+    pub response_size: Option<usize>,
+}
+
+type RequestCallback = Arc<dyn Fn(&RequestStats) + Send + Sync>;
+
+/// Measure a REST request for the request completion callback.
+struct RequestTimer {
+    callback: RequestCallback,
+    method: Method,
+    path: String,
+    retries: usize,
+    start: Instant,
+}
+
+impl RequestTimer {
+    fn new(callback: Option<&RequestCallback>, request: &http::request::Builder) -> Option<Self> {
+        callback.map(|callback| {
+            Self {
+                callback: callback.clone(),
+                method: request.method_ref().cloned().unwrap_or_default(),
+                path: request
+                    .uri_ref()
+                    .map(|uri| uri.path().into())
+                    .unwrap_or_default(),
+                retries: api::retry::Attempt::retries(request),
+                start: Instant::now(),
+            }
+        })
+    }
+
+    fn finish<E>(self, rsp: &Result<HttpResponse<Bytes>, E>) {
+        let stats = RequestStats {
+            method: self.method,
+            path: self.path,
+            status: rsp.as_ref().ok().map(HttpResponse::status),
+            duration: self.start.elapsed(),
+            retries: self.retries,
+            response_size: rsp.as_ref().ok().map(|rsp| rsp.body().len()),
+        };
+        (self.callback)(&stats);
+    }
+}
+
 // Private enum that enables the parsing of the cert bytes to be
 // delayed until the client is built rather than when they're passed
 // to a builder.
@@ -111,17 +231,24 @@ enum ClientCert {
 pub struct Gitlab {
     /// The client to use for API calls.
     client: Client,
+    /// The base URL of the instance.
+    instance_url: Url,
     /// The base URL to use for API calls.
     rest_url: Url,
     /// The URL to use for GraphQL API calls.
     graphql_url: Url,
     /// The authentication information to use when communicating with Gitlab.
     auth: Auth,
+    /// The cached capabilities of the instance.
+    capabilities: Arc<Capabilities>,
+    /// A callback to call when a REST request completes.
+    on_request_complete: Option<RequestCallback>,
 }
 
 impl Debug for Gitlab {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Gitlab")
+            .field("instance_url", &self.instance_url)
             .field("rest_url", &self.rest_url)
             .field("graphql_url", &self.graphql_url)
             .finish()
@@ -147,11 +274,11 @@ impl Gitlab {
         T: Into<String>,
     {
         Self::new_impl(
-            "https",
-            host.as_ref(),
+            ApiUrls::for_host("https", host.as_ref())?,
             Auth::Token(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            None,
         )
     }
 
@@ -164,11 +291,11 @@ impl Gitlab {
         T: Into<String>,
     {
         Self::new_impl(
-            "http",
-            host.as_ref(),
+            ApiUrls::for_host("http", host.as_ref())?,
             Auth::Token(token.into()),
             CertPolicy::Insecure,
             ClientCert::None,
+            None,
         )
     }
 
@@ -182,11 +309,11 @@ impl Gitlab {
         T: Into<String>,
     {
         Self::new_impl(
-            "https",
-            host.as_ref(),
+            ApiUrls::for_host("https", host.as_ref())?,
             Auth::JobToken(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            None,
         )
     }
 
@@ -200,11 +327,11 @@ impl Gitlab {
         T: Into<String>,
     {
         Self::new_impl(
-            "http",
-            host.as_ref(),
+            ApiUrls::for_host("http", host.as_ref())?,
             Auth::JobToken(token.into()),
             CertPolicy::Insecure,
             ClientCert::None,
+            None,
         )
     }
 
@@ -218,11 +345,11 @@ impl Gitlab {
         T: Into<String>,
     {
         Self::new_impl(
-            "https",
-            host.as_ref(),
+            ApiUrls::for_host("https", host.as_ref())?,
             Auth::OAuth2(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            None,
         )
     }
 
@@ -236,25 +363,22 @@ impl Gitlab {
         T: Into<String>,
     {
         Self::new_impl(
-            "http",
-            host.as_ref(),
+            ApiUrls::for_host("http", host.as_ref())?,
             Auth::OAuth2(token.into()),
             CertPolicy::Default,
             ClientCert::None,
+            None,
         )
     }
 
     /// Internal method to create a new Gitlab client.
     fn new_impl(
-        protocol: &str,
-        host: &str,
+        urls: ApiUrls,
         auth: Auth,
         cert_validation: CertPolicy,
         identity: ClientCert,
+        on_request_complete: Option<RequestCallback>,
     ) -> GitlabResult<Self> {
-        let rest_url = Url::parse(&format!("{}://{}/api/v4/", protocol, host))?;
-        let graphql_url = Url::parse(&format!("{}://{}/api/graphql", protocol, host))?;
-
         let client = match cert_validation {
             CertPolicy::Insecure => {
                 Client::builder()
@@ -280,9 +404,12 @@ impl Gitlab {
 
         let api = Gitlab {
             client,
-            rest_url,
-            graphql_url,
+            instance_url: urls.instance,
+            rest_url: urls.rest,
+            graphql_url: urls.graphql,
             auth,
+            capabilities: Arc::default(),
+            on_request_complete,
         };
 
         // Ensure the API is working.
@@ -300,6 +427,14 @@ impl Gitlab {
         GitlabBuilder::new(host, token)
     }
 
+    /// The capabilities of the instance.
+    ///
+    /// The version of the instance is fetched on first use and shared between clones of this
+    /// client.
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
     /// Send a GraphQL query.
     pub fn graphql<Q>(&self, query: &QueryBody<Q::Variables>) -> GitlabResult<Q::ResponseData>
     where
@@ -342,12 +477,16 @@ impl Gitlab {
     }
 
     /// Perform a REST query with a given auth.
-    fn rest_auth(
+    fn rest_auth<B>(
         &self,
         mut request: http::request::Builder,
-        body: Vec<u8>,
+        body: B,
         auth: &Auth,
-    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>>
+    where
+        B: Into<reqwest::blocking::Body>,
+    {
+        let timer = RequestTimer::new(self.on_request_complete.as_ref(), &request);
         let call = || -> Result<_, RestError> {
             auth.set_header(request.headers_mut().unwrap())?;
             let http_request = request.body(body)?;
@@ -363,7 +502,11 @@ impl Gitlab {
             }
             Ok(http_rsp.body(rsp.bytes()?)?)
         };
-        call().map_err(api::ApiError::client)
+        let rsp = call();
+        if let Some(timer) = timer {
+            timer.finish(&rsp);
+        }
+        rsp.map_err(api::ApiError::client)
     }
 }
 
@@ -394,6 +537,15 @@ impl api::RestClient for Gitlab {
         debug!(target: "gitlab", "REST api call {}", endpoint);
         Ok(self.rest_url.join(endpoint)?)
     }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        debug!(target: "gitlab", "instance api call {}", endpoint);
+        Ok(self.instance_url.join(endpoint)?)
+    }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        Some(&self.capabilities)
+    }
 }
 
 impl api::Client for Gitlab {
@@ -404,14 +556,70 @@ impl api::Client for Gitlab {
     ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
         self.rest_auth(request, body, &self.auth)
     }
+
+    fn rest_spooled(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
+        self.rest_auth(request, spooled_request_body(body)?, &self.auth)
+    }
+}
+
+/// Stream a spooled body to the server.
+fn spooled_request_body<E>(
+    body: api::SpooledBody,
+) -> Result<reqwest::blocking::Body, api::ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let reader = body.into_reader().map_err(api::BodyError::from)?;
+    let len = reader.len();
+    Ok(reqwest::blocking::Body::sized(reader, len))
+}
+
+/// The size of the chunks read from a spooled body when streaming it asynchronously.
+const SPOOLED_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Stream a spooled body to the server asynchronously.
+///
+/// The length of the body is known up front, so it is sent as the `Content-Length` of the request
+/// rather than using a chunked transfer encoding.
+fn spooled_async_request_body<E>(
+    request: http::request::Builder,
+    body: api::SpooledBody,
+) -> Result<(http::request::Builder, reqwest::Body), api::ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let mut reader = body.into_reader().map_err(api::BodyError::from)?;
+    let request = request.header(header::CONTENT_LENGTH, reader.len());
+    let chunks = iter::from_fn(move || {
+        let mut chunk = vec![0; SPOOLED_CHUNK_SIZE];
+        loop {
+            return match reader.read(&mut chunk) {
+                Ok(0) => None,
+                Ok(len) => {
+                    chunk.truncate(len);
+                    Some(Ok(Bytes::from(chunk)))
+                },
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => Some(Err(err)),
+            };
+        }
+    });
+    Ok((request, reqwest::Body::wrap_stream(stream::iter(chunks))))
 }
 
 pub struct GitlabBuilder {
     protocol: &'static str,
     host: String,
+    relative_url_root: String,
+    api_prefix: String,
     token: Auth,
     cert_validation: CertPolicy,
     identity: ClientCert,
+    on_request_complete: Option<RequestCallback>,
 }
 
 impl GitlabBuilder {
@@ -424,9 +632,12 @@ impl GitlabBuilder {
         Self {
             protocol: "https",
             host: host.into(),
+            relative_url_root: String::new(),
+            api_prefix: DEFAULT_API_PREFIX.into(),
             token: Auth::Token(token.into()),
             cert_validation: CertPolicy::Default,
             identity: ClientCert::None,
+            on_request_complete: None,
         }
     }
 
@@ -438,9 +649,12 @@ impl GitlabBuilder {
         Self {
             protocol: "https",
             host: host.into(),
+            relative_url_root: String::new(),
+            api_prefix: DEFAULT_API_PREFIX.into(),
             token: Auth::None,
             cert_validation: CertPolicy::Default,
             identity: ClientCert::None,
+            on_request_complete: None,
         }
     }
 
@@ -450,6 +664,38 @@ impl GitlabBuilder {
         self
     }
 
+    /// Set the relative URL root of the instance.
+    ///
+    /// This is needed for instances which are not hosted at the root of their host (e.g., use
+    /// `/gitlab` for an instance at `https://host/gitlab/`).
+    pub fn relative_url_root<R>(&mut self, relative_url_root: R) -> &mut Self
+    where
+        R: Into<String>,
+    {
+        self.relative_url_root = relative_url_root.into();
+        self
+    }
+
+    /// Set the prefix of the REST API relative to the root of the instance.
+    ///
+    /// Defaults to `api/v4`. An empty prefix serves the REST API from the root of the instance.
+    pub fn api_prefix<P>(&mut self, api_prefix: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.api_prefix = api_prefix.into();
+        self
+    }
+
+    fn urls(&self) -> GitlabResult<ApiUrls> {
+        ApiUrls::new(
+            self.protocol,
+            &self.host,
+            &self.relative_url_root,
+            &self.api_prefix,
+        )
+    }
+
     pub fn cert_insecure(&mut self) -> &mut Self {
         self.cert_validation = CertPolicy::Insecure;
         self
@@ -479,23 +725,36 @@ impl GitlabBuilder {
         self
     }
 
+    /// Call a function after each REST request completes.
+    ///
+    /// The callback receives statistics about the request which may be used to gather metrics
+    /// about API usage. It is called for every attempt, including those which fail to receive a
+    /// response.
+    pub fn on_request_complete<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&RequestStats) + Send + Sync + 'static,
+    {
+        self.on_request_complete = Some(Arc::new(callback));
+        self
+    }
+
     pub fn build(&self) -> GitlabResult<Gitlab> {
         Gitlab::new_impl(
-            self.protocol,
-            &self.host,
+            self.urls()?,
             self.token.clone(),
             self.cert_validation.clone(),
             self.identity.clone(),
+            self.on_request_complete.clone(),
         )
     }
 
     pub async fn build_async(&self) -> GitlabResult<AsyncGitlab> {
         AsyncGitlab::new_impl(
-            self.protocol,
-            &self.host,
+            self.urls()?,
             self.token.clone(),
             self.cert_validation.clone(),
             self.identity.clone(),
+            self.on_request_complete.clone(),
         )
         .await
     }
@@ -516,6 +775,10 @@ pub struct AsyncGitlab {
     graphql_url: Url,
     /// The authentication information to use when communicating with Gitlab.
     auth: Auth,
+    /// The cached capabilities of the instance.
+    capabilities: Arc<Capabilities>,
+    /// A callback to call when a REST request completes.
+    on_request_complete: Option<RequestCallback>,
 }
 
 impl Debug for AsyncGitlab {
@@ -541,6 +804,10 @@ impl api::RestClient for AsyncGitlab {
         debug!(target: "gitlab", "instance api call {}", endpoint);
         Ok(self.instance_url.join(endpoint)?)
     }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        Some(&self.capabilities)
+    }
 }
 
 #[async_trait]
@@ -552,21 +819,26 @@ impl api::AsyncClient for AsyncGitlab {
     ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
         self.rest_async_auth(request, body, &self.auth).await
     }
+
+    async fn rest_spooled_async(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
+        let (request, body) = spooled_async_request_body(request, body)?;
+        self.rest_async_auth(request, body, &self.auth).await
+    }
 }
 
 impl AsyncGitlab {
     /// Internal method to create a new Gitlab client.
     async fn new_impl(
-        protocol: &str,
-        host: &str,
+        urls: ApiUrls,
         auth: Auth,
         cert_validation: CertPolicy,
         identity: ClientCert,
+        on_request_complete: Option<RequestCallback>,
     ) -> GitlabResult<Self> {
-        let instance_url = Url::parse(&format!("{}://{}/", protocol, host))?;
-        let rest_url = Url::parse(&format!("{}://{}/api/v4/", protocol, host))?;
-        let graphql_url = Url::parse(&format!("{}://{}/api/graphql", protocol, host))?;
-
         let client = match cert_validation {
             CertPolicy::Insecure => {
                 AsyncClient::builder()
@@ -592,10 +864,12 @@ impl AsyncGitlab {
 
         let api = AsyncGitlab {
             client,
-            instance_url,
-            rest_url,
-            graphql_url,
+            instance_url: urls.instance,
+            rest_url: urls.rest,
+            graphql_url: urls.graphql,
             auth,
+            capabilities: Arc::default(),
+            on_request_complete,
         };
 
         // Ensure the API is working.
@@ -604,6 +878,14 @@ impl AsyncGitlab {
         Ok(api)
     }
 
+    /// The capabilities of the instance.
+    ///
+    /// The version of the instance is fetched on first use and shared between clones of this
+    /// client.
+    pub fn capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
     /// Send a GraphQL query.
     pub async fn graphql<Q>(&self, query: &QueryBody<Q::Variables>) -> GitlabResult<Q::ResponseData>
     where
@@ -646,13 +928,16 @@ impl AsyncGitlab {
     }
 
     /// Perform a REST query with a given auth.
-    async fn rest_async_auth(
+    async fn rest_async_auth<B>(
         &self,
         mut request: http::request::Builder,
-        body: Vec<u8>,
+        body: B,
         auth: &Auth,
-    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
-        use futures_util::TryFutureExt;
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>>
+    where
+        B: Into<reqwest::Body>,
+    {
+        let timer = RequestTimer::new(self.on_request_complete.as_ref(), &request);
         let call = || {
             async {
                 auth.set_header(request.headers_mut().unwrap())?;
@@ -670,7 +955,11 @@ impl AsyncGitlab {
                 Ok(http_rsp.body(rsp.bytes().await?)?)
             }
         };
-        call().map_err(api::ApiError::client).await
+        let rsp = call().await;
+        if let Some(timer) = timer {
+            timer.finish(&rsp);
+        }
+        rsp.map_err(api::ApiError::client)
     }
 }
 
@@ -714,6 +1003,10 @@ where
     fn instance_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
         self.client.instance_endpoint(endpoint)
     }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        self.client.capabilities()
+    }
 }
 
 impl<'a> api::Client for ImpersonationClient<'a, Gitlab> {
@@ -724,6 +1017,15 @@ impl<'a> api::Client for ImpersonationClient<'a, Gitlab> {
     ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
         self.client.rest_auth(request, body, &self.auth)
     }
+
+    fn rest_spooled(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
+        self.client
+            .rest_auth(request, spooled_request_body(body)?, &self.auth)
+    }
 }
 
 #[async_trait]
@@ -735,4 +1037,264 @@ impl<'a> api::AsyncClient for ImpersonationClient<'a, AsyncGitlab> {
     ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
         self.client.rest_async_auth(request, body, &self.auth).await
     }
+
+    async fn rest_spooled_async(
+        &self,
+        request: http::request::Builder,
+        body: api::SpooledBody,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
+        let (request, body) = spooled_async_request_body(request, body)?;
+        self.client.rest_async_auth(request, body, &self.auth).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, JoinHandle};
+
+    use bytes::Bytes;
+    use http::{Method, Request, Response, StatusCode};
+
+    use crate::api::instance::Health;
+    use crate::api::retry::Attempt;
+    use crate::api::{self, Query};
+    use crate::auth::Auth;
+    use crate::gitlab::{
+        ApiUrls, CertPolicy, ClientCert, Gitlab, GitlabBuilder, RequestCallback, RequestStats,
+        RequestTimer,
+    };
+
+    #[test]
+    fn api_urls_for_host() {
+        let urls = ApiUrls::for_host("https", "gitlab.example.com").unwrap();
+        assert_eq!(urls.instance.as_str(), "https://gitlab.example.com/");
+        assert_eq!(urls.rest.as_str(), "https://gitlab.example.com/api/v4/");
+        assert_eq!(
+            urls.graphql.as_str(),
+            "https://gitlab.example.com/api/graphql",
+        );
+    }
+
+    #[test]
+    fn api_urls_relative_url_root() {
+        for root in ["gitlab", "/gitlab", "/gitlab/"] {
+            let urls = ApiUrls::new("https", "example.com", root, "api/v4").unwrap();
+            assert_eq!(urls.instance.as_str(), "https://example.com/gitlab/");
+            assert_eq!(urls.rest.as_str(), "https://example.com/gitlab/api/v4/");
+            assert_eq!(
+                urls.graphql.as_str(),
+                "https://example.com/gitlab/api/graphql",
+            );
+        }
+    }
+
+    #[test]
+    fn api_urls_api_prefix() {
+        let urls = ApiUrls::new("http", "example.com", "", "/proxy/api/v4/").unwrap();
+        assert_eq!(urls.instance.as_str(), "http://example.com/");
+        assert_eq!(urls.rest.as_str(), "http://example.com/proxy/api/v4/");
+        assert_eq!(urls.graphql.as_str(), "http://example.com/api/graphql");
+    }
+
+    #[test]
+    fn api_urls_empty_api_prefix() {
+        for prefix in ["", "/"] {
+            let urls = GitlabBuilder::new("example.com", "token")
+                .relative_url_root("gitlab")
+                .api_prefix(prefix)
+                .urls()
+                .unwrap();
+            assert_eq!(urls.instance.as_str(), "https://example.com/gitlab/");
+            assert_eq!(urls.rest.as_str(), "https://example.com/gitlab/");
+            assert_eq!(
+                urls.graphql.as_str(),
+                "https://example.com/gitlab/api/graphql",
+            );
+        }
+    }
+
+    fn recorder() -> (RequestCallback, Arc<Mutex<Vec<RequestStats>>>) {
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let callback: RequestCallback = {
+            let recorded = recorded.clone();
+            Arc::new(move |stats: &RequestStats| recorded.lock().unwrap().push(stats.clone()))
+        };
+        (callback, recorded)
+    }
+
+    #[test]
+    fn request_timer_no_callback() {
+        let request = Request::builder().uri("https://example.com/api/v4/projects");
+        assert!(RequestTimer::new(None, &request).is_none());
+    }
+
+    #[test]
+    fn request_timer_response() {
+        let (callback, recorded) = recorder();
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("https://example.com/api/v4/projects/1/issues?labels=bug")
+            .extension(Attempt(2));
+        let timer = RequestTimer::new(Some(&callback), &request).unwrap();
+        let rsp: Result<_, ()> = Ok(Response::builder()
+            .status(StatusCode::CREATED)
+            .body(Bytes::from_static(b"{}"))
+            .unwrap());
+        timer.finish(&rsp);
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        let stats = &recorded[0];
+        assert_eq!(stats.method, Method::POST);
+        assert_eq!(stats.path, "/api/v4/projects/1/issues");
+        assert_eq!(stats.status, Some(StatusCode::CREATED));
+        assert_eq!(stats.retries, 2);
+        assert_eq!(stats.response_size, Some(2));
+    }
+
+    #[test]
+    fn request_timer_error() {
+        let (callback, recorded) = recorder();
+        let request = Request::builder().uri("https://example.com/api/v4/user");
+        let timer = RequestTimer::new(Some(&callback), &request).unwrap();
+        let rsp: Result<Response<Bytes>, _> = Err(());
+        timer.finish(&rsp);
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        let stats = &recorded[0];
+        assert_eq!(stats.method, Method::GET);
+        assert_eq!(stats.path, "/api/v4/user");
+        assert_eq!(stats.status, None);
+        assert_eq!(stats.retries, 0);
+        assert_eq!(stats.response_size, None);
+    }
+
+    /// Serve a single plain text response on a local port.
+    ///
+    /// Returns the address of the server and a handle which yields the request line it received.
+    struct ServedRequest {
+        line: String,
+        headers: Vec<String>,
+        body: Vec<u8>,
+    }
+
+    fn serve_once(body: &'static str) -> (String, JoinHandle<ServedRequest>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut headers = Vec::new();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end().to_lowercase();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some(length) = header.strip_prefix("content-length: ") {
+                    content_length = length.parse().unwrap();
+                }
+                headers.push(header);
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body,
+            )
+            .unwrap();
+
+            ServedRequest {
+                line,
+                headers,
+                body: request_body,
+            }
+        });
+
+        (host, server)
+    }
+
+    #[test]
+    fn instance_endpoint() {
+        let (host, server) = serve_once("GitLab OK");
+        let client = Gitlab::new_impl(
+            ApiUrls::for_host("http", &host).unwrap(),
+            Auth::None,
+            CertPolicy::Insecure,
+            ClientCert::None,
+            None,
+        )
+        .unwrap();
+
+        let endpoint = Health::builder().build().unwrap();
+        let data = api::raw(endpoint).query(&client).unwrap();
+        assert_eq!(data, b"GitLab OK");
+        let request = server.join().unwrap();
+        assert!(
+            request.line.starts_with("GET /-/health"),
+            "unexpected request: {}",
+            request.line,
+        );
+        let host_header = format!("host: {}", host);
+        assert!(request.headers.contains(&host_header));
+        assert!(request.body.is_empty());
+    }
+
+    #[cfg(feature = "api-projects")]
+    #[tokio::test]
+    async fn rest_spooled_async() {
+        use crate::api::projects::repository::files::CreateFile;
+        use crate::api::{AsyncQuery, Endpoint};
+        use crate::gitlab::AsyncGitlab;
+
+        let (host, server) = serve_once("{}");
+        let client = AsyncGitlab::new_impl(
+            ApiUrls::for_host("http", &host).unwrap(),
+            Auth::None,
+            CertPolicy::Insecure,
+            ClientCert::None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let content = vec![0xfe; 256 * 1024];
+        let endpoint = CreateFile::builder()
+            .project(1)
+            .file_path("path/to/file")
+            .branch("master")
+            .content(&content[..])
+            .commit_message("message")
+            .build()
+            .unwrap();
+        let (_, expected) = endpoint.body().unwrap().unwrap();
+        api::raw(endpoint).query_async(&client).await.unwrap();
+
+        let request = server.join().unwrap();
+        assert!(
+            request
+                .line
+                .starts_with("POST /api/v4/projects/1/repository/files/path%2Fto%2Ffile"),
+            "unexpected request: {}",
+            request.line,
+        );
+        let content_length = format!("content-length: {}", expected.len());
+        assert!(request.headers.contains(&content_length));
+        assert!(!request
+            .headers
+            .iter()
+            .any(|header| header.starts_with("transfer-encoding")));
+        assert_eq!(request.body, expected);
+    }
 }
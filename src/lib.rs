@@ -23,17 +23,23 @@ pub mod systemhooks;
 #[cfg(not(feature = "_nohooks"))]
 pub mod webhooks;
 
+#[cfg(all(feature = "schema-tests", not(feature = "_nohooks")))]
+pub mod schema;
+
 #[cfg(feature = "client_api")]
 pub mod api;
 #[cfg(feature = "client_api")]
 mod auth;
+#[cfg(all(feature = "client_api", any(test, feature = "testing")))]
+pub mod testing;
 
 #[cfg(feature = "client_api")]
 pub use crate::auth::AuthError;
 #[cfg(feature = "client_api")]
 pub use crate::gitlab::{
-    AsyncGitlab, Gitlab, GitlabBuilder, GitlabError, ImpersonationClient, RestError,
+    AsyncGitlab, Gitlab, GitlabBuilder, GitlabError, ImpersonationClient, RequestStats, RestError,
 };
 
 #[cfg(test)]
+#[cfg_attr(not(feature = "full"), allow(dead_code, unused_imports, unused_macros))]
 mod test;
@@ -0,0 +1,345 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compatibility checks for hook structures
+//!
+//! GitLab does not version its hook payloads and fields are added or changed between releases.
+//! This module checks recorded payloads against the hook structures in this crate and reports
+//! fields which are ignored during deserialization as well as payloads which fail to
+//! deserialize. Running it over payloads recorded from an instance shows whether the crate is
+//! able to handle the hooks the instance sends.
+//!
+//! Payloads are expected to be stored in a directory with a subdirectory per GitLab version:
+//!
+//! ```text
+//! payloads/
+//!   16.11/
+//!     push.json
+//!     merge_request.json
+//!   17.2/
+//!     system/
+//!       user_create.json
+//! ```
+//!
+//! Each `.json` file within a version directory (at any depth) contains a single hook payload.
+//! The structure used to deserialize a payload is determined by its `object_kind` or
+//! `event_name` field in the same way as [`GitlabHook`](crate::hooks::GitlabHook).
+
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::systemhooks::{
+    GroupMemberSystemHook, GroupSystemHook, KeySystemHook, ProjectMemberSystemHook,
+    ProjectSystemHook, PushSystemHook, UserSystemHook,
+};
+use crate::webhooks::{BuildHook, IssueHook, MergeRequestHook, NoteHook, PipelineHook, PushHook};
+
+/// The result of checking a single payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PayloadOutcome {
+    /// The payload deserialized successfully.
+    Parsed {
+        /// The fields in the payload which are not part of the structure.
+        ///
+        /// The data in these fields is dropped during deserialization.
+        dropped_fields: Vec<String>,
+    },
+    /// The payload failed to deserialize.
+    Failed {
+        /// The path to the field which failed to deserialize.
+        path: String,
+        /// The deserialization error.
+        message: String,
+    },
+    /// The payload is not a hook known to the crate.
+    Unrecognized {
+        /// Why the payload was not recognized.
+        reason: String,
+    },
+}
+
+impl PayloadOutcome {
+    /// Whether the payload can be deserialized without losing data.
+    pub fn is_compatible(&self) -> bool {
+        if let PayloadOutcome::Parsed {
+            dropped_fields,
+        } = self
+        {
+            dropped_fields.is_empty()
+        } else {
+            false
+        }
+    }
+}
+
+/// The result of checking a payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadCheck {
+    /// The name of the structure used for the payload.
+    ///
+    /// This is `None` if the payload was not recognized.
+    pub typename: Option<&'static str>,
+    /// The outcome of the check.
+    pub outcome: PayloadOutcome,
+}
+
+fn check_as<T>(value: &Value) -> PayloadCheck
+where
+    T: DeserializeOwned,
+{
+    let mut dropped_fields = Vec::new();
+    let mut record_dropped = |path: serde_ignored::Path| dropped_fields.push(path.to_string());
+    let de = serde_ignored::Deserializer::new(value, &mut record_dropped);
+    let res: Result<T, _> = serde_path_to_error::deserialize(de);
+
+    let outcome = match res {
+        Ok(_) => {
+            PayloadOutcome::Parsed {
+                dropped_fields,
+            }
+        },
+        Err(err) => {
+            PayloadOutcome::Failed {
+                path: err.path().to_string(),
+                message: err.into_inner().to_string(),
+            }
+        },
+    };
+
+    PayloadCheck {
+        typename: Some(std::any::type_name::<T>()),
+        outcome,
+    }
+}
+
+fn unrecognized<R>(reason: R) -> PayloadCheck
+where
+    R: Into<String>,
+{
+    PayloadCheck {
+        typename: None,
+        outcome: PayloadOutcome::Unrecognized {
+            reason: reason.into(),
+        },
+    }
+}
+
+fn check_web_hook(value: &Value, object_kind: &str) -> PayloadCheck {
+    match object_kind {
+        "push" | "tag_push" => check_as::<PushHook>(value),
+        "issue" => check_as::<IssueHook>(value),
+        "merge_request" => check_as::<MergeRequestHook>(value),
+        "note" => check_as::<NoteHook>(value),
+        "build" => check_as::<BuildHook>(value),
+        "pipeline" => check_as::<PipelineHook>(value),
+        _ => unrecognized(format!("unrecognized webhook object kind: {}", object_kind)),
+    }
+}
+
+fn check_system_hook(value: &Value, event_name: &str) -> PayloadCheck {
+    match event_name {
+        "project_create" | "project_destroy" | "project_rename" | "project_transfer"
+        | "project_update" => check_as::<ProjectSystemHook>(value),
+        "user_add_to_team" | "user_remove_from_team" => check_as::<ProjectMemberSystemHook>(value),
+        "user_create" | "user_destroy" => check_as::<UserSystemHook>(value),
+        "key_create" | "key_destroy" => check_as::<KeySystemHook>(value),
+        "group_create" | "group_destroy" => check_as::<GroupSystemHook>(value),
+        "user_add_to_group" | "user_remove_from_group" => check_as::<GroupMemberSystemHook>(value),
+        "push" | "tag_push" => check_as::<PushSystemHook>(value),
+        _ => unrecognized(format!("unrecognized system event name: {}", event_name)),
+    }
+}
+
+/// Check a hook payload against the structures in this crate.
+pub fn check_payload(value: &Value) -> PayloadCheck {
+    // Look for `object_kind` first because some web hooks also have `event_name`.
+    if let Some(object_kind) = value.pointer("/object_kind") {
+        if let Value::String(object_kind) = object_kind {
+            check_web_hook(value, object_kind)
+        } else {
+            unrecognized("`object_kind` is not a string")
+        }
+    } else if let Some(event_name) = value.pointer("/event_name") {
+        if let Value::String(event_name) = event_name {
+            check_system_hook(value, event_name)
+        } else {
+            unrecognized("`event_name` is not a string")
+        }
+    } else {
+        unrecognized("missing either `object_kind` or `event_name`")
+    }
+}
+
+/// The result of checking a payload file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadReport {
+    /// The path to the payload.
+    pub path: PathBuf,
+    /// The GitLab version the payload was recorded from.
+    pub version: String,
+    /// The result of the check.
+    pub check: PayloadCheck,
+}
+
+/// The results of checking a directory of payloads.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaReport {
+    /// The reports for each payload.
+    ///
+    /// Reports are sorted by version and then path.
+    pub payloads: Vec<PayloadReport>,
+}
+
+impl SchemaReport {
+    /// The versions which payloads were recorded from.
+    pub fn versions(&self) -> Vec<&str> {
+        let mut versions = self
+            .payloads
+            .iter()
+            .map(|payload| payload.version.as_str())
+            .collect::<Vec<_>>();
+        versions.dedup();
+        versions
+    }
+
+    /// The payloads which cannot be deserialized without losing data.
+    pub fn incompatible(&self) -> impl Iterator<Item = &PayloadReport> {
+        self.payloads
+            .iter()
+            .filter(|payload| !payload.check.outcome.is_compatible())
+    }
+
+    /// Whether all payloads can be deserialized without losing data.
+    pub fn is_compatible(&self) -> bool {
+        self.incompatible().next().is_none()
+    }
+}
+
+impl fmt::Display for SchemaReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for payload in &self.payloads {
+            let typename = payload.check.typename.unwrap_or("<unknown>");
+            match &payload.check.outcome {
+                PayloadOutcome::Parsed {
+                    dropped_fields,
+                } => {
+                    if dropped_fields.is_empty() {
+                        writeln!(
+                            f,
+                            "ok: {} ({}): {}",
+                            payload.path.display(),
+                            payload.version,
+                            typename,
+                        )?;
+                    } else {
+                        writeln!(
+                            f,
+                            "dropped: {} ({}): {}: {}",
+                            payload.path.display(),
+                            payload.version,
+                            typename,
+                            dropped_fields.join(", "),
+                        )?;
+                    }
+                },
+                PayloadOutcome::Failed {
+                    path,
+                    message,
+                } => {
+                    writeln!(
+                        f,
+                        "failed: {} ({}): {}: at `{}`: {}",
+                        payload.path.display(),
+                        payload.version,
+                        typename,
+                        path,
+                        message,
+                    )?;
+                },
+                PayloadOutcome::Unrecognized {
+                    reason,
+                } => {
+                    writeln!(
+                        f,
+                        "unrecognized: {} ({}): {}",
+                        payload.path.display(),
+                        payload.version,
+                        reason,
+                    )?;
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn sorted_entries(path: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+fn collect_payloads(
+    path: &Path,
+    version: &str,
+    reports: &mut Vec<PayloadReport>,
+) -> io::Result<()> {
+    for entry in sorted_entries(path)? {
+        if entry.is_dir() {
+            collect_payloads(&entry, version, reports)?;
+        } else if entry.extension() == Some(OsStr::new("json")) {
+            let contents = fs::read(&entry)?;
+            let check = match serde_json::from_slice(&contents) {
+                Ok(value) => check_payload(&value),
+                Err(err) => unrecognized(format!("invalid JSON: {}", err)),
+            };
+
+            reports.push(PayloadReport {
+                path: entry,
+                version: version.into(),
+                check,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Check a directory of recorded payloads.
+///
+/// Each subdirectory of `path` is named for the GitLab version its payloads were recorded from.
+/// Files directly within `path` are ignored.
+pub fn check_directory<P>(path: P) -> io::Result<SchemaReport>
+where
+    P: AsRef<Path>,
+{
+    let mut payloads = Vec::new();
+    for entry in sorted_entries(path.as_ref())? {
+        if !entry.is_dir() {
+            continue;
+        }
+
+        let version = entry
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        collect_payloads(&entry, &version, &mut payloads)?;
+    }
+
+    Ok(SchemaReport {
+        payloads,
+    })
+}
@@ -4,9 +4,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(feature = "client_api")]
+pub mod body;
 #[cfg(feature = "client_api")]
 pub mod client;
 
+#[cfg(all(feature = "schema-tests", not(feature = "_nohooks")))]
+mod schema;
 #[cfg(not(feature = "_nohooks"))]
 mod webhooks;
 
@@ -27,3 +31,26 @@ macro_rules! assert_missing_field {
 }
 #[cfg(feature = "client_api")]
 pub(crate) use assert_missing_field;
+
+#[cfg(feature = "client_api")]
+macro_rules! assert_invalid_path_param {
+    ($err:expr, $type:tt, $field:expr $(,)?) => {
+        let in_err = $err;
+        let in_field = $field;
+        if let $type::ValidationError(msg) = in_err {
+            assert!(
+                msg.starts_with(&format!("invalid `{}`: ", in_field)),
+                "unexpected validation error (expected to be about `{}`): {}",
+                in_field,
+                msg,
+            );
+        } else {
+            panic!(
+                "unexpected error (expected `{}` to be invalid): {:?}",
+                in_field, in_err,
+            );
+        }
+    };
+}
+#[cfg(feature = "client_api")]
+pub(crate) use assert_invalid_path_param;
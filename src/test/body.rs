@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Structured expectations for request bodies.
+//!
+//! Comparing bodies as strings ties tests to the order parameters are added in by the endpoint.
+//! These expectations are instead compared after parsing the actual body.
+
+use std::borrow::Cow;
+
+use serde_json::Value;
+use url::form_urlencoded;
+
+type FormParams = Vec<(Cow<'static, str>, Cow<'static, str>)>;
+
+/// An expected request body which is compared structurally.
+#[derive(Debug, Clone)]
+pub enum StructuredBody {
+    /// A `application/x-www-form-urlencoded` body.
+    ///
+    /// Parameters may appear in any order, but repeated parameters (i.e., arrays) must keep their
+    /// relative order.
+    Form(FormParams),
+    /// A `application/json` body.
+    ///
+    /// Object keys may appear in any order.
+    Json(Value),
+}
+
+impl StructuredBody {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            StructuredBody::Form(_) => "application/x-www-form-urlencoded",
+            StructuredBody::Json(_) => "application/json",
+        }
+    }
+
+    pub fn check(&self, body: &[u8]) {
+        match self {
+            StructuredBody::Form(expected) => {
+                let actual = form_urlencoded::parse(body)
+                    .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                    .collect::<Vec<_>>();
+                let expected = expected
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    Self::sorted(actual),
+                    Self::sorted(expected),
+                    "\nform body is not the same:\nactual: {}\n",
+                    String::from_utf8_lossy(body),
+                );
+            },
+            StructuredBody::Json(expected) => {
+                let actual: Value = serde_json::from_slice(body).unwrap_or_else(|err| {
+                    panic!(
+                        "body is not JSON ({}): {}",
+                        err,
+                        String::from_utf8_lossy(body),
+                    )
+                });
+
+                assert_eq!(&actual, expected, "\nJSON body is not the same\n");
+            },
+        }
+    }
+
+    fn sorted(mut params: Vec<(String, String)>) -> Vec<(String, String)> {
+        // A stable sort on the key keeps array values in their given order.
+        params.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        params
+    }
+}
+
+#[test]
+fn test_form_body_ignores_parameter_order() {
+    let body = StructuredBody::Form(vec![("a".into(), "1".into()), ("b".into(), "x/y".into())]);
+    body.check(b"b=x%2Fy&a=1");
+}
+
+#[test]
+#[should_panic(expected = "form body is not the same")]
+fn test_form_body_keeps_array_order() {
+    let body = StructuredBody::Form(vec![("a[]".into(), "1".into()), ("a[]".into(), "2".into())]);
+    body.check(b"a%5B%5D=2&a%5B%5D=1");
+}
+
+#[test]
+#[should_panic(expected = "form body is not the same")]
+fn test_form_body_missing_parameter() {
+    let body = StructuredBody::Form(vec![("a".into(), "1".into()), ("b".into(), "2".into())]);
+    body.check(b"a=1");
+}
+
+#[test]
+fn test_json_body_ignores_key_order() {
+    let body = StructuredBody::Json(serde_json::json!({"a": 1, "b": [1, 2]}));
+    body.check(br#"{"b":[1,2],"a":1}"#);
+}
+
+#[test]
+#[should_panic(expected = "body is not JSON")]
+fn test_json_body_not_json() {
+    let body = StructuredBody::Json(serde_json::json!({}));
+    body.check(b"a=1");
+}
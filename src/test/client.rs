@@ -18,7 +18,8 @@ use serde::ser::Serialize;
 use thiserror::Error;
 use url::Url;
 
-use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::api::{ApiError, AsyncClient, Client, RestClient, UrlBase};
+use crate::test::body::StructuredBody;
 
 #[derive(Debug, Builder)]
 pub struct ExpectedUrl {
@@ -31,8 +32,12 @@ pub struct ExpectedUrl {
     pub content_type: Option<String>,
     #[builder(default)]
     pub body: Vec<u8>,
+    #[builder(setter(custom), default)]
+    pub structured_body: Option<StructuredBody>,
     #[builder(default = "StatusCode::OK")]
     pub status: StatusCode,
+    #[builder(default = "UrlBase::ApiV4")]
+    pub url_base: UrlBase,
 
     #[builder(default = "false")]
     pub paginated: bool,
@@ -50,6 +55,31 @@ impl ExpectedUrlBuilder {
         self.body = Some(body.bytes().collect());
         self
     }
+
+    /// Expect a form body with the given (unencoded) parameters in any order.
+    ///
+    /// This also sets the expected content type.
+    pub fn form_params(&mut self, pairs: &[(&'static str, &'static str)]) -> &mut Self {
+        let params = pairs
+            .iter()
+            .cloned()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        self.structured(StructuredBody::Form(params))
+    }
+
+    /// Expect a JSON body equivalent to the given value.
+    ///
+    /// This also sets the expected content type.
+    pub fn json_body(&mut self, body: serde_json::Value) -> &mut Self {
+        self.structured(StructuredBody::Json(body))
+    }
+
+    fn structured(&mut self, body: StructuredBody) -> &mut Self {
+        self.content_type = Some(Some(body.content_type().into()));
+        self.structured_body = Some(Some(body));
+        self
+    }
 }
 
 impl ExpectedUrl {
@@ -67,7 +97,7 @@ impl ExpectedUrl {
         assert_eq!(url.password(), None);
         assert_eq!(url.host_str().unwrap(), "gitlab.host.invalid");
         assert_eq!(url.port(), None);
-        assert_eq!(url.path(), format!("/api/v4/{}", self.endpoint));
+        assert_eq!(url.path(), self.path());
         let mut count = 0;
         for (ref key, ref value) in url.query_pairs() {
             if self.paginated && Self::is_pagination_key(key) {
@@ -87,6 +117,27 @@ impl ExpectedUrl {
         assert_eq!(url.fragment(), None);
     }
 
+    fn check_body(&self, body: &[u8]) {
+        if let Some(structured) = self.structured_body.as_ref() {
+            structured.check(body);
+        } else {
+            assert_eq!(
+                body,
+                self.body.as_slice(),
+                "\nbody is not the same:\nactual  : {}\nexpected: {}\n",
+                String::from_utf8_lossy(body),
+                String::from_utf8_lossy(&self.body),
+            );
+        }
+    }
+
+    fn path(&self) -> String {
+        match self.url_base {
+            UrlBase::ApiV4 => format!("/api/v4/{}", self.endpoint),
+            UrlBase::Instance => format!("/{}", self.endpoint),
+        }
+    }
+
     fn is_pagination_key(key: &str) -> bool {
         key == "pagination" || key == "__test_keyset" || key == "page" || key == "per_page"
     }
@@ -128,10 +179,7 @@ impl SingleTestClient {
     {
         let mut client = MockClient::default();
 
-        let request = (
-            expected.method.clone(),
-            format!("/api/v4/{}", expected.endpoint),
-        );
+        let request = (expected.method.clone(), expected.path());
         let response = MockResponse {
             status: expected.status,
             data: data.into(),
@@ -166,7 +214,7 @@ impl RestClient for SingleTestClient {
     }
 
     fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
-        Ok(Url::parse(&format!("{}/{}", CLIENT_STUB, endpoint))?)
+        Ok(Url::parse(&format!("{}{}", CLIENT_STUB, endpoint))?)
     }
 }
 
@@ -179,13 +227,7 @@ impl Client for SingleTestClient {
         let url = Url::parse(&format!("{}", request.uri_ref().unwrap())).unwrap();
         self.expected
             .check(request.method_ref().unwrap().clone(), &url);
-        assert_eq!(
-            &body,
-            &self.expected.body,
-            "\nbody is not the same:\nactual  : {}\nexpected: {}\n",
-            String::from_utf8_lossy(&body),
-            String::from_utf8_lossy(&self.expected.body),
-        );
+        self.expected.check_body(&body);
         let headers = request.headers_ref().unwrap();
         let content_type = headers
             .get_all(header::CONTENT_TYPE)
@@ -273,7 +315,7 @@ impl<T> RestClient for PagedTestClient<T> {
     }
 
     fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
-        Ok(Url::parse(&format!("{}/{}", CLIENT_STUB, endpoint))?)
+        Ok(Url::parse(&format!("{}{}", CLIENT_STUB, endpoint))?)
     }
 }
 
@@ -290,13 +332,7 @@ where
 
         self.expected
             .check(request.method_ref().unwrap().clone(), &url);
-        assert_eq!(
-            &body,
-            &self.expected.body,
-            "\nbody is not the same:\nactual  : {}\nexpected: {}\n",
-            String::from_utf8_lossy(&body),
-            String::from_utf8_lossy(&self.expected.body),
-        );
+        self.expected.check_body(&body);
         let headers = request.headers_ref().unwrap();
         let content_type = headers
             .get_all(header::CONTENT_TYPE)
@@ -0,0 +1,105 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use serde_json::json;
+
+use crate::schema::{self, PayloadOutcome};
+
+#[test]
+fn test_check_payload_unrecognized() {
+    let check = schema::check_payload(&json!({}));
+    assert_eq!(check.typename, None);
+    assert_eq!(
+        check.outcome,
+        PayloadOutcome::Unrecognized {
+            reason: "missing either `object_kind` or `event_name`".into(),
+        },
+    );
+
+    let check = schema::check_payload(&json!({"event_name": 1}));
+    assert_eq!(
+        check.outcome,
+        PayloadOutcome::Unrecognized {
+            reason: "`event_name` is not a string".into(),
+        },
+    );
+}
+
+#[test]
+fn test_check_directory() {
+    let report = schema::check_directory("src/test/schema").unwrap();
+
+    assert_eq!(report.versions(), ["16.0", "17.0"]);
+    assert!(!report.is_compatible());
+
+    let paths = report
+        .payloads
+        .iter()
+        .map(|payload| payload.path.to_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "src/test/schema/16.0/pipeline.json",
+            "src/test/schema/17.0/pipeline.json",
+            "src/test/schema/17.0/pipeline_bad.json",
+            "src/test/schema/17.0/system/user_create.json",
+            "src/test/schema/17.0/wiki_page.json",
+        ],
+    );
+
+    let outcomes = report
+        .payloads
+        .iter()
+        .map(|payload| (payload.check.typename, &payload.check.outcome))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outcomes,
+        [
+            (
+                Some("gitlab::webhooks::PipelineHook"),
+                &PayloadOutcome::Parsed {
+                    dropped_fields: Vec::new(),
+                },
+            ),
+            (
+                Some("gitlab::webhooks::PipelineHook"),
+                &PayloadOutcome::Parsed {
+                    dropped_fields: vec!["object_attributes.queued_duration".into()],
+                },
+            ),
+            (
+                Some("gitlab::webhooks::PipelineHook"),
+                &PayloadOutcome::Failed {
+                    path: "object_attributes.id".into(),
+                    message: "invalid type: string \"not a number\", expected u64".into(),
+                },
+            ),
+            (
+                Some("gitlab::systemhooks::UserSystemHook"),
+                &PayloadOutcome::Parsed {
+                    dropped_fields: Vec::new(),
+                },
+            ),
+            (
+                None,
+                &PayloadOutcome::Unrecognized {
+                    reason: "unrecognized webhook object kind: wiki_page".into(),
+                },
+            ),
+        ],
+    );
+    assert_eq!(report.incompatible().count(), 3);
+
+    let display = report.to_string();
+    assert!(display.starts_with(
+        "ok: src/test/schema/16.0/pipeline.json (16.0): gitlab::webhooks::PipelineHook\n",
+    ));
+    assert!(display.contains(
+        "dropped: src/test/schema/17.0/pipeline.json (17.0): gitlab::webhooks::PipelineHook: \
+         object_attributes.queued_duration\n",
+    ));
+}
@@ -0,0 +1,844 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(missing_docs)]
+
+//! Testing utilities
+//!
+//! This module provides a mock client which may be used to test code which uses the `api`
+//! module without a GitLab instance. Expected requests are registered with canned responses and
+//! are checked as requests are made.
+//!
+//! This module requires the `testing` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "testing", feature = "api-projects"))]
+//! # fn main() {
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use gitlab::api::{self, projects, Pagination, Query};
+//! use gitlab::testing::{ExpectedRequest, MockClient, MockResponse};
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Project {
+//!     name: String,
+//! }
+//!
+//! let client = MockClient::new();
+//! client.expect(
+//!     ExpectedRequest::builder()
+//!         .endpoint("projects/group%2Fproject")
+//!         .build()
+//!         .unwrap(),
+//!     MockResponse::json(&json!({"name": "project"})),
+//! );
+//! client.expect_paged(
+//!     ExpectedRequest::builder().endpoint("projects").build().unwrap(),
+//!     (0..150).map(|i| json!({"name": format!("project{}", i)})),
+//! );
+//!
+//! let endpoint = projects::Project::builder()
+//!     .project("group/project")
+//!     .build()
+//!     .unwrap();
+//! let project: Project = endpoint.query(&client).unwrap();
+//! assert_eq!(project.name, "project");
+//!
+//! let endpoint = projects::Projects::builder().build().unwrap();
+//! let projects: Vec<Project> = api::paged(endpoint, Pagination::All).query(&client).unwrap();
+//! assert_eq!(projects.len(), 150);
+//!
+//! client.assert_all_consumed();
+//! # }
+//! # #[cfg(not(all(feature = "testing", feature = "api-projects")))]
+//! # fn main() {}
+//! ```
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use derive_builder::Builder;
+use http::request::Builder as RequestBuilder;
+use http::{header, HeaderMap, HeaderName, HeaderValue, Method, Response, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+use url::Url;
+
+use crate::api::instance::Capabilities;
+use crate::api::{ApiError, AsyncClient, Client, RestClient};
+
+const MOCK_HOST: &str = "https://gitlab.host.invalid";
+const KEYSET_QUERY_PARAM: &str = "__mock_keyset";
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Errors from the mock client.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MockClientError {
+    /// A request did not match any expected request.
+    #[error("unexpected request: {} {} (next expected: {})", method, url, expected)]
+    UnexpectedRequest {
+        /// The method of the request.
+        method: Method,
+        /// The URL of the request.
+        url: String,
+        /// A description of the next expected request.
+        expected: String,
+    },
+}
+
+/// A request which is expected to be made to the mock client.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct ExpectedRequest {
+    /// The method of the request.
+    #[builder(default = "Method::GET")]
+    method: Method,
+    /// The endpoint of the request.
+    ///
+    /// This is relative to the `api/v4` prefix and is as-sent, so names used in paths must be
+    /// URL-encoded (e.g., `projects/group%2Fproject`).
+    #[builder(setter(into))]
+    endpoint: String,
+    /// The expected query parameters.
+    ///
+    /// Query parameters used for pagination are ignored.
+    #[builder(setter(name = "_query"), default, private)]
+    query: Vec<(String, String)>,
+    /// The expected content type of the request body.
+    #[builder(setter(into), default)]
+    content_type: Option<String>,
+    /// The expected body of the request.
+    ///
+    /// If not set, the body is not checked.
+    #[builder(setter(into), default)]
+    body: Option<Vec<u8>>,
+}
+
+impl ExpectedRequest {
+    /// Create a builder for an expected request.
+    pub fn builder() -> ExpectedRequestBuilder {
+        ExpectedRequestBuilder::default()
+    }
+
+    fn path(&self) -> String {
+        format!("/api/v4/{}", self.endpoint)
+    }
+
+    fn matches(&self, request: &RecordedRequest) -> bool {
+        if request.method != self.method || request.url.path() != self.path() {
+            return false;
+        }
+
+        let mut query = request
+            .url
+            .query_pairs()
+            .filter(|(key, _)| !is_pagination_key(key))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect::<Vec<_>>();
+        query.sort();
+        let mut expected_query = self.query.clone();
+        expected_query.sort();
+        if query != expected_query {
+            return false;
+        }
+
+        if let Some(content_type) = self.content_type.as_ref() {
+            if request.content_type.as_ref() != Some(content_type) {
+                return false;
+            }
+        }
+
+        if let Some(body) = self.body.as_ref() {
+            if &request.body != body {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn describe(&self) -> String {
+        format!("{} {}", self.method, self.path())
+    }
+}
+
+impl ExpectedRequestBuilder {
+    /// Expect a query parameter.
+    pub fn query_param<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.into()));
+        self
+    }
+
+    /// Expect a set of query parameters.
+    pub fn query_params<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(|(key, value)| (key.into(), value.into())));
+        self
+    }
+
+    /// Expect a body given as a string.
+    pub fn body_str(&mut self, body: &str) -> &mut Self {
+        self.body(body.as_bytes())
+    }
+}
+
+fn is_pagination_key(key: &str) -> bool {
+    key == "pagination" || key == KEYSET_QUERY_PARAM || key == "page" || key == "per_page"
+}
+
+/// A canned response from the mock client.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// A response with the given body.
+    pub fn raw<B>(body: B) -> Self
+    where
+        B: Into<Vec<u8>>,
+    {
+        Self {
+            status: StatusCode::OK,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A response with a JSON body.
+    pub fn json<T>(data: &T) -> Self
+    where
+        T: Serialize + ?Sized,
+    {
+        Self::raw(serde_json::to_vec(data).expect("failed to serialize mock response"))
+    }
+
+    /// An empty response.
+    ///
+    /// Useful for endpoints whose results are ignored.
+    pub fn empty() -> Self {
+        Self::raw(Vec::new())
+    }
+
+    /// Set the status of the response.
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Add a header to the response.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    fn response(&self) -> Response<Bytes> {
+        let mut builder = Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        builder.body(self.body.clone().into()).unwrap()
+    }
+}
+
+/// A request made to the mock client.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// The method of the request.
+    pub method: Method,
+    /// The URL of the request.
+    pub url: Url,
+    /// The content type of the request body.
+    pub content_type: Option<String>,
+    /// The headers of the request.
+    pub headers: HeaderMap,
+    /// The body of the request.
+    pub body: Vec<u8>,
+}
+
+enum Responder {
+    Single(MockResponse),
+    Paged {
+        items: Vec<Value>,
+        status: StatusCode,
+        served: bool,
+    },
+}
+
+struct Expectation {
+    request: ExpectedRequest,
+    responder: Responder,
+}
+
+impl Expectation {
+    fn is_partially_served(&self) -> bool {
+        matches!(
+            self.responder,
+            Responder::Paged {
+                served: true,
+                ..
+            }
+        )
+    }
+
+    /// Respond to a request.
+    ///
+    /// Returns the response and whether the expectation has been fully consumed.
+    fn respond(&mut self, url: &Url) -> (Response<Bytes>, bool) {
+        match &mut self.responder {
+            Responder::Single(response) => (response.response(), true),
+            Responder::Paged {
+                items,
+                status,
+                served,
+            } => {
+                *served = true;
+                paged_response(url, items, *status)
+            },
+        }
+    }
+}
+
+fn paged_response(url: &Url, items: &[Value], status: StatusCode) -> (Response<Bytes>, bool) {
+    let mut keyset_pagination = false;
+    let mut keyset = None;
+    let mut page = None;
+    let mut per_page = DEFAULT_PAGE_SIZE;
+
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "pagination" => keyset_pagination = value == "keyset",
+            KEYSET_QUERY_PARAM => keyset = value.parse().ok(),
+            "page" => page = value.parse().ok(),
+            "per_page" => per_page = value.parse().unwrap_or(DEFAULT_PAGE_SIZE),
+            _ => (),
+        }
+    }
+
+    let start = if keyset_pagination {
+        keyset.unwrap_or(0)
+    } else {
+        per_page * (cmp::max(page.unwrap_or(1), 1) - 1)
+    };
+    let start = cmp::min(start, items.len());
+    let end = cmp::min(start + per_page, items.len());
+
+    let mut builder = Response::builder().status(status);
+    let done = if keyset_pagination {
+        if end < items.len() {
+            let mut next_url = url.clone();
+            next_url
+                .query_pairs_mut()
+                .clear()
+                .extend_pairs(
+                    url.query_pairs()
+                        .filter(|(key, _)| key != KEYSET_QUERY_PARAM),
+                )
+                .append_pair(KEYSET_QUERY_PARAM, &end.to_string());
+            builder = builder.header(header::LINK, format!("<{}>; rel=\"next\"", next_url));
+            false
+        } else {
+            true
+        }
+    } else {
+        // Clients stop requesting pages once a short page has been seen.
+        end - start < per_page
+    };
+
+    let body = serde_json::to_vec(&items[start..end]).unwrap();
+    (builder.body(body.into()).unwrap(), done)
+}
+
+struct MockState {
+    expectations: VecDeque<Expectation>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// A mock GitLab client.
+///
+/// Requests made through the client are checked against the expected requests and answered with
+/// their canned responses. By default, requests must be made in the order they are expected; use
+/// `unordered` to allow any order.
+///
+/// Requests which do not match an expectation return a `MockClientError::UnexpectedRequest`
+/// error.
+pub struct MockClient {
+    ordered: bool,
+    capabilities: Option<Capabilities>,
+    state: Mutex<MockState>,
+}
+
+impl MockClient {
+    /// Create a mock client which expects requests in order.
+    pub fn new() -> Self {
+        Self {
+            ordered: true,
+            capabilities: None,
+            state: Mutex::new(MockState {
+                expectations: VecDeque::new(),
+                requests: Vec::new(),
+            }),
+        }
+    }
+
+    /// Create a mock client which accepts expected requests in any order.
+    pub fn unordered() -> Self {
+        Self {
+            ordered: false,
+            ..Self::new()
+        }
+    }
+
+    /// Track the capabilities of the mocked instance.
+    ///
+    /// Endpoints which behave differently across GitLab versions will query the `version`
+    /// endpoint through the client, so it must be expected like any other request.
+    pub fn with_capabilities(mut self) -> Self {
+        self.capabilities = Some(Capabilities::new());
+        self
+    }
+
+    /// Expect a request and answer it with the given response.
+    ///
+    /// Each expectation is used for a single request. Expect the same request multiple times
+    /// if it will be made more than once.
+    pub fn expect(&self, request: ExpectedRequest, response: MockResponse) -> &Self {
+        self.push(request, Responder::Single(response))
+    }
+
+    /// Expect a paginated request and answer it with pages of the given items.
+    ///
+    /// The expectation is used for all pages of the request. Both page-based and keyset
+    /// pagination are supported.
+    pub fn expect_paged<I, T>(&self, request: ExpectedRequest, items: I) -> &Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Serialize,
+    {
+        let items = items
+            .into_iter()
+            .map(|item| serde_json::to_value(item).expect("failed to serialize mock item"))
+            .collect();
+        self.push(
+            request,
+            Responder::Paged {
+                items,
+                status: StatusCode::OK,
+                served: false,
+            },
+        )
+    }
+
+    fn push(&self, request: ExpectedRequest, responder: Responder) -> &Self {
+        self.lock().expectations.push_back(Expectation {
+            request,
+            responder,
+        });
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().expect("poisoned mock client state")
+    }
+
+    /// The requests made to the client so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Assert that all expected requests have been made.
+    ///
+    /// Paginated expectations are satisfied once any page has been requested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any expected request has not been made.
+    pub fn assert_all_consumed(&self) {
+        let state = self.lock();
+        let remaining = state
+            .expectations
+            .iter()
+            .filter(|expectation| !expectation.is_partially_served())
+            .map(|expectation| expectation.request.describe())
+            .collect::<Vec<_>>();
+        assert!(
+            remaining.is_empty(),
+            "expected requests were not made: {:?}",
+            remaining,
+        );
+    }
+
+    fn handle(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<MockClientError>> {
+        let method = request.method_ref().cloned().unwrap_or(Method::GET);
+        let url = Url::parse(
+            &request
+                .uri_ref()
+                .expect("request without a URI")
+                .to_string(),
+        )?;
+        let content_type = request.headers_ref().and_then(|headers| {
+            headers
+                .get(header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+        });
+        let headers = request.headers_ref().cloned().unwrap_or_default();
+        let recorded = RecordedRequest {
+            method,
+            url,
+            content_type,
+            headers,
+            body,
+        };
+
+        let mut state = self.lock();
+        state.requests.push(recorded.clone());
+
+        let index = if self.ordered {
+            loop {
+                match state.expectations.front() {
+                    Some(expectation) if expectation.request.matches(&recorded) => break Some(0),
+                    // A paginated request which has stopped early is done.
+                    Some(expectation) if expectation.is_partially_served() => {
+                        state.expectations.pop_front();
+                    },
+                    _ => break None,
+                }
+            }
+        } else {
+            state
+                .expectations
+                .iter()
+                .position(|expectation| expectation.request.matches(&recorded))
+        };
+
+        let index = if let Some(index) = index {
+            index
+        } else {
+            let expected = state
+                .expectations
+                .front()
+                .map(|expectation| expectation.request.describe())
+                .unwrap_or_else(|| "none".into());
+            return Err(ApiError::client(MockClientError::UnexpectedRequest {
+                method: recorded.method,
+                url: recorded.url.into(),
+                expected,
+            }));
+        };
+
+        let (response, done) = state.expectations[index].respond(&recorded.url);
+        if done {
+            state.expectations.remove(index);
+        }
+
+        Ok(response)
+    }
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RestClient for MockClient {
+    type Error = MockClientError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!("{}/api/v4/{}", MOCK_HOST, endpoint))?)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!("{}/{}", MOCK_HOST, endpoint))?)
+    }
+
+    fn capabilities(&self) -> Option<&Capabilities> {
+        self.capabilities.as_ref()
+    }
+}
+
+impl Client for MockClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.handle(request, body)
+    }
+}
+
+#[async_trait]
+impl AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        self.handle(request, body)
+    }
+}
+
+#[cfg(all(test, feature = "api-projects"))]
+mod tests {
+    use http::{Method, StatusCode};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::projects::{self, issues};
+    use crate::api::{self, ApiError, AsyncQuery, Pagination, Query};
+    use crate::testing::{
+        ExpectedRequest, ExpectedRequestBuilderError, MockClient, MockClientError, MockResponse,
+    };
+
+    #[derive(Debug, Deserialize)]
+    struct Dummy {
+        value: u64,
+    }
+
+    fn project_request() -> ExpectedRequest {
+        ExpectedRequest::builder()
+            .endpoint("projects/group%2Fproject")
+            .build()
+            .unwrap()
+    }
+
+    fn project_endpoint() -> projects::Project<'static> {
+        projects::Project::builder()
+            .project("group/project")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn endpoint_is_needed() {
+        let err = ExpectedRequest::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ExpectedRequestBuilderError, "endpoint");
+    }
+
+    #[test]
+    fn single_request() {
+        let client = MockClient::new();
+        client.expect(project_request(), MockResponse::json(&json!({"value": 1})));
+
+        let res: Dummy = project_endpoint().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        client.assert_all_consumed();
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(requests[0].url.path(), "/api/v4/projects/group%2Fproject");
+    }
+
+    #[test]
+    fn ordered_requests() {
+        let client = MockClient::new();
+        client
+            .expect(project_request(), MockResponse::json(&json!({"value": 1})))
+            .expect(
+                ExpectedRequest::builder()
+                    .method(Method::POST)
+                    .endpoint("projects/group%2Fproject/issues")
+                    .content_type("application/x-www-form-urlencoded")
+                    .body_str("title=title")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"value": 2})),
+            );
+
+        let create = issues::CreateIssue::builder()
+            .project("group/project")
+            .title("title")
+            .build()
+            .unwrap();
+        let err = api::ignore(&create).query(&client).unwrap_err();
+        if let ApiError::Client {
+            source:
+                MockClientError::UnexpectedRequest {
+                    method,
+                    expected,
+                    ..
+                },
+        } = err
+        {
+            assert_eq!(method, Method::POST);
+            assert_eq!(expected, "GET /api/v4/projects/group%2Fproject");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+
+        let res: Dummy = project_endpoint().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        let res: Dummy = create.query(&client).unwrap();
+        assert_eq!(res.value, 2);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn unordered_requests() {
+        let client = MockClient::unordered();
+        client
+            .expect(project_request(), MockResponse::json(&json!({"value": 1})))
+            .expect(
+                ExpectedRequest::builder()
+                    .endpoint("projects/group%2Fproject/issues/1")
+                    .build()
+                    .unwrap(),
+                MockResponse::json(&json!({"value": 2})),
+            );
+
+        let issue = issues::Issue::builder()
+            .project("group/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        let res: Dummy = issue.query(&client).unwrap();
+        assert_eq!(res.value, 2);
+        let res: Dummy = project_endpoint().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn query_params() {
+        let client = MockClient::new();
+        client.expect(
+            ExpectedRequest::builder()
+                .endpoint("projects/group%2Fproject")
+                .query_param("statistics", "true")
+                .build()
+                .unwrap(),
+            MockResponse::json(&json!({"value": 1})),
+        );
+
+        let err = api::ignore(project_endpoint()).query(&client).unwrap_err();
+        assert!(matches!(err, ApiError::Client { .. }));
+
+        let endpoint = projects::Project::builder()
+            .project("group/project")
+            .statistics(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn error_status() {
+        let client = MockClient::new();
+        client.expect(
+            project_request(),
+            MockResponse::json(&json!({"message": "404 Project Not Found"}))
+                .status(StatusCode::NOT_FOUND),
+        );
+
+        let err = api::ignore(project_endpoint()).query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "404 Project Not Found");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn paged_requests() {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects/group%2Fproject/issues")
+                .build()
+                .unwrap(),
+            (0..250).map(|value| json!({ "value": value })),
+        );
+        client.expect(project_request(), MockResponse::json(&json!({"value": 1})));
+
+        let endpoint = issues::Issues::builder()
+            .project("group/project")
+            .build()
+            .unwrap();
+        let res: Vec<Dummy> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 250);
+        itertools::assert_equal(res.iter().map(|d| d.value), 0..250);
+        // Three pages were requested.
+        assert_eq!(client.requests().len(), 3);
+
+        let res: Dummy = project_endpoint().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    fn paged_requests_limited() {
+        let client = MockClient::new();
+        client.expect_paged(
+            ExpectedRequest::builder()
+                .endpoint("projects")
+                .build()
+                .unwrap(),
+            (0..250).map(|value| json!({ "value": value })),
+        );
+        client.expect(project_request(), MockResponse::json(&json!({"value": 1})));
+
+        let endpoint = projects::Projects::builder().build().unwrap();
+        let res: Vec<Dummy> = api::paged(endpoint, Pagination::Limit(10))
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 10);
+
+        let res: Dummy = project_endpoint().query(&client).unwrap();
+        assert_eq!(res.value, 1);
+        client.assert_all_consumed();
+    }
+
+    #[test]
+    #[should_panic = "expected requests were not made"]
+    fn assert_all_consumed() {
+        let client = MockClient::new();
+        client.expect(project_request(), MockResponse::empty());
+        client.assert_all_consumed();
+    }
+
+    #[tokio::test]
+    async fn async_requests() {
+        let client = MockClient::new();
+        client.expect(project_request(), MockResponse::json(&json!({"value": 1})));
+
+        let res: Dummy = project_endpoint().query_async(&client).await.unwrap();
+        assert_eq!(res.value, 1);
+        client.assert_all_consumed();
+    }
+}